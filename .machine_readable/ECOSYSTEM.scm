@@ -54,14 +54,14 @@
     (project
       (name "panicbot")
       (relationship "direct-consumer")
-      (integration "invokes `panic-attack assail --output-format json`, translates WeakPoints to fleet Findings via PA001–PA020 rule mapping")
+      (integration "invokes `panic-attack assail --output-format json`, translates WeakPoints to fleet Findings via PA001–PA024 rule mapping")
       (url "https://github.com/hyperpolymath/gitbot-fleet")
       (description "Tier-4 verifier bot in gitbot-fleet — static analysis auditing via panic-attack")
       (interface
         (protocol "subprocess")
         (command "panic-attack assail <target> --output-format json")
         (output-format "AssailReport JSON (flat or assault envelope)")
-        (category-mapping "WeakPointCategory → PA001–PA020 rule IDs")
+        (category-mapping "WeakPointCategory → PA001–PA024 rule IDs")
         (severity-mapping "PascalCase → lowercase → fleet severity levels")
         (directives ".machine_readable/bot_directives/panicbot.scm")))
 
@@ -59,7 +59,7 @@
 
     (current-capabilities
       "Assail static analysis (47 languages: BEAM, ML, Lisp, functional, proof, logic, systems, config, scripting, nextgen DSLs)"
-      "20 weak point categories (UnsafeCode, CommandInjection, UnsafeDeserialization, AtomExhaustion, UnsafeFFI, PathTraversal, HardcodedSecret, etc.)"
+      "24 weak point categories (UnsafeCode, CommandInjection, UnsafeDeserialization, AtomExhaustion, UnsafeFFI, PathTraversal, HardcodedSecret, etc.)"
       "miniKanren-inspired logic engine with substitution-based unification"
       "Taint analysis: source-to-sink data flow tracking"
       "Cross-language vulnerability chain detection (FFI, NIF, Port, subprocess boundaries)"
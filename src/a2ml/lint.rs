@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Structural checks for `AI.a2ml` (`panic-attack manifest lint`).
+//!
+//! `Manifest::load` only needs well-formed s-expressions and quietly falls back to
+//! defaults for anything it cannot read. The lint reports every syntax error, with a
+//! hint where indentation points at a stray or missing `)`, and what would be ignored: a
+//! second copy of a section (only the first is read), unknown keys and values in the
+//! sections panic-attack reads (`reports`, `analyzers`, `advisories`), lists where a
+//! value belongs, and a missing `reports` section. Sections panic-attack does not read
+//! are left alone.
+
+use super::{Node, NodeValue, Parser, Position};
+use crate::assail::ExternalAnalyzer;
+use crate::report::ReportOutputFormat;
+use crate::storage::{ReportKind, StorageMode};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(position: Position, severity: Severity, message: String) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+            severity,
+            message,
+        }
+    }
+}
+
+/// What the values of a known key must be.
+enum Values {
+    /// Names accepted by `accepts`, listed in messages as `expected`.
+    OneOf {
+        what: &'static str,
+        accepts: fn(&str) -> bool,
+        expected: &'static str,
+    },
+    /// A single quoted path.
+    Path,
+}
+
+/// Keys of the sections `Manifest` reads.
+const SECTIONS: &[(&str, &[(&str, Values)])] = &[
+    (
+        "reports",
+        &[
+            (
+                "formats",
+                Values::OneOf {
+                    what: "report format",
+                    accepts: |value| ReportOutputFormat::parse(value).is_some(),
+                    expected: "json, yaml, nickel, sarif",
+                },
+            ),
+            (
+                "storage-targets",
+                Values::OneOf {
+                    what: "storage target",
+                    accepts: |value| StorageMode::from_str(value).is_some(),
+                    expected: "filesystem, verisimdb",
+                },
+            ),
+            (
+                "persist-kinds",
+                Values::OneOf {
+                    what: "report kind",
+                    accepts: |value| ReportKind::from_str(value).is_some(),
+                    expected: "assault, amuck, abduct, adjudicate, axial (audience)",
+                },
+            ),
+        ],
+    ),
+    (
+        "analyzers",
+        &[(
+            "enabled",
+            Values::OneOf {
+                what: "analyzer",
+                accepts: |value| ExternalAnalyzer::parse(value).is_some(),
+                expected: "clippy, semgrep, bandit",
+            },
+        )],
+    ),
+    ("advisories", &[("database", Values::Path)]),
+];
+
+/// Lint the manifest at `path`; only failing to read it is an `Err`.
+pub fn lint_file(path: &Path) -> Result<Vec<Diagnostic>> {
+    let raw = fs::read_to_string(path)
+        .with_context(|| format!("reading A2ML manifest {}", path.display()))?;
+    Ok(lint(&raw))
+}
+
+/// Diagnostics for a manifest document, in document order.
+pub fn lint(raw: &str) -> Vec<Diagnostic> {
+    let mut parser = Parser::new(raw);
+    let root = parser.parse_document();
+    // Syntax errors are recovered from, so the structure is still checked below.
+    let mut diagnostics: Vec<Diagnostic> = parser
+        .errors
+        .into_iter()
+        .map(|err| Diagnostic::new(err.position, Severity::Error, err.message))
+        .collect();
+    let Some(root) = root else {
+        return diagnostics;
+    };
+    let Some((name, entries)) = named_list(&root) else {
+        diagnostics.push(Diagnostic::new(
+            root.position,
+            Severity::Error,
+            "manifest must be a list starting with its name, e.g. (manifest ...)".to_string(),
+        ));
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+        return diagnostics;
+    };
+    if name != "manifest" {
+        diagnostics.push(Diagnostic::new(
+            root.position,
+            Severity::Warning,
+            format!("root is named `{}`; expected `manifest`", name),
+        ));
+    }
+
+    let mut sections: HashMap<&str, Position> = HashMap::new();
+    for entry in entries {
+        let Some((section, keys)) = named_list(entry) else {
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Error,
+                "expected a section: a list starting with its name".to_string(),
+            ));
+            continue;
+        };
+        if let Some(first) = sections.get(section) {
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Error,
+                format!(
+                    "duplicate section `{}` (first at {}); only the first is read",
+                    section, first
+                ),
+            ));
+            continue;
+        }
+        sections.insert(section, entry.position);
+        if let Some((_, known)) = SECTIONS.iter().find(|(name, _)| *name == section) {
+            lint_section(section, keys, known, &mut diagnostics);
+        }
+    }
+    if !sections.contains_key("reports") {
+        diagnostics.push(Diagnostic::new(
+            root.position,
+            Severity::Warning,
+            "no `reports` section; reports are written as json and nickel to the filesystem"
+                .to_string(),
+        ));
+    }
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    diagnostics
+}
+
+fn lint_section(
+    section: &str,
+    keys: &[Node],
+    known: &[(&str, Values)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut seen: HashMap<&str, Position> = HashMap::new();
+    for entry in keys {
+        let Some((key, values)) = named_list(entry) else {
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Error,
+                format!("expected a (key value ...) entry in `{}`", section),
+            ));
+            continue;
+        };
+        let Some((_, spec)) = known.iter().find(|(name, _)| *name == key) else {
+            let names: Vec<&str> = known.iter().map(|(name, _)| *name).collect();
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Warning,
+                format!(
+                    "unknown key `{}` in `{}` (expected {})",
+                    key,
+                    section,
+                    names.join(", ")
+                ),
+            ));
+            continue;
+        };
+        if let Some(first) = seen.get(key) {
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Warning,
+                format!(
+                    "duplicate key `{}` in `{}` (first at {})",
+                    key, section, first
+                ),
+            ));
+        } else {
+            seen.insert(key, entry.position);
+        }
+        if values.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                entry.position,
+                Severity::Warning,
+                format!("`{}` lists no values; the default applies", key),
+            ));
+        }
+        for value in values {
+            if let Some(diagnostic) = check_value(key, spec, value, values.len()) {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+}
+
+fn check_value(key: &str, spec: &Values, value: &Node, count: usize) -> Option<Diagnostic> {
+    let error = |message: String| Some(Diagnostic::new(value.position, Severity::Error, message));
+    match (spec, &value.value) {
+        (_, NodeValue::List(_)) => error(format!("`{}` takes values, not a list", key)),
+        (
+            Values::OneOf {
+                what,
+                accepts,
+                expected,
+            },
+            NodeValue::String(text) | NodeValue::Atom(text),
+        ) if !accepts(text) => error(format!(
+            "unknown {} `{}` (expected {})",
+            what, text, expected
+        )),
+        (Values::OneOf { .. }, _) => None,
+        (Values::Path, NodeValue::Atom(text)) => {
+            error(format!("`{}` path must be quoted: \"{}\"", key, text))
+        }
+        (Values::Path, NodeValue::String(_)) if count > 1 => Some(Diagnostic::new(
+            value.position,
+            Severity::Warning,
+            format!("`{}` takes one path; only the first is read", key),
+        )),
+        (Values::Path, NodeValue::String(_)) => None,
+    }
+}
+
+/// Name and remaining items of a list that starts with an atom.
+fn named_list(node: &Node) -> Option<(&str, &[Node])> {
+    let NodeValue::List(items) = &node.value else {
+        return None;
+    };
+    match items.first().map(|first| &first.value) {
+        Some(NodeValue::Atom(name)) => Some((name.as_str(), &items[1..])),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(raw: &str) -> Vec<String> {
+        lint(raw)
+            .iter()
+            .map(|d| format!("{}:{} {} {}", d.line, d.column, d.severity, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_reports_positions_of_structural_problems() {
+        let raw = r#"(manifest
+  (reports
+    (formats "json" "pdf")
+    (storage-targets filesystem (verisimdb))
+    (persist-kinds "audience")
+    (persist-kinds)
+    (retention "30d"))
+  (advisories (database osv/))
+  (reports (formats "yaml")))
+"#;
+        assert_eq!(
+            summary(raw),
+            [
+                "3:21 error unknown report format `pdf` (expected json, yaml, nickel, sarif)",
+                "4:33 error `storage-targets` takes values, not a list",
+                "6:5 warning duplicate key `persist-kinds` in `reports` (first at line 5, column 5)",
+                "6:5 warning `persist-kinds` lists no values; the default applies",
+                "7:5 warning unknown key `retention` in `reports` (expected formats, storage-targets, persist-kinds)",
+                "8:25 error `database` path must be quoted: \"osv/\"",
+                "9:3 error duplicate section `reports` (first at line 2, column 3); only the first is read",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_syntax_errors_and_missing_sections() {
+        assert_eq!(
+            summary("(manifest\n  (project \"x\"\n  (reports)"),
+            [
+                "1:1 warning no `reports` section; reports are written as json and nickel to the filesystem",
+                "2:3 error unterminated list (`project` at line 2, column 3 is probably missing its `)`: line 3 is indented like its sibling)",
+            ]
+        );
+        assert_eq!(
+            summary("(config (project \"x\") \"loose\")"),
+            [
+                "1:1 warning root is named `config`; expected `manifest`",
+                "1:1 warning no `reports` section; reports are written as json and nickel to the filesystem",
+                "1:23 error expected a section: a list starting with its name",
+            ]
+        );
+        let repo_manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/AI.a2ml");
+        assert_eq!(lint_file(Path::new(repo_manifest)).unwrap(), []);
+    }
+
+    #[test]
+    fn test_lint_recovers_and_points_at_stray_parenthesis() {
+        let raw = r#"(manifest
+  (reports
+    (formats "json"))
+    (storage-targets "disk"))
+  (analyzers (enabled "lint"))
+)
+"#;
+        assert_eq!(
+            summary(raw),
+            [
+                "5:3 error extra tokens after manifest (the `)` at line 3, column 21 probably closes `reports` too early: line 4 is indented deeper than its siblings)",
+                "6:1 error unexpected closing parenthesis",
+            ]
+        );
+        assert_eq!(
+            summary("(manifest (reports (formats \"json\"))) )"),
+            ["1:39 error unexpected closing parenthesis (the manifest already ended at line 1, column 37; is that `)` one too many?)"]
+        );
+
+        let err = Parser::new("(manifest\n  (a \"x)\n")
+            .parse_all()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: unterminated string literal"
+        );
+    }
+}
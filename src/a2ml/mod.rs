@@ -2,14 +2,17 @@
 
 //! Minimal A2ML parser and Nickel exporter
 
-use crate::report::formatter::nickel_escape_string;
+pub mod lint;
+pub mod nickel;
+
+use crate::assail::ExternalAnalyzer;
 use crate::report::ReportOutputFormat;
-use crate::storage::StorageMode;
+use crate::storage::write_atomic;
+use crate::storage::{ReportKind, StorageMode};
 use crate::types::{AssailReport, AssaultReport, AttackResult};
 use crate::{abduct, adjudicate, amuck, axial};
 use anyhow::{anyhow, Context, Result};
 use serde::de::DeserializeOwned;
-use serde_json;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -52,7 +55,8 @@ impl ReportBundleKind {
             "amuck" => Some(Self::Amuck),
             "abduct" => Some(Self::Abduct),
             "adjudicate" => Some(Self::Adjudicate),
-            "axial" => Some(Self::Axial),
+            // Pipelines and storage call axial reports `audience`.
+            "axial" | "audience" => Some(Self::Axial),
             _ => None,
         }
     }
@@ -160,7 +164,11 @@ impl Manifest {
         let raw = fs::read_to_string(path)
             .with_context(|| format!("reading A2ML manifest {}", path.display()))?;
         let mut parser = Parser::new(&raw);
-        let tree = parser.parse_all()?;
+        let tree = Sexpr::from(
+            parser
+                .parse_all()
+                .with_context(|| format!("parsing A2ML manifest {}", path.display()))?,
+        );
         if let Sexpr::List(mut items) = tree {
             if let Some(Sexpr::Atom(root)) = items.first() {
                 let root_name = root.clone();
@@ -218,10 +226,68 @@ impl Manifest {
             .unwrap_or_else(|| vec![StorageMode::Filesystem])
     }
 
+    /// Report kinds stored in the storage targets, from `(reports (persist-kinds "assault"
+    /// "adjudicate" ...))`; assault reports only when the manifest does not say.
+    pub fn persisted_kinds(&self) -> Vec<ReportKind> {
+        self.section_entries("reports")
+            .and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|(key, _)| key == "persist-kinds")
+                    .map(|(_, groups)| {
+                        groups
+                            .iter()
+                            .flat_map(|values| {
+                                values.iter().filter_map(|value| match value {
+                                    Sexpr::String(text) => ReportKind::from_str(text),
+                                    Sexpr::Atom(atom) => ReportKind::from_str(atom),
+                                    _ => None,
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+            })
+            .unwrap_or_else(|| vec![ReportKind::Assault])
+    }
+
+    /// External analyzers enabled by `(analyzers (enabled "clippy" "semgrep" ...))`.
+    pub fn external_analyzers(&self) -> Vec<ExternalAnalyzer> {
+        self.section_entries("analyzers")
+            .and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|(key, _)| key == "enabled")
+                    .map(|(_, groups)| {
+                        groups
+                            .iter()
+                            .flat_map(|values| {
+                                values.iter().filter_map(|value| match value {
+                                    Sexpr::String(text) => ExternalAnalyzer::parse(text),
+                                    Sexpr::Atom(atom) => ExternalAnalyzer::parse(atom),
+                                    _ => None,
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+            })
+            .unwrap_or_default()
+    }
+
+    /// Advisory snapshot from `(advisories (database "osv/"))`.
+    pub fn advisory_database(&self) -> Option<PathBuf> {
+        self.section_entries("advisories")?
+            .into_iter()
+            .find(|(key, _)| key == "database")
+            .and_then(|(_, groups)| {
+                groups.into_iter().flatten().find_map(|value| match value {
+                    Sexpr::String(text) => Some(PathBuf::from(text)),
+                    _ => None,
+                })
+            })
+    }
+
     pub fn to_nickel(&self) -> String {
-        let entries = gather_entries(&self.entries);
-        let body = record_to_nickel(&entries);
-        format!("let {} = {};\n{}", self.root_name, body, self.root_name)
+        nickel::render(&self.root_name, &self.entries)
     }
 
     fn section_entries(&self, key: &str) -> Option<Vec<(String, Vec<Vec<Sexpr>>)>> {
@@ -244,7 +310,7 @@ pub fn write_report_bundle(bundle: &ReportBundle, path: &Path) -> Result<()> {
             .with_context(|| format!("creating report-bundle parent {}", parent.display()))?;
     }
     let rendered = render_report_bundle(bundle)?;
-    fs::write(path, rendered).with_context(|| format!("writing {}", path.display()))?;
+    write_atomic(path, rendered).with_context(|| format!("writing {}", path.display()))?;
     Ok(())
 }
 
@@ -277,7 +343,7 @@ pub fn import_report_file(input: &Path, output: &Path) -> Result<ReportBundleKin
         ReportBundlePayload::Axial(v) => serde_json::to_string_pretty(v),
     }
     .context("serializing imported report")?;
-    fs::write(output, json).with_context(|| format!("writing {}", output.display()))?;
+    write_atomic(output, json).with_context(|| format!("writing {}", output.display()))?;
     Ok(bundle.kind())
 }
 
@@ -366,7 +432,7 @@ fn render_report_bundle(bundle: &ReportBundle) -> Result<String> {
 
 fn parse_report_bundle(raw: &str) -> Result<ReportBundle> {
     let mut parser = Parser::new(raw);
-    let tree = parser.parse_all()?;
+    let tree = Sexpr::from(parser.parse_all()?);
     let (root, entries) = match tree {
         Sexpr::List(items) => {
             if items.is_empty() {
@@ -479,9 +545,84 @@ enum Sexpr {
     List(Vec<Sexpr>),
 }
 
+/// 1-based line and column of a character in an A2ML document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Syntax error with the position it was found at.
+#[derive(Debug)]
+pub struct ParseError {
+    pub position: Position,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An expression and the position it starts at; `lint` reports against these.
+#[derive(Clone, Debug)]
+struct Node {
+    position: Position,
+    value: NodeValue,
+}
+
+#[derive(Clone, Debug)]
+enum NodeValue {
+    Atom(String),
+    String(String),
+    List(Vec<Node>),
+}
+
+impl From<Node> for Sexpr {
+    fn from(node: Node) -> Self {
+        match node.value {
+            NodeValue::Atom(atom) => Sexpr::Atom(atom),
+            NodeValue::String(text) => Sexpr::String(text),
+            NodeValue::List(items) => Sexpr::List(items.into_iter().map(Sexpr::from).collect()),
+        }
+    }
+}
+
+/// Where a `)` is probably missing or one too many, judged by indentation.
+#[derive(Clone, Debug)]
+enum Hint {
+    /// An item of `list` starts on a new line no deeper than the list itself.
+    Missing {
+        list: Position,
+        name: String,
+        item: Position,
+    },
+    /// An item starts on a new line deeper than its siblings, right after a list that
+    /// was closed at `close`.
+    Early {
+        close: Position,
+        name: String,
+        item: Position,
+    },
+}
+
+/// Recovering parser: it records syntax errors and keeps going, so one pass reports
+/// every problem in a file.
 struct Parser<'a> {
     chars: std::str::Chars<'a>,
     peeked: Option<Option<char>>,
+    position: Position,
+    errors: Vec<ParseError>,
+    hints: Vec<Hint>,
 }
 
 impl<'a> Parser<'a> {
@@ -489,6 +630,9 @@ impl<'a> Parser<'a> {
         Self {
             chars: input.chars(),
             peeked: None,
+            position: Position { line: 1, column: 1 },
+            errors: Vec::new(),
+            hints: Vec::new(),
         }
     }
 
@@ -503,7 +647,7 @@ impl<'a> Parser<'a> {
     }
 
     fn next(&mut self) -> Option<char> {
-        if let Some(opt) = self.peeked.take() {
+        let next = if let Some(opt) = self.peeked.take() {
             if let Some(ch) = opt {
                 self.chars.next();
                 Some(ch)
@@ -512,7 +656,20 @@ impl<'a> Parser<'a> {
             }
         } else {
             self.chars.next()
+        };
+        match next {
+            Some('\n') => {
+                self.position.line += 1;
+                self.position.column = 1;
+            }
+            Some(_) => self.position.column += 1,
+            None => {}
         }
+        next
+    }
+
+    fn error(&mut self, position: Position, message: String) {
+        self.errors.push(ParseError { position, message });
     }
 
     fn skip_whitespace(&mut self) {
@@ -533,36 +690,112 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_all(&mut self) -> Result<Sexpr> {
-        self.skip_whitespace();
-        let expr = self.parse_expr()?;
-        self.skip_whitespace();
-        if self.peek().is_some() {
-            Err(anyhow!("extra tokens after manifest"))
-        } else {
-            Ok(expr)
+    /// The first syntax error, or the document's single expression.
+    fn parse_all(&mut self) -> Result<Node, ParseError> {
+        let root = self.parse_document();
+        if !self.errors.is_empty() {
+            return Err(self.errors.remove(0));
         }
+        root.ok_or_else(|| ParseError {
+            position: self.position,
+            message: "unexpected EOF while parsing A2ML".to_string(),
+        })
     }
 
-    fn parse_expr(&mut self) -> Result<Sexpr> {
+    /// The first expression, recovered as far as possible; problems go to `errors`.
+    fn parse_document(&mut self) -> Option<Node> {
+        let mut root: Option<Node> = None;
+        // Where the root's closing `)` was, once it has been seen.
+        let mut root_end = None;
+        let mut explained = false;
+        loop {
+            self.skip_whitespace();
+            let position = self.position;
+            let message = match self.peek() {
+                None => break,
+                Some(')') => {
+                    self.next();
+                    "unexpected closing parenthesis"
+                }
+                Some(_) => {
+                    let expr = self.parse_expr();
+                    if root.is_none() {
+                        if matches!(expr.value, NodeValue::List(_)) {
+                            root_end = Some(Position {
+                                line: self.position.line,
+                                column: self.position.column - 1,
+                            });
+                        }
+                        root = Some(expr);
+                        continue;
+                    }
+                    "extra tokens after manifest"
+                }
+            };
+            // The first stray token usually follows a `)` that closed too much.
+            let hint = match (explained, root_end) {
+                (false, Some(end)) => {
+                    explained = true;
+                    Some(self.early_close_hint().unwrap_or_else(|| {
+                        format!(
+                            "the manifest already ended at {}; is that `)` one too many?",
+                            end
+                        )
+                    }))
+                }
+                _ => None,
+            };
+            let message = match hint {
+                Some(hint) => format!("{} ({})", message, hint),
+                None => message.to_string(),
+            };
+            self.error(position, message);
+        }
+        if root.is_none() && self.errors.is_empty() {
+            self.error(
+                self.position,
+                "unexpected EOF while parsing A2ML".to_string(),
+            );
+        }
+        root
+    }
+
+    fn early_close_hint(&self) -> Option<String> {
+        self.hints.iter().find_map(|hint| match hint {
+            Hint::Early { close, name, item } => Some(format!(
+                "the `)` at {} probably closes `{}` too early: line {} is indented deeper than its siblings",
+                close, name, item.line
+            )),
+            Hint::Missing { .. } => None,
+        })
+    }
+
+    fn missing_close_hint(&self) -> Option<String> {
+        self.hints.iter().find_map(|hint| match hint {
+            Hint::Missing { list, name, item } => Some(format!(
+                "`{}` at {} is probably missing its `)`: line {} is indented like its sibling",
+                name, list, item.line
+            )),
+            Hint::Early { .. } => None,
+        })
+    }
+
+    fn parse_expr(&mut self) -> Node {
         self.skip_whitespace();
         match self.peek() {
             Some('(') => self.parse_list(),
             Some('"') => self.parse_string(),
-            Some(ch) => {
-                if ch == ')' {
-                    Err(anyhow!("unexpected closing parenthesis"))
-                } else {
-                    self.parse_atom()
-                }
-            }
-            None => Err(anyhow!("unexpected EOF while parsing A2ML")),
+            _ => self.parse_atom(),
         }
     }
 
-    fn parse_list(&mut self) -> Result<Sexpr> {
+    fn parse_list(&mut self) -> Node {
+        let position = self.position;
         self.next(); // consume '('
-        let mut items = Vec::new();
+        let mut items: Vec<Node> = Vec::new();
+        // Column of the first item on a line of its own, and where the last list item closed.
+        let mut item_column = None;
+        let mut last_close: Option<(Position, String)> = None;
         loop {
             self.skip_whitespace();
             match self.peek() {
@@ -570,19 +803,78 @@ impl<'a> Parser<'a> {
                     self.next();
                     break;
                 }
-                Some(_) => items.push(self.parse_expr()?),
-                None => return Err(anyhow!("unterminated list")),
+                Some(_) => {
+                    let item = self.parse_expr();
+                    let on_new_line = item.position.line > position.line;
+                    if on_new_line && item.position.column <= position.column {
+                        self.hints.push(Hint::Missing {
+                            list: position,
+                            name: list_name(&items),
+                            item: item.position,
+                        });
+                    } else if on_new_line {
+                        match item_column {
+                            None => item_column = Some(item.position.column),
+                            Some(column) if item.position.column > column => {
+                                if let Some((close, name)) = last_close.take() {
+                                    self.hints.push(Hint::Early {
+                                        close,
+                                        name,
+                                        item: item.position,
+                                    });
+                                }
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                    last_close = match &item.value {
+                        NodeValue::List(inner) => Some((
+                            Position {
+                                line: self.position.line,
+                                column: self.position.column - 1,
+                            },
+                            list_name(inner),
+                        )),
+                        _ => None,
+                    };
+                    items.push(item);
+                }
+                None => {
+                    // Only the innermost open list reports; the lists around it end
+                    // at the same EOF.
+                    if !self
+                        .errors
+                        .iter()
+                        .any(|err| err.message.starts_with("unterminated"))
+                    {
+                        let message = match self.missing_close_hint() {
+                            Some(hint) => format!("unterminated list ({})", hint),
+                            None => "unterminated list".to_string(),
+                        };
+                        self.error(position, message);
+                    }
+                    break;
+                }
             }
         }
-        Ok(Sexpr::List(items))
+        Node {
+            position,
+            value: NodeValue::List(items),
+        }
     }
 
-    fn parse_string(&mut self) -> Result<Sexpr> {
+    fn parse_string(&mut self) -> Node {
+        let position = self.position;
         self.next(); // consume '"'
         let mut value = String::new();
         while let Some(ch) = self.next() {
             match ch {
-                '"' => return Ok(Sexpr::String(value)),
+                '"' => {
+                    return Node {
+                        position,
+                        value: NodeValue::String(value),
+                    }
+                }
                 '\\' => {
                     if let Some(esc) = self.next() {
                         let replacement = match esc {
@@ -599,10 +891,15 @@ impl<'a> Parser<'a> {
                 other => value.push(other),
             }
         }
-        Err(anyhow!("unterminated string literal"))
+        self.error(position, "unterminated string literal".to_string());
+        Node {
+            position,
+            value: NodeValue::String(value),
+        }
     }
 
-    fn parse_atom(&mut self) -> Result<Sexpr> {
+    fn parse_atom(&mut self) -> Node {
+        let position = self.position;
         let mut value = String::new();
         while let Some(ch) = self.peek() {
             if ch.is_whitespace() || ch == '(' || ch == ')' {
@@ -611,14 +908,21 @@ impl<'a> Parser<'a> {
             value.push(ch);
             self.next();
         }
-        if value.is_empty() {
-            Err(anyhow!("unexpected token"))
-        } else {
-            Ok(Sexpr::Atom(value))
+        Node {
+            position,
+            value: NodeValue::Atom(value),
         }
     }
 }
 
+/// Head atom of a list's items, for messages.
+fn list_name(items: &[Node]) -> String {
+    match items.first().map(|first| &first.value) {
+        Some(NodeValue::Atom(name)) => name.clone(),
+        _ => "list".to_string(),
+    }
+}
+
 fn gather_entries(entries: &[Sexpr]) -> Vec<(String, Vec<Vec<Sexpr>>)> {
     let mut grouped: Vec<(String, Vec<Vec<Sexpr>>)> = Vec::new();
     for entry in entries {
@@ -637,88 +941,13 @@ fn gather_entries(entries: &[Sexpr]) -> Vec<(String, Vec<Vec<Sexpr>>)> {
     grouped
 }
 
-fn record_to_nickel(entries: &[(String, Vec<Vec<Sexpr>>)]) -> String {
-    let lines: Vec<String> = entries
-        .iter()
-        .map(|(key, groups)| {
-            let value = if groups.len() == 1 {
-                values_to_nickel(&groups[0])
-            } else {
-                let array = groups
-                    .iter()
-                    .map(|values| values_to_nickel(values))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("[{}]", array)
-            };
-            format!("{} = {}", key_to_nickel(key), value)
-        })
-        .collect();
-    format!("{{\n    {}\n}}", lines.join(";\n    "))
-}
-
-fn values_to_nickel(values: &[Sexpr]) -> String {
-    match values.len() {
-        0 => "null".to_string(),
-        1 => value_to_nickel(&values[0]),
-        _ => {
-            if values.iter().all(|v| matches!(v, Sexpr::List(inner) if inner.first().map(|c| matches!(c, Sexpr::Atom(_))).unwrap_or(false))) {
-                let nested_entries = gather_entries(values);
-                record_to_nickel(&nested_entries)
-            } else {
-                let list = values
-                    .iter()
-                    .map(value_to_nickel)
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("[{}]", list)
-            }
-        }
-    }
-}
-
-fn value_to_nickel(value: &Sexpr) -> String {
-    match value {
-        Sexpr::String(text) => nickel_escape_string(text),
-        Sexpr::Atom(text) => nickel_escape_string(text),
-        Sexpr::List(list) => {
-            if list.is_empty() {
-                "{}".to_string()
-            } else if list.iter().all(|entry| {
-                matches!(entry, Sexpr::List(inner) if inner.first().map(|c| matches!(c, Sexpr::Atom(_))).unwrap_or(false))
-            }) {
-                let nested_entries = gather_entries(list);
-                record_to_nickel(&nested_entries)
-            } else {
-                let inner = list
-                    .iter()
-                    .map(value_to_nickel)
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("[{}]", inner)
-            }
-        }
-    }
-}
-
-fn key_to_nickel(key: &str) -> String {
-    if key
-        .chars()
-        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
-    {
-        key.to_string()
-    } else {
-        serde_json::to_string(key).unwrap_or_else(|_| format!("\"{}\"", key))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::{
-        AttackAxis, BugSignature, CrashReport, DependencyGraph, FileStatistics, Framework,
-        Language, OverallAssessment, ProgramStatistics, Severity, SignatureType, TaintMatrix,
-        TimelineEventReport, TimelineReport, WeakPoint, WeakPointCategory,
+        AttackAxis, BugSignature, CrashReport, FileStatistics, Framework, Language,
+        OverallAssessment, ProgramStatistics, Severity, SignatureType, TimelineEventReport,
+        TimelineReport, WeakPoint, WeakPointCategory,
     };
     use std::collections::BTreeMap;
     use std::path::PathBuf;
@@ -736,6 +965,9 @@ mod tests {
                 severity: Severity::Medium,
                 description: "unchecked result".to_string(),
                 recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             }],
             statistics: ProgramStatistics {
                 total_lines: 42,
@@ -757,9 +989,7 @@ mod tests {
                 threading_constructs: 0,
             }],
             recommended_attacks: vec![AttackAxis::Concurrency],
-            dependency_graph: DependencyGraph::default(),
-            taint_matrix: TaintMatrix::default(),
-            migration_metrics: None,
+            ..Default::default()
         }
     }
 
@@ -768,24 +998,24 @@ mod tests {
             program: PathBuf::from("./bin/target"),
             axis: AttackAxis::Cpu,
             success: false,
-            skipped: false,
-            skip_reason: None,
             exit_code: Some(1),
             duration: Duration::from_secs(1),
             peak_memory: 1024,
             crashes: vec![CrashReport {
                 timestamp: "2026-01-01T00:00:00Z".to_string(),
                 signal: Some("SIGABRT".to_string()),
-                backtrace: None,
                 stderr: "panic".to_string(),
-                stdout: String::new(),
+                ..Default::default()
             }],
             signatures_detected: vec![BugSignature {
                 signature_type: SignatureType::UnhandledError,
                 confidence: 0.5,
                 evidence: vec!["stderr panic".to_string()],
                 location: Some("main".to_string()),
+                rule: None,
+                calibration: None,
             }],
+            ..Default::default()
         }]
     }
 
@@ -811,8 +1041,13 @@ mod tests {
                     args: vec!["--foo".to_string()],
                     peak_memory: Some(1000),
                     ran: true,
+                    ..Default::default()
                 }],
+                stopped_by: None,
+                runs: None,
+                attribution: Vec::new(),
             }),
+            ..Default::default()
         }
     }
 
@@ -829,14 +1064,17 @@ mod tests {
             source_spec: None,
             preset: "dangerous".to_string(),
             max_combinations: 2,
+            seed: 6,
             output_dir: PathBuf::from("runtime/amuck"),
             combinations_planned: 2,
             combinations_run: 1,
+            baseline: None,
             outcomes: vec![amuck::AmuckOutcome {
                 id: 1,
                 name: "flip".to_string(),
                 operations: vec!["replace_first(true->false)".to_string()],
                 applied_changes: 1,
+                weak_point: None,
                 mutated_file: Some(PathBuf::from("runtime/amuck/main.amuck.001.rs")),
                 apply_error: None,
                 execution: Some(amuck::ExecutionOutcome {
@@ -846,7 +1084,12 @@ mod tests {
                     stdout: String::new(),
                     stderr: "compile error".to_string(),
                     spawn_error: None,
+                    artifacts: Vec::new(),
+                    identity: None,
+                    syscall_summary: None,
                 }),
+                comparison: None,
+                cleaned: false,
             }],
         }
     }
@@ -854,6 +1097,7 @@ mod tests {
     fn sample_abduct_report() -> abduct::AbductReport {
         abduct::AbductReport {
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: 7,
             target: PathBuf::from("src/main.rs"),
             source_root: PathBuf::from("src"),
             workspace_dir: PathBuf::from("runtime/abduct/abduct-20260101000000"),
@@ -866,6 +1110,7 @@ mod tests {
             time_scale: Some(0.1),
             virtual_now: Some("2026-01-01T00:00:00Z".to_string()),
             notes: vec!["sample abduct note".to_string()],
+            targets: Vec::new(),
             files: vec![abduct::AbductFileRecord {
                 source: PathBuf::from("src/main.rs"),
                 destination: PathBuf::from("runtime/abduct/abduct-20260101000000/src/main.rs"),
@@ -881,13 +1126,19 @@ mod tests {
                 stdout: "ok".to_string(),
                 stderr: String::new(),
                 spawn_error: None,
+                artifacts: Vec::new(),
+                identity: None,
+                syscall_summary: None,
             }),
+            no_network: false,
+            network_escape_signal: None,
         }
     }
 
     fn sample_adjudicate_report() -> adjudicate::AdjudicateReport {
         adjudicate::AdjudicateReport {
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: 8,
             reports: vec![
                 PathBuf::from("reports/a.json"),
                 PathBuf::from("reports/b.json"),
@@ -899,10 +1150,13 @@ mod tests {
                 assault_reports: 1,
                 amuck_reports: 1,
                 abduct_reports: 0,
+                axial_reports: 0,
                 total_crashes: 1,
                 total_signatures: 1,
                 critical_weak_points: 0,
                 failed_attacks: 1,
+                stalled_attacks: 0,
+                mixed_build_targets: 0,
                 mutation_apply_errors: 0,
                 mutation_exec_failures: 1,
                 abduct_exec_failures: 0,
@@ -919,6 +1173,16 @@ mod tests {
                 message: "failed attack execution needs review".to_string(),
             }],
             notes: Vec::new(),
+            risk: Some(adjudicate::RiskScore {
+                score: 31.6,
+                components: vec![adjudicate::ScoreComponent {
+                    signal: "total_crashes".to_string(),
+                    count: 1,
+                    risk: 0.3,
+                }],
+                previous: Some(40.0),
+                delta: Some(-8.4),
+            }),
         }
     }
 
@@ -927,6 +1191,7 @@ mod tests {
         signal_counts.insert("panic_signal".to_string(), 1);
         axial::AxialReport {
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: 9,
             target: PathBuf::from("src/main.rs"),
             executed_program: Some("panic-attack".to_string()),
             repeat: 1,
@@ -958,6 +1223,7 @@ mod tests {
                     evidence: "panic found in stderr".to_string(),
                 }],
                 spellcheck: None,
+                artifacts: Vec::new(),
             }],
             report_observations: Vec::new(),
             signal_counts,
@@ -968,6 +1234,8 @@ mod tests {
                 run_observations_with_misspellings: 0,
                 report_observations_with_misspellings: 0,
             }),
+            probe: None,
+            run_variance: None,
         }
     }
 
@@ -1038,6 +1306,7 @@ mod tests {
         };
         assert_eq!(payload.combinations_planned, 2);
         assert_eq!(payload.combinations_run, 1);
+        assert_eq!(payload.seed, 6);
     }
 
     #[test]
@@ -1052,6 +1321,7 @@ mod tests {
         };
         assert_eq!(payload.selected_files, 2);
         assert_eq!(payload.locked_files, 2);
+        assert_eq!(payload.seed, 7);
     }
 
     #[test]
@@ -1066,6 +1336,7 @@ mod tests {
         };
         assert_eq!(payload.verdict, "warn");
         assert_eq!(payload.processed_reports, 2);
+        assert_eq!(payload.seed, 8);
     }
 
     #[test]
@@ -1080,6 +1351,16 @@ mod tests {
         };
         assert_eq!(payload.observed_runs, 1);
         assert_eq!(payload.signal_counts.get("panic_signal"), Some(&1));
+        assert_eq!(payload.seed, 9);
+
+        let audience = rendered.replace("(kind \"axial\")", "(kind \"audience\")");
+        assert_ne!(audience, rendered);
+        let parsed = parse_report_bundle(&audience).expect("audience kind should parse");
+        assert_eq!(parsed.kind(), ReportBundleKind::Axial);
+        assert_eq!(
+            ReportBundleKind::parse("Audience"),
+            Some(ReportBundleKind::Axial)
+        );
     }
 
     #[test]
@@ -1178,6 +1459,19 @@ mod tests {
                 "imported json should parse for kind {}",
                 kind.as_str()
             );
+
+            let seed = match kind {
+                ReportBundleKind::Amuck => Some(6),
+                ReportBundleKind::Abduct => Some(7),
+                ReportBundleKind::Adjudicate => Some(8),
+                ReportBundleKind::Axial => Some(9),
+                _ => None,
+            };
+            if let Some(seed) = seed {
+                let header: serde_json::Value =
+                    serde_json::from_str(&output_body).expect("output should be json");
+                assert_eq!(header["seed"], seed, "seed of kind {}", kind.as_str());
+            }
         }
     }
 }
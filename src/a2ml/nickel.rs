@@ -0,0 +1,708 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Nickel form of the AI manifest (`panic-attack manifest`, `manifest from-nickel`).
+//!
+//! The export is a Nickel record checked by contracts for the sections panic-attack
+//! reads: report formats, storage targets, persisted kinds, analyzers and the advisory
+//! database, with their defaults. `nickel export` rejects a misspelt format before
+//! panic-attack ever sees it.
+//!
+//! `from_nickel` reads an edited export back into A2ML. It understands the subset the
+//! export uses: leading `let ... in` bindings (skipped), records, arrays, strings,
+//! numbers, booleans and `null`, field annotations (skipped) and a trailing contract.
+//! Records become nested entries and an array of records or of arrays becomes one
+//! entry per element. Anything computed (functions, interpolation, merges) has to be
+//! evaluated with `nickel export` first.
+
+use super::{gather_entries, Position, Sexpr};
+use anyhow::{anyhow, Result};
+
+/// Value contracts: name and accepted names, aliases included.
+const ENUMS: &[(&str, &[&str])] = &[
+    (
+        "ReportFormat",
+        &["json", "yaml", "yml", "nickel", "ncl", "sarif"],
+    ),
+    (
+        "StorageTarget",
+        &[
+            "filesystem",
+            "disk",
+            "local",
+            "verisimdb",
+            "verisim",
+            "veri",
+        ],
+    ),
+    (
+        "ReportKind",
+        &[
+            "assault",
+            "amuck",
+            "abduct",
+            "adjudicate",
+            "axial",
+            "audience",
+        ],
+    ),
+    ("Analyzer", &["clippy", "semgrep", "bandit"]),
+];
+
+/// Fields of the sections panic-attack reads: section, key, element contract and
+/// default. Fields without a default are optional paths.
+const FIELDS: &[(&str, &str, &str, Option<&str>)] = &[
+    (
+        "reports",
+        "formats",
+        "ReportFormat",
+        Some(r#"["json", "nickel"]"#),
+    ),
+    (
+        "reports",
+        "storage-targets",
+        "StorageTarget",
+        Some(r#"["filesystem"]"#),
+    ),
+    (
+        "reports",
+        "persist-kinds",
+        "ReportKind",
+        Some(r#"["assault"]"#),
+    ),
+    ("analyzers", "enabled", "Analyzer", Some("[]")),
+    ("advisories", "database", "String", None),
+];
+
+/// Comment carrying the A2ML root name, which Nickel has no place for.
+const ROOT_COMMENT: &str = "# a2ml-root: ";
+
+const SECTIONS: &[(&str, &str)] = &[
+    ("reports", "Reports"),
+    ("analyzers", "Analyzers"),
+    ("advisories", "Advisories"),
+];
+
+/// The manifest entries as a Nickel document with contracts.
+pub(super) fn render(root_name: &str, entries: &[Sexpr]) -> String {
+    let mut out = String::from(
+        "# panic-attack AI manifest. Check it with `nickel export`; convert it back to A2ML\n\
+         # with `panic-attack manifest from-nickel`.\n",
+    );
+    out.push_str(&format!("{}{}\n", ROOT_COMMENT, root_name));
+    for (name, values) in ENUMS {
+        let quoted: Vec<String> = values.iter().map(|value| string(value)).collect();
+        out.push_str(&format!(
+            "let {} = std.contract.from_predicate (fun value => std.is_string value && std.array.elem (std.string.lowercase value) [{}]) in\n",
+            name,
+            quoted.join(", ")
+        ));
+    }
+    for (section, contract) in SECTIONS {
+        out.push_str(&format!("let {} = {{\n", contract));
+        for (_, key, element, default) in FIELDS.iter().filter(|field| field.0 == *section) {
+            match default {
+                Some(default) => out.push_str(&format!(
+                    "  {} | Array {} | default = {},\n",
+                    key_name(key),
+                    element,
+                    default
+                )),
+                None => out.push_str(&format!("  {} | {} | optional,\n", key_name(key), element)),
+            }
+        }
+        out.push_str("  ..\n} in\n");
+    }
+    out.push_str("let Manifest = {\n");
+    for (section, contract) in SECTIONS {
+        out.push_str(&format!("  {} | {} | optional,\n", section, contract));
+    }
+    out.push_str("  ..\n} in\n");
+    out.push_str(&record(&gather_entries(entries), 0, None));
+    out.push_str(" | Manifest\n");
+    out
+}
+
+fn record(entries: &[(String, Vec<Vec<Sexpr>>)], depth: usize, section: Option<&str>) -> String {
+    if entries.is_empty() {
+        return "{}".to_string();
+    }
+    let indent = "  ".repeat(depth + 1);
+    let fields: Vec<String> = entries
+        .iter()
+        .map(|(key, groups)| {
+            let known = section.and_then(|section| {
+                FIELDS
+                    .iter()
+                    .find(|field| field.0 == section && field.1 == key)
+            });
+            let value = match known {
+                // Known fields always have their contract's shape, whatever the A2ML spelled.
+                Some((_, _, "String", _)) => groups
+                    .iter()
+                    .flatten()
+                    .find_map(scalar)
+                    .map_or_else(|| "null".to_string(), |text| string(&text)),
+                Some(_) => {
+                    let items: Vec<String> = groups
+                        .iter()
+                        .flatten()
+                        .filter_map(scalar)
+                        .map(|text| string(&text))
+                        .collect();
+                    format!("[{}]", items.join(", "))
+                }
+                None if groups.len() == 1 => {
+                    let nested_section = (depth == 0
+                        && SECTIONS.iter().any(|(name, _)| name == key))
+                    .then_some(key.as_str());
+                    values(&groups[0], depth + 1, nested_section)
+                }
+                None => {
+                    let items: Vec<String> = groups
+                        .iter()
+                        .map(|values_of| values(values_of, depth + 1, None))
+                        .collect();
+                    format!("[{}]", items.join(", "))
+                }
+            };
+            format!("{}{} = {}", indent, key_name(key), value)
+        })
+        .collect();
+    format!("{{\n{}\n{}}}", fields.join(",\n"), "  ".repeat(depth))
+}
+
+fn values(values: &[Sexpr], depth: usize, section: Option<&str>) -> String {
+    match values {
+        [] => "null".to_string(),
+        [single] if !is_entry(single) => value(single, depth),
+        _ if values.iter().all(is_entry) => record(&gather_entries(values), depth, section),
+        _ => {
+            let items: Vec<String> = values.iter().map(|item| value(item, depth)).collect();
+            format!("[{}]", items.join(", "))
+        }
+    }
+}
+
+fn value(item: &Sexpr, depth: usize) -> String {
+    match item {
+        Sexpr::String(text) | Sexpr::Atom(text) => string(text),
+        Sexpr::List(list) if list.is_empty() => "{}".to_string(),
+        Sexpr::List(list) if list.iter().all(is_entry) => {
+            record(&gather_entries(list), depth, None)
+        }
+        Sexpr::List(list) => {
+            let items: Vec<String> = list.iter().map(|item| value(item, depth)).collect();
+            format!("[{}]", items.join(", "))
+        }
+    }
+}
+
+/// A list starting with an atom, i.e. a `(key values...)` entry.
+fn is_entry(value: &Sexpr) -> bool {
+    matches!(value, Sexpr::List(inner) if matches!(inner.first(), Some(Sexpr::Atom(_))))
+}
+
+fn scalar(value: &Sexpr) -> Option<String> {
+    match value {
+        Sexpr::String(text) | Sexpr::Atom(text) => Some(text.clone()),
+        Sexpr::List(_) => None,
+    }
+}
+
+fn key_name(key: &str) -> String {
+    if key
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        && key.starts_with(|ch: char| ch.is_ascii_alphabetic())
+    {
+        key.to_string()
+    } else {
+        string(key)
+    }
+}
+
+/// Nickel string literal; `%{` would start an interpolation, so it is escaped.
+fn string(text: &str) -> String {
+    let mut out = String::from("\"");
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '%' if chars.peek() == Some(&'{') => out.push_str("\\%"),
+            _ => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A Nickel value of the supported subset.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Scalar(String),
+    /// Strings are quoted in A2ML; numbers and booleans are atoms.
+    Text(String),
+    Null,
+    Array(Vec<Value>),
+    Record(Vec<(String, Value)>),
+}
+
+/// Convert a Nickel manifest back into an A2ML document.
+pub fn from_nickel(source: &str) -> Result<String> {
+    let mut reader = Reader::new(source);
+    reader.skip_bindings()?;
+    let Value::Record(fields) = reader.value()? else {
+        return Err(reader.error("the manifest must be a record"));
+    };
+    // A trailing `| Contract` (or several) is allowed.
+    loop {
+        reader.skip_space();
+        if !reader.eat('|') {
+            break;
+        }
+        reader.skip_space();
+        reader.identifier()?;
+    }
+    reader.skip_space();
+    if reader.peek().is_some() {
+        return Err(reader.error("unexpected text after the manifest record"));
+    }
+    let root_name = source
+        .lines()
+        .find_map(|line| line.strip_prefix(ROOT_COMMENT))
+        .map_or("manifest", str::trim);
+    let mut out = format!("({}", root_name);
+    for (key, value) in &fields {
+        entries(key, value, 1, &mut out);
+    }
+    out.push_str(")\n");
+    Ok(out)
+}
+
+/// Append `(key ...)` entries for a field at `depth`.
+fn entries(key: &str, value: &Value, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    match value {
+        // An array of records or arrays is one entry per element.
+        Value::Array(items)
+            if !items.is_empty()
+                && items
+                    .iter()
+                    .all(|item| matches!(item, Value::Record(_) | Value::Array(_))) =>
+        {
+            for item in items {
+                entries(key, item, depth, out);
+            }
+        }
+        Value::Record(fields) => {
+            out.push_str(&format!("\n{}({}", indent, key));
+            for (inner_key, inner) in fields {
+                entries(inner_key, inner, depth + 1, out);
+            }
+            out.push(')');
+        }
+        _ => {
+            let mut items = Vec::new();
+            inline(value, &mut items);
+            out.push_str(&format!("\n{}({}", indent, key));
+            for item in items {
+                out.push(' ');
+                out.push_str(&item);
+            }
+            out.push(')');
+        }
+    }
+}
+
+/// Values of a one-line entry. A nested array is a sub-list whose first string names
+/// it, as the export writes `(readme "README.md")` inside a list.
+fn inline(value: &Value, items: &mut Vec<String>) {
+    match value {
+        Value::Null => {}
+        Value::Scalar(text) => items.push(text.clone()),
+        Value::Text(text) => items.push(a2ml_string(text)),
+        Value::Array(values) => {
+            for value in values {
+                match value {
+                    Value::Array(inner) => {
+                        let mut nested = Vec::new();
+                        if let Some(Value::Text(head)) = inner.first() {
+                            if !head.is_empty()
+                                && head.chars().all(|ch| {
+                                    ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '.'
+                                })
+                            {
+                                nested.push(head.clone());
+                                for rest in &inner[1..] {
+                                    inline(rest, &mut nested);
+                                }
+                                items.push(format!("({})", nested.join(" ")));
+                                continue;
+                            }
+                        }
+                        inline(value, &mut nested);
+                        items.push(format!("({})", nested.join(" ")));
+                    }
+                    other => inline(other, items),
+                }
+            }
+        }
+        Value::Record(fields) => {
+            for (key, value) in fields {
+                let mut nested = vec![key.clone()];
+                inline(value, &mut nested);
+                items.push(format!("({})", nested.join(" ")));
+            }
+        }
+    }
+}
+
+fn a2ml_string(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{}\"", text))
+}
+
+struct Reader<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, offset: 0 }
+    }
+
+    fn position(&self) -> Position {
+        let before = &self.source[..self.offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |last| last.chars().count())
+            + 1;
+        Position { line, column }
+    }
+
+    fn error(&self, message: &str) -> anyhow::Error {
+        anyhow!("{}: {}", self.position(), message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.offset += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        self.source[self.offset..].starts_with(text)
+    }
+
+    fn skip_space(&mut self) {
+        while let Some(ch) = self.peek() {
+            if ch.is_whitespace() {
+                self.bump();
+            } else if ch == '#' {
+                while let Some(ch) = self.bump() {
+                    if ch == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String> {
+        let start = self.offset;
+        while let Some(ch) = self.peek() {
+            if ch.is_alphanumeric() || matches!(ch, '_' | '-' | '\'' | '.') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        if start == self.offset {
+            return Err(self.error("expected a name"));
+        }
+        Ok(self.source[start..self.offset].to_string())
+    }
+
+    /// Skip `let name = ... in` bindings; their bodies are not evaluated.
+    fn skip_bindings(&mut self) -> Result<()> {
+        loop {
+            self.skip_space();
+            if !self.keyword("let") {
+                return Ok(());
+            }
+            let mut depth = 0usize;
+            loop {
+                self.skip_space();
+                match self.peek() {
+                    None => return Err(self.error("`let` without `in`")),
+                    Some('"') => {
+                        self.string()?;
+                    }
+                    Some('(' | '[' | '{') => {
+                        depth += 1;
+                        self.bump();
+                    }
+                    Some(')' | ']' | '}') => {
+                        depth = depth.saturating_sub(1);
+                        self.bump();
+                    }
+                    Some(_) if depth == 0 && self.keyword("in") => break,
+                    Some(ch) if ch.is_alphanumeric() || ch == '_' => {
+                        self.identifier()?;
+                    }
+                    Some(_) => {
+                        self.bump();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Consume `word` when it is the next whole word.
+    fn keyword(&mut self, word: &str) -> bool {
+        if !self.starts_with(word) {
+            return false;
+        }
+        let after = self.source[self.offset + word.len()..].chars().next();
+        if after.is_some_and(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-') {
+            return false;
+        }
+        self.offset += word.len();
+        true
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_space();
+        match self.peek() {
+            Some('{') => self.record(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Value::Text(self.string()?)),
+            Some('m') if self.starts_with("m%\"") => {
+                Err(self.error("multiline strings are not supported; use \"...\""))
+            }
+            Some(ch) if ch.is_ascii_digit() || ch == '-' => {
+                let start = self.offset;
+                while self
+                    .peek()
+                    .is_some_and(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '+'))
+                {
+                    self.bump();
+                }
+                Ok(Value::Scalar(self.source[start..self.offset].to_string()))
+            }
+            Some(_) if self.keyword("null") => Ok(Value::Null),
+            Some(_) if self.keyword("true") => Ok(Value::Scalar("true".to_string())),
+            Some(_) if self.keyword("false") => Ok(Value::Scalar("false".to_string())),
+            Some(_) => {
+                Err(self
+                    .error("unsupported expression; evaluate the file with `nickel export` first"))
+            }
+            None => Err(self.error("unexpected end of file")),
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        self.bump(); // '['
+        let mut items = Vec::new();
+        loop {
+            self.skip_space();
+            if self.eat(']') {
+                return Ok(Value::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_space();
+            if !self.eat(',') {
+                self.skip_space();
+                if !self.eat(']') {
+                    return Err(self.error("expected `,` or `]`"));
+                }
+                return Ok(Value::Array(items));
+            }
+        }
+    }
+
+    fn record(&mut self) -> Result<Value> {
+        self.bump(); // '{'
+        let mut fields = Vec::new();
+        loop {
+            self.skip_space();
+            if self.eat('}') {
+                return Ok(Value::Record(fields));
+            }
+            if self.starts_with("..") {
+                self.offset += 2;
+            } else {
+                let key = if self.peek() == Some('"') {
+                    self.string()?
+                } else {
+                    self.identifier()?
+                };
+                self.skip_space();
+                self.skip_annotations()?;
+                if self.eat('=') {
+                    fields.push((key, self.value()?));
+                }
+            }
+            self.skip_space();
+            if !self.eat(',') {
+                self.skip_space();
+                if !self.eat('}') {
+                    return Err(self.error("expected `,` or `}`"));
+                }
+                return Ok(Value::Record(fields));
+            }
+        }
+    }
+
+    /// Skip `| Contract`, `| default` and `| optional` annotations up to the field's
+    /// `=`, `,` or `}`.
+    fn skip_annotations(&mut self) -> Result<()> {
+        if self.peek() != Some('|') {
+            return Ok(());
+        }
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated field annotation")),
+                Some('"') => {
+                    self.string()?;
+                }
+                Some('=') if self.starts_with("==") || self.starts_with("=>") => {
+                    self.offset += 2;
+                }
+                Some('=' | ',' | '}') if depth == 0 => return Ok(()),
+                Some('(' | '[' | '{') => {
+                    depth += 1;
+                    self.bump();
+                }
+                Some(')' | ']' | '}') => {
+                    depth = depth.saturating_sub(1);
+                    self.bump();
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let start = self.position();
+        self.bump(); // '"'
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(anyhow!("{}: unterminated string", start)),
+                Some('"') => return Ok(value),
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(other) => value.push(other),
+                    None => return Err(anyhow!("{}: unterminated string", start)),
+                },
+                Some('%') if self.peek() == Some('{') => {
+                    return Err(self.error("string interpolation is not supported"))
+                }
+                Some(ch) => value.push(ch),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Manifest, Parser};
+    use super::*;
+    use crate::report::ReportOutputFormat;
+    use crate::storage::StorageMode;
+
+    fn manifest(raw: &str) -> Manifest {
+        let mut items = match Sexpr::from(Parser::new(raw).parse_all().unwrap()) {
+            Sexpr::List(items) => items,
+            other => panic!("not a list: {:?}", other),
+        };
+        items.remove(0);
+        Manifest {
+            root_name: "manifest".to_string(),
+            entries: items,
+        }
+    }
+
+    #[test]
+    fn test_nickel_export_has_contracts_and_round_trips() {
+        let raw = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/AI.a2ml")).unwrap();
+        let original = manifest(&raw);
+        let nickel = original.to_nickel();
+        assert!(nickel.contains(
+            "let Reports = {\n  formats | Array ReportFormat | default = [\"json\", \"nickel\"],"
+        ));
+        assert!(nickel.contains("  advisories | Advisories | optional,"));
+        assert!(nickel.ends_with("} | Manifest\n"));
+        assert!(nickel.contains("    \"storage-targets\" = [\"filesystem\", \"verisimdb\"]"));
+
+        let imported = from_nickel(&nickel).unwrap();
+        assert!(crate::a2ml::lint::lint(&imported).is_empty());
+        let back = manifest(&imported);
+        assert_eq!(back.to_nickel(), nickel);
+        assert_eq!(back.storage_modes(), original.storage_modes());
+        assert!(imported.contains("(docs \".\" (readme \"README.md\") (security"));
+    }
+
+    #[test]
+    fn test_from_nickel_reads_edits_and_rejects_computed_values() {
+        let edited = r#"let ReportFormat = std.contract.from_predicate (fun value => std.is_string value) in
+{
+  project = "demo",
+  reports = {
+    formats | Array ReportFormat = ["sarif"],
+    "storage-targets" = ["filesystem"],
+  },
+  advisories = { database = "osv/" },
+  retries = 3,
+  rule = [{ never = "x" }, { always = "y" }],
+} | Manifest
+"#;
+        let imported = from_nickel(edited).unwrap();
+        assert_eq!(
+            imported,
+            "(manifest\n  (project \"demo\")\n  (reports\n    (formats \"sarif\")\n    (storage-targets \"filesystem\"))\n  (advisories\n    (database \"osv/\"))\n  (retries 3)\n  (rule\n    (never \"x\"))\n  (rule\n    (always \"y\")))\n"
+        );
+        let back = manifest(&imported);
+        assert_eq!(back.report_formats(), [ReportOutputFormat::Sarif]);
+        assert_eq!(back.storage_modes(), [StorageMode::Filesystem]);
+        assert_eq!(
+            back.advisory_database(),
+            Some(std::path::PathBuf::from("osv/"))
+        );
+
+        let err = from_nickel("{\n  project = std.string.uppercase \"x\"\n}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: unsupported expression; evaluate the file with `nickel export` first"
+        );
+        assert!(from_nickel("{ a = \"%{b}\" }").is_err());
+        assert_eq!(string("100%{x} \"q\""), "\"100\\%{x} \\\"q\\\"\"");
+    }
+}
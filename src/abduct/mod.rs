@@ -1,8 +1,23 @@
 // SPDX-License-Identifier: PMPL-1.0-or-later
 
 //! Abduct isolation harness for defensive lock-in and delayed-trigger testing.
+//!
+//! Several targets (a module and its test file, say) can be abducted into one
+//! workspace: each target's dependency scope is resolved on its own and the
+//! union is copied once. The report keeps a record per target with the part of
+//! the workspace its scope selected, next to the combined file list.
+//!
+//! With `no_network` the exec command runs without network access (see
+//! [`network`]) and blocked connection attempts are reported.
+
+pub mod network;
 
 use crate::assail;
+use crate::report::artifacts::{self, ArtifactStore};
+use crate::run_as::{self, ProcessIdentity, RunAs};
+use crate::storage::write_atomic;
+use crate::syscalls::{self, SyscallSummary, Traced};
+use crate::types::{DependencyEdge, OutputArtifact};
 use anyhow::{anyhow, Context, Result};
 use filetime::FileTime;
 use serde::{Deserialize, Serialize};
@@ -35,7 +50,8 @@ pub struct ExecutionCommand {
 
 #[derive(Debug, Clone)]
 pub struct AbductConfig {
-    pub target: PathBuf,
+    /// Files to abduct; the first is the primary target that `{file}` names.
+    pub targets: Vec<PathBuf>,
     pub source_root: Option<PathBuf>,
     pub output_root: PathBuf,
     pub dependency_scope: DependencyScope,
@@ -46,11 +62,22 @@ pub struct AbductConfig {
     pub virtual_now: Option<String>,
     pub execute: Option<ExecutionCommand>,
     pub exec_timeout_secs: u64,
+    /// Run the exec command without network access.
+    pub no_network: bool,
+    /// Account the exec command is switched to.
+    pub run_as: Option<RunAs>,
+    /// Run the exec command under strace and summarize its syscalls.
+    pub trace_syscalls: bool,
+    /// Campaign seed, recorded in the report.
+    pub seed: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AbductReport {
     pub created_at: String,
+    /// Campaign seed of the run that produced the report.
+    #[serde(default)]
+    pub seed: u64,
     pub target: PathBuf,
     pub source_root: PathBuf,
     pub workspace_dir: PathBuf,
@@ -66,10 +93,27 @@ pub struct AbductReport {
     pub virtual_now: Option<String>,
     #[serde(default)]
     pub notes: Vec<String>,
+    /// Every target with its own dependency closure; `target` is the first one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<AbductTargetRecord>,
+    /// Combined file list of the workspace.
     #[serde(default)]
     pub files: Vec<AbductFileRecord>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub execution: Option<ExecutionOutcome>,
+    /// Whether the exec command ran without network access.
+    #[serde(default)]
+    pub no_network: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_escape_signal: Option<network::NetworkEscapeSignal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbductTargetRecord {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    /// Workspace-relative paths this target's dependency scope selected, itself included.
+    pub closure: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,33 +135,38 @@ pub struct ExecutionOutcome {
     pub stderr: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spawn_error: Option<String>,
+    /// Full stdout/stderr when too large to inline (see `report::artifacts`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<OutputArtifact>,
+    /// uid/gid the program ran with (see `run_as`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<ProcessIdentity>,
+    /// Files, network contacts and processes seen with `--trace-syscalls`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub syscall_summary: Option<SyscallSummary>,
 }
 
 pub fn run(config: AbductConfig) -> Result<AbductReport> {
-    if !config.target.exists() {
-        return Err(anyhow!(
-            "target file {} does not exist",
-            config.target.display()
-        ));
-    }
-    if !config.target.is_file() {
-        return Err(anyhow!(
-            "target path {} is not a file",
-            config.target.display()
-        ));
-    }
+    let targets = canonical_targets(&config.targets)?;
     if config.exec_timeout_secs == 0 {
         return Err(anyhow!("--exec-timeout must be at least 1 second"));
     }
     if config.time_mode == TimeMode::Slow && config.time_scale <= 0.0 {
         return Err(anyhow!("--time-scale must be > 0 for time-mode=slow"));
     }
+    if config.no_network && !network::supported() {
+        return Err(anyhow!("--no-network is only supported on Linux"));
+    }
+    if config.trace_syscalls && config.execute.is_some() {
+        syscalls::ensure_available()?;
+    }
 
-    let target = fs::canonicalize(&config.target)
-        .with_context(|| format!("canonicalizing target {}", config.target.display()))?;
-    let source_root = determine_source_root(&target, config.source_root)?;
-    let (selected_sources, mut notes) =
-        collect_selected_files(&target, &source_root, config.dependency_scope)?;
+    let source_root = determine_source_root(&targets, config.source_root)?;
+    let Selection {
+        files: selected_sources,
+        closures,
+        mut notes,
+    } = collect_closures(&targets, &source_root, config.dependency_scope)?;
 
     if selected_sources.is_empty() {
         return Err(anyhow!("no files selected for abduct run"));
@@ -142,7 +191,6 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
 
     // Copy-first strategy ensures all lock/time mutations happen on isolated artifacts only.
     let mut files = Vec::with_capacity(selected_sources.len());
-    let mut copied_target: Option<PathBuf> = None;
     for source_path in selected_sources {
         let relative = relative_path(&source_root, &source_path);
         let destination = workspace_dir.join(&relative);
@@ -157,9 +205,6 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
                 destination.to_string_lossy()
             )
         })?;
-        if source_path == target {
-            copied_target = Some(destination.clone());
-        }
         files.push(AbductFileRecord {
             source: source_path,
             destination,
@@ -169,12 +214,35 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
         });
     }
 
-    let copied_target = copied_target.ok_or_else(|| {
-        anyhow!(
-            "internal error: copied target file not found for {}",
-            target.display()
-        )
-    })?;
+    let mut target_records = Vec::with_capacity(targets.len());
+    for (target, closure) in targets.iter().zip(closures) {
+        let destination = files
+            .iter()
+            .find(|file| file.source == *target)
+            .map(|file| file.destination.clone())
+            .ok_or_else(|| {
+                anyhow!(
+                    "internal error: copied target file not found for {}",
+                    target.display()
+                )
+            })?;
+        target_records.push(AbductTargetRecord {
+            source: target.clone(),
+            destination,
+            closure: closure
+                .iter()
+                .map(|path| {
+                    relative_path(&source_root, path)
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect(),
+        });
+    }
+    let copied_targets: Vec<PathBuf> = target_records
+        .iter()
+        .map(|record| record.destination.clone())
+        .collect();
 
     // mtime shifting is a cheap delayed-trigger simulation primitive for file-timestamp checks.
     let mtime_shifted = if config.mtime_offset_days != 0 {
@@ -193,13 +261,16 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
     let execution = config.execute.as_ref().map(|exec| {
         run_execution(
             exec,
-            &copied_target,
+            &copied_targets,
             &workspace_dir,
             config.exec_timeout_secs,
             config.time_mode,
             config.time_scale,
             config.virtual_now.as_deref(),
             config.mtime_offset_days,
+            config.no_network,
+            config.run_as.as_ref(),
+            config.trace_syscalls,
         )
         .unwrap_or_else(|err| ExecutionOutcome {
             success: false,
@@ -209,20 +280,29 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
             stdout: String::new(),
             stderr: String::new(),
             spawn_error: Some(err.to_string()),
+            artifacts: Vec::new(),
+            identity: None,
+            syscall_summary: None,
         })
     });
 
+    let network_escape_signal = execution
+        .as_ref()
+        .filter(|_| config.no_network)
+        .and_then(|execution| network::escape_signal(&execution.stdout, &execution.stderr));
+
     if matches!(
         config.dependency_scope,
         DependencyScope::Direct | DependencyScope::TwoHops
-    ) && files.len() == 1
+    ) && files.len() == targets.len()
     {
-        notes.push("dependency graph did not resolve neighbors; only target copied".to_string());
+        notes.push("dependency graph did not resolve neighbors; only targets copied".to_string());
     }
 
     Ok(AbductReport {
         created_at: chrono::Utc::now().to_rfc3339(),
-        target,
+        seed: config.seed,
+        target: targets[0].clone(),
         source_root,
         workspace_dir,
         dependency_scope: dependency_scope_name(config.dependency_scope).to_string(),
@@ -238,8 +318,11 @@ pub fn run(config: AbductConfig) -> Result<AbductReport> {
         },
         virtual_now: config.virtual_now,
         notes,
+        targets: target_records,
         files,
         execution,
+        no_network: config.no_network,
+        network_escape_signal,
     })
 }
 
@@ -248,12 +331,57 @@ pub fn write_report(report: &AbductReport, path: &Path) -> Result<()> {
         fs::create_dir_all(parent)
             .with_context(|| format!("creating report parent directory {}", parent.display()))?;
     }
-    let json = serde_json::to_string_pretty(report).context("serializing abduct report")?;
-    fs::write(path, json).with_context(|| format!("writing report {}", path.display()))?;
+    let mut report = report.clone();
+    let store = ArtifactStore::beside(path);
+    if let Some(execution) = report.execution.as_mut() {
+        let fields = [
+            ("stdout", &mut execution.stdout),
+            ("stderr", &mut execution.stderr),
+        ];
+        artifacts::spill_fields(&store, &mut execution.artifacts, fields)?;
+    }
+    let json = serde_json::to_string_pretty(&report).context("serializing abduct report")?;
+    write_atomic(path, json).with_context(|| format!("writing report {}", path.display()))?;
     Ok(())
 }
 
-fn determine_source_root(target: &Path, source_root: Option<PathBuf>) -> Result<PathBuf> {
+/// Number and total size of the files `run` would copy into the workspace.
+pub fn planned_copy(config: &AbductConfig) -> Result<(usize, u64)> {
+    let targets = canonical_targets(&config.targets)?;
+    let source_root = determine_source_root(&targets, config.source_root.clone())?;
+    let selected = collect_closures(&targets, &source_root, config.dependency_scope)?.files;
+    let bytes = selected
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    Ok((selected.len(), bytes))
+}
+
+/// Canonical, de-duplicated target paths in the order given; each must be an existing file.
+fn canonical_targets(targets: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    if targets.is_empty() {
+        return Err(anyhow!("abduct needs at least one target file"));
+    }
+    let mut canonical: Vec<PathBuf> = Vec::with_capacity(targets.len());
+    for target in targets {
+        if !target.exists() {
+            return Err(anyhow!("target file {} does not exist", target.display()));
+        }
+        if !target.is_file() {
+            return Err(anyhow!("target path {} is not a file", target.display()));
+        }
+        let path = fs::canonicalize(target)
+            .with_context(|| format!("canonicalizing target {}", target.display()))?;
+        if !canonical.contains(&path) {
+            canonical.push(path);
+        }
+    }
+    Ok(canonical)
+}
+
+/// `--source-root` when given, else the deepest directory holding every target.
+fn determine_source_root(targets: &[PathBuf], source_root: Option<PathBuf>) -> Result<PathBuf> {
     if let Some(root) = source_root {
         let canonical = fs::canonicalize(&root)
             .with_context(|| format!("canonicalizing source root {}", root.display()))?;
@@ -271,16 +399,76 @@ fn determine_source_root(target: &Path, source_root: Option<PathBuf>) -> Result<
         }
         return Ok(canonical);
     }
-    target
+    let mut root = targets[0]
         .parent()
         .map(Path::to_path_buf)
-        .ok_or_else(|| anyhow!("cannot derive target parent for source root"))
+        .ok_or_else(|| anyhow!("cannot derive target parent for source root"))?;
+    for target in &targets[1..] {
+        while !target.starts_with(&root) {
+            if !root.pop() {
+                return Err(anyhow!("targets share no common directory"));
+            }
+        }
+    }
+    Ok(root)
 }
 
+/// Files selected for a run.
+struct Selection {
+    /// Union of every target's closure.
+    files: Vec<PathBuf>,
+    /// Each target's own closure, in target order.
+    closures: Vec<Vec<PathBuf>>,
+    /// Selection notes, prefixed with the target when there are several.
+    notes: Vec<String>,
+}
+
+fn collect_closures(
+    targets: &[PathBuf],
+    source_root: &Path,
+    scope: DependencyScope,
+) -> Result<Selection> {
+    let mut graph = None;
+    let mut union = BTreeSet::new();
+    let mut closures = Vec::with_capacity(targets.len());
+    let mut notes = Vec::new();
+    for target in targets {
+        let (selected, target_notes) =
+            collect_selected_files(target, source_root, scope, &mut graph)?;
+        for note in target_notes {
+            let note = if targets.len() > 1 {
+                let relative = relative_path(source_root, target);
+                format!("{}: {}", relative.display(), note)
+            } else {
+                note
+            };
+            if !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+        union.extend(selected.iter().cloned());
+        closures.push(selected);
+    }
+    Ok(Selection {
+        files: union.into_iter().collect(),
+        closures,
+        notes,
+    })
+}
+
+/// Dependency edges of `source_root` from an assail scan, `None` when the scan fails.
+fn dependency_edges(source_root: &Path) -> Option<Vec<DependencyEdge>> {
+    assail::analyze(source_root)
+        .ok()
+        .map(|report| report.dependency_graph.edges)
+}
+
+/// Files `scope` selects around `target`. `graph` caches the dependency scan across targets.
 fn collect_selected_files(
     target: &Path,
     source_root: &Path,
     scope: DependencyScope,
+    graph: &mut Option<Option<Vec<DependencyEdge>>>,
 ) -> Result<(Vec<PathBuf>, Vec<String>)> {
     let mut notes = Vec::new();
     let mut selected = BTreeSet::new();
@@ -308,18 +496,17 @@ fn collect_selected_files(
                     "target is outside --source-root; dependency scope fell back to target only"
                         .to_string(),
                 );
-            } else if let Ok(report) = assail::analyze(source_root) {
-                let target_rel = maybe_target_rel
-                    .expect("checked is_some")
-                    .to_string_lossy()
-                    .to_string();
+            } else if let (Some(target_rel), Some(edges)) = (
+                maybe_target_rel,
+                graph.get_or_insert_with(|| dependency_edges(source_root)),
+            ) {
+                let target_rel = target_rel.to_string_lossy().to_string();
                 let depth = if scope == DependencyScope::Direct {
                     1
                 } else {
                     2
                 };
-                let rel_nodes =
-                    related_nodes_from_graph(&target_rel, &report.dependency_graph.edges, depth);
+                let rel_nodes = related_nodes_from_graph(&target_rel, edges, depth);
                 if rel_nodes.len() <= 1 {
                     notes.push(
                         "no direct dependency neighbors found; falling back to same directory"
@@ -368,7 +555,7 @@ fn collect_selected_files(
 
 fn related_nodes_from_graph(
     target_rel: &str,
-    edges: &[crate::types::DependencyEdge],
+    edges: &[DependencyEdge],
     depth: usize,
 ) -> HashSet<String> {
     let mut adj: HashMap<String, Vec<String>> = HashMap::new();
@@ -471,45 +658,80 @@ fn set_readonly_preserve_exec(path: &Path) -> Result<()> {
 #[allow(clippy::too_many_arguments)]
 fn run_execution(
     command: &ExecutionCommand,
-    copied_target: &Path,
+    copied_targets: &[PathBuf],
     workspace_dir: &Path,
     timeout_secs: u64,
     time_mode: TimeMode,
     time_scale: f64,
     virtual_now: Option<&str>,
     mtime_offset_days: i64,
+    no_network: bool,
+    run_as: Option<&RunAs>,
+    trace_syscalls: bool,
 ) -> Result<ExecutionOutcome> {
-    let file_token = copied_target.to_string_lossy().to_string();
-    let workspace_token = workspace_dir.to_string_lossy().to_string();
-    let mut args = command
-        .args
+    let file_tokens: Vec<String> = copied_targets
         .iter()
-        .map(|arg| {
-            arg.replace("{file}", &file_token)
-                .replace("{workspace}", &workspace_token)
-        })
-        .collect::<Vec<_>>();
-    if args.is_empty() || !args.iter().any(|arg| arg == &file_token) {
-        args.push(file_token.clone());
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    let file_token = file_tokens[0].clone();
+    let workspace_token = workspace_dir.to_string_lossy().to_string();
+    // `{files}` as a whole argument expands to every copied target.
+    let mut args = Vec::with_capacity(command.args.len());
+    for arg in &command.args {
+        if arg == "{files}" {
+            args.extend(file_tokens.iter().cloned());
+        } else {
+            args.push(
+                arg.replace("{file}", &file_token)
+                    .replace("{workspace}", &workspace_token),
+            );
+        }
+    }
+    if !args.iter().any(|arg| file_tokens.contains(arg)) {
+        args.extend(file_tokens.iter().cloned());
     }
+    let files_env = std::env::join_paths(copied_targets)
+        .with_context(|| "joining target paths for ABDUCT_TARGET_FILES")?;
 
     let virtual_now_value = virtual_now
         .map(ToOwned::to_owned)
         .unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
 
-    let mut child = Command::new(&command.program)
-        .args(&args)
+    let traced = trace_syscalls.then(|| Traced::new(&command.program, &args));
+    let (program, args) = match &traced {
+        Some(traced) => (&traced.program, &traced.args),
+        None => (&command.program, &args),
+    };
+    let mut process = Command::new(program);
+    if let Some(run_as) = run_as {
+        run_as.apply(&mut process);
+    }
+    if no_network {
+        network::lock_down(&mut process);
+    }
+    let mut child = process
+        .args(args)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .env("ABDUCT_TARGET_FILE", &file_token)
+        .env("ABDUCT_TARGET_FILES", &files_env)
         .env("ABDUCT_WORKSPACE", &workspace_token)
         .env("ABDUCT_TIME_MODE", time_mode_name(time_mode))
         .env("ABDUCT_VIRTUAL_NOW", &virtual_now_value)
         .env("ABDUCT_MTIME_OFFSET_DAYS", mtime_offset_days.to_string())
         .env("ABDUCT_TIME_SCALE", time_scale.to_string())
         .spawn()
-        .with_context(|| format!("executing {}", command.program))?;
+        .with_context(|| {
+            if no_network {
+                format!(
+                    "executing {} without network access (needs unprivileged user namespaces)",
+                    command.program
+                )
+            } else {
+                format!("executing {}", command.program)
+            }
+        })?;
 
     let started = Instant::now();
     let limit = Duration::from_secs(timeout_secs);
@@ -532,21 +754,15 @@ fn run_execution(
         exit_code: output.status.code(),
         duration_ms: started.elapsed().as_millis(),
         timed_out,
-        stdout: clamp_output(String::from_utf8_lossy(&output.stdout).to_string()),
-        stderr: clamp_output(String::from_utf8_lossy(&output.stderr).to_string()),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
         spawn_error: None,
+        artifacts: Vec::new(),
+        identity: run_as::identity(run_as),
+        syscall_summary: traced.and_then(Traced::finish),
     })
 }
 
-fn clamp_output(mut value: String) -> String {
-    const MAX_LEN: usize = 8192;
-    if value.len() > MAX_LEN {
-        value.truncate(MAX_LEN);
-        value.push_str("\n...<truncated>");
-    }
-    value
-}
-
 fn dependency_scope_name(scope: DependencyScope) -> &'static str {
     match scope {
         DependencyScope::None => "none",
@@ -579,7 +795,7 @@ mod tests {
 
         let output_root = dir.path().join("runtime-abduct");
         let report = run(AbductConfig {
-            target: target.clone(),
+            targets: vec![target.clone()],
             source_root: Some(src.clone()),
             output_root,
             dependency_scope: DependencyScope::None,
@@ -590,6 +806,10 @@ mod tests {
             virtual_now: None,
             execute: None,
             exec_timeout_secs: 30,
+            no_network: false,
+            run_as: None,
+            trace_syscalls: false,
+            seed: 0,
         })
         .expect("abduct run should succeed");
 
@@ -610,7 +830,7 @@ mod tests {
 
         let output_root = dir.path().join("runtime-abduct");
         let report = run(AbductConfig {
-            target: target.clone(),
+            targets: vec![target.clone()],
             source_root: Some(src.clone()),
             output_root,
             dependency_scope: DependencyScope::Directory,
@@ -621,9 +841,71 @@ mod tests {
             virtual_now: None,
             execute: None,
             exec_timeout_secs: 30,
+            no_network: false,
+            run_as: None,
+            trace_syscalls: false,
+            seed: 0,
         })
         .expect("abduct run should succeed");
 
         assert_eq!(report.selected_files, 2);
     }
+
+    #[test]
+    fn abduct_multiple_targets_share_one_workspace() {
+        let dir = TempDir::new().expect("temp dir should create");
+        let module = dir.path().join("src").join("parser.rs");
+        let test = dir.path().join("tests").join("parser_test.rs");
+        let helper = dir.path().join("tests").join("helper.rs");
+        for path in [&module, &test, &helper] {
+            fs::create_dir_all(path.parent().unwrap()).expect("dir should create");
+            fs::write(path, "fn f() {}\n").expect("file should write");
+        }
+
+        let report = run(AbductConfig {
+            targets: vec![module.clone(), test.clone(), module.clone()],
+            source_root: None,
+            output_root: dir.path().join("runtime-abduct"),
+            dependency_scope: DependencyScope::Directory,
+            lock_files: false,
+            mtime_offset_days: 0,
+            time_mode: TimeMode::Normal,
+            time_scale: 1.0,
+            virtual_now: None,
+            execute: Some(ExecutionCommand {
+                program: "sh".to_string(),
+                args: vec![
+                    "-c".to_string(),
+                    "echo \"$# $ABDUCT_TARGET_FILES\"".to_string(),
+                    "sh".to_string(),
+                    "{files}".to_string(),
+                ],
+            }),
+            exec_timeout_secs: 30,
+            no_network: false,
+            run_as: None,
+            trace_syscalls: false,
+            seed: 0,
+        })
+        .expect("abduct run should succeed");
+
+        // The common directory is the source root; the duplicate target is dropped.
+        assert_eq!(report.source_root, fs::canonicalize(dir.path()).unwrap());
+        assert_eq!(report.targets.len(), 2);
+        assert_eq!(report.targets[0].closure, ["src/parser.rs"]);
+        assert_eq!(
+            report.targets[1].closure,
+            ["tests/helper.rs", "tests/parser_test.rs"]
+        );
+        assert_eq!(report.selected_files, 3);
+        assert!(report.targets[1]
+            .destination
+            .ends_with("tests/parser_test.rs"));
+        let stdout = &report.execution.as_ref().expect("execution").stdout;
+        assert!(stdout.starts_with("2 "), "{}", stdout);
+        assert!(stdout.contains(&format!(
+            "src/parser.rs:{}",
+            report.targets[1].destination.display()
+        )));
+    }
 }
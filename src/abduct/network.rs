@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Network lockdown for abduct exec (`--no-network`)
+//!
+//! The exec command is started in fresh user and network namespaces: the new
+//! network namespace has no interfaces but a loopback device that is down, so
+//! every connection, loopback included, fails instead of leaving the machine.
+//! The user namespace maps the caller's own uid and gid, so no privileges are
+//! needed as long as the kernel allows unprivileged user namespaces.
+//!
+//! A blocked connection shows up as an error in the command's output. Those
+//! errors (unreachable networks, failed name lookups) are collected as a
+//! `network_escape_signal`: the file under analysis tried to reach out.
+
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Most evidence lines kept in a [`NetworkEscapeSignal`].
+const MAX_EVIDENCE: usize = 5;
+
+/// Longest evidence line kept.
+const MAX_LINE: usize = 200;
+
+/// Output fragments (lowercase) of connections refused by the lockdown.
+const BLOCKED_PATTERNS: &[&str] = &[
+    "network is unreachable",
+    "enetunreach",
+    "temporary failure in name resolution",
+    "name or service not known",
+    "could not resolve host",
+    "failed to lookup address",
+    "no address associated with hostname",
+    "getaddrinfo",
+    "cannot assign requested address",
+];
+
+/// Connection attempts the exec command made while network access was locked down.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkEscapeSignal {
+    /// Output lines reporting a blocked connection.
+    pub attempts: usize,
+    /// The first few of those lines.
+    pub evidence: Vec<String>,
+}
+
+/// Make `command` start without network access.
+#[cfg(target_os = "linux")]
+pub fn lock_down(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+
+    unsafe {
+        command.pre_exec(|| {
+            // Runs after any `--run-as` switch, so the ids mapped are the ones the
+            // program runs with. Only async-signal-safe calls are allowed here.
+            let (uid, gid) = (libc::getuid(), libc::getgid());
+            if libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            // Mapping our own ids is optional; without it they show up as `nobody`.
+            let (uid_map, uid_len) = id_map(uid);
+            let (gid_map, gid_len) = id_map(gid);
+            let _ = write_proc(c"/proc/self/setgroups", b"deny")
+                .and_then(|_| write_proc(c"/proc/self/uid_map", &uid_map[..uid_len]))
+                .and_then(|_| write_proc(c"/proc/self/gid_map", &gid_map[..gid_len]));
+            Ok(())
+        });
+    }
+}
+
+/// `"<id> <id> 1\n"`, formatted without allocating.
+#[cfg(target_os = "linux")]
+fn id_map(id: u32) -> ([u8; 32], usize) {
+    let mut digits = [0u8; 10];
+    let mut len = 0;
+    let mut rest = id;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    let mut line = [0u8; 32];
+    let mut pos = 0;
+    for _ in 0..2 {
+        for digit in digits[..len].iter().rev() {
+            line[pos] = *digit;
+            pos += 1;
+        }
+        line[pos] = b' ';
+        pos += 1;
+    }
+    line[pos] = b'1';
+    line[pos + 1] = b'\n';
+    (line, pos + 2)
+}
+
+#[cfg(target_os = "linux")]
+fn write_proc(path: &std::ffi::CStr, content: &[u8]) -> std::io::Result<()> {
+    let fd = unsafe { libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC) };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let written = unsafe { libc::write(fd, content.as_ptr().cast(), content.len()) };
+    let result = if written < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(())
+    };
+    unsafe { libc::close(fd) };
+    result
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn lock_down(_command: &mut Command) {}
+
+/// Whether `lock_down` can isolate commands on this platform.
+pub fn supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Blocked connection attempts reported in the command's output, if any.
+pub fn escape_signal(stdout: &str, stderr: &str) -> Option<NetworkEscapeSignal> {
+    let blocked: Vec<&str> = stdout
+        .lines()
+        .chain(stderr.lines())
+        .filter(|line| {
+            let line = line.to_lowercase();
+            BLOCKED_PATTERNS
+                .iter()
+                .any(|pattern| line.contains(pattern))
+        })
+        .collect();
+    if blocked.is_empty() {
+        return None;
+    }
+    Some(NetworkEscapeSignal {
+        attempts: blocked.len(),
+        evidence: blocked
+            .iter()
+            .take(MAX_EVIDENCE)
+            .map(|line| clip(line.trim()))
+            .collect(),
+    })
+}
+
+fn clip(line: &str) -> String {
+    match line.char_indices().nth(MAX_LINE) {
+        Some((idx, _)) => format!("{}...", &line[..idx]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_signal_collects_blocked_connections() {
+        assert!(escape_signal("checked 3 files\n", "").is_none());
+        let signal = escape_signal(
+            "fetching payload\n",
+            "curl: (6) Could not resolve host: c2.example\n\
+             connect: Network is unreachable\n",
+        )
+        .unwrap();
+        assert_eq!(signal.attempts, 2);
+        assert_eq!(
+            signal.evidence[0],
+            "curl: (6) Could not resolve host: c2.example"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_id_map_line() {
+        let (line, len) = id_map(65534);
+        assert_eq!(&line[..len], b"65534 65534 1\n");
+        let (line, len) = id_map(0);
+        assert_eq!(&line[..len], b"0 0 1\n");
+    }
+}
@@ -2,23 +2,35 @@
 
 //! Adjudicate campaign-wide findings using miniKanren-style rule inference.
 
+pub mod summary;
+
 use crate::abduct::AbductReport;
 use crate::amuck::AmuckReport;
+use crate::axial::AxialReport;
 use crate::kanren::core::{FactDB, LogicFact, LogicRule, RuleMetadata, Term};
 use crate::report;
+use crate::storage::write_atomic;
 use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 pub struct AdjudicateConfig {
     pub reports: Vec<PathBuf>,
+    /// Earlier adjudicate report whose risk score the new one is compared against.
+    pub previous: Option<PathBuf>,
+    /// Campaign seed, recorded in the report.
+    pub seed: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdjudicateReport {
     pub created_at: String,
+    /// Campaign seed of the run that produced the report.
+    #[serde(default)]
+    pub seed: u64,
     pub reports: Vec<PathBuf>,
     pub processed_reports: usize,
     pub failed_reports: usize,
@@ -30,6 +42,9 @@ pub struct AdjudicateReport {
     pub priorities: Vec<PriorityFinding>,
     #[serde(default)]
     pub notes: Vec<String>,
+    /// Graded campaign risk; absent in reports from before scoring existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk: Option<RiskScore>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -37,10 +52,19 @@ pub struct AdjudicateTotals {
     pub assault_reports: usize,
     pub amuck_reports: usize,
     pub abduct_reports: usize,
+    /// Axial (audience) reports.
+    #[serde(default)]
+    pub axial_reports: usize,
     pub total_crashes: usize,
     pub total_signatures: usize,
     pub critical_weak_points: usize,
     pub failed_attacks: usize,
+    /// Attacks stopped because their progress check saw no progress.
+    #[serde(default)]
+    pub stalled_attacks: usize,
+    /// Targets whose binary differs between the assault reports, so their runs do not compare.
+    #[serde(default)]
+    pub mixed_build_targets: usize,
     pub mutation_apply_errors: usize,
     pub mutation_exec_failures: usize,
     pub abduct_exec_failures: usize,
@@ -61,6 +85,45 @@ pub struct PriorityFinding {
     pub message: String,
 }
 
+/// Campaign risk from 0 (no signal) to 100, combining every signal as independent evidence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskScore {
+    pub score: f64,
+    /// Signals that contributed, largest first.
+    pub components: Vec<ScoreComponent>,
+    /// Score of the previous campaign, when one was found.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreComponent {
+    /// A totals field, or `rule:<name>` for a rule hit.
+    pub signal: String,
+    pub count: usize,
+    /// This signal's own risk in 0..=1.
+    pub risk: f64,
+}
+
+/// Ceiling risk per totals signal; each occurrence closes half the remaining gap to it, so
+/// one crash counts for half the crash weight and four for nearly all of it.
+const SIGNAL_WEIGHTS: &[(&str, f64)] = &[
+    ("total_crashes", 0.60),
+    ("critical_weak_points", 0.40),
+    ("abduct_timeouts", 0.40),
+    ("failed_attacks", 0.25),
+    ("stalled_attacks", 0.20),
+    ("mutation_exec_failures", 0.20),
+    ("abduct_exec_failures", 0.20),
+    ("mutation_apply_errors", 0.10),
+    ("mixed_build_targets", 0.10),
+];
+
+/// Share of a fired rule's confidence (scaled by priority / 100) that enters the score.
+const RULE_WEIGHT: f64 = 0.5;
+
 pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
     if config.reports.is_empty() {
         return Err(anyhow!("provide at least one report path"));
@@ -72,6 +135,8 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
     let mut db = FactDB::new();
     let mut processed = 0usize;
     let mut failed = 0usize;
+    // Distinct SHA-256s seen per target program across the assault reports.
+    let mut builds: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
 
     for (idx, path) in config.reports.iter().enumerate() {
         let id = format!("report-{}", idx + 1);
@@ -93,6 +158,17 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
                     .iter()
                     .filter(|r| !r.skipped && !r.success)
                     .count();
+                totals.stalled_attacks += assault
+                    .attack_results
+                    .iter()
+                    .filter(|r| r.stall.is_some())
+                    .count();
+                for target in &assault.targets {
+                    let hashes = builds.entry(target.program.clone()).or_default();
+                    if !hashes.contains(&target.sha256) {
+                        hashes.push(target.sha256.clone());
+                    }
+                }
 
                 db.assert_fact(LogicFact::new("report", vec![Term::atom(&id)]));
                 if assault.total_crashes > 0 {
@@ -113,6 +189,9 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
                 {
                     db.assert_fact(LogicFact::new("medium_signal", vec![Term::atom(&id)]));
                 }
+                if assault.attack_results.iter().any(|r| r.stall.is_some()) {
+                    db.assert_fact(LogicFact::new("medium_signal", vec![Term::atom(&id)]));
+                }
             }
             Ok(ParsedReport::Amuck(amuck)) => {
                 // Mutation errors/failures are usually medium-signal, but trend across runs matters.
@@ -155,13 +234,31 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
                 }
 
                 db.assert_fact(LogicFact::new("report", vec![Term::atom(&id)]));
-                if abduct.execution.as_ref().is_some_and(|exe| exe.timed_out) {
+                // A sample that tried to reach the network under --no-network is as telling.
+                if abduct.execution.as_ref().is_some_and(|exe| exe.timed_out)
+                    || abduct.network_escape_signal.is_some()
+                {
                     db.assert_fact(LogicFact::new("high_signal", vec![Term::atom(&id)]));
                 }
                 if abduct.execution.as_ref().is_some_and(|exe| !exe.success) {
                     db.assert_fact(LogicFact::new("medium_signal", vec![Term::atom(&id)]));
                 }
             }
+            Ok(ParsedReport::Axial(axial)) => {
+                // The observer already graded its signals; carry the grades over as facts.
+                processed += 1;
+                totals.axial_reports += 1;
+                db.assert_fact(LogicFact::new("report", vec![Term::atom(&id)]));
+                let severities: Vec<&str> = axial_signals(&axial)
+                    .map(|signal| signal.severity.as_str())
+                    .collect();
+                if severities.contains(&"high") {
+                    db.assert_fact(LogicFact::new("high_signal", vec![Term::atom(&id)]));
+                }
+                if severities.contains(&"medium") {
+                    db.assert_fact(LogicFact::new("medium_signal", vec![Term::atom(&id)]));
+                }
+            }
             Err(err) => {
                 failed += 1;
                 notes.push(format!("{}: {}", path.display(), err));
@@ -169,6 +266,20 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
         }
     }
 
+    for (program, hashes) in builds.iter().filter(|(_, hashes)| hashes.len() > 1) {
+        totals.mixed_build_targets += 1;
+        let short: Vec<&str> = hashes
+            .iter()
+            .map(|hash| &hash[..hash.len().min(12)])
+            .collect();
+        notes.push(format!(
+            "{}: reports stressed {} different builds (sha256 {})",
+            program.display(),
+            hashes.len(),
+            short.join(", ")
+        ));
+    }
+
     // Rules are intentionally compact; they provide explainable pass/warn/fail decisions.
     load_rules(&mut db);
     let (_, applications) = db.forward_chain();
@@ -194,8 +305,21 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
 
     let priorities = build_priorities(&totals, verdict);
 
+    let mut risk = risk_score(&totals, &rule_hits);
+    if let Some(path) = &config.previous {
+        match previous_score(path) {
+            Ok(Some(previous)) => {
+                risk.previous = Some(previous);
+                risk.delta = Some(round1(risk.score - previous));
+            }
+            Ok(None) => notes.push(format!("{}: no risk score to compare", path.display())),
+            Err(err) => notes.push(format!("{}: {}", path.display(), err)),
+        }
+    }
+
     Ok(AdjudicateReport {
         created_at: chrono::Utc::now().to_rfc3339(),
+        seed: config.seed,
         reports: config.reports,
         processed_reports: processed,
         failed_reports: failed,
@@ -204,42 +328,130 @@ pub fn run(config: AdjudicateConfig) -> Result<AdjudicateReport> {
         rule_hits,
         priorities,
         notes,
+        risk: Some(risk),
     })
 }
 
+/// Combine the weighted totals and fired rules with a noisy-OR: the score is the chance
+/// that at least one signal is a real problem, so adding evidence always raises it and no
+/// amount of it passes 100.
+pub fn risk_score(totals: &AdjudicateTotals, rule_hits: &[RuleHit]) -> RiskScore {
+    let mut components: Vec<ScoreComponent> = SIGNAL_WEIGHTS
+        .iter()
+        .map(|(signal, weight)| {
+            let count = total(totals, signal);
+            ScoreComponent {
+                signal: signal.to_string(),
+                count,
+                risk: weight * (1.0 - 0.5f64.powi(count.min(64) as i32)),
+            }
+        })
+        .chain(rule_hits.iter().filter(|hit| hit.derived > 0).map(|hit| {
+            ScoreComponent {
+                signal: format!("rule:{}", hit.rule),
+                count: hit.derived,
+                risk: (RULE_WEIGHT * hit.confidence * hit.priority.min(100) as f64 / 100.0)
+                    .clamp(0.0, 1.0),
+            }
+        }))
+        .filter(|component| component.risk > 0.0)
+        .collect();
+    components.sort_by(|a, b| b.risk.total_cmp(&a.risk).then(a.signal.cmp(&b.signal)));
+
+    let clear = components
+        .iter()
+        .fold(1.0, |clear, component| clear * (1.0 - component.risk));
+    for component in &mut components {
+        component.risk = (component.risk * 1000.0).round() / 1000.0;
+    }
+    RiskScore {
+        score: round1(100.0 * (1.0 - clear)),
+        components,
+        previous: None,
+        delta: None,
+    }
+}
+
+fn total(totals: &AdjudicateTotals, signal: &str) -> usize {
+    match signal {
+        "total_crashes" => totals.total_crashes,
+        "critical_weak_points" => totals.critical_weak_points,
+        "abduct_timeouts" => totals.abduct_timeouts,
+        "failed_attacks" => totals.failed_attacks,
+        "stalled_attacks" => totals.stalled_attacks,
+        "mutation_exec_failures" => totals.mutation_exec_failures,
+        "abduct_exec_failures" => totals.abduct_exec_failures,
+        "mutation_apply_errors" => totals.mutation_apply_errors,
+        "mixed_build_targets" => totals.mixed_build_targets,
+        _ => 0,
+    }
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn previous_score(path: &Path) -> Result<Option<f64>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("reading previous adjudicate report {}", path.display()))?;
+    let previous: AdjudicateReport =
+        serde_json::from_str(&content).context("parsing previous adjudicate report")?;
+    Ok(previous.risk.map(|risk| risk.score))
+}
+
 pub fn write_report(report: &AdjudicateReport, path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .with_context(|| format!("creating report parent directory {}", parent.display()))?;
     }
     let json = serde_json::to_string_pretty(report).context("serializing adjudicate report")?;
-    fs::write(path, json).with_context(|| format!("writing report {}", path.display()))?;
+    write_atomic(path, json).with_context(|| format!("writing report {}", path.display()))?;
     Ok(())
 }
 
 enum ParsedReport {
-    Assault(crate::types::AssaultReport),
-    Amuck(AmuckReport),
-    Abduct(AbductReport),
+    Assault(Box<crate::types::AssaultReport>),
+    Amuck(Box<AmuckReport>),
+    Abduct(Box<AbductReport>),
+    Axial(Box<AxialReport>),
 }
 
 fn parse_input_report(path: &Path) -> Result<ParsedReport> {
     // Try in order of most structured schema to least constrained JSON payloads.
     if let Ok(assault) = report::load_report(path) {
-        return Ok(ParsedReport::Assault(assault));
+        return Ok(ParsedReport::Assault(Box::new(assault)));
     }
 
     let content =
         fs::read_to_string(path).with_context(|| format!("reading report {}", path.display()))?;
     if let Ok(amuck) = serde_json::from_str::<AmuckReport>(&content) {
-        return Ok(ParsedReport::Amuck(amuck));
+        return Ok(ParsedReport::Amuck(Box::new(amuck)));
     }
     if let Ok(abduct) = serde_json::from_str::<AbductReport>(&content) {
-        return Ok(ParsedReport::Abduct(abduct));
+        return Ok(ParsedReport::Abduct(Box::new(abduct)));
+    }
+    if let Ok(axial) = serde_json::from_str::<AxialReport>(&content) {
+        return Ok(ParsedReport::Axial(Box::new(axial)));
     }
     Err(anyhow!("unsupported report format"))
 }
 
+/// Every signal an axial report raised: per run, per observed report, from the HTTP probe
+/// and from comparing repeated runs.
+fn axial_signals(axial: &AxialReport) -> impl Iterator<Item = &crate::axial::Signal> {
+    let runs = axial.run_observations.iter().flat_map(|run| &run.signals);
+    let reports = axial
+        .report_observations
+        .iter()
+        .flat_map(|report| &report.signals);
+    let probe = axial.probe.iter().flat_map(|probe| &probe.signals);
+    let variance = axial
+        .run_variance
+        .iter()
+        .flat_map(|variance| &variance.signals);
+    runs.chain(reports).chain(probe).chain(variance)
+}
+
 fn load_rules(db: &mut FactDB) {
     // campaign_fail(global) :- high_signal(R)
     db.add_rule(LogicRule::with_metadata(
@@ -297,6 +509,24 @@ fn build_priorities(totals: &AdjudicateTotals, verdict: &str) -> Vec<PriorityFin
             ),
         });
     }
+    if totals.stalled_attacks > 0 {
+        items.push(PriorityFinding {
+            level: "medium".to_string(),
+            message: format!(
+                "{} attacks stalled without progress",
+                totals.stalled_attacks
+            ),
+        });
+    }
+    if totals.mixed_build_targets > 0 {
+        items.push(PriorityFinding {
+            level: "medium".to_string(),
+            message: format!(
+                "{} targets were tested as different builds across reports",
+                totals.mixed_build_targets
+            ),
+        });
+    }
     if totals.mutation_apply_errors > 0 || totals.mutation_exec_failures > 0 {
         items.push(PriorityFinding {
             level: "medium".to_string(),
@@ -340,14 +570,17 @@ mod tests {
             source_spec: None,
             preset: "dangerous".to_string(),
             max_combinations: 1,
+            seed: 0,
             output_dir: PathBuf::from("runtime/amuck"),
             combinations_planned: 1,
             combinations_run: 1,
+            baseline: None,
             outcomes: vec![AmuckOutcome {
                 id: 1,
                 name: "test".to_string(),
                 operations: vec!["append_text".to_string()],
                 applied_changes: 1,
+                weak_point: None,
                 mutated_file: Some(PathBuf::from("runtime/amuck/main.amuck.001.rs")),
                 apply_error: None,
                 execution: Some(crate::amuck::ExecutionOutcome {
@@ -357,7 +590,12 @@ mod tests {
                     stdout: String::new(),
                     stderr: "panic".to_string(),
                     spawn_error: None,
+                    artifacts: Vec::new(),
+                    identity: None,
+                    syscall_summary: None,
                 }),
+                comparison: None,
+                cleaned: false,
             }],
         };
         fs::write(
@@ -368,10 +606,114 @@ mod tests {
 
         let out = run(AdjudicateConfig {
             reports: vec![report_path],
+            previous: None,
+            seed: 0,
         })
         .expect("adjudicate should run");
         assert_eq!(out.processed_reports, 1);
         assert_eq!(out.totals.amuck_reports, 1);
         assert_eq!(out.verdict, "warn");
+        let risk = out.risk.as_ref().expect("score is always computed");
+        assert_eq!(risk.score, 31.6);
+        assert_eq!(
+            risk.components[0].signal,
+            "rule:campaign_warn_on_medium_signal"
+        );
+
+        let previous_path = dir.path().join("adjudicate-previous.json");
+        let mut previous = out.clone();
+        previous.risk.as_mut().unwrap().score = 40.0;
+        write_report(&previous, &previous_path).expect("report should write");
+        let again = run(AdjudicateConfig {
+            reports: out.reports.clone(),
+            previous: Some(previous_path),
+            seed: 0,
+        })
+        .expect("adjudicate should run");
+        let risk = again.risk.unwrap();
+        assert_eq!(risk.previous, Some(40.0));
+        assert_eq!(risk.delta, Some(-8.4));
+    }
+
+    #[test]
+    fn adjudicate_ingests_audience_reports() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let report_path = dir.path().join("audience.json");
+        let axial = AxialReport {
+            created_at: chrono::Utc::now().to_rfc3339(),
+            seed: 0,
+            target: PathBuf::from("src/main.rs"),
+            executed_program: None,
+            repeat: 1,
+            observed_runs: 0,
+            observed_reports: 1,
+            language: "en".to_string(),
+            run_observations: Vec::new(),
+            report_observations: vec![crate::axial::ReportObservation {
+                path: PathBuf::from("reports/abduct.json"),
+                kind: "abduct".to_string(),
+                excerpt_head: Vec::new(),
+                excerpt_tail: Vec::new(),
+                matches: Vec::new(),
+                signals: vec![crate::axial::Signal {
+                    severity: "high".to_string(),
+                    name: "network_contact_signal".to_string(),
+                    evidence: "1 abduct exec runs contacted the network".to_string(),
+                }],
+                spellcheck: None,
+            }],
+            signal_counts: BTreeMap::from([("network_contact_signal".to_string(), 1)]),
+            recommendations: Vec::new(),
+            aspell: None,
+            probe: None,
+            run_variance: None,
+        };
+        fs::write(
+            &report_path,
+            serde_json::to_string_pretty(&axial).expect("serialize should work"),
+        )
+        .expect("report should write");
+
+        let out = run(AdjudicateConfig {
+            reports: vec![report_path],
+            previous: None,
+            seed: 0,
+        })
+        .expect("adjudicate should run");
+        assert_eq!(out.processed_reports, 1);
+        assert_eq!(out.totals.axial_reports, 1);
+        assert_eq!(out.verdict, "fail");
+    }
+
+    #[test]
+    fn risk_score_grades_and_saturates() {
+        let empty = risk_score(&AdjudicateTotals::default(), &[]);
+        assert_eq!(empty.score, 0.0);
+        assert!(empty.components.is_empty());
+
+        let crashes = |n| AdjudicateTotals {
+            total_crashes: n,
+            ..AdjudicateTotals::default()
+        };
+        let one = risk_score(&crashes(1), &[]).score;
+        let four = risk_score(&crashes(4), &[]).score;
+        let many = risk_score(&crashes(10_000), &[]).score;
+        assert_eq!(one, 30.0);
+        assert!(one < four && four < many && many <= 60.0);
+
+        let fail = RuleHit {
+            rule: "campaign_fail_on_high_signal".to_string(),
+            derived: 1,
+            confidence: 0.95,
+            priority: 100,
+        };
+        let with_rule = risk_score(&crashes(1), std::slice::from_ref(&fail));
+        // 1 - (1 - 0.3) * (1 - 0.475)
+        assert_eq!(with_rule.score, 63.3);
+        let mut everything = crashes(100);
+        everything.critical_weak_points = 100;
+        everything.abduct_timeouts = 100;
+        everything.failed_attacks = 100;
+        assert!(risk_score(&everything, &[fail]).score < 100.0);
     }
 }
@@ -0,0 +1,364 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Executive summary of an adjudicate report (`adjudicate --summary-output`).
+//!
+//! A short page for release-readiness documents: verdict, risk score with a trend arrow
+//! against the previous campaign, the top priorities and links to the reports that were
+//! adjudicated. Markdown or HTML, chosen by the output file's extension; headings come from
+//! the i18n catalog.
+
+use super::{AdjudicateReport, PriorityFinding};
+use crate::i18n::{t, Lang};
+use crate::storage::write_atomic;
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Priorities shown; the full list stays in the JSON report.
+const TOP_PRIORITIES: usize = 5;
+
+/// Score moves smaller than this read as flat.
+const FLAT_TREND: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Markdown,
+    Html,
+}
+
+impl SummaryFormat {
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("md") | Some("markdown") => Ok(Self::Markdown),
+            Some("html") | Some("htm") => Ok(Self::Html),
+            _ => Err(anyhow!(
+                "summary output {} must end in .md or .html",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// Write the summary to `path`, linking the underlying reports relative to it.
+pub fn write_summary(report: &AdjudicateReport, path: &Path, lang: Lang) -> Result<()> {
+    let format = SummaryFormat::from_path(path)?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    if !dir.as_os_str().is_empty() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("creating summary parent directory {}", dir.display()))?;
+    }
+    write_atomic(path, render(report, lang, format, dir))
+        .with_context(|| format!("writing summary {}", path.display()))
+}
+
+/// Render the summary; report links are made relative to `link_base`.
+pub fn render(
+    report: &AdjudicateReport,
+    lang: Lang,
+    format: SummaryFormat,
+    link_base: &Path,
+) -> String {
+    let facts = vec![
+        (t(lang, "adjudicate.verdict"), report.verdict.to_uppercase()),
+        (
+            t(lang, "adjudicate.risk_score"),
+            report
+                .risk
+                .as_ref()
+                .map(|risk| format!("{:.1}/100", risk.score))
+                .unwrap_or_else(|| t(lang, "common.unknown").to_string()),
+        ),
+        (t(lang, "adjudicate.trend"), trend(report, lang)),
+        (
+            t(lang, "adjudicate.campaigns"),
+            report.processed_reports.to_string(),
+        ),
+    ];
+    let priorities = top_priorities(&report.priorities);
+    let links: Vec<(String, String)> = report
+        .reports
+        .iter()
+        .map(|path| {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string());
+            (name, link(link_base, path))
+        })
+        .collect();
+
+    match format {
+        SummaryFormat::Markdown => markdown(report, lang, &facts, &priorities, &links),
+        SummaryFormat::Html => html(report, lang, &facts, &priorities, &links),
+    }
+}
+
+fn markdown(
+    report: &AdjudicateReport,
+    lang: Lang,
+    facts: &[(&str, String)],
+    priorities: &[&PriorityFinding],
+    links: &[(String, String)],
+) -> String {
+    let mut lines = vec![
+        format!(
+            "# {}: {}",
+            t(lang, "adjudicate.summary"),
+            t(lang, "adjudicate.title")
+        ),
+        String::new(),
+        format!("_{}_", report.created_at),
+        String::new(),
+    ];
+    lines.extend(
+        facts
+            .iter()
+            .map(|(label, value)| format!("- **{}:** {}", label, value)),
+    );
+    lines.push(String::new());
+    lines.push(format!("## {}", t(lang, "adjudicate.priorities")));
+    lines.extend(
+        priorities
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| format!("{}. **{}**: {}", idx + 1, item.level, item.message)),
+    );
+    lines.push(String::new());
+    lines.push(format!("## {}", t(lang, "adjudicate.reports")));
+    lines.extend(
+        links
+            .iter()
+            .map(|(name, href)| format!("- [{}]({})", name, href.replace(' ', "%20"))),
+    );
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn html(
+    report: &AdjudicateReport,
+    lang: Lang,
+    facts: &[(&str, String)],
+    priorities: &[&PriorityFinding],
+    links: &[(String, String)],
+) -> String {
+    let title = format!(
+        "{}: {}",
+        t(lang, "adjudicate.summary"),
+        t(lang, "adjudicate.title")
+    );
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_string(),
+        format!("<html lang=\"{}\">", lang.code()),
+        "<head>".to_string(),
+        "<meta charset=\"utf-8\">".to_string(),
+        format!("<title>{}</title>", escape(&title)),
+        "</head>".to_string(),
+        "<body>".to_string(),
+        format!("<h1>{}</h1>", escape(&title)),
+        format!("<p><em>{}</em></p>", escape(&report.created_at)),
+        "<ul>".to_string(),
+    ];
+    lines.extend(facts.iter().map(|(label, value)| {
+        format!(
+            "<li><strong>{}:</strong> {}</li>",
+            escape(label),
+            escape(value)
+        )
+    }));
+    lines.push("</ul>".to_string());
+    lines.push(format!(
+        "<h2>{}</h2>",
+        escape(t(lang, "adjudicate.priorities"))
+    ));
+    lines.push("<ol>".to_string());
+    lines.extend(priorities.iter().map(|item| {
+        format!(
+            "<li><strong>{}</strong>: {}</li>",
+            escape(&item.level),
+            escape(&item.message)
+        )
+    }));
+    lines.push("</ol>".to_string());
+    lines.push(format!(
+        "<h2>{}</h2>",
+        escape(t(lang, "adjudicate.reports"))
+    ));
+    lines.push("<ul>".to_string());
+    lines.extend(links.iter().map(|(name, href)| {
+        format!(
+            "<li><a href=\"{}\">{}</a></li>",
+            escape(&href.replace(' ', "%20")),
+            escape(name)
+        )
+    }));
+    lines.push("</ul>".to_string());
+    lines.push("</body>".to_string());
+    lines.push("</html>".to_string());
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// `↑ +4.2 since previous campaign (27.4)`; a rising score is a worsening campaign.
+fn trend(report: &AdjudicateReport, lang: Lang) -> String {
+    let Some((previous, delta)) = report
+        .risk
+        .as_ref()
+        .and_then(|risk| Some((risk.previous?, risk.delta?)))
+    else {
+        return format!("→ {}", t(lang, "adjudicate.no_previous"));
+    };
+    let arrow = if delta >= FLAT_TREND {
+        "↑"
+    } else if delta <= -FLAT_TREND {
+        "↓"
+    } else {
+        "→"
+    };
+    format!(
+        "{} {:+.1} {} ({:.1})",
+        arrow,
+        delta,
+        t(lang, "adjudicate.since_previous"),
+        previous
+    )
+}
+
+/// Highest level first, keeping report order within a level.
+fn top_priorities(priorities: &[PriorityFinding]) -> Vec<&PriorityFinding> {
+    let rank = |level: &str| match level {
+        "high" => 0,
+        "medium" => 1,
+        "low" => 2,
+        _ => 3,
+    };
+    let mut items: Vec<&PriorityFinding> = priorities.iter().collect();
+    items.sort_by_key(|item| rank(&item.level));
+    items.truncate(TOP_PRIORITIES);
+    items
+}
+
+/// Path to `target` from `base`, both relative to the working directory unless absolute.
+fn link(base: &Path, target: &Path) -> String {
+    if target.is_absolute() || base.is_absolute() {
+        let absolute = std::env::current_dir()
+            .map(|cwd| cwd.join(target))
+            .unwrap_or_else(|_| target.to_path_buf());
+        return slashes(&absolute);
+    }
+    let base = plain_components(base);
+    let target = plain_components(target);
+    let common = base.iter().zip(&target).take_while(|(a, b)| a == b).count();
+    if base[common..]
+        .iter()
+        .any(|c| matches!(c, Component::ParentDir))
+    {
+        return slashes(&target.iter().collect::<PathBuf>());
+    }
+    let mut path = PathBuf::new();
+    for _ in common..base.len() {
+        path.push("..");
+    }
+    path.extend(&target[common..]);
+    slashes(&path)
+}
+
+fn plain_components(path: &Path) -> Vec<Component<'_>> {
+    path.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .collect()
+}
+
+fn slashes(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adjudicate::{AdjudicateTotals, RiskScore};
+
+    fn report() -> AdjudicateReport {
+        let finding = |level: &str, message: &str| PriorityFinding {
+            level: level.to_string(),
+            message: message.to_string(),
+        };
+        AdjudicateReport {
+            created_at: "2026-03-02T10:00:00+00:00".to_string(),
+            seed: 0,
+            reports: vec![
+                PathBuf::from("reports/assault-a.json"),
+                PathBuf::from("runs/n1/amuck b.json"),
+            ],
+            processed_reports: 2,
+            failed_reports: 0,
+            verdict: "fail".to_string(),
+            totals: AdjudicateTotals::default(),
+            rule_hits: Vec::new(),
+            priorities: vec![
+                finding("medium", "m1"),
+                finding("high", "3 crashes <in> assault"),
+                finding("medium", "m2"),
+                finding("medium", "m3"),
+                finding("high", "h2"),
+                finding("medium", "m4"),
+            ],
+            notes: Vec::new(),
+            risk: Some(RiskScore {
+                score: 63.3,
+                components: Vec::new(),
+                previous: Some(51.0),
+                delta: Some(12.3),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_markdown_summary() {
+        let text = render(
+            &report(),
+            Lang::En,
+            SummaryFormat::Markdown,
+            Path::new("docs/release"),
+        );
+        assert!(text.starts_with("# Executive Summary: Adjudicate Verdict\n"));
+        assert!(text.contains("- **Overall Verdict:** FAIL"));
+        assert!(text.contains("- **Risk Score:** 63.3/100"));
+        assert!(text.contains("- **Trend:** ↑ +12.3 since previous campaign (51.0)"));
+        assert!(text
+            .contains("1. **high**: 3 crashes <in> assault\n2. **high**: h2\n3. **medium**: m1"));
+        assert!(!text.contains("m4"), "only the top five priorities");
+        assert!(text.contains("- [assault-a.json](../../reports/assault-a.json)"));
+        assert!(text.contains("- [amuck b.json](../../runs/n1/amuck%20b.json)"));
+    }
+
+    #[test]
+    fn test_html_summary_is_translated_and_escaped() {
+        let mut report = report();
+        report.risk.as_mut().unwrap().previous = None;
+        report.risk.as_mut().unwrap().delta = None;
+        let text = render(&report, Lang::Es, SummaryFormat::Html, Path::new(""));
+        assert!(text.contains("<html lang=\"es\">"));
+        assert!(text.contains("<h2>Prioridades Principales</h2>"));
+        assert!(text.contains("<strong>Tendencia:</strong> → sin campaña anterior"));
+        assert!(text.contains("3 crashes &lt;in&gt; assault"));
+        assert!(text.contains("<a href=\"reports/assault-a.json\">assault-a.json</a>"));
+
+        assert_eq!(
+            SummaryFormat::from_path(Path::new("out/SUMMARY.HTML")).unwrap(),
+            SummaryFormat::Html
+        );
+        assert!(SummaryFormat::from_path(Path::new("summary.pdf")).is_err());
+    }
+}
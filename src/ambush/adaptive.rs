@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Closed-loop intensity: hold a metric at a setpoint instead of applying a fixed level.
+//!
+//! The configured intensity becomes the ceiling. Stressors read a shared [`LoadLevel`] (0-1)
+//! and duty-cycle their work against it; memory allocation grows or shrinks toward the same
+//! fraction of its target. A controller thread samples the metric every interval and moves the
+//! level with a velocity-form PI step, so the trajectory shows how much stress the setpoint
+//! actually needed. The controller assumes more stress raises the metric; a metric that does
+//! not respond simply pins the level at a bound, which the trajectory makes visible.
+
+use super::conditions::ProcessSampler;
+use crate::types::{AdaptiveConfig, AdaptiveMetric, AdaptiveReport, AdaptiveStep};
+use std::fs;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const DEFAULT_INTERVAL_MS: u64 = 500;
+const MIN_INTERVAL_MS: u64 = 50;
+/// Stress never drops fully to zero, so the metric keeps reacting to level changes.
+const MIN_LEVEL: f64 = 0.05;
+const INITIAL_LEVEL: f64 = 0.5;
+const PROPORTIONAL_GAIN: f64 = 0.3;
+const INTEGRAL_GAIN: f64 = 0.15;
+const DUTY_WINDOW: Duration = Duration::from_millis(20);
+const TRAJECTORY_SAMPLE_LIMIT: usize = 512;
+
+/// Fraction of the configured intensity that stressors should currently apply.
+#[derive(Debug)]
+pub struct LoadLevel {
+    bits: AtomicU64,
+    origin: Instant,
+}
+
+impl LoadLevel {
+    pub fn new(level: f64) -> Self {
+        Self {
+            bits: AtomicU64::new(level.clamp(0.0, 1.0).to_bits()),
+            origin: Instant::now(),
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, level: f64) {
+        self.bits
+            .store(level.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Sleep through the idle part of the current duty window; returns at once at full level.
+    pub fn pace(&self) {
+        let level = self.get();
+        if level >= 1.0 {
+            return;
+        }
+        let window = DUTY_WINDOW.as_nanos();
+        let position = self.origin.elapsed().as_nanos() % window;
+        let busy = (window as f64 * level) as u128;
+        if position >= busy {
+            thread::sleep(Duration::from_nanos((window - position) as u64));
+        }
+    }
+}
+
+/// One velocity-form PI step. Errors are normalised by the setpoint (positive = below it).
+pub fn next_level(level: f64, error: f64, previous_error: f64) -> f64 {
+    let step = PROPORTIONAL_GAIN * (error - previous_error) + INTEGRAL_GAIN * error;
+    (level + step).clamp(MIN_LEVEL, 1.0)
+}
+
+/// Run the controller until `deadline` or `stop`, adjusting `level` and recording each step.
+pub fn spawn_controller(
+    pid: u32,
+    config: AdaptiveConfig,
+    level: Arc<LoadLevel>,
+    deadline: Instant,
+    stop: Arc<AtomicBool>,
+) -> JoinHandle<AdaptiveReport> {
+    thread::spawn(move || {
+        let interval_ms = config
+            .interval_ms
+            .unwrap_or(DEFAULT_INTERVAL_MS)
+            .max(MIN_INTERVAL_MS);
+        let mut report = AdaptiveReport {
+            metric: config.metric,
+            setpoint: config.setpoint,
+            interval_ms,
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let mut sampler = MetricSampler::new(config.metric, pid, start);
+        let mut previous_error = 0.0;
+        let mut unreadable = 0_usize;
+
+        level.set(INITIAL_LEVEL);
+        while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+            sleep_interruptible(Duration::from_millis(interval_ms), deadline, &stop);
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+            let measured = sampler.sample();
+            let current = level.get();
+            let next = match measured {
+                Some(value) => {
+                    let error = (config.setpoint - value) / config.setpoint;
+                    let next = next_level(current, error, previous_error);
+                    previous_error = error;
+                    next
+                }
+                None => {
+                    unreadable += 1;
+                    current
+                }
+            };
+            level.set(next);
+            if report.trajectory.len() < TRAJECTORY_SAMPLE_LIMIT {
+                report.trajectory.push(AdaptiveStep {
+                    offset: start.elapsed(),
+                    measured,
+                    level: next,
+                });
+            }
+        }
+
+        summarize(&mut report);
+        if unreadable > 0 {
+            report.note = Some(format!(
+                "{} of {} samples could not read {}",
+                unreadable,
+                report.trajectory.len(),
+                config.metric.label()
+            ));
+        }
+        report
+    })
+}
+
+/// Mean level over the run, and mean absolute error over its second half once settled.
+fn summarize(report: &mut AdaptiveReport) {
+    let steps = &report.trajectory;
+    if steps.is_empty() {
+        return;
+    }
+    report.mean_level = steps.iter().map(|step| step.level).sum::<f64>() / steps.len() as f64;
+    let errors: Vec<f64> = steps[steps.len() / 2..]
+        .iter()
+        .filter_map(|step| step.measured)
+        .map(|value| (value - report.setpoint).abs())
+        .collect();
+    if !errors.is_empty() {
+        report.settled_error = Some(errors.iter().sum::<f64>() / errors.len() as f64);
+    }
+}
+
+fn sleep_interruptible(interval: Duration, deadline: Instant, stop: &AtomicBool) {
+    let wake = (Instant::now() + interval).min(deadline);
+    while !stop.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        if now >= wake {
+            break;
+        }
+        thread::sleep((wake - now).min(Duration::from_millis(10)));
+    }
+}
+
+/// Reads the controlled metric: host metrics from `/proc/stat` and `/proc/meminfo`, target
+/// metrics through the timeline sampler.
+struct MetricSampler {
+    metric: AdaptiveMetric,
+    target: ProcessSampler,
+    last_host_cpu: Option<(u64, u64)>,
+}
+
+impl MetricSampler {
+    fn new(metric: AdaptiveMetric, pid: u32, started: Instant) -> Self {
+        let mut sampler = Self {
+            metric,
+            target: ProcessSampler::new(pid, started),
+            last_host_cpu: None,
+        };
+        // Prime the CPU deltas so the first real sample already has a baseline.
+        sampler.sample();
+        sampler
+    }
+
+    fn sample(&mut self) -> Option<f64> {
+        match self.metric {
+            AdaptiveMetric::HostCpu => {
+                let stat = fs::read_to_string("/proc/stat").ok()?;
+                let (busy, total) = parse_host_cpu(&stat)?;
+                let (last_busy, last_total) = self.last_host_cpu.replace((busy, total))?;
+                let elapsed = total.checked_sub(last_total).filter(|delta| *delta > 0)?;
+                Some(busy.saturating_sub(last_busy) as f64 / elapsed as f64 * 100.0)
+            }
+            AdaptiveMetric::HostMemory => {
+                parse_memory_used_percent(&fs::read_to_string("/proc/meminfo").ok()?)
+            }
+            AdaptiveMetric::TargetCpu => self.target.sample().cpu_percent,
+            AdaptiveMetric::TargetRss => self
+                .target
+                .sample()
+                .rss
+                .map(|bytes| bytes as f64 / (1024.0 * 1024.0)),
+        }
+    }
+}
+
+/// Busy and total jiffies from the aggregate `cpu` line (idle + iowait count as idle).
+fn parse_host_cpu(stat: &str) -> Option<(u64, u64)> {
+    let line = stat.lines().find(|line| line.starts_with("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|value| value.parse().ok())
+        .collect();
+    if values.len() < 4 {
+        return None;
+    }
+    let total: u64 = values.iter().take(8).sum();
+    let idle = values[3] + values.get(4).copied().unwrap_or(0);
+    Some((total.saturating_sub(idle), total))
+}
+
+fn parse_memory_used_percent(meminfo: &str) -> Option<f64> {
+    let field = |key: &str| -> Option<f64> {
+        meminfo
+            .lines()
+            .find(|line| line.starts_with(key))?
+            .split_whitespace()
+            .nth(1)?
+            .parse()
+            .ok()
+    };
+    let total = field("MemTotal:")?;
+    let available = field("MemAvailable:")?;
+    (total > 0.0).then(|| (total - available) / total * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controller_converges_on_linear_plant() {
+        // Metric responds proportionally to stress: 100% level gives 100 units.
+        let setpoint = 60.0;
+        let mut level = INITIAL_LEVEL;
+        let mut previous_error = 0.0;
+        for _ in 0..60 {
+            let measured = 100.0 * level;
+            let error = (setpoint - measured) / setpoint;
+            level = next_level(level, error, previous_error);
+            previous_error = error;
+        }
+        assert!((level - 0.6).abs() < 0.02, "level {}", level);
+    }
+
+    #[test]
+    fn test_level_is_clamped() {
+        assert_eq!(next_level(1.0, 5.0, 0.0), 1.0);
+        assert_eq!(next_level(MIN_LEVEL, -5.0, 0.0), MIN_LEVEL);
+    }
+
+    #[test]
+    fn test_parse_host_metrics() {
+        let stat = "cpu  100 0 100 700 100 0 0 0 0 0\ncpu0 50 0 50 350 50 0 0 0 0 0\n";
+        assert_eq!(parse_host_cpu(stat), Some((200, 1000)));
+        let meminfo = "MemTotal: 1000 kB\nMemFree: 100 kB\nMemAvailable: 250 kB\n";
+        assert_eq!(parse_memory_used_percent(meminfo), Some(75.0));
+    }
+
+    #[test]
+    fn test_summary_uses_second_half_for_error() {
+        let mut report = AdaptiveReport {
+            setpoint: 50.0,
+            ..Default::default()
+        };
+        for (measured, level) in [(10.0, 0.2), (30.0, 0.4), (48.0, 0.5), (52.0, 0.5)] {
+            report.trajectory.push(AdaptiveStep {
+                offset: Duration::ZERO,
+                measured: Some(measured),
+                level,
+            });
+        }
+        summarize(&mut report);
+        assert!((report.mean_level - 0.4).abs() < 1e-9);
+        assert_eq!(report.settled_error, Some(2.0));
+    }
+}
@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! CPU topology chaos: migrate the target across cores and toggle its nice value.
+//!
+//! Each interval applies the next step of a rotation (each allowed core alone, then all cores)
+//! to every thread of the target, and every third interval flips the nice value between 0 and
+//! `max_nice`. Lowering nice again needs CAP_SYS_NICE, so denied renices are counted rather than
+//! treated as errors. The scheduler's run-queue delay for the target
+//! (`/proc/<pid>/schedstat`) is sampled per interval to show the latency impact. The core
+//! order and the interval lengths come from the campaign seed, so a run can be replayed.
+
+use crate::seed::SeededRng;
+use crate::types::{CpuChaosConfig, CpuChaosInterval, CpuChaosReport};
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const DEFAULT_INTERVAL_MS: u64 = 250;
+const DEFAULT_MAX_NICE: i32 = 10;
+const INTERVAL_SAMPLE_LIMIT: usize = 512;
+const RENICE_EVERY: usize = 3;
+const INTERVAL_JITTER: f64 = 0.2;
+
+/// CPU set for interval `tick`: one allowed core at a time, widening to all once per cycle.
+pub fn cpus_for_tick(tick: usize, allowed: &[usize]) -> Vec<usize> {
+    if allowed.is_empty() {
+        return vec![0];
+    }
+    let step = tick % (allowed.len() + 1);
+    if step == allowed.len() {
+        let mut all = allowed.to_vec();
+        all.sort_unstable();
+        all
+    } else {
+        vec![allowed[step]]
+    }
+}
+
+/// Nice value for interval `tick`, or `None` when this interval leaves priority alone.
+pub fn nice_for_tick(tick: usize, max_nice: i32) -> Option<i32> {
+    if tick % RENICE_EVERY != 0 {
+        return None;
+    }
+    if (tick / RENICE_EVERY) % 2 == 0 {
+        Some(max_nice)
+    } else {
+        Some(0)
+    }
+}
+
+pub fn spawn_cpu_chaos(
+    pid: u32,
+    config: CpuChaosConfig,
+    deadline: Instant,
+    stop: Arc<AtomicBool>,
+    seed: u64,
+) -> JoinHandle<CpuChaosReport> {
+    thread::spawn(move || {
+        let interval_ms = config.interval_ms.unwrap_or(DEFAULT_INTERVAL_MS).max(10);
+        let max_nice = config.max_nice.unwrap_or(DEFAULT_MAX_NICE).clamp(0, 19);
+        let mut report = CpuChaosReport {
+            interval_ms,
+            max_nice,
+            ..Default::default()
+        };
+        if !cfg!(target_os = "linux") {
+            report.note = Some("cpu chaos requires Linux".to_string());
+            return report;
+        }
+
+        let allowed = allowed_cpus();
+        let mut rng = SeededRng::derive(seed, "cpu-chaos");
+        let mut rotation = allowed.clone();
+        rng.shuffle(&mut rotation);
+        let start = Instant::now();
+        let mut last_delay = run_delay_ns(pid);
+        let mut tick = 0_usize;
+
+        while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+            let cpus = cpus_for_tick(tick, &rotation);
+            let applied = set_affinity_all_threads(pid, &cpus);
+            if applied {
+                report.migrations += 1;
+            }
+            let nice = nice_for_tick(tick, max_nice);
+            let mut reniced = None;
+            if let Some(value) = nice {
+                report.renice_attempts += 1;
+                let ok = set_nice(pid, value);
+                if !ok {
+                    report.renice_denied += 1;
+                }
+                reniced = Some(ok);
+            }
+
+            let pause = rng.jitter(Duration::from_millis(interval_ms), INTERVAL_JITTER);
+            let wake = (Instant::now() + pause).min(deadline);
+            while !stop.load(Ordering::Relaxed) && Instant::now() < wake {
+                thread::sleep(Duration::from_millis(5));
+            }
+
+            let delay = run_delay_ns(pid);
+            let run_delay_us = match (last_delay, delay) {
+                (Some(before), Some(after)) => Some(after.saturating_sub(before) / 1000),
+                _ => None,
+            };
+            last_delay = delay;
+            if delay.is_none() && !process_alive(pid) {
+                report.note = Some("target exited during cpu chaos".to_string());
+                break;
+            }
+
+            if report.intervals.len() < INTERVAL_SAMPLE_LIMIT {
+                report.intervals.push(CpuChaosInterval {
+                    offset: start.elapsed(),
+                    cpus,
+                    applied,
+                    nice,
+                    reniced,
+                    run_delay_us,
+                });
+            }
+            tick += 1;
+        }
+
+        // Leave the target on every core; nice cannot always be restored unprivileged.
+        set_affinity_all_threads(pid, &allowed);
+        report
+    })
+}
+
+/// Cumulative run-queue wait in nanoseconds (second field of `/proc/<pid>/schedstat`).
+fn run_delay_ns(pid: u32) -> Option<u64> {
+    let content = fs::read_to_string(format!("/proc/{}/schedstat", pid)).ok()?;
+    parse_schedstat_delay(&content)
+}
+
+pub fn parse_schedstat_delay(content: &str) -> Option<u64> {
+    content.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn process_alive(pid: u32) -> bool {
+    fs::metadata(format!("/proc/{}", pid)).is_ok()
+}
+
+fn thread_ids(pid: u32) -> Vec<u32> {
+    let mut tids: Vec<u32> = fs::read_dir(format!("/proc/{}/task", pid))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    if tids.is_empty() {
+        tids.push(pid);
+    }
+    tids
+}
+
+/// CPUs panic-attack itself may run on (the target inherited the same mask).
+#[cfg(target_os = "linux")]
+fn allowed_cpus() -> Vec<usize> {
+    // SAFETY: cpu_set_t is plain data; zeroed is its documented empty state.
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    // SAFETY: pid 0 queries the calling thread; the set pointer and size are valid.
+    let rc =
+        unsafe { libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) };
+    if rc != 0 {
+        return Vec::new();
+    }
+    (0..libc::CPU_SETSIZE as usize)
+        // SAFETY: index is below CPU_SETSIZE.
+        .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn allowed_cpus() -> Vec<usize> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity_all_threads(pid: u32, cpus: &[usize]) -> bool {
+    // SAFETY: cpu_set_t is plain data; zeroed is its documented empty state.
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for cpu in cpus {
+        // SAFETY: CPU_SET bounds-checks against the set size.
+        unsafe { libc::CPU_SET(*cpu, &mut set) };
+    }
+    let mut any = false;
+    for tid in thread_ids(pid) {
+        // SAFETY: the set pointer and size describe a valid cpu_set_t.
+        let rc = unsafe {
+            libc::sched_setaffinity(
+                tid as libc::pid_t,
+                std::mem::size_of::<libc::cpu_set_t>(),
+                &set,
+            )
+        };
+        any |= rc == 0;
+    }
+    any
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_affinity_all_threads(_pid: u32, _cpus: &[usize]) -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn set_nice(pid: u32, nice: i32) -> bool {
+    let mut any = false;
+    for tid in thread_ids(pid) {
+        // SAFETY: setpriority has no memory-safety preconditions. On Linux nice is per-thread.
+        any |= unsafe { libc::setpriority(libc::PRIO_PROCESS, tid as libc::id_t, nice) } == 0;
+    }
+    any
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_nice(_pid: u32, _nice: i32) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpus_rotate_then_widen() {
+        let allowed = [0, 2, 4];
+        assert_eq!(cpus_for_tick(0, &allowed), vec![0]);
+        assert_eq!(cpus_for_tick(2, &allowed), vec![4]);
+        assert_eq!(cpus_for_tick(3, &allowed), vec![0, 2, 4]);
+        assert_eq!(cpus_for_tick(4, &allowed), vec![0]);
+        assert_eq!(cpus_for_tick(1, &[]), vec![0]);
+    }
+
+    #[test]
+    fn test_nice_alternates_every_third_tick() {
+        assert_eq!(nice_for_tick(0, 10), Some(10));
+        assert_eq!(nice_for_tick(1, 10), None);
+        assert_eq!(nice_for_tick(3, 10), Some(0));
+        assert_eq!(nice_for_tick(6, 10), Some(10));
+    }
+
+    #[test]
+    fn test_parse_schedstat_delay() {
+        assert_eq!(parse_schedstat_delay("123456 7890 12\n"), Some(7890));
+        assert_eq!(parse_schedstat_delay(""), None);
+    }
+}
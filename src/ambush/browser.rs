@@ -0,0 +1,686 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! WebDriver interaction storms against a target's web UI
+//!
+//! Web front ends fail where a CLI harness cannot see: an uncaught exception in the page, a
+//! rejected promise, a server that answers with a 502 while it is starved. While an ambush
+//! stresses the server, this drives a headless browser through a WebDriver endpoint
+//! (chromedriver or geckodriver) with a seeded storm of clicks, hostile text input, back and
+//! reload, and records console errors and crash pages. Each distinct failure becomes a
+//! [`CrashReport`] on the ambush result.
+//!
+//! Console errors are collected by a hook injected after every action, so errors thrown while
+//! a page is still loading (before the hook is back in place) can be missed.
+
+use crate::seed::SeededRng;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// W3C key under which element references are returned.
+const ELEMENT_KEY: &str = "element-6066-11e4-a52e-4f735466cecf";
+/// Time the target gets to serve its first clean page before the storm gives up.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+/// WebDriver response bytes read per command.
+const MAX_RESPONSE_BYTES: u64 = 16 * 1024 * 1024;
+/// Failures kept per storm; repeats of the same message are dropped anyway.
+const MAX_ERRORS: usize = 50;
+
+const INTERACTIVE: &str = "a[href], button, input, select, textarea, [onclick], [role=button]";
+const TEXT_INPUTS: &str = "input:not([type=hidden]), textarea, [contenteditable=true]";
+
+/// Collects uncaught errors, unhandled rejections and `console.error` calls; idempotent.
+const HOOK_SCRIPT: &str = r#"
+if (!window.__panicAttack) {
+  window.__panicAttack = [];
+  var push = function (m) { window.__panicAttack.push(String(m)); };
+  window.addEventListener('error', function (e) {
+    push(e.message + (e.error && e.error.stack ? '\n' + e.error.stack : ''));
+  });
+  window.addEventListener('unhandledrejection', function (e) {
+    push('Unhandled rejection: ' + (e.reason && e.reason.stack ? e.reason.stack : e.reason));
+  });
+  var original = console.error;
+  console.error = function () {
+    push(Array.prototype.map.call(arguments, String).join(' '));
+    return original.apply(console, arguments);
+  };
+}
+"#;
+const DRAIN_SCRIPT: &str =
+    "var e = window.__panicAttack || []; window.__panicAttack = []; return e;";
+const TEXT_SCRIPT: &str = "return document.body ? document.body.innerText.slice(0, 2000) : '';";
+
+/// Pages browsers show instead of content when a load or renderer fails.
+const CRASH_URL_PREFIXES: &[&str] = &[
+    "chrome-error://",
+    "about:neterror",
+    "about:tabcrashed",
+    "about:crashcontent",
+];
+/// Titles and body text of browser and server error pages.
+const CRASH_MARKERS: &[&str] = &[
+    "Aw, Snap!",
+    "Internal Server Error",
+    "502 Bad Gateway",
+    "503 Service Unavailable",
+    "504 Gateway Time",
+    "This site can’t be reached",
+    "Unable to connect",
+    "The connection was reset",
+];
+
+/// Text typed into inputs: empty, oversized, markup, injection and non-ASCII values.
+const HOSTILE_INPUTS: &[&str] = &[
+    "",
+    "<script>alert(1)</script>",
+    "' OR '1'='1",
+    "%s%s%s%n",
+    "-1",
+    "99999999999999999999999999",
+    "NaN",
+    "😀😀😀😀😀😀😀😀",
+    "\u{202e}gnp.exe",
+    "../../../../etc/passwd",
+];
+
+/// Background storm; [`BrowserStorm::stop`] ends the session and returns the summary.
+pub struct BrowserStorm {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<BrowserReport>,
+}
+
+/// Open a WebDriver session and start storming `config.url`.
+pub fn start(config: &BrowserConfig, intensity: IntensityLevel, seed: u64) -> BrowserStorm {
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = stop.clone();
+    let config = config.clone();
+    let handle = thread::spawn(move || storm(&config, intensity, seed, &flag));
+    BrowserStorm { stop, handle }
+}
+
+impl BrowserStorm {
+    pub fn stop(self) -> BrowserReport {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_else(|_| BrowserReport {
+            browser: BrowserKind::default(),
+            url: String::new(),
+            actions: 0,
+            failed_actions: 0,
+            errors: Vec::new(),
+            note: Some("browser storm panicked".to_string()),
+        })
+    }
+}
+
+/// One crash report per UI failure the storm recorded.
+pub fn crash_reports(report: &BrowserReport) -> Vec<CrashReport> {
+    report
+        .errors
+        .iter()
+        .map(|error| {
+            let (summary, stack) = match error.message.split_once('\n') {
+                Some((summary, stack)) => (summary, Some(stack.to_string())),
+                None => (error.message.as_str(), None),
+            };
+            let label = match error.kind {
+                BrowserErrorKind::Console => "console error",
+                BrowserErrorKind::CrashPage => "crash page",
+            };
+            CrashReport {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                backtrace: stack,
+                stderr: format!("{}: {}", label, summary),
+                stdout: format!(
+                    "{} at {} ({:.1}s into the browser storm)",
+                    error.page,
+                    report.url,
+                    error.at.as_secs_f64()
+                ),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn storm(
+    config: &BrowserConfig,
+    intensity: IntensityLevel,
+    seed: u64,
+    stop: &AtomicBool,
+) -> BrowserReport {
+    let mut report = BrowserReport {
+        browser: config.browser,
+        url: config.url.clone(),
+        actions: 0,
+        failed_actions: 0,
+        errors: Vec::new(),
+        note: None,
+    };
+    let driver = match WebDriver::connect(&config.webdriver, config.browser) {
+        Ok(driver) => driver,
+        Err(err) => {
+            report.note = Some(format!("{:#}", err));
+            return report;
+        }
+    };
+    if let Err(err) = drive(&driver, config, intensity, seed, stop, &mut report) {
+        report.note = Some(format!("{:#}", err));
+    }
+    driver.close();
+    report
+}
+
+fn drive(
+    driver: &WebDriver,
+    config: &BrowserConfig,
+    intensity: IntensityLevel,
+    seed: u64,
+    stop: &AtomicBool,
+    report: &mut BrowserReport,
+) -> Result<()> {
+    let started = Instant::now();
+    // The target starts alongside the browser; wait for it to serve a clean page first.
+    loop {
+        let loaded = driver.navigate(&config.url).is_ok()
+            && driver.page().is_ok_and(|page| crash_page(&page).is_none());
+        if loaded {
+            break;
+        }
+        if stop.load(Ordering::SeqCst) || started.elapsed() >= STARTUP_TIMEOUT {
+            return Err(anyhow!("{} never loaded cleanly", config.url));
+        }
+        thread::sleep(Duration::from_millis(250));
+    }
+
+    let mut rng = SeededRng::derive(seed, "browser");
+    let pause = Duration::from_secs_f64(0.5 / intensity.multiplier());
+    let origin = origin(&config.url);
+    let mut seen = HashSet::new();
+    let mut record = |report: &mut BrowserReport, kind, message: String, page: &str| {
+        if report.errors.len() < MAX_ERRORS && seen.insert((kind, message.clone())) {
+            report.errors.push(BrowserError {
+                kind,
+                message,
+                page: page.to_string(),
+                at: started.elapsed(),
+            });
+        }
+    };
+
+    let _ = driver.execute(HOOK_SCRIPT);
+    while !stop.load(Ordering::SeqCst) {
+        let acted = match rng.below(10) {
+            0..=5 => click_random(driver, &mut rng),
+            6..=7 => type_random(driver, &mut rng),
+            8 => driver.command("back"),
+            _ => driver.command("refresh"),
+        };
+        report.actions += 1;
+        if acted.is_err() {
+            report.failed_actions += 1;
+        }
+
+        let page = driver.page()?;
+        let _ = driver.execute(HOOK_SCRIPT);
+        if let Ok(Value::Array(messages)) = driver.execute(DRAIN_SCRIPT) {
+            for message in messages.iter().filter_map(Value::as_str) {
+                record(
+                    report,
+                    BrowserErrorKind::Console,
+                    message.to_string(),
+                    &page.url,
+                );
+            }
+        }
+        if let Some(message) = crash_page(&page) {
+            record(report, BrowserErrorKind::CrashPage, message, &page.url);
+            let _ = driver.navigate(&config.url);
+        } else if !page.url.starts_with(origin) {
+            let _ = driver.navigate(&config.url);
+        }
+        thread::sleep(pause);
+    }
+    Ok(())
+}
+
+fn click_random(driver: &WebDriver, rng: &mut SeededRng) -> Result<()> {
+    let elements = driver.elements(INTERACTIVE)?;
+    if elements.is_empty() {
+        return Err(anyhow!("nothing to click"));
+    }
+    let element = &elements[rng.below(elements.len())];
+    driver.call("POST", &format!("element/{}/click", element), json!({}))?;
+    Ok(())
+}
+
+fn type_random(driver: &WebDriver, rng: &mut SeededRng) -> Result<()> {
+    let inputs = driver.elements(TEXT_INPUTS)?;
+    if inputs.is_empty() {
+        return Err(anyhow!("nothing to type into"));
+    }
+    let input = &inputs[rng.below(inputs.len())];
+    let text = if rng.below(4) == 0 {
+        "A".repeat(1 << (8 + rng.below(8)))
+    } else {
+        HOSTILE_INPUTS[rng.below(HOSTILE_INPUTS.len())].to_string()
+    };
+    driver.call(
+        "POST",
+        &format!("element/{}/value", input),
+        json!({ "text": text }),
+    )?;
+    Ok(())
+}
+
+struct Page {
+    url: String,
+    title: String,
+    text: String,
+}
+
+/// Why `page` is an error page rather than the UI, if it is one.
+fn crash_page(page: &Page) -> Option<String> {
+    if let Some(prefix) = CRASH_URL_PREFIXES
+        .iter()
+        .find(|prefix| page.url.starts_with(**prefix))
+    {
+        return Some(format!("browser error page ({})", prefix));
+    }
+    CRASH_MARKERS
+        .iter()
+        .find(|marker| page.title.contains(**marker) || page.text.contains(**marker))
+        .map(|marker| format!("error page: {}", marker))
+}
+
+/// `scheme://host[:port]` of a URL, or the whole URL when it has no path.
+fn origin(url: &str) -> &str {
+    let after_scheme = url.find("://").map_or(0, |index| index + 3);
+    match url[after_scheme..].find('/') {
+        Some(index) => &url[..after_scheme + index],
+        None => url,
+    }
+}
+
+/// Minimal W3C WebDriver client over plain HTTP/1.1.
+struct WebDriver {
+    authority: String,
+    prefix: String,
+    session: String,
+}
+
+impl WebDriver {
+    fn connect(endpoint: &str, browser: BrowserKind) -> Result<Self> {
+        let rest = endpoint
+            .strip_prefix("http://")
+            .ok_or_else(|| anyhow!("WebDriver endpoint must be an http:// URL: {}", endpoint))?;
+        let (authority, prefix) = match rest.find('/') {
+            Some(index) => (&rest[..index], rest[index..].trim_end_matches('/')),
+            None => (rest, ""),
+        };
+        let mut driver = Self {
+            authority: authority.to_string(),
+            prefix: prefix.to_string(),
+            session: String::new(),
+        };
+        let capabilities = match browser {
+            BrowserKind::Chrome => json!({
+                "browserName": "chrome",
+                "goog:chromeOptions": { "args": ["--headless=new", "--no-sandbox"] },
+            }),
+            BrowserKind::Firefox => json!({
+                "browserName": "firefox",
+                "moz:firefoxOptions": { "args": ["-headless"] },
+            }),
+        };
+        let created = driver
+            .request(
+                "POST",
+                "/session",
+                Some(json!({ "capabilities": { "alwaysMatch": capabilities } })),
+            )
+            .with_context(|| format!("starting a WebDriver session at {}", endpoint))?;
+        driver.session = created["sessionId"]
+            .as_str()
+            .ok_or_else(|| anyhow!("WebDriver returned no session id"))?
+            .to_string();
+        Ok(driver)
+    }
+
+    fn close(&self) {
+        let _ = self.request("DELETE", &format!("/session/{}", self.session), None);
+    }
+
+    fn navigate(&self, url: &str) -> Result<()> {
+        self.call("POST", "url", json!({ "url": url }))?;
+        Ok(())
+    }
+
+    /// Body-less session command such as `back` or `refresh`.
+    fn command(&self, name: &str) -> Result<()> {
+        self.call("POST", name, json!({}))?;
+        Ok(())
+    }
+
+    fn execute(&self, script: &str) -> Result<Value> {
+        self.call(
+            "POST",
+            "execute/sync",
+            json!({ "script": script, "args": [] }),
+        )
+    }
+
+    fn elements(&self, css: &str) -> Result<Vec<String>> {
+        let found = self.call(
+            "POST",
+            "elements",
+            json!({ "using": "css selector", "value": css }),
+        )?;
+        Ok(found
+            .as_array()
+            .map(|elements| {
+                elements
+                    .iter()
+                    .filter_map(|element| element[ELEMENT_KEY].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn page(&self) -> Result<Page> {
+        let url = self.request("GET", &self.path("url"), None)?;
+        let title = self.request("GET", &self.path("title"), None)?;
+        Ok(Page {
+            url: url.as_str().unwrap_or_default().to_string(),
+            title: title.as_str().unwrap_or_default().to_string(),
+            text: self
+                .execute(TEXT_SCRIPT)
+                .ok()
+                .and_then(|text| text.as_str().map(str::to_string))
+                .unwrap_or_default(),
+        })
+    }
+
+    fn call(&self, method: &str, command: &str, body: Value) -> Result<Value> {
+        self.request(method, &self.path(command), Some(body))
+    }
+
+    fn path(&self, command: &str) -> String {
+        format!("/session/{}/{}", self.session, command)
+    }
+
+    /// Send one request and return the response's `value`, or its WebDriver error.
+    fn request(&self, method: &str, path: &str, body: Option<Value>) -> Result<Value> {
+        let body = body.map(|body| body.to_string()).unwrap_or_default();
+        let (status, body) = http_request(
+            &self.authority,
+            method,
+            &format!("{}{}", self.prefix, path),
+            &[("Content-Type", "application/json")],
+            &body,
+            MAX_RESPONSE_BYTES,
+        )
+        .with_context(|| format!("talking to WebDriver at {}", self.authority))?;
+
+        let response: Value = serde_json::from_str(&body)
+            .with_context(|| format!("WebDriver sent non-JSON for {} {}", method, path))?;
+        let value = response["value"].clone();
+        if let Some(error) = value["error"].as_str() {
+            return Err(anyhow!(
+                "WebDriver {} ({}): {}",
+                error,
+                status,
+                value["message"].as_str().unwrap_or_default()
+            ));
+        }
+        Ok(value)
+    }
+}
+
+/// Send one plain HTTP/1.1 request to `authority` (port 80 unless it names one) and return
+/// the status and body, reading at most `max_bytes` of the response. The request goes out in
+/// one write, so a server that answers after its first read still sees all of it.
+pub(crate) fn http_request(
+    authority: &str,
+    method: &str,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &str,
+    max_bytes: u64,
+) -> Result<(u16, String)> {
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+    let address = address
+        .to_socket_addrs()
+        .with_context(|| format!("resolving {}", authority))?
+        .next()
+        .ok_or_else(|| anyhow!("{} did not resolve", authority))?;
+    let mut stream = TcpStream::connect_timeout(&address, IO_TIMEOUT)
+        .with_context(|| format!("connecting to {}", authority))?;
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let mut request = format!("{} {} HTTP/1.1\r\nHost: {}\r\n", method, path, authority);
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if method != "GET" {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("Connection: close\r\n\r\n");
+    request.push_str(body);
+    stream.write_all(request.as_bytes())?;
+    let mut raw = Vec::new();
+    stream.take(max_bytes).read_to_end(&mut raw)?;
+    parse_response(&raw)
+}
+
+/// Status code and body of a raw HTTP/1.1 response, undoing chunked encoding.
+pub(crate) fn parse_response(raw: &[u8]) -> Result<(u16, String)> {
+    let text = String::from_utf8_lossy(raw);
+    let (head, body) = text
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| anyhow!("truncated HTTP response"))?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| anyhow!("malformed HTTP status line"))?;
+    let chunked = head.lines().any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    if !chunked {
+        return Ok((status, body.to_string()));
+    }
+
+    let mut decoded = String::new();
+    let mut rest = body;
+    while let Some((size, after)) = rest.split_once("\r\n") {
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or("").trim(), 16)
+            .context("malformed chunk size")?;
+        if size == 0 {
+            break;
+        }
+        let chunk = after
+            .get(..size)
+            .ok_or_else(|| anyhow!("truncated HTTP chunk"))?;
+        decoded.push_str(chunk);
+        rest = after[size..].trim_start_matches("\r\n");
+    }
+    Ok((status, decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn page(url: &str, title: &str, text: &str) -> Page {
+        Page {
+            url: url.to_string(),
+            title: title.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_crash_page_detection() {
+        assert!(crash_page(&page("http://app.test/", "Dashboard", "Welcome")).is_none());
+        assert!(crash_page(&page("chrome-error://chromewebdata/", "", ""))
+            .unwrap()
+            .contains("browser error page"));
+        assert_eq!(
+            crash_page(&page("http://app.test/", "502 Bad Gateway", "nginx")).as_deref(),
+            Some("error page: 502 Bad Gateway")
+        );
+        assert_eq!(origin("http://app.test:8080/a/b"), "http://app.test:8080");
+        assert_eq!(origin("http://app.test"), "http://app.test");
+    }
+
+    #[test]
+    fn test_parse_chunked_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+            7\r\n{\"value\r\n4\r\n\":1}\r\n0\r\n\r\n";
+        assert_eq!(
+            parse_response(raw).unwrap(),
+            (200, "{\"value\":1}".to_string())
+        );
+        let plain = b"HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\n\r\n{}";
+        assert_eq!(parse_response(plain).unwrap().0, 404);
+        assert!(parse_response(b"HTTP/1.1 200").is_err());
+    }
+
+    #[test]
+    fn test_http_request_reads_at_most_max_bytes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n");
+            // Far more than the caller asked for; it stops reading after its cap.
+            let _ = stream.write_all(&[b'x'; 64 * 1024]);
+        });
+        let (status, body) = http_request(&address, "GET", "/", &[], "", 1024).unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body.len(), 1024 - "HTTP/1.1 200 OK\r\n\r\n".len());
+    }
+
+    /// Answers WebDriver commands for one session on a page that throws once.
+    fn fake_driver() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || {
+            let mut thrown = false;
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                // Read the head, then as much body as Content-Length announces.
+                while let Ok(read) = stream.read(&mut buffer) {
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| line.strip_prefix("Content-Length: "))
+                            .and_then(|length| length.parse::<usize>().ok())
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break;
+                        }
+                    }
+                }
+                let request = String::from_utf8_lossy(&request).to_string();
+                let line = request.lines().next().unwrap_or_default().to_string();
+                let value = if line.starts_with("POST /session ") {
+                    json!({ "sessionId": "s1" })
+                } else if line.starts_with("GET /session/s1/url") {
+                    json!("http://app.test/")
+                } else if line.starts_with("GET /session/s1/title") {
+                    json!("App")
+                } else if line.contains("/elements") {
+                    json!([{ ELEMENT_KEY: "e1" }])
+                } else if request.contains("__panicAttack = []; return e") {
+                    let errors = if thrown {
+                        json!([])
+                    } else {
+                        json!(["TypeError: cart is undefined\n    at checkout (app.js:3)"])
+                    };
+                    thrown = true;
+                    errors
+                } else if request.contains("innerText") {
+                    json!("Welcome")
+                } else {
+                    Value::Null
+                };
+                let body = json!({ "value": value }).to_string();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+        format!("http://{}", address)
+    }
+
+    #[test]
+    fn test_storm_records_console_errors() {
+        let config = BrowserConfig {
+            webdriver: fake_driver(),
+            url: "http://app.test/".to_string(),
+            browser: BrowserKind::Chrome,
+        };
+        let storm = start(&config, IntensityLevel::Extreme, 7);
+        thread::sleep(Duration::from_millis(300));
+        let report = storm.stop();
+
+        assert_eq!(report.note, None);
+        assert!(report.actions > 0);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].kind, BrowserErrorKind::Console);
+
+        let crashes = crash_reports(&report);
+        assert_eq!(
+            crashes[0].stderr,
+            "console error: TypeError: cart is undefined"
+        );
+        assert_eq!(
+            crashes[0].backtrace.as_deref(),
+            Some("    at checkout (app.js:3)")
+        );
+    }
+
+    #[test]
+    fn test_unreachable_driver_is_noted() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let config = BrowserConfig {
+            webdriver: format!("http://{}", address),
+            url: "http://app.test/".to_string(),
+            browser: BrowserKind::Firefox,
+        };
+        let report = start(&config, IntensityLevel::Light, 1).stop();
+        assert_eq!(report.actions, 0);
+        assert!(report
+            .note
+            .unwrap()
+            .contains("starting a WebDriver session"));
+    }
+}
@@ -0,0 +1,611 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! cgroup v2 target control: squeeze the target's own memory and I/O budgets.
+//!
+//! The self-allocating memory stressor pressures the host, which only reaches the target
+//! indirectly. In cgroup mode the target is moved into a dedicated child cgroup whose
+//! `memory.high` is lowered step by step (with `memory.max` trailing 25% above it), so the
+//! kernel reclaims from and eventually OOM-kills the target itself. The disk axis can likewise
+//! apply `io.max` limits on the device backing the target's data directory. Both need a
+//! writable, delegated cgroup v2 subtree (e.g. a systemd user scope with `Delegate=yes`).
+//!
+//! cgroup v2 only lets a cgroup without member processes enable controllers for its children,
+//! so the first attach moves panic-attack and the processes it started into a `supervisor`
+//! leaf beside the target cgroups. Anything else sharing that cgroup is left alone, and the
+//! attach fails; start panic-attack in a scope of its own then (`systemd-run --user --scope
+//! -p Delegate=yes panic-attack ...`).
+//!
+//! A target has one cgroup for its whole run, shared by every stressor that attaches to it, so
+//! overlapping timeline events do not pull the target out from under each other. Each event
+//! only sets its own limits and lifts them again when it ends; the target leaves the cgroup
+//! when the last event lets go of it.
+
+use crate::types::{
+    DiskThrottleConfig, DiskThrottleReport, IntensityLevel, MemoryPressureConfig,
+    MemoryPressureMode, MemoryPressureReport, MemoryPressureStep,
+};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// cgroup v2 mount points: pure unified layout first, then the hybrid-layout location.
+const CGROUP_ROOTS: &[&str] = &["/sys/fs/cgroup", "/sys/fs/cgroup/unified"];
+const DEFAULT_START_MB: u64 = 1024;
+const PRESSURE_STEPS: usize = 10;
+const MIB: u64 = 1024 * 1024;
+/// Leaf that panic-attack's own processes move into, so their cgroup can delegate controllers.
+const SUPERVISOR: &str = "supervisor";
+
+/// The cgroup that was ours, emptied into its `supervisor` leaf; prepared once per process.
+static CONTROL_PARENT: OnceLock<Result<PathBuf, String>> = OnceLock::new();
+/// Target cgroups in use, by target pid.
+static TARGETS: Mutex<Vec<(u32, Weak<TargetCgroup>)>> = Mutex::new(Vec::new());
+
+/// A child cgroup holding the target process.
+pub struct TargetCgroup {
+    /// Where the target goes back to when the cgroup is removed.
+    home: PathBuf,
+    dir: PathBuf,
+    pid: u32,
+}
+
+impl TargetCgroup {
+    /// The cgroup of target `pid` with `controller` enabled: the one its run already has, or
+    /// a new child of our own cgroup that `pid` moves into.
+    pub fn attach(pid: u32, controller: &str) -> Result<Arc<Self>> {
+        if !cfg!(target_os = "linux") {
+            return Err(anyhow!("cgroup {} control requires Linux", controller));
+        }
+        let parent = CONTROL_PARENT
+            .get_or_init(|| prepare_parent().map_err(|err| format!("{:#}", err)))
+            .clone()
+            .map_err(|err| anyhow!(err))?;
+        let available = fs::read_to_string(parent.join("cgroup.controllers")).unwrap_or_default();
+        if !available.split_whitespace().any(|c| c == controller) {
+            return Err(anyhow!(
+                "{} controller is not delegated to {}",
+                controller,
+                parent.display()
+            ));
+        }
+
+        let subtree = fs::read_to_string(parent.join("cgroup.subtree_control")).unwrap_or_default();
+        if !subtree.split_whitespace().any(|c| c == controller) {
+            fs::write(
+                parent.join("cgroup.subtree_control"),
+                format!("+{}", controller),
+            )
+            .with_context(|| {
+                format!(
+                    "enabling {} controller under {}",
+                    controller,
+                    parent.display()
+                )
+            })?;
+        }
+
+        let mut targets = TARGETS.lock().expect("target cgroup registry lock");
+        targets.retain(|(_, cgroup)| cgroup.strong_count() > 0);
+        if let Some(cgroup) = targets
+            .iter()
+            .find(|(target, _)| *target == pid)
+            .and_then(|(_, cgroup)| cgroup.upgrade())
+        {
+            return Ok(cgroup);
+        }
+
+        let dir = parent.join(format!("panic-attack-{}-{}", std::process::id(), pid));
+        fs::create_dir(&dir).with_context(|| format!("creating cgroup {}", dir.display()))?;
+        let supervisor = parent.join(SUPERVISOR);
+        let cgroup = Self {
+            home: if supervisor.is_dir() {
+                supervisor
+            } else {
+                parent
+            },
+            dir,
+            pid,
+        };
+        fs::write(cgroup.dir.join("cgroup.procs"), pid.to_string())
+            .with_context(|| format!("moving pid {} into {}", pid, cgroup.dir.display()))?;
+        let cgroup = Arc::new(cgroup);
+        targets.push((pid, Arc::downgrade(&cgroup)));
+        Ok(cgroup)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn set_limits(&self, high: u64, max: u64) -> Result<()> {
+        // Limits only descend, so lowering high first keeps it below max mid-update.
+        fs::write(self.dir.join("memory.high"), high.to_string())
+            .with_context(|| format!("writing memory.high in {}", self.dir.display()))?;
+        fs::write(self.dir.join("memory.max"), max.to_string())
+            .with_context(|| format!("writing memory.max in {}", self.dir.display()))?;
+        Ok(())
+    }
+
+    /// Lift the memory limits again.
+    pub fn clear_limits(&self) {
+        let _ = fs::write(self.dir.join("memory.max"), "max");
+        let _ = fs::write(self.dir.join("memory.high"), "max");
+    }
+
+    pub fn current(&self) -> u64 {
+        read_u64(&self.dir.join("memory.current")).unwrap_or(0)
+    }
+
+    pub fn peak(&self) -> Option<u64> {
+        read_u64(&self.dir.join("memory.peak"))
+    }
+
+    pub fn events(&self) -> HashMap<String, u64> {
+        fs::read_to_string(self.dir.join("memory.events"))
+            .map(|content| parse_flat_keyed(&content))
+            .unwrap_or_default()
+    }
+
+    pub fn stat(&self) -> HashMap<String, u64> {
+        fs::read_to_string(self.dir.join("memory.stat"))
+            .map(|content| parse_flat_keyed(&content))
+            .unwrap_or_default()
+    }
+
+    pub fn set_io_max(&self, line: &str) -> Result<()> {
+        fs::write(self.dir.join("io.max"), line)
+            .with_context(|| format!("writing io.max in {}", self.dir.display()))
+    }
+
+    /// `io.stat` counters for one `MAJ:MIN` device.
+    pub fn io_stat(&self, device: &str) -> HashMap<String, u64> {
+        fs::read_to_string(self.dir.join("io.stat"))
+            .ok()
+            .and_then(|content| parse_io_stat(&content, device))
+            .unwrap_or_default()
+    }
+
+    /// Total stall time (microseconds) from the `some` line of `io.pressure`.
+    pub fn io_pressure_total(&self) -> Option<u64> {
+        let content = fs::read_to_string(self.dir.join("io.pressure")).ok()?;
+        let line = content.lines().find(|line| line.starts_with("some"))?;
+        line.split_whitespace()
+            .find_map(|field| field.strip_prefix("total="))
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+impl Drop for TargetCgroup {
+    fn drop(&mut self) {
+        // The last event is over; a still-running target goes back home first. Only a pid
+        // still listed here is the target: once it exits, its pid may belong to anyone.
+        let members = fs::read_to_string(self.dir.join("cgroup.procs")).unwrap_or_default();
+        if listed(&members, self.pid) {
+            let _ = fs::write(self.home.join("cgroup.procs"), self.pid.to_string());
+        }
+        let _ = fs::remove_dir(&self.dir);
+    }
+}
+
+/// Turn our own cgroup into one that may enable controllers: this process and its
+/// descendants move into the `supervisor` leaf ("no internal processes" rule). Other members
+/// are not ours to move, so they make this fail, leaving the cgroup as it was.
+fn prepare_parent() -> Result<PathBuf> {
+    let membership =
+        fs::read_to_string("/proc/self/cgroup").context("reading /proc/self/cgroup")?;
+    let relative = unified_cgroup_path(&membership)
+        .ok_or_else(|| anyhow!("no cgroup v2 (unified) hierarchy for this process"))?;
+    let root = CGROUP_ROOTS
+        .iter()
+        .map(Path::new)
+        .find(|root| root.join("cgroup.controllers").exists())
+        .ok_or_else(|| anyhow!("cgroup v2 is not mounted"))?;
+    let parent = root.join(relative.trim_start_matches('/'));
+    if relative.trim_start_matches('/').is_empty() {
+        // The root cgroup is exempt from the rule; its processes are the whole host's.
+        return Ok(parent);
+    }
+
+    let members = fs::read_to_string(parent.join("cgroup.procs"))
+        .with_context(|| format!("reading {}/cgroup.procs", parent.display()))?;
+    let ours = std::process::id();
+    let (moving, others): (Vec<u32>, Vec<u32>) = members
+        .split_whitespace()
+        .filter_map(|pid| pid.parse().ok())
+        .partition(|member| descends_from(*member, ours));
+    if !others.is_empty() {
+        return Err(foreign_members(&parent, &others));
+    }
+
+    let supervisor = parent.join(SUPERVISOR);
+    let created = match fs::create_dir(&supervisor) {
+        Ok(()) => true,
+        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => false,
+        Err(err) => {
+            return Err(err).with_context(|| format!("creating cgroup {}", supervisor.display()))
+        }
+    };
+    for member in &moving {
+        // Writing a pid moves all its threads; a member that exits meanwhile is gone
+        // from the parent anyway.
+        let _ = fs::write(supervisor.join("cgroup.procs"), member.to_string());
+    }
+    let remaining: Vec<u32> = fs::read_to_string(parent.join("cgroup.procs"))
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter_map(|pid| pid.parse().ok())
+        .collect();
+    if !remaining.is_empty() {
+        // Something joined meanwhile; put everything back the way it was.
+        for member in &moving {
+            let _ = fs::write(parent.join("cgroup.procs"), member.to_string());
+        }
+        if created {
+            let _ = fs::remove_dir(&supervisor);
+        }
+        return Err(foreign_members(&parent, &remaining));
+    }
+    Ok(parent)
+}
+
+fn foreign_members(parent: &Path, members: &[u32]) -> anyhow::Error {
+    anyhow!(
+        "{} also holds processes {} that panic-attack did not start; run it in a delegated \
+         scope of its own (systemd-run --user --scope -p Delegate=yes)",
+        parent.display(),
+        members
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Whether `pid` appears in `cgroup.procs` content.
+fn listed(members: &str, pid: u32) -> bool {
+    members
+        .split_whitespace()
+        .any(|member| member.parse() == Ok(pid))
+}
+
+/// Whether `pid` is `ancestor` or one of its descendants.
+fn descends_from(mut pid: u32, ancestor: u32) -> bool {
+    while pid > 1 {
+        if pid == ancestor {
+            return true;
+        }
+        match fs::read_to_string(format!("/proc/{}/stat", pid))
+            .ok()
+            .and_then(|stat| parent_pid(&stat))
+        {
+            Some(parent) => pid = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Parent pid from `/proc/<pid>/stat` content; the command name may contain spaces and
+/// parentheses, so fields are counted from the last `)`.
+pub fn parent_pid(stat: &str) -> Option<u32> {
+    let (_, fields) = stat.rsplit_once(')')?;
+    fields.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// Extract the unified hierarchy path from `/proc/<pid>/cgroup` content.
+pub fn unified_cgroup_path(content: &str) -> Option<String> {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|path| path.trim().to_string())
+}
+
+/// Parse cgroup "flat keyed" files such as `memory.events`.
+pub fn parse_flat_keyed(content: &str) -> HashMap<String, u64> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let key = parts.next()?;
+            let value = parts.next()?.parse().ok()?;
+            Some((key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Parse the `io.stat` line for `device` (`8:0 rbytes=1 wbytes=2 rios=3 ...`).
+pub fn parse_io_stat(content: &str, device: &str) -> Option<HashMap<String, u64>> {
+    let line = content
+        .lines()
+        .find(|line| line.split_whitespace().next() == Some(device))?;
+    Some(
+        line.split_whitespace()
+            .skip(1)
+            .filter_map(|field| {
+                let (key, value) = field.split_once('=')?;
+                Some((key.to_string(), value.parse().ok()?))
+            })
+            .collect(),
+    )
+}
+
+/// Resolve the whole-disk `MAJ:MIN` backing `path`; `io.max` rejects partitions.
+pub fn block_device_for(path: &Path) -> Result<String> {
+    let (major, minor) = device_numbers(path)?;
+    let sys = PathBuf::from(format!("/sys/dev/block/{}:{}", major, minor));
+    if !sys.exists() {
+        return Err(anyhow!(
+            "{} is not backed by a block device ({}:{})",
+            path.display(),
+            major,
+            minor
+        ));
+    }
+    if sys.join("partition").exists() {
+        let disk = fs::canonicalize(&sys)
+            .with_context(|| format!("resolving {}", sys.display()))?
+            .parent()
+            .map(|parent| parent.join("dev"))
+            .ok_or_else(|| anyhow!("no parent disk for {}", sys.display()))?;
+        return Ok(fs::read_to_string(&disk)
+            .with_context(|| format!("reading {}", disk.display()))?
+            .trim()
+            .to_string());
+    }
+    Ok(format!("{}:{}", major, minor))
+}
+
+#[cfg(target_os = "linux")]
+fn device_numbers(path: &Path) -> Result<(u32, u32)> {
+    use std::os::unix::fs::MetadataExt;
+    let dev = fs::metadata(path)
+        .with_context(|| format!("reading metadata for {}", path.display()))?
+        .dev();
+    Ok((libc::major(dev), libc::minor(dev)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn device_numbers(_path: &Path) -> Result<(u32, u32)> {
+    Err(anyhow!("block device lookup requires Linux"))
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Start and floor for `memory.high`, in bytes.
+pub fn pressure_bounds(config: &MemoryPressureConfig, intensity: IntensityLevel) -> (u64, u64) {
+    let start = config.start_mb.unwrap_or(DEFAULT_START_MB).max(1) * MIB;
+    let floor = match config.floor_mb {
+        Some(mb) => mb * MIB,
+        None => (start as f64 / (2.0 * intensity.multiplier())) as u64,
+    };
+    (start, floor.clamp(MIB, start))
+}
+
+/// Linear descent from `start` to `floor` over `steps` points (inclusive).
+pub fn pressure_schedule(start: u64, floor: u64, steps: usize) -> Vec<u64> {
+    if steps <= 1 {
+        return vec![floor];
+    }
+    (0..steps)
+        .map(|i| start - (start - floor) * i as u64 / (steps as u64 - 1))
+        .collect()
+}
+
+/// Run the descent on a background thread, keeping `peak_memory` updated.
+pub fn spawn_cgroup_pressure(
+    cgroup: Arc<TargetCgroup>,
+    config: MemoryPressureConfig,
+    intensity: IntensityLevel,
+    duration: Duration,
+    stop: Arc<AtomicBool>,
+    peak_memory: Arc<AtomicU64>,
+) -> JoinHandle<MemoryPressureReport> {
+    thread::spawn(move || {
+        let (start_bytes, floor_bytes) = pressure_bounds(&config, intensity);
+        let mut report = MemoryPressureReport {
+            mode: MemoryPressureMode::Cgroup,
+            cgroup: Some(cgroup.path().display().to_string()),
+            start_bytes,
+            floor_bytes,
+            ..Default::default()
+        };
+        // The cgroup may have served earlier events; count only this one's.
+        let baseline = cgroup.events();
+        let reclaimed_before = cgroup.stat().get("pgsteal").copied().unwrap_or(0);
+
+        let begin = Instant::now();
+        let step_len = duration / PRESSURE_STEPS as u32;
+        for (index, high) in pressure_schedule(start_bytes, floor_bytes, PRESSURE_STEPS)
+            .into_iter()
+            .enumerate()
+        {
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+            let max = high + high / 4;
+            if let Err(err) = cgroup.set_limits(high, max) {
+                report.note = Some(err.to_string());
+                break;
+            }
+            let current = cgroup.current();
+            peak_memory.fetch_max(current, Ordering::Relaxed);
+            report.steps.push(MemoryPressureStep {
+                offset: begin.elapsed(),
+                memory_high: high,
+                memory_max: max,
+                memory_current: current,
+            });
+
+            let wake = begin + step_len * (index as u32 + 1);
+            while !stop.load(Ordering::Relaxed) && Instant::now() < wake {
+                peak_memory.fetch_max(cgroup.current(), Ordering::Relaxed);
+                thread::sleep(Duration::from_millis(20));
+            }
+        }
+
+        // Hold the floor until the run ends so late allocations still hit it.
+        while !stop.load(Ordering::Relaxed) && begin.elapsed() < duration {
+            peak_memory.fetch_max(cgroup.current(), Ordering::Relaxed);
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        cgroup.clear_limits();
+        let events = cgroup.events();
+        let count = |key: &str| {
+            let before = baseline.get(key).copied().unwrap_or(0);
+            events.get(key).copied().unwrap_or(0).saturating_sub(before)
+        };
+        report.high_events = count("high");
+        report.max_events = count("max");
+        report.oom_events = count("oom");
+        report.oom_kills = count("oom_kill");
+        report.reclaimed_pages = cgroup
+            .stat()
+            .get("pgsteal")
+            .copied()
+            .unwrap_or(0)
+            .saturating_sub(reclaimed_before);
+        report.peak_current = cgroup
+            .peak()
+            .unwrap_or_else(|| peak_memory.load(Ordering::Relaxed));
+        peak_memory.fetch_max(report.peak_current, Ordering::Relaxed);
+        report
+    })
+}
+
+/// Apply `io.max` for the run, then collect the device counters.
+pub fn spawn_io_throttle(
+    cgroup: Arc<TargetCgroup>,
+    device: String,
+    config: DiskThrottleConfig,
+    duration: Duration,
+    stop: Arc<AtomicBool>,
+) -> JoinHandle<DiskThrottleReport> {
+    thread::spawn(move || {
+        let limits = config.io_max_line(&device);
+        let mut report = DiskThrottleReport {
+            cgroup: Some(cgroup.path().display().to_string()),
+            device: device.clone(),
+            limits: limits.clone(),
+            ..Default::default()
+        };
+        if let Err(err) = cgroup.set_io_max(&limits) {
+            report.note = Some(format!("{:#}", err));
+            return report;
+        }
+        // The cgroup may have served earlier events; count only this one's.
+        let baseline = cgroup.io_stat(&device);
+        let stall_before = cgroup.io_pressure_total();
+
+        let begin = Instant::now();
+        while !stop.load(Ordering::Relaxed) && begin.elapsed() < duration {
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        let _ = cgroup.set_io_max(&DiskThrottleConfig::default().io_max_line(&device));
+        let stat = cgroup.io_stat(&device);
+        let count = |key: &str| {
+            let before = baseline.get(key).copied().unwrap_or(0);
+            stat.get(key).copied().unwrap_or(0).saturating_sub(before)
+        };
+        report.read_bytes = count("rbytes");
+        report.write_bytes = count("wbytes");
+        report.read_ios = count("rios");
+        report.write_ios = count("wios");
+        report.stall_micros = cgroup
+            .io_pressure_total()
+            .map(|total| total.saturating_sub(stall_before.unwrap_or(0)));
+        report
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_cgroup_path() {
+        let content = "12:pids:/legacy\n0::/user.slice/user-1000.slice/session-2.scope\n";
+        assert_eq!(
+            unified_cgroup_path(content).as_deref(),
+            Some("/user.slice/user-1000.slice/session-2.scope")
+        );
+        assert_eq!(unified_cgroup_path("3:cpu:/x\n"), None);
+    }
+
+    #[test]
+    fn test_parent_pid_skips_command_name() {
+        let stat = "4242 (odd (name) x) S 4200 4242 4200 0 -1 4194560 118 0 0 0\n";
+        assert_eq!(parent_pid(stat), Some(4200));
+        assert_eq!(parent_pid("4242 (truncated"), None);
+        assert!(!descends_from(1, std::process::id()));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_descends_from_finds_our_children() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .expect("spawn sleep");
+        assert!(descends_from(child.id(), std::process::id()));
+        assert!(!descends_from(std::process::id(), child.id()));
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn test_listed_matches_whole_pids() {
+        assert!(listed("12\n345\n", 345));
+        assert!(!listed("12\n3456\n", 345));
+        assert!(!listed("", 345));
+    }
+
+    #[test]
+    fn test_parse_memory_events() {
+        let events = parse_flat_keyed("low 0\nhigh 12\nmax 3\noom 1\noom_kill 1\n");
+        assert_eq!(events.get("high"), Some(&12));
+        assert_eq!(events.get("oom_kill"), Some(&1));
+    }
+
+    #[test]
+    fn test_parse_io_stat_for_device() {
+        let content = "259:0 rbytes=4096 wbytes=8192 rios=1 wios=2 dbytes=0 dios=0\n8:0 rbytes=1\n";
+        let stat = parse_io_stat(content, "259:0").unwrap();
+        assert_eq!(stat.get("wbytes"), Some(&8192));
+        assert_eq!(stat.get("rios"), Some(&1));
+        assert!(parse_io_stat(content, "7:0").is_none());
+    }
+
+    #[test]
+    fn test_pressure_schedule_descends_to_floor() {
+        let schedule = pressure_schedule(1000, 100, 10);
+        assert_eq!(schedule.len(), 10);
+        assert_eq!(schedule[0], 1000);
+        assert_eq!(schedule[9], 100);
+        assert!(schedule.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_pressure_bounds_scale_with_intensity() {
+        let config = MemoryPressureConfig::default();
+        let (start, light_floor) = pressure_bounds(&config, IntensityLevel::Light);
+        let (_, heavy_floor) = pressure_bounds(&config, IntensityLevel::Heavy);
+        assert_eq!(start, DEFAULT_START_MB * MIB);
+        assert!(heavy_floor < light_floor);
+
+        let fixed = MemoryPressureConfig {
+            floor_mb: Some(4096),
+            ..config
+        };
+        // Floors above the start are clamped to the start.
+        assert_eq!(pressure_bounds(&fixed, IntensityLevel::Light).1, start);
+    }
+}
@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Runtime conditions for timeline triggers (`start_when`, `stop_when`).
+//!
+//! A condition compares one sampled metric against a threshold, for example `rss > 1GiB`,
+//! `cpu >= 90%`, `fds > 500` or `elapsed > 2m`. Comparisons can be joined with `and` and `or`,
+//! and `and` binds tighter. Metrics come from a sampler that reads `/proc/<pid>` about every
+//! 100ms. Hosts without procfs only get `elapsed`, so other metrics there never satisfy a
+//! condition.
+
+use crate::signatures::leak::ResourcePoint;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Metric {
+    /// Resident set size in bytes.
+    Rss,
+    /// CPU use of the target in percent of one core.
+    Cpu,
+    Threads,
+    Fds,
+    /// Time since the timeline started, in milliseconds.
+    Elapsed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Threshold {
+    pub metric: Metric,
+    pub comparison: Comparison,
+    pub value: f64,
+}
+
+/// Disjunction of conjunctions of thresholds, kept with its source text for reports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub source: String,
+    any_of: Vec<Vec<Threshold>>,
+}
+
+/// Latest observation of the target; `None` when the metric could not be read.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuntimeSample {
+    pub rss: Option<u64>,
+    pub cpu_percent: Option<f64>,
+    pub threads: Option<u64>,
+    pub fds: Option<u64>,
+    pub elapsed: Duration,
+}
+
+impl From<&RuntimeSample> for ResourcePoint {
+    fn from(sample: &RuntimeSample) -> Self {
+        Self {
+            elapsed: sample.elapsed,
+            rss: sample.rss,
+            fds: sample.fds,
+            threads: sample.threads,
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Condition {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let source = raw.trim().to_string();
+        if source.is_empty() {
+            return Err(anyhow!("condition cannot be empty"));
+        }
+        let any_of = source
+            .split(" or ")
+            .map(|clause| {
+                clause
+                    .split(" and ")
+                    .map(parse_threshold)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { source, any_of })
+    }
+
+    pub fn holds(&self, sample: &RuntimeSample) -> bool {
+        self.any_of
+            .iter()
+            .any(|clause| clause.iter().all(|threshold| threshold.holds(sample)))
+    }
+}
+
+impl Threshold {
+    fn holds(&self, sample: &RuntimeSample) -> bool {
+        let observed = match self.metric {
+            Metric::Rss => sample.rss.map(|v| v as f64),
+            Metric::Cpu => sample.cpu_percent,
+            Metric::Threads => sample.threads.map(|v| v as f64),
+            Metric::Fds => sample.fds.map(|v| v as f64),
+            Metric::Elapsed => Some(sample.elapsed.as_secs_f64() * 1000.0),
+        };
+        let Some(observed) = observed else {
+            return false;
+        };
+        match self.comparison {
+            Comparison::Greater => observed > self.value,
+            Comparison::GreaterEqual => observed >= self.value,
+            Comparison::Less => observed < self.value,
+            Comparison::LessEqual => observed <= self.value,
+        }
+    }
+}
+
+fn parse_threshold(raw: &str) -> Result<Threshold> {
+    let raw = raw.trim();
+    let (split, comparison, width) = [
+        (">=", Comparison::GreaterEqual),
+        ("<=", Comparison::LessEqual),
+        (">", Comparison::Greater),
+        ("<", Comparison::Less),
+    ]
+    .into_iter()
+    .find_map(|(token, comparison)| raw.find(token).map(|at| (at, comparison, token.len())))
+    .ok_or_else(|| anyhow!("condition '{}' needs a comparison (>, >=, <, <=)", raw))?;
+
+    let name = raw[..split].trim().to_ascii_lowercase();
+    let value = raw[split + width..].trim();
+    let metric = match name.as_str() {
+        "rss" | "memory" => Metric::Rss,
+        "cpu" => Metric::Cpu,
+        "threads" => Metric::Threads,
+        "fds" => Metric::Fds,
+        "elapsed" | "time" => Metric::Elapsed,
+        _ => {
+            return Err(anyhow!(
+                "unknown metric '{}' (expected rss, cpu, threads, fds, elapsed)",
+                name
+            ))
+        }
+    };
+    let value = match metric {
+        Metric::Rss => parse_bytes(value)?,
+        Metric::Cpu => parse_number(value.trim_end_matches('%'))?,
+        Metric::Threads | Metric::Fds => parse_number(value)?,
+        Metric::Elapsed => super::timeline::parse_duration(value)?.as_secs_f64() * 1000.0,
+    };
+    Ok(Threshold {
+        metric,
+        comparison,
+        value,
+    })
+}
+
+fn parse_number(raw: &str) -> Result<f64> {
+    raw.trim()
+        .parse()
+        .map_err(|_| anyhow!("invalid number '{}' in condition", raw))
+}
+
+/// Byte sizes: plain numbers or K/M/G with optional `B`/`iB` (all binary multiples).
+fn parse_bytes(raw: &str) -> Result<f64> {
+    let upper = raw.trim().to_ascii_uppercase();
+    let digits = upper.trim_end_matches("IB").trim_end_matches('B');
+    let (number, multiplier) = match digits.chars().last() {
+        Some('K') => (&digits[..digits.len() - 1], 1024.0),
+        Some('M') => (&digits[..digits.len() - 1], 1024.0 * 1024.0),
+        Some('G') => (&digits[..digits.len() - 1], 1024.0 * 1024.0 * 1024.0),
+        _ => (digits, 1.0),
+    };
+    Ok(parse_number(number)? * multiplier)
+}
+
+/// Samples `/proc/<pid>` and derives CPU use from the change in utime+stime between calls.
+pub struct ProcessSampler {
+    pid: u32,
+    started: Instant,
+    last_cpu: Option<(Instant, u64)>,
+    ticks_per_second: f64,
+}
+
+impl ProcessSampler {
+    pub fn new(pid: u32, started: Instant) -> Self {
+        Self {
+            pid,
+            started,
+            last_cpu: None,
+            ticks_per_second: clock_ticks(),
+        }
+    }
+
+    pub fn sample(&mut self) -> RuntimeSample {
+        let status = fs::read_to_string(format!("/proc/{}/status", self.pid)).unwrap_or_default();
+        let cpu_percent = self.cpu_percent();
+        RuntimeSample {
+            rss: status_field(&status, "VmRSS:").map(|kib| kib * 1024),
+            cpu_percent,
+            threads: status_field(&status, "Threads:"),
+            fds: fs::read_dir(format!("/proc/{}/fd", self.pid))
+                .ok()
+                .map(|entries| entries.count() as u64),
+            elapsed: self.started.elapsed(),
+        }
+    }
+
+    fn cpu_percent(&mut self) -> Option<f64> {
+        let stat = fs::read_to_string(format!("/proc/{}/stat", self.pid)).ok()?;
+        let ticks = parse_cpu_ticks(&stat)?;
+        let now = Instant::now();
+        let previous = self.last_cpu.replace((now, ticks));
+        let (then, before) = previous?;
+        let wall = now.duration_since(then).as_secs_f64();
+        if wall <= 0.0 {
+            return None;
+        }
+        let busy = ticks.saturating_sub(before) as f64 / self.ticks_per_second;
+        Some(busy / wall * 100.0)
+    }
+}
+
+/// Samples the target in the background, keeping every observation for leak detection.
+pub struct ResourceRecorder {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<Vec<ResourcePoint>>,
+}
+
+impl ResourceRecorder {
+    pub fn start(pid: u32, interval: Duration) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let flag = stop.clone();
+        let handle = thread::spawn(move || {
+            let mut sampler = ProcessSampler::new(pid, Instant::now());
+            let mut points = Vec::new();
+            while !flag.load(Ordering::Relaxed) {
+                points.push(ResourcePoint::from(&sampler.sample()));
+                thread::sleep(interval);
+            }
+            points
+        });
+        Self { stop, handle }
+    }
+
+    pub fn stop(self) -> Vec<ResourcePoint> {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_default()
+    }
+}
+
+fn status_field(status: &str, key: &str) -> Option<u64> {
+    status
+        .lines()
+        .find(|line| line.starts_with(key))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+/// utime + stime from `/proc/<pid>/stat`, skipping the parenthesised command name.
+pub fn parse_cpu_ticks(stat: &str) -> Option<u64> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    // After the name: state is field 3, so utime (14) and stime (15) sit at offsets 11 and 12.
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some(utime + stime)
+}
+
+#[cfg(unix)]
+pub(crate) fn clock_ticks() -> f64 {
+    // SAFETY: sysconf has no preconditions.
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks > 0 {
+        ticks as f64
+    } else {
+        100.0
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn clock_ticks() -> f64 {
+    100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(rss_mib: u64, cpu: f64, secs: u64) -> RuntimeSample {
+        RuntimeSample {
+            rss: Some(rss_mib * 1024 * 1024),
+            cpu_percent: Some(cpu),
+            threads: Some(4),
+            fds: Some(12),
+            elapsed: Duration::from_secs(secs),
+        }
+    }
+
+    #[test]
+    fn test_parse_and_evaluate_thresholds() {
+        let rss = Condition::parse("rss > 1GiB").unwrap();
+        assert!(!rss.holds(&sample(512, 0.0, 0)));
+        assert!(rss.holds(&sample(2048, 0.0, 0)));
+
+        let cpu = Condition::parse("cpu >= 90%").unwrap();
+        assert!(cpu.holds(&sample(0, 90.0, 0)));
+        assert!(!cpu.holds(&RuntimeSample::default()));
+
+        let elapsed = Condition::parse("elapsed > 1m").unwrap();
+        assert!(elapsed.holds(&sample(0, 0.0, 61)));
+    }
+
+    #[test]
+    fn test_and_binds_tighter_than_or() {
+        let condition = Condition::parse("threads > 8 or rss > 100M and fds > 10").unwrap();
+        assert!(condition.holds(&sample(200, 0.0, 0)));
+        assert!(!condition.holds(&sample(50, 0.0, 0)));
+        assert_eq!(
+            condition.to_string(),
+            "threads > 8 or rss > 100M and fds > 10"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Condition::parse("").is_err());
+        assert!(Condition::parse("rss 5").is_err());
+        assert!(Condition::parse("load > 2").is_err());
+        assert!(Condition::parse("rss > lots").is_err());
+    }
+
+    #[test]
+    fn test_parse_cpu_ticks_skips_command_name() {
+        let stat = "42 (my prog) S 1 42 42 0 -1 4194560 100 0 0 0 250 50 0 0 20 0 1 0";
+        assert_eq!(parse_cpu_ticks(stat), Some(300));
+    }
+}
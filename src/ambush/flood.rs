@@ -0,0 +1,521 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Message floods against event-driven targets (MQTT and Kafka)
+//!
+//! Consumers of a message bus trust what arrives on their topic far more than a server trusts
+//! its clients: a payload that is not JSON, a message larger than any buffer, a redelivered
+//! message, a sequence number that goes backwards. While an ambush runs, this publishes a
+//! seeded mix of valid, malformed, oversized, duplicated and out-of-order messages to the
+//! configured topic in phases of doubling rate, and keeps per-phase delivery statistics so a
+//! target failure can be placed in the phase (and rate) that caused it.
+//!
+//! Publishing waits for each acknowledgement, so the achieved rate drops below the target
+//! when the broker slows down; both are reported.
+
+use super::kafka::KafkaPublisher;
+use super::mqtt::MqttPublisher;
+use crate::seed::SeededRng;
+use crate::types::*;
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// First-phase publish rate at light intensity; other levels scale it by the multiplier.
+const BASE_RATE_HZ: f64 = 10.0;
+/// Size of oversized messages at light intensity, scaled like the rate and capped.
+const OVERSIZED_BYTES: usize = 1024 * 1024;
+const MAX_OVERSIZED_BYTES: usize = 16 * 1024 * 1024;
+const TICK: Duration = Duration::from_millis(50);
+/// Pause before reconnecting after the broker dropped or refused the connection.
+const RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+/// Backlog after which missed sends are dropped rather than published in a burst.
+const MAX_BACKLOG: Duration = Duration::from_secs(1);
+const MAX_PHASE_ERRORS: usize = 5;
+
+/// Outcome of one publish on a live connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery {
+    Acknowledged,
+    /// The broker answered with an error.
+    Rejected(String),
+}
+
+/// A broker connection; an `Err` from [`Publisher::publish`] means the connection is gone.
+pub trait Publisher {
+    fn publish(&mut self, payload: &[u8]) -> Result<Delivery>;
+}
+
+/// Background flood; [`MessageFlood::stop`] closes the connection and returns the statistics.
+pub struct MessageFlood {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<MessageFloodReport>,
+}
+
+/// Start flooding the topic; `window` is the attack length the phases are spread over.
+pub fn start(
+    config: &MessageFloodConfig,
+    intensity: IntensityLevel,
+    window: Duration,
+    seed: u64,
+) -> Result<MessageFlood> {
+    let endpoint = Endpoint::parse(&config.broker)?;
+    let plan = Plan::new(config, intensity, window);
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = stop.clone();
+    let handle = thread::spawn(move || run(&endpoint, &plan, intensity, seed, &flag));
+    Ok(MessageFlood { stop, handle })
+}
+
+impl MessageFlood {
+    pub fn stop(self) -> MessageFloodReport {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_else(|_| MessageFloodReport {
+            protocol: BrokerProtocol::Mqtt,
+            broker: String::new(),
+            topic: String::new(),
+            phases: Vec::new(),
+            correlation: None,
+            note: Some("message flood panicked".to_string()),
+        })
+    }
+}
+
+/// Name the flood phase a target failure `failed_at` into the attack fell in.
+pub fn correlate(report: &mut MessageFloodReport, failed_at: Duration) {
+    let position = report
+        .phases
+        .iter()
+        .rposition(|phase| phase.started_at <= failed_at);
+    let so_far = &report.phases[..position.map_or(0, |position| position + 1)];
+    let total = |count: fn(&FloodPhase) -> u64| so_far.iter().map(count).sum::<u64>();
+    let Some(phase) = so_far.last().filter(|_| total(|p| p.sent) > 0) else {
+        report.correlation = Some("target failed before the flood published anything".to_string());
+        return;
+    };
+    report.correlation = Some(format!(
+        "target failed {:.1}s into flood phase {} of {} ({:.0} msg/s target, {:.0} achieved); \
+         {} malformed, {} oversized, {} duplicate and {} out-of-order messages published by then",
+        (failed_at - phase.started_at).as_secs_f64(),
+        phase.index + 1,
+        report.phases.len(),
+        phase.target_rate_hz,
+        phase.achieved_rate_hz,
+        total(|p| p.malformed),
+        total(|p| p.oversized),
+        total(|p| p.duplicated),
+        total(|p| p.out_of_order),
+    ));
+}
+
+/// Broker address and topic from a `mqtt://` or `kafka://` URL.
+#[derive(Debug, Clone, PartialEq)]
+struct Endpoint {
+    protocol: BrokerProtocol,
+    address: String,
+    topic: String,
+}
+
+impl Endpoint {
+    fn parse(url: &str) -> Result<Self> {
+        let (scheme, rest) = url
+            .split_once("://")
+            .ok_or_else(|| anyhow!("message flood broker must be a mqtt:// or kafka:// URL"))?;
+        let (protocol, default_port) = match scheme {
+            "mqtt" => (BrokerProtocol::Mqtt, 1883),
+            "kafka" => (BrokerProtocol::Kafka, 9092),
+            other => return Err(anyhow!("unsupported message broker scheme '{}'", other)),
+        };
+        let (host, topic) = rest
+            .split_once('/')
+            .filter(|(host, topic)| !host.is_empty() && !topic.is_empty())
+            .ok_or_else(|| {
+                anyhow!(
+                    "broker URL needs a host and a topic: {}://host/topic",
+                    scheme
+                )
+            })?;
+        match protocol {
+            BrokerProtocol::Mqtt if topic.contains(['+', '#']) => {
+                return Err(anyhow!("cannot publish to MQTT wildcard topic '{}'", topic));
+            }
+            BrokerProtocol::Kafka
+                if topic.len() > 249
+                    || !topic
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')) =>
+            {
+                return Err(anyhow!("invalid Kafka topic name '{}'", topic));
+            }
+            _ => {}
+        }
+        // A port is present when the text after the last ':' is numeric (IPv6 stays intact).
+        let has_port = host
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| port.parse::<u16>().is_ok() && !host.ends_with(']'));
+        let address = if has_port {
+            host.to_string()
+        } else {
+            format!("{}:{}", host, default_port)
+        };
+        Ok(Self {
+            protocol,
+            address,
+            topic: topic.to_string(),
+        })
+    }
+
+    fn connect(&self, client_id: &str) -> Result<Box<dyn Publisher>> {
+        Ok(match self.protocol {
+            BrokerProtocol::Mqtt => Box::new(MqttPublisher::connect(
+                &self.address,
+                &self.topic,
+                client_id,
+            )?),
+            BrokerProtocol::Kafka => Box::new(KafkaPublisher::connect(
+                &self.address,
+                &self.topic,
+                client_id,
+            )?),
+        })
+    }
+}
+
+/// Phase layout: equal slices of the window, each at twice the previous rate.
+#[derive(Debug, Clone, PartialEq)]
+struct Plan {
+    phases: usize,
+    phase_length: Duration,
+    start_rate_hz: f64,
+}
+
+impl Plan {
+    fn new(config: &MessageFloodConfig, intensity: IntensityLevel, window: Duration) -> Self {
+        let phases = config.phases.max(1);
+        Self {
+            phases,
+            phase_length: window / phases as u32,
+            start_rate_hz: config
+                .start_rate_hz
+                .filter(|rate| *rate > 0.0)
+                .unwrap_or(BASE_RATE_HZ * intensity.multiplier()),
+        }
+    }
+
+    /// Phase running `elapsed` into the flood; the last phase continues past the window.
+    fn phase_at(&self, elapsed: Duration) -> usize {
+        if self.phase_length.is_zero() {
+            return self.phases - 1;
+        }
+        ((elapsed.as_secs_f64() / self.phase_length.as_secs_f64()) as usize).min(self.phases - 1)
+    }
+
+    fn rate(&self, phase: usize) -> f64 {
+        self.start_rate_hz * 2f64.powi(phase as i32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageKind {
+    Valid,
+    Malformed,
+    Oversized,
+    Duplicate,
+    OutOfOrder,
+}
+
+/// Produces the seeded message mix: valid envelopes with a rising sequence number, broken
+/// encodings, oversized bodies, repeats of the last valid message and stale sequence numbers.
+struct Messages {
+    rng: SeededRng,
+    seq: u64,
+    last_valid: Option<Vec<u8>>,
+    oversized_bytes: usize,
+}
+
+impl Messages {
+    fn new(seed: u64, intensity: IntensityLevel) -> Self {
+        Self {
+            rng: SeededRng::derive(seed, "flood"),
+            seq: 0,
+            last_valid: None,
+            oversized_bytes: ((OVERSIZED_BYTES as f64 * intensity.multiplier()) as usize)
+                .min(MAX_OVERSIZED_BYTES),
+        }
+    }
+
+    fn next(&mut self) -> (MessageKind, Vec<u8>) {
+        let kind = match self.rng.below(20) {
+            0..=7 => MessageKind::Valid,
+            8..=10 => MessageKind::Malformed,
+            11 => MessageKind::Oversized,
+            12..=15 if self.last_valid.is_some() => MessageKind::Duplicate,
+            16..=19 if self.seq > 1 => MessageKind::OutOfOrder,
+            _ => MessageKind::Valid,
+        };
+        let payload = match kind {
+            MessageKind::Valid => {
+                self.seq += 1;
+                let payload = envelope(self.seq, "");
+                self.last_valid = Some(payload.clone());
+                payload
+            }
+            MessageKind::Malformed => {
+                self.seq += 1;
+                match self.rng.below(5) {
+                    0 => {
+                        let mut payload = envelope(self.seq, "");
+                        payload.truncate(payload.len() / 2);
+                        payload
+                    }
+                    1 => vec![0xff, 0xfe, 0x00, 0xc3, 0x28, 0xa0, 0xa1],
+                    2 => format!(r#"{{"seq":"{}","sent_at_ms":null,"body":{{}}}}"#, self.seq)
+                        .into_bytes(),
+                    3 => "[".repeat(10_000).into_bytes(),
+                    _ => Vec::new(),
+                }
+            }
+            MessageKind::Oversized => {
+                self.seq += 1;
+                envelope(self.seq, &"A".repeat(self.oversized_bytes))
+            }
+            MessageKind::Duplicate => self.last_valid.clone().unwrap_or_default(),
+            MessageKind::OutOfOrder => {
+                let back = 1 + self.rng.below(10) as u64;
+                envelope(self.seq.saturating_sub(back).max(1), "")
+            }
+        };
+        (kind, payload)
+    }
+}
+
+fn envelope(seq: u64, body: &str) -> Vec<u8> {
+    let sent_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or(0);
+    format!(
+        r#"{{"seq":{},"source":"panic-attack","sent_at_ms":{},"body":"{}"}}"#,
+        seq, sent_at_ms, body
+    )
+    .into_bytes()
+}
+
+fn run(
+    endpoint: &Endpoint,
+    plan: &Plan,
+    intensity: IntensityLevel,
+    seed: u64,
+    stop: &AtomicBool,
+) -> MessageFloodReport {
+    let mut report = MessageFloodReport {
+        protocol: endpoint.protocol,
+        broker: endpoint.address.clone(),
+        topic: endpoint.topic.clone(),
+        phases: (0..plan.phases)
+            .map(|index| FloodPhase {
+                index,
+                started_at: plan.phase_length * index as u32,
+                target_rate_hz: plan.rate(index),
+                ..FloodPhase::default()
+            })
+            .collect(),
+        correlation: None,
+        note: None,
+    };
+    let client_id = format!("panic-attack-{:x}", seed);
+    let mut messages = Messages::new(seed, intensity);
+    let mut publisher: Option<Box<dyn Publisher>> = None;
+    let mut reconnect_at = Instant::now();
+    let start = Instant::now();
+    let mut next_send = start;
+
+    while !stop.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        let index = plan.phase_at(now - start);
+        let wait_until = next_send.max(reconnect_at);
+        if now < wait_until {
+            thread::sleep((wait_until - now).min(TICK));
+            continue;
+        }
+        if now - next_send > MAX_BACKLOG {
+            next_send = now;
+        }
+        next_send += Duration::from_secs_f64(1.0 / plan.rate(index));
+
+        let phase = &mut report.phases[index];
+        if publisher.is_none() {
+            match endpoint.connect(&client_id) {
+                Ok(connected) => publisher = Some(connected),
+                Err(err) => {
+                    phase.failed += 1;
+                    record_error(phase, format!("{:#}", err));
+                    reconnect_at = Instant::now() + RECONNECT_BACKOFF;
+                    continue;
+                }
+            }
+        }
+        let (kind, payload) = messages.next();
+        phase.sent += 1;
+        match kind {
+            MessageKind::Valid => {}
+            MessageKind::Malformed => phase.malformed += 1,
+            MessageKind::Oversized => phase.oversized += 1,
+            MessageKind::Duplicate => phase.duplicated += 1,
+            MessageKind::OutOfOrder => phase.out_of_order += 1,
+        }
+        match publisher.as_mut().expect("connected").publish(&payload) {
+            Ok(Delivery::Acknowledged) => phase.delivered += 1,
+            Ok(Delivery::Rejected(reason)) => {
+                phase.rejected += 1;
+                record_error(phase, reason);
+            }
+            Err(err) => {
+                phase.failed += 1;
+                record_error(phase, format!("connection lost: {:#}", err));
+                publisher = None;
+                reconnect_at = Instant::now() + RECONNECT_BACKOFF;
+            }
+        }
+    }
+    achieved_rates(&mut report.phases, start.elapsed());
+
+    let delivered: u64 = report.phases.iter().map(|phase| phase.delivered).sum();
+    let rejected: u64 = report.phases.iter().map(|phase| phase.rejected).sum();
+    if delivered == 0 && rejected == 0 {
+        report.note = Some(format!(
+            "broker {} never acknowledged a message",
+            report.broker
+        ));
+    }
+    report
+}
+
+/// Rate each phase reached over the part of it the flood actually ran.
+fn achieved_rates(phases: &mut [FloodPhase], elapsed: Duration) {
+    let ends: Vec<Duration> = phases
+        .iter()
+        .skip(1)
+        .map(|phase| phase.started_at.min(elapsed))
+        .chain([elapsed])
+        .collect();
+    for (phase, end) in phases.iter_mut().zip(ends) {
+        let length = end.saturating_sub(phase.started_at).as_secs_f64();
+        if length > 0.0 {
+            phase.achieved_rate_hz = phase.sent as f64 / length;
+        }
+    }
+}
+
+fn record_error(phase: &mut FloodPhase, error: String) {
+    if phase.errors.len() < MAX_PHASE_ERRORS && !phase.errors.contains(&error) {
+        phase.errors.push(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_parse_endpoint() {
+        let mqtt = Endpoint::parse("mqtt://broker.local/plant/line-1").unwrap();
+        assert_eq!(mqtt.protocol, BrokerProtocol::Mqtt);
+        assert_eq!(mqtt.address, "broker.local:1883");
+        assert_eq!(mqtt.topic, "plant/line-1");
+
+        let kafka = Endpoint::parse("kafka://127.0.0.1:29092/orders.v2").unwrap();
+        assert_eq!(kafka.protocol, BrokerProtocol::Kafka);
+        assert_eq!(kafka.address, "127.0.0.1:29092");
+
+        assert!(Endpoint::parse("amqp://rabbit/queue").is_err());
+        assert!(Endpoint::parse("mqtt://broker").is_err());
+        assert!(Endpoint::parse("mqtt://broker/sensors/#").is_err());
+        assert!(Endpoint::parse("kafka://broker/bad topic").is_err());
+    }
+
+    #[test]
+    fn test_plan_doubles_rate_per_phase() {
+        let config = MessageFloodConfig {
+            broker: "mqtt://localhost/t".to_string(),
+            phases: 4,
+            start_rate_hz: None,
+        };
+        let plan = Plan::new(&config, IntensityLevel::Medium, Duration::from_secs(8));
+        assert_eq!(plan.phase_length, Duration::from_secs(2));
+        assert_eq!(plan.rate(0), 50.0);
+        assert_eq!(plan.rate(3), 400.0);
+        assert_eq!(plan.phase_at(Duration::from_millis(4500)), 2);
+        assert_eq!(plan.phase_at(Duration::from_secs(30)), 3);
+    }
+
+    #[test]
+    fn test_message_mix_is_seeded_and_hostile() {
+        let sample = |seed| {
+            let mut messages = Messages::new(seed, IntensityLevel::Light);
+            (0..400).map(|_| messages.next()).collect::<Vec<_>>()
+        };
+        let first = sample(7);
+        let kinds: Vec<MessageKind> = first.iter().map(|(kind, _)| *kind).collect();
+        let second: Vec<MessageKind> = sample(7).iter().map(|(kind, _)| *kind).collect();
+        assert_eq!(kinds, second);
+        for kind in [
+            MessageKind::Valid,
+            MessageKind::Malformed,
+            MessageKind::Oversized,
+            MessageKind::Duplicate,
+            MessageKind::OutOfOrder,
+        ] {
+            assert!(kinds.contains(&kind), "{:?} never generated", kind);
+        }
+        let (_, oversized) = first
+            .iter()
+            .find(|(kind, _)| *kind == MessageKind::Oversized)
+            .unwrap();
+        assert!(oversized.len() > OVERSIZED_BYTES);
+    }
+
+    #[test]
+    fn test_unreachable_broker_and_correlation() {
+        // Grab a free port, then close it so connections are refused.
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let config = MessageFloodConfig {
+            broker: format!("mqtt://127.0.0.1:{}/t", port),
+            phases: 2,
+            start_rate_hz: Some(20.0),
+        };
+        let flood = start(
+            &config,
+            IntensityLevel::Light,
+            Duration::from_millis(400),
+            1,
+        )
+        .unwrap();
+        thread::sleep(Duration::from_millis(700));
+        let mut report = flood.stop();
+        assert_eq!(report.phases.len(), 2);
+        assert_eq!(report.phases[1].target_rate_hz, 40.0);
+        assert!(report.phases.iter().all(|phase| phase.delivered == 0));
+        assert!(report.phases[0].failed > 0);
+        assert!(report
+            .note
+            .as_deref()
+            .unwrap()
+            .contains("never acknowledged"));
+
+        report.phases[1].sent = 3;
+        report.phases[1].malformed = 1;
+        correlate(&mut report, Duration::from_millis(300));
+        let correlation = report.correlation.clone().unwrap();
+        assert!(correlation.contains("0.1s into flood phase 2 of 2 (40 msg/s target"));
+        assert!(correlation.contains("1 malformed"));
+        correlate(&mut report, Duration::from_millis(100));
+        assert!(report.correlation.unwrap().contains("before the flood"));
+    }
+}
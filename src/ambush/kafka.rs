@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Minimal Kafka producer for message floods
+//!
+//! Sends one-record Produce (v3) requests with `acks=1` to partition 0 of the topic on the
+//! given broker, encoded as a magic-2 record batch. There is no metadata lookup: when the
+//! broker does not lead partition 0 every message comes back rejected with
+//! `NOT_LEADER_OR_FOLLOWER`, so point the flood at the leader (a single-broker test cluster).
+
+use super::flood::{Delivery, Publisher};
+use anyhow::{anyhow, Context, Result};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+const PRODUCE: i16 = 0;
+const PRODUCE_VERSION: i16 = 3;
+const ACKS: i16 = 1;
+/// Server-side wait for the acknowledgement, in milliseconds.
+const ACK_TIMEOUT_MS: i32 = 5000;
+/// Largest response accepted; produce responses for one partition are tiny.
+const MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+
+pub struct KafkaPublisher {
+    stream: TcpStream,
+    topic: String,
+    client_id: String,
+    correlation_id: i32,
+}
+
+impl KafkaPublisher {
+    pub fn connect(address: &str, topic: &str, client_id: &str) -> Result<Self> {
+        let addr = address
+            .to_socket_addrs()
+            .with_context(|| format!("resolving {}", address))?
+            .next()
+            .ok_or_else(|| anyhow!("{} did not resolve", address))?;
+        let stream = TcpStream::connect_timeout(&addr, IO_TIMEOUT)
+            .with_context(|| format!("connecting to Kafka broker {}", address))?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            stream,
+            topic: topic.to_string(),
+            client_id: client_id.to_string(),
+            correlation_id: 0,
+        })
+    }
+}
+
+impl Publisher for KafkaPublisher {
+    fn publish(&mut self, payload: &[u8]) -> Result<Delivery> {
+        self.correlation_id = self.correlation_id.wrapping_add(1);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as i64)
+            .unwrap_or(0);
+        let request = produce_request(
+            self.correlation_id,
+            &self.client_id,
+            &self.topic,
+            &record_batch(payload, timestamp),
+        );
+        self.stream
+            .write_all(&request)
+            .context("writing produce request")?;
+
+        let mut size = [0u8; 4];
+        self.stream
+            .read_exact(&mut size)
+            .context("waiting for produce response")?;
+        let size = i32::from_be_bytes(size);
+        if size < 4 || size as usize > MAX_RESPONSE_BYTES {
+            return Err(anyhow!("implausible produce response size {}", size));
+        }
+        let mut response = vec![0u8; size as usize];
+        self.stream.read_exact(&mut response)?;
+        match produce_error(&response, self.correlation_id)? {
+            0 => Ok(Delivery::Acknowledged),
+            code => Ok(Delivery::Rejected(error_name(code))),
+        }
+    }
+}
+
+fn produce_request(correlation_id: i32, client_id: &str, topic: &str, batch: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(batch.len() + 64);
+    body.extend_from_slice(&PRODUCE.to_be_bytes());
+    body.extend_from_slice(&PRODUCE_VERSION.to_be_bytes());
+    body.extend_from_slice(&correlation_id.to_be_bytes());
+    put_string(&mut body, client_id);
+    body.extend_from_slice(&(-1i16).to_be_bytes()); // no transactional id
+    body.extend_from_slice(&ACKS.to_be_bytes());
+    body.extend_from_slice(&ACK_TIMEOUT_MS.to_be_bytes());
+    body.extend_from_slice(&1i32.to_be_bytes()); // one topic
+    put_string(&mut body, topic);
+    body.extend_from_slice(&1i32.to_be_bytes()); // one partition
+    body.extend_from_slice(&0i32.to_be_bytes());
+    body.extend_from_slice(&(batch.len() as i32).to_be_bytes());
+    body.extend_from_slice(batch);
+
+    let mut request = Vec::with_capacity(body.len() + 4);
+    request.extend_from_slice(&(body.len() as i32).to_be_bytes());
+    request.extend_from_slice(&body);
+    request
+}
+
+/// A record batch (magic 2) holding one record with no key or headers.
+fn record_batch(value: &[u8], timestamp_ms: i64) -> Vec<u8> {
+    let mut record = Vec::with_capacity(value.len() + 16);
+    record.push(0); // attributes
+    put_varint(&mut record, 0); // timestamp delta
+    put_varint(&mut record, 0); // offset delta
+    put_varint(&mut record, -1); // null key
+    put_varint(&mut record, value.len() as i64);
+    record.extend_from_slice(value);
+    put_varint(&mut record, 0); // no headers
+
+    // Everything the CRC covers: attributes through the records.
+    let mut checked = Vec::with_capacity(record.len() + 48);
+    checked.extend_from_slice(&0i16.to_be_bytes()); // attributes: no compression
+    checked.extend_from_slice(&0i32.to_be_bytes()); // last offset delta
+    checked.extend_from_slice(&timestamp_ms.to_be_bytes());
+    checked.extend_from_slice(&timestamp_ms.to_be_bytes());
+    checked.extend_from_slice(&(-1i64).to_be_bytes()); // producer id
+    checked.extend_from_slice(&(-1i16).to_be_bytes()); // producer epoch
+    checked.extend_from_slice(&(-1i32).to_be_bytes()); // base sequence
+    checked.extend_from_slice(&1i32.to_be_bytes()); // record count
+    put_varint(&mut checked, record.len() as i64);
+    checked.extend_from_slice(&record);
+
+    let mut batch = Vec::with_capacity(checked.len() + 21);
+    // Base offset, then a length counting everything after itself: epoch, magic, CRC and the
+    // checked bytes.
+    batch.extend_from_slice(&0i64.to_be_bytes());
+    batch.extend_from_slice(&((4 + 1 + 4 + checked.len()) as i32).to_be_bytes());
+    batch.extend_from_slice(&(-1i32).to_be_bytes()); // partition leader epoch
+    batch.push(2); // magic
+    batch.extend_from_slice(&crc32c(&checked).to_be_bytes());
+    batch.extend_from_slice(&checked);
+    batch
+}
+
+/// Error code of the first partition in a Produce v3 response body.
+fn produce_error(response: &[u8], correlation_id: i32) -> Result<i16> {
+    let mut cursor = Cursor(response);
+    if cursor.i32()? != correlation_id {
+        return Err(anyhow!("produce response for another request"));
+    }
+    if cursor.i32()? < 1 {
+        return Err(anyhow!("produce response without topics"));
+    }
+    let topic_len = cursor.i16()?;
+    cursor.skip(topic_len.max(0) as usize)?;
+    if cursor.i32()? < 1 {
+        return Err(anyhow!("produce response without partitions"));
+    }
+    cursor.i32()?; // partition
+    cursor.i16()
+}
+
+struct Cursor<'a>(&'a [u8]);
+
+impl Cursor<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        if self.0.len() < len {
+            return Err(anyhow!("truncated produce response"));
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into()?))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into()?))
+    }
+}
+
+fn error_name(code: i16) -> String {
+    let name = match code {
+        2 => "CORRUPT_MESSAGE",
+        3 => "UNKNOWN_TOPIC_OR_PARTITION",
+        6 => "NOT_LEADER_OR_FOLLOWER",
+        7 => "REQUEST_TIMED_OUT",
+        10 => "MESSAGE_TOO_LARGE",
+        19 => "NOT_ENOUGH_REPLICAS",
+        29 => "TOPIC_AUTHORIZATION_FAILED",
+        87 => "INVALID_RECORD",
+        _ => return format!("Kafka error code {}", code),
+    };
+    name.to_string()
+}
+
+fn put_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as i16).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Zigzag-encoded variable-length integer, as used inside record batches.
+fn put_varint(out: &mut Vec<u8>, value: i64) {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// CRC-32C (Castagnoli), the checksum of magic-2 record batches.
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn test_encoding_primitives() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        let mut out = Vec::new();
+        for value in [0, -1, 1, 300] {
+            put_varint(&mut out, value);
+        }
+        assert_eq!(out, [0x00, 0x01, 0x02, 0xd8, 0x04]);
+
+        let batch = record_batch(b"hello", 1_700_000_000_000);
+        let length = i32::from_be_bytes(batch[8..12].try_into().unwrap()) as usize;
+        assert_eq!(length, batch.len() - 12);
+        assert_eq!(batch[16], 2);
+        let crc = u32::from_be_bytes(batch[17..21].try_into().unwrap());
+        assert_eq!(crc, crc32c(&batch[21..]));
+        assert!(batch.ends_with(b"hello\x00"));
+    }
+
+    #[test]
+    fn test_produce_against_fake_broker() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let broker = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut topics = Vec::new();
+            for error_code in [0i16, 10] {
+                let mut size = [0u8; 4];
+                stream.read_exact(&mut size).unwrap();
+                let mut request = vec![0u8; i32::from_be_bytes(size) as usize];
+                stream.read_exact(&mut request).unwrap();
+                assert_eq!(&request[..4], &[0, 0, 0, 3]); // Produce v3
+                let correlation = &request[4..8];
+                let client_len = usize::from(u16::from_be_bytes([request[8], request[9]]));
+                // transactional id, acks, timeout, topic count
+                let topic_at = 10 + client_len + 2 + 2 + 4 + 4;
+                let topic_len = usize::from(u16::from_be_bytes([
+                    request[topic_at],
+                    request[topic_at + 1],
+                ]));
+                topics.push(
+                    String::from_utf8_lossy(&request[topic_at + 2..topic_at + 2 + topic_len])
+                        .to_string(),
+                );
+
+                let mut body = correlation.to_vec();
+                body.extend_from_slice(&1i32.to_be_bytes());
+                put_string(&mut body, "orders");
+                body.extend_from_slice(&1i32.to_be_bytes());
+                body.extend_from_slice(&0i32.to_be_bytes());
+                body.extend_from_slice(&error_code.to_be_bytes());
+                body.extend_from_slice(&0i64.to_be_bytes());
+                body.extend_from_slice(&(-1i64).to_be_bytes());
+                body.extend_from_slice(&0i32.to_be_bytes()); // throttle time
+                stream
+                    .write_all(&(body.len() as i32).to_be_bytes())
+                    .unwrap();
+                stream.write_all(&body).unwrap();
+            }
+            topics
+        });
+
+        let mut publisher = KafkaPublisher::connect(&address, "orders", "flood-test").unwrap();
+        assert_eq!(publisher.publish(b"{}").unwrap(), Delivery::Acknowledged);
+        assert_eq!(
+            publisher.publish(&[0u8; 2048]).unwrap(),
+            Delivery::Rejected("MESSAGE_TOO_LARGE".to_string())
+        );
+        assert_eq!(broker.join().unwrap(), ["orders", "orders"]);
+    }
+}
@@ -2,68 +2,215 @@
 
 //! Ambush execution: run a target program while applying ambient stressors.
 
+mod adaptive;
+mod affinity;
+mod browser;
+mod cgroup;
+mod conditions;
+mod database;
+mod flood;
+mod kafka;
+mod mqtt;
+mod signals;
 mod timeline;
 
-pub use timeline::{load_timeline_with_default, TimelinePlan};
+pub(crate) use browser::http_request;
+pub(crate) use conditions::clock_ticks;
+pub use signals::{canonical_signal, spawn_signal_storm};
+pub use timeline::{load_timeline_with_default, merge_timeline_runs, TimelinePlan};
 
-use crate::signatures::SignatureEngine;
+use adaptive::LoadLevel;
+use conditions::{ProcessSampler, ResourceRecorder, RuntimeSample};
+use timeline::TimelineEventPlan;
+
+use crate::attack::budget::{self, Allotment, Budget};
+use crate::attack::gpu::{self, GpuStressor};
+use crate::attack::telemetry::{self, TelemetrySampler};
+use crate::attack::{capability, custom, hang, progress};
+use crate::signatures::leak::{self, ResourcePoint};
+use crate::signatures::{backtrace, postmortem, SignatureEngine};
 use crate::types::*;
 use anyhow::{Context, Result};
+use browser::BrowserStorm;
+use database::DbChaos;
+use flood::MessageFlood;
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::path::PathBuf;
-use std::process::{Command, Output, Stdio};
+use std::process::{Child, Command, Output, Stdio};
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
     Arc, Mutex,
 };
 use std::thread::{self, JoinHandle};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
+
+const MEMORY_CHUNK_BYTES: u64 = 4 * 1024 * 1024;
+const DISK_FILE_BYTES: usize = 128 * 1024;
+const NETWORK_PAYLOAD_BYTES: usize = 4096;
+
+/// How often the timeline samples the target for trigger conditions.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
 
 struct StressHandle {
     stop: Arc<AtomicBool>,
     threads: Vec<JoinHandle<()>>,
     peak_memory: Arc<AtomicU64>,
+    signal_storm: Option<JoinHandle<SignalStormReport>>,
+    memory_pressure: Option<JoinHandle<MemoryPressureReport>>,
+    disk_throttle: Option<JoinHandle<DiskThrottleReport>>,
+    cpu_chaos: Option<JoinHandle<CpuChaosReport>>,
+    adaptive: Option<JoinHandle<AdaptiveReport>>,
+}
+
+struct StressOutcome {
+    peak_memory: u64,
+    signal_storm: Option<SignalStormReport>,
+    memory_pressure: Option<MemoryPressureReport>,
+    disk_throttle: Option<DiskThrottleReport>,
+    cpu_chaos: Option<CpuChaosReport>,
+    adaptive: Option<AdaptiveReport>,
 }
 
 impl StressHandle {
-    fn stop(self) -> u64 {
+    fn stop(self) -> StressOutcome {
         self.stop.store(true, Ordering::SeqCst);
         for handle in self.threads {
             let _ = handle.join();
         }
-        self.peak_memory.load(Ordering::Relaxed)
+        let memory_pressure = self.memory_pressure.and_then(|handle| handle.join().ok());
+        StressOutcome {
+            peak_memory: self.peak_memory.load(Ordering::Relaxed),
+            signal_storm: self.signal_storm.and_then(|handle| handle.join().ok()),
+            memory_pressure,
+            disk_throttle: self.disk_throttle.and_then(|handle| handle.join().ok()),
+            cpu_chaos: self.cpu_chaos.and_then(|handle| handle.join().ok()),
+            adaptive: self.adaptive.and_then(|handle| handle.join().ok()),
+        }
     }
 }
 
 pub fn execute(config: AttackConfig) -> Result<Vec<AttackResult>> {
     let mut results = Vec::new();
+    let blocked = capability::probe_axes(&config);
+    for (axis, reason) in &blocked {
+        tracing::warn!("Capability probe: skipping {:?} axis: {}", axis, reason);
+    }
+
+    let budget = config.timeout.map(Budget::start);
+    let mut remaining_runs = config.target_programs.len() * config.axes.len();
 
     for program in &config.target_programs {
         for axis in &config.axes {
-            println!(
+            let _axis =
+                tracing::info_span!("axis", program = %program.display(), axis = ?axis).entered();
+            let scoped = config.for_axis(axis);
+            let base = scoped.as_ref().unwrap_or(&config);
+            let allotment = budget
+                .as_ref()
+                .map(|budget| budget.allot(base.duration, remaining_runs));
+            remaining_runs -= 1;
+            let trimmed;
+            let (config, budget_note) = match allotment {
+                Some(Allotment::Exhausted { note }) => {
+                    tracing::warn!("Campaign timeout: {:?} axis {}", axis, note);
+                    results.push(budget::skipped_result(program, axis.clone(), note));
+                    continue;
+                }
+                Some(Allotment::Trimmed { duration, note }) => {
+                    tracing::warn!("Campaign timeout: {:?} axis {}", axis, note);
+                    trimmed = AttackConfig {
+                        duration,
+                        ..base.clone()
+                    };
+                    (&trimmed, Some(note))
+                }
+                Some(Allotment::Full) | None => (base, None),
+            };
+            tracing::info!(
                 "Ambushing {:?} on axis {:?} (intensity: {:?}, duration: {:?})",
-                program, axis, config.intensity, config.duration
+                program,
+                axis,
+                config.intensity,
+                config.duration
             );
+            if let Some(reason) = blocked.get(axis) {
+                results.push(capability::skipped_result(program, axis.clone(), reason));
+                continue;
+            }
 
-            let args = args_for_axis(&config, *axis);
+            let args = args_for_axis(config, axis.clone());
+            let telemetry_sampler = config.telemetry.then(telemetry::start);
+            let gpu_stressor = config
+                .gpu
+                .as_ref()
+                .map(|gpu| gpu::start(gpu, config.intensity));
+            let browser_storm = config
+                .browser
+                .as_ref()
+                .map(|browser| browser::start(browser, config.intensity, config.seed));
+            let db_chaos = config
+                .database
+                .as_ref()
+                .map(|db| database::start(db, config.intensity, config.seed))
+                .transpose()?;
+            let message_flood = config
+                .flood
+                .as_ref()
+                .map(|flood| flood::start(flood, config.intensity, config.duration, config.seed))
+                .transpose()?;
             let start = Instant::now();
+            let started_at = SystemTime::now();
 
-            let stress = start_stressor(*axis, config.intensity, config.duration);
-            let output = run_program_with_deadline(program, &args, config.duration)?;
-            let peak_memory = stress.stop();
+            // The target is spawned first so pid-directed stressors (signal storms) have a target.
+            let child = spawn_program(program, &args)?;
+            let pid = child.id();
+            let recorder = ResourceRecorder::start(pid, SAMPLE_INTERVAL);
+            let stress = start_stressor(
+                axis.clone(),
+                config.intensity,
+                config.duration,
+                child.id(),
+                config,
+            );
+            let hang::Supervised {
+                output,
+                hang,
+                stall,
+            } = hang::supervise(child, Some(config.duration), config.progress.as_ref())?;
+            let resources = recorder.stop();
+            let outcome = stress.stop();
+            let telemetry = telemetry_sampler.map(TelemetrySampler::stop);
+            let gpu = gpu_stressor.map(GpuStressor::stop);
+            let browser = browser_storm.map(BrowserStorm::stop);
+            let mut database = db_chaos.map(DbChaos::stop);
+            let mut flood = message_flood.map(MessageFlood::stop);
 
             let duration = start.elapsed();
             let exit_code = output.status.code();
-            let success = output.status.success();
+            let browser_crashes: Vec<CrashReport> =
+                browser.iter().flat_map(browser::crash_reports).collect();
+            // UI failures fail the run even when the server itself exited cleanly.
+            let success = output.status.success() && browser_crashes.is_empty();
+            if let Some(database) = database.as_mut().filter(|_| !success) {
+                database::correlate(database, duration);
+            }
+            if let Some(flood) = flood.as_mut().filter(|_| !success) {
+                flood::correlate(flood, duration);
+            }
 
             let mut crashes = Vec::new();
-            if !success {
-                crashes.push(crash_from_output(&output));
+            // A target stopped by its progress check was killed by us, not crashed.
+            if !output.status.success() && stall.is_none() {
+                let mut crash = crash_from_output(&output);
+                postmortem::attach(&mut crash, &output, program, Some(pid), started_at);
+                crashes.push(crash);
             }
+            crashes.extend(browser_crashes);
 
-            let signatures_detected = if !crashes.is_empty() {
+            let mut signatures_detected: Vec<BugSignature> = if !crashes.is_empty() {
                 let engine = SignatureEngine::new();
                 crashes
                     .iter()
@@ -72,18 +219,40 @@ pub fn execute(config: AttackConfig) -> Result<Vec<AttackResult>> {
             } else {
                 Vec::new()
             };
+            signatures_detected.extend(hang.as_ref().and_then(hang::signature));
+            signatures_detected.extend(stall.as_ref().map(progress::signature));
+            signatures_detected.extend(leak::detect(&resources));
+
+            let signal_hz = outcome
+                .signal_storm
+                .as_ref()
+                .map(|storm| storm.frequency_hz);
+            let profile = stress_profile(axis.clone(), config, args, signal_hz);
 
             results.push(AttackResult {
                 program: program.clone(),
-                axis: *axis,
+                axis: axis.clone(),
                 success,
-                skipped: false,
-                skip_reason: None,
                 exit_code,
                 duration,
-                peak_memory,
+                peak_memory: outcome.peak_memory,
                 crashes,
                 signatures_detected,
+                signal_storm: outcome.signal_storm,
+                memory_pressure: outcome.memory_pressure,
+                disk_throttle: outcome.disk_throttle,
+                cpu_chaos: outcome.cpu_chaos,
+                adaptive: outcome.adaptive,
+                stress_profile: Some(profile),
+                hang,
+                stall,
+                telemetry,
+                gpu,
+                browser,
+                database,
+                flood,
+                budget_note,
+                ..Default::default()
             });
         }
     }
@@ -101,73 +270,194 @@ pub fn execute_timeline(
         .or_else(|| config.target_programs.first().cloned())
         .ok_or_else(|| anyhow::anyhow!("no program specified for ambush timeline"))?;
     config.target_programs = vec![program.clone()];
+    // Overlapping events would run competing controllers on one metric; the timeline already
+    // shapes the load, so adaptive intensity only applies to plain ambush runs.
+    if config.adaptive.take().is_some() {
+        tracing::warn!("adaptive intensity is ignored for timeline runs");
+    }
 
+    let spawned_at = SystemTime::now();
+    let child = spawn_program(&program, &config.common_args)?;
+    let pid = child.id();
     let timeline_start = Instant::now();
     let stop = Arc::new(AtomicBool::new(false));
     let reports: Arc<Mutex<Vec<TimelineEventReport>>> = Arc::new(Mutex::new(Vec::new()));
+    // Triggers read the latest target sample; `finished` records each event's end (None when
+    // it never ran) so `after` dependents can start or give up.
+    let sample = Arc::new(Mutex::new(RuntimeSample::default()));
+    let finished: Arc<Mutex<HashMap<String, Option<Instant>>>> = Arc::default();
+    let stopped_by: Arc<Mutex<Option<String>>> = Arc::default();
     let mut handles = Vec::new();
 
+    let sampler = {
+        let stop = stop.clone();
+        let sample = sample.clone();
+        let stopped_by = stopped_by.clone();
+        let stop_when = timeline.stop_when.clone();
+        thread::spawn(move || {
+            let mut sampler = ProcessSampler::new(pid, timeline_start);
+            let mut resources = Vec::new();
+            while !stop.load(Ordering::Relaxed) {
+                let latest = sampler.sample();
+                resources.push(ResourcePoint::from(&latest));
+                *sample.lock().expect("timeline sample lock") = latest;
+                if let Some(condition) = stop_when.as_ref().filter(|c| c.holds(&latest)) {
+                    *stopped_by.lock().expect("timeline stop lock") = Some(format!(
+                        "{} at {:.2}s",
+                        condition,
+                        latest.elapsed.as_secs_f64()
+                    ));
+                    stop.store(true, Ordering::SeqCst);
+                    break;
+                }
+                thread::sleep(SAMPLE_INTERVAL);
+            }
+            resources
+        })
+    };
+
     for event in &timeline.events {
         let event = event.clone();
         let stop = stop.clone();
         let reports = reports.clone();
+        let sample = sample.clone();
+        let finished = finished.clone();
+        let stressors = config.clone();
         let handle = thread::spawn(move || {
-            if wait_until(timeline_start + event.start_offset, &stop) {
-                let stress = start_stressor(event.axis, event.intensity, event.duration);
-                let deadline = Instant::now() + event.duration;
+            let mut report = TimelineEventReport {
+                id: event.id.clone(),
+                axis: event.axis.clone(),
+                start_offset: event.start_offset,
+                duration: event.duration,
+                intensity: event.intensity,
+                args: event.args.clone(),
+                group: event.group.clone(),
+                iteration: event.iteration,
+                ran: false,
+                ..Default::default()
+            };
+
+            let started = wait_for_trigger(&event, timeline_start, &finished, &sample, &stop);
+            let ran = started.is_some();
+            if let Some((started_at, trigger)) = started {
+                let stress = start_stressor(
+                    event.axis.clone(),
+                    event.intensity,
+                    event.duration,
+                    pid,
+                    &stressors,
+                );
+                let deadline = started_at + event.duration;
                 while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+                    if let Some(condition) = &event.stop_when {
+                        let latest = *sample.lock().expect("timeline sample lock");
+                        if condition.holds(&latest) {
+                            report.stopped_by = Some(format!(
+                                "{} at {:.2}s",
+                                condition,
+                                latest.elapsed.as_secs_f64()
+                            ));
+                            break;
+                        }
+                    }
                     thread::sleep(Duration::from_millis(25));
                 }
-                let peak_memory = stress.stop();
-                let mut reports = reports.lock().expect("timeline report lock");
-                reports.push(TimelineEventReport {
-                    id: event.id,
-                    axis: event.axis,
-                    start_offset: event.start_offset,
-                    duration: event.duration,
-                    intensity: event.intensity,
-                    args: event.args,
-                    peak_memory: if event.axis == AttackAxis::Memory {
-                        Some(peak_memory)
-                    } else {
-                        None
-                    },
-                    ran: true,
-                });
-            } else {
-                let mut reports = reports.lock().expect("timeline report lock");
-                reports.push(TimelineEventReport {
-                    id: event.id,
-                    axis: event.axis,
-                    start_offset: event.start_offset,
-                    duration: event.duration,
-                    intensity: event.intensity,
-                    args: event.args,
-                    peak_memory: None,
-                    ran: false,
-                });
+                let outcome = stress.stop();
+                if trigger.is_some() {
+                    report.start_offset = started_at.duration_since(timeline_start);
+                }
+                report.trigger = trigger;
+                report.peak_memory =
+                    (event.axis == AttackAxis::Memory).then_some(outcome.peak_memory);
+                report.ran = true;
+                report.signal_storm = outcome.signal_storm;
+                report.memory_pressure = outcome.memory_pressure;
+                report.disk_throttle = outcome.disk_throttle;
+                report.cpu_chaos = outcome.cpu_chaos;
             }
+
+            finished
+                .lock()
+                .expect("timeline finish lock")
+                .insert(event.id.clone(), ran.then(Instant::now));
+            reports.lock().expect("timeline report lock").push(report);
         });
         handles.push(handle);
     }
 
+    let telemetry_sampler = config.telemetry.then(telemetry::start);
+    let gpu_stressor = config
+        .gpu
+        .as_ref()
+        .map(|gpu| gpu::start(gpu, config.intensity));
+    let browser_storm = config
+        .browser
+        .as_ref()
+        .map(|browser| browser::start(browser, config.intensity, config.seed));
+    let db_chaos = config
+        .database
+        .as_ref()
+        .map(|db| database::start(db, config.intensity, config.seed))
+        .transpose()?;
+    let message_flood = config
+        .flood
+        .as_ref()
+        .map(|flood| flood::start(flood, config.intensity, timeline.duration, config.seed))
+        .transpose()?;
     let start = Instant::now();
-    let output = run_program_with_deadline(&program, &config.common_args, timeline.duration)?;
+    let hang::Supervised {
+        output,
+        hang,
+        stall,
+    } = hang::supervise(child, Some(timeline.duration), config.progress.as_ref())?;
+    let exited_at = timeline_start.elapsed();
     stop.store(true, Ordering::SeqCst);
     for handle in handles {
         let _ = handle.join();
     }
+    let resources = sampler.join().unwrap_or_default();
+    let telemetry = telemetry_sampler.map(TelemetrySampler::stop);
+    let gpu = gpu_stressor.map(GpuStressor::stop);
+    let browser = browser_storm.map(BrowserStorm::stop);
+    let mut database = db_chaos.map(DbChaos::stop);
+    let mut flood = message_flood.map(MessageFlood::stop);
+    let global_stop = stopped_by.lock().expect("timeline stop lock").clone();
 
     let duration = start.elapsed();
     let exit_code = output.status.code();
-    let success = output.status.success();
+    let browser_crashes: Vec<CrashReport> =
+        browser.iter().flat_map(browser::crash_reports).collect();
+    // UI failures fail the run even when the server itself exited cleanly.
+    let success = output.status.success() && browser_crashes.is_empty();
+    if let Some(database) = database.as_mut().filter(|_| !success) {
+        database::correlate(database, duration);
+    }
+    if let Some(flood) = flood.as_mut().filter(|_| !success) {
+        flood::correlate(flood, duration);
+    }
+
+    let event_reports = {
+        let mut reports = reports.lock().expect("timeline report lock");
+        reports.sort_by_key(|report| report.start_offset);
+        reports.clone()
+    };
 
     let mut crashes = Vec::new();
-    if !success {
-        crashes.push(crash_from_output(&output));
+    if !output.status.success() && stall.is_none() {
+        let mut crash = crash_from_output(&output);
+        postmortem::attach(&mut crash, &output, &program, Some(pid), spawned_at);
+        let ended: HashMap<String, Option<Duration>> = finished
+            .lock()
+            .expect("timeline finish lock")
+            .iter()
+            .map(|(id, end)| (id.clone(), end.map(|end| end - timeline_start)))
+            .collect();
+        crash.active_stressors = timeline::active_at(&event_reports, &ended, exited_at);
+        crashes.push(crash);
     }
+    crashes.extend(browser_crashes);
 
-    let signatures_detected = if !crashes.is_empty() {
+    let mut signatures_detected: Vec<BugSignature> = if !crashes.is_empty() {
         let engine = SignatureEngine::new();
         crashes
             .iter()
@@ -176,12 +466,9 @@ pub fn execute_timeline(
     } else {
         Vec::new()
     };
-
-    let event_reports = {
-        let mut reports = reports.lock().expect("timeline report lock");
-        reports.sort_by_key(|report| report.start_offset);
-        reports.clone()
-    };
+    signatures_detected.extend(hang.as_ref().and_then(hang::signature));
+    signatures_detected.extend(stall.as_ref().map(progress::signature));
+    signatures_detected.extend(leak::detect(&resources));
 
     let peak_memory = event_reports
         .iter()
@@ -193,13 +480,19 @@ pub fn execute_timeline(
         program,
         axis: AttackAxis::Time,
         success,
-        skipped: false,
-        skip_reason: None,
         exit_code,
         duration,
         peak_memory,
         crashes,
         signatures_detected,
+        hang,
+        stall,
+        telemetry,
+        gpu,
+        browser,
+        database,
+        flood,
+        ..Default::default()
     }];
 
     Ok((
@@ -207,18 +500,77 @@ pub fn execute_timeline(
         TimelineReport {
             duration: timeline.duration,
             events: event_reports,
+            stopped_by: global_stop,
+            runs: None,
+            attribution: Vec::new(),
         },
     ))
 }
 
 fn args_for_axis(config: &AttackConfig, axis: AttackAxis) -> Vec<String> {
     let mut args = config.common_args.clone();
-    if let Some(axis_args) = config.axis_args.get(&axis) {
-        args.extend(axis_args.clone());
-    }
+    args.extend(custom::args(config, &axis));
     args
 }
 
+/// Block until `event` may start: its `after` dependency has finished, its offset has passed
+/// and its `start_when` holds. Returns the start instant and a description of what triggered
+/// it, or `None` if the timeline stopped first or the dependency never ran.
+fn wait_for_trigger(
+    event: &TimelineEventPlan,
+    timeline_start: Instant,
+    finished: &Mutex<HashMap<String, Option<Instant>>>,
+    sample: &Mutex<RuntimeSample>,
+    stop: &AtomicBool,
+) -> Option<(Instant, Option<String>)> {
+    let mut triggers = Vec::new();
+    let ready_at = match &event.after {
+        Some(dependency) => loop {
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+            let ended = finished
+                .lock()
+                .expect("timeline finish lock")
+                .get(dependency)
+                .copied();
+            match ended {
+                Some(Some(end)) => {
+                    triggers.push(format!("after {}", dependency));
+                    break end + event.start_offset;
+                }
+                Some(None) => return None,
+                None => thread::sleep(Duration::from_millis(25)),
+            }
+        },
+        None => timeline_start + event.start_offset,
+    };
+    if !wait_until(ready_at, stop) {
+        return None;
+    }
+
+    if let Some(condition) = &event.start_when {
+        loop {
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+            let latest = *sample.lock().expect("timeline sample lock");
+            if condition.holds(&latest) {
+                triggers.push(format!(
+                    "{} at {:.2}s",
+                    condition,
+                    latest.elapsed.as_secs_f64()
+                ));
+                break;
+            }
+            thread::sleep(Duration::from_millis(25));
+        }
+    }
+
+    let trigger = (!triggers.is_empty()).then(|| triggers.join("; "));
+    Some((Instant::now(), trigger))
+}
+
 fn wait_until(target: Instant, stop: &AtomicBool) -> bool {
     while Instant::now() < target {
         if stop.load(Ordering::Relaxed) {
@@ -229,26 +581,177 @@ fn wait_until(target: Instant, stop: &AtomicBool) -> bool {
     !stop.load(Ordering::Relaxed)
 }
 
-fn start_stressor(axis: AttackAxis, intensity: IntensityLevel, duration: Duration) -> StressHandle {
+fn start_stressor(
+    axis: AttackAxis,
+    intensity: IntensityLevel,
+    duration: Duration,
+    pid: u32,
+    config: &AttackConfig,
+) -> StressHandle {
     let stop = Arc::new(AtomicBool::new(false));
     let peak_memory = Arc::new(AtomicU64::new(0));
     let deadline = Instant::now() + duration;
 
+    let signal_storm = if axis == AttackAxis::Signal {
+        Some(spawn_signal_storm(
+            pid,
+            config.signal_storm.clone(),
+            intensity,
+            deadline,
+            stop.clone(),
+            config.seed,
+        ))
+    } else {
+        None
+    };
+
+    let disk_throttle = if axis == AttackAxis::Disk && config.disk_throttle.is_enabled() {
+        Some(start_io_throttle(
+            pid,
+            &config.disk_throttle,
+            duration,
+            stop.clone(),
+        ))
+    } else {
+        None
+    };
+
+    let cpu_chaos = if axis == AttackAxis::Cpu && config.cpu_chaos.enabled {
+        Some(affinity::spawn_cpu_chaos(
+            pid,
+            config.cpu_chaos.clone(),
+            deadline,
+            stop.clone(),
+            config.seed,
+        ))
+    } else {
+        None
+    };
+
+    // Without a controller the level stays at 1.0 and pacing is a no-op.
+    let level = Arc::new(LoadLevel::new(1.0));
+    let adaptive = config.adaptive.clone().map(|adaptive| {
+        if matches!(
+            axis,
+            AttackAxis::Time | AttackAxis::Signal | AttackAxis::Custom(_)
+        ) || (axis == AttackAxis::Memory
+            && config.memory_pressure.mode == MemoryPressureMode::Cgroup)
+        {
+            let note = format!("{:?} axis has no adjustable load", axis);
+            return thread::spawn(move || AdaptiveReport {
+                metric: adaptive.metric,
+                setpoint: adaptive.setpoint,
+                note: Some(note),
+                ..Default::default()
+            });
+        }
+        adaptive::spawn_controller(pid, adaptive, level.clone(), deadline, stop.clone())
+    });
+
+    let mut memory_pressure = None;
     let threads = match axis {
-        AttackAxis::Cpu => spawn_cpu_stress(stop.clone(), deadline, intensity),
-        AttackAxis::Memory => {
-            spawn_memory_stress(stop.clone(), deadline, intensity, peak_memory.clone())
+        AttackAxis::Cpu => spawn_cpu_stress(stop.clone(), deadline, intensity, level.clone()),
+        AttackAxis::Memory if config.memory_pressure.mode == MemoryPressureMode::Cgroup => {
+            match cgroup::TargetCgroup::attach(pid, "memory") {
+                Ok(target_cgroup) => {
+                    memory_pressure = Some(cgroup::spawn_cgroup_pressure(
+                        target_cgroup,
+                        config.memory_pressure.clone(),
+                        intensity,
+                        duration,
+                        stop.clone(),
+                        peak_memory.clone(),
+                    ));
+                    Vec::new()
+                }
+                Err(err) => {
+                    // Without a delegated cgroup, fall back to host pressure and say so.
+                    let note = format!(
+                        "cgroup mode unavailable ({:#}); fell back to self-allocation",
+                        err
+                    );
+                    memory_pressure = Some(thread::spawn(move || MemoryPressureReport {
+                        mode: MemoryPressureMode::Allocate,
+                        note: Some(note),
+                        ..Default::default()
+                    }));
+                    spawn_memory_stress(
+                        stop.clone(),
+                        deadline,
+                        intensity,
+                        peak_memory.clone(),
+                        level.clone(),
+                    )
+                }
+            }
+        }
+        AttackAxis::Memory => spawn_memory_stress(
+            stop.clone(),
+            deadline,
+            intensity,
+            peak_memory.clone(),
+            level.clone(),
+        ),
+        AttackAxis::Disk => spawn_disk_stress(stop.clone(), deadline, intensity, level.clone()),
+        AttackAxis::Network => {
+            spawn_network_stress(stop.clone(), deadline, intensity, level.clone())
         }
-        AttackAxis::Disk => spawn_disk_stress(stop.clone(), deadline, intensity),
-        AttackAxis::Network => spawn_network_stress(stop.clone(), deadline, intensity),
-        AttackAxis::Concurrency => spawn_concurrency_stress(stop.clone(), deadline, intensity),
-        AttackAxis::Time => Vec::new(),
+        AttackAxis::Concurrency => {
+            spawn_concurrency_stress(stop.clone(), deadline, intensity, level.clone())
+        }
+        AttackAxis::Time | AttackAxis::Signal => Vec::new(),
+        AttackAxis::Custom(_) => custom::spec(config, &axis)
+            .and_then(|spec| {
+                custom::spawn_stressor(
+                    spec,
+                    intensity,
+                    duration,
+                    config.seed,
+                    Some(pid),
+                    stop.clone(),
+                )
+            })
+            .into_iter()
+            .collect(),
     };
 
     StressHandle {
         stop,
         threads,
         peak_memory,
+        signal_storm,
+        memory_pressure,
+        disk_throttle,
+        cpu_chaos,
+        adaptive,
+    }
+}
+
+fn start_io_throttle(
+    pid: u32,
+    throttle: &DiskThrottleConfig,
+    duration: Duration,
+    stop: Arc<AtomicBool>,
+) -> JoinHandle<DiskThrottleReport> {
+    let data_dir = throttle
+        .data_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    let setup = cgroup::block_device_for(&data_dir).and_then(|device| {
+        cgroup::TargetCgroup::attach(pid, "io").map(|target_cgroup| (target_cgroup, device))
+    });
+    match setup {
+        Ok((target_cgroup, device)) => {
+            cgroup::spawn_io_throttle(target_cgroup, device, throttle.clone(), duration, stop)
+        }
+        Err(err) => {
+            // The host-side disk stressor still runs; only the target throttle is missing.
+            let note = format!("io.max throttle unavailable ({:#})", err);
+            thread::spawn(move || DiskThrottleReport {
+                note: Some(note),
+                ..Default::default()
+            })
+        }
     }
 }
 
@@ -256,16 +759,21 @@ fn spawn_cpu_stress(
     stop: Arc<AtomicBool>,
     deadline: Instant,
     intensity: IntensityLevel,
+    level: Arc<LoadLevel>,
 ) -> Vec<JoinHandle<()>> {
     let workers = worker_count(intensity);
     (0..workers)
         .map(|_| {
             let stop = stop.clone();
+            let level = level.clone();
             thread::spawn(move || {
                 let mut acc: u64 = 0x1234_5678;
                 while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
-                    acc = acc.wrapping_mul(1664525).wrapping_add(1013904223);
+                    for _ in 0..1024 {
+                        acc = acc.wrapping_mul(1664525).wrapping_add(1013904223);
+                    }
                     std::hint::black_box(acc);
+                    level.pace();
                 }
             })
         })
@@ -276,15 +784,18 @@ fn spawn_concurrency_stress(
     stop: Arc<AtomicBool>,
     deadline: Instant,
     intensity: IntensityLevel,
+    level: Arc<LoadLevel>,
 ) -> Vec<JoinHandle<()>> {
-    let workers = (50.0 * intensity.multiplier()).max(1.0) as usize;
+    let workers = concurrency_workers(intensity);
     (0..workers)
         .map(|_| {
             let stop = stop.clone();
+            let level = level.clone();
             thread::spawn(move || {
                 while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
                     std::hint::black_box(Instant::now());
                     thread::sleep(Duration::from_millis(5));
+                    level.pace();
                 }
             })
         })
@@ -296,27 +807,34 @@ fn spawn_memory_stress(
     deadline: Instant,
     intensity: IntensityLevel,
     peak_memory: Arc<AtomicU64>,
+    level: Arc<LoadLevel>,
 ) -> Vec<JoinHandle<()>> {
     vec![thread::spawn(move || {
-        let target_bytes = (64_u64 * 1024 * 1024) * intensity.multiplier() as u64;
-        let chunk = 4_u64 * 1024 * 1024;
+        let target_bytes = memory_target_bytes(intensity);
+        let chunk = MEMORY_CHUNK_BYTES;
         let mut allocated = 0_u64;
+        let mut exhausted = false;
         let mut buffers: Vec<Vec<u8>> = Vec::new();
 
-        while !stop.load(Ordering::Relaxed) && Instant::now() < deadline && allocated < target_bytes
-        {
-            let mut buf: Vec<u8> = Vec::new();
-            if buf.try_reserve_exact(chunk as usize).is_err() {
-                break;
-            }
-            buf.resize(chunk as usize, 0);
-            buffers.push(buf);
-            allocated += chunk;
-            peak_memory.store(allocated, Ordering::Relaxed);
-        }
-
+        // Grow (or, under an adaptive controller, shrink) toward the current share of the target.
         while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
-            thread::sleep(Duration::from_millis(50));
+            let wanted = (target_bytes as f64 * level.get()) as u64;
+            if !exhausted && allocated + chunk <= wanted {
+                let mut buf: Vec<u8> = Vec::new();
+                if buf.try_reserve_exact(chunk as usize).is_err() {
+                    exhausted = true;
+                    continue;
+                }
+                buf.resize(chunk as usize, 0);
+                buffers.push(buf);
+                allocated += chunk;
+                peak_memory.fetch_max(allocated, Ordering::Relaxed);
+            } else if allocated > wanted + chunk {
+                buffers.pop();
+                allocated -= chunk;
+            } else {
+                thread::sleep(Duration::from_millis(50));
+            }
         }
         drop(buffers);
     })]
@@ -326,12 +844,13 @@ fn spawn_disk_stress(
     stop: Arc<AtomicBool>,
     deadline: Instant,
     intensity: IntensityLevel,
+    level: Arc<LoadLevel>,
 ) -> Vec<JoinHandle<()>> {
     vec![thread::spawn(move || {
         let root = std::env::temp_dir().join(format!("panic-attack-ambush-{}", std::process::id()));
         let _ = fs::create_dir_all(&root);
-        let files_per_cycle = (25.0 * intensity.multiplier()).max(1.0) as usize;
-        let payload = vec![0xA5_u8; 128 * 1024];
+        let files_per_cycle = disk_files_per_cycle(intensity);
+        let payload = vec![0xA5_u8; DISK_FILE_BYTES];
         let mut counter = 0_u64;
 
         while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
@@ -344,6 +863,7 @@ fn spawn_disk_stress(
                 if let Ok(mut file) = File::create(&path) {
                     let _ = file.write_all(&payload);
                 }
+                level.pace();
             }
         }
 
@@ -355,6 +875,7 @@ fn spawn_network_stress(
     stop: Arc<AtomicBool>,
     deadline: Instant,
     intensity: IntensityLevel,
+    level: Arc<LoadLevel>,
 ) -> Vec<JoinHandle<()>> {
     let listener = TcpListener::bind("127.0.0.1:0");
     let Ok(listener) = listener else {
@@ -383,20 +904,21 @@ fn spawn_network_stress(
         }
     });
 
-    let clients = (20.0 * intensity.multiplier()).max(1.0) as usize;
+    let clients = network_clients(intensity);
     let mut threads = Vec::with_capacity(clients + 1);
     threads.push(server);
 
     for _ in 0..clients {
         let stop = stop.clone();
-        let addr = addr.clone();
+        let level = level.clone();
         threads.push(thread::spawn(move || {
-            let payload = vec![0x5A_u8; 4096];
+            let payload = vec![0x5A_u8; NETWORK_PAYLOAD_BYTES];
             while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
                 if let Ok(mut stream) = TcpStream::connect(addr) {
                     let _ = stream.write_all(&payload);
                 }
                 thread::sleep(Duration::from_millis(10));
+                level.pace();
             }
         }));
     }
@@ -404,39 +926,82 @@ fn spawn_network_stress(
     threads
 }
 
-fn worker_count(intensity: IntensityLevel) -> usize {
-    let base = std::thread::available_parallelism()
+/// Resolved parameters for one ambush axis, using the same sizing as the stressors.
+fn stress_profile(
+    axis: AttackAxis,
+    config: &AttackConfig,
+    args: Vec<String>,
+    signal_hz: Option<f64>,
+) -> StressProfile {
+    let intensity = config.intensity;
+    let mut profile = StressProfile::new(intensity, config.duration, config.seed);
+    profile.target_args = args;
+    match axis {
+        AttackAxis::Cpu => {
+            profile.host_parallelism = Some(host_parallelism());
+            profile.workers = Some(worker_count(intensity));
+        }
+        AttackAxis::Concurrency => profile.workers = Some(concurrency_workers(intensity)),
+        AttackAxis::Memory => {
+            // Cgroup mode squeezes the target instead of allocating; its report has the limits.
+            if config.memory_pressure.mode != MemoryPressureMode::Cgroup {
+                profile.memory_target_bytes = Some(memory_target_bytes(intensity));
+                profile.memory_chunk_bytes = Some(MEMORY_CHUNK_BYTES);
+            }
+        }
+        AttackAxis::Disk => {
+            profile.disk_files_per_cycle = Some(disk_files_per_cycle(intensity));
+            profile.disk_file_bytes = Some(DISK_FILE_BYTES as u64);
+        }
+        AttackAxis::Network => {
+            profile.network_clients = Some(network_clients(intensity));
+            profile.network_payload_bytes = Some(NETWORK_PAYLOAD_BYTES as u64);
+        }
+        AttackAxis::Signal => profile.signal_hz = signal_hz,
+        AttackAxis::Time | AttackAxis::Custom(_) => {}
+    }
+    profile
+}
+
+fn concurrency_workers(intensity: IntensityLevel) -> usize {
+    (50.0 * intensity.multiplier()).max(1.0) as usize
+}
+
+pub(crate) fn memory_target_bytes(intensity: IntensityLevel) -> u64 {
+    (64_u64 * 1024 * 1024) * intensity.multiplier() as u64
+}
+
+fn disk_files_per_cycle(intensity: IntensityLevel) -> usize {
+    (25.0 * intensity.multiplier()).max(1.0) as usize
+}
+
+/// Scratch space one disk-stressor cycle writes before deleting it again.
+pub(crate) fn disk_bytes_per_cycle(intensity: IntensityLevel) -> u64 {
+    (disk_files_per_cycle(intensity) * DISK_FILE_BYTES) as u64
+}
+
+fn network_clients(intensity: IntensityLevel) -> usize {
+    (20.0 * intensity.multiplier()).max(1.0) as usize
+}
+
+fn host_parallelism() -> usize {
+    std::thread::available_parallelism()
         .map(|n| n.get())
-        .unwrap_or(1);
-    (base as f64 * intensity.multiplier()).max(1.0) as usize
+        .unwrap_or(1)
 }
 
-fn run_program_with_deadline(
-    program: &PathBuf,
-    args: &[String],
-    duration: Duration,
-) -> Result<Output> {
-    let mut child = Command::new(program)
+fn worker_count(intensity: IntensityLevel) -> usize {
+    (host_parallelism() as f64 * intensity.multiplier()).max(1.0) as usize
+}
+
+fn spawn_program(program: &PathBuf, args: &[String]) -> Result<Child> {
+    Command::new(program)
         .args(args)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .with_context(|| format!("Failed to execute program {}", program.display()))?;
-
-    let start = Instant::now();
-    loop {
-        if let Some(_status) = child.try_wait()? {
-            break;
-        }
-        if start.elapsed() >= duration {
-            let _ = child.kill();
-            break;
-        }
-        thread::sleep(Duration::from_millis(20));
-    }
-
-    Ok(child.wait_with_output()?)
+        .with_context(|| format!("Failed to execute program {}", program.display()))
 }
 
 fn crash_from_output(output: &Output) -> CrashReport {
@@ -446,6 +1011,8 @@ fn crash_from_output(output: &Output) -> CrashReport {
         backtrace: extract_backtrace(&output.stderr),
         stderr: String::from_utf8_lossy(&output.stderr).to_string(),
         stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        frames: backtrace::parse(&String::from_utf8_lossy(&output.stderr)),
+        ..Default::default()
     }
 }
 
@@ -464,7 +1031,7 @@ fn extract_signal(stderr: &[u8]) -> Option<String> {
 
 fn extract_backtrace(stderr: &[u8]) -> Option<String> {
     let stderr_str = String::from_utf8_lossy(stderr);
-    if stderr_str.contains("backtrace") || stderr_str.contains("stack backtrace") {
+    if stderr_str.contains("backtrace") || !backtrace::parse(&stderr_str).is_empty() {
         Some(stderr_str.to_string())
     } else {
         None
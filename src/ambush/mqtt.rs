@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Minimal MQTT 3.1.1 publisher for message floods
+//!
+//! Just enough of the protocol to publish at QoS 1 and see each PUBACK: CONNECT with a clean
+//! session, PUBLISH, and PUBACK matching by packet identifier. 3.1.1 has no negative publish
+//! acknowledgement, so a broker that refuses a message closes the connection instead.
+
+use super::flood::{Delivery, Publisher};
+use anyhow::{anyhow, Context, Result};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+const KEEP_ALIVE_SECS: u16 = 60;
+/// Largest remaining length the variable-length encoding can express.
+const MAX_REMAINING_LENGTH: usize = 268_435_455;
+
+const CONNECT: u8 = 0x10;
+const CONNACK: u8 = 0x20;
+/// PUBLISH at QoS 1, no DUP or RETAIN.
+const PUBLISH_QOS1: u8 = 0x32;
+const PUBACK: u8 = 0x40;
+
+pub struct MqttPublisher {
+    stream: TcpStream,
+    topic: String,
+    packet_id: u16,
+}
+
+impl MqttPublisher {
+    pub fn connect(address: &str, topic: &str, client_id: &str) -> Result<Self> {
+        let addr = address
+            .to_socket_addrs()
+            .with_context(|| format!("resolving {}", address))?
+            .next()
+            .ok_or_else(|| anyhow!("{} did not resolve", address))?;
+        let mut stream = TcpStream::connect_timeout(&addr, IO_TIMEOUT)
+            .with_context(|| format!("connecting to MQTT broker {}", address))?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        stream.write_all(&connect_packet(client_id))?;
+        let (kind, body) = read_packet(&mut stream)?;
+        if kind & 0xf0 != CONNACK || body.len() != 2 {
+            return Err(anyhow!(
+                "broker answered CONNECT with packet type {:#x}",
+                kind
+            ));
+        }
+        if body[1] != 0 {
+            return Err(anyhow!(
+                "broker refused connection: {}",
+                connack_reason(body[1])
+            ));
+        }
+        Ok(Self {
+            stream,
+            topic: topic.to_string(),
+            packet_id: 0,
+        })
+    }
+}
+
+impl Publisher for MqttPublisher {
+    fn publish(&mut self, payload: &[u8]) -> Result<Delivery> {
+        // Packet identifiers must be non-zero.
+        self.packet_id = self.packet_id.checked_add(1).unwrap_or(1);
+        let packet = publish_packet(&self.topic, self.packet_id, payload)?;
+        self.stream.write_all(&packet).context("writing PUBLISH")?;
+        loop {
+            let (kind, body) = read_packet(&mut self.stream).context("waiting for PUBACK")?;
+            if kind & 0xf0 == PUBACK && body.get(..2) == Some(&self.packet_id.to_be_bytes()[..]) {
+                return Ok(Delivery::Acknowledged);
+            }
+        }
+    }
+}
+
+fn connect_packet(client_id: &str) -> Vec<u8> {
+    let mut body = Vec::new();
+    put_string(&mut body, "MQTT");
+    body.push(4); // protocol level 3.1.1
+    body.push(0x02); // clean session
+    body.extend_from_slice(&KEEP_ALIVE_SECS.to_be_bytes());
+    put_string(&mut body, client_id);
+    frame(CONNECT, &body)
+}
+
+fn publish_packet(topic: &str, packet_id: u16, payload: &[u8]) -> Result<Vec<u8>> {
+    let length = 2 + topic.len() + 2 + payload.len();
+    if length > MAX_REMAINING_LENGTH {
+        return Err(anyhow!(
+            "{} byte message exceeds the MQTT packet limit",
+            payload.len()
+        ));
+    }
+    let mut body = Vec::with_capacity(length);
+    put_string(&mut body, topic);
+    body.extend_from_slice(&packet_id.to_be_bytes());
+    body.extend_from_slice(payload);
+    Ok(frame(PUBLISH_QOS1, &body))
+}
+
+fn frame(kind: u8, body: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(body.len() + 5);
+    packet.push(kind);
+    let mut length = body.len();
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        packet.push(byte);
+        if length == 0 {
+            break;
+        }
+    }
+    packet.extend_from_slice(body);
+    packet
+}
+
+fn put_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Read one packet: its first header byte and body.
+fn read_packet(stream: &mut impl Read) -> Result<(u8, Vec<u8>)> {
+    let mut byte = [0u8; 1];
+    stream.read_exact(&mut byte)?;
+    let kind = byte[0];
+    let mut length = 0usize;
+    for shift in 0..4 {
+        stream.read_exact(&mut byte)?;
+        length |= usize::from(byte[0] & 0x7f) << (7 * shift);
+        if byte[0] & 0x80 == 0 {
+            let mut body = vec![0u8; length];
+            stream.read_exact(&mut body)?;
+            return Ok((kind, body));
+        }
+    }
+    Err(anyhow!("malformed MQTT remaining length"))
+}
+
+fn connack_reason(code: u8) -> String {
+    match code {
+        1 => "unacceptable protocol version".to_string(),
+        2 => "client identifier rejected".to_string(),
+        3 => "server unavailable".to_string(),
+        4 => "bad user name or password".to_string(),
+        5 => "not authorized".to_string(),
+        other => format!("return code {}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn test_remaining_length_encoding() {
+        assert_eq!(frame(PUBACK, &[0, 1]), [PUBACK, 2, 0, 1]);
+        let packet = frame(PUBLISH_QOS1, &vec![0u8; 321]);
+        assert_eq!(&packet[..3], &[PUBLISH_QOS1, 0xc1, 0x02]);
+        let (kind, body) = read_packet(&mut packet.as_slice()).unwrap();
+        assert_eq!((kind, body.len()), (PUBLISH_QOS1, 321));
+    }
+
+    #[test]
+    fn test_publish_against_fake_broker() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let broker = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let (kind, connect) = read_packet(&mut stream).unwrap();
+            assert_eq!(kind, CONNECT);
+            assert!(connect.ends_with(b"flood-test"));
+            stream.write_all(&[CONNACK, 2, 0, 0]).unwrap();
+            let mut received = Vec::new();
+            for _ in 0..2 {
+                let (kind, body) = read_packet(&mut stream).unwrap();
+                assert_eq!(kind, PUBLISH_QOS1);
+                let topic_len = usize::from(u16::from_be_bytes([body[0], body[1]]));
+                let id = &body[2 + topic_len..4 + topic_len];
+                received.push((
+                    String::from_utf8_lossy(&body[2..2 + topic_len]).to_string(),
+                    body[4 + topic_len..].to_vec(),
+                ));
+                stream.write_all(&[PUBACK, 2, id[0], id[1]]).unwrap();
+            }
+            received
+        });
+
+        let mut publisher = MqttPublisher::connect(&address, "plant/line-1", "flood-test").unwrap();
+        assert_eq!(publisher.publish(b"one").unwrap(), Delivery::Acknowledged);
+        assert_eq!(publisher.publish(b"").unwrap(), Delivery::Acknowledged);
+        let received = broker.join().unwrap();
+        assert_eq!(received[0], ("plant/line-1".to_string(), b"one".to_vec()));
+        assert_eq!(received[1].1, b"");
+    }
+}
@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Signal-storm stressor: deliver benign signals to the target at a fixed cadence.
+//!
+//! The default rotation only uses signals whose default disposition is harmless or that a
+//! robust program is expected to handle (USR1/USR2/ALRM terminate targets without handlers,
+//! which is itself a finding). Optional SIGSTOP/SIGCONT pulses freeze the target briefly so
+//! blocking syscalls observe EINTR/restart behaviour.
+//!
+//! On Linux the target is signalled through a pidfd opened before anyone can reap it, so the
+//! storm notices the exit while the target is still a zombie and can never reach a process
+//! that reuses its pid.
+
+use crate::seed::SeededRng;
+use crate::types::{IntensityLevel, SignalDelivery, SignalStormConfig, SignalStormReport};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Rotation used when no explicit signal list is configured.
+pub const DEFAULT_SIGNALS: &[&str] = &["SIGUSR1", "SIGUSR2", "SIGCHLD", "SIGWINCH", "SIGALRM"];
+
+/// Signals accepted in a rotation. SIGKILL/SIGSTOP are deliberately excluded.
+const KNOWN_SIGNALS: &[&str] = &[
+    "SIGUSR1", "SIGUSR2", "SIGCHLD", "SIGWINCH", "SIGALRM", "SIGHUP", "SIGINT", "SIGTERM",
+    "SIGPIPE", "SIGURG", "SIGCONT",
+];
+
+const BASE_FREQUENCY_HZ: f64 = 10.0;
+const MAX_FREQUENCY_HZ: f64 = 1000.0;
+const MIN_FREQUENCY_HZ: f64 = 0.1;
+const SCHEDULE_SAMPLE_LIMIT: usize = 256;
+const STOP_PULSE_EVERY: usize = 16;
+const STOP_PULSE_LENGTH: Duration = Duration::from_millis(50);
+/// Each gap between deliveries is scaled by a seeded factor within ±20%.
+const INTERVAL_JITTER: f64 = 0.2;
+
+/// Normalise a signal name (`usr1`, `SIGUSR1`, ` sigusr1 `) to its canonical form.
+pub fn canonical_signal(raw: &str) -> Option<String> {
+    let upper = raw.trim().to_ascii_uppercase();
+    let name = if upper.starts_with("SIG") {
+        upper
+    } else {
+        format!("SIG{}", upper)
+    };
+    KNOWN_SIGNALS
+        .iter()
+        .find(|known| **known == name)
+        .map(|known| known.to_string())
+}
+
+/// Delivery rate: explicit override, otherwise 10 Hz scaled by intensity.
+pub fn storm_frequency(config: &SignalStormConfig, intensity: IntensityLevel) -> f64 {
+    config
+        .frequency_hz
+        .unwrap_or(BASE_FREQUENCY_HZ * intensity.multiplier())
+        .clamp(MIN_FREQUENCY_HZ, MAX_FREQUENCY_HZ)
+}
+
+fn rotation(config: &SignalStormConfig) -> Vec<String> {
+    let configured: Vec<String> = config
+        .signals
+        .iter()
+        .filter_map(|raw| canonical_signal(raw))
+        .collect();
+    if configured.is_empty() {
+        DEFAULT_SIGNALS.iter().map(|s| s.to_string()).collect()
+    } else {
+        configured
+    }
+}
+
+/// Run the storm on a background thread until `deadline` or `stop` is raised.
+pub fn spawn_signal_storm(
+    pid: u32,
+    config: SignalStormConfig,
+    intensity: IntensityLevel,
+    deadline: Instant,
+    stop: Arc<AtomicBool>,
+    seed: u64,
+) -> JoinHandle<SignalStormReport> {
+    // Opened here, before the caller goes on to wait for (and reap) the target.
+    let target = Target::open(pid);
+    thread::spawn(move || run_signal_storm(&target, &config, intensity, deadline, &stop, seed))
+}
+
+fn run_signal_storm(
+    target: &Target,
+    config: &SignalStormConfig,
+    intensity: IntensityLevel,
+    deadline: Instant,
+    stop: &AtomicBool,
+    seed: u64,
+) -> SignalStormReport {
+    let frequency_hz = storm_frequency(config, intensity);
+    let signals = rotation(config);
+    let mut report = SignalStormReport {
+        frequency_hz,
+        signals: signals.clone(),
+        stop_pulses: config.stop_pulses,
+        ..Default::default()
+    };
+
+    if !cfg!(unix) {
+        report.note = Some("signal storms require a unix host".to_string());
+        return report;
+    }
+
+    let interval = Duration::from_secs_f64(1.0 / frequency_hz);
+    let mut rng = SeededRng::derive(seed, "signal-storm");
+    let start = Instant::now();
+    let mut tick = 0_usize;
+
+    while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+        let signal = &signals[tick % signals.len()];
+        if target.exited() || !deliver(target, signal, start, &mut report) {
+            report.note = Some("target exited during signal storm".to_string());
+            break;
+        }
+        tick += 1;
+
+        if config.stop_pulses && tick % STOP_PULSE_EVERY == 0 {
+            let stopped = deliver(target, "SIGSTOP", start, &mut report);
+            thread::sleep(STOP_PULSE_LENGTH);
+            // Always resume, even if the stop failed, so the target is never left frozen.
+            deliver(target, "SIGCONT", start, &mut report);
+            if !stopped {
+                report.note = Some("target exited during signal storm".to_string());
+                break;
+            }
+        }
+
+        sleep_interruptible(rng.jitter(interval, INTERVAL_JITTER), deadline, stop);
+    }
+
+    report
+}
+
+fn deliver(target: &Target, signal: &str, start: Instant, report: &mut SignalStormReport) -> bool {
+    let delivered = target.send(signal);
+    if delivered {
+        report.delivered += 1;
+        *report.counts.entry(signal.to_string()).or_insert(0) += 1;
+    } else {
+        report.failed += 1;
+    }
+    if report.schedule.len() < SCHEDULE_SAMPLE_LIMIT {
+        report.schedule.push(SignalDelivery {
+            offset: start.elapsed(),
+            signal: signal.to_string(),
+            delivered,
+        });
+    }
+    delivered
+}
+
+fn sleep_interruptible(interval: Duration, deadline: Instant, stop: &AtomicBool) {
+    let wake = (Instant::now() + interval).min(deadline);
+    while !stop.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        if now >= wake {
+            break;
+        }
+        thread::sleep((wake - now).min(Duration::from_millis(10)));
+    }
+}
+
+#[cfg(unix)]
+fn signal_number(name: &str) -> Option<libc::c_int> {
+    let number = match name {
+        "SIGUSR1" => libc::SIGUSR1,
+        "SIGUSR2" => libc::SIGUSR2,
+        "SIGCHLD" => libc::SIGCHLD,
+        "SIGWINCH" => libc::SIGWINCH,
+        "SIGALRM" => libc::SIGALRM,
+        "SIGHUP" => libc::SIGHUP,
+        "SIGINT" => libc::SIGINT,
+        "SIGTERM" => libc::SIGTERM,
+        "SIGPIPE" => libc::SIGPIPE,
+        "SIGURG" => libc::SIGURG,
+        "SIGCONT" => libc::SIGCONT,
+        "SIGSTOP" => libc::SIGSTOP,
+        _ => return None,
+    };
+    Some(number)
+}
+
+/// The process a storm signals: a pidfd on Linux, falling back to the bare pid on kernels
+/// without pidfd_open and on other unix hosts.
+struct Target {
+    pid: u32,
+    #[cfg(target_os = "linux")]
+    pidfd: Option<std::os::fd::OwnedFd>,
+}
+
+impl Target {
+    #[cfg(target_os = "linux")]
+    fn open(pid: u32) -> Self {
+        use std::os::fd::{FromRawFd, OwnedFd, RawFd};
+        // SAFETY: pidfd_open returns a new descriptor that we own.
+        let pidfd = unsafe {
+            let pidfd = libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0);
+            (pidfd >= 0).then(|| OwnedFd::from_raw_fd(pidfd as RawFd))
+        };
+        Self { pid, pidfd }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn open(pid: u32) -> Self {
+        Self { pid }
+    }
+
+    /// Whether the target has exited, reaped or not. Only a pidfd can tell.
+    #[cfg(target_os = "linux")]
+    fn exited(&self) -> bool {
+        use std::os::fd::AsRawFd;
+        let Some(pidfd) = &self.pidfd else {
+            return false;
+        };
+        let mut exit = libc::pollfd {
+            fd: pidfd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: polling one descriptor we own; a pidfd turns readable when its process exits.
+        unsafe { libc::poll(&mut exit, 1, 0) > 0 }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn exited(&self) -> bool {
+        false
+    }
+
+    #[cfg(unix)]
+    fn send(&self, signal: &str) -> bool {
+        let Some(number) = signal_number(signal) else {
+            return false;
+        };
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = &self.pidfd {
+            use std::os::fd::AsRawFd;
+            // SAFETY: signalling through a pidfd we own, with no siginfo.
+            return unsafe {
+                libc::syscall(
+                    libc::SYS_pidfd_send_signal,
+                    pidfd.as_raw_fd(),
+                    number,
+                    std::ptr::null::<libc::siginfo_t>(),
+                    0,
+                ) == 0
+            };
+        }
+        // SAFETY: kill(2) has no memory-safety preconditions; pid is a child we spawned.
+        unsafe { libc::kill(self.pid as libc::pid_t, number) == 0 }
+    }
+
+    #[cfg(not(unix))]
+    fn send(&self, _signal: &str) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_signal_names() {
+        assert_eq!(canonical_signal("usr1").as_deref(), Some("SIGUSR1"));
+        assert_eq!(canonical_signal(" SIGWINCH ").as_deref(), Some("SIGWINCH"));
+        assert_eq!(canonical_signal("sigalrm").as_deref(), Some("SIGALRM"));
+        assert_eq!(canonical_signal("kill"), None);
+        assert_eq!(canonical_signal("SIGSTOP"), None);
+    }
+
+    #[test]
+    fn test_frequency_scales_with_intensity() {
+        let config = SignalStormConfig::default();
+        assert_eq!(storm_frequency(&config, IntensityLevel::Light), 10.0);
+        assert_eq!(storm_frequency(&config, IntensityLevel::Heavy), 100.0);
+        let fixed = SignalStormConfig {
+            frequency_hz: Some(5000.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            storm_frequency(&fixed, IntensityLevel::Light),
+            MAX_FREQUENCY_HZ
+        );
+    }
+
+    #[test]
+    fn test_rotation_defaults_when_empty() {
+        let config = SignalStormConfig::default();
+        assert_eq!(rotation(&config).len(), DEFAULT_SIGNALS.len());
+        let custom = SignalStormConfig {
+            signals: vec!["hup".to_string(), "bogus".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(rotation(&custom), vec!["SIGHUP".to_string()]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_storm_records_schedule_against_child() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .expect("spawn sleep");
+        let config = SignalStormConfig {
+            frequency_hz: Some(50.0),
+            signals: vec!["SIGCHLD".to_string(), "SIGWINCH".to_string()],
+            stop_pulses: false,
+        };
+        let stop = AtomicBool::new(false);
+        let deadline = Instant::now() + Duration::from_millis(200);
+        let report = run_signal_storm(
+            &Target::open(child.id()),
+            &config,
+            IntensityLevel::Light,
+            deadline,
+            &stop,
+            1,
+        );
+        let _ = child.kill();
+        let _ = child.wait();
+
+        assert!(report.delivered > 0);
+        assert_eq!(report.failed, 0);
+        assert_eq!(report.schedule[0].signal, "SIGCHLD");
+        assert!(report.counts.contains_key("SIGWINCH"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_storm_stops_at_an_unreaped_target() {
+        let mut child = std::process::Command::new("true")
+            .spawn()
+            .expect("spawn true");
+        // Let it exit; until the wait below it stays a zombie that kill(2) still accepts.
+        thread::sleep(Duration::from_millis(100));
+        let config = SignalStormConfig {
+            frequency_hz: Some(50.0),
+            signals: vec!["SIGWINCH".to_string()],
+            stop_pulses: false,
+        };
+        let stop = AtomicBool::new(false);
+        let deadline = Instant::now() + Duration::from_millis(200);
+        let report = run_signal_storm(
+            &Target::open(child.id()),
+            &config,
+            IntensityLevel::Light,
+            deadline,
+            &stop,
+            1,
+        );
+        let _ = child.wait();
+
+        assert_eq!(report.delivered, 0);
+        assert_eq!(
+            report.note.as_deref(),
+            Some("target exited during signal storm")
+        );
+    }
+}
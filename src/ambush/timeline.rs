@@ -1,10 +1,26 @@
 // SPDX-License-Identifier: PMPL-1.0-or-later
 
 //! Ambush timeline specification and parsing.
-
-use crate::types::{AttackAxis, IntensityLevel};
+//!
+//! Events may repeat (`repeat` times, `every` apart, each start shifted by up to ±`jitter`),
+//! and top-level groups repeat a set of events across axes as one unit. Repeats are expanded
+//! into concrete events here, with jitter drawn from the campaign seed, so the executor and
+//! the report only ever see a flat event list.
+//!
+//! Events can also wait on each other and on the target: `after` starts an event once another
+//! has finished (`at` then counts from that moment), `start_when`/`stop_when` gate it on a
+//! runtime condition (see `conditions`), and a top-level `stop_when` ends all stress early.
+
+use super::conditions::Condition;
+use crate::schema::{self, SchemaKind};
+use crate::seed::SeededRng;
+use crate::types::{
+    ActiveStressor, AttackAxis, AttackResult, EventAttribution, IntensityLevel, IntensitySpec,
+    TimelineEventReport, TimelineReport,
+};
 use anyhow::{anyhow, Context, Result};
 use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -14,6 +30,8 @@ pub struct TimelinePlan {
     pub program: Option<PathBuf>,
     pub duration: Duration,
     pub events: Vec<TimelineEventPlan>,
+    /// Ends every stressor once it holds; the target keeps running to the end.
+    pub stop_when: Option<Condition>,
 }
 
 #[derive(Debug, Clone)]
@@ -24,18 +42,53 @@ pub struct TimelineEventPlan {
     pub duration: Duration,
     pub intensity: IntensityLevel,
     pub args: Vec<String>,
+    /// Group this event was expanded from, if any.
+    pub group: Option<String>,
+    /// 1-based repetition of the event (or of its group) when it repeats.
+    pub iteration: Option<u32>,
+    /// Event that must finish first; `start_offset` then counts from its end.
+    pub after: Option<String>,
+    pub start_when: Option<Condition>,
+    pub stop_when: Option<Condition>,
 }
 
+/// Upper bound on expanded events, so a typo in `repeat` cannot spawn millions of stressors.
+const MAX_EXPANDED_EVENTS: usize = 10_000;
+
 #[derive(Debug, Clone, Deserialize)]
 struct TimelineSpec {
     pub program: Option<PathBuf>,
     pub duration: Option<String>,
+    #[serde(default)]
     pub tracks: Vec<TimelineTrackSpec>,
+    #[serde(default)]
+    pub groups: Vec<TimelineGroupSpec>,
+    pub stop_when: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TimelineGroupSpec {
+    pub id: Option<String>,
+    pub at: Option<String>,
+    pub repeat: Option<u32>,
+    /// Period between group starts (default: the group's own span, i.e. back to back).
+    pub every: Option<String>,
+    pub jitter: Option<String>,
+    pub events: Vec<TimelineGroupEventSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TimelineGroupEventSpec {
+    pub axis: String,
+    #[serde(flatten)]
+    pub event: TimelineEventSpec,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 struct TimelineTrackSpec {
     pub axis: String,
+    /// Intensity for this axis's events that do not set their own.
+    pub intensity: Option<IntensitySpec>,
     pub events: Vec<TimelineEventSpec>,
 }
 
@@ -45,17 +98,43 @@ struct TimelineEventSpec {
     pub at: String,
     #[serde(rename = "for")]
     pub for_duration: String,
-    pub intensity: Option<String>,
+    pub intensity: Option<IntensitySpec>,
     #[serde(default)]
     pub args: Vec<String>,
+    pub repeat: Option<u32>,
+    /// Period between repeat starts (default: the event's own length, i.e. back to back).
+    pub every: Option<String>,
+    pub jitter: Option<String>,
+    pub after: Option<String>,
+    pub start_when: Option<String>,
+    pub stop_when: Option<String>,
+}
+
+/// Event fields resolved once; `expand` then stamps out its occurrences.
+#[derive(Clone)]
+struct ResolvedEvent {
+    id: String,
+    axis: AttackAxis,
+    at: Duration,
+    duration: Duration,
+    intensity: IntensityLevel,
+    args: Vec<String>,
+    repeat: u32,
+    every: Duration,
+    jitter: Duration,
+    after: Option<String>,
+    start_when: Option<Condition>,
+    stop_when: Option<Condition>,
 }
 
 pub fn load_timeline_with_default(
     path: &Path,
     default_intensity: Option<IntensityLevel>,
+    seed: u64,
 ) -> Result<TimelinePlan> {
     let content =
         fs::read_to_string(path).with_context(|| format!("reading timeline {}", path.display()))?;
+    schema::check_document(SchemaKind::Timeline, &content, path)?;
     let spec: TimelineSpec = if path.extension().and_then(|s| s.to_str()) == Some("yaml")
         || path.extension().and_then(|s| s.to_str()) == Some("yml")
     {
@@ -66,94 +145,344 @@ pub fn load_timeline_with_default(
             .with_context(|| format!("parsing json timeline {}", path.display()))?
     };
 
-    build_plan(spec, default_intensity)
+    build_plan(spec, default_intensity, seed)
 }
 
 fn build_plan(
     spec: TimelineSpec,
     default_intensity: Option<IntensityLevel>,
+    seed: u64,
 ) -> Result<TimelinePlan> {
+    // Jitter is drawn in file order, so the same file and seed always expand identically.
+    let mut rng = SeededRng::derive(seed, "timeline");
     let mut events = Vec::new();
     for track in spec.tracks {
         let axis =
             parse_axis(&track.axis).ok_or_else(|| anyhow!("unknown axis '{}'", track.axis))?;
+        let track_intensity = match track.intensity {
+            Some(spec) => Some(resolve_intensity(spec)?),
+            None => default_intensity,
+        };
         for (index, event) in track.events.into_iter().enumerate() {
-            let id = event
-                .id
-                .unwrap_or_else(|| format!("{}-{}", axis_label(axis), index + 1));
-            let start_offset = parse_duration(&event.at)?;
-            let duration = parse_duration(&event.for_duration)?;
-            let intensity = match event.intensity {
-                Some(raw) => {
-                    parse_intensity(&raw).ok_or_else(|| anyhow!("unknown intensity '{}'", raw))?
+            let resolved = resolve_event(axis.clone(), index, event, track_intensity)?;
+            expand(&resolved, Duration::ZERO, None, None, &mut rng, &mut events)?;
+        }
+    }
+
+    for (group_index, group) in spec.groups.into_iter().enumerate() {
+        let group_id = group
+            .id
+            .unwrap_or_else(|| format!("group-{}", group_index + 1));
+        let mut members = Vec::new();
+        for (index, member) in group.events.into_iter().enumerate() {
+            let axis = parse_axis(&member.axis)
+                .ok_or_else(|| anyhow!("unknown axis '{}' in group '{}'", member.axis, group_id))?;
+            if member.event.repeat.is_some() || member.event.every.is_some() {
+                return Err(anyhow!(
+                    "event in group '{}' sets repeat/every; repeat the group instead",
+                    group_id
+                ));
+            }
+            if member.event.after.is_some() {
+                return Err(anyhow!(
+                    "event in group '{}' sets after; members are timed from the group start",
+                    group_id
+                ));
+            }
+            members.push(resolve_event(axis, index, member.event, default_intensity)?);
+        }
+        let span = members
+            .iter()
+            .map(|member| member.at + member.duration)
+            .max()
+            .unwrap_or_default();
+        let group_at = optional_duration(group.at.as_deref())?.unwrap_or_default();
+        let repeat = group.repeat.unwrap_or(1).max(1);
+        let every = optional_duration(group.every.as_deref())?.unwrap_or(span);
+        let jitter = optional_duration(group.jitter.as_deref())?.unwrap_or_default();
+        for iteration in 0..repeat {
+            let base = jittered(group_at + every * iteration, jitter, &mut rng);
+            let label = (repeat > 1).then_some(iteration + 1);
+            for member in &members {
+                let mut scoped = member.clone();
+                scoped.id = format!("{}/{}", group_id, member.id);
+                if let Some(n) = label {
+                    scoped.id = format!("{}#{}", scoped.id, n);
                 }
-                None => default_intensity.unwrap_or(IntensityLevel::Medium),
-            };
-            events.push(TimelineEventPlan {
-                id,
-                axis,
-                start_offset,
-                duration,
-                intensity,
-                args: event.args,
-            });
+                expand(&scoped, base, Some(&group_id), label, &mut rng, &mut events)?;
+            }
         }
     }
 
+    check_dependencies(&events)?;
     let duration = match spec.duration {
         Some(raw) => parse_duration(&raw)?,
         None => infer_duration(&events)?,
     };
+    let stop_when = spec
+        .stop_when
+        .as_deref()
+        .map(Condition::parse)
+        .transpose()?;
 
     Ok(TimelinePlan {
         program: spec.program,
         duration,
         events,
+        stop_when,
     })
 }
 
+fn resolve_event(
+    axis: AttackAxis,
+    index: usize,
+    event: TimelineEventSpec,
+    default_intensity: Option<IntensityLevel>,
+) -> Result<ResolvedEvent> {
+    let id = event
+        .id
+        .unwrap_or_else(|| format!("{}-{}", axis.name(), index + 1));
+    let duration = parse_duration(&event.for_duration)?;
+    let intensity = match event.intensity {
+        Some(spec) => resolve_intensity(spec)?,
+        None => default_intensity.unwrap_or(IntensityLevel::Medium),
+    };
+    Ok(ResolvedEvent {
+        id,
+        axis,
+        at: parse_duration(&event.at)?,
+        duration,
+        intensity,
+        args: event.args,
+        repeat: event.repeat.unwrap_or(1).max(1),
+        every: optional_duration(event.every.as_deref())?.unwrap_or(duration),
+        jitter: optional_duration(event.jitter.as_deref())?.unwrap_or_default(),
+        after: event.after,
+        start_when: event
+            .start_when
+            .as_deref()
+            .map(Condition::parse)
+            .transpose()?,
+        stop_when: event
+            .stop_when
+            .as_deref()
+            .map(Condition::parse)
+            .transpose()?,
+    })
+}
+
+/// Push every occurrence of `event`, offset from `base` (the group start, or zero).
+fn expand(
+    event: &ResolvedEvent,
+    base: Duration,
+    group: Option<&str>,
+    group_iteration: Option<u32>,
+    rng: &mut SeededRng,
+    events: &mut Vec<TimelineEventPlan>,
+) -> Result<()> {
+    for iteration in 0..event.repeat {
+        if events.len() >= MAX_EXPANDED_EVENTS {
+            return Err(anyhow!(
+                "timeline expands to more than {} events; lower repeat counts",
+                MAX_EXPANDED_EVENTS
+            ));
+        }
+        let nominal = base + event.at + event.every * iteration;
+        let repeated = event.repeat > 1;
+        events.push(TimelineEventPlan {
+            id: if repeated {
+                format!("{}#{}", event.id, iteration + 1)
+            } else {
+                event.id.clone()
+            },
+            axis: event.axis.clone(),
+            start_offset: jittered(nominal, event.jitter, rng),
+            duration: event.duration,
+            intensity: event.intensity,
+            args: event.args.clone(),
+            group: group.map(str::to_string),
+            iteration: if repeated {
+                Some(iteration + 1)
+            } else {
+                group_iteration
+            },
+            after: event.after.clone(),
+            start_when: event.start_when.clone(),
+            stop_when: event.stop_when.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// `nominal` shifted uniformly within ±`jitter`, never before the timeline start.
+fn jittered(nominal: Duration, jitter: Duration, rng: &mut SeededRng) -> Duration {
+    if jitter.is_zero() {
+        return nominal;
+    }
+    let shift = jitter.as_secs_f64() * (2.0 * rng.unit() - 1.0);
+    Duration::from_secs_f64((nominal.as_secs_f64() + shift).max(0.0))
+}
+
+fn optional_duration(raw: Option<&str>) -> Result<Option<Duration>> {
+    raw.map(parse_duration).transpose()
+}
+
+/// Every `after` must name an event in the plan, and the chain must not loop.
+fn check_dependencies(events: &[TimelineEventPlan]) -> Result<()> {
+    let by_id: HashMap<&str, &TimelineEventPlan> = events
+        .iter()
+        .map(|event| (event.id.as_str(), event))
+        .collect();
+    for event in events {
+        let mut seen = HashSet::from([event.id.as_str()]);
+        let mut cursor = event;
+        while let Some(dependency) = cursor.after.as_deref() {
+            cursor = by_id.get(dependency).copied().ok_or_else(|| {
+                anyhow!(
+                    "event '{}' runs after unknown event '{}'",
+                    event.id,
+                    dependency
+                )
+            })?;
+            if !seen.insert(cursor.id.as_str()) {
+                return Err(anyhow!("event '{}' is part of an `after` cycle", event.id));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Latest nominal end, following `after` chains (triggered events count from their `at`).
 fn infer_duration(events: &[TimelineEventPlan]) -> Result<Duration> {
+    let by_id: HashMap<&str, &TimelineEventPlan> = events
+        .iter()
+        .map(|event| (event.id.as_str(), event))
+        .collect();
+    let end = |event: &TimelineEventPlan| {
+        let mut total = event.start_offset + event.duration;
+        let mut cursor = event.after.as_deref();
+        while let Some(dependency) = cursor.and_then(|id| by_id.get(id)) {
+            total += dependency.start_offset + dependency.duration;
+            cursor = dependency.after.as_deref();
+        }
+        total
+    };
     events
         .iter()
-        .map(|event| event.start_offset + event.duration)
+        .map(end)
         .max()
         .ok_or_else(|| anyhow!("timeline has no events to infer duration"))
 }
 
 fn parse_axis(raw: &str) -> Option<AttackAxis> {
-    match raw.trim().to_ascii_lowercase().as_str() {
-        "cpu" => Some(AttackAxis::Cpu),
-        "memory" => Some(AttackAxis::Memory),
-        "disk" => Some(AttackAxis::Disk),
-        "network" => Some(AttackAxis::Network),
-        "concurrency" => Some(AttackAxis::Concurrency),
-        "time" => Some(AttackAxis::Time),
-        _ => None,
-    }
+    AttackAxis::builtin(raw.trim())
+}
+
+fn parse_intensity(raw: &str) -> Option<IntensityLevel> {
+    raw.parse().ok()
 }
 
-fn axis_label(axis: AttackAxis) -> &'static str {
-    match axis {
-        AttackAxis::Cpu => "cpu",
-        AttackAxis::Memory => "memory",
-        AttackAxis::Disk => "disk",
-        AttackAxis::Network => "network",
-        AttackAxis::Concurrency => "concurrency",
-        AttackAxis::Time => "time",
+/// A preset name or a 0.0-1.0 value.
+fn resolve_intensity(spec: IntensitySpec) -> Result<IntensityLevel> {
+    match spec {
+        IntensitySpec::Name(raw) => {
+            parse_intensity(&raw).ok_or_else(|| anyhow!("unknown intensity '{}'", raw))
+        }
+        IntensitySpec::Scale(scale) => {
+            IntensityLevel::from_scale(scale).map_err(|err| anyhow!(err))
+        }
     }
 }
 
-fn parse_intensity(raw: &str) -> Option<IntensityLevel> {
-    match raw.trim().to_ascii_lowercase().as_str() {
-        "light" => Some(IntensityLevel::Light),
-        "medium" => Some(IntensityLevel::Medium),
-        "heavy" => Some(IntensityLevel::Heavy),
-        "extreme" => Some(IntensityLevel::Extreme),
-        _ => None,
+/// Events that had started and not yet finished `at` into the timeline. `finished` holds each
+/// event's end offset (`None` when it never ran); an event missing from it is still running.
+pub(super) fn active_at(
+    events: &[TimelineEventReport],
+    finished: &HashMap<String, Option<Duration>>,
+    at: Duration,
+) -> Vec<ActiveStressor> {
+    events
+        .iter()
+        .filter(|event| event.ran && event.start_offset <= at)
+        .filter(|event| match finished.get(&event.id) {
+            Some(Some(end)) => *end > at,
+            Some(None) => false,
+            None => true,
+        })
+        .map(|event| ActiveStressor {
+            event: event.id.clone(),
+            axis: event.axis.clone(),
+            intensity: event.intensity,
+            active_for: at - event.start_offset,
+        })
+        .collect()
+}
+
+/// Fold repeated runs of one timeline into a single result list and report. With more than one
+/// run the report keeps the first run's events and ranks every event by the share of runs that
+/// crashed while it was active.
+pub fn merge_timeline_runs(
+    runs: Vec<(Vec<AttackResult>, TimelineReport)>,
+) -> (Vec<AttackResult>, TimelineReport) {
+    let total = runs.len() as u32;
+    let mut order: Vec<(String, AttackAxis)> = Vec::new();
+    let mut ran: HashMap<String, u32> = HashMap::new();
+    let mut crashed: HashMap<String, u32> = HashMap::new();
+    for (results, report) in &runs {
+        for event in report.events.iter().filter(|event| event.ran) {
+            if !ran.contains_key(&event.id) {
+                order.push((event.id.clone(), event.axis.clone()));
+            }
+            *ran.entry(event.id.clone()).or_default() += 1;
+        }
+        let active: HashSet<&str> = results
+            .iter()
+            .flat_map(|result| &result.crashes)
+            .flat_map(|crash| &crash.active_stressors)
+            .map(|stressor| stressor.event.as_str())
+            .collect();
+        for event in active {
+            *crashed.entry(event.to_string()).or_default() += 1;
+        }
     }
+
+    let mut runs = runs.into_iter();
+    let Some((mut results, mut report)) = runs.next() else {
+        return (
+            Vec::new(),
+            TimelineReport {
+                duration: Duration::ZERO,
+                events: Vec::new(),
+                stopped_by: None,
+                runs: None,
+                attribution: Vec::new(),
+            },
+        );
+    };
+    if total < 2 {
+        return (results, report);
+    }
+    results.extend(runs.flat_map(|(results, _)| results));
+    let mut attribution: Vec<EventAttribution> = order
+        .into_iter()
+        .map(|(event, axis)| {
+            let crashes_while_active = crashed.get(&event).copied().unwrap_or(0);
+            EventAttribution {
+                ran: ran[&event],
+                crashes_while_active,
+                crash_probability: f64::from(crashes_while_active) / f64::from(total),
+                event,
+                axis,
+            }
+        })
+        .collect();
+    attribution.sort_by(|a, b| b.crash_probability.total_cmp(&a.crash_probability));
+    report.runs = Some(total);
+    report.attribution = attribution;
+    (results, report)
 }
 
-fn parse_duration(raw: &str) -> Result<Duration> {
+pub(super) fn parse_duration(raw: &str) -> Result<Duration> {
     let trimmed = raw.trim().to_ascii_lowercase();
     if trimmed.is_empty() {
         return Err(anyhow!("duration cannot be empty"));
@@ -191,6 +520,7 @@ fn parse_duration(raw: &str) -> Result<Duration> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::CrashReport;
     use tempfile::TempDir;
 
     #[test]
@@ -237,6 +567,7 @@ mod tests {
         assert_eq!(parse_axis("network"), Some(AttackAxis::Network));
         assert_eq!(parse_axis("concurrency"), Some(AttackAxis::Concurrency));
         assert_eq!(parse_axis("time"), Some(AttackAxis::Time));
+        assert_eq!(parse_axis("signal"), Some(AttackAxis::Signal));
         assert_eq!(parse_axis("CPU"), Some(AttackAxis::Cpu));
         assert_eq!(parse_axis("bogus"), None);
     }
@@ -271,7 +602,7 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, None).unwrap();
+        let plan = load_timeline_with_default(&path, None, 0).unwrap();
         assert_eq!(plan.duration, Duration::from_secs(10));
         assert_eq!(plan.events.len(), 1);
         assert_eq!(plan.events[0].axis, AttackAxis::Cpu);
@@ -288,7 +619,7 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, None).unwrap();
+        let plan = load_timeline_with_default(&path, None, 0).unwrap();
         assert_eq!(plan.duration, Duration::from_secs(15));
         assert_eq!(plan.events.len(), 1);
         assert_eq!(plan.events[0].axis, AttackAxis::Memory);
@@ -314,7 +645,7 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Medium)).unwrap();
+        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Medium), 0).unwrap();
         // 5s start + 10s duration = 15s total
         assert_eq!(plan.duration, Duration::from_secs(15));
     }
@@ -346,7 +677,7 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Medium)).unwrap();
+        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Medium), 0).unwrap();
         assert_eq!(plan.events.len(), 3);
         assert_eq!(plan.events[0].id, "cpu-1");
         assert_eq!(plan.events[1].id, "cpu-2");
@@ -364,10 +695,50 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Heavy)).unwrap();
+        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Heavy), 0).unwrap();
         assert_eq!(plan.events[0].intensity, IntensityLevel::Heavy);
     }
 
+    #[test]
+    fn test_timeline_scale_and_track_intensity() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.yaml");
+        std::fs::write(
+            &path,
+            r#"
+duration: 5s
+tracks:
+  - axis: memory
+    intensity: 0.3
+    events:
+      - { at: 0s, for: 2s }
+      - { at: 2s, for: 2s, intensity: heavy }
+  - axis: cpu
+    events:
+      - { at: 0s, for: 5s, intensity: 0.65 }
+"#,
+        )
+        .unwrap();
+
+        let plan = load_timeline_with_default(&path, Some(IntensityLevel::Light), 0).unwrap();
+        assert_eq!(
+            plan.events[0].intensity,
+            IntensityLevel::from_scale(0.3).unwrap()
+        );
+        assert_eq!(plan.events[1].intensity, IntensityLevel::Heavy);
+        assert_eq!(
+            plan.events[2].intensity,
+            IntensityLevel::from_scale(0.65).unwrap()
+        );
+
+        std::fs::write(
+            &path,
+            "tracks: [{ axis: cpu, events: [{ at: 0s, for: 1s, intensity: 1.5 }] }]\n",
+        )
+        .unwrap();
+        assert!(load_timeline_with_default(&path, None, 0).is_err());
+    }
+
     #[test]
     fn test_timeline_auto_generated_ids() {
         let dir = TempDir::new().unwrap();
@@ -378,8 +749,269 @@ mod tests {
         )
         .unwrap();
 
-        let plan = load_timeline_with_default(&path, None).unwrap();
+        let plan = load_timeline_with_default(&path, None, 0).unwrap();
         assert_eq!(plan.events[0].id, "disk-1");
         assert_eq!(plan.events[1].id, "disk-2");
     }
+
+    #[test]
+    fn test_repeat_with_jitter_is_seeded() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.yaml");
+        std::fs::write(
+            &path,
+            "tracks:\n  - axis: memory\n    events:\n      - id: spike\n        at: 10s\n        for: 5s\n        repeat: 60\n        every: 60s\n        jitter: 5s\n",
+        )
+        .unwrap();
+
+        let plan = load_timeline_with_default(&path, None, 7).unwrap();
+        assert_eq!(plan.events.len(), 60);
+        assert_eq!(plan.events[0].id, "spike#1");
+        assert_eq!(plan.events[59].iteration, Some(60));
+        for (index, event) in plan.events.iter().enumerate() {
+            let nominal = 10.0 + 60.0 * index as f64;
+            assert!((event.start_offset.as_secs_f64() - nominal).abs() <= 5.0);
+        }
+
+        let again = load_timeline_with_default(&path, None, 7).unwrap();
+        let other = load_timeline_with_default(&path, None, 8).unwrap();
+        let starts = |plan: &TimelinePlan| -> Vec<Duration> {
+            plan.events.iter().map(|event| event.start_offset).collect()
+        };
+        assert_eq!(starts(&plan), starts(&again));
+        assert_ne!(starts(&plan), starts(&other));
+    }
+
+    #[test]
+    fn test_group_repeats_back_to_back() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.json");
+        std::fs::write(
+            &path,
+            r#"{"groups": [{"id": "burst", "at": "1s", "repeat": 3, "events": [
+                {"axis": "cpu", "at": "0s", "for": "2s"},
+                {"axis": "disk", "id": "io", "at": "1s", "for": "3s"}
+            ]}]}"#,
+        )
+        .unwrap();
+
+        let plan = load_timeline_with_default(&path, None, 0).unwrap();
+        assert_eq!(plan.events.len(), 6);
+        // Span is 4s, so iterations start at 1s, 5s and 9s.
+        let io: Vec<&TimelineEventPlan> = plan
+            .events
+            .iter()
+            .filter(|e| e.axis == AttackAxis::Disk)
+            .collect();
+        assert_eq!(io[2].id, "burst/io#3");
+        assert_eq!(io[2].start_offset, Duration::from_secs(10));
+        assert_eq!(io[2].group.as_deref(), Some("burst"));
+        assert_eq!(plan.duration, Duration::from_secs(13));
+    }
+
+    #[test]
+    fn test_group_member_repeat_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.json");
+        std::fs::write(
+            &path,
+            r#"{"groups": [{"events": [{"axis": "cpu", "at": "0s", "for": "1s", "repeat": 2}]}]}"#,
+        )
+        .unwrap();
+
+        assert!(load_timeline_with_default(&path, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_after_chain_and_conditions_parse() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.json");
+        std::fs::write(
+            &path,
+            r#"{"stop_when": "elapsed > 30s", "tracks": [
+                {"axis": "cpu", "events": [{"id": "spike", "at": "0s", "for": "5s"}]},
+                {"axis": "disk", "events": [
+                    {"id": "io", "after": "spike", "at": "1s", "for": "4s",
+                     "start_when": "rss > 1GiB", "stop_when": "fds > 500"}
+                ]}
+            ]}"#,
+        )
+        .unwrap();
+
+        let plan = load_timeline_with_default(&path, None, 0).unwrap();
+        let io = plan.events.iter().find(|e| e.id == "io").unwrap();
+        assert_eq!(io.after.as_deref(), Some("spike"));
+        assert_eq!(io.start_when.as_ref().unwrap().to_string(), "rss > 1GiB");
+        assert!(io.stop_when.is_some());
+        assert!(plan.stop_when.is_some());
+        // spike ends at 5s, io starts 1s later and runs 4s.
+        assert_eq!(plan.duration, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_unknown_or_cyclic_after_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let unknown = dir.path().join("unknown.json");
+        std::fs::write(
+            &unknown,
+            r#"{"tracks": [{"axis": "cpu", "events": [{"id": "a", "after": "ghost", "at": "0s", "for": "1s"}]}]}"#,
+        )
+        .unwrap();
+        let err = load_timeline_with_default(&unknown, None, 0).unwrap_err();
+        assert!(err.to_string().contains("ghost"));
+
+        let cyclic = dir.path().join("cyclic.json");
+        std::fs::write(
+            &cyclic,
+            r#"{"tracks": [{"axis": "cpu", "events": [
+                {"id": "a", "after": "b", "at": "0s", "for": "1s"},
+                {"id": "b", "after": "a", "at": "0s", "for": "1s"}
+            ]}]}"#,
+        )
+        .unwrap();
+        let err = load_timeline_with_default(&cyclic, None, 0).unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn test_invalid_condition_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("timeline.json");
+        std::fs::write(
+            &path,
+            r#"{"tracks": [{"axis": "cpu", "events": [{"at": "0s", "for": "1s", "start_when": "load > 2"}]}]}"#,
+        )
+        .unwrap();
+        assert!(load_timeline_with_default(&path, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_active_at_finds_overlapping_events() {
+        let event = |id: &str, axis: AttackAxis, start: u64| TimelineEventReport {
+            id: id.to_string(),
+            axis,
+            start_offset: Duration::from_secs(start),
+            duration: Duration::from_secs(10),
+            intensity: IntensityLevel::Medium,
+            ran: true,
+            ..Default::default()
+        };
+        let events = vec![
+            event("cpu-1", AttackAxis::Cpu, 0),
+            event("memory-1", AttackAxis::Memory, 2),
+            event("network-1", AttackAxis::Network, 4),
+            event("disk-1", AttackAxis::Disk, 9),
+            TimelineEventReport {
+                ran: false,
+                ..event("signal-1", AttackAxis::Signal, 1)
+            },
+        ];
+        let secs = |s: u64| Some(Duration::from_secs(s));
+        let finished = HashMap::from([
+            ("cpu-1".to_string(), secs(5)),
+            ("memory-1".to_string(), secs(12)),
+            ("signal-1".to_string(), None),
+        ]);
+
+        let active = active_at(&events, &finished, Duration::from_secs(7));
+        let ids: Vec<&str> = active.iter().map(|s| s.event.as_str()).collect();
+        assert_eq!(ids, vec!["memory-1", "network-1"]);
+        assert_eq!(active[1].active_for, Duration::from_secs(3));
+
+        let crash = CrashReport {
+            active_stressors: active,
+            ..Default::default()
+        };
+        assert_eq!(
+            crash.stressor_context().as_deref(),
+            Some("crashed 3.0s into the memory+network overlap")
+        );
+    }
+
+    #[test]
+    fn test_merge_timeline_runs_ranks_events_by_crash_share() {
+        let event = |id: &str, axis: AttackAxis| TimelineEventReport {
+            id: id.to_string(),
+            axis,
+            start_offset: Duration::ZERO,
+            duration: Duration::from_secs(10),
+            intensity: IntensityLevel::Medium,
+            ran: true,
+            ..Default::default()
+        };
+        let run = |active: &[&str]| {
+            let crashes = (!active.is_empty())
+                .then(|| CrashReport {
+                    active_stressors: active
+                        .iter()
+                        .map(|id| ActiveStressor {
+                            event: id.to_string(),
+                            axis: AttackAxis::Memory,
+                            intensity: IntensityLevel::Medium,
+                            active_for: Duration::from_secs(1),
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect();
+            let result = AttackResult {
+                program: PathBuf::from("app"),
+                axis: AttackAxis::Time,
+                success: active.is_empty(),
+                exit_code: Some(if active.is_empty() { 0 } else { 101 }),
+                duration: Duration::from_secs(10),
+                peak_memory: 0,
+                crashes,
+                ..Default::default()
+            };
+            let report = TimelineReport {
+                duration: Duration::from_secs(10),
+                events: vec![
+                    event("cpu-1", AttackAxis::Cpu),
+                    event("memory-1", AttackAxis::Memory),
+                    event("network-1", AttackAxis::Network),
+                ],
+                stopped_by: None,
+                runs: None,
+                attribution: Vec::new(),
+            };
+            (vec![result], report)
+        };
+
+        let (results, report) = merge_timeline_runs(vec![run(&["memory-1"])]);
+        assert_eq!(results.len(), 1);
+        assert_eq!(report.runs, None);
+        assert!(report.attribution.is_empty());
+
+        let (results, report) = merge_timeline_runs(vec![
+            run(&["memory-1", "network-1"]),
+            run(&[]),
+            run(&["memory-1"]),
+            run(&["network-1", "memory-1"]),
+        ]);
+        assert_eq!(results.len(), 4);
+        assert_eq!(report.runs, Some(4));
+        assert_eq!(report.events.len(), 3);
+        let ranked: Vec<(&str, u32, f64)> = report
+            .attribution
+            .iter()
+            .map(|a| {
+                (
+                    a.event.as_str(),
+                    a.crashes_while_active,
+                    a.crash_probability,
+                )
+            })
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("memory-1", 3, 0.75),
+                ("network-1", 2, 0.5),
+                ("cpu-1", 0, 0.0)
+            ]
+        );
+        assert_eq!(report.attribution[0].ran, 4);
+    }
 }
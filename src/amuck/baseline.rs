@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Comparison of mutant exec runs against a baseline run
+//!
+//! A passing or failing exec check says little on its own: a checker that
+//! already fails on the pristine target fails on every mutant too. When an
+//! exec program is given, amuck first runs it on a pristine copy of the target
+//! (`<stem>.amuck.000.<ext>`) and compares every mutant run with that baseline:
+//! - `killed`: the exit code changed, so the check noticed the mutation;
+//! - `survived`: same exit code but different output, so behaviour changed
+//!   without the check failing;
+//! - `equivalent-output`: exit code, stdout and stderr all match, so the
+//!   mutant is equivalent or the check cannot see it.
+//!
+//! The path of the file under test is masked as `{file}` on both sides so
+//! diagnostics that quote it do not count as a difference.
+
+use super::{AmuckOutcome, ExecutionOutcome};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Longest line kept in a [`LineChange`].
+const MAX_LINE: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Killed,
+    Survived,
+    EquivalentOutput,
+}
+
+impl Verdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            Verdict::Killed => "killed",
+            Verdict::Survived => "survived",
+            Verdict::EquivalentOutput => "equivalent-output",
+        }
+    }
+}
+
+/// How a mutant run differs from the baseline run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineComparison {
+    pub verdict: Verdict,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<ExitCodeChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<LineChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<LineChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExitCodeChange {
+    pub baseline: Option<i32>,
+    pub mutant: Option<i32>,
+}
+
+/// First line where a stream differs; a side is `None` when its output ended before it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineChange {
+    /// 1-based line number.
+    pub line: usize,
+    pub baseline: Option<String>,
+    pub mutant: Option<String>,
+}
+
+/// Compare a mutant run with the baseline run; `None` when either could not be started.
+pub fn compare(
+    baseline: &ExecutionOutcome,
+    baseline_file: &Path,
+    mutant: &ExecutionOutcome,
+    mutant_file: &Path,
+) -> Option<BaselineComparison> {
+    if baseline.spawn_error.is_some() || mutant.spawn_error.is_some() {
+        return None;
+    }
+    let exit_code = (baseline.success != mutant.success || baseline.exit_code != mutant.exit_code)
+        .then_some(ExitCodeChange {
+            baseline: baseline.exit_code,
+            mutant: mutant.exit_code,
+        });
+    let stdout = first_change(
+        &mask(&baseline.stdout, baseline_file),
+        &mask(&mutant.stdout, mutant_file),
+    );
+    let stderr = first_change(
+        &mask(&baseline.stderr, baseline_file),
+        &mask(&mutant.stderr, mutant_file),
+    );
+    let verdict = if exit_code.is_some() {
+        Verdict::Killed
+    } else if stdout.is_some() || stderr.is_some() {
+        Verdict::Survived
+    } else {
+        Verdict::EquivalentOutput
+    };
+    Some(BaselineComparison {
+        verdict,
+        exit_code,
+        stdout,
+        stderr,
+    })
+}
+
+/// Outcomes per verdict: `(killed, survived, equivalent_output)`.
+pub fn tally(outcomes: &[AmuckOutcome]) -> (usize, usize, usize) {
+    let count = |verdict| {
+        outcomes
+            .iter()
+            .filter(|outcome| {
+                outcome
+                    .comparison
+                    .as_ref()
+                    .is_some_and(|comparison| comparison.verdict == verdict)
+            })
+            .count()
+    };
+    (
+        count(Verdict::Killed),
+        count(Verdict::Survived),
+        count(Verdict::EquivalentOutput),
+    )
+}
+
+/// Replace the file under test's path, then its bare name, with `{file}`.
+fn mask(output: &str, file: &Path) -> String {
+    let mut masked = output.replace(&*file.to_string_lossy(), "{file}");
+    if let Some(name) = file.file_name().and_then(|name| name.to_str()) {
+        masked = masked.replace(name, "{file}");
+    }
+    masked
+}
+
+fn first_change(baseline: &str, mutant: &str) -> Option<LineChange> {
+    if baseline == mutant {
+        return None;
+    }
+    let mut baseline_lines = baseline.lines();
+    let mut mutant_lines = mutant.lines();
+    let mut line = 1;
+    loop {
+        match (baseline_lines.next(), mutant_lines.next()) {
+            (Some(a), Some(b)) if a == b => line += 1,
+            (None, None) => {
+                // Only trailing newlines differ.
+                return Some(LineChange {
+                    line,
+                    baseline: None,
+                    mutant: None,
+                });
+            }
+            (a, b) => {
+                return Some(LineChange {
+                    line,
+                    baseline: a.map(clip),
+                    mutant: b.map(clip),
+                })
+            }
+        }
+    }
+}
+
+fn clip(line: &str) -> String {
+    match line.char_indices().nth(MAX_LINE) {
+        Some((idx, _)) => format!("{}...", &line[..idx]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(exit_code: i32, stdout: &str, stderr: &str) -> ExecutionOutcome {
+        ExecutionOutcome {
+            success: exit_code == 0,
+            exit_code: Some(exit_code),
+            duration_ms: 1,
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+            spawn_error: None,
+            artifacts: Vec::new(),
+            identity: None,
+            syscall_summary: None,
+        }
+    }
+
+    #[test]
+    fn test_verdicts_mask_the_file_path() {
+        let base_file = Path::new("out/main.amuck.000.rs");
+        let mutant_file = Path::new("out/main.amuck.004.rs");
+        let baseline = run(0, "checked out/main.amuck.000.rs\nok\n", "");
+
+        let same = run(0, "checked out/main.amuck.004.rs\nok\n", "");
+        let comparison = compare(&baseline, base_file, &same, mutant_file).unwrap();
+        assert_eq!(comparison.verdict, Verdict::EquivalentOutput);
+
+        let changed = run(
+            0,
+            "checked out/main.amuck.004.rs\n",
+            "warning: main.amuck.004.rs",
+        );
+        let comparison = compare(&baseline, base_file, &changed, mutant_file).unwrap();
+        assert_eq!(comparison.verdict, Verdict::Survived);
+        assert_eq!(
+            comparison.stdout,
+            Some(LineChange {
+                line: 2,
+                baseline: Some("ok".to_string()),
+                mutant: None,
+            })
+        );
+        assert_eq!(
+            comparison.stderr.unwrap().mutant.as_deref(),
+            Some("warning: {file}")
+        );
+
+        let failed = run(101, "", "panicked");
+        let comparison = compare(&baseline, base_file, &failed, mutant_file).unwrap();
+        assert_eq!(comparison.verdict, Verdict::Killed);
+        assert_eq!(
+            comparison.exit_code,
+            Some(ExitCodeChange {
+                baseline: Some(0),
+                mutant: Some(101),
+            })
+        );
+
+        let mut unstarted = run(0, "", "");
+        unstarted.spawn_error = Some("not found".to_string());
+        assert!(compare(&baseline, base_file, &unstarted, mutant_file).is_none());
+    }
+}
@@ -0,0 +1,312 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Byte-level mutations for binary targets
+//!
+//! With `amuck --binary` the target is read as raw bytes and combinations use
+//! byte operations instead of text ones: flip bits of a byte at an offset,
+//! overwrite a header field, truncate the file, or inflate an integer length
+//! field to its maximum. Mutants of an image, archive or protocol capture then
+//! go to the parser under test through `--exec-program`. Text and byte
+//! operations do not mix; a combination using the wrong kind for the mode is
+//! reported as an apply error.
+
+use super::{MutationComboSpec, MutationOperation};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Bytes at the start of the file left alone by header corruption, so the parser still
+/// recognizes the format and gets past its magic check.
+const MAGIC_LEN: usize = 4;
+
+/// Header bytes scanned for values that look like lengths.
+const HEADER_SCAN: usize = 64;
+
+/// Length fields inflated by the dangerous preset.
+const MAX_LENGTH_FIELDS: usize = 3;
+
+/// Width and byte order of an integer length field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthField {
+    U16Le,
+    U16Be,
+    U32Le,
+    U32Be,
+    U64Le,
+    U64Be,
+}
+
+impl LengthField {
+    pub fn width(self) -> usize {
+        match self {
+            LengthField::U16Le | LengthField::U16Be => 2,
+            LengthField::U32Le | LengthField::U32Be => 4,
+            LengthField::U64Le | LengthField::U64Be => 8,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LengthField::U16Le => "u16_le",
+            LengthField::U16Be => "u16_be",
+            LengthField::U32Le => "u32_le",
+            LengthField::U32Be => "u32_be",
+            LengthField::U64Le => "u64_le",
+            LengthField::U64Be => "u64_be",
+        }
+    }
+
+    fn big_endian(self) -> bool {
+        matches!(
+            self,
+            LengthField::U16Be | LengthField::U32Be | LengthField::U64Be
+        )
+    }
+
+    fn read(self, bytes: &[u8]) -> u64 {
+        let fold = |value: u64, byte: &u8| value << 8 | u64::from(*byte);
+        if self.big_endian() {
+            bytes.iter().fold(0, fold)
+        } else {
+            bytes.iter().rev().fold(0, fold)
+        }
+    }
+}
+
+/// Whether `operation` works on bytes rather than text.
+pub fn is_binary(operation: &MutationOperation) -> bool {
+    matches!(
+        operation,
+        MutationOperation::FlipByte { .. }
+            | MutationOperation::OverwriteBytes { .. }
+            | MutationOperation::Truncate { .. }
+            | MutationOperation::InflateLength { .. }
+    )
+}
+
+/// Apply every operation to a copy of `source`, returning the mutant and the number of
+/// changes.
+pub fn apply_operations(
+    source: &[u8],
+    operations: &[MutationOperation],
+) -> Result<(Vec<u8>, usize)> {
+    let mut content = source.to_vec();
+    let mut changes = 0usize;
+    for operation in operations {
+        changes += apply_operation(&mut content, operation)?;
+    }
+    if changes == 0 {
+        return Err(anyhow!("combination produced no change"));
+    }
+    Ok((content, changes))
+}
+
+fn apply_operation(content: &mut Vec<u8>, operation: &MutationOperation) -> Result<usize> {
+    match operation {
+        MutationOperation::FlipByte { offset, mask } => {
+            let byte = content
+                .get_mut(*offset)
+                .ok_or_else(|| anyhow!("flip_byte: offset {} is outside the file", offset))?;
+            *byte ^= mask;
+            Ok(usize::from(*mask != 0))
+        }
+        MutationOperation::OverwriteBytes { offset, bytes } => {
+            let field = offset
+                .checked_add(bytes.len())
+                .and_then(|end| content.get_mut(*offset..end))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "overwrite_bytes: {} bytes at offset {} run past the end of the file",
+                        bytes.len(),
+                        offset
+                    )
+                })?;
+            let changed = field.iter().zip(bytes).filter(|(a, b)| a != b).count();
+            field.copy_from_slice(bytes);
+            Ok(changed)
+        }
+        MutationOperation::Truncate { length } => {
+            if *length >= content.len() {
+                return Ok(0);
+            }
+            content.truncate(*length);
+            Ok(1)
+        }
+        MutationOperation::InflateLength { offset, field } => {
+            let slot = offset
+                .checked_add(field.width())
+                .and_then(|end| content.get_mut(*offset..end))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "inflate_length: field at offset {} runs past the end of the file",
+                        offset
+                    )
+                })?;
+            if slot.iter().all(|byte| *byte == 0xFF) {
+                return Ok(0);
+            }
+            slot.fill(0xFF);
+            Ok(1)
+        }
+        text => Err(anyhow!(
+            "{} is a text operation; run without --binary to use it",
+            super::describe_operation(text)
+        )),
+    }
+}
+
+/// Whether `operation` would change `source`.
+pub fn can_change(source: &[u8], operation: &MutationOperation) -> bool {
+    apply_operation(&mut source.to_vec(), operation).is_ok_and(|changes| changes > 0)
+}
+
+/// Built-in byte combinations for `source`. The light preset flips the first and middle
+/// bytes and cuts the file in half; the dangerous one also corrupts the header after its
+/// magic, truncates inside the header and inflates the header values that look like lengths.
+pub fn built_in_combinations(dangerous: bool, source: &[u8]) -> Vec<MutationComboSpec> {
+    let len = source.len();
+    let combo = |name: String, operation: MutationOperation| MutationComboSpec {
+        name: Some(name),
+        operations: vec![operation],
+        weak_point: None,
+    };
+    let mut combos = vec![
+        combo(
+            "flip-first-byte".to_string(),
+            MutationOperation::FlipByte {
+                offset: 0,
+                mask: 0xFF,
+            },
+        ),
+        combo(
+            "flip-middle-byte".to_string(),
+            MutationOperation::FlipByte {
+                offset: len / 2,
+                mask: 0xFF,
+            },
+        ),
+        combo(
+            "truncate-half".to_string(),
+            MutationOperation::Truncate { length: len / 2 },
+        ),
+    ];
+
+    if dangerous {
+        let offset = if len > MAGIC_LEN { MAGIC_LEN } else { 0 };
+        let width = (len - offset).min(8);
+        combos.push(combo(
+            "header-corruption".to_string(),
+            MutationOperation::OverwriteBytes {
+                offset,
+                bytes: vec![0xFF; width],
+            },
+        ));
+        combos.push(combo(
+            "truncate-header".to_string(),
+            MutationOperation::Truncate {
+                length: len.min(HEADER_SCAN) / 4,
+            },
+        ));
+        for (offset, field) in length_fields(source) {
+            combos.push(combo(
+                format!("inflate-length-{}", offset),
+                MutationOperation::InflateLength { offset, field },
+            ));
+        }
+    }
+
+    combos
+        .into_iter()
+        .filter(|combo| {
+            combo
+                .operations
+                .iter()
+                .any(|operation| can_change(source, operation))
+        })
+        .collect()
+}
+
+/// Aligned 32-bit header values that could be lengths or offsets into the file: non-zero
+/// and no larger than the file itself.
+fn length_fields(source: &[u8]) -> Vec<(usize, LengthField)> {
+    let header = &source[..source.len().min(HEADER_SCAN)];
+    let plausible = |value: u64| value > 0 && value <= source.len() as u64;
+    let mut fields = Vec::new();
+    for (idx, chunk) in header.chunks_exact(4).enumerate() {
+        let offset = idx * 4;
+        if plausible(LengthField::U32Le.read(chunk)) {
+            fields.push((offset, LengthField::U32Le));
+        } else if plausible(LengthField::U32Be.read(chunk)) {
+            fields.push((offset, LengthField::U32Be));
+        }
+    }
+    fields.truncate(MAX_LENGTH_FIELDS);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_operations_and_mode_mismatch() {
+        // "PNGX", big-endian length 12, then 12 payload bytes.
+        let mut source = b"PNGX\x00\x00\x00\x0c".to_vec();
+        source.extend_from_slice(&[7u8; 12]);
+        let (mutated, changes) = apply_operations(
+            &source,
+            &[
+                MutationOperation::FlipByte {
+                    offset: 0,
+                    mask: 0x01,
+                },
+                MutationOperation::InflateLength {
+                    offset: 4,
+                    field: LengthField::U32Be,
+                },
+                MutationOperation::Truncate { length: 10 },
+            ],
+        )
+        .unwrap();
+        assert_eq!(changes, 3);
+        assert_eq!(mutated, b"QNGX\xff\xff\xff\xff\x07\x07");
+
+        assert_eq!(length_fields(&source), vec![(4, LengthField::U32Be)]);
+        let err = apply_operations(
+            &source,
+            &[MutationOperation::AppendText {
+                text: "x".to_string(),
+            }],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("run without --binary"));
+        assert!(apply_operations(
+            &source,
+            &[MutationOperation::OverwriteBytes {
+                offset: 18,
+                bytes: vec![0; 4],
+            }],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_built_in_presets_skip_no_op_combinations() {
+        let source = b"MAGC\x10\x00\x00\x00abcdefghijkl".to_vec();
+        let names = |dangerous| -> Vec<String> {
+            built_in_combinations(dangerous, &source)
+                .into_iter()
+                .filter_map(|combo| combo.name)
+                .collect()
+        };
+        assert_eq!(
+            names(false),
+            ["flip-first-byte", "flip-middle-byte", "truncate-half"]
+        );
+        assert_eq!(
+            names(true)[3..],
+            ["header-corruption", "truncate-header", "inflate-length-4"]
+        );
+        assert!(built_in_combinations(true, &[]).is_empty());
+    }
+}
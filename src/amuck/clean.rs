@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Workspace cleanup for mutated variants (`panic-attack amuck clean`)
+//!
+//! amuck never edits its target in place; every mutant is a copy in the output
+//! directory (`<stem>.amuck.NNN.<ext>`), so reverting a campaign means deleting
+//! those copies. Without cleanup they pile up across campaigns. `clean` removes
+//! them by age (`--older-than 7d`), keeps the newest few (`--keep-last N`), and
+//! with `--keep failed-only` removes only the variants whose exec check passed
+//! according to the amuck reports given, keeping the ones that broke something.
+//! A run can do the same as it goes with `amuck --keep failed-only`.
+
+use super::AmuckReport;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Which mutated variants survive once their exec check has run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+#[value(rename_all = "kebab-case")]
+pub enum KeepMode {
+    /// Keep every variant.
+    #[default]
+    All,
+    /// Delete variants whose exec check passed; keep the ones that failed.
+    FailedOnly,
+}
+
+#[derive(Debug, Clone)]
+pub struct CleanOptions {
+    /// Directory the variants were written to (amuck's `--output-dir`).
+    pub dir: PathBuf,
+    /// Only remove variants last modified before this time.
+    pub older_than: Option<DateTime<Utc>>,
+    /// Never remove the newest `keep_last` variants.
+    pub keep_last: Option<usize>,
+    pub keep: KeepMode,
+    /// Amuck reports whose outcomes tell which variants passed their exec check.
+    pub reports: Vec<PathBuf>,
+    /// List what would be removed without deleting anything.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CleanReport {
+    pub removed: Vec<PathBuf>,
+    pub kept: usize,
+    pub freed_bytes: u64,
+    pub dry_run: bool,
+}
+
+/// A mutated variant on disk.
+struct Variant {
+    path: PathBuf,
+    modified: DateTime<Utc>,
+    size: u64,
+}
+
+/// Remove the variants in `options.dir` that the retention options let go.
+pub fn clean(options: &CleanOptions) -> Result<CleanReport> {
+    if !options.dir.is_dir() {
+        return Err(anyhow!(
+            "variant directory {} does not exist",
+            options.dir.display()
+        ));
+    }
+    let passed = match options.keep {
+        KeepMode::All => None,
+        KeepMode::FailedOnly if options.reports.is_empty() => {
+            return Err(anyhow!(
+                "--keep failed-only needs the amuck reports of the runs (--report)"
+            ));
+        }
+        KeepMode::FailedOnly => Some(passed_variants(&options.reports)?),
+    };
+
+    let mut variants = variants(&options.dir)?;
+    variants.sort_by_key(|variant| std::cmp::Reverse(variant.modified));
+    let mut report = CleanReport {
+        dry_run: options.dry_run,
+        ..CleanReport::default()
+    };
+    for (idx, variant) in variants.into_iter().enumerate() {
+        let protected = options.keep_last.is_some_and(|keep_last| idx < keep_last);
+        let old_enough = options
+            .older_than
+            .is_none_or(|cutoff| variant.modified < cutoff);
+        let releasable = match &passed {
+            None => true,
+            Some(passed) => passed.iter().any(|(path, written)| {
+                *path == canonical(&variant.path) && variant.modified <= *written
+            }),
+        };
+        if protected || !old_enough || !releasable {
+            report.kept += 1;
+            continue;
+        }
+        if !options.dry_run {
+            fs::remove_file(&variant.path)
+                .with_context(|| format!("removing {}", variant.path.display()))?;
+        }
+        report.freed_bytes += variant.size;
+        report.removed.push(variant.path);
+    }
+    Ok(report)
+}
+
+/// Delete the variant a run just checked when `keep` says a pass is not worth keeping.
+/// Returns whether it was removed.
+pub fn discard_passed(keep: KeepMode, variant: &Path, passed: bool) -> Result<bool> {
+    if keep != KeepMode::FailedOnly || !passed {
+        return Ok(false);
+    }
+    fs::remove_file(variant).with_context(|| format!("removing {}", variant.display()))?;
+    Ok(true)
+}
+
+/// Whether `name` is a mutated variant: `<stem>.amuck.NNN[.<ext>]`.
+fn is_variant(name: &str) -> bool {
+    name.split_once(".amuck.").is_some_and(|(_, rest)| {
+        let id = rest.split('.').next().unwrap_or("");
+        id.len() >= 3 && id.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+fn variants(dir: &Path) -> Result<Vec<Variant>> {
+    let mut variants = Vec::new();
+    let entries = fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_name().to_str().is_some_and(is_variant) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        variants.push(Variant {
+            path: entry.path(),
+            modified: metadata.modified().map(DateTime::from).unwrap_or_default(),
+            size: metadata.len(),
+        });
+    }
+    Ok(variants)
+}
+
+/// Variants whose exec check passed, with the time their report was written. A variant
+/// rewritten after that (by a later run reusing its name) no longer matches the outcome.
+fn passed_variants(reports: &[PathBuf]) -> Result<Vec<(PathBuf, DateTime<Utc>)>> {
+    let mut passed = Vec::new();
+    for path in reports {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("reading amuck report {}", path.display()))?;
+        let report: AmuckReport = serde_json::from_str(&content)
+            .with_context(|| format!("parsing amuck report {}", path.display()))?;
+        let written = DateTime::parse_from_rfc3339(&report.created_at)
+            .map(|at| at.with_timezone(&Utc))
+            .with_context(|| format!("{}: invalid created_at", path.display()))?;
+        for outcome in &report.outcomes {
+            let Some(file) = &outcome.mutated_file else {
+                continue;
+            };
+            if outcome.execution.as_ref().is_some_and(|exec| exec.success) {
+                passed.push((canonical(file), written));
+            }
+        }
+    }
+    Ok(passed)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amuck::{AmuckOutcome, ExecutionOutcome};
+    use tempfile::TempDir;
+
+    fn outcome(file: PathBuf, success: bool) -> AmuckOutcome {
+        AmuckOutcome {
+            id: 1,
+            name: "combo".to_string(),
+            operations: Vec::new(),
+            applied_changes: 1,
+            weak_point: None,
+            mutated_file: Some(file),
+            apply_error: None,
+            execution: Some(ExecutionOutcome {
+                success,
+                exit_code: Some(if success { 0 } else { 1 }),
+                duration_ms: 1,
+                stdout: String::new(),
+                stderr: String::new(),
+                spawn_error: None,
+                artifacts: Vec::new(),
+                identity: None,
+                syscall_summary: None,
+            }),
+            comparison: None,
+            cleaned: false,
+        }
+    }
+
+    #[test]
+    fn test_failed_only_keeps_failures_and_unrelated_files() {
+        let dir = TempDir::new().unwrap();
+        let variant = |id: usize| dir.path().join(format!("main.amuck.{:03}.rs", id));
+        for id in 1..=3 {
+            fs::write(variant(id), "mutant").unwrap();
+        }
+        fs::write(dir.path().join("main.from-assail.json"), "{}").unwrap();
+        let report_path = dir.path().join("amuck.json");
+        let report = AmuckReport {
+            created_at: (Utc::now() + chrono::Duration::minutes(1)).to_rfc3339(),
+            target: PathBuf::from("main.rs"),
+            source_spec: None,
+            preset: "light".to_string(),
+            max_combinations: 3,
+            seed: 0,
+            output_dir: dir.path().to_path_buf(),
+            combinations_planned: 2,
+            combinations_run: 2,
+            baseline: None,
+            outcomes: vec![outcome(variant(1), true), outcome(variant(2), false)],
+        };
+        fs::write(&report_path, serde_json::to_string(&report).unwrap()).unwrap();
+
+        let mut options = CleanOptions {
+            dir: dir.path().to_path_buf(),
+            older_than: None,
+            keep_last: None,
+            keep: KeepMode::FailedOnly,
+            reports: Vec::new(),
+            dry_run: false,
+        };
+        assert!(clean(&options).is_err());
+        options.reports = vec![report_path];
+        let cleaned = clean(&options).unwrap();
+        assert_eq!(cleaned.removed, vec![variant(1)]);
+        assert_eq!(cleaned.kept, 2);
+        assert!(variant(2).exists() && variant(3).exists());
+        assert!(dir.path().join("main.from-assail.json").exists());
+
+        // Plain retention: the newest variant stays, nothing is old enough for a past cutoff.
+        options.keep = KeepMode::All;
+        options.older_than = Some(Utc::now() - chrono::Duration::days(7));
+        assert!(clean(&options).unwrap().removed.is_empty());
+        options.older_than = None;
+        options.keep_last = Some(1);
+        assert_eq!(clean(&options).unwrap().removed.len(), 1);
+        assert!(is_variant("image.amuck.012"));
+        assert!(!is_variant("notes.amuck.txt"));
+    }
+}
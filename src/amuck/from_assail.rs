@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Mutation specs generated from assail weak points
+//!
+//! `amuck --from-assail REPORT` turns the weak points an assail (or assault)
+//! report found in the target file into line-addressed combinations: drop the
+//! guard just above the site, delete the site's statement, or move a
+//! comparison on it off by one. Weak points with a line use it; file-level
+//! ones are located through the constructs their category flags (`.unwrap()`
+//! for panic paths, `let _ =` for unchecked errors, ...). Each combination
+//! carries the weak point's triage ID (`PA005@src/io.rs`) into its outcome.
+
+use super::{MutationComboSpec, MutationOperation, MutationSpecFile};
+use crate::report::sarif::{parse_location, rule_id};
+use crate::storage::write_atomic;
+use crate::triage::weak_point_id;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Sites mutated per file-level weak point.
+const MAX_SITES: usize = 3;
+
+/// Lines above a site searched for the guard that protects it.
+const GUARD_WINDOW: usize = 3;
+
+/// Boundary mutations, spaced so generics and arrows are left alone.
+const COMPARISONS: &[(&str, &str)] = &[
+    (" <= ", " < "),
+    (" >= ", " > "),
+    (" == ", " != "),
+    (" != ", " == "),
+    (" < ", " <= "),
+    (" > ", " >= "),
+];
+
+/// Load the assail report on its own or inside an assault report (JSON or YAML).
+pub fn load(path: &Path) -> Result<AssailReport> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let yaml = matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml") | Some("yml")
+    );
+    let parsed = if yaml {
+        serde_yaml::from_str::<AssaultReport>(&content)
+            .map(|assault| assault.assail_report)
+            .or_else(|_| serde_yaml::from_str::<AssailReport>(&content).map_err(|e| e.to_string()))
+    } else {
+        serde_json::from_str::<AssaultReport>(&content)
+            .map(|assault| assault.assail_report)
+            .or_else(|_| serde_json::from_str::<AssailReport>(&content).map_err(|e| e.to_string()))
+    };
+    parsed.map_err(|err| {
+        anyhow!(
+            "{} is neither an assail nor an assault report: {}",
+            path.display(),
+            err
+        )
+    })
+}
+
+/// Combinations targeting the weak points `report` found in `target`, whose text is `source`.
+pub fn combos(report: &AssailReport, target: &Path, source: &str) -> Vec<MutationComboSpec> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut combos = Vec::new();
+    for wp in &report.weak_points {
+        let Some(location) = &wp.location else {
+            continue;
+        };
+        let (path, line) = parse_location(location);
+        if !target.ends_with(path) {
+            continue;
+        }
+        let sites = match line {
+            Some(line) => vec![line as usize],
+            None => find_sites(wp.category, &lines),
+        };
+        let id = weak_point_id(wp);
+        for site in sites {
+            for (kind, operation) in site_mutations(&lines, site) {
+                combos.push(MutationComboSpec {
+                    name: Some(format!("{}-{}-L{}", rule_id(&wp.category), kind, site)),
+                    operations: vec![operation],
+                    weak_point: Some(id.clone()),
+                });
+            }
+        }
+    }
+    combos
+}
+
+/// Generate the spec for `target` from `report_path` into `output_dir`, returning its path.
+pub fn write_spec(report_path: &Path, target: &Path, output_dir: &Path) -> Result<PathBuf> {
+    let report = load(report_path)?;
+    let source = fs::read_to_string(target)
+        .with_context(|| format!("reading target file {}", target.display()))?;
+    let combos = combos(&report, target, &source);
+    if combos.is_empty() {
+        return Err(anyhow!(
+            "{} has no weak points with mutable sites in {}",
+            report_path.display(),
+            target.display()
+        ));
+    }
+    fs::create_dir_all(output_dir)
+        .with_context(|| format!("creating output directory {}", output_dir.display()))?;
+    let stem = target
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("target");
+    let path = output_dir.join(format!("{}.from-assail.json", stem));
+    let json = serde_json::to_string_pretty(&MutationSpecFile { combos })?;
+    write_atomic(&path, json).with_context(|| format!("writing spec {}", path.display()))?;
+    Ok(path)
+}
+
+/// 1-based lines holding the construct a file-level weak point of `category` flags.
+fn find_sites(category: WeakPointCategory, lines: &[&str]) -> Vec<usize> {
+    let needles: &[&str] = match category {
+        WeakPointCategory::PanicPath => &[".unwrap()", ".expect(", "panic!(", "unreachable!("],
+        WeakPointCategory::UncheckedError => &["let _ =", ".ok();", "_ = "],
+        WeakPointCategory::UnsafeCode
+        | WeakPointCategory::UnsafeFFI
+        | WeakPointCategory::RawPointerDeref
+        | WeakPointCategory::UnsafeTransmute => &["unsafe"],
+        WeakPointCategory::UncheckedAllocation => &["with_capacity(", "vec![", "alloc("],
+        WeakPointCategory::UnboundedLoop => &["loop {", "while "],
+        WeakPointCategory::BlockingIO => &["read_to_string(", ".read(", ".write("],
+        WeakPointCategory::RaceCondition | WeakPointCategory::DeadlockPotential => {
+            &[".lock()", ".write()", ".read()"]
+        }
+        WeakPointCategory::ResourceLeak => &["File::open(", "File::create(", "open("],
+        WeakPointCategory::CommandInjection | WeakPointCategory::DynamicCodeExecution => {
+            &["Command::new(", "system(", "exec(", "eval("]
+        }
+        WeakPointCategory::PathTraversal => &[".join(", "../"],
+        _ => &[],
+    };
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            let code = line.trim_start();
+            !code.starts_with("//") && needles.iter().any(|needle| line.contains(needle))
+        })
+        .map(|(idx, _)| idx + 1)
+        .take(MAX_SITES)
+        .collect()
+}
+
+/// The mutations that apply at `site`: dropping its guard, deleting its statement and
+/// moving a comparison on it across the boundary.
+fn site_mutations(lines: &[&str], site: usize) -> Vec<(&'static str, MutationOperation)> {
+    let Some(text) = site.checked_sub(1).and_then(|idx| lines.get(idx)) else {
+        return Vec::new();
+    };
+    let mut mutations = Vec::new();
+    if let Some(guard) = find_guard(lines, site) {
+        mutations.push(("drop-guard", guard));
+    }
+    if text.trim_end().ends_with(';') {
+        mutations.push((
+            "delete-site",
+            MutationOperation::DeleteLine { line: site as u32 },
+        ));
+    }
+    if let Some((from, to)) = COMPARISONS.iter().find(|(from, _)| text.contains(from)) {
+        mutations.push((
+            "flip-bound",
+            MutationOperation::ReplaceInLine {
+                line: site as u32,
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        ));
+    }
+    mutations
+}
+
+/// Neutralize the nearest guard at or above `site`: an `if` never fires, an assertion or
+/// early error return is deleted.
+fn find_guard(lines: &[&str], site: usize) -> Option<MutationOperation> {
+    let first = site.saturating_sub(GUARD_WINDOW).max(1);
+    (first..=site).rev().find_map(|line| {
+        let code = lines.get(line - 1)?.trim_start();
+        if code.starts_with("if ") || code.starts_with("} else if ") {
+            Some(MutationOperation::ReplaceInLine {
+                line: line as u32,
+                from: "if ".to_string(),
+                to: "if false && ".to_string(),
+            })
+        } else if ["assert!", "assert_eq!", "ensure!(", "bail!(", "return Err"]
+            .iter()
+            .any(|guard| code.starts_with(guard))
+            && code.trim_end().ends_with(';')
+        {
+            Some(MutationOperation::DeleteLine { line: line as u32 })
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+fn read(path: &str, limit: usize) -> Result<String> {
+    let text = fs::read_to_string(path).unwrap();
+    if text.len() > limit {
+        let _ = log(\"too long\");
+    }
+    Ok(text)
+}
+";
+
+    fn weak_point(category: WeakPointCategory, location: &str) -> WeakPoint {
+        WeakPoint {
+            category,
+            location: Some(location.to_string()),
+            severity: Severity::Medium,
+            description: String::new(),
+            recommended_attack: Vec::new(),
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn test_combos_target_flagged_lines_and_link_weak_points() {
+        let report = AssailReport {
+            program_path: PathBuf::from("project"),
+            language: Language::Rust,
+            weak_points: vec![
+                weak_point(WeakPointCategory::UncheckedError, "src/io.rs:4"),
+                weak_point(WeakPointCategory::PanicPath, "src/io.rs"),
+                weak_point(WeakPointCategory::PanicPath, "src/other.rs"),
+            ],
+            ..Default::default()
+        };
+        let combos = combos(&report, Path::new("project/src/io.rs"), SOURCE);
+        let names: Vec<_> = combos.iter().filter_map(|c| c.name.as_deref()).collect();
+        assert_eq!(
+            names,
+            [
+                "PA018-drop-guard-L4",
+                "PA018-delete-site-L4",
+                "PA005-delete-site-L2",
+            ]
+        );
+        assert_eq!(combos[0].weak_point.as_deref(), Some("PA018@src/io.rs:4"));
+        assert_eq!(combos[2].weak_point.as_deref(), Some("PA005@src/io.rs"));
+
+        // The guard over the unchecked error never fires in the mutant.
+        let (mutated, changes) =
+            super::super::apply_operations(SOURCE, &combos[0].operations).unwrap();
+        assert_eq!(changes, 1);
+        assert!(mutated.contains("    if false && text.len() > limit {\n"));
+        let guard_flip = site_mutations(&SOURCE.lines().collect::<Vec<_>>(), 3);
+        assert!(matches!(
+            &guard_flip[1].1,
+            MutationOperation::ReplaceInLine { from, .. } if from == " > "
+        ));
+    }
+}
@@ -2,7 +2,23 @@
 
 //! Amuck mutation runner for stress-testing source files with combination attacks.
 
+pub mod baseline;
+pub mod binary;
+pub mod clean;
+pub mod from_assail;
+
+use crate::progress::Progress;
+use crate::report::artifacts::{self, ArtifactStore};
+use crate::run_as::{self, ProcessIdentity, RunAs};
+use crate::schema::{self, SchemaKind};
+use crate::seed::SeededRng;
+use crate::storage::write_atomic;
+use crate::syscalls::{self, SyscallSummary, Traced};
+use crate::types::OutputArtifact;
 use anyhow::{anyhow, Context, Result};
+use baseline::BaselineComparison;
+use binary::LengthField;
+use clean::KeepMode;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -23,6 +39,16 @@ pub struct AmuckConfig {
     pub max_combinations: usize,
     pub output_dir: PathBuf,
     pub execute: Option<ExecutionCommand>,
+    /// Campaign seed; picks which combinations run when more exist than `max_combinations`.
+    pub seed: u64,
+    /// Mutate the target as raw bytes with the operations in `binary`.
+    pub binary: bool,
+    /// Which variants stay on disk after their exec check.
+    pub keep: KeepMode,
+    /// Account the exec program is switched to.
+    pub run_as: Option<RunAs>,
+    /// Run the exec program under strace and summarize its syscalls.
+    pub trace_syscalls: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +69,12 @@ pub enum MutationOperation {
     SwapTokens { left: String, right: String },
     AppendText { text: String },
     PrependText { text: String },
+    DeleteLine { line: u32 },
+    ReplaceInLine { line: u32, from: String, to: String },
+    FlipByte { offset: usize, mask: u8 },
+    OverwriteBytes { offset: usize, bytes: Vec<u8> },
+    Truncate { length: usize },
+    InflateLength { offset: usize, field: LengthField },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +82,9 @@ pub struct MutationComboSpec {
     #[serde(default)]
     pub name: Option<String>,
     pub operations: Vec<MutationOperation>,
+    /// Triage ID of the weak point the combination targets (see `from_assail`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weak_point: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,9 +100,14 @@ pub struct AmuckReport {
     pub source_spec: Option<PathBuf>,
     pub preset: String,
     pub max_combinations: usize,
+    #[serde(default)]
+    pub seed: u64,
     pub output_dir: PathBuf,
     pub combinations_planned: usize,
     pub combinations_run: usize,
+    /// Exec run on the pristine target that every mutant run is compared with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<ExecutionOutcome>,
     pub outcomes: Vec<AmuckOutcome>,
 }
 
@@ -77,12 +117,21 @@ pub struct AmuckOutcome {
     pub name: String,
     pub operations: Vec<String>,
     pub applied_changes: usize,
+    /// Weak point the combination targets, carried over from the spec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weak_point: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mutated_file: Option<PathBuf>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub apply_error: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub execution: Option<ExecutionOutcome>,
+    /// How the exec run differs from the baseline run (see `baseline`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<BaselineComparison>,
+    /// The variant passed its exec check and was deleted (`--keep failed-only`).
+    #[serde(default)]
+    pub cleaned: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,6 +143,15 @@ pub struct ExecutionOutcome {
     pub stderr: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spawn_error: Option<String>,
+    /// Full stdout/stderr when too large to inline (see `report::artifacts`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<OutputArtifact>,
+    /// uid/gid the program ran with (see `run_as`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<ProcessIdentity>,
+    /// Files, network contacts and processes seen with `--trace-syscalls`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub syscall_summary: Option<SyscallSummary>,
 }
 
 pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
@@ -101,6 +159,10 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
         return Err(anyhow!("--max-combinations must be at least 1"));
     }
 
+    if config.trace_syscalls && config.execute.is_some() {
+        syscalls::ensure_available()?;
+    }
+
     if !config.target.exists() {
         return Err(anyhow!(
             "target file {} does not exist",
@@ -114,13 +176,25 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
         ));
     }
 
-    // Source text is loaded once and each combo is applied from the pristine baseline.
-    let source = fs::read_to_string(&config.target)
+    // Source is loaded once and each combo is applied from the pristine baseline.
+    let bytes = fs::read(&config.target)
         .with_context(|| format!("reading target file {}", config.target.display()))?;
+    let source = if config.binary {
+        String::new()
+    } else {
+        String::from_utf8(bytes.clone()).map_err(|_| {
+            anyhow!(
+                "target file {} is not UTF-8 text; use --binary to mutate its bytes",
+                config.target.display()
+            )
+        })?
+    };
 
     let mut combos = if let Some(spec_path) = &config.spec_path {
         let spec = load_spec(spec_path)?;
         spec.combos
+    } else if config.binary {
+        binary::built_in_combinations(config.preset == AmuckPreset::Dangerous, &bytes)
     } else {
         built_in_combinations(config.preset, &source)
     };
@@ -129,45 +203,77 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
         return Err(anyhow!("no mutation combinations available"));
     }
 
-    combos.truncate(config.max_combinations);
+    if combos.len() > config.max_combinations {
+        // Sample rather than truncate so later combinations get coverage across seeds.
+        let mut rng = SeededRng::derive(config.seed, "amuck");
+        let keep = rng.sample_indices(combos.len(), config.max_combinations);
+        combos = combos
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| keep.binary_search(idx).is_ok())
+            .map(|(_, combo)| combo)
+            .collect();
+    }
     fs::create_dir_all(&config.output_dir)
         .with_context(|| format!("creating output directory {}", config.output_dir.display()))?;
 
+    // The exec program's verdict on the pristine target is what each mutant is judged against.
+    let baseline = match &config.execute {
+        Some(exec) => {
+            let pristine = mutation_path(&config.target, &config.output_dir, 0);
+            fs::write(&pristine, &bytes)
+                .with_context(|| format!("writing baseline copy {}", pristine.display()))?;
+            let outcome = run_execution(exec, &pristine, &config).unwrap_or_else(spawn_failure);
+            Some((outcome, pristine))
+        }
+        None => None,
+    };
+
     // Each combination yields an independent artifact to preserve reproducibility and diffability.
     let mut outcomes = Vec::with_capacity(combos.len());
+    let progress = Progress::new("Amuck", combos.len());
     for (idx, combo) in combos.iter().enumerate() {
         let id = idx + 1;
         let name = combo
             .name
             .clone()
             .unwrap_or_else(|| format!("combo-{:03}", id));
+        progress.begin(name.as_str());
         let operation_labels = combo.operations.iter().map(describe_operation).collect();
 
-        match apply_operations(&source, &combo.operations) {
+        let applied = if config.binary {
+            binary::apply_operations(&bytes, &combo.operations)
+        } else {
+            apply_operations(&source, &combo.operations)
+                .map(|(mutated, changes)| (mutated.into_bytes(), changes))
+        };
+        match applied {
             Ok((mutated, applied_changes)) => {
                 let mutated_file = mutation_path(&config.target, &config.output_dir, id);
-                match fs::write(&mutated_file, mutated.as_bytes()) {
+                match fs::write(&mutated_file, mutated) {
                     Ok(()) => {
                         let execution = config.execute.as_ref().map(|exec| {
-                            run_execution(exec, &mutated_file).unwrap_or_else(|err| {
-                                ExecutionOutcome {
-                                    success: false,
-                                    exit_code: None,
-                                    duration_ms: 0,
-                                    stdout: String::new(),
-                                    stderr: String::new(),
-                                    spawn_error: Some(err.to_string()),
-                                }
-                            })
+                            run_execution(exec, &mutated_file, &config)
+                                .unwrap_or_else(spawn_failure)
                         });
+                        let comparison = baseline.as_ref().zip(execution.as_ref()).and_then(
+                            |((baseline, pristine), execution)| {
+                                baseline::compare(baseline, pristine, execution, &mutated_file)
+                            },
+                        );
+                        let passed = execution.as_ref().is_some_and(|exec| exec.success);
+                        let cleaned = clean::discard_passed(config.keep, &mutated_file, passed)?;
                         outcomes.push(AmuckOutcome {
                             id,
                             name,
                             operations: operation_labels,
                             applied_changes,
+                            weak_point: combo.weak_point.clone(),
                             mutated_file: Some(mutated_file),
                             apply_error: None,
                             execution,
+                            comparison,
+                            cleaned,
                         });
                     }
                     Err(err) => {
@@ -176,9 +282,12 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
                             name,
                             operations: operation_labels,
                             applied_changes,
+                            weak_point: combo.weak_point.clone(),
                             mutated_file: None,
                             apply_error: Some(format!("write error: {}", err)),
                             execution: None,
+                            comparison: None,
+                            cleaned: false,
                         });
                     }
                 }
@@ -189,13 +298,18 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
                     name,
                     operations: operation_labels,
                     applied_changes: 0,
+                    weak_point: combo.weak_point.clone(),
                     mutated_file: None,
                     apply_error: Some(err.to_string()),
                     execution: None,
+                    comparison: None,
+                    cleaned: false,
                 });
             }
         }
+        progress.inc();
     }
+    drop(progress);
 
     let combinations_run = outcomes.iter().filter(|o| o.mutated_file.is_some()).count();
     let report = AmuckReport {
@@ -207,9 +321,11 @@ pub fn run(config: AmuckConfig) -> Result<AmuckReport> {
             AmuckPreset::Dangerous => "dangerous".to_string(),
         },
         max_combinations: config.max_combinations,
+        seed: config.seed,
         output_dir: config.output_dir,
         combinations_planned: outcomes.len(),
         combinations_run,
+        baseline: baseline.map(|(outcome, _)| outcome),
         outcomes,
     };
     Ok(report)
@@ -220,12 +336,29 @@ pub fn write_report(report: &AmuckReport, path: &Path) -> Result<()> {
         fs::create_dir_all(parent)
             .with_context(|| format!("creating report parent directory {}", parent.display()))?;
     }
-    let json = serde_json::to_string_pretty(report).context("serializing amuck report")?;
-    fs::write(path, json).with_context(|| format!("writing report {}", path.display()))?;
+    let mut report = report.clone();
+    let store = ArtifactStore::beside(path);
+    let executions = report
+        .outcomes
+        .iter_mut()
+        .filter_map(|outcome| outcome.execution.as_mut());
+    for execution in report.baseline.iter_mut().chain(executions) {
+        let fields = [
+            ("stdout", &mut execution.stdout),
+            ("stderr", &mut execution.stderr),
+        ];
+        artifacts::spill_fields(&store, &mut execution.artifacts, fields)?;
+    }
+    let json = serde_json::to_string_pretty(&report).context("serializing amuck report")?;
+    write_atomic(path, json).with_context(|| format!("writing report {}", path.display()))?;
     Ok(())
 }
 
-fn run_execution(command: &ExecutionCommand, mutated_file: &Path) -> Result<ExecutionOutcome> {
+fn run_execution(
+    command: &ExecutionCommand,
+    mutated_file: &Path,
+    config: &AmuckConfig,
+) -> Result<ExecutionOutcome> {
     let mut args = command.args.clone();
     if args.is_empty() || !args.iter().any(|arg| arg.contains("{file}")) {
         args.push("{file}".to_string());
@@ -237,9 +370,21 @@ fn run_execution(command: &ExecutionCommand, mutated_file: &Path) -> Result<Exec
         .map(|arg| arg.replace("{file}", &file_token))
         .collect::<Vec<_>>();
 
+    let traced = config
+        .trace_syscalls
+        .then(|| Traced::new(&command.program, &resolved_args));
+    let (program, args) = match &traced {
+        Some(traced) => (&traced.program, &traced.args),
+        None => (&command.program, &resolved_args),
+    };
+    let run_as = config.run_as.as_ref();
+    let mut process = Command::new(program);
+    if let Some(run_as) = run_as {
+        run_as.apply(&mut process);
+    }
     let started = Instant::now();
-    let output = Command::new(&command.program)
-        .args(&resolved_args)
+    let output = process
+        .args(args)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -251,19 +396,27 @@ fn run_execution(command: &ExecutionCommand, mutated_file: &Path) -> Result<Exec
         success: output.status.success(),
         exit_code: output.status.code(),
         duration_ms,
-        stdout: clamp_output(String::from_utf8_lossy(&output.stdout).to_string()),
-        stderr: clamp_output(String::from_utf8_lossy(&output.stderr).to_string()),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
         spawn_error: None,
+        artifacts: Vec::new(),
+        identity: run_as::identity(run_as),
+        syscall_summary: traced.and_then(Traced::finish),
     })
 }
 
-fn clamp_output(mut value: String) -> String {
-    const MAX_LEN: usize = 8192;
-    if value.len() > MAX_LEN {
-        value.truncate(MAX_LEN);
-        value.push_str("\n...<truncated>");
+fn spawn_failure(err: anyhow::Error) -> ExecutionOutcome {
+    ExecutionOutcome {
+        success: false,
+        exit_code: None,
+        duration_ms: 0,
+        stdout: String::new(),
+        stderr: String::new(),
+        spawn_error: Some(err.to_string()),
+        artifacts: Vec::new(),
+        identity: None,
+        syscall_summary: None,
     }
-    value
 }
 
 fn mutation_path(target: &Path, output_dir: &Path, id: usize) -> PathBuf {
@@ -283,6 +436,7 @@ fn mutation_path(target: &Path, output_dir: &Path, id: usize) -> PathBuf {
 fn load_spec(path: &Path) -> Result<MutationSpecFile> {
     let content =
         fs::read_to_string(path).with_context(|| format!("reading spec {}", path.display()))?;
+    schema::check_document(SchemaKind::AmuckSpec, &content, path)?;
     let spec =
         match path.extension().and_then(|ext| ext.to_str()) {
             Some("json") => serde_json::from_str(&content)
@@ -313,6 +467,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                     to: "true".to_string(),
                 },
             ],
+            weak_point: None,
         },
         MutationComboSpec {
             name: Some("comparison-flip".to_string()),
@@ -326,6 +481,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                     to: "<=".to_string(),
                 },
             ],
+            weak_point: None,
         },
         MutationComboSpec {
             name: Some("mutation-marker".to_string()),
@@ -337,6 +493,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                     text: "\n/* amuck: end marker */\n".to_string(),
                 },
             ],
+            weak_point: None,
         },
     ];
 
@@ -352,6 +509,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                         needle: "guard".to_string(),
                     },
                 ],
+                weak_point: None,
             },
             MutationComboSpec {
                 name: Some("auth-bypass-token-swap".to_string()),
@@ -365,6 +523,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                         right: "reject".to_string(),
                     },
                 ],
+                weak_point: None,
             },
             MutationComboSpec {
                 name: Some("dup-dangerous-calls".to_string()),
@@ -378,6 +537,7 @@ fn built_in_combinations(preset: AmuckPreset, source: &str) -> Vec<MutationCombo
                         times: 1,
                     },
                 ],
+                weak_point: None,
             },
         ]);
     }
@@ -412,6 +572,17 @@ fn operation_can_change_source(source: &str, operation: &MutationOperation) -> b
         MutationOperation::AppendText { text } | MutationOperation::PrependText { text } => {
             !text.is_empty()
         }
+        MutationOperation::DeleteLine { line } => {
+            *line >= 1 && *line as usize <= source.lines().count()
+        }
+        MutationOperation::ReplaceInLine { line, from, .. } => {
+            !from.is_empty()
+                && line
+                    .checked_sub(1)
+                    .and_then(|idx| source.lines().nth(idx as usize))
+                    .is_some_and(|text| text.contains(from.as_str()))
+        }
+        binary => !binary::is_binary(binary),
     }
 }
 
@@ -554,9 +725,51 @@ fn apply_operation(content: &mut String, operation: &MutationOperation) -> Resul
             content.insert_str(0, text);
             Ok(1)
         }
+        MutationOperation::DeleteLine { line } => edit_line(content, *line, |_| None)
+            .ok_or_else(|| anyhow!("delete_line: line {} is outside the file", line)),
+        MutationOperation::ReplaceInLine { line, from, to } => {
+            if from.is_empty() {
+                return Err(anyhow!("replace_in_line cannot use empty 'from' token"));
+            }
+            edit_line(content, *line, |text| {
+                Some(text.replacen(from.as_str(), to, 1))
+            })
+            .ok_or_else(|| anyhow!("replace_in_line: line {} is outside the file", line))
+        }
+        binary => Err(anyhow!(
+            "{} is a byte operation; run with --binary to use it",
+            describe_operation(binary)
+        )),
     }
 }
 
+/// Rewrite the 1-based `line` with `edit` (`None` deletes it); `None` when there is no such
+/// line, else the number of lines changed.
+fn edit_line(
+    content: &mut String,
+    line: u32,
+    edit: impl FnOnce(&str) -> Option<String>,
+) -> Option<usize> {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let idx = (line as usize)
+        .checked_sub(1)
+        .filter(|idx| *idx < lines.len())?;
+    let before = lines[idx].clone();
+    match edit(&before) {
+        Some(after) if after == before => return Some(0),
+        Some(after) => lines[idx] = after,
+        None => {
+            lines.remove(idx);
+        }
+    }
+    let trailing_newline = content.ends_with('\n');
+    *content = lines.join("\n");
+    if trailing_newline {
+        content.push('\n');
+    }
+    Some(1)
+}
+
 fn describe_operation(operation: &MutationOperation) -> String {
     match operation {
         MutationOperation::ReplaceFirst { from, to } => {
@@ -580,6 +793,20 @@ fn describe_operation(operation: &MutationOperation) -> String {
         }
         MutationOperation::AppendText { .. } => "append_text(...)".to_string(),
         MutationOperation::PrependText { .. } => "prepend_text(...)".to_string(),
+        MutationOperation::DeleteLine { line } => format!("delete_line({})", line),
+        MutationOperation::ReplaceInLine { line, from, to } => {
+            format!("replace_in_line({}, '{}' -> '{}')", line, from, to)
+        }
+        MutationOperation::FlipByte { offset, mask } => {
+            format!("flip_byte({:#x}, ^{:#04x})", offset, mask)
+        }
+        MutationOperation::OverwriteBytes { offset, bytes } => {
+            format!("overwrite_bytes({:#x}, {} bytes)", offset, bytes.len())
+        }
+        MutationOperation::Truncate { length } => format!("truncate({})", length),
+        MutationOperation::InflateLength { offset, field } => {
+            format!("inflate_length({:#x}, {})", offset, field.label())
+        }
     }
 }
 
@@ -632,6 +859,7 @@ mod tests {
                     from: "true".to_string(),
                     to: "false".to_string(),
                 }],
+                weak_point: None,
             }],
         };
         fs::write(
@@ -648,6 +876,11 @@ mod tests {
             max_combinations: 8,
             output_dir: output_dir.clone(),
             execute: None,
+            seed: 0,
+            binary: false,
+            keep: KeepMode::All,
+            run_as: None,
+            trace_syscalls: false,
         })
         .expect("amuck should run");
 
@@ -661,4 +894,101 @@ mod tests {
         let mutated_body = fs::read_to_string(mutated).expect("mutated file should read");
         assert!(mutated_body.contains("false"));
     }
+
+    #[test]
+    fn seeded_selection_is_reproducible() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let target = dir.path().join("sample.rs");
+        fs::write(&target, "let a = 1;\n").expect("target should write");
+
+        let spec_path = dir.path().join("spec.json");
+        let spec = MutationSpecFile {
+            combos: (0..6)
+                .map(|idx| MutationComboSpec {
+                    name: Some(format!("append-{}", idx)),
+                    operations: vec![MutationOperation::AppendText {
+                        text: format!("// {}\n", idx),
+                    }],
+                    weak_point: None,
+                })
+                .collect(),
+        };
+        fs::write(
+            &spec_path,
+            serde_json::to_string_pretty(&spec).expect("spec should serialize"),
+        )
+        .expect("spec should write");
+
+        let names = |seed: u64, out: &str| -> Vec<String> {
+            let report = run(AmuckConfig {
+                target: target.clone(),
+                spec_path: Some(spec_path.clone()),
+                preset: AmuckPreset::Light,
+                max_combinations: 3,
+                output_dir: dir.path().join(out),
+                execute: None,
+                seed,
+                binary: false,
+                keep: KeepMode::All,
+                run_as: None,
+                trace_syscalls: false,
+            })
+            .expect("amuck should run");
+            assert_eq!(report.seed, seed);
+            report.outcomes.into_iter().map(|o| o.name).collect()
+        };
+
+        let first = names(99, "a");
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, names(99, "b"));
+    }
+
+    #[test]
+    fn binary_run_mutates_bytes_and_rejects_text_operations() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let target = dir.path().join("image.bin");
+        fs::write(&target, b"\x89IMG\x00\x00\x00\x04\xde\xad\xbe\xef")
+            .expect("target should write");
+        let spec_path = dir.path().join("spec.yaml");
+        fs::write(
+            &spec_path,
+            "combos:\n  - name: corrupt\n    operations:\n      - { op: flip_byte, offset: 8, mask: 255 }\n      - { op: inflate_length, offset: 4, field: u32_be }\n  - name: text\n    operations:\n      - { op: append_text, text: x }\n",
+        )
+        .expect("spec should write");
+        let config = |binary: bool, spec_path: Option<PathBuf>| AmuckConfig {
+            target: target.clone(),
+            spec_path,
+            preset: AmuckPreset::Dangerous,
+            max_combinations: 8,
+            output_dir: dir.path().join("out"),
+            execute: None,
+            seed: 0,
+            binary,
+            keep: KeepMode::All,
+            run_as: None,
+            trace_syscalls: false,
+        };
+
+        let report = run(config(true, Some(spec_path))).expect("amuck should run");
+        assert_eq!(report.combinations_run, 1);
+        let mutated = report.outcomes[0].mutated_file.as_ref().expect("mutant");
+        assert_eq!(
+            fs::read(mutated).expect("mutant should read"),
+            b"\x89IMG\xff\xff\xff\xff\x21\xad\xbe\xef"
+        );
+        assert_eq!(
+            report.outcomes[0].operations,
+            ["flip_byte(0x8, ^0xff)", "inflate_length(0x4, u32_be)"]
+        );
+        let text_error = report.outcomes[1].apply_error.as_deref().unwrap_or("");
+        assert!(text_error.contains("run without --binary"));
+
+        let err = run(config(false, None)).expect_err("binary target needs --binary");
+        assert!(err.to_string().contains("use --binary"));
+        let built_in = run(config(true, None)).expect("built-in binary combos");
+        assert!(built_in
+            .outcomes
+            .iter()
+            .any(|outcome| outcome.name == "inflate-length-4"));
+    }
 }
@@ -0,0 +1,477 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Offline known-vulnerability lookup for declared dependencies.
+//!
+//! Matches the manifests assail found against an advisory snapshot in OSV JSON, which both
+//! osv.dev exports and the RustSec database publish. Only denial-of-service and memory-safety
+//! advisories are kept, since those are the ones a stress run can provoke; each match becomes
+//! a `VulnerableDependency` weak point recommending the axes that exercise it. Nothing is
+//! fetched: the snapshot is a file or directory the operator refreshes.
+//!
+//! Manifests declare requirements, not resolved versions, so a dependency is flagged when the
+//! lowest version its requirement admits (`^1.2` admits 1.2.0) falls in an affected range.
+
+use crate::types::{
+    AttackAxis, DependencyManifest, Ecosystem, FindingProvenance, Severity, WeakPoint,
+    WeakPointCategory,
+};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Provenance tool name on advisory weak points.
+pub const TOOL: &str = "advisories";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AdvisoryClass {
+    DenialOfService,
+    MemorySafety,
+}
+
+#[derive(Debug, Clone)]
+struct Advisory {
+    id: String,
+    summary: String,
+    class: AdvisoryClass,
+    /// Thread-safety advisories also recommend the concurrency axis.
+    thread_safety: bool,
+    affected: Vec<Affected>,
+}
+
+#[derive(Debug, Clone)]
+struct Affected {
+    ecosystem: Ecosystem,
+    name: String,
+    /// `(introduced, fixed)` pairs; an upper bound of `Inclusive` is OSV's `last_affected`.
+    ranges: Vec<(Version, Bound)>,
+    versions: Vec<Version>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Bound {
+    Exclusive(Version),
+    Inclusive(Version),
+    Unbounded,
+}
+
+/// `major.minor.patch`, with pre-release and build metadata ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Version([u64; 3]);
+
+impl Version {
+    fn parse(text: &str) -> Option<Self> {
+        let core = text.trim().trim_start_matches('v');
+        let core = core.split(['-', '+']).next()?;
+        let mut parts = [0u64; 3];
+        for (index, part) in core.split('.').enumerate() {
+            if index >= 3 {
+                break;
+            }
+            // `1.x` and `1.*` wildcards admit the lowest release.
+            parts[index] = match part {
+                "x" | "X" | "*" => break,
+                _ => part.parse().ok()?,
+            };
+        }
+        Some(Self(parts))
+    }
+}
+
+/// An advisory snapshot loaded from OSV JSON.
+#[derive(Debug, Clone, Default)]
+pub struct AdvisoryDatabase {
+    advisories: Vec<Advisory>,
+}
+
+impl AdvisoryDatabase {
+    /// Load a JSON file (one OSV record, an array of them, or `{"vulns": [...]}`) or a
+    /// directory of such files, searched recursively.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut records = Vec::new();
+        collect_records(path, &mut records)?;
+        Ok(Self::from_records(&records))
+    }
+
+    /// Keep the denial-of-service and memory-safety advisories among OSV `records`.
+    pub fn from_records(records: &[Value]) -> Self {
+        Self {
+            advisories: records.iter().filter_map(parse_advisory).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.advisories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.advisories.is_empty()
+    }
+
+    /// One weak point per (dependency, advisory) match, located at the declaring manifest.
+    pub fn check(&self, manifests: &[DependencyManifest]) -> Vec<WeakPoint> {
+        let mut weak_points = Vec::new();
+        for manifest in manifests {
+            for dependency in &manifest.dependencies {
+                let Some(requirement) = dependency.version.as_deref() else {
+                    continue;
+                };
+                let floors = requirement_floors(manifest.ecosystem, requirement);
+                for advisory in &self.advisories {
+                    let hit = advisory.affected.iter().any(|affected| {
+                        affected.ecosystem == manifest.ecosystem
+                            && affected.name == dependency.name
+                            && floors.iter().any(|floor| affected.contains(*floor))
+                    });
+                    if hit {
+                        weak_points.push(advisory.weak_point(
+                            &dependency.name,
+                            requirement,
+                            &manifest.path,
+                        ));
+                    }
+                }
+            }
+        }
+        weak_points
+    }
+}
+
+impl Advisory {
+    fn weak_point(&self, name: &str, requirement: &str, manifest: &str) -> WeakPoint {
+        let (severity, kind, mut axes) = match self.class {
+            AdvisoryClass::DenialOfService => (
+                Severity::High,
+                "denial of service",
+                vec![AttackAxis::Cpu, AttackAxis::Memory],
+            ),
+            AdvisoryClass::MemorySafety => (
+                Severity::Critical,
+                "memory safety",
+                vec![AttackAxis::Memory],
+            ),
+        };
+        if self.thread_safety {
+            axes.push(AttackAxis::Concurrency);
+        }
+        WeakPoint {
+            category: WeakPointCategory::VulnerableDependency,
+            location: Some(manifest.to_string()),
+            severity,
+            description: format!(
+                "{} {} is affected by {} ({}): {} [{} in {}]",
+                name, requirement, self.id, kind, self.summary, TOOL, manifest
+            ),
+            recommended_attack: axes,
+            provenance: Some(FindingProvenance {
+                tool: TOOL.to_string(),
+                rule: self.id.clone(),
+                line: None,
+            }),
+            calibrated: None,
+            owner: None,
+        }
+    }
+}
+
+impl Affected {
+    fn contains(&self, version: Version) -> bool {
+        self.versions.contains(&version)
+            || self.ranges.iter().any(|(introduced, bound)| {
+                version >= *introduced
+                    && match bound {
+                        Bound::Exclusive(fixed) => version < *fixed,
+                        Bound::Inclusive(last) => version <= *last,
+                        Bound::Unbounded => true,
+                    }
+            })
+    }
+}
+
+fn collect_records(path: &Path, records: &mut Vec<Value>) -> Result<()> {
+    if path.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(path)
+            .with_context(|| format!("reading advisory directory {}", path.display()))?
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        entries.sort();
+        for entry in entries {
+            if entry.is_dir() || entry.extension().and_then(|e| e.to_str()) == Some("json") {
+                // One bad file in a large snapshot should not discard the rest.
+                if let Err(err) = collect_records(&entry, records) {
+                    tracing::warn!("Skipping advisory file: {:#}", err);
+                }
+            }
+        }
+        return Ok(());
+    }
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("reading advisory file {}", path.display()))?;
+    let value: Value = serde_json::from_str(&content)
+        .with_context(|| format!("parsing advisory file {}", path.display()))?;
+    match value {
+        Value::Array(items) => records.extend(items),
+        Value::Object(ref object) if object.contains_key("vulns") => {
+            records.extend(object["vulns"].as_array().cloned().unwrap_or_default())
+        }
+        other => records.push(other),
+    }
+    Ok(())
+}
+
+fn parse_advisory(record: &Value) -> Option<Advisory> {
+    // Withdrawn advisories no longer apply.
+    if record.get("withdrawn").is_some_and(|w| !w.is_null()) {
+        return None;
+    }
+    let id = record["id"].as_str()?.to_string();
+    let summary = record["summary"]
+        .as_str()
+        .or_else(|| record["details"].as_str().and_then(|d| d.lines().next()))
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    // RustSec carries categories in `database_specific`, at the top or per affected package.
+    let mut categories: Vec<String> = Vec::new();
+    let mut gather = |specific: &Value| {
+        if let Some(list) = specific["categories"].as_array() {
+            categories.extend(list.iter().filter_map(|c| c.as_str()).map(str::to_string));
+        }
+    };
+    gather(&record["database_specific"]);
+    for affected in record["affected"].as_array().into_iter().flatten() {
+        gather(&affected["database_specific"]);
+    }
+    let text = format!(
+        "{} {}",
+        summary,
+        record["details"].as_str().unwrap_or_default()
+    )
+    .to_ascii_lowercase();
+    let (class, thread_safety) = classify(&categories, &text)?;
+
+    let affected = record["affected"]
+        .as_array()?
+        .iter()
+        .filter_map(parse_affected)
+        .collect::<Vec<_>>();
+    (!affected.is_empty()).then_some(Advisory {
+        id,
+        summary,
+        class,
+        thread_safety,
+        affected,
+    })
+}
+
+/// RustSec categories first, then keywords in the advisory text.
+fn classify(categories: &[String], text: &str) -> Option<(AdvisoryClass, bool)> {
+    let has_category = |names: &[&str]| categories.iter().any(|c| names.contains(&c.as_str()));
+    let has_text = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+    let thread_safety = has_category(&["thread-safety"]) || has_text(&["data race"]);
+
+    if thread_safety
+        || has_category(&["memory-corruption", "memory-exposure"])
+        || has_text(&[
+            "use after free",
+            "use-after-free",
+            "double free",
+            "buffer overflow",
+            "out-of-bounds",
+            "out of bounds",
+            "memory corruption",
+            "uninitialized memory",
+            "heap overflow",
+        ])
+    {
+        return Some((AdvisoryClass::MemorySafety, thread_safety));
+    }
+    if has_category(&["denial-of-service"])
+        || has_text(&[
+            "denial of service",
+            "denial-of-service",
+            "stack overflow",
+            "uncontrolled recursion",
+            "resource exhaustion",
+            "infinite loop",
+            "excessive memory",
+            "redos",
+        ])
+    {
+        return Some((AdvisoryClass::DenialOfService, false));
+    }
+    None
+}
+
+fn parse_affected(affected: &Value) -> Option<Affected> {
+    let package = &affected["package"];
+    let ecosystem = match package["ecosystem"].as_str()? {
+        "crates.io" => Ecosystem::Cargo,
+        "npm" => Ecosystem::Npm,
+        "Go" => Ecosystem::Go,
+        _ => return None,
+    };
+    let mut ranges = Vec::new();
+    for range in affected["ranges"].as_array().into_iter().flatten() {
+        if range["type"] == "GIT" {
+            continue;
+        }
+        let mut introduced: Option<Version> = None;
+        for event in range["events"].as_array().into_iter().flatten() {
+            let field = |key: &str| event[key].as_str().and_then(Version::parse);
+            if let Some(version) = field("introduced") {
+                introduced = Some(version);
+            } else if let Some(fixed) = field("fixed") {
+                ranges.extend(introduced.take().map(|i| (i, Bound::Exclusive(fixed))));
+            } else if let Some(last) = field("last_affected") {
+                ranges.extend(introduced.take().map(|i| (i, Bound::Inclusive(last))));
+            }
+        }
+        ranges.extend(introduced.map(|i| (i, Bound::Unbounded)));
+    }
+    let versions = affected["versions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str().and_then(Version::parse))
+        .collect();
+    Some(Affected {
+        ecosystem,
+        name: package["name"].as_str()?.to_string(),
+        ranges,
+        versions,
+    })
+}
+
+/// Lowest version each alternative of a declared requirement admits. Requirements that
+/// name no version (`latest`, git or file URLs) admit none we can check.
+fn requirement_floors(ecosystem: Ecosystem, requirement: &str) -> Vec<Version> {
+    let alternatives: Vec<&str> = match ecosystem {
+        Ecosystem::Npm => requirement.split("||").collect(),
+        _ => vec![requirement],
+    };
+    alternatives
+        .into_iter()
+        .filter_map(|alternative| {
+            // Only the first comparator bounds from below: `>=1.2, <2` and `1.2.3 - 2.0.0`.
+            let first = alternative.trim().split([',', ' ']).next()?.trim();
+            if first.is_empty() || first == "*" || first.starts_with('<') {
+                return Some(Version([0, 0, 0]));
+            }
+            Version::parse(first.trim_start_matches(['^', '~', '=', '>']))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DeclaredDependency, DependencyScope};
+    use serde_json::json;
+
+    fn manifest(ecosystem: Ecosystem, name: &str, version: &str) -> DependencyManifest {
+        DependencyManifest {
+            path: "Cargo.toml".to_string(),
+            ecosystem,
+            name: None,
+            version: None,
+            dependencies: vec![DeclaredDependency {
+                name: name.to_string(),
+                version: Some(version.to_string()),
+                scope: DependencyScope::Runtime,
+            }],
+        }
+    }
+
+    fn rustsec_record() -> Value {
+        json!({
+            "id": "RUSTSEC-2021-0001",
+            "summary": "Use after free in Queue::pop",
+            "affected": [{
+                "package": { "ecosystem": "crates.io", "name": "queue" },
+                "ranges": [{ "type": "SEMVER", "events": [
+                    { "introduced": "0.0.0-0" }, { "fixed": "1.4.0" },
+                    { "introduced": "2.0.0" }, { "last_affected": "2.1.3" }
+                ] }],
+                "database_specific": { "categories": ["memory-corruption", "thread-safety"] }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_requirement_floors() {
+        let floors = |eco, req| requirement_floors(eco, req);
+        assert_eq!(floors(Ecosystem::Cargo, "^1.2"), vec![Version([1, 2, 0])]);
+        assert_eq!(
+            floors(Ecosystem::Cargo, ">=0.3, <0.5"),
+            vec![Version([0, 3, 0])]
+        );
+        assert_eq!(
+            floors(Ecosystem::Npm, "~1.x || 3.0.1"),
+            vec![Version([1, 0, 0]), Version([3, 0, 1])]
+        );
+        assert_eq!(
+            floors(Ecosystem::Go, "v0.0.0-20210101-abcdef"),
+            vec![Version([0, 0, 0])]
+        );
+        assert!(floors(Ecosystem::Npm, "git+https://x/y.git").is_empty());
+    }
+
+    #[test]
+    fn test_matches_ranges_and_classifies() {
+        let db = AdvisoryDatabase::from_records(&[
+            rustsec_record(),
+            json!({ "id": "GHSA-info", "summary": "Typo in docs",
+                    "affected": [{ "package": { "ecosystem": "crates.io", "name": "queue" } }] }),
+        ]);
+        assert_eq!(db.len(), 1, "non-DoS/memory advisories are dropped");
+
+        let found = db.check(&[manifest(Ecosystem::Cargo, "queue", "1.3")]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].category, WeakPointCategory::VulnerableDependency);
+        assert_eq!(found[0].severity, Severity::Critical);
+        assert_eq!(
+            found[0].recommended_attack,
+            vec![AttackAxis::Memory, AttackAxis::Concurrency]
+        );
+        assert_eq!(
+            found[0].provenance.as_ref().unwrap().rule,
+            "RUSTSEC-2021-0001"
+        );
+
+        for safe in ["1.4", "=2.2.0"] {
+            assert!(db
+                .check(&[manifest(Ecosystem::Cargo, "queue", safe)])
+                .is_empty());
+        }
+        assert_eq!(
+            db.check(&[manifest(Ecosystem::Cargo, "queue", "=2.1.3")])
+                .len(),
+            1
+        );
+        assert!(db
+            .check(&[manifest(Ecosystem::Npm, "queue", "1.0.0")])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_denial_of_service_from_text() {
+        let db = AdvisoryDatabase::from_records(&[json!({
+            "id": "GHSA-redos",
+            "summary": "Regular expression denial of service in parser",
+            "affected": [{
+                "package": { "ecosystem": "npm", "name": "parser" },
+                "versions": ["2.0.0"]
+            }]
+        })]);
+        let found = db.check(&[manifest(Ecosystem::Npm, "parser", "2.0.0")]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].severity, Severity::High);
+        assert_eq!(
+            found[0].recommended_attack,
+            vec![AttackAxis::Cpu, AttackAxis::Memory]
+        );
+    }
+}
@@ -7,6 +7,14 @@
 //! across BEAM, ML, Lisp, proof assistant, logic programming,
 //! systems, functional, config, scripting, and custom DSL families.
 
+use super::advisories::AdvisoryDatabase;
+use super::annotations;
+use super::external::{run_external, ExternalAnalyzer};
+use super::manifests;
+use super::owners::Ownership;
+use super::policy::PolicySet;
+use crate::error::AssailError;
+use crate::progress::Progress;
 use crate::types::*;
 use anyhow::Result;
 use regex::Regex;
@@ -20,7 +28,8 @@ use std::sync::OnceLock;
 // These collect deprecated/modern API counts across all files during a single
 // analyze() run, then get consumed by build_migration_metrics().
 thread_local! {
-    static MIGRATION_DEPRECATED: RefCell<Vec<DeprecatedPattern>> = RefCell::new(Vec::new());
+    static MIGRATION_DEPRECATED: RefCell<Vec<DeprecatedPattern>> =
+        const { RefCell::new(Vec::new()) };
     static MIGRATION_DEPRECATED_COUNT: RefCell<usize> = const { RefCell::new(0) };
     static MIGRATION_MODERN_COUNT: RefCell<usize> = const { RefCell::new(0) };
     static MIGRATION_FILE_COUNT: RefCell<usize> = const { RefCell::new(0) };
@@ -154,25 +163,93 @@ static RE_SHELL_UNQUOTED_VAR: OnceLock<Regex> = OnceLock::new();
 static RE_HTTP_URL: OnceLock<Regex> = OnceLock::new();
 static RE_HTTP_LOCALHOST: OnceLock<Regex> = OnceLock::new();
 static RE_HARDCODED_SECRET: OnceLock<Regex> = OnceLock::new();
+static RE_RUST_RAW_PTR_BINDING: OnceLock<Regex> = OnceLock::new();
+static RE_RUST_STATIC_MUT: OnceLock<Regex> = OnceLock::new();
+static RE_RUST_SEND_SYNC_IMPL: OnceLock<Regex> = OnceLock::new();
+static RE_GO_FOR_LOOP: OnceLock<Regex> = OnceLock::new();
+static RE_GO_MAP_DECL: OnceLock<Regex> = OnceLock::new();
+static RE_PY_PICKLE_EXTERNAL: OnceLock<Regex> = OnceLock::new();
+static RE_PY_BARE_EXCEPT: OnceLock<Regex> = OnceLock::new();
+static RE_JS_FUNCTION_CTOR: OnceLock<Regex> = OnceLock::new();
+static RE_JS_SYNC_CALL: OnceLock<Regex> = OnceLock::new();
+static RE_JS_DEEP_MERGE: OnceLock<Regex> = OnceLock::new();
+static RE_JS_FOR_IN: OnceLock<Regex> = OnceLock::new();
+
+/// What one source file contributes to a report.
+#[derive(Clone)]
+struct FileAnalysis {
+    rel_path: String,
+    language: Language,
+    stats: ProgramStatistics,
+    weak_points: Vec<WeakPoint>,
+    annotations: Vec<SourceAnnotation>,
+}
+
+/// Per-file results kept between [`Analyzer::analyze_cached`] runs, keyed by path and
+/// invalidated by a change in size or mtime.
+#[derive(Default)]
+pub struct FileCache {
+    entries: HashMap<PathBuf, (FileStamp, Option<FileAnalysis>)>,
+    reanalyzed: Vec<PathBuf>,
+}
+
+type FileStamp = (u64, Option<std::time::SystemTime>);
+
+impl FileCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files read afresh by the most recent run.
+    pub fn reanalyzed(&self) -> &[PathBuf] {
+        &self.reanalyzed
+    }
+
+    fn analysis(
+        &mut self,
+        file: &Path,
+        analyze: impl FnOnce(&Path) -> Result<Option<FileAnalysis>>,
+    ) -> Result<Option<FileAnalysis>> {
+        let stamp = fs::metadata(file)
+            .map(|meta| (meta.len(), meta.modified().ok()))
+            .unwrap_or((0, None));
+        if let Some((cached, analysis)) = self.entries.get(file) {
+            if *cached == stamp {
+                return Ok(analysis.clone());
+            }
+        }
+        let analysis = analyze(file)?;
+        self.reanalyzed.push(file.to_path_buf());
+        // ReScript files feed the migration accumulators as they are read, so they never
+        // come from the cache.
+        if Language::detect(file.to_str().unwrap_or("")) != Language::ReScript {
+            self.entries
+                .insert(file.to_path_buf(), (stamp, analysis.clone()));
+        }
+        Ok(analysis)
+    }
+
+    fn begin(&mut self, files: &[PathBuf]) {
+        let live: HashSet<&PathBuf> = files.iter().collect();
+        self.entries.retain(|path, _| live.contains(path));
+        self.reanalyzed.clear();
+    }
+}
 
 pub struct Analyzer {
     target: PathBuf,
     language: Language,
-    verbose: bool,
+    external: Vec<ExternalAnalyzer>,
+    advisories: Option<AdvisoryDatabase>,
+    only_files: Option<Vec<PathBuf>>,
+    blame_owners: bool,
 }
 
 impl Analyzer {
+    /// Skipped files are logged at `debug` level (`-v`).
     pub fn new(target: &Path) -> Result<Self> {
-        Self::build(target, false)
-    }
-
-    pub fn new_verbose(target: &Path) -> Result<Self> {
-        Self::build(target, true)
-    }
-
-    fn build(target: &Path, verbose: bool) -> Result<Self> {
         if !target.exists() {
-            anyhow::bail!("Target does not exist: {}", target.display());
+            return Err(AssailError::TargetMissing(target.to_path_buf()).into());
         }
 
         let language = if target.is_file() {
@@ -184,10 +261,43 @@ impl Analyzer {
         Ok(Self {
             target: target.to_path_buf(),
             language,
-            verbose,
+            external: Vec::new(),
+            advisories: None,
+            only_files: None,
+            blame_owners: false,
         })
     }
 
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// Also run these external tools and merge their findings (see [`super::external`]).
+    pub fn with_external_analyzers(mut self, analyzers: Vec<ExternalAnalyzer>) -> Self {
+        self.external = analyzers;
+        self
+    }
+
+    /// Flag declared dependencies matching these advisories (see [`super::advisories`]).
+    pub fn with_advisories(mut self, advisories: AdvisoryDatabase) -> Self {
+        self.advisories = Some(advisories);
+        self
+    }
+
+    /// Read only these files instead of walking the target; paths without a known source
+    /// language, or that no longer exist, are skipped.
+    pub fn with_only_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.only_files = Some(files);
+        self
+    }
+
+    /// Fall back to `git blame` for weak points CODEOWNERS assigns to nobody
+    /// (see [`super::owners`]).
+    pub fn with_blame_owners(mut self, blame: bool) -> Self {
+        self.blame_owners = blame;
+        self
+    }
+
     /// Run analysis with an optional evidence accumulator for attestation.
     ///
     /// When `accumulator` is `Some`, each successfully read file and each
@@ -198,16 +308,23 @@ impl Analyzer {
         &self,
         accumulator: Option<&mut crate::attestation::EvidenceAccumulator>,
     ) -> Result<AssailReport> {
-        self.analyze_inner(accumulator)
+        self.analyze_inner(accumulator, None)
     }
 
     pub fn analyze(&self) -> Result<AssailReport> {
-        self.analyze_inner(None)
+        self.analyze_inner(None, None)
+    }
+
+    /// Run analysis, re-reading only files whose size or mtime changed since the last run
+    /// against `cache`. Whole-tree stages (manifests, policies, frameworks) always rerun.
+    pub fn analyze_cached(&self, cache: &mut FileCache) -> Result<AssailReport> {
+        self.analyze_inner(None, Some(cache))
     }
 
     fn analyze_inner(
         &self,
         mut accumulator: Option<&mut crate::attestation::EvidenceAccumulator>,
+        mut cache: Option<&mut FileCache>,
     ) -> Result<AssailReport> {
         // Reset migration accumulators for a clean scan
         reset_migration_accumulators();
@@ -224,9 +341,14 @@ impl Analyzer {
             threading_constructs: 0,
         };
         let mut all_weak_points = Vec::new();
+        let mut annotations = Vec::new();
         let mut file_statistics = Vec::new();
+        let mut languages_seen = HashSet::from([self.language]);
 
         let files = self.collect_source_files()?;
+        if let Some(cache) = cache.as_deref_mut() {
+            cache.begin(&files);
+        }
 
         let base = if self.target.is_dir() {
             self.target.clone()
@@ -248,272 +370,34 @@ impl Analyzer {
         }
 
         // Each source file is analyzed independently; this keeps weak-point attribution precise.
+        let progress = self
+            .target
+            .is_dir()
+            .then(|| Progress::new("Scanning", files.len()));
         for file in &files {
-            let raw_bytes = match fs::read(file) {
-                Ok(b) => b,
-                Err(e) => {
-                    if self.verbose {
-                        eprintln!("Skipping unreadable file: {} ({})", file.display(), e);
-                    }
-                    continue;
-                }
-            };
-
-            // Try UTF-8 first, then Latin-1 fallback.
-            // Use str::from_utf8 to borrow rather than cloning raw_bytes.
-            let content = match std::str::from_utf8(&raw_bytes) {
-                Ok(s) => s.to_owned(),
-                Err(_) => {
-                    let (cow, _, had_errors) = encoding_rs::WINDOWS_1252.decode(&raw_bytes);
-                    if had_errors {
-                        if self.verbose {
-                            eprintln!(
-                                "Skipping non-text file: {} (neither UTF-8 nor Latin-1)",
-                                file.display()
-                            );
-                        }
-                        continue;
-                    }
-                    cow.into_owned()
-                }
-            };
-
-            let rel_path = file
-                .strip_prefix(&base)
-                .unwrap_or(file)
-                .to_string_lossy()
-                .to_string();
-
-            let mut file_stats = ProgramStatistics {
-                total_lines: 0,
-                unsafe_blocks: 0,
-                panic_sites: 0,
-                unwrap_calls: 0,
-                allocation_sites: 0,
-                io_operations: 0,
-                threading_constructs: 0,
-            };
-
-            file_stats.total_lines = content.lines().count();
-
-            let mut file_weak_points = Vec::new();
-
-            // Dispatch to language-specific analyzer
-            let file_lang = Language::detect(file.to_str().unwrap_or(""));
-
-            // Record this file into the attestation accumulator (zero-cost when None)
-            if let Some(ref mut acc) = accumulator {
-                acc.record_file(&rel_path, &raw_bytes, &format!("{:?}", file_lang));
+            if let Some(progress) = &progress {
+                let shown = file.strip_prefix(&base).unwrap_or(file);
+                progress.begin(shown.display().to_string());
             }
-
-            match file_lang {
-                Language::Rust => {
-                    self.analyze_rust(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::C | Language::Cpp => {
-                    self.analyze_c_cpp(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Go => {
-                    self.analyze_go(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Python => {
-                    self.analyze_python(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::JavaScript => {
-                    self.analyze_javascript(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Ruby => {
-                    self.analyze_ruby(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                // BEAM family
-                Language::Elixir => {
-                    self.analyze_elixir(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Erlang => {
-                    self.analyze_erlang(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Gleam => {
-                    self.analyze_gleam(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                // ML family
-                Language::ReScript => {
-                    record_migration_file(file_stats.total_lines);
-                    self.analyze_rescript(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::OCaml => {
-                    self.analyze_ocaml(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::StandardML => {
-                    self.analyze_sml(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                // Lisp family
-                Language::Scheme | Language::Racket => {
-                    self.analyze_lisp(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                // Functional
-                Language::Haskell => {
-                    self.analyze_haskell(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::PureScript => {
-                    self.analyze_purescript(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                // Proof assistants
-                Language::Idris => {
-                    self.analyze_idris(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Lean => {
-                    self.analyze_lean(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Agda => {
-                    self.analyze_agda(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                // Logic programming
-                Language::Prolog | Language::Logtalk | Language::Datalog => {
-                    self.analyze_logic(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                // Systems languages
-                Language::Zig => {
-                    self.analyze_zig(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Ada => {
-                    self.analyze_ada(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Odin => {
-                    self.analyze_odin(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Nim => {
-                    self.analyze_nim(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::Pony => {
-                    self.analyze_pony(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                Language::DLang => {
-                    self.analyze_dlang(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                // Config languages
-                Language::Nickel | Language::Nix => {
-                    self.analyze_config(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                // Scripting
-                Language::Shell => {
-                    self.analyze_shell(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Julia => {
-                    self.analyze_julia(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Lua => {
-                    self.analyze_lua(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                // Nextgen DSLs - shared analyzer
-                Language::WokeLang
-                | Language::Eclexia
-                | Language::MyLang
-                | Language::JuliaTheViper
-                | Language::Oblibeny
-                | Language::Anvomidav
-                | Language::AffineScript
-                | Language::Ephapax
-                | Language::BetLang
-                | Language::ErrorLang
-                | Language::VQL
-                | Language::FBQL => {
-                    self.analyze_nextgen_dsl(
-                        &content,
-                        &mut file_stats,
-                        &mut file_weak_points,
-                        &rel_path,
-                    )?;
-                }
-                Language::Java => {
-                    self.analyze_java(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
-                }
-                _ => {
-                    self.analyze_generic(&content, &mut file_stats, &rel_path)?;
-                }
+            let analysis = match cache.as_deref_mut() {
+                Some(cache) => cache.analysis(file, |file| self.analyze_file(file, &base, None))?,
+                None => self.analyze_file(file, &base, accumulator.as_deref_mut())?,
+            };
+            if let Some(progress) = &progress {
+                progress.inc();
             }
-
-            // Cross-language security checks (run on all files)
-            self.analyze_cross_language(&content, &mut file_weak_points, &rel_path)?;
+            let Some(FileAnalysis {
+                rel_path,
+                language: file_lang,
+                stats: file_stats,
+                weak_points: file_weak_points,
+                annotations: file_annotations,
+            }) = analysis
+            else {
+                continue;
+            };
+            languages_seen.insert(file_lang);
+            annotations.extend(file_annotations);
 
             // Accumulate global stats
             global_stats.total_lines += file_stats.total_lines;
@@ -547,6 +431,26 @@ impl Analyzer {
             }
         }
 
+        drop(progress);
+
+        if !self.external.is_empty() {
+            all_weak_points.extend(run_external(&self.external, &base, &languages_seen));
+        }
+
+        let manifests = manifests::collect(&self.target);
+        if let Some(advisories) = &self.advisories {
+            all_weak_points.extend(advisories.check(&manifests));
+        }
+
+        // Directory policies first, so magic comments can still force findings in excluded trees.
+        let policies = PolicySet::new(&base).apply(&mut all_weak_points)?;
+
+        // Magic comments apply after external analyzers so they can silence those findings too.
+        annotations::apply(&mut all_weak_points, &mut annotations);
+        Ownership::discover(&base)?
+            .with_blame(self.blame_owners)
+            .apply(&mut all_weak_points);
+
         // Secondary synthesis stages derive framework hints and relational overlays.
         let frameworks = self.detect_frameworks(&files)?;
         let recommended_attacks = self.generate_recommendations(&all_weak_points, &global_stats);
@@ -571,13 +475,233 @@ impl Analyzer {
             dependency_graph,
             taint_matrix,
             migration_metrics,
+            annotations,
+            policies,
+            manifests,
         })
     }
 
+    /// Analyze one source file; `None` when it cannot be read as text.
+    fn analyze_file(
+        &self,
+        file: &Path,
+        base: &Path,
+        mut accumulator: Option<&mut crate::attestation::EvidenceAccumulator>,
+    ) -> Result<Option<FileAnalysis>> {
+        let raw_bytes = match fs::read(file) {
+            Ok(b) => b,
+            Err(e) => {
+                tracing::debug!("Skipping unreadable file: {} ({})", file.display(), e);
+                return Ok(None);
+            }
+        };
+
+        // Try UTF-8 first, then Latin-1 fallback.
+        // Use str::from_utf8 to borrow rather than cloning raw_bytes.
+        let content = match std::str::from_utf8(&raw_bytes) {
+            Ok(s) => s.to_owned(),
+            Err(_) => {
+                let (cow, _, had_errors) = encoding_rs::WINDOWS_1252.decode(&raw_bytes);
+                if had_errors {
+                    tracing::debug!(
+                        "Skipping non-text file: {} (neither UTF-8 nor Latin-1)",
+                        file.display()
+                    );
+                    return Ok(None);
+                }
+                cow.into_owned()
+            }
+        };
+
+        let rel_path = file
+            .strip_prefix(base)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .to_string();
+
+        let mut file_stats = ProgramStatistics {
+            total_lines: 0,
+            unsafe_blocks: 0,
+            panic_sites: 0,
+            unwrap_calls: 0,
+            allocation_sites: 0,
+            io_operations: 0,
+            threading_constructs: 0,
+        };
+
+        file_stats.total_lines = content.lines().count();
+
+        let mut file_weak_points = Vec::new();
+
+        // Dispatch to language-specific analyzer
+        let file_lang = Language::detect(file.to_str().unwrap_or(""));
+
+        // Record this file into the attestation accumulator (zero-cost when None)
+        if let Some(ref mut acc) = accumulator {
+            acc.record_file(&rel_path, &raw_bytes, &format!("{:?}", file_lang));
+        }
+
+        match file_lang {
+            Language::Rust => {
+                self.analyze_rust(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::C | Language::Cpp => {
+                self.analyze_c_cpp(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Go => {
+                self.analyze_go(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Python => {
+                self.analyze_python(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::JavaScript => {
+                self.analyze_javascript(
+                    &content,
+                    &mut file_stats,
+                    &mut file_weak_points,
+                    &rel_path,
+                )?;
+            }
+            Language::Ruby => {
+                self.analyze_ruby(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // BEAM family
+            Language::Elixir => {
+                self.analyze_elixir(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Erlang => {
+                self.analyze_erlang(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Gleam => {
+                self.analyze_gleam(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // ML family
+            Language::ReScript => {
+                record_migration_file(file_stats.total_lines);
+                self.analyze_rescript(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::OCaml => {
+                self.analyze_ocaml(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::StandardML => {
+                self.analyze_sml(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Lisp family
+            Language::Scheme | Language::Racket => {
+                self.analyze_lisp(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Functional
+            Language::Haskell => {
+                self.analyze_haskell(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::PureScript => {
+                self.analyze_purescript(
+                    &content,
+                    &mut file_stats,
+                    &mut file_weak_points,
+                    &rel_path,
+                )?;
+            }
+            // Proof assistants
+            Language::Idris => {
+                self.analyze_idris(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Lean => {
+                self.analyze_lean(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Agda => {
+                self.analyze_agda(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Logic programming
+            Language::Prolog | Language::Logtalk | Language::Datalog => {
+                self.analyze_logic(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Systems languages
+            Language::Zig => {
+                self.analyze_zig(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Ada => {
+                self.analyze_ada(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Odin => {
+                self.analyze_odin(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Nim => {
+                self.analyze_nim(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Pony => {
+                self.analyze_pony(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::DLang => {
+                self.analyze_dlang(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Config languages
+            Language::Nickel | Language::Nix => {
+                self.analyze_config(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Scripting
+            Language::Shell => {
+                self.analyze_shell(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Julia => {
+                self.analyze_julia(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            Language::Lua => {
+                self.analyze_lua(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            // Nextgen DSLs - shared analyzer
+            Language::WokeLang
+            | Language::Eclexia
+            | Language::MyLang
+            | Language::JuliaTheViper
+            | Language::Oblibeny
+            | Language::Anvomidav
+            | Language::AffineScript
+            | Language::Ephapax
+            | Language::BetLang
+            | Language::ErrorLang
+            | Language::VQL
+            | Language::FBQL => {
+                self.analyze_nextgen_dsl(
+                    &content,
+                    &mut file_stats,
+                    &mut file_weak_points,
+                    &rel_path,
+                )?;
+            }
+            Language::Java => {
+                self.analyze_java(&content, &mut file_stats, &mut file_weak_points, &rel_path)?;
+            }
+            _ => {
+                self.analyze_generic(&content, &mut file_stats, &rel_path)?;
+            }
+        }
+
+        // Cross-language security checks (run on all files)
+        self.analyze_cross_language(&content, &mut file_weak_points, &rel_path)?;
+
+        Ok(Some(FileAnalysis {
+            annotations: annotations::parse(&rel_path, &content),
+            rel_path,
+            language: file_lang,
+            stats: file_stats,
+            weak_points: file_weak_points,
+        }))
+    }
+
     fn collect_source_files(&self) -> Result<Vec<PathBuf>> {
         let mut files = Vec::new();
 
-        if self.target.is_file() {
+        if let Some(only) = &self.only_files {
+            files.extend(
+                only.iter()
+                    .filter(|file| {
+                        file.is_file()
+                            && Language::detect(file.to_str().unwrap_or("")) != Language::Unknown
+                    })
+                    .cloned(),
+            );
+        } else if self.target.is_file() {
             files.push(self.target.clone());
         } else {
             // Directory mode performs a conservative recursive walk with language filtering.
@@ -725,6 +849,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} unsafe blocks in {}", stats.unsafe_blocks, file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -738,17 +865,25 @@ impl Analyzer {
                     stats.unwrap_calls, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
         // mem::transmute — type-punning bypasses Rust's type system entirely
-        if content.contains("transmute(") || content.contains("transmute::<") {
+        let transmutes =
+            content.matches("transmute(").count() + content.matches("transmute::<").count();
+        if transmutes > 0 {
             weak_points.push(WeakPoint {
-                category: WeakPointCategory::UnsafeCode,
+                category: WeakPointCategory::UnsafeTransmute,
                 location: Some(file_path.to_string()),
                 severity: Severity::Critical,
-                description: format!("mem::transmute usage in {}", file_path),
+                description: format!("{} mem::transmute call(s) in {}", transmutes, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -760,6 +895,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("mem::forget usage (resource leak) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -771,6 +909,126 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Raw pointer cast in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Raw pointer dereferences — only counted inside unsafe blocks/fns, where they compile
+        let derefs = rust_raw_pointer_derefs(content);
+        if derefs > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::RawPointerDeref,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "{} raw pointer dereference(s) in unsafe code in {}",
+                    derefs, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // static mut — unsynchronised global state, a data race as soon as two threads touch it
+        let static_mut =
+            RE_RUST_STATIC_MUT.get_or_init(|| Regex::new(r"\bstatic\s+mut\s+\w+").unwrap());
+        let statics = static_mut.find_iter(content).count();
+        if statics > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::MutableStatic,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!("{} static mut item(s) in {}", statics, file_path),
+                recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Blocking calls inside async fns/blocks stall the executor thread they run on
+        let blocking = rust_blocking_in_async(content);
+        if blocking > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::BlockingIO,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "{} blocking call(s) (thread::sleep/std I/O) inside async code in {}",
+                    blocking, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Unbounded channels — a slow consumer turns into unbounded memory growth
+        let unbounded = [
+            "unbounded_channel(",
+            "unbounded_channel::<",
+            "mpsc::unbounded(",
+            "channel::unbounded(",
+            "unbounded::<",
+        ]
+        .iter()
+        .map(|pattern| content.matches(pattern).count())
+        .sum::<usize>();
+        if unbounded > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::UncheckedAllocation,
+                location: Some(file_path.to_string()),
+                severity: Severity::Medium,
+                description: format!("{} unbounded channel(s) in {}", unbounded, file_path),
+                recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // block_on in library code — panics or deadlocks when the caller is already async
+        let block_on = content.matches("block_on(").count();
+        if block_on > 0 && rust_is_library_file(file_path, content) {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::DeadlockPotential,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "{} block_on call(s) in library code in {}",
+                    block_on, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Manual Send/Sync impls — the compiler's thread-safety proof is replaced by a promise
+        let send_sync = RE_RUST_SEND_SYNC_IMPL.get_or_init(|| {
+            Regex::new(r"unsafe\s+impl\s*(?:<[^>]*>)?\s*(?:[\w:]+::)?(Send|Sync)\s+for\s+([\w:]+)")
+                .unwrap()
+        });
+        let impls: Vec<String> = send_sync
+            .captures_iter(content)
+            .map(|caps| format!("{} for {}", &caps[1], &caps[2]))
+            .collect();
+        if !impls.is_empty() {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::ManualSendSync,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!("Manual unsafe impl {} in {}", impls.join(", "), file_path),
+                recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -801,6 +1059,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Unchecked malloc in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -812,6 +1073,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("gets() usage (unbounded buffer write) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -823,6 +1087,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("system() call (command injection risk) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -834,6 +1101,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("sprintf() usage (buffer overflow risk) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -845,6 +1115,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Unbounded string operation (strcpy/strcat) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -870,6 +1143,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} goroutines spawned in {}", go_count, file_path),
                 recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -881,6 +1157,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("unsafe.Pointer usage in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -892,6 +1171,102 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("exec.Command usage (command injection risk) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        let synchronized = content.contains("sync.Mutex")
+            || content.contains("sync.RWMutex")
+            || content.contains("sync.Map")
+            || content.contains(".Lock()");
+
+        // Plain maps written from goroutines — Go's runtime aborts with "concurrent map writes"
+        let racy_maps = go_maps_written_in_goroutines(content);
+        if !racy_maps.is_empty() && !synchronized {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::RaceCondition,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "Map(s) {} written from goroutines without sync in {}",
+                    racy_maps.join(", "),
+                    file_path
+                ),
+                recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // time.Sleep standing in for a WaitGroup/channel — passes until the scheduler is busy
+        let spawns_goroutines = content.contains("go func") || go_count > 0;
+        let waits = content.contains(".Wait()") || content.contains("<-");
+        if spawns_goroutines && !waits && content.contains("time.Sleep(") {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::RaceCondition,
+                location: Some(file_path.to_string()),
+                severity: Severity::Medium,
+                description: format!(
+                    "time.Sleep used to wait for goroutines (no WaitGroup/channel) in {}",
+                    file_path
+                ),
+                recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        let loops = go_loops(content);
+
+        // Unbounded loops in context-aware code that never check for cancellation
+        if content.contains("context.Context") {
+            let uncancellable = loops
+                .iter()
+                .filter(|(header, body)| {
+                    // `for {}` and `for cond {}`; range and three-clause loops are bounded.
+                    let unbounded = !header.contains("range") && !header.contains(';');
+                    unbounded && !body.contains(".Done()") && !body.contains(".Err()")
+                })
+                .count();
+            if uncancellable > 0 {
+                weak_points.push(WeakPoint {
+                    category: WeakPointCategory::UnboundedLoop,
+                    location: Some(file_path.to_string()),
+                    severity: Severity::Medium,
+                    description: format!(
+                        "{} long-running loop(s) without ctx.Done() check in {}",
+                        uncancellable, file_path
+                    ),
+                    recommended_attack: vec![AttackAxis::Time, AttackAxis::Concurrency],
+                    provenance: None,
+                    calibrated: None,
+                    owner: None,
+                });
+            }
+        }
+
+        // defer inside a loop body runs at function exit, holding every iteration's resource
+        let deferred = loops
+            .iter()
+            .filter(|(_, body)| body.contains("defer ") && !body.contains("func("))
+            .count();
+        if deferred > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::ResourceLeak,
+                location: Some(file_path.to_string()),
+                severity: Severity::Medium,
+                description: format!(
+                    "{} loop(s) with defer in the loop body in {}",
+                    deferred, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Time, AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -915,6 +1290,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Unbounded while True loop in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -925,17 +1303,45 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Dynamic code execution (eval/exec) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
         // pickle.load / pickle.loads — arbitrary code execution via deserialization
         if content.contains("pickle.load") {
+            let external = RE_PY_PICKLE_EXTERNAL
+                .get_or_init(|| {
+                    Regex::new(
+                        r"pickle\.loads?\([^)]*(?:request|recv\(|\.read\(|\.content|\.body|b64decode|socket|stdin)",
+                    )
+                    .unwrap()
+                })
+                .is_match(content);
+            let (description, recommended_attack) = if external {
+                (
+                    format!(
+                        "pickle.loads on external data (request/socket/stream) in {}",
+                        file_path
+                    ),
+                    vec![AttackAxis::Network, AttackAxis::Cpu, AttackAxis::Memory],
+                )
+            } else {
+                (
+                    format!("pickle deserialization (arbitrary code execution) in {}", file_path),
+                    vec![AttackAxis::Cpu, AttackAxis::Memory],
+                )
+            };
             weak_points.push(WeakPoint {
                 category: WeakPointCategory::UnsafeDeserialization,
                 location: Some(file_path.to_string()),
                 severity: Severity::Critical,
-                description: format!("pickle deserialization (arbitrary code execution) in {}", file_path),
-                recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                description,
+                recommended_attack,
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -947,20 +1353,79 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Shell command execution (os.system/os.popen) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
-        // subprocess with shell=True
-        if content.contains("subprocess.call") || content.contains("subprocess.Popen") || content.contains("subprocess.run") {
-            if content.contains("shell=True") || content.contains("shell = True") {
-                weak_points.push(WeakPoint {
-                    category: WeakPointCategory::CommandInjection,
-                    location: Some(file_path.to_string()),
-                    severity: Severity::High,
-                    description: format!("subprocess with shell=True in {}", file_path),
-                    recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
-                });
-            }
+        // subprocess with shell=True and a command built from f-strings/format/%/+ — injection
+        let interpolated = python_interpolated_shell_calls(content);
+        if interpolated > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::CommandInjection,
+                location: Some(file_path.to_string()),
+                severity: Severity::Critical,
+                description: format!(
+                    "{} subprocess call(s) with shell=True on an interpolated command in {}",
+                    interpolated, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        } else if (content.contains("subprocess.call")
+            || content.contains("subprocess.Popen")
+            || content.contains("subprocess.run"))
+            && (content.contains("shell=True") || content.contains("shell = True"))
+        {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::CommandInjection,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!("subprocess with shell=True in {}", file_path),
+                recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Blocking calls inside `async def` freeze the whole event loop
+        let blocking = python_blocking_in_async(content);
+        if blocking > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::BlockingIO,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "{} blocking call(s) (time.sleep/requests/subprocess) inside async def in {}",
+                    blocking, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Bare `except:` also swallows KeyboardInterrupt/SystemExit and hides real failures
+        let bare_except = RE_PY_BARE_EXCEPT
+            .get_or_init(|| Regex::new(r"(?m)^\s*except\s*:").unwrap())
+            .find_iter(content)
+            .count();
+        if bare_except > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::UncheckedError,
+                location: Some(file_path.to_string()),
+                severity: Severity::Medium,
+                description: format!("{} bare except: clause(s) in {}", bare_except, file_path),
+                recommended_attack: vec![AttackAxis::Memory, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
         }
 
         Ok(())
@@ -973,6 +1438,14 @@ impl Analyzer {
         weak_points: &mut Vec<WeakPoint>,
         file_path: &str,
     ) -> Result<()> {
+        // TypeScript declaration files carry types only, no runtime code
+        if [".d.ts", ".d.mts", ".d.cts"]
+            .iter()
+            .any(|ext| file_path.ends_with(ext))
+        {
+            return Ok(());
+        }
+
         stats.io_operations += content.matches("fs.read").count();
         stats.io_operations += content.matches("fs.write").count();
         stats.io_operations += content.matches("fetch(").count();
@@ -986,6 +1459,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("eval() usage in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -997,6 +1473,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("DOM manipulation (innerHTML/document.write) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Network],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1008,6 +1487,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("dangerouslySetInnerHTML (XSS risk) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Network],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1019,6 +1501,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Deno -A (all permissions) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Network, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1031,6 +1516,94 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} JSON.parseExn calls in {}", parse_exn_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // new Function(...) / string timers — eval by another name
+        let function_ctor = RE_JS_FUNCTION_CTOR
+            .get_or_init(|| {
+                Regex::new(r#"\bnew\s+Function\s*\(|\bFunction\s*\(\s*["'`]|\bset(?:Timeout|Interval)\s*\(\s*["'`]"#)
+                    .unwrap()
+            })
+            .find_iter(content)
+            .count();
+        if function_ctor > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::DynamicCodeExecution,
+                location: Some(file_path.to_string()),
+                severity: Severity::Critical,
+                description: format!(
+                    "{} Function constructor/string timer call(s) in {}",
+                    function_ctor, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Network, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Deep merges of request data can write through `__proto__` into every object
+        let merges = js_prototype_pollution_merges(content);
+        if merges > 0 {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::UnsafeDeserialization,
+                location: Some(file_path.to_string()),
+                severity: Severity::High,
+                description: format!(
+                    "{} deep merge(s) open to prototype pollution in {}",
+                    merges, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Network, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            });
+        }
+
+        // Synchronous fs/child_process calls in a server block every in-flight request
+        if js_is_server_code(content) {
+            let sync_calls = RE_JS_SYNC_CALL
+                .get_or_init(|| {
+                    Regex::new(r"\b(?:fs\.\w+Sync|execSync|execFileSync|spawnSync)\s*\(").unwrap()
+                })
+                .find_iter(content)
+                .count();
+            if sync_calls > 0 {
+                weak_points.push(WeakPoint {
+                    category: WeakPointCategory::BlockingIO,
+                    location: Some(file_path.to_string()),
+                    severity: Severity::High,
+                    description: format!(
+                        "{} synchronous fs/child_process call(s) in server code in {}",
+                        sync_calls, file_path
+                    ),
+                    recommended_attack: vec![AttackAxis::Network, AttackAxis::Concurrency],
+                    provenance: None,
+                    calibrated: None,
+                    owner: None,
+                });
+            }
+        }
+
+        // Promise chains without .catch() become unhandled rejections (fatal since Node 15)
+        let unhandled = js_unhandled_promise_chains(content);
+        if unhandled > 0 && !content.contains("unhandledRejection") {
+            weak_points.push(WeakPoint {
+                category: WeakPointCategory::UncheckedError,
+                location: Some(file_path.to_string()),
+                severity: Severity::Medium,
+                description: format!(
+                    "{} promise chain(s) without .catch() in {}",
+                    unhandled, file_path
+                ),
+                recommended_attack: vec![AttackAxis::Network, AttackAxis::Time],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1055,6 +1628,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Dynamic code execution in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1081,6 +1657,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Runtime.exec() in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1124,6 +1703,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Code.eval_string/eval_quoted in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1139,6 +1721,9 @@ impl Analyzer {
                     atom_count, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1150,6 +1735,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("System command execution in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1162,6 +1750,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("Dynamic apply/3 in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1196,6 +1787,9 @@ impl Analyzer {
                     atom_count, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1207,6 +1801,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("os:cmd call in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1234,6 +1831,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} @external FFI calls in {}", external_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1271,6 +1871,9 @@ impl Analyzer {
                     parse_exn, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1289,6 +1892,9 @@ impl Analyzer {
                     ignore_count, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1304,6 +1910,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} unsafe get calls in {}", unsafe_gets, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1517,6 +2126,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Obj.magic (unsafe type coercion) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1527,6 +2139,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Obj.repr (unsafe representation access) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1538,6 +2153,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Unsafe Marshal deserialization in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1549,6 +2167,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Unix.system/execvp command execution in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1581,6 +2202,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} unsafe operations in {}", unsafe_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1610,6 +2234,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("eval usage in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1621,6 +2248,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("System/process call in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1640,6 +2270,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} call/cc usage in {}", callcc_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1669,6 +2302,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("{} unsafePerformIO in {}", unsafe_io, file_path),
                 recommended_attack: vec![AttackAxis::Concurrency, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1679,6 +2315,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("{} unsafeCoerce in {}", unsafe_coerce, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1699,6 +2338,9 @@ impl Analyzer {
                     partials, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1715,6 +2357,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} error/undefined in {}", error_count, file_path),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1745,6 +2390,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} foreign imports in {}", ffi_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1756,6 +2404,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Unsafe coercion in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1786,6 +2437,9 @@ impl Analyzer {
                     believe_count, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1797,6 +2451,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("unsafePerformIO in {}", file_path),
                 recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1827,6 +2484,9 @@ impl Analyzer {
                     sorry_count, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1838,6 +2498,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("native_decide in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1849,6 +2512,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Unsafe cast/implementedBy in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1871,6 +2537,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("trustMe/primTrustMe (proof bypass) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1912,6 +2581,9 @@ impl Analyzer {
                     file_path
                 ),
                 recommended_attack: vec![AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1923,6 +2595,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Shell/process_create in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -1962,6 +2637,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} unsafe pointer casts in {}", ptr_ops, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2005,6 +2683,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} Unchecked_* operations in {}", unchecked, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2016,6 +2697,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("pragma Suppress (runtime checks disabled) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2066,6 +2750,9 @@ impl Analyzer {
                     severity: Severity::Medium,
                     description: format!("{} rawptr usage in {}", rawptr_count, file_path),
                     recommended_attack: vec![AttackAxis::Memory],
+                    provenance: None,
+                    calibrated: None,
+                    owner: None,
                 });
             }
         }
@@ -2088,6 +2775,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("emit pragma (raw code injection) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2101,6 +2791,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("{} cast[] (unsafe coercion) in {}", cast_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2138,6 +2831,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} FFI calls in {}", ffi_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2170,6 +2866,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} @system functions in {}", system_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2205,6 +2904,9 @@ impl Analyzer {
                     severity: Severity::Critical,
                     description: format!("builtins.exec (command execution) in {}", file_path),
                     recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                    provenance: None,
+                    calibrated: None,
+                    owner: None,
                 });
             }
 
@@ -2250,6 +2952,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("eval usage in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2267,6 +2972,9 @@ impl Analyzer {
                     dollar_vars, file_path
                 ),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2278,6 +2986,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("World-writable permissions in {}", file_path),
                 recommended_attack: vec![AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2289,6 +3000,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("Deno -A (all permissions) in {}", file_path),
                 recommended_attack: vec![AttackAxis::Network, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2300,6 +3014,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("Hardcoded /tmp/ path without mktemp in {}", file_path),
                 recommended_attack: vec![AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2325,6 +3042,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("eval/Meta.parse in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2339,6 +3059,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} ccall/FFI calls in {}", ccall_count, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2379,6 +3102,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("loadstring/dofile in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2390,6 +3116,9 @@ impl Analyzer {
                 severity: Severity::High,
                 description: format!("os.execute/io.popen in {}", file_path),
                 recommended_attack: vec![AttackAxis::Cpu, AttackAxis::Disk],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2429,6 +3158,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} FFI/external bindings in {}", ffi_patterns, file_path),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2472,6 +3204,9 @@ impl Analyzer {
                 severity: Severity::Medium,
                 description: format!("{} HTTP (non-HTTPS) URLs in {}", http_count, file_path),
                 recommended_attack: vec![AttackAxis::Network],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2486,6 +3221,9 @@ impl Analyzer {
                 severity: Severity::Critical,
                 description: format!("Possible hardcoded secret in {}", file_path),
                 recommended_attack: vec![AttackAxis::Network],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2501,6 +3239,9 @@ impl Analyzer {
                 severity: Severity::Low,
                 description: format!("{} TODO/FIXME/HACK markers in {}", todo_count, file_path),
                 recommended_attack: vec![AttackAxis::Cpu],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             });
         }
 
@@ -2705,12 +3446,11 @@ impl Analyzer {
                     }
                 }
 
-                Language::Erlang => {
+                Language::Erlang
                     if content.contains("-behaviour(gen_server)")
-                        || content.contains("-behaviour(supervisor)")
-                    {
-                        frameworks.insert(Framework::OTP);
-                    }
+                        || content.contains("-behaviour(supervisor)") =>
+                {
+                    frameworks.insert(Framework::OTP);
                 }
 
                 Language::Go => {
@@ -2812,7 +3552,7 @@ impl Analyzer {
 
         // Base recommendations come from weak-point categories.
         for wp in weak_points {
-            recommendations.extend(&wp.recommended_attack);
+            recommendations.extend(wp.recommended_attack.iter().cloned());
         }
 
         // Global heuristics widen coverage when aggregate risk indicators are high.
@@ -2892,10 +3632,10 @@ impl Analyzer {
         // Rows are keyed by source category x sink axis to enable pivot-friendly reporting.
         for wp in weak_points {
             for axis in &wp.recommended_attack {
-                let key = (wp.category, *axis);
+                let key = (wp.category, axis.clone());
                 let entry = matrix.entry(key).or_insert_with(|| TaintMatrixRow {
                     source_category: wp.category,
-                    sink_axis: *axis,
+                    sink_axis: axis.clone(),
                     severity_value: Self::severity_value(wp.severity),
                     files: Vec::new(),
                     frameworks: frameworks.to_vec(),
@@ -2922,3 +3662,352 @@ impl Analyzer {
         }
     }
 }
+
+/// Bodies of `<keyword> { ... }` / `<keyword> move { ... }` blocks and `<keyword> fn` items,
+/// found by brace matching. Used for `unsafe` and `async` regions.
+fn rust_keyword_bodies<'a>(content: &'a str, keyword: &str) -> Vec<&'a str> {
+    let mut bodies = Vec::new();
+    let mut search = 0;
+    while let Some(found) = content[search..].find(keyword) {
+        let begin = search + found;
+        let start = begin + keyword.len();
+        search = start;
+        let preceded_by_ident = content[..begin]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if preceded_by_ident {
+            continue;
+        }
+        let mut rest = content[start..].trim_start();
+        if let Some(after_move) = rest.strip_prefix("move") {
+            rest = after_move.trim_start();
+        }
+        let open = if rest.starts_with('{') {
+            start + (content[start..].len() - rest.len())
+        } else if rest.starts_with("fn ") {
+            match content[start..].find('{') {
+                Some(offset) if !content[start..start + offset].contains(';') => start + offset,
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+        bodies.extend(brace_body(content, open));
+    }
+    bodies
+}
+
+/// Text between the `{` at byte offset `open` and its matching `}`.
+fn brace_body(content: &str, open: usize) -> Option<&str> {
+    let mut depth = 0usize;
+    for (offset, ch) in content[open..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(&content[open + 1..open + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Count dereferences of raw-pointer bindings (`*p`, `ptr::read(p)`, ...) inside unsafe code.
+fn rust_raw_pointer_derefs(content: &str) -> usize {
+    let bodies = rust_keyword_bodies(content, "unsafe");
+    if bodies.is_empty() {
+        return 0;
+    }
+
+    let binding = RE_RUST_RAW_PTR_BINDING.get_or_init(|| {
+        Regex::new(
+            r"(?:\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=[^;]*(?:as\s+\*(?:const|mut)\b|\.as_(?:mut_)?ptr\(\)|ptr::null(?:_mut)?)|\b(\w+)\s*:\s*\*(?:const|mut)\s)",
+        )
+        .unwrap()
+    });
+    let names: HashSet<&str> = binding
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| m.as_str())
+        .collect();
+
+    let explicit = [
+        "ptr::read(",
+        "ptr::write(",
+        ".read_volatile(",
+        ".write_volatile(",
+        ".read_unaligned(",
+    ];
+    let mut derefs: usize = bodies
+        .iter()
+        .map(|body| {
+            explicit
+                .iter()
+                .map(|p| body.matches(p).count())
+                .sum::<usize>()
+        })
+        .sum();
+
+    if !names.is_empty() {
+        let alternatives: Vec<String> = names.iter().map(|n| regex::escape(n)).collect();
+        // A leading operator/bracket distinguishes `*p` from multiplication `a * p`.
+        let pattern = format!(r"(?:^|[\s(=,{{;!&\[])\*(?:{})\b", alternatives.join("|"));
+        if let Ok(deref) = Regex::new(&pattern) {
+            derefs += bodies
+                .iter()
+                .map(|body| deref.find_iter(body).count())
+                .sum::<usize>();
+        }
+    }
+    derefs
+}
+
+/// Blocking std calls that stall an executor thread when made inside async code.
+fn rust_blocking_in_async(content: &str) -> usize {
+    let bodies = rust_keyword_bodies(content, "async");
+    if bodies.is_empty() {
+        return 0;
+    }
+
+    let mut blocking = vec![
+        "thread::sleep(",
+        "std::fs::",
+        "std::net::TcpStream::connect(",
+        "std::io::stdin()",
+        "reqwest::blocking::",
+    ];
+    // Bare `fs::`/`File::` only mean std when std's fs is imported and tokio's is not.
+    if content.contains("use std::fs") && !content.contains("tokio::fs") {
+        blocking.extend(["fs::read", "fs::write", "File::open(", "File::create("]);
+    }
+
+    bodies
+        .iter()
+        .map(|body| {
+            blocking
+                .iter()
+                .map(|pattern| body.matches(pattern).count())
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// Library code runs inside someone else's runtime, so `block_on` there can deadlock it.
+fn rust_is_library_file(file_path: &str, content: &str) -> bool {
+    let path = file_path.replace('\\', "/");
+    let binary = path.ends_with("main.rs")
+        || path.ends_with("build.rs")
+        || ["bin/", "examples/", "tests/", "benches/"]
+            .iter()
+            .any(|dir| path.starts_with(dir) || path.contains(&format!("/{}", dir)));
+    !binary && !content.contains("fn main(")
+}
+
+/// Go `for` loops as (header, body) pairs; the header is everything between `for` and `{`.
+fn go_loops(content: &str) -> Vec<(&str, &str)> {
+    let loop_head = RE_GO_FOR_LOOP.get_or_init(|| Regex::new(r"\bfor\b([^{\n]*)\{").unwrap());
+    loop_head
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            let body = brace_body(content, whole.end() - 1)?;
+            Some((caps.get(1)?.as_str().trim(), body))
+        })
+        .collect()
+}
+
+/// Maps created in this file that are assigned to inside a `go func` literal.
+fn go_maps_written_in_goroutines(content: &str) -> Vec<String> {
+    let map_decl = RE_GO_MAP_DECL.get_or_init(|| {
+        Regex::new(r"(\w+)\s*(?::=|=)\s*(?:make\(\s*)?map\[|\bvar\s+(\w+)\s+map\[").unwrap()
+    });
+    let maps: HashSet<&str> = map_decl
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| m.as_str())
+        .collect();
+    if maps.is_empty() {
+        return Vec::new();
+    }
+
+    let goroutine_bodies: Vec<&str> = content
+        .match_indices("go func(")
+        .filter_map(|(start, _)| {
+            let open = start + content[start..].find('{')?;
+            brace_body(content, open)
+        })
+        .collect();
+
+    let mut written: Vec<String> = maps
+        .into_iter()
+        .filter(|name| {
+            // `m[k] = v` or `m[k] += v`, but not a comparison `m[k] == v`.
+            let write = format!(r"\b{}\[[^\]]*\]\s*[-+*/]?=[^=]", regex::escape(name));
+            Regex::new(&write)
+                .map(|re| goroutine_bodies.iter().any(|body| re.is_match(body)))
+                .unwrap_or(false)
+        })
+        .map(str::to_string)
+        .collect();
+    written.sort();
+    written
+}
+
+/// Lines of every `async def` body, delimited by indentation.
+fn python_async_bodies(content: &str) -> Vec<Vec<&str>> {
+    let mut bodies = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    for (i, line) in lines.iter().enumerate() {
+        if !line.trim_start().starts_with("async def ") {
+            continue;
+        }
+        let def_indent = indent(line);
+        let body: Vec<&str> = lines[i + 1..]
+            .iter()
+            .copied()
+            .take_while(|l| l.trim().is_empty() || indent(l) > def_indent)
+            .collect();
+        bodies.push(body);
+    }
+    bodies
+}
+
+/// Synchronous calls that block the asyncio event loop when awaited code makes them.
+fn python_blocking_in_async(content: &str) -> usize {
+    const BLOCKING: &[&str] = &[
+        "time.sleep(",
+        "requests.get(",
+        "requests.post(",
+        "requests.put(",
+        "requests.delete(",
+        "requests.request(",
+        "urllib.request.urlopen(",
+        "subprocess.run(",
+        "subprocess.call(",
+        "subprocess.check_output(",
+        "input(",
+    ];
+    python_async_bodies(content)
+        .iter()
+        .flatten()
+        .map(|line| BLOCKING.iter().filter(|call| line.contains(*call)).count())
+        .sum()
+}
+
+/// `subprocess.*(..., shell=True)` calls whose command is an f-string, `.format`, `%` or `+`.
+fn python_interpolated_shell_calls(content: &str) -> usize {
+    const CALLS: &[&str] = &[
+        "subprocess.call(",
+        "subprocess.run(",
+        "subprocess.Popen(",
+        "subprocess.check_call(",
+        "subprocess.check_output(",
+    ];
+    let mut count = 0;
+    for call in CALLS {
+        for (start, _) in content.match_indices(call) {
+            let args_start = start + call.len();
+            let mut depth = 1usize;
+            let mut end = content.len();
+            for (offset, ch) in content[args_start..].char_indices() {
+                match ch {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            end = args_start + offset;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let args = &content[args_start..end];
+            let shell = args.contains("shell=True") || args.contains("shell = True");
+            let command = args.split(',').next().unwrap_or_default().trim_start();
+            let interpolated = command.starts_with("f\"")
+                || command.starts_with("f'")
+                || command.contains(".format(")
+                || command.contains('%')
+                || command.contains('+');
+            if shell && interpolated {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Deep-merge calls from libraries with known prototype-pollution history, plus hand-rolled
+/// `for...in` copies that write `target[key]` without guarding `__proto__`/`constructor`.
+fn js_prototype_pollution_merges(content: &str) -> usize {
+    let library = RE_JS_DEEP_MERGE
+        .get_or_init(|| {
+            Regex::new(
+                r"\b(?:_\.(?:merge|mergeWith|defaultsDeep|set|setWith)|(?:\$|jQuery)\.extend\s*\(\s*true|deepmerge|mergeDeep|deepExtend)\s*\(",
+            )
+            .unwrap()
+        })
+        .find_iter(content)
+        .count();
+
+    let guarded = content.contains("__proto__")
+        || content.contains("hasOwnProperty")
+        || content.contains("Object.hasOwn(")
+        || content.contains("Object.create(null)");
+    let hand_rolled = if guarded {
+        0
+    } else {
+        let for_in = RE_JS_FOR_IN.get_or_init(|| {
+            Regex::new(r"for\s*\(\s*(?:const|let|var)\s+(\w+)\s+in\s+\w+\s*\)").unwrap()
+        });
+        for_in
+            .captures_iter(content)
+            .filter(|caps| content.contains(&format!("[{}] =", &caps[1])))
+            .count()
+    };
+    library + hand_rolled
+}
+
+/// Node server entry points: synchronous I/O there stalls every connection.
+fn js_is_server_code(content: &str) -> bool {
+    [
+        "express(",
+        "http.createServer",
+        "https.createServer",
+        "fastify(",
+        "new Koa(",
+        "@nestjs/",
+        "Deno.serve(",
+        "app.listen(",
+    ]
+    .iter()
+    .any(|marker| content.contains(marker))
+}
+
+/// `.then(...)` chains whose statement never reaches a `.catch(`.
+fn js_unhandled_promise_chains(content: &str) -> usize {
+    content
+        .match_indices(".then(")
+        .filter(|(start, _)| {
+            let statement_end = content[*start..]
+                .find(";\n")
+                .or_else(|| content[*start..].find("\n\n"))
+                .map(|offset| start + offset)
+                .unwrap_or(content.len());
+            let statement = &content[*start..statement_end];
+            // Only judge the chain once, from its first `.then(`.
+            let first = !content[..*start]
+                .rsplit(";\n")
+                .next()
+                .unwrap_or_default()
+                .contains(".then(");
+            first && !statement.contains(".catch(")
+        })
+        .count()
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! `panic-attack:` magic comments
+//!
+//! Source can steer assail with comments such as
+//!
+//! ```text
+//! // panic-attack: ignore category=PanicPath reason="fixture panics on purpose"
+//! # panic-attack: axis=concurrency severity=high reason="lock order not enforced"
+//! ```
+//!
+//! Weak points are reported per file, so `ignore` drops the file's findings
+//! (of one category when `category=` is given) and a forcing annotation adds a
+//! finding that names the annotated line. Every annotation is copied into the
+//! report so suppressions stay auditable.
+
+use crate::types::*;
+use regex::Regex;
+use std::sync::OnceLock;
+
+static RE_ANNOTATION: OnceLock<Regex> = OnceLock::new();
+static RE_FIELD: OnceLock<Regex> = OnceLock::new();
+
+/// Parse every annotation in a file. Malformed annotations are reported and skipped.
+pub fn parse(file: &str, content: &str) -> Vec<SourceAnnotation> {
+    let annotation = RE_ANNOTATION.get_or_init(|| {
+        Regex::new(r"(?://|#|--|;|/\*|\(\*)\s*panic-attack:\s*(.*?)\s*(?:\*/|\*\))?\s*$").unwrap()
+    });
+
+    let mut annotations = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let Some(caps) = annotation.captures(line) else {
+            continue;
+        };
+        let text = caps[1].to_string();
+        match parse_text(&text) {
+            Ok((action, category)) => annotations.push(SourceAnnotation {
+                file: file.to_string(),
+                line: index + 1,
+                text,
+                action,
+                category,
+                suppressed: 0,
+            }),
+            Err(err) => tracing::warn!(
+                "ignoring panic-attack annotation at {}:{}: {}",
+                file,
+                index + 1,
+                err
+            ),
+        }
+    }
+    annotations
+}
+
+/// Remove findings suppressed by `ignore` annotations and add forced findings.
+pub fn apply(weak_points: &mut Vec<WeakPoint>, annotations: &mut [SourceAnnotation]) {
+    for annotation in annotations
+        .iter_mut()
+        .filter(|a| a.action == AnnotationAction::Ignore)
+    {
+        let before = weak_points.len();
+        weak_points.retain(|wp| !suppresses(annotation, wp));
+        annotation.suppressed = before - weak_points.len();
+    }
+
+    for annotation in annotations
+        .iter()
+        .filter(|a| a.action == AnnotationAction::Force)
+    {
+        if let Some(wp) = forced_weak_point(annotation) {
+            weak_points.push(wp);
+        }
+    }
+}
+
+fn suppresses(annotation: &SourceAnnotation, wp: &WeakPoint) -> bool {
+    wp.location.as_deref() == Some(annotation.file.as_str())
+        && annotation.category.is_none_or(|c| c == wp.category)
+}
+
+fn forced_weak_point(annotation: &SourceAnnotation) -> Option<WeakPoint> {
+    let fields = fields(&annotation.text);
+    let axes: Vec<AttackAxis> = fields
+        .iter()
+        .filter(|(key, _)| key == "axis")
+        .flat_map(|(_, value)| value.split(',').filter_map(parse_axis).collect::<Vec<_>>())
+        .collect();
+    let category = annotation
+        .category
+        .or_else(|| axes.first().map(|axis| category_for_axis(axis.clone())))?;
+    let severity = fields
+        .iter()
+        .find(|(key, _)| key == "severity")
+        .and_then(|(_, value)| parse_severity(value))
+        .unwrap_or(Severity::Medium);
+    let reason = fields
+        .iter()
+        .find(|(key, _)| key == "reason")
+        .map(|(_, value)| value.as_str())
+        .unwrap_or("no reason given");
+
+    Some(WeakPoint {
+        category,
+        location: Some(annotation.file.clone()),
+        severity,
+        description: format!(
+            "Annotated at {}:{}: {}",
+            annotation.file, annotation.line, reason
+        ),
+        recommended_attack: axes,
+        provenance: None,
+        calibrated: None,
+        owner: None,
+    })
+}
+
+fn parse_text(text: &str) -> Result<(AnnotationAction, Option<WeakPointCategory>), String> {
+    let fields = fields(text);
+    let ignore = text.split_whitespace().next() == Some("ignore");
+
+    let mut category = None;
+    for (key, value) in &fields {
+        match key.as_str() {
+            "category" => {
+                category = Some(
+                    serde_json::from_value(serde_json::Value::String(value.clone()))
+                        .map_err(|_| format!("unknown category `{}`", value))?,
+                )
+            }
+            "axis" => {
+                if let Some(bad) = value.split(',').find(|a| parse_axis(a).is_none()) {
+                    return Err(format!("unknown axis `{}`", bad));
+                }
+            }
+            "severity" => {
+                if parse_severity(value).is_none() {
+                    return Err(format!("unknown severity `{}`", value));
+                }
+            }
+            "reason" => {}
+            other => return Err(format!("unknown field `{}`", other)),
+        }
+    }
+
+    if ignore {
+        return Ok((AnnotationAction::Ignore, category));
+    }
+    let has_axis = fields.iter().any(|(key, _)| key == "axis");
+    if category.is_none() && !has_axis {
+        return Err("expected `ignore`, `axis=...` or `category=...`".to_string());
+    }
+    Ok((AnnotationAction::Force, category))
+}
+
+/// `key=value` and `key="quoted value"` pairs.
+fn fields(text: &str) -> Vec<(String, String)> {
+    let field = RE_FIELD.get_or_init(|| Regex::new(r#"(\w+)=(?:"([^"]*)"|(\S+))"#).unwrap());
+    field
+        .captures_iter(text)
+        .map(|caps| {
+            let value = caps
+                .get(2)
+                .or_else(|| caps.get(3))
+                .map_or("", |m| m.as_str());
+            (caps[1].to_ascii_lowercase(), value.to_string())
+        })
+        .collect()
+}
+
+fn parse_axis(value: &str) -> Option<AttackAxis> {
+    AttackAxis::builtin(value.trim())
+}
+
+fn parse_severity(value: &str) -> Option<Severity> {
+    match value.to_ascii_lowercase().as_str() {
+        "low" => Some(Severity::Low),
+        "medium" => Some(Severity::Medium),
+        "high" => Some(Severity::High),
+        "critical" => Some(Severity::Critical),
+        _ => None,
+    }
+}
+
+/// Category used for a forced finding that only names an axis.
+fn category_for_axis(axis: AttackAxis) -> WeakPointCategory {
+    match axis {
+        AttackAxis::Cpu => WeakPointCategory::UnboundedLoop,
+        AttackAxis::Memory => WeakPointCategory::UncheckedAllocation,
+        AttackAxis::Disk => WeakPointCategory::BlockingIO,
+        AttackAxis::Network => WeakPointCategory::InsecureProtocol,
+        AttackAxis::Concurrency => WeakPointCategory::RaceCondition,
+        AttackAxis::Time => WeakPointCategory::DeadlockPotential,
+        AttackAxis::Signal | AttackAxis::Custom(_) => WeakPointCategory::PanicPath,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(category: WeakPointCategory) -> WeakPoint {
+        WeakPoint {
+            category,
+            location: Some("src/lib.rs".to_string()),
+            severity: Severity::High,
+            description: String::new(),
+            recommended_attack: vec![AttackAxis::Memory],
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn test_ignore_and_force_annotations() {
+        let content = r#"
+fn a() {}
+// panic-attack: ignore category=PanicPath reason="panics are the test oracle"
+fn b() {}
+/* panic-attack: axis=concurrency severity=high reason="lock order not enforced" */
+"#;
+        let mut annotations = parse("src/lib.rs", content);
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations[0].line, 3);
+        assert_eq!(annotations[0].category, Some(WeakPointCategory::PanicPath));
+        assert_eq!(annotations[1].action, AnnotationAction::Force);
+
+        let mut weak_points = vec![
+            finding(WeakPointCategory::PanicPath),
+            finding(WeakPointCategory::UnsafeCode),
+        ];
+        apply(&mut weak_points, &mut annotations);
+
+        assert_eq!(annotations[0].suppressed, 1);
+        assert_eq!(weak_points.len(), 2);
+        assert_eq!(weak_points[0].category, WeakPointCategory::UnsafeCode);
+        let forced = &weak_points[1];
+        assert_eq!(forced.category, WeakPointCategory::RaceCondition);
+        assert_eq!(forced.severity, Severity::High);
+        assert_eq!(forced.recommended_attack, vec![AttackAxis::Concurrency]);
+        assert_eq!(
+            forced.description,
+            "Annotated at src/lib.rs:5: lock order not enforced"
+        );
+    }
+
+    #[test]
+    fn test_malformed_annotations_are_skipped() {
+        let content = "# panic-attack: axis=gpu\n# panic-attack: please\n# panic-attack: ignore\n";
+        let annotations = parse("tool.py", content);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].action, AnnotationAction::Ignore);
+        assert_eq!(annotations[0].category, None);
+    }
+}
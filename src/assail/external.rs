@@ -0,0 +1,393 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! External analyzers aggregated into assail results.
+//!
+//! Instead of reimplementing every lint, assail can run established tools for the languages it
+//! detected and fold their findings in as weak points: clippy for Rust, bandit for Python and
+//! semgrep for any language. Each finding keeps its tool, rule id and line as provenance.
+//! Findings whose rule does not map onto a weak-point category (style lints, for example) are
+//! dropped. A missing or failing tool produces a warning rather than aborting the scan.
+
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalAnalyzer {
+    Clippy,
+    Semgrep,
+    Bandit,
+}
+
+impl ExternalAnalyzer {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "clippy" => Some(Self::Clippy),
+            "semgrep" => Some(Self::Semgrep),
+            "bandit" => Some(Self::Bandit),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Clippy => "clippy",
+            Self::Semgrep => "semgrep",
+            Self::Bandit => "bandit",
+        }
+    }
+
+    /// Whether the tool has anything to say about a project written in `languages`.
+    pub fn applies_to(self, languages: &HashSet<Language>) -> bool {
+        match self {
+            Self::Clippy => languages.contains(&Language::Rust),
+            Self::Bandit => languages.contains(&Language::Python),
+            Self::Semgrep => true,
+        }
+    }
+
+    fn command(self, root: &Path) -> Command {
+        let mut command = match self {
+            Self::Clippy => {
+                let mut command = Command::new("cargo");
+                command.args(["clippy", "--quiet", "--message-format=json"]);
+                command
+            }
+            Self::Semgrep => {
+                let mut command = Command::new("semgrep");
+                command.args(["scan", "--json", "--quiet", "--config", "auto", "."]);
+                command
+            }
+            Self::Bandit => {
+                let mut command = Command::new("bandit");
+                command.args(["-r", ".", "-f", "json", "-q"]);
+                command
+            }
+        };
+        command.current_dir(root);
+        command
+    }
+
+    /// Run the tool over `root` and map its findings.
+    pub fn run(self, root: &Path) -> Result<Vec<WeakPoint>> {
+        if self == Self::Clippy && !root.join("Cargo.toml").is_file() {
+            return Err(anyhow!("no Cargo.toml in {}", root.display()));
+        }
+        let output = self
+            .command(root)
+            .output()
+            .with_context(|| format!("running {}", self.name()))?;
+        // All three exit non-zero when they find something, so only unparseable output fails.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        self.parse_output(&stdout).map_err(|err| {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            match stderr.lines().find(|line| !line.trim().is_empty()) {
+                Some(line) => anyhow!("{} ({})", err, line.trim()),
+                None => err,
+            }
+        })
+    }
+
+    /// Map the tool's JSON output onto weak points, with paths relative to the scanned root.
+    pub fn parse_output(self, stdout: &str) -> Result<Vec<WeakPoint>> {
+        let findings = match self {
+            Self::Clippy => parse_clippy(stdout),
+            Self::Semgrep => parse_semgrep(stdout)?,
+            Self::Bandit => parse_bandit(stdout)?,
+        };
+        Ok(findings
+            .into_iter()
+            .filter_map(|finding| finding.into_weak_point(self))
+            .collect())
+    }
+}
+
+/// Run each applicable analyzer, warning about (and skipping) any that fail.
+pub fn run_external(
+    analyzers: &[ExternalAnalyzer],
+    root: &Path,
+    languages: &HashSet<Language>,
+) -> Vec<WeakPoint> {
+    let mut weak_points = Vec::new();
+    for analyzer in analyzers {
+        if !analyzer.applies_to(languages) {
+            tracing::debug!(
+                "Skipping {}: no matching language detected",
+                analyzer.name()
+            );
+            continue;
+        }
+        match analyzer.run(root) {
+            Ok(found) => {
+                tracing::debug!("{} reported {} weak point(s)", analyzer.name(), found.len());
+                weak_points.extend(found);
+            }
+            Err(err) => tracing::warn!("{} analyzer skipped: {:#}", analyzer.name(), err),
+        }
+    }
+    weak_points
+}
+
+/// One finding in tool-neutral form, before category mapping.
+struct Finding {
+    rule: String,
+    message: String,
+    path: String,
+    line: Option<usize>,
+    severity: Severity,
+}
+
+impl Finding {
+    fn into_weak_point(self, analyzer: ExternalAnalyzer) -> Option<WeakPoint> {
+        let category = categorize(&format!("{} {}", self.rule, self.message))?;
+        let path = self.path.trim_start_matches("./").to_string();
+        Some(WeakPoint {
+            category,
+            location: Some(path.clone()),
+            severity: self.severity,
+            description: format!(
+                "{} [{} {}] in {}",
+                self.message.lines().next().unwrap_or_default().trim(),
+                analyzer.name(),
+                self.rule,
+                path
+            ),
+            recommended_attack: axes_for(category),
+            provenance: Some(FindingProvenance {
+                tool: analyzer.name().to_string(),
+                rule: self.rule,
+                line: self.line,
+            }),
+            calibrated: None,
+            owner: None,
+        })
+    }
+}
+
+fn parse_clippy(stdout: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for line in stdout.lines() {
+        let Ok(record) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        if record["reason"] != "compiler-message" {
+            continue;
+        }
+        let message = &record["message"];
+        let Some(rule) = message["code"]["code"].as_str() else {
+            continue;
+        };
+        let Some(span) = message["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true))
+        else {
+            continue;
+        };
+        findings.push(Finding {
+            rule: rule.to_string(),
+            message: message["message"].as_str().unwrap_or_default().to_string(),
+            path: span["file_name"].as_str().unwrap_or_default().to_string(),
+            line: span["line_start"].as_u64().map(|line| line as usize),
+            severity: match message["level"].as_str() {
+                Some("error") => Severity::High,
+                _ => Severity::Medium,
+            },
+        });
+    }
+    findings
+}
+
+fn parse_semgrep(stdout: &str) -> Result<Vec<Finding>> {
+    let report: Value = serde_json::from_str(stdout).context("parsing semgrep JSON output")?;
+    let results = report["results"].as_array().cloned().unwrap_or_default();
+    Ok(results
+        .iter()
+        .map(|result| Finding {
+            rule: result["check_id"].as_str().unwrap_or_default().to_string(),
+            message: result["extra"]["message"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            path: result["path"].as_str().unwrap_or_default().to_string(),
+            line: result["start"]["line"].as_u64().map(|line| line as usize),
+            severity: match result["extra"]["severity"].as_str() {
+                Some("ERROR") => Severity::High,
+                Some("WARNING") => Severity::Medium,
+                _ => Severity::Low,
+            },
+        })
+        .collect())
+}
+
+fn parse_bandit(stdout: &str) -> Result<Vec<Finding>> {
+    let report: Value = serde_json::from_str(stdout).context("parsing bandit JSON output")?;
+    let results = report["results"].as_array().cloned().unwrap_or_default();
+    Ok(results
+        .iter()
+        .map(|result| Finding {
+            rule: format!(
+                "{}:{}",
+                result["test_id"].as_str().unwrap_or_default(),
+                result["test_name"].as_str().unwrap_or_default()
+            ),
+            message: result["issue_text"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            path: result["filename"].as_str().unwrap_or_default().to_string(),
+            line: result["line_number"].as_u64().map(|line| line as usize),
+            severity: match result["issue_severity"].as_str() {
+                Some("HIGH") => Severity::High,
+                Some("MEDIUM") => Severity::Medium,
+                _ => Severity::Low,
+            },
+        })
+        .collect())
+}
+
+/// Map a rule id plus message onto the closest weak-point category by keyword.
+fn categorize(text: &str) -> Option<WeakPointCategory> {
+    let text = text.to_ascii_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+    let category = if has(&["injection", "shell", "subprocess", "os.system", "popen"]) {
+        WeakPointCategory::CommandInjection
+    } else if has(&["pickle", "marshal", "yaml.load", "yaml_load", "deserializ"]) {
+        WeakPointCategory::UnsafeDeserialization
+    } else if has(&["eval", "exec_used", "dynamic code"]) {
+        WeakPointCategory::DynamicCodeExecution
+    } else if has(&[
+        "password",
+        "secret",
+        "hardcoded",
+        "credential",
+        "api key",
+        "token",
+    ]) {
+        WeakPointCategory::HardcodedSecret
+    } else if has(&["traversal", "path_join", "tarfile", "zip slip"]) {
+        WeakPointCategory::PathTraversal
+    } else if has(&["chmod", "permission"]) {
+        WeakPointCategory::ExcessivePermissions
+    } else if has(&[
+        "insecure", "ssl", "tls", "telnet", "ftp", "md5", "sha1", "http://",
+    ]) {
+        WeakPointCategory::InsecureProtocol
+    } else if has(&["ffi", "extern \"c\"", "ctypes"]) {
+        WeakPointCategory::UnsafeFFI
+    } else if has(&["transmute"]) {
+        WeakPointCategory::UnsafeTransmute
+    } else if has(&["static_mut", "static mut"]) {
+        WeakPointCategory::MutableStatic
+    } else if has(&[
+        "non_send_fields_in_send_ty",
+        "unsafe impl send",
+        "unsafe impl sync",
+    ]) {
+        WeakPointCategory::ManualSendSync
+    } else if has(&["ptr_arg_deref", "deref_raw", "raw pointer deref"]) {
+        WeakPointCategory::RawPointerDeref
+    } else if has(&["unsafe", "raw pointer", "ptr_", "_ptr"]) {
+        WeakPointCategory::UnsafeCode
+    } else if has(&["await_holding", "mutex", "deadlock"]) {
+        WeakPointCategory::DeadlockPotential
+    } else if has(&["race", "non_send", "arc_with_non_send"]) {
+        WeakPointCategory::RaceCondition
+    } else if has(&["mem_forget", "leak", "not closed"]) {
+        WeakPointCategory::ResourceLeak
+    } else if has(&["recursion", "recursive"]) {
+        WeakPointCategory::InfiniteRecursion
+    } else if has(&["infinite_loop", "empty_loop", "unbounded"]) {
+        WeakPointCategory::UnboundedLoop
+    } else if has(&["with_capacity", "uninit_vec", "allocation"]) {
+        WeakPointCategory::UncheckedAllocation
+    } else if has(&["blocking", "sleep"]) {
+        WeakPointCategory::BlockingIO
+    } else if has(&["cast_", "as_conversions", "coerc"]) {
+        WeakPointCategory::UnsafeTypeCoercion
+    } else if has(&[
+        "unwrap",
+        "expect",
+        "panic",
+        "indexing",
+        "unreachable",
+        "assert",
+    ]) {
+        WeakPointCategory::PanicPath
+    } else if has(&[
+        "unused_result",
+        "let_underscore",
+        "try_except_pass",
+        "error",
+    ]) {
+        WeakPointCategory::UncheckedError
+    } else {
+        return None;
+    };
+    Some(category)
+}
+
+fn axes_for(category: WeakPointCategory) -> Vec<AttackAxis> {
+    use WeakPointCategory::*;
+    match category {
+        UncheckedAllocation | ResourceLeak | UnsafeCode | UnsafeFFI | UnsafeTypeCoercion
+        | UnsafeTransmute | RawPointerDeref | VulnerableDependency => vec![AttackAxis::Memory],
+        RaceCondition | DeadlockPotential | MutableStatic | ManualSendSync => {
+            vec![AttackAxis::Concurrency]
+        }
+        BlockingIO | PathTraversal | ExcessivePermissions => vec![AttackAxis::Disk],
+        InsecureProtocol => vec![AttackAxis::Network],
+        UnboundedLoop | InfiniteRecursion | AtomExhaustion => {
+            vec![AttackAxis::Cpu, AttackAxis::Memory]
+        }
+        CommandInjection | DynamicCodeExecution | UnsafeDeserialization | HardcodedSecret => {
+            vec![AttackAxis::Cpu]
+        }
+        PanicPath | UncheckedError => vec![AttackAxis::Memory, AttackAxis::Disk],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clippy_lints_map_with_provenance() {
+        let stdout = [
+            r#"{"reason":"compiler-artifact","target":{}}"#,
+            r#"{"reason":"compiler-message","message":{"code":{"code":"clippy::unwrap_used"},"level":"warning","message":"used `unwrap()` on a `Result` value","spans":[{"file_name":"src/main.rs","line_start":12,"is_primary":true}]}}"#,
+            r#"{"reason":"compiler-message","message":{"code":{"code":"clippy::needless_return"},"level":"warning","message":"unneeded `return` statement","spans":[{"file_name":"src/lib.rs","line_start":3,"is_primary":true}]}}"#,
+        ]
+        .join("\n");
+        let points = ExternalAnalyzer::Clippy.parse_output(&stdout).unwrap();
+        assert_eq!(points.len(), 1, "style lints are dropped");
+        assert_eq!(points[0].category, WeakPointCategory::PanicPath);
+        assert_eq!(points[0].location.as_deref(), Some("src/main.rs"));
+        assert_eq!(
+            points[0].provenance,
+            Some(FindingProvenance {
+                tool: "clippy".to_string(),
+                rule: "clippy::unwrap_used".to_string(),
+                line: Some(12),
+            })
+        );
+    }
+
+    #[test]
+    fn test_bandit_and_semgrep_results() {
+        let bandit = r#"{"results":[{"test_id":"B602","test_name":"subprocess_popen_with_shell_equals_true","filename":"./app/run.py","line_number":8,"issue_severity":"HIGH","issue_text":"subprocess call with shell=True identified"}]}"#;
+        let points = ExternalAnalyzer::Bandit.parse_output(bandit).unwrap();
+        assert_eq!(points[0].category, WeakPointCategory::CommandInjection);
+        assert_eq!(points[0].severity, Severity::High);
+        assert_eq!(points[0].location.as_deref(), Some("app/run.py"));
+
+        let semgrep = r#"{"results":[{"check_id":"python.lang.security.deserialization.pickle.avoid-pickle","path":"app/load.py","start":{"line":4},"extra":{"message":"Avoid using pickle","severity":"WARNING"}}]}"#;
+        let points = ExternalAnalyzer::Semgrep.parse_output(semgrep).unwrap();
+        assert_eq!(points[0].category, WeakPointCategory::UnsafeDeserialization);
+        assert_eq!(points[0].severity, Severity::Medium);
+
+        assert!(ExternalAnalyzer::Bandit.parse_output("not json").is_err());
+    }
+}
@@ -0,0 +1,422 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Dependency manifest discovery for SBOM export.
+//!
+//! Walks the scan root for `Cargo.toml`, `package.json` and `go.mod` and records the package
+//! each one declares plus its declared dependencies, versions kept verbatim. Nothing is
+//! resolved: lockfiles, registries and workspace inheritance are out of scope, so a
+//! `foo.workspace = true` entry is listed without a version. Unparseable manifests are
+//! skipped with a warning rather than failing the scan.
+
+use crate::types::{DeclaredDependency, DependencyManifest, DependencyScope, Ecosystem};
+use std::fs;
+use std::path::Path;
+
+/// Directories never searched for manifests or watched for changes: build output and
+/// vendored dependency trees.
+pub(crate) const SKIP_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "vendor",
+    "build",
+    "dist",
+    "_build",
+];
+
+/// Every manifest under `root` (or `root` itself when it is a manifest), sorted by path.
+pub fn collect(root: &Path) -> Vec<DependencyManifest> {
+    let mut manifests = Vec::new();
+    if root.is_file() {
+        let name = root.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        manifests.extend(parse_file(root, name));
+    } else {
+        walk(root, root, &mut manifests);
+    }
+    manifests.sort_by(|a, b| a.path.cmp(&b.path));
+    manifests
+}
+
+fn walk(root: &Path, dir: &Path, manifests: &mut Vec<DependencyManifest>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+            if !name.starts_with('.') && !SKIP_DIRS.contains(&name.as_str()) {
+                walk(root, &path, manifests);
+            }
+        } else {
+            let rel = path.strip_prefix(root).unwrap_or(&path);
+            manifests.extend(parse_file(&path, &rel.to_string_lossy()));
+        }
+    }
+}
+
+fn parse_file(path: &Path, rel: &str) -> Option<DependencyManifest> {
+    let file_name = path.file_name()?.to_str()?;
+    let parse: fn(&str) -> Option<DependencyManifest> = match file_name {
+        "Cargo.toml" => parse_cargo,
+        "package.json" => parse_package_json,
+        "go.mod" => parse_go_mod,
+        _ => return None,
+    };
+    let content = fs::read_to_string(path).ok()?;
+    let Some(mut manifest) = parse(&content) else {
+        tracing::warn!("Skipping unparseable manifest {}", path.display());
+        return None;
+    };
+    manifest.path = rel.to_string();
+    Some(manifest)
+}
+
+/// `key = value` pairs of one dependency entry, values still quoted.
+type Fields = Vec<(String, String)>;
+
+/// Line-oriented `Cargo.toml` reader covering `[package]`, the dependency tables (including
+/// `target.'cfg(..)'` and `workspace` variants) and `[dependencies.foo]` sub-tables.
+pub fn parse_cargo(content: &str) -> Option<DependencyManifest> {
+    let mut manifest = DependencyManifest {
+        path: String::new(),
+        ecosystem: Ecosystem::Cargo,
+        name: None,
+        version: None,
+        dependencies: Vec::new(),
+    };
+    let mut section = String::new();
+    // `[dependencies.foo]` collects keys until the next header.
+    let mut table_dep: Option<(String, Fields, DependencyScope)> = None;
+
+    for raw in content.lines() {
+        let line = strip_toml_comment(raw).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            if let Some((key, fields, scope)) = table_dep.take() {
+                manifest
+                    .dependencies
+                    .push(cargo_dependency(&key, &fields, scope));
+            }
+            section = header.trim_end_matches(']').trim().to_string();
+            if let Some((table, dep)) = section.rsplit_once('.') {
+                if let Some(scope) = cargo_scope(table) {
+                    table_dep = Some((unquote(dep).to_string(), Vec::new(), scope));
+                }
+            }
+            continue;
+        }
+        // Continuation lines of multi-line arrays (e.g. under `[features]`) carry no key.
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if let Some((_, fields, _)) = table_dep.as_mut() {
+            fields.push((key.to_string(), value.to_string()));
+        } else if section == "package" {
+            match key {
+                "name" => manifest.name = Some(unquote(value).to_string()),
+                "version" => manifest.version = Some(unquote(value).to_string()),
+                _ => {}
+            }
+        } else if let Some(scope) = cargo_scope(&section) {
+            let (name, fields) = match key.split_once('.') {
+                // `foo.workspace = true` and friends.
+                Some((name, field)) => (name, vec![(field.to_string(), value.to_string())]),
+                None if value.starts_with('{') => (key, inline_table(value)),
+                None => (key, vec![("version".to_string(), value.to_string())]),
+            };
+            manifest
+                .dependencies
+                .push(cargo_dependency(unquote(name), &fields, scope));
+        }
+    }
+    if let Some((key, fields, scope)) = table_dep {
+        manifest
+            .dependencies
+            .push(cargo_dependency(&key, &fields, scope));
+    }
+    Some(manifest)
+}
+
+fn cargo_scope(section: &str) -> Option<DependencyScope> {
+    let table = section.rsplit('.').next().unwrap_or(section);
+    match table {
+        "dependencies" => Some(DependencyScope::Runtime),
+        "dev-dependencies" | "dev_dependencies" => Some(DependencyScope::Dev),
+        "build-dependencies" | "build_dependencies" => Some(DependencyScope::Build),
+        _ => None,
+    }
+}
+
+fn cargo_dependency(
+    key: &str,
+    fields: &[(String, String)],
+    scope: DependencyScope,
+) -> DeclaredDependency {
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| unquote(value).to_string())
+    };
+    let scope = if scope == DependencyScope::Runtime && field("optional").as_deref() == Some("true")
+    {
+        DependencyScope::Optional
+    } else {
+        scope
+    };
+    DeclaredDependency {
+        // `package = "..."` renames the crate behind the key.
+        name: field("package").unwrap_or_else(|| key.to_string()),
+        version: field("version"),
+        scope,
+    }
+}
+
+/// Top-level `key = value` pairs of a single-line inline table; arrays are kept whole.
+fn inline_table(value: &str) -> Fields {
+    let body = value.trim().trim_start_matches('{').trim_end_matches('}');
+    let mut pairs = Vec::new();
+    let (mut depth, mut in_string, mut start) = (0usize, false, 0usize);
+    let mut push = |part: &str| {
+        if let Some((key, value)) = part.split_once('=') {
+            pairs.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    };
+    for (index, ch) in body.char_indices() {
+        match ch {
+            '"' | '\'' => in_string = !in_string,
+            '[' | '{' if !in_string => depth += 1,
+            ']' | '}' if !in_string => depth = depth.saturating_sub(1),
+            ',' if !in_string && depth == 0 => {
+                push(&body[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    push(&body[start..]);
+    pairs
+}
+
+fn strip_toml_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (index, ch) in line.char_indices() {
+        match ch {
+            '"' | '\'' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches(|c| c == '"' || c == '\'')
+}
+
+pub fn parse_package_json(content: &str) -> Option<DependencyManifest> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let text = |key: &str| json.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    let mut dependencies = Vec::new();
+    for (key, scope) in [
+        ("dependencies", DependencyScope::Runtime),
+        ("devDependencies", DependencyScope::Dev),
+        ("optionalDependencies", DependencyScope::Optional),
+    ] {
+        let Some(table) = json.get(key).and_then(|v| v.as_object()) else {
+            continue;
+        };
+        dependencies.extend(table.iter().map(|(name, version)| DeclaredDependency {
+            name: name.clone(),
+            version: version.as_str().map(str::to_string),
+            scope,
+        }));
+    }
+    Some(DependencyManifest {
+        path: String::new(),
+        ecosystem: Ecosystem::Npm,
+        name: text("name"),
+        version: text("version"),
+        dependencies,
+    })
+}
+
+/// `module` and `require` directives, single-line and block form; `// indirect` marks
+/// transitive requirements.
+pub fn parse_go_mod(content: &str) -> Option<DependencyManifest> {
+    let mut manifest = DependencyManifest {
+        path: String::new(),
+        ecosystem: Ecosystem::Go,
+        name: None,
+        version: None,
+        dependencies: Vec::new(),
+    };
+    let mut in_require = false;
+    for raw in content.lines() {
+        let (code, comment) = raw.split_once("//").unwrap_or((raw, ""));
+        let code = code.trim();
+        if in_require {
+            if code == ")" {
+                in_require = false;
+                continue;
+            }
+        } else if let Some(module) = code.strip_prefix("module ") {
+            manifest.name = Some(unquote(module).to_string());
+            continue;
+        } else if code == "require (" {
+            in_require = true;
+            continue;
+        }
+        let requirement = if in_require {
+            code
+        } else if let Some(rest) = code.strip_prefix("require ") {
+            rest
+        } else {
+            continue;
+        };
+        let mut parts = requirement.split_whitespace();
+        let (Some(name), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        manifest.dependencies.push(DeclaredDependency {
+            name: unquote(name).to_string(),
+            version: Some(version.to_string()),
+            scope: if comment.trim() == "indirect" {
+                DependencyScope::Indirect
+            } else {
+                DependencyScope::Runtime
+            },
+        });
+    }
+    Some(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn dep(name: &str, version: Option<&str>, scope: DependencyScope) -> DeclaredDependency {
+        DeclaredDependency {
+            name: name.to_string(),
+            version: version.map(str::to_string),
+            scope,
+        }
+    }
+
+    #[test]
+    fn test_parse_cargo_manifest() {
+        let content = r#"
+[package]
+name = "demo"   # the crate
+version = "0.3.1"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive", "rc"] }
+anyhow = "1"
+local = { path = "../local" }
+eframe = { version = "0.29", optional = true }
+shared.workspace = true
+renamed = { package = "real-name", version = "=2.0.0" }
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[dev-dependencies.tempfile]
+version = "3"
+
+[build-dependencies]
+cc = "1.0"
+
+[features]
+gui = ["eframe"]
+"#;
+        let manifest = parse_cargo(content).unwrap();
+        assert_eq!(manifest.name.as_deref(), Some("demo"));
+        assert_eq!(manifest.version.as_deref(), Some("0.3.1"));
+        assert_eq!(
+            manifest.dependencies,
+            vec![
+                dep("serde", Some("1.0"), DependencyScope::Runtime),
+                dep("anyhow", Some("1"), DependencyScope::Runtime),
+                dep("local", None, DependencyScope::Runtime),
+                dep("eframe", Some("0.29"), DependencyScope::Optional),
+                dep("shared", None, DependencyScope::Runtime),
+                dep("real-name", Some("=2.0.0"), DependencyScope::Runtime),
+                dep("libc", Some("0.2"), DependencyScope::Runtime),
+                dep("tempfile", Some("3"), DependencyScope::Dev),
+                dep("cc", Some("1.0"), DependencyScope::Build),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_package_json_and_go_mod() {
+        let package = r#"{"name": "web", "version": "1.0.0",
+            "dependencies": {"express": "^4.18.2"},
+            "devDependencies": {"jest": "~29.0.0"}}"#;
+        let manifest = parse_package_json(package).unwrap();
+        assert_eq!(manifest.name.as_deref(), Some("web"));
+        assert_eq!(
+            manifest.dependencies,
+            vec![
+                dep("express", Some("^4.18.2"), DependencyScope::Runtime),
+                dep("jest", Some("~29.0.0"), DependencyScope::Dev),
+            ]
+        );
+        assert!(parse_package_json("{ not json").is_none());
+
+        let go_mod = "module example.com/svc\n\ngo 1.22\n\nrequire github.com/pkg/errors v0.9.1\n\
+                      require (\n\tgolang.org/x/sys v0.20.0 // indirect\n\tgithub.com/gin-gonic/gin v1.9.1\n)\n";
+        let manifest = parse_go_mod(go_mod).unwrap();
+        assert_eq!(manifest.name.as_deref(), Some("example.com/svc"));
+        assert_eq!(
+            manifest.dependencies,
+            vec![
+                dep(
+                    "github.com/pkg/errors",
+                    Some("v0.9.1"),
+                    DependencyScope::Runtime
+                ),
+                dep(
+                    "golang.org/x/sys",
+                    Some("v0.20.0"),
+                    DependencyScope::Indirect
+                ),
+                dep(
+                    "github.com/gin-gonic/gin",
+                    Some("v1.9.1"),
+                    DependencyScope::Runtime
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_skips_dependency_trees() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"root\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("web/node_modules/left-pad")).unwrap();
+        fs::write(dir.path().join("web/package.json"), r#"{"name": "web"}"#).unwrap();
+        fs::write(
+            dir.path().join("web/node_modules/left-pad/package.json"),
+            r#"{"name": "left-pad"}"#,
+        )
+        .unwrap();
+
+        let manifests = collect(dir.path());
+        let paths: Vec<&str> = manifests.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["Cargo.toml", "web/package.json"]);
+        assert_eq!(
+            collect(&dir.path().join("Cargo.toml"))[0].path,
+            "Cargo.toml"
+        );
+    }
+}
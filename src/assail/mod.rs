@@ -4,31 +4,59 @@
 //!
 //! Pre-analyzes target programs to identify weak points and recommend attacks
 
+pub mod advisories;
 pub mod analyzer;
+pub mod annotations;
+pub mod external;
+pub mod manifests;
+pub mod owners;
 pub mod patterns;
+pub mod policy;
+pub mod revision;
+pub mod watch;
 
+use crate::error::AssailError;
 use crate::kanren::core::LogicEngine;
 use crate::kanren::crosslang::CrossLangAnalyzer;
 use crate::kanren::strategy::{self, SearchStrategy};
 use crate::kanren::taint::TaintAnalyzer;
 use crate::types::*;
 use anyhow::Result;
+use std::fs;
 use std::path::Path;
 
 pub use analyzer::Analyzer;
+pub use external::ExternalAnalyzer;
 
 /// Run Assail analysis on a target program
-pub fn analyze<P: AsRef<Path>>(target: P) -> Result<AssailReport> {
+pub fn analyze<P: AsRef<Path>>(target: P) -> Result<AssailReport, AssailError> {
     // Non-verbose mode keeps stdout clean for automation pipelines.
+    check_target(target.as_ref())?;
     let analyzer = Analyzer::new(target.as_ref())?;
-    analyzer.analyze()
+    Ok(analyzer.analyze()?)
 }
 
 /// Run Assail analysis with verbose output including per-file breakdown
 /// and miniKanren logic engine results
-pub fn analyze_verbose<P: AsRef<Path>>(target: P) -> Result<AssailReport> {
+pub fn analyze_verbose<P: AsRef<Path>>(target: P) -> Result<AssailReport, AssailError> {
+    check_target(target.as_ref())?;
+    analyze_verbose_with(&Analyzer::new(target.as_ref())?)
+}
+
+/// Fail with a typed error when `target` cannot be read.
+pub fn check_target(target: &Path) -> Result<(), AssailError> {
+    let metadata = fs::metadata(target).map_err(|err| AssailError::from_io(target, err))?;
+    let readable = if metadata.is_dir() {
+        fs::read_dir(target).map(drop)
+    } else {
+        fs::File::open(target).map(drop)
+    };
+    readable.map_err(|err| AssailError::from_io(target, err))
+}
+
+/// Verbose analysis with a preconfigured analyzer (e.g. one with external analyzers attached)
+pub fn analyze_verbose_with(analyzer: &Analyzer) -> Result<AssailReport, AssailError> {
     // Verbose mode is operator-facing and intentionally prints prioritization context.
-    let analyzer = Analyzer::new_verbose(target.as_ref())?;
     let report = analyzer.analyze()?;
 
     println!("Assail Analysis Complete");
@@ -0,0 +1,364 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Finding ownership from CODEOWNERS, with git blame as a fallback
+//!
+//! The repository's CODEOWNERS file (`.github/`, the root or `docs/`, the
+//! first one found, as GitHub does) is matched against each finding's path
+//! with gitignore-style patterns; the last matching rule wins and a rule with
+//! no owners leaves the path unowned. With blame enabled, paths no rule
+//! covers fall back to the author of the flagged line, or the author of most
+//! of the file when the finding has no line. Crash buckets are owned by the
+//! file of their crashing frame.
+
+use crate::attack::explore::crash_bucket;
+use crate::report::sarif::parse_location;
+use crate::signatures::backtrace;
+use crate::types::*;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const CODEOWNERS_PATHS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Parsed CODEOWNERS rules, in file order.
+#[derive(Debug, Clone, Default)]
+pub struct Codeowners {
+    rules: Vec<(Regex, Vec<String>)>,
+}
+
+impl Codeowners {
+    /// Parse CODEOWNERS text; lines whose pattern cannot be translated are skipped.
+    pub fn parse(content: &str) -> Self {
+        let mut rules = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let Some(pattern) = fields.next() else {
+                continue;
+            };
+            let owners = fields
+                .take_while(|field| !field.starts_with('#'))
+                .map(str::to_string)
+                .collect();
+            match pattern_regex(pattern) {
+                Some(regex) => rules.push((regex, owners)),
+                None => tracing::warn!("ignoring CODEOWNERS pattern `{}`", pattern),
+            }
+        }
+        Self { rules }
+    }
+
+    /// Owners of a repository-relative path, space-separated; `None` when no rule matches
+    /// or the last matching rule lists nobody.
+    pub fn owner_of(&self, path: &str) -> Option<String> {
+        let path = path.trim_start_matches("./");
+        let (_, owners) = self
+            .rules
+            .iter()
+            .rev()
+            .find(|(regex, _)| regex.is_match(path))?;
+        (!owners.is_empty()).then(|| owners.join(" "))
+    }
+}
+
+/// Translate a gitignore-style CODEOWNERS pattern into an anchored regex. A pattern
+/// without an inner `/` matches at any depth; a match on a directory covers everything
+/// below it.
+fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let anchored = pattern.trim_end_matches('/').contains('/');
+    let body = pattern.trim_start_matches('/').trim_end_matches('/');
+    if body.is_empty() {
+        return None;
+    }
+    let mut regex = String::from(if anchored { "^" } else { "^(.*/)?" });
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push_str("(/.*)?$");
+    Regex::new(&regex).ok()
+}
+
+/// Resolves owners for findings under one scan root.
+pub struct Ownership {
+    /// Repository root: the closest ancestor of the scan root holding `.git`.
+    root: PathBuf,
+    /// Scan root relative to `root`; finding locations are relative to the scan root.
+    prefix: PathBuf,
+    codeowners: Option<Codeowners>,
+    blame: bool,
+    /// Author of each line, per repository-relative file, from `git blame`.
+    blamed: HashMap<PathBuf, Vec<Option<String>>>,
+}
+
+impl Ownership {
+    /// Find the repository around `scan_root` and load its CODEOWNERS, if any.
+    pub fn discover(scan_root: &Path) -> Result<Self> {
+        let scan_root = scan_root
+            .canonicalize()
+            .unwrap_or_else(|_| scan_root.to_path_buf());
+        let root = scan_root
+            .ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .unwrap_or(&scan_root)
+            .to_path_buf();
+        let prefix = scan_root
+            .strip_prefix(&root)
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let codeowners = match CODEOWNERS_PATHS
+            .iter()
+            .map(|path| root.join(path))
+            .find(|path| path.is_file())
+        {
+            Some(path) => {
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?;
+                Some(Codeowners::parse(&content))
+            }
+            None => None,
+        };
+        Ok(Self {
+            root,
+            prefix,
+            codeowners,
+            blame: false,
+            blamed: HashMap::new(),
+        })
+    }
+
+    /// Fall back to `git blame` for paths CODEOWNERS does not cover.
+    pub fn with_blame(mut self, blame: bool) -> Self {
+        self.blame = blame;
+        self
+    }
+
+    /// Set `owner` on every weak point whose file has one.
+    pub fn apply(&mut self, weak_points: &mut [WeakPoint]) {
+        for wp in weak_points.iter_mut() {
+            if let Some(location) = &wp.location {
+                let (path, line) = parse_location(location);
+                let relative = self.prefix.join(path);
+                wp.owner = self.owner_of(&relative, line);
+            }
+        }
+    }
+
+    /// Owner of each crash bucket, from the file of its crashing frame. Frame paths may be
+    /// absolute or relative to the repository root.
+    pub fn crash_owners(&mut self, results: &[AttackResult]) -> BTreeMap<String, String> {
+        let mut owners = BTreeMap::new();
+        for result in results {
+            let Some(bucket) = crash_bucket(result) else {
+                continue;
+            };
+            let Some((file, line)) = result.crashes.iter().find_map(|crash| {
+                let frame = backtrace::top_frame(&crash.frames)?;
+                Some((frame.file.clone()?, frame.line))
+            }) else {
+                continue;
+            };
+            let path = Path::new(&file);
+            let relative = if path.is_absolute() {
+                match path.strip_prefix(&self.root) {
+                    Ok(relative) => relative.to_path_buf(),
+                    Err(_) => continue,
+                }
+            } else {
+                path.strip_prefix("./").unwrap_or(path).to_path_buf()
+            };
+            if let Some(owner) = self.owner_of(&relative, line) {
+                owners.insert(bucket, owner);
+            }
+        }
+        owners
+    }
+
+    fn owner_of(&mut self, relative: &Path, line: Option<u32>) -> Option<String> {
+        let codeowners_path = relative.to_string_lossy().replace('\\', "/");
+        if let Some(owner) = self
+            .codeowners
+            .as_ref()
+            .and_then(|codeowners| codeowners.owner_of(&codeowners_path))
+        {
+            return Some(owner);
+        }
+        if !self.blame {
+            return None;
+        }
+        let root = self.root.clone();
+        let authors = self
+            .blamed
+            .entry(relative.to_path_buf())
+            .or_insert_with(|| blame(&root, relative));
+        match line {
+            Some(line) => authors
+                .get(line.checked_sub(1)? as usize)
+                .cloned()
+                .flatten(),
+            None => most_lines(authors),
+        }
+    }
+}
+
+/// Author e-mail of every line of `relative`, or nothing when git cannot blame it.
+fn blame(root: &Path, relative: &Path) -> Vec<Option<String>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["blame", "--line-porcelain", "--"])
+        .arg(relative)
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            parse_blame(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Line authors from `git blame --line-porcelain`; uncommitted lines have none.
+fn parse_blame(porcelain: &str) -> Vec<Option<String>> {
+    porcelain
+        .lines()
+        .filter_map(|line| line.strip_prefix("author-mail "))
+        .map(|mail| {
+            let mail = mail.trim().trim_start_matches('<').trim_end_matches('>');
+            (mail != "not.committed.yet").then(|| mail.to_string())
+        })
+        .collect()
+}
+
+fn most_lines(authors: &[Option<String>]) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for author in authors.iter().flatten() {
+        *counts.entry(author).or_default() += 1;
+    }
+    let (author, _) = counts
+        .into_iter()
+        .max_by_key(|(author, count)| (*count, std::cmp::Reverse(*author)))?;
+    Some(author.to_string())
+}
+
+/// Whether a space-separated owner list includes `wanted` (case-insensitive).
+pub fn is_owned_by(owner: Option<&str>, wanted: &str) -> bool {
+    owner.is_some_and(|owner| {
+        owner
+            .split_whitespace()
+            .any(|candidate| candidate.eq_ignore_ascii_case(wanted))
+    })
+}
+
+/// Narrow a report to what `owner` owns: their weak points and crashes. Attack results
+/// without crashes stay, since they belong to nobody.
+pub fn slice(report: &mut AssaultReport, owner: &str) {
+    report
+        .assail_report
+        .weak_points
+        .retain(|wp| is_owned_by(wp.owner.as_deref(), owner));
+    let crash_owners = &report.crash_owners;
+    report
+        .attack_results
+        .retain(|result| match crash_bucket(result) {
+            Some(bucket) => is_owned_by(crash_owners.get(&bucket).map(String::as_str), owner),
+            None => true,
+        });
+    report.total_crashes = report
+        .attack_results
+        .iter()
+        .map(|result| result.crashes.len())
+        .sum();
+    report.total_signatures = report
+        .attack_results
+        .iter()
+        .map(|result| result.signatures_detected.len())
+        .sum();
+    report
+        .notes
+        .push(format!("Sliced to findings owned by {}", owner));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODEOWNERS: &str = "\
+# Default owners
+*                 @core
+*.py              @data-team
+/src/net/         @backend-team @alice   # networking
+docs/**/*.md      @docs
+src/net/vendor/
+";
+
+    #[test]
+    fn test_last_matching_rule_wins() {
+        let codeowners = Codeowners::parse(CODEOWNERS);
+        assert_eq!(codeowners.owner_of("README").as_deref(), Some("@core"));
+        assert_eq!(
+            codeowners.owner_of("tools/gen/run.py").as_deref(),
+            Some("@data-team")
+        );
+        assert_eq!(
+            codeowners.owner_of("./src/net/tcp.rs").as_deref(),
+            Some("@backend-team @alice")
+        );
+        assert_eq!(
+            codeowners.owner_of("docs/guide/a/b.md").as_deref(),
+            Some("@docs")
+        );
+        // Anchored: a nested src/net is not the root one.
+        assert_eq!(
+            codeowners.owner_of("lib/src/net/x.rs").as_deref(),
+            Some("@core")
+        );
+        // A rule without owners leaves the subtree unowned.
+        assert_eq!(codeowners.owner_of("src/net/vendor/zlib.c"), None);
+    }
+
+    #[test]
+    fn test_blame_porcelain_and_owner_matching() {
+        let porcelain = "\
+abc 1 1 1
+author Alice
+author-mail <alice@example.com>
+\tfn main() {
+abc 2 2 1
+author-mail <bob@example.com>
+\t    run();
+000 3 3 1
+author-mail <not.committed.yet>
+\t}
+def 4 4 1
+author-mail <alice@example.com>
+\t
+";
+        let authors = parse_blame(porcelain);
+        assert_eq!(authors.len(), 4);
+        assert_eq!(authors[1].as_deref(), Some("bob@example.com"));
+        assert_eq!(authors[2], None);
+        assert_eq!(most_lines(&authors).as_deref(), Some("alice@example.com"));
+
+        assert!(is_owned_by(Some("@backend-team @alice"), "@Alice"));
+        assert!(!is_owned_by(Some("@backend-team"), "@backend"));
+        assert!(!is_owned_by(None, "@core"));
+    }
+}
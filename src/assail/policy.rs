@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Per-directory analysis policies
+//!
+//! A `panic-attack.policy.yaml` in any directory under the scan root adjusts
+//! findings for that subtree, so test fixtures and examples stop dominating
+//! weak-point counts:
+//!
+//! ```yaml
+//! exclude_categories: [PanicPath, UncheckedError]
+//! severity_reduction: 1      # lower every remaining finding one step
+//! max_severity: Medium       # then cap
+//! severity:                  # per-category severity, instead of the reduction
+//!   UnsafeCode: High
+//! ```
+//!
+//! Policies merge from the scan root down to the finding's directory:
+//! excluded categories accumulate, every other setting is taken from the
+//! closest policy that sets it.
+
+use crate::types::*;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+pub const POLICY_FILE: &str = "panic-attack.policy.yaml";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    #[serde(default)]
+    pub exclude_categories: Vec<WeakPointCategory>,
+    #[serde(default)]
+    pub severity_reduction: Option<u8>,
+    #[serde(default)]
+    pub max_severity: Option<Severity>,
+    #[serde(default)]
+    pub severity: BTreeMap<String, Severity>,
+}
+
+impl Policy {
+    /// Overlay a deeper policy onto this one.
+    fn merge(&mut self, child: &Policy) {
+        for category in &child.exclude_categories {
+            if !self.exclude_categories.contains(category) {
+                self.exclude_categories.push(*category);
+            }
+        }
+        if child.severity_reduction.is_some() {
+            self.severity_reduction = child.severity_reduction;
+        }
+        if child.max_severity.is_some() {
+            self.max_severity = child.max_severity;
+        }
+        for (category, severity) in &child.severity {
+            self.severity.insert(category.clone(), *severity);
+        }
+    }
+
+    /// New severity for a weak point, or `None` when the policy excludes it.
+    fn judge(&self, wp: &WeakPoint) -> Option<Severity> {
+        if self.exclude_categories.contains(&wp.category) {
+            return None;
+        }
+        let mut severity = match self.severity.get(&format!("{:?}", wp.category)) {
+            Some(severity) => *severity,
+            None => (0..self.severity_reduction.unwrap_or(0)).fold(wp.severity, |s, _| lower(s)),
+        };
+        if let Some(cap) = self.max_severity {
+            severity = severity.min(cap);
+        }
+        Some(severity)
+    }
+}
+
+/// Policy files found under the scan root, loaded on demand.
+pub struct PolicySet {
+    root: PathBuf,
+    loaded: HashMap<PathBuf, Option<Policy>>,
+}
+
+impl PolicySet {
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            loaded: HashMap::new(),
+        }
+    }
+
+    /// Apply the effective policy of each weak point's directory, dropping excluded
+    /// findings and adjusting severities. Returns what each policy file changed.
+    pub fn apply(&mut self, weak_points: &mut Vec<WeakPoint>) -> Result<Vec<AppliedPolicy>> {
+        // Resolve every policy before touching the findings so a bad policy file loses nothing.
+        let verdicts = weak_points
+            .iter()
+            .map(|wp| match &wp.location {
+                Some(location) => self.effective(Path::new(location)),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut applied: BTreeMap<String, AppliedPolicy> = BTreeMap::new();
+        let mut kept = Vec::with_capacity(weak_points.len());
+        for (mut wp, verdict) in weak_points.drain(..).zip(verdicts) {
+            let Some((policy, nearest)) = verdict else {
+                kept.push(wp);
+                continue;
+            };
+            let entry = applied
+                .entry(nearest.clone())
+                .or_insert_with(|| AppliedPolicy {
+                    path: nearest,
+                    excluded: 0,
+                    adjusted: 0,
+                });
+            match policy.judge(&wp) {
+                None => entry.excluded += 1,
+                Some(severity) => {
+                    if severity != wp.severity {
+                        entry.adjusted += 1;
+                        wp.severity = severity;
+                    }
+                    kept.push(wp);
+                }
+            }
+        }
+
+        *weak_points = kept;
+        Ok(applied.into_values().collect())
+    }
+
+    /// Merged policy for a file path relative to the root, with the closest policy file.
+    fn effective(&mut self, relative: &Path) -> Result<Option<(Policy, String)>> {
+        let dir = relative.parent().unwrap_or(Path::new(""));
+        let mut chain: Vec<&Path> = dir.ancestors().collect();
+        chain.reverse();
+
+        let mut merged: Option<(Policy, String)> = None;
+        for ancestor in chain {
+            if let Some(policy) = self.load(ancestor)? {
+                let path = ancestor.join(POLICY_FILE).to_string_lossy().to_string();
+                merged = Some(match merged {
+                    Some((mut base, _)) => {
+                        base.merge(&policy);
+                        (base, path)
+                    }
+                    None => (policy, path),
+                });
+            }
+        }
+        Ok(merged)
+    }
+
+    fn load(&mut self, dir: &Path) -> Result<Option<Policy>> {
+        if let Some(cached) = self.loaded.get(dir) {
+            return Ok(cached.clone());
+        }
+        let path = self.root.join(dir).join(POLICY_FILE);
+        let policy = if path.is_file() {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("reading policy {}", path.display()))?;
+            let policy: Policy = serde_yaml::from_str(&content)
+                .with_context(|| format!("parsing policy {}", path.display()))?;
+            for category in policy.severity.keys() {
+                serde_json::from_value::<WeakPointCategory>(serde_json::Value::String(
+                    category.clone(),
+                ))
+                .with_context(|| {
+                    format!(
+                        "unknown category `{}` in policy {}",
+                        category,
+                        path.display()
+                    )
+                })?;
+            }
+            Some(policy)
+        } else {
+            None
+        };
+        self.loaded.insert(dir.to_path_buf(), policy.clone());
+        Ok(policy)
+    }
+}
+
+fn lower(severity: Severity) -> Severity {
+    match severity {
+        Severity::Critical => Severity::High,
+        Severity::High => Severity::Medium,
+        Severity::Medium | Severity::Low => Severity::Low,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn finding(location: &str, category: WeakPointCategory, severity: Severity) -> WeakPoint {
+        WeakPoint {
+            category,
+            location: Some(location.to_string()),
+            severity,
+            description: String::new(),
+            recommended_attack: Vec::new(),
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn test_policies_merge_from_root_to_leaf() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("tests/fixtures")).unwrap();
+        std::fs::write(
+            dir.path().join("tests").join(POLICY_FILE),
+            "exclude_categories: [PanicPath]\nseverity_reduction: 1\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("tests/fixtures").join(POLICY_FILE),
+            "exclude_categories: [UncheckedError]\nmax_severity: Low\nseverity:\n  UnsafeCode: Medium\n",
+        )
+        .unwrap();
+
+        let mut weak_points = vec![
+            finding("src/lib.rs", WeakPointCategory::PanicPath, Severity::High),
+            finding("tests/it.rs", WeakPointCategory::PanicPath, Severity::High),
+            finding(
+                "tests/it.rs",
+                WeakPointCategory::UnsafeCode,
+                Severity::Critical,
+            ),
+            finding(
+                "tests/fixtures/a.rs",
+                WeakPointCategory::UncheckedError,
+                Severity::Medium,
+            ),
+            finding(
+                "tests/fixtures/a.rs",
+                WeakPointCategory::UnsafeCode,
+                Severity::Critical,
+            ),
+        ];
+        let applied = PolicySet::new(dir.path()).apply(&mut weak_points).unwrap();
+
+        let severities: Vec<(&str, Severity)> = weak_points
+            .iter()
+            .map(|wp| (wp.location.as_deref().unwrap(), wp.severity))
+            .collect();
+        assert_eq!(
+            severities,
+            vec![
+                ("src/lib.rs", Severity::High),
+                ("tests/it.rs", Severity::High),
+                // The per-category override wins over the reduction, then the cap applies.
+                ("tests/fixtures/a.rs", Severity::Low),
+            ]
+        );
+        assert_eq!(applied.len(), 2);
+        assert_eq!(applied[0].path, "tests/fixtures/panic-attack.policy.yaml");
+        assert_eq!((applied[0].excluded, applied[0].adjusted), (1, 1));
+        assert_eq!((applied[1].excluded, applied[1].adjusted), (1, 1));
+    }
+
+    #[test]
+    fn test_policy_rejects_unknown_fields_and_categories() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(POLICY_FILE), "exclude: [PanicPath]\n").unwrap();
+        let mut weak_points = vec![finding("a.rs", WeakPointCategory::PanicPath, Severity::Low)];
+        assert!(PolicySet::new(dir.path()).apply(&mut weak_points).is_err());
+        assert_eq!(weak_points.len(), 1);
+
+        std::fs::write(dir.path().join(POLICY_FILE), "severity:\n  Panics: Low\n").unwrap();
+        assert!(PolicySet::new(dir.path()).apply(&mut weak_points).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Source trees for `assail-diff`: a directory as-is, or a git revision exported to a
+//! temporary directory with `git archive`, so the working tree and index are never touched.
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// A tree to scan. Exported revisions are deleted when this is dropped.
+pub struct SourceTree {
+    pub path: PathBuf,
+    pub label: String,
+    exported: bool,
+}
+
+impl SourceTree {
+    /// `spec` names a directory if one exists at that path, otherwise a revision of `repo`.
+    pub fn resolve(spec: &str, repo: &Path) -> Result<Self> {
+        let path = Path::new(spec);
+        if path.is_dir() {
+            return Ok(Self {
+                path: path.to_path_buf(),
+                label: spec.to_string(),
+                exported: false,
+            });
+        }
+        Self::export(repo, spec)
+    }
+
+    fn export(repo: &Path, rev: &str) -> Result<Self> {
+        let slug: String = rev
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let tree = Self {
+            path: std::env::temp_dir().join(format!(
+                "panic-attack-assail-diff-{}-{}",
+                std::process::id(),
+                slug
+            )),
+            label: rev.to_string(),
+            exported: true,
+        };
+        fs::create_dir_all(&tree.path)
+            .with_context(|| format!("creating {}", tree.path.display()))?;
+
+        let mut archive = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(["archive", "--format=tar", rev])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("running git archive")?;
+        let stream = archive
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("git archive produced no output stream"))?;
+        let unpacked = Command::new("tar")
+            .args(["-x", "-f", "-", "-C"])
+            .arg(&tree.path)
+            .stdin(stream)
+            .output()
+            .context("running tar")?;
+        let archived = archive.wait_with_output()?;
+        if !archived.status.success() {
+            let stderr = String::from_utf8_lossy(&archived.stderr);
+            return Err(anyhow!(
+                "'{}' is neither a directory nor a revision of {}: {}",
+                rev,
+                repo.display(),
+                stderr.lines().next().unwrap_or("git archive failed").trim()
+            ));
+        }
+        if !unpacked.status.success() {
+            return Err(anyhow!(
+                "unpacking {}: {}",
+                rev,
+                String::from_utf8_lossy(&unpacked.stderr).trim()
+            ));
+        }
+        Ok(tree)
+    }
+}
+
+impl Drop for SourceTree {
+    fn drop(&mut self) {
+        if self.exported {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_resolve_directory_and_revision() {
+        let dir = TempDir::new().unwrap();
+        let tree = SourceTree::resolve(dir.path().to_str().unwrap(), dir.path()).unwrap();
+        assert_eq!(tree.path, dir.path());
+        drop(tree);
+        assert!(dir.path().exists(), "directories are never removed");
+
+        if !git(dir.path(), &["init", "-q"]) {
+            return; // git unavailable
+        }
+        fs::write(dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+        assert!(git(dir.path(), &["add", "."]));
+        assert!(git(
+            dir.path(),
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@t",
+                "commit",
+                "-qm",
+                "init"
+            ]
+        ));
+        fs::write(dir.path().join("main.rs"), "changed\n").unwrap();
+
+        let tree = SourceTree::resolve("HEAD", dir.path()).unwrap();
+        let exported = tree.path.clone();
+        assert_eq!(
+            fs::read_to_string(exported.join("main.rs")).unwrap(),
+            "fn main() {}\n"
+        );
+        drop(tree);
+        assert!(!exported.exists());
+
+        assert!(SourceTree::resolve("no-such-rev", dir.path()).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! `assail --watch`: rescan the target whenever a file under it changes.
+//!
+//! Changes are found by polling file sizes and mtimes, which needs no platform watcher and
+//! at the default interval reports a save well inside a second. Rescans go through
+//! [`Analyzer::analyze_cached`], so only the files that changed are read again.
+
+use super::analyzer::{Analyzer, FileCache};
+use super::manifests::SKIP_DIRS;
+use crate::types::AssailReport;
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the tree is polled.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(250);
+
+type Snapshot = BTreeMap<PathBuf, (u64, Option<SystemTime>)>;
+
+pub struct WatchSession {
+    analyzer: Analyzer,
+    cache: FileCache,
+    snapshot: Snapshot,
+}
+
+/// A rescan triggered by a change on disk.
+pub struct Rescan {
+    pub report: AssailReport,
+    /// Files created, modified or deleted since the previous scan, relative to the target.
+    pub changed: Vec<String>,
+    /// Source files read again rather than taken from the cache.
+    pub reanalyzed: usize,
+    pub elapsed: Duration,
+}
+
+impl WatchSession {
+    /// Snapshot the tree and run the initial full analysis.
+    pub fn start(analyzer: Analyzer) -> Result<(Self, AssailReport)> {
+        let mut cache = FileCache::new();
+        let snapshot = snapshot(analyzer.target());
+        let report = analyzer.analyze_cached(&mut cache)?;
+        Ok((
+            Self {
+                analyzer,
+                cache,
+                snapshot,
+            },
+            report,
+        ))
+    }
+
+    /// Rescan if anything changed since the last call; `None` when the tree is unchanged.
+    pub fn poll(&mut self) -> Result<Option<Rescan>> {
+        let current = snapshot(self.analyzer.target());
+        if current == self.snapshot {
+            return Ok(None);
+        }
+        let started = Instant::now();
+        let target = self.analyzer.target();
+        let mut changed: Vec<String> = current
+            .iter()
+            .filter(|(path, stamp)| self.snapshot.get(*path) != Some(stamp))
+            .map(|(path, _)| path)
+            .chain(
+                self.snapshot
+                    .keys()
+                    .filter(|path| !current.contains_key(*path)),
+            )
+            .map(|path| relative(target, path))
+            .collect();
+        changed.sort();
+        self.snapshot = current;
+
+        let report = self.analyzer.analyze_cached(&mut self.cache)?;
+        Ok(Some(Rescan {
+            report,
+            changed,
+            reanalyzed: self.cache.reanalyzed().len(),
+            elapsed: started.elapsed(),
+        }))
+    }
+}
+
+fn relative(target: &Path, path: &Path) -> String {
+    let base = if target.is_dir() {
+        target
+    } else {
+        target.parent().unwrap_or(Path::new("."))
+    };
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Size and mtime of every file under `target`, skipping hidden entries (editor swap files,
+/// `.git`) and build output.
+fn snapshot(target: &Path) -> Snapshot {
+    let mut files = Snapshot::new();
+    if target.is_dir() {
+        walk(target, &mut files);
+    } else {
+        stamp(target, &mut files);
+    }
+    files
+}
+
+fn walk(dir: &Path, files: &mut Snapshot) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            if !SKIP_DIRS.contains(&name.as_str()) {
+                walk(&path, files);
+            }
+        } else {
+            stamp(&path, files);
+        }
+    }
+}
+
+fn stamp(path: &Path, files: &mut Snapshot) {
+    if let Ok(meta) = fs::metadata(path) {
+        files.insert(path.to_path_buf(), (meta.len(), meta.modified().ok()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WeakPointCategory;
+    use tempfile::TempDir;
+
+    fn unsafe_points(report: &AssailReport) -> usize {
+        report
+            .weak_points
+            .iter()
+            .filter(|wp| wp.category == WeakPointCategory::UnsafeCode)
+            .count()
+    }
+
+    #[test]
+    fn test_poll_rescans_changed_files_only() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("a.rs"), "fn a() {}\n").unwrap();
+        fs::write(dir.path().join("b.rs"), "fn b() {}\n").unwrap();
+
+        let analyzer = Analyzer::new(dir.path()).unwrap();
+        let (mut session, report) = WatchSession::start(analyzer).unwrap();
+        assert_eq!(unsafe_points(&report), 0);
+        assert!(session.poll().unwrap().is_none());
+
+        fs::write(
+            dir.path().join("a.rs"),
+            "fn a() { unsafe { std::ptr::null::<u8>().read(); } }\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join(".a.rs.swp"), "editor state").unwrap();
+        let rescan = session.poll().unwrap().expect("a.rs changed");
+        assert_eq!(rescan.changed, vec!["a.rs"]);
+        assert_eq!(rescan.reanalyzed, 1, "b.rs comes from the cache");
+        assert_eq!(unsafe_points(&rescan.report), 1);
+
+        fs::remove_file(dir.path().join("a.rs")).unwrap();
+        let rescan = session.poll().unwrap().expect("a.rs removed");
+        assert_eq!(rescan.changed, vec!["a.rs"]);
+        assert_eq!(rescan.reanalyzed, 0);
+        assert_eq!(unsafe_points(&rescan.report), 0);
+    }
+}
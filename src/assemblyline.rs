@@ -10,6 +10,7 @@
 //! runs, repos whose source files haven't changed are skipped.
 
 use crate::assail;
+use crate::storage::write_atomic;
 use crate::types::AssailReport;
 use anyhow::Result;
 use rayon::prelude::*;
@@ -112,7 +113,7 @@ impl FingerprintCache {
             fs::create_dir_all(parent)?;
         }
         let json = serde_json::to_string_pretty(&cache)?;
-        fs::write(path, json)?;
+        write_atomic(path, json)?;
         Ok(())
     }
 
@@ -302,12 +303,14 @@ fn scan_repo(repo_path: &Path) -> RepoResult {
 ///
 /// Uses rayon for parallel scanning across available CPU cores.
 /// If a cache file is configured, loads BLAKE3 fingerprints to skip
-/// repos whose source files haven't changed (incremental mode).
+/// repos whose source files haven't changed (incremental mode). A saved
+/// assemblyline report works as the cache file too.
 /// After scanning, saves updated fingerprints back to the cache file.
 pub fn run(config: &AssemblylineConfig) -> Result<AssemblylineReport> {
     let cache = match &config.cache_file {
         Some(path) if path.exists() => {
             FingerprintCache::load_cache_file(path)
+                .or_else(|_| FingerprintCache::load_from_report_file(path))
                 .ok() // gracefully degrade if cache is corrupt
         }
         _ => None,
@@ -373,7 +376,7 @@ pub fn run_with_cache(
         .count();
 
     // Sort by weak point count descending (riskiest repos first)
-    results.sort_by(|a, b| b.weak_point_count.cmp(&a.weak_point_count));
+    results.sort_by_key(|r| std::cmp::Reverse(r.weak_point_count));
 
     // Apply filters
     if config.findings_only {
@@ -460,7 +463,7 @@ pub fn write_report(report: &AssemblylineReport, path: &Path) -> Result<()> {
         fs::create_dir_all(parent)?;
     }
     let json = serde_json::to_string_pretty(report)?;
-    fs::write(path, json)?;
+    write_atomic(path, json)?;
     Ok(())
 }
 
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Campaign wall-clock budget (`--campaign-timeout`)
+//!
+//! Axes run one after another, so rather than killing the campaign mid-axis and losing its
+//! report, each axis is given its planned duration or, once the time left cannot cover every
+//! remaining axis, an equal share of what is left. Axes that would get less than
+//! [`MIN_AXIS_TIME`] are skipped. Every decision is noted on the result it affected.
+
+use crate::attack::capability;
+use crate::types::{AttackAxis, AttackResult};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Shortest run worth starting once the budget runs low.
+pub const MIN_AXIS_TIME: Duration = Duration::from_secs(1);
+
+pub struct Budget {
+    limit: Duration,
+    started: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Allotment {
+    Full,
+    Trimmed { duration: Duration, note: String },
+    Exhausted { note: String },
+}
+
+impl Budget {
+    pub fn start(limit: Duration) -> Self {
+        Self {
+            limit,
+            started: Instant::now(),
+        }
+    }
+
+    /// Time for the next run, when it and `remaining_runs - 1` others still need `planned`
+    /// each. A `planned` of zero stands for runs whose length the target decides; those are
+    /// only ever skipped.
+    pub fn allot(&self, planned: Duration, remaining_runs: usize) -> Allotment {
+        self.allot_after(self.started.elapsed(), planned, remaining_runs)
+    }
+
+    fn allot_after(
+        &self,
+        elapsed: Duration,
+        planned: Duration,
+        remaining_runs: usize,
+    ) -> Allotment {
+        let left = self.limit.saturating_sub(elapsed);
+        let runs = remaining_runs.max(1);
+        // Whole seconds keep the trimmed durations readable and match the CLI's unit.
+        let share = Duration::from_secs((left / runs as u32).as_secs());
+        let situation = format!(
+            "{}s of the {}s campaign timeout left for {} run(s)",
+            left.as_secs(),
+            self.limit.as_secs(),
+            runs
+        );
+        if left < MIN_AXIS_TIME || (planned > share && share < MIN_AXIS_TIME) {
+            Allotment::Exhausted {
+                note: format!("skipped: {}", situation),
+            }
+        } else if planned <= share || planned.is_zero() {
+            Allotment::Full
+        } else {
+            Allotment::Trimmed {
+                duration: share,
+                note: format!(
+                    "trimmed from {}s to {}s: {}",
+                    planned.as_secs(),
+                    share.as_secs(),
+                    situation
+                ),
+            }
+        }
+    }
+}
+
+/// Result recorded for a run the budget no longer had time for.
+pub fn skipped_result(program: &Path, axis: AttackAxis, note: String) -> AttackResult {
+    AttackResult {
+        skip_reason: Some("campaign timeout reached".to_string()),
+        budget_note: Some(note),
+        ..capability::skipped_result(program, axis, "")
+    }
+}
+
+/// Report notes for every run the budget changed, e.g. `./app on Cpu axis: trimmed from 30s to 12s: ...`.
+pub fn notes(results: &[AttackResult]) -> Vec<String> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let note = result.budget_note.as_ref()?;
+            Some(format!(
+                "{} on {:?} axis: {}",
+                result.program.display(),
+                result.axis,
+                note
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn remaining_axes_share_what_is_left() {
+        let budget = Budget::start(SECOND * 100);
+        assert_eq!(
+            budget.allot_after(SECOND * 10, SECOND * 30, 3),
+            Allotment::Full
+        );
+
+        // 40s left for 3 axes of 30s: each gets 13s.
+        match budget.allot_after(SECOND * 60, SECOND * 30, 3) {
+            Allotment::Trimmed { duration, note } => {
+                assert_eq!(duration, SECOND * 13);
+                assert_eq!(
+                    note,
+                    "trimmed from 30s to 13s: 40s of the 100s campaign timeout left for 3 run(s)"
+                );
+            }
+            other => panic!("expected a trim, got {:?}", other),
+        }
+
+        assert!(matches!(
+            budget.allot_after(SECOND * 99, SECOND * 30, 2),
+            Allotment::Exhausted { .. }
+        ));
+        // Target-paced runs still go ahead while any time is left, and stop once none is.
+        assert_eq!(
+            budget.allot_after(SECOND * 99, Duration::ZERO, 2),
+            Allotment::Full
+        );
+        assert!(matches!(
+            budget.allot_after(SECOND * 100, Duration::ZERO, 1),
+            Allotment::Exhausted { .. }
+        ));
+    }
+
+    #[test]
+    fn skipped_runs_become_report_notes() {
+        let result = skipped_result(
+            Path::new("/bin/app"),
+            AttackAxis::Memory,
+            "skipped: 0s of the 60s campaign timeout left for 1 run(s)".to_string(),
+        );
+        assert!(result.skipped);
+        assert_eq!(
+            notes(&[result]),
+            ["/bin/app on Memory axis: skipped: 0s of the 60s campaign timeout left for 1 run(s)"]
+        );
+    }
+}
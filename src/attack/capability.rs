@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Up-front capability probe: check each requested axis against the host before running it.
+//!
+//! Help-text probing (`ProbeMode`) asks whether the *target* understands an axis's flags; this
+//! asks whether the *environment* can apply the axis at all. Axes that cannot run (no loopback
+//! sockets, a full or read-only temp directory, too little free memory, a thread limit below
+//! what the axis spawns, no signals) are skipped with the exact reason instead of failing
+//! minutes into a campaign. The checks only block on hard limits; anything an axis can degrade
+//! around (missing cgroup delegation, for instance) is left to its own report notes.
+
+use crate::attack::custom;
+use crate::types::*;
+use std::collections::HashMap;
+use std::fs;
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Free space the disk axis needs in the temp directory.
+const MIN_FREE_DISK_BYTES: u64 = 256 * 1024 * 1024;
+/// Available memory below which the memory axis would mostly provoke the host OOM killer.
+const MIN_AVAILABLE_MEMORY_BYTES: u64 = 128 * 1024 * 1024;
+/// Threads the concurrency axis starts per intensity multiplier (matches the stressors).
+const CONCURRENCY_THREADS_PER_MULTIPLIER: f64 = 50.0;
+
+/// Probe every configured axis; returns the blocked ones with the reason.
+pub fn probe_axes(config: &AttackConfig) -> HashMap<AttackAxis, String> {
+    config
+        .axes
+        .iter()
+        .filter_map(|axis| {
+            probe_axis(axis.clone(), config)
+                .err()
+                .map(|reason| (axis.clone(), reason))
+        })
+        .collect()
+}
+
+pub fn probe_axis(axis: AttackAxis, config: &AttackConfig) -> Result<(), String> {
+    match axis {
+        AttackAxis::Network => probe_loopback(),
+        AttackAxis::Disk => probe_temp_dir(&std::env::temp_dir()),
+        AttackAxis::Memory => match available_memory() {
+            Some(available) => {
+                check_minimum("available memory", available, MIN_AVAILABLE_MEMORY_BYTES)
+            }
+            None => Ok(()),
+        },
+        AttackAxis::Concurrency => {
+            let needed =
+                (CONCURRENCY_THREADS_PER_MULTIPLIER * config.intensity.multiplier()) as u64;
+            match process_limit() {
+                Some(limit) => check_process_limit(limit, needed),
+                None => Ok(()),
+            }
+        }
+        AttackAxis::Signal if !cfg!(unix) => Err("signal delivery requires a unix host".into()),
+        AttackAxis::Cpu | AttackAxis::Time | AttackAxis::Signal => Ok(()),
+        AttackAxis::Custom(_) => custom::probe(config, &axis),
+    }
+}
+
+/// Result recorded for an axis the probe blocked.
+pub fn skipped_result(program: &Path, axis: AttackAxis, reason: &str) -> AttackResult {
+    AttackResult {
+        program: program.to_path_buf(),
+        axis,
+        success: false,
+        skipped: true,
+        skip_reason: Some(format!("capability: {}", reason)),
+        duration: Duration::from_secs(0),
+        peak_memory: 0,
+        ..Default::default()
+    }
+}
+
+fn probe_loopback() -> Result<(), String> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .map_err(|err| format!("cannot bind a loopback TCP socket ({})", err))?;
+    let addr = listener
+        .local_addr()
+        .map_err(|err| format!("loopback socket has no address ({})", err))?;
+    TcpStream::connect_timeout(&addr, Duration::from_secs(1))
+        .map(|_| ())
+        .map_err(|err| format!("cannot connect to loopback {} ({})", addr, err))
+}
+
+fn probe_temp_dir(dir: &Path) -> Result<(), String> {
+    let marker: PathBuf = dir.join(format!("panic-attack-probe-{}", std::process::id()));
+    fs::write(&marker, b"probe")
+        .map_err(|err| format!("temp directory {} is not writable ({})", dir.display(), err))?;
+    let _ = fs::remove_file(&marker);
+    match free_disk_bytes(dir) {
+        Some(free) => check_minimum(
+            &format!("free space in {}", dir.display()),
+            free,
+            MIN_FREE_DISK_BYTES,
+        ),
+        None => Ok(()),
+    }
+}
+
+fn check_minimum(what: &str, available: u64, required: u64) -> Result<(), String> {
+    if available >= required {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} is {} MiB, below the {} MiB this axis needs",
+            what,
+            available / (1024 * 1024),
+            required / (1024 * 1024)
+        ))
+    }
+}
+
+fn check_process_limit(limit: u64, needed: u64) -> Result<(), String> {
+    if limit >= needed {
+        Ok(())
+    } else {
+        Err(format!(
+            "RLIMIT_NPROC is {}, below the {} threads this intensity starts",
+            limit, needed
+        ))
+    }
+}
+
+pub(crate) fn available_memory() -> Option<u64> {
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+    parse_available_memory(&meminfo)
+}
+
+fn parse_available_memory(meminfo: &str) -> Option<u64> {
+    let kib: u64 = meminfo
+        .lines()
+        .find(|line| line.starts_with("MemAvailable:"))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+// statvfs and rlimit field widths differ between unix targets, so the casts are kept.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn free_disk_bytes(dir: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::ffi::CString::new(dir.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `path` is NUL-terminated and `stat` is a valid out-pointer.
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn free_disk_bytes(_dir: &Path) -> Option<u64> {
+    None
+}
+
+/// Soft RLIMIT_NPROC, or `None` when unlimited or unknown.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+fn process_limit() -> Option<u64> {
+    let mut limit = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    // SAFETY: getrlimit only writes into `limit`.
+    if unsafe { libc::getrlimit(libc::RLIMIT_NPROC, &mut limit) } != 0 {
+        return None;
+    }
+    (limit.rlim_cur != libc::RLIM_INFINITY).then_some(limit.rlim_cur as u64)
+}
+
+#[cfg(not(unix))]
+fn process_limit() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_minimums_report_precise_reasons() {
+        assert!(check_minimum("free space", 512 << 20, MIN_FREE_DISK_BYTES).is_ok());
+        let err = check_minimum("free space in /tmp", 10 << 20, MIN_FREE_DISK_BYTES).unwrap_err();
+        assert_eq!(
+            err,
+            "free space in /tmp is 10 MiB, below the 256 MiB this axis needs"
+        );
+        let err = check_process_limit(100, 250).unwrap_err();
+        assert!(err.contains("RLIMIT_NPROC is 100"));
+        assert!(check_process_limit(4096, 250).is_ok());
+    }
+
+    #[test]
+    fn test_parse_available_memory() {
+        let meminfo = "MemTotal: 2048 kB\nMemAvailable: 1024 kB\n";
+        assert_eq!(parse_available_memory(meminfo), Some(1024 * 1024));
+        assert_eq!(parse_available_memory("MemTotal: 1 kB\n"), None);
+    }
+
+    #[test]
+    fn test_unwritable_temp_dir_is_blocked() {
+        let dir = TempDir::new().unwrap();
+        let missing = dir.path().join("does-not-exist");
+        let err = probe_temp_dir(&missing).unwrap_err();
+        assert!(err.contains("not writable"));
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Campaign checkpoints for resumable assaults
+//!
+//! An assault started with `--checkpoint DIR` rewrites `DIR/checkpoint.json` each time an
+//! axis finishes. `--resume DIR` loads it, reuses the finished axes' results instead of
+//! attacking again, runs the rest under the same seed and merges both into one report.
+
+use crate::storage::write_atomic;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const CHECKPOINT_FILE: &str = "checkpoint.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub created_at: String,
+    pub updated_at: String,
+    pub seed: u64,
+    pub programs: Vec<PathBuf>,
+    pub axes: Vec<AttackAxis>,
+    pub intensity: IntensityLevel,
+    pub duration: Duration,
+    /// Results of the (program, axis) runs finished so far, in run order.
+    #[serde(default)]
+    pub completed: Vec<AttackResult>,
+    /// Set once every axis has run.
+    #[serde(default)]
+    pub finished: bool,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl Checkpoint {
+    /// Start a new checkpoint for `config` in `dir`, refusing to overwrite one that is
+    /// still unfinished.
+    pub fn create(dir: &Path, config: &AttackConfig) -> Result<Self> {
+        let path = dir.join(CHECKPOINT_FILE);
+        if path.exists() && !Self::resume(dir).is_ok_and(|existing| existing.finished) {
+            return Err(anyhow!(
+                "{} holds an unfinished campaign; continue it with --resume {}",
+                path.display(),
+                dir.display()
+            ));
+        }
+        fs::create_dir_all(dir)
+            .with_context(|| format!("creating checkpoint directory {}", dir.display()))?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let checkpoint = Self {
+            created_at: now.clone(),
+            updated_at: now,
+            seed: config.seed,
+            programs: config.target_programs.clone(),
+            axes: config.axes.clone(),
+            intensity: config.intensity,
+            duration: config.duration,
+            completed: Vec::new(),
+            finished: false,
+            path,
+        };
+        checkpoint.save()?;
+        Ok(checkpoint)
+    }
+
+    /// Load the checkpoint kept in `dir`.
+    pub fn resume(dir: &Path) -> Result<Self> {
+        let path = dir.join(CHECKPOINT_FILE);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("reading checkpoint {}", path.display()))?;
+        let mut checkpoint: Self = serde_json::from_str(&content)
+            .with_context(|| format!("parsing checkpoint {}", path.display()))?;
+        checkpoint.path = path;
+        Ok(checkpoint)
+    }
+
+    /// Fail unless `config` describes the campaign this checkpoint was taken for.
+    pub fn check_matches(&self, config: &AttackConfig) -> Result<()> {
+        let mut differences = Vec::new();
+        if self.seed != config.seed {
+            differences.push(format!("seed {} vs {}", self.seed, config.seed));
+        }
+        if self.programs != config.target_programs {
+            differences.push("target program".to_string());
+        }
+        if self.axes != config.axes {
+            differences.push(format!("axes {:?} vs {:?}", self.axes, config.axes));
+        }
+        if self.intensity != config.intensity {
+            differences.push(format!(
+                "intensity {:?} vs {:?}",
+                self.intensity, config.intensity
+            ));
+        }
+        if self.duration != config.duration {
+            differences.push(format!(
+                "duration {}s vs {}s",
+                self.duration.as_secs(),
+                config.duration.as_secs()
+            ));
+        }
+        if differences.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "checkpoint {} belongs to a different campaign ({})",
+                self.path.display(),
+                differences.join("; ")
+            ))
+        }
+    }
+
+    pub fn completed_result(&self, program: &Path, axis: AttackAxis) -> Option<&AttackResult> {
+        self.completed
+            .iter()
+            .find(|result| result.program == program && result.axis == axis)
+    }
+
+    /// Add a finished axis run and persist the checkpoint straight away.
+    pub fn record(&mut self, result: &AttackResult) -> Result<()> {
+        self.completed.push(result.clone());
+        self.save()
+    }
+
+    /// Mark the campaign complete.
+    pub fn finish(&mut self) -> Result<()> {
+        self.finished = true;
+        self.save()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Written atomically, so an interruption mid-write leaves the previous checkpoint intact.
+    fn save(&self) -> Result<()> {
+        let mut checkpoint = self.clone();
+        checkpoint.updated_at = chrono::Utc::now().to_rfc3339();
+        write_atomic(&self.path, serde_json::to_string_pretty(&checkpoint)?)
+            .with_context(|| format!("writing checkpoint {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attack::capability;
+    use crate::types::fixtures::config;
+    use tempfile::TempDir;
+
+    #[test]
+    fn checkpoint_survives_reload_and_guards_the_campaign() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let config = config(vec![AttackAxis::Cpu, AttackAxis::Memory]);
+        let mut checkpoint = Checkpoint::create(dir.path(), &config).expect("checkpoint");
+        let program = PathBuf::from("/bin/true");
+        checkpoint
+            .record(&capability::skipped_result(
+                &program,
+                AttackAxis::Cpu,
+                "test",
+            ))
+            .expect("record");
+
+        // An interrupted campaign cannot be silently restarted.
+        assert!(Checkpoint::create(dir.path(), &config).is_err());
+
+        let mut resumed = Checkpoint::resume(dir.path()).expect("resume");
+        resumed.check_matches(&config).expect("same campaign");
+        assert!(resumed
+            .completed_result(&program, AttackAxis::Cpu)
+            .is_some());
+        assert!(resumed
+            .completed_result(&program, AttackAxis::Memory)
+            .is_none());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        let mut other = config.clone();
+        other.seed = 7;
+        other.axes = vec![AttackAxis::Cpu];
+        let err = resumed.check_matches(&other).unwrap_err().to_string();
+        assert!(err.contains("seed 42 vs 7"), "{}", err);
+        assert!(err.contains("axes"), "{}", err);
+
+        // Finished axes are replayed from the checkpoint, not attacked again.
+        resumed
+            .record(&capability::skipped_result(
+                &program,
+                AttackAxis::Memory,
+                "test",
+            ))
+            .expect("record");
+        let results = crate::attack::AttackExecutor::new(config.clone())
+            .execute_with_checkpoint(&mut resumed)
+            .expect("execute");
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|result| result.skip_reason.as_deref() == Some("capability: test")));
+        assert_eq!(resumed.completed.len(), 2);
+
+        resumed.finish().expect("finish");
+        assert!(Checkpoint::create(dir.path(), &config).is_ok());
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! User-defined attack axes
+//!
+//! A profile (or config) registers custom axes under `custom_axes`; `--axis NAME` then runs
+//! the target with the axis's `args` while its `stressor` command loads the host for the
+//! attack window. Both attack and ambush use the same registry.
+
+use crate::attack::check_target;
+use crate::error::AttackError;
+use crate::types::{AttackAxis, AttackConfig, CustomAxisSpec, IntensityLevel};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// The registered definition of `axis`, if it is a custom axis with one.
+pub fn spec<'a>(config: &'a AttackConfig, axis: &AttackAxis) -> Option<&'a CustomAxisSpec> {
+    match axis {
+        AttackAxis::Custom(name) => config.custom_axes.iter().find(|spec| spec.name == *name),
+        _ => None,
+    }
+}
+
+/// Reject malformed registrations and custom axes that have neither a registration nor
+/// `--axis-arg` arguments to run with.
+pub fn check(config: &AttackConfig) -> Result<(), AttackError> {
+    for spec in &config.custom_axes {
+        if AttackAxis::builtin(&spec.name).is_some() || !AttackAxis::valid_custom_name(&spec.name) {
+            return Err(AttackError::UnsupportedAxis(spec.name.to_string()));
+        }
+    }
+    match config.axes.iter().find(|axis| {
+        axis.is_custom() && spec(config, axis).is_none() && !config.axis_args.contains_key(axis)
+    }) {
+        Some(axis) => Err(AttackError::UnsupportedAxis(axis.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Why `axis` cannot run on this host, for the capability probe.
+pub fn probe(config: &AttackConfig, axis: &AttackAxis) -> Result<(), String> {
+    match spec(config, axis).and_then(|spec| spec.stressor.first()) {
+        Some(program) => check_target(Path::new(program))
+            .map_err(|err| format!("stressor for {} axis: {}", axis, err)),
+        None => Ok(()),
+    }
+}
+
+/// Registered arguments for `axis` followed by any `--axis-arg` ones.
+pub fn args(config: &AttackConfig, axis: &AttackAxis) -> Vec<String> {
+    let mut args = spec(config, axis)
+        .map(|spec| spec.args.clone())
+        .unwrap_or_default();
+    if let Some(extra) = config.axis_args.get(axis) {
+        args.extend(extra.iter().cloned());
+    }
+    args
+}
+
+/// Run the stressor of `spec` until `stop` is set or `deadline` passes.
+pub fn spawn_stressor(
+    spec: &CustomAxisSpec,
+    intensity: IntensityLevel,
+    duration: Duration,
+    seed: u64,
+    target_pid: Option<u32>,
+    stop: Arc<AtomicBool>,
+) -> Option<JoinHandle<()>> {
+    let (program, args) = spec.stressor.split_first()?;
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .env("PANIC_ATTACK_AXIS", spec.name.as_str())
+        .env(
+            "PANIC_ATTACK_INTENSITY",
+            format!("{:?}", intensity).to_lowercase(),
+        )
+        .env("PANIC_ATTACK_DURATION_SECS", duration.as_secs().to_string())
+        .env("PANIC_ATTACK_SEED", seed.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Some(pid) = target_pid {
+        command.env("PANIC_ATTACK_TARGET_PID", pid.to_string());
+    }
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            tracing::warn!("stressor for {} axis did not start: {}", spec.name, err);
+            return None;
+        }
+    };
+    let deadline = Instant::now() + duration;
+    Some(thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+            if matches!(child.try_wait(), Ok(Some(_))) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        let _ = child.kill();
+        let _ = child.wait();
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use smol_str::SmolStr;
+
+    fn config(axes: Vec<AttackAxis>, custom_axes: Vec<CustomAxisSpec>) -> AttackConfig {
+        AttackConfig {
+            custom_axes,
+            ..fixtures::config(axes)
+        }
+    }
+
+    fn fuzz_db() -> CustomAxisSpec {
+        CustomAxisSpec {
+            name: SmolStr::new("fuzz-db"),
+            description: None,
+            args: vec!["--db-chaos".to_string()],
+            stressor: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn custom_axes_must_be_registered() {
+        let axis = AttackAxis::Custom(SmolStr::new("fuzz-db"));
+        let unregistered = config(vec![axis.clone()], Vec::new());
+        let err = check(&unregistered).unwrap_err();
+        assert_eq!(err.code(), "attack.unsupported_axis");
+
+        let mut registered = config(vec![axis.clone()], vec![fuzz_db()]);
+        assert!(check(&registered).is_ok());
+        registered
+            .axis_args
+            .insert(axis.clone(), vec!["--rows=10".to_string()]);
+        assert_eq!(args(&registered, &axis), ["--db-chaos", "--rows=10"]);
+
+        let shadowing = config(
+            Vec::new(),
+            vec![CustomAxisSpec {
+                name: SmolStr::new("cpu"),
+                ..fuzz_db()
+            }],
+        );
+        assert!(check(&shadowing).is_err());
+    }
+
+    #[test]
+    fn custom_axes_round_trip_through_reports() {
+        let axes = vec![AttackAxis::Cpu, AttackAxis::Custom(SmolStr::new("fuzz-db"))];
+        let json = serde_json::to_string(&axes).unwrap();
+        assert_eq!(json, r#"["cpu","fuzz-db"]"#);
+        let back: Vec<AttackAxis> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, axes);
+    }
+}
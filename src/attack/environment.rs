@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Host environment snapshot: the machine a report's stress ran on.
+//!
+//! A memory axis that crashes a target on a 2 GiB container says little about a 64 GiB
+//! workstation, and a concurrency run under `ulimit -u 256` is a different experiment from one
+//! without. Every assault and ambush report records the OS and kernel, CPU model and count,
+//! total memory, the cgroup layout, whether it ran inside a container, and the soft resource
+//! limits the target inherited. Anything the host does not expose is left unset.
+
+use crate::types::{HostEnvironment, ResourceLimits};
+use std::fs;
+use std::path::Path;
+
+pub fn capture() -> HostEnvironment {
+    let read = |path: &str| fs::read_to_string(path).ok();
+    HostEnvironment {
+        os: read("/etc/os-release")
+            .as_deref()
+            .and_then(parse_os_name)
+            .unwrap_or_else(|| std::env::consts::OS.to_string()),
+        kernel: read("/proc/sys/kernel/osrelease").map(|release| release.trim().to_string()),
+        arch: std::env::consts::ARCH.to_string(),
+        cpu_model: read("/proc/cpuinfo").as_deref().and_then(parse_cpu_model),
+        cpu_count: std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        total_memory_bytes: read("/proc/meminfo")
+            .as_deref()
+            .and_then(parse_total_memory),
+        cgroup_version: cgroup_version(Path::new("/sys/fs/cgroup")),
+        container: detect_container(read("/proc/self/cgroup").as_deref().unwrap_or_default()),
+        limits: resource_limits(),
+    }
+}
+
+/// `PRETTY_NAME` from os-release, else `NAME VERSION_ID`.
+fn parse_os_name(os_release: &str) -> Option<String> {
+    let field = |key: &str| {
+        os_release.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix('=')?;
+            Some(value.trim().trim_matches('"').to_string())
+        })
+    };
+    field("PRETTY_NAME").or_else(|| match (field("NAME"), field("VERSION_ID")) {
+        (Some(name), Some(version)) => Some(format!("{} {}", name, version)),
+        (name, _) => name,
+    })
+}
+
+/// `model name` (x86) or `Model`/`Hardware` (ARM boards) from `/proc/cpuinfo`.
+fn parse_cpu_model(cpuinfo: &str) -> Option<String> {
+    ["model name", "Model", "Hardware"].iter().find_map(|key| {
+        cpuinfo.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            (name.trim() == *key && !value.trim().is_empty()).then(|| value.trim().to_string())
+        })
+    })
+}
+
+fn parse_total_memory(meminfo: &str) -> Option<u64> {
+    let kib: u64 = meminfo
+        .lines()
+        .find(|line| line.starts_with("MemTotal:"))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+/// `v2` for the unified layout, `hybrid` when v2 sits beside v1 controllers, `v1` otherwise.
+fn cgroup_version(root: &Path) -> Option<String> {
+    let version = if root.join("cgroup.controllers").exists() {
+        "v2"
+    } else if root.join("unified/cgroup.controllers").exists() {
+        "hybrid"
+    } else if root.join("memory").is_dir() || root.join("cpu").is_dir() {
+        "v1"
+    } else {
+        return None;
+    };
+    Some(version.to_string())
+}
+
+/// Container runtime from its marker files, the Kubernetes service environment, or the
+/// runtime's name in our cgroup path.
+fn detect_container(self_cgroup: &str) -> Option<String> {
+    let runtime = if Path::new("/.dockerenv").exists() {
+        "docker"
+    } else if Path::new("/run/.containerenv").exists() {
+        "podman"
+    } else if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some() {
+        "kubernetes"
+    } else {
+        return container_from_cgroup(self_cgroup);
+    };
+    Some(runtime.to_string())
+}
+
+fn container_from_cgroup(self_cgroup: &str) -> Option<String> {
+    [
+        ("kubepods", "kubernetes"),
+        ("docker", "docker"),
+        ("libpod", "podman"),
+        ("lxc", "lxc"),
+        ("containerd", "containerd"),
+    ]
+    .iter()
+    .find(|(marker, _)| self_cgroup.contains(marker))
+    .map(|(_, runtime)| runtime.to_string())
+}
+
+// rlimit field widths differ between unix targets, so the casts are kept.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+fn resource_limits() -> ResourceLimits {
+    let soft = |resource| {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: getrlimit only writes into `limit`.
+        if unsafe { libc::getrlimit(resource, &mut limit) } != 0 {
+            return None;
+        }
+        (limit.rlim_cur != libc::RLIM_INFINITY).then_some(limit.rlim_cur as u64)
+    };
+    ResourceLimits {
+        open_files: soft(libc::RLIMIT_NOFILE),
+        processes: soft(libc::RLIMIT_NPROC),
+        address_space_bytes: soft(libc::RLIMIT_AS),
+        stack_bytes: soft(libc::RLIMIT_STACK),
+        core_bytes: soft(libc::RLIMIT_CORE),
+    }
+}
+
+#[cfg(not(unix))]
+fn resource_limits() -> ResourceLimits {
+    ResourceLimits::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_host_files() {
+        let os_release = "NAME=\"Debian GNU/Linux\"\nVERSION_ID=\"12\"\n";
+        assert_eq!(
+            parse_os_name(os_release).as_deref(),
+            Some("Debian GNU/Linux 12")
+        );
+        let os_release = "PRETTY_NAME=\"Fedora Linux 40\"\nNAME=Fedora\n";
+        assert_eq!(
+            parse_os_name(os_release).as_deref(),
+            Some("Fedora Linux 40")
+        );
+
+        let cpuinfo = "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R) Xeon(R)\n";
+        assert_eq!(
+            parse_cpu_model(cpuinfo).as_deref(),
+            Some("Intel(R) Xeon(R)")
+        );
+        assert_eq!(
+            parse_cpu_model("Hardware\t: BCM2835\nModel\t: Raspberry Pi 4\n").as_deref(),
+            Some("Raspberry Pi 4")
+        );
+
+        let meminfo = "MemTotal: 2048 kB\nMemAvailable: 1024 kB\n";
+        assert_eq!(parse_total_memory(meminfo), Some(2048 * 1024));
+    }
+
+    #[test]
+    fn test_cgroup_and_container_detection() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(cgroup_version(dir.path()), None);
+        fs::create_dir(dir.path().join("memory")).unwrap();
+        assert_eq!(cgroup_version(dir.path()).as_deref(), Some("v1"));
+        fs::write(dir.path().join("cgroup.controllers"), "cpu memory").unwrap();
+        assert_eq!(cgroup_version(dir.path()).as_deref(), Some("v2"));
+
+        assert_eq!(
+            container_from_cgroup("0::/kubepods/burstable/pod1/abc").as_deref(),
+            Some("kubernetes")
+        );
+        assert_eq!(
+            container_from_cgroup("0::/user.slice/session-2.scope"),
+            None
+        );
+    }
+}
@@ -3,22 +3,80 @@
 //! Attack execution engine
 
 use crate::assail::patterns::PatternDetector;
+use crate::attack::budget::{self, Allotment, Budget};
+use crate::attack::capability;
+use crate::attack::checkpoint::Checkpoint;
+use crate::attack::custom;
+use crate::attack::gpu::{self, GpuStressor};
+use crate::attack::hang::{self, Supervised};
+use crate::attack::io_faults::{Interceptor, Scope};
+use crate::attack::malloc_faults::Injection;
+use crate::attack::progress;
+use crate::attack::rr;
+use crate::attack::schedule;
 use crate::attack::strategies::*;
-use crate::signatures::SignatureEngine;
+use crate::attack::telemetry::{self, TelemetrySampler};
+use crate::progress::Progress;
+use crate::signatures::{backtrace, postmortem, SignatureEngine};
 use crate::types::*;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
-use std::process::{Command, Output, Stdio};
-use std::time::{Duration, Instant};
+use std::path::PathBuf;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant, SystemTime};
 
 struct AttackRun {
     output: Output,
     peak_memory: u64,
+    signal_storm: Option<SignalStormReport>,
+    /// Arguments the target actually received.
+    args: Vec<String>,
+    /// rr trace kept because the recorded run crashed.
+    rr_trace: Option<PathBuf>,
+    /// Thread schedule kept because the schedule-fuzzed run crashed.
+    schedule: Option<PathBuf>,
+    /// Allocation failures the preloaded shim injected.
+    malloc_faults: Option<MallocFaultReport>,
+    /// File syscalls the seccomp interceptor saw and failed.
+    io_faults: Option<IoFaultReport>,
+    /// Socket syscalls the seccomp interceptor saw and failed.
+    net_faults: Option<IoFaultReport>,
+    /// Faults most recently injected into the target's file I/O.
+    injected_faults: Vec<InjectedFault>,
+    /// Thread states of a target killed at its deadline after going quiet.
+    hang: Option<HangReport>,
+    /// Progress check that stopped the target.
+    stall: Option<StallSignal>,
+}
+
+impl AttackRun {
+    fn supervised(run: Supervised, args: Vec<String>) -> Self {
+        Self {
+            output: run.output,
+            peak_memory: 0,
+            signal_storm: None,
+            args,
+            rr_trace: None,
+            schedule: None,
+            injected_faults: Vec::new(),
+            malloc_faults: None,
+            io_faults: None,
+            net_faults: None,
+            hang: run.hang,
+            stall: run.stall,
+        }
+    }
 }
 
 pub struct AttackExecutor {
     config: AttackConfig,
     patterns: Vec<AttackPattern>,
+    /// Deadline a trimmed campaign budget sets for runs that otherwise end with the target.
+    limit: Option<Duration>,
 }
 
 impl AttackExecutor {
@@ -26,6 +84,7 @@ impl AttackExecutor {
         Self {
             config,
             patterns: Vec::new(),
+            limit: None,
         }
     }
 
@@ -35,13 +94,45 @@ impl AttackExecutor {
         frameworks: &[Framework],
     ) -> Self {
         let patterns = PatternDetector::patterns_for(language, frameworks);
-        Self { config, patterns }
+        Self {
+            config,
+            patterns,
+            limit: None,
+        }
     }
 
     pub fn execute(&self) -> Result<Vec<AttackResult>> {
+        self.run_axes(None)
+    }
+
+    /// Execute like [`execute`](Self::execute), reusing the results `checkpoint` already
+    /// holds and recording each new one into it as soon as its axis finishes.
+    pub fn execute_with_checkpoint(
+        &self,
+        checkpoint: &mut Checkpoint,
+    ) -> Result<Vec<AttackResult>> {
+        self.run_axes(Some(checkpoint))
+    }
+
+    fn run_axes(&self, mut checkpoint: Option<&mut Checkpoint>) -> Result<Vec<AttackResult>> {
         let mut results = Vec::new();
         // Probe cache avoids re-running `--help` for every axis when probing is enabled.
         let mut probe_cache: HashMap<std::path::PathBuf, Option<String>> = HashMap::new();
+        let blocked = capability::probe_axes(&self.config);
+        for (axis, reason) in &blocked {
+            tracing::warn!("Capability probe: skipping {:?} axis: {}", axis, reason);
+        }
+        let budget = self.config.timeout.map(Budget::start);
+        let mut remaining_runs = self.config.target_programs.len() * self.config.axes.len();
+        let progress = Progress::with_plan(
+            "Assault",
+            self.config
+                .target_programs
+                .iter()
+                .flat_map(|_| &self.config.axes)
+                .map(|axis| self.expected_duration(axis))
+                .collect(),
+        );
 
         for program in &self.config.target_programs {
             let probe_text = if self.config.probe_mode == ProbeMode::Always {
@@ -54,35 +145,103 @@ impl AttackExecutor {
             };
 
             for axis in &self.config.axes {
-                println!("Attacking {:?} on axis {:?}...", program, axis);
+                let _axis = tracing::info_span!(
+                    "axis",
+                    program = %program.display(),
+                    axis = ?axis
+                )
+                .entered();
+                progress.begin(format!("{:?} on {}", axis, program.display()));
+                if let Some(done) = checkpoint
+                    .as_deref()
+                    .and_then(|checkpoint| checkpoint.completed_result(program, axis.clone()))
+                {
+                    tracing::info!(
+                        "Skipping {:?} on axis {:?}: finished before the checkpoint",
+                        program,
+                        axis
+                    );
+                    results.push(done.clone());
+                    remaining_runs -= 1;
+                    progress.inc();
+                    continue;
+                }
+                let scoped = self.config.for_axis(axis).map(|config| Self {
+                    config,
+                    patterns: self.patterns.clone(),
+                    limit: None,
+                });
+                let base = scoped.as_ref().unwrap_or(self);
+                let allotment = budget
+                    .as_ref()
+                    .map(|budget| budget.allot(base.planned_duration(), remaining_runs));
+                remaining_runs -= 1;
+                let trimmed;
+                let (executor, budget_note) = match allotment {
+                    Some(Allotment::Exhausted { note }) => {
+                        tracing::warn!("Campaign timeout: {:?} axis {}", axis, note);
+                        let result = budget::skipped_result(program, axis.clone(), note);
+                        if let Some(checkpoint) = checkpoint.as_deref_mut() {
+                            checkpoint.record(&result)?;
+                        }
+                        results.push(result);
+                        progress.inc();
+                        continue;
+                    }
+                    Some(Allotment::Trimmed { duration, note }) => {
+                        tracing::warn!("Campaign timeout: {:?} axis {}", axis, note);
+                        trimmed = Self {
+                            config: AttackConfig {
+                                duration,
+                                ..base.config.clone()
+                            },
+                            patterns: self.patterns.clone(),
+                            limit: Some(duration),
+                        };
+                        (&trimmed, Some(note))
+                    }
+                    Some(Allotment::Full) | None => (base, None),
+                };
+                tracing::info!("Attacking {:?} on axis {:?}...", program, axis);
 
+                let mut probe_skip = None;
                 if let Some(help_text) = &probe_text {
                     // In probe mode, skip axes whose required flags are clearly unsupported.
-                    let required_flags = self.required_flags_for_axis(*axis);
+                    let required_flags = self.required_flags_for_axis(axis.clone());
                     if !required_flags.is_empty()
                         && !required_flags.iter().all(|flag| help_text.contains(flag))
                     {
-                        results.push(AttackResult {
+                        probe_skip = Some(AttackResult {
                             program: program.clone(),
-                            axis: *axis,
+                            axis: axis.clone(),
                             success: false,
                             skipped: true,
                             skip_reason: Some(format!(
                                 "probe: missing flags [{}]",
                                 required_flags.join(", ")
                             )),
-                            exit_code: None,
                             duration: std::time::Duration::from_secs(0),
                             peak_memory: 0,
-                            crashes: Vec::new(),
-                            signatures_detected: Vec::new(),
+                            ..Default::default()
                         });
-                        continue;
                     }
                 }
 
-                let result = self.execute_single_attack(program, *axis)?;
+                let result = if let Some(reason) = blocked.get(axis) {
+                    capability::skipped_result(program, axis.clone(), reason)
+                } else if let Some(skipped) = probe_skip {
+                    skipped
+                } else {
+                    AttackResult {
+                        budget_note,
+                        ..executor.execute_single_attack(program, axis.clone())?
+                    }
+                };
+                if let Some(checkpoint) = checkpoint.as_deref_mut() {
+                    checkpoint.record(&result)?;
+                }
                 results.push(result);
+                progress.inc();
             }
         }
 
@@ -94,8 +253,8 @@ impl AttackExecutor {
         program: &std::path::PathBuf,
         axis: AttackAxis,
     ) -> Result<AttackResult> {
-        let strategy = self.select_strategy(axis);
-        println!("  Strategy: {}", strategy.description());
+        let strategy = self.select_strategy(axis.clone());
+        tracing::info!("Strategy: {}", strategy.description());
 
         // Log applicable patterns for this axis
         let applicable: Vec<_> = self
@@ -103,34 +262,51 @@ impl AttackExecutor {
             .iter()
             .filter(|p| p.applicable_axes.contains(&axis))
             .collect();
-        if !applicable.is_empty() {
-            println!("  Applicable patterns:");
-            for pat in &applicable {
-                println!("    - {}: {}", pat.name, pat.description);
-            }
+        for pat in &applicable {
+            tracing::debug!("Applicable pattern {}: {}", pat.name, pat.description);
         }
 
+        let telemetry_sampler = self.config.telemetry.then(telemetry::start);
+        let gpu_stressor = self
+            .config
+            .gpu
+            .as_ref()
+            .map(|gpu| gpu::start(gpu, self.config.intensity));
         let start = Instant::now();
+        let started_at = SystemTime::now();
 
         // Execute attack based on strategy
-        let run = if let Some(custom_args) = self.config.axis_args.get(&axis) {
-            self.attack_custom(program, axis, custom_args)?
-        } else {
-            match strategy {
+        let run = match self.config.axis_args.get(&axis) {
+            Some(custom_args) if !axis.is_custom() => {
+                self.attack_custom(program, axis.clone(), custom_args)?
+            }
+            _ => match strategy {
                 AttackStrategy::CpuStress => self.attack_cpu(program)?,
                 AttackStrategy::MemoryExhaustion => self.attack_memory(program)?,
                 AttackStrategy::DiskThrashing => self.attack_disk(program)?,
                 AttackStrategy::NetworkFlood => self.attack_network(program)?,
                 AttackStrategy::ConcurrencyStorm => self.attack_concurrency(program)?,
                 AttackStrategy::TimeBomb => self.attack_time(program)?,
-            }
+                AttackStrategy::SignalStorm => self.attack_signal(program)?,
+                AttackStrategy::Custom => self.attack_registered(program, &axis)?,
+            },
         };
 
         let duration = start.elapsed();
+        let telemetry = telemetry_sampler.map(TelemetrySampler::stop);
+        let gpu = gpu_stressor.map(GpuStressor::stop);
         let exit_code = run.output.status.code();
+        let stress_profile = Some(self.stress_profile(axis.clone(), &run));
 
         // Auto-probe fallback: convert obvious flag incompatibility into a skip with context.
         if self.config.probe_mode != ProbeMode::Never && Self::is_unsupported_flags(&run.output) {
+            // A trace of a run that only rejected its flags is not worth keeping.
+            if let Some(trace) = &run.rr_trace {
+                let _ = std::fs::remove_dir_all(trace);
+            }
+            if let Some(schedule) = &run.schedule {
+                let _ = std::fs::remove_file(schedule);
+            }
             let fallback = Self::fallback_run(program);
             let reason = Self::unsupported_reason(&run.output, fallback.as_ref());
             return Ok(AttackResult {
@@ -142,19 +318,28 @@ impl AttackExecutor {
                 exit_code,
                 duration,
                 peak_memory: run.peak_memory,
-                crashes: Vec::new(),
-                signatures_detected: Vec::new(),
+                signal_storm: run.signal_storm,
+                stress_profile: stress_profile.clone(),
+                telemetry,
+                gpu,
+                ..Default::default()
             });
         }
 
         let success = run.output.status.success();
         let mut crashes = Vec::new();
-        if !success {
-            crashes.push(Self::crash_from_output(&run.output));
+        // A target stopped by its progress check was killed by us, not crashed.
+        if !success && run.stall.is_none() {
+            let mut crash = Self::crash_from_output(&run.output);
+            crash.rr_trace = run.rr_trace.clone();
+            crash.schedule = run.schedule.clone();
+            crash.injected_faults = run.injected_faults.clone();
+            postmortem::attach(&mut crash, &run.output, program, None, started_at);
+            crashes.push(crash);
         }
 
         // Run signature detection on any crashes
-        let signatures_detected = if !crashes.is_empty() {
+        let mut signatures_detected: Vec<BugSignature> = if !crashes.is_empty() {
             let engine = SignatureEngine::new();
             crashes
                 .iter()
@@ -163,21 +348,46 @@ impl AttackExecutor {
         } else {
             Vec::new()
         };
+        signatures_detected.extend(run.hang.as_ref().and_then(hang::signature));
+        signatures_detected.extend(run.stall.as_ref().map(progress::signature));
 
         Ok(AttackResult {
             program: program.clone(),
             axis,
             success,
-            skipped: false,
-            skip_reason: None,
             exit_code,
             duration,
             peak_memory: run.peak_memory,
             crashes,
             signatures_detected,
+            signal_storm: run.signal_storm,
+            stress_profile,
+            hang: run.hang,
+            stall: run.stall,
+            telemetry,
+            gpu,
+            net_faults: run.net_faults,
+            ..Default::default()
         })
     }
 
+    /// Load flags are already in the target args; record them with the limits around the run.
+    fn stress_profile(&self, axis: AttackAxis, run: &AttackRun) -> StressProfile {
+        let mut profile = StressProfile::new(
+            self.config.intensity,
+            self.config.duration,
+            self.config.seed,
+        );
+        profile.target_args = run.args.clone();
+        if matches!(axis, AttackAxis::Time | AttackAxis::Signal) {
+            profile.timeout = Some(Duration::from_secs(self.extended_duration_secs()));
+        }
+        profile.signal_hz = run.signal_storm.as_ref().map(|storm| storm.frequency_hz);
+        profile.malloc_faults = run.malloc_faults.clone();
+        profile.io_faults = run.io_faults.clone();
+        profile
+    }
+
     fn select_strategy(&self, axis: AttackAxis) -> AttackStrategy {
         match axis {
             AttackAxis::Cpu => AttackStrategy::CpuStress,
@@ -186,6 +396,8 @@ impl AttackExecutor {
             AttackAxis::Network => AttackStrategy::NetworkFlood,
             AttackAxis::Concurrency => AttackStrategy::ConcurrencyStorm,
             AttackAxis::Time => AttackStrategy::TimeBomb,
+            AttackAxis::Signal => AttackStrategy::SignalStorm,
+            AttackAxis::Custom(_) => AttackStrategy::Custom,
         }
     }
 
@@ -194,11 +406,8 @@ impl AttackExecutor {
         let iterations = (1000.0 * self.config.intensity.multiplier()) as u64;
 
         let args = self.args_with_common(vec!["--iterations".to_string(), iterations.to_string()]);
-        let output = Self::run_program(program, &args)?;
-        Ok(AttackRun {
-            output,
-            peak_memory: 0,
-        })
+        let run = self.run_program(program, &args)?;
+        Ok(AttackRun::supervised(run, args))
     }
 
     fn attack_memory(&self, program: &std::path::PathBuf) -> Result<AttackRun> {
@@ -206,10 +415,9 @@ impl AttackExecutor {
         let memory_mb = (1024.0 * self.config.intensity.multiplier()) as u64;
 
         let args = self.args_with_common(vec!["--allocate-mb".to_string(), memory_mb.to_string()]);
-        let output = Self::run_program(program, &args)?;
         Ok(AttackRun {
-            output,
             peak_memory: memory_mb * 1024 * 1024,
+            ..self.run_with_malloc_faults(program, args)?
         })
     }
 
@@ -218,11 +426,7 @@ impl AttackExecutor {
         let file_count = (100.0 * self.config.intensity.multiplier()) as u64;
 
         let args = self.args_with_common(vec!["--write-files".to_string(), file_count.to_string()]);
-        let output = Self::run_program(program, &args)?;
-        Ok(AttackRun {
-            output,
-            peak_memory: 0,
-        })
+        self.run_with_io_faults(program, args, Scope::Files)
     }
 
     fn attack_network(&self, program: &std::path::PathBuf) -> Result<AttackRun> {
@@ -231,11 +435,7 @@ impl AttackExecutor {
 
         let args =
             self.args_with_common(vec!["--connections".to_string(), connections.to_string()]);
-        let output = Self::run_program(program, &args)?;
-        Ok(AttackRun {
-            output,
-            peak_memory: 0,
-        })
+        self.run_with_io_faults(program, args, Scope::Sockets)
     }
 
     fn attack_concurrency(&self, program: &std::path::PathBuf) -> Result<AttackRun> {
@@ -243,26 +443,15 @@ impl AttackExecutor {
         let threads = (50.0 * self.config.intensity.multiplier()) as u64;
 
         let args = self.args_with_common(vec!["--threads".to_string(), threads.to_string()]);
-        let output = Self::run_program(program, &args)?;
-        Ok(AttackRun {
-            output,
-            peak_memory: 0,
-        })
+        self.run_recorded(program, args)
     }
 
     fn attack_time(&self, program: &std::path::PathBuf) -> Result<AttackRun> {
         // Time-based attacks: run for extended duration
-        let duration_secs = if self.config.duration.as_secs() > 0 {
-            self.config.duration.as_secs()
-        } else {
-            (60.0 * self.config.intensity.multiplier()) as u64
-        };
+        let duration_secs = self.extended_duration_secs();
         let args = self.args_with_common(Vec::new());
-        let output = Self::run_program_with_timeout(program, &args, duration_secs)?;
-        Ok(AttackRun {
-            output,
-            peak_memory: 0,
-        })
+        let run = self.run_program_with_timeout(program, &args, duration_secs)?;
+        Ok(AttackRun::supervised(run, args))
     }
 
     fn attack_custom(
@@ -272,22 +461,110 @@ impl AttackExecutor {
         custom_args: &[String],
     ) -> Result<AttackRun> {
         let args = self.args_with_common(custom_args.to_vec());
-        let output = if axis == AttackAxis::Time {
-            let duration_secs = if self.config.duration.as_secs() > 0 {
-                self.config.duration.as_secs()
-            } else {
-                (60.0 * self.config.intensity.multiplier()) as u64
-            };
-            Self::run_program_with_timeout(program, &args, duration_secs)?
-        } else {
-            Self::run_program(program, &args)?
+        if axis == AttackAxis::Signal {
+            return self.run_with_signal_storm(program, &args);
+        }
+        let run = match axis {
+            AttackAxis::Time => {
+                let duration_secs = self.extended_duration_secs();
+                self.run_program_with_timeout(program, &args, duration_secs)?
+            }
+            AttackAxis::Concurrency => return self.run_recorded(program, args),
+            AttackAxis::Memory => return self.run_with_malloc_faults(program, args),
+            AttackAxis::Disk => return self.run_with_io_faults(program, args, Scope::Files),
+            AttackAxis::Network => return self.run_with_io_faults(program, args, Scope::Sockets),
+            _ => self.run_program(program, &args)?,
         };
+        Ok(AttackRun::supervised(run, args))
+    }
+
+    /// Run a custom axis: the target gets the registered arguments while the axis's stressor
+    /// loads the host for the attack window.
+    fn attack_registered(
+        &self,
+        program: &std::path::PathBuf,
+        axis: &AttackAxis,
+    ) -> Result<AttackRun> {
+        let args = self.args_with_common(custom::args(&self.config, axis));
+        let stop = Arc::new(AtomicBool::new(false));
+        let stressor = custom::spec(&self.config, axis).and_then(|spec| {
+            custom::spawn_stressor(
+                spec,
+                self.config.intensity,
+                self.config.duration,
+                self.config.seed,
+                None,
+                stop.clone(),
+            )
+        });
+        let run = self.run_program(program, &args);
+        stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = stressor {
+            let _ = handle.join();
+        }
+        Ok(AttackRun::supervised(run?, args))
+    }
+
+    fn attack_signal(&self, program: &std::path::PathBuf) -> Result<AttackRun> {
+        // Signal storm: the target runs unmodified while signals are delivered to its pid
+        let args = self.args_with_common(Vec::new());
+        self.run_with_signal_storm(program, &args)
+    }
+
+    fn run_with_signal_storm(
+        &self,
+        program: &std::path::PathBuf,
+        args: &[String],
+    ) -> Result<AttackRun> {
+        let child = Self::spawn_program(program, args, &[])?;
+
+        let limit = Duration::from_secs(self.extended_duration_secs());
+        let start = Instant::now();
+        let stop = Arc::new(AtomicBool::new(false));
+        let storm = crate::ambush::spawn_signal_storm(
+            child.id(),
+            self.config.signal_storm.clone(),
+            self.config.intensity,
+            start + limit,
+            stop.clone(),
+            self.config.seed,
+        );
+
+        let run = hang::supervise(child, Some(limit), self.config.progress.as_ref())?;
+        stop.store(true, Ordering::SeqCst);
+        let signal_storm = storm.join().ok();
+
         Ok(AttackRun {
-            output,
-            peak_memory: 0,
+            signal_storm,
+            ..AttackRun::supervised(run, args.to_vec())
         })
     }
 
+    /// How long an axis is planned to run: the configured duration, or the intensity-scaled
+    /// fallback when none is set.
+    fn planned_duration(&self) -> Duration {
+        Duration::from_secs(self.extended_duration_secs())
+    }
+
+    /// How long `axis` is configured to run under its own scoped config, for the progress ETA.
+    fn expected_duration(&self, axis: &AttackAxis) -> Duration {
+        let scoped = self.config.for_axis(axis).map(|config| Self {
+            config,
+            patterns: Vec::new(),
+            limit: None,
+        });
+        let base = scoped.as_ref().unwrap_or(self);
+        base.planned_duration()
+    }
+
+    fn extended_duration_secs(&self) -> u64 {
+        if self.config.duration.as_secs() > 0 {
+            self.config.duration.as_secs()
+        } else {
+            (60.0 * self.config.intensity.multiplier()) as u64
+        }
+    }
+
     fn crash_from_output(output: &Output) -> CrashReport {
         CrashReport {
             timestamp: chrono::Utc::now().to_rfc3339(),
@@ -295,6 +572,8 @@ impl AttackExecutor {
             backtrace: Self::extract_backtrace(&output.stderr),
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
             stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            frames: backtrace::parse(&String::from_utf8_lossy(&output.stderr)),
+            ..Default::default()
         }
     }
 
@@ -308,43 +587,202 @@ impl AttackExecutor {
         combined
     }
 
-    fn run_program(program: &std::path::PathBuf, args: &[String]) -> Result<Output> {
-        Command::new(program)
-            .args(args)
+    /// Run a concurrency target under the configured wrapper, falling back to a plain run
+    /// when the wrapper is not installed.
+    fn run_recorded(&self, program: &std::path::PathBuf, args: Vec<String>) -> Result<AttackRun> {
+        let recorded = |output| Supervised {
+            output,
+            hang: None,
+            stall: None,
+        };
+        match self.config.wrapper {
+            Some(TargetWrapper::Rr) if rr::available() => {
+                let (output, rr_trace) =
+                    rr::record(program, &args, std::path::Path::new(rr::TRACE_DIR))?;
+                Ok(AttackRun {
+                    rr_trace,
+                    ..AttackRun::supervised(recorded(output), args)
+                })
+            }
+            Some(TargetWrapper::Rr) => {
+                tracing::warn!("rr not found; running the concurrency axis without recording");
+                let run = self.run_program(program, &args)?;
+                Ok(AttackRun::supervised(run, args))
+            }
+            Some(TargetWrapper::Schedule) if schedule::available() => {
+                let (plan, seed) = match &self.config.schedule_replay {
+                    Some(path) => {
+                        let replay = schedule::load(path)?;
+                        (schedule::Plan::replay(&replay), replay.seed)
+                    }
+                    None => (
+                        schedule::Plan::seeded(self.config.seed, self.config.intensity),
+                        self.config.seed,
+                    ),
+                };
+                let root = self.config.schedule_dir.clone().unwrap_or_else(|| {
+                    std::path::Path::new("reports").join(schedule::SCHEDULE_DIR)
+                });
+                let limit = self.limit.unwrap_or_else(|| self.planned_duration());
+                match schedule::record(program, &args, &plan, seed, &root, limit) {
+                    Ok((output, schedule)) => Ok(AttackRun {
+                        schedule,
+                        ..AttackRun::supervised(recorded(output), args)
+                    }),
+                    Err(err) => {
+                        tracing::warn!(
+                            "schedule fuzzing failed ({:#}); running the concurrency axis untraced",
+                            err
+                        );
+                        let run = self.run_program(program, &args)?;
+                        Ok(AttackRun::supervised(run, args))
+                    }
+                }
+            }
+            Some(TargetWrapper::Schedule) => {
+                tracing::warn!(
+                    "schedule fuzzing needs Linux ptrace; running the concurrency axis untraced"
+                );
+                let run = self.run_program(program, &args)?;
+                Ok(AttackRun::supervised(run, args))
+            }
+            None => {
+                let run = self.run_program(program, &args)?;
+                Ok(AttackRun::supervised(run, args))
+            }
+        }
+    }
+
+    /// Run a memory target with the malloc fault shim preloaded when `--malloc-faults` is
+    /// set, falling back to a plain run when the shim cannot be built.
+    fn run_with_malloc_faults(
+        &self,
+        program: &std::path::PathBuf,
+        args: Vec<String>,
+    ) -> Result<AttackRun> {
+        let injection = self.config.malloc_faults.as_ref().and_then(|config| {
+            Injection::prepare(config, self.config.intensity, self.config.seed)
+                .map_err(|err| {
+                    tracing::warn!("{:#}; running the memory axis without malloc faults", err)
+                })
+                .ok()
+        });
+        let env = injection.as_ref().map(Injection::env).unwrap_or_default();
+        let run = self.run_program_with_env(program, &args, &env)?;
+        Ok(AttackRun {
+            malloc_faults: injection.map(Injection::finish),
+            ..AttackRun::supervised(run, args)
+        })
+    }
+
+    /// Run a disk or network target with its file or socket syscalls intercepted when
+    /// `--io-faults` or `--net-faults` is set, falling back to a plain run when seccomp is
+    /// unavailable.
+    fn run_with_io_faults(
+        &self,
+        program: &std::path::PathBuf,
+        args: Vec<String>,
+        scope: Scope,
+    ) -> Result<AttackRun> {
+        let (config, axis) = match scope {
+            Scope::Files => (&self.config.io_faults, "disk"),
+            Scope::Sockets => (&self.config.net_faults, "network"),
+        };
+        let Some(config) = config else {
+            let run = self.run_program(program, &args)?;
+            return Ok(AttackRun::supervised(run, args));
+        };
+        let mut command = Command::new(program);
+        command
+            .args(&args)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
-            .context("Failed to execute program")
+            .stderr(Stdio::piped());
+        let intensity = self.config.intensity;
+        let (child, interceptor) =
+            match Interceptor::spawn(command, scope, config, intensity, self.config.seed) {
+                Ok(spawned) => spawned,
+                Err(err) => {
+                    tracing::warn!("{:#}; running the {} axis without faults", err, axis);
+                    let run = self.run_program(program, &args)?;
+                    return Ok(AttackRun::supervised(run, args));
+                }
+            };
+        // A target that loses a thread to an injected fault may wait forever on it.
+        let limit = Duration::from_secs(self.extended_duration_secs());
+        let run = hang::supervise(child, Some(limit), self.config.progress.as_ref());
+        let (report, injected_faults) = interceptor.finish();
+        let (io_faults, net_faults) = match scope {
+            Scope::Files => (Some(report), None),
+            Scope::Sockets => (None, Some(report)),
+        };
+        Ok(AttackRun {
+            io_faults,
+            net_faults,
+            injected_faults,
+            ..AttackRun::supervised(run?, args)
+        })
+    }
+
+    /// Run the target to completion, or until its progress check reports a stall.
+    fn run_program(&self, program: &std::path::PathBuf, args: &[String]) -> Result<Supervised> {
+        self.run_program_with_env(program, args, &[])
+    }
+
+    fn run_program_with_env(
+        &self,
+        program: &std::path::PathBuf,
+        args: &[String],
+        env: &[(String, String)],
+    ) -> Result<Supervised> {
+        if self.config.progress.is_none() && self.limit.is_none() {
+            let output = Command::new(program)
+                .args(args)
+                .envs(env.iter().cloned())
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .output()
+                .context("Failed to execute program")?;
+            return Ok(Supervised {
+                output,
+                hang: None,
+                stall: None,
+            });
+        }
+        hang::supervise(
+            Self::spawn_program(program, args, env)?,
+            self.limit,
+            self.config.progress.as_ref(),
+        )
     }
 
     fn run_program_with_timeout(
+        &self,
         program: &std::path::PathBuf,
         args: &[String],
         duration_secs: u64,
-    ) -> Result<Output> {
-        let mut child = Command::new(program)
+    ) -> Result<Supervised> {
+        hang::supervise(
+            Self::spawn_program(program, args, &[])?,
+            Some(Duration::from_secs(duration_secs)),
+            self.config.progress.as_ref(),
+        )
+    }
+
+    fn spawn_program(
+        program: &std::path::PathBuf,
+        args: &[String],
+        env: &[(String, String)],
+    ) -> Result<Child> {
+        Command::new(program)
             .args(args)
+            .envs(env.iter().cloned())
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .context("Failed to execute program")?;
-
-        let start = Instant::now();
-        let limit = Duration::from_secs(duration_secs);
-        loop {
-            if let Some(_status) = child.try_wait()? {
-                break;
-            }
-            if start.elapsed() >= limit {
-                let _ = child.kill();
-                break;
-            }
-            std::thread::sleep(Duration::from_millis(20));
-        }
-
-        Ok(child.wait_with_output()?)
+            .context("Failed to execute program")
     }
 
     fn probe_help(program: &std::path::PathBuf) -> Option<String> {
@@ -359,7 +797,12 @@ impl AttackExecutor {
 
     fn required_flags_for_axis(&self, axis: AttackAxis) -> Vec<String> {
         let mut required = Self::flag_tokens_from_args(&self.config.common_args);
-        if let Some(custom) = self.config.axis_args.get(&axis) {
+        if axis.is_custom() {
+            required.extend(Self::flag_tokens_from_args(&custom::args(
+                &self.config,
+                &axis,
+            )));
+        } else if let Some(custom) = self.config.axis_args.get(&axis) {
             required.extend(Self::flag_tokens_from_args(custom));
         } else {
             // Built-in strategy flags are used only when no axis override is provided.
@@ -369,7 +812,7 @@ impl AttackExecutor {
                 AttackAxis::Disk => vec!["--write-files"],
                 AttackAxis::Network => vec!["--connections"],
                 AttackAxis::Concurrency => vec!["--threads"],
-                AttackAxis::Time => Vec::new(),
+                AttackAxis::Time | AttackAxis::Signal | AttackAxis::Custom(_) => Vec::new(),
             };
             required.extend(built_in.into_iter().map(|s| s.to_string()));
         }
@@ -451,10 +894,42 @@ impl AttackExecutor {
 
     fn extract_backtrace(stderr: &[u8]) -> Option<String> {
         let stderr_str = String::from_utf8_lossy(stderr);
-        if stderr_str.contains("backtrace") || stderr_str.contains("stack backtrace") {
+        if stderr_str.contains("backtrace") || !backtrace::parse(&stderr_str).is_empty() {
             Some(stderr_str.to_string())
         } else {
             None
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::fixtures;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_campaign_timeout_trims_a_target_paced_axis() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("slow");
+        std::fs::write(&target, "#!/bin/sh\nexec sleep 30\n").unwrap();
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let config = AttackConfig {
+            duration: Duration::from_secs(30),
+            target_programs: vec![target],
+            timeout: Some(Duration::from_secs(2)),
+            ..fixtures::config(vec![AttackAxis::Cpu])
+        };
+
+        let results = AttackExecutor::new(config).execute().unwrap();
+        let result = &results[0];
+        assert!(!result.skipped);
+        assert!(result
+            .budget_note
+            .as_deref()
+            .unwrap()
+            .starts_with("trimmed from 30s to "));
+        assert!(result.duration < Duration::from_secs(10));
+    }
+}
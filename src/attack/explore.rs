@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Crash-directed exploration: find the least stress that still reproduces each crash.
+//!
+//! Crashes are grouped into buckets by axis and cause (first signature, else signal, else exit
+//! code), plus the innermost application frame when a backtrace was parsed. For the first crash
+//! in each bucket the axis is rerun alone: once at the original settings to confirm the crash
+//! reproduces, then bisecting the intensity presets below it, then (for duration-driven runs)
+//! bisecting the duration in whole seconds at that intensity. The search assumes reproduction
+//! is monotone in stress; a flaky crash shows up as `reproducible: false` or as a threshold
+//! that moves between runs.
+
+use crate::signatures::backtrace;
+use crate::types::*;
+use anyhow::Result;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Buckets explored per run; each costs at most 1 + 2 + `DURATION_PROBES` reruns.
+pub const MAX_BUCKETS: usize = 8;
+const DURATION_PROBES: usize = 4;
+const MIN_DURATION: Duration = Duration::from_secs(1);
+
+/// Stable key grouping crashes with the same axis, apparent cause and crashing function.
+pub fn crash_bucket(result: &AttackResult) -> Option<String> {
+    if result.skipped || result.crashes.is_empty() {
+        return None;
+    }
+    let cause = if let Some(signature) = result.signatures_detected.first() {
+        format!("{:?}", signature.signature_type)
+    } else if let Some(signal) = result.crashes.iter().find_map(|c| c.signal.clone()) {
+        signal
+    } else if let Some(code) = result.exit_code {
+        format!("exit-{}", code)
+    } else {
+        "killed".to_string()
+    };
+    let bucket = format!("{:?}:{}", result.axis, cause).to_lowercase();
+    // Frames keep their case: symbols differing only in case are different functions.
+    match result
+        .crashes
+        .iter()
+        .find_map(|crash| backtrace::top_frame(&crash.frames))
+    {
+        Some(frame) => Some(format!("{}@{}", bucket, frame.function)),
+        None => Some(bucket),
+    }
+}
+
+/// Rerun crashing axes through `run` to find each bucket's reproduction threshold.
+///
+/// `duration_driven` says whether the runner honours `config.duration` on every axis (ambush);
+/// otherwise only the time axis gets a duration search.
+pub fn explore_crashes<F>(
+    config: &AttackConfig,
+    results: &[AttackResult],
+    duration_driven: bool,
+    mut run: F,
+) -> Result<Vec<ReproductionThreshold>>
+where
+    F: FnMut(&AttackConfig) -> Result<Vec<AttackResult>>,
+{
+    let mut seen = HashSet::new();
+    let mut thresholds = Vec::new();
+
+    for result in results {
+        let Some(bucket) = crash_bucket(result) else {
+            continue;
+        };
+        if !seen.insert(bucket.clone()) {
+            continue;
+        }
+        if thresholds.len() >= MAX_BUCKETS {
+            break;
+        }
+
+        // The axis's own intensity and duration are the ones to bisect below.
+        let mut probe_config = config
+            .for_axis(&result.axis)
+            .unwrap_or_else(|| config.clone());
+        probe_config.axis_intensity.clear();
+        probe_config.axis_duration.clear();
+        probe_config.target_programs = vec![result.program.clone()];
+        probe_config.axes = vec![result.axis.clone()];
+        let mut prober = Prober {
+            config: probe_config,
+            bucket: &bucket,
+            run: &mut run,
+            probes: 0,
+        };
+        let bisect_duration = duration_driven || result.axis == AttackAxis::Time;
+        thresholds.push(prober.threshold(result, bisect_duration)?);
+    }
+
+    Ok(thresholds)
+}
+
+struct Prober<'a, F> {
+    config: AttackConfig,
+    bucket: &'a str,
+    run: &'a mut F,
+    probes: usize,
+}
+
+impl<F> Prober<'_, F>
+where
+    F: FnMut(&AttackConfig) -> Result<Vec<AttackResult>>,
+{
+    fn reproduces(&mut self, intensity: IntensityLevel, duration: Duration) -> Result<bool> {
+        self.probes += 1;
+        let mut config = self.config.clone();
+        config.intensity = intensity;
+        config.duration = duration;
+        let results = (self.run)(&config)?;
+        Ok(results
+            .iter()
+            .any(|result| crash_bucket(result).as_deref() == Some(self.bucket)))
+    }
+
+    fn threshold(
+        &mut self,
+        result: &AttackResult,
+        bisect_duration: bool,
+    ) -> Result<ReproductionThreshold> {
+        let original_intensity = self.config.intensity;
+        let original_duration = self.config.duration;
+        let mut threshold = ReproductionThreshold {
+            bucket: self.bucket.to_string(),
+            program: result.program.clone(),
+            axis: result.axis.clone(),
+            original_intensity,
+            original_duration,
+            reproducible: false,
+            min_intensity: None,
+            min_duration: None,
+            probes: 0,
+        };
+
+        if !self.reproduces(original_intensity, original_duration)? {
+            threshold.probes = self.probes;
+            return Ok(threshold);
+        }
+        threshold.reproducible = true;
+
+        // The presets below the original intensity, then the original itself.
+        let mut levels: Vec<IntensityLevel> = IntensityLevel::PRESETS
+            .into_iter()
+            .filter(|level| level.scale() < original_intensity.scale())
+            .collect();
+        levels.push(original_intensity);
+        let (mut low, mut high) = (0, levels.len() - 1);
+        while low < high {
+            let mid = (low + high) / 2;
+            if self.reproduces(levels[mid], original_duration)? {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        let min_intensity = levels[high];
+        threshold.min_intensity = Some(min_intensity);
+
+        if bisect_duration && original_duration > MIN_DURATION {
+            let (mut low, mut high) = (MIN_DURATION.as_secs(), original_duration.as_secs());
+            for _ in 0..DURATION_PROBES {
+                if high - low <= 1 {
+                    break;
+                }
+                let mid = (low + high) / 2;
+                if self.reproduces(min_intensity, Duration::from_secs(mid))? {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            threshold.min_duration = Some(Duration::from_secs(high));
+        }
+
+        threshold.probes = self.probes;
+        Ok(threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(intensity: IntensityLevel, secs: u64) -> AttackConfig {
+        AttackConfig {
+            duration: Duration::from_secs(secs),
+            intensity,
+            probe_mode: ProbeMode::Never,
+            ..fixtures::config(vec![AttackAxis::Memory])
+        }
+    }
+
+    fn result(axis: AttackAxis, crashed: bool) -> AttackResult {
+        fixtures::result(axis, if crashed { 101 } else { 0 })
+    }
+
+    #[test]
+    fn test_bucket_keys() {
+        assert_eq!(
+            crash_bucket(&result(AttackAxis::Memory, true)).as_deref(),
+            Some("memory:exit-101")
+        );
+        assert_eq!(crash_bucket(&result(AttackAxis::Memory, false)), None);
+
+        let mut panicked = result(AttackAxis::Memory, true);
+        panicked.crashes[0].frames = backtrace::parse(
+            "stack backtrace:\n   0: rust_begin_unwind\n   1: core::panicking::panic_fmt\n   \
+             2: app::Parser::next\n   3: app::main\n",
+        );
+        assert_eq!(
+            crash_bucket(&panicked).as_deref(),
+            Some("memory:exit-101@app::Parser::next")
+        );
+    }
+
+    #[test]
+    fn test_bisects_intensity_and_duration() {
+        // The simulated target crashes from Medium upwards once it has run for 5s.
+        let run = |config: &AttackConfig| -> Result<Vec<AttackResult>> {
+            let crashed = config.intensity != IntensityLevel::Light
+                && config.duration >= Duration::from_secs(5);
+            Ok(vec![result(config.axes[0].clone(), crashed)])
+        };
+        let base = config(IntensityLevel::Extreme, 20);
+        let found = explore_crashes(&base, &[result(AttackAxis::Memory, true)], true, run).unwrap();
+
+        assert_eq!(found.len(), 1);
+        let threshold = &found[0];
+        assert!(threshold.reproducible);
+        assert_eq!(threshold.min_intensity, Some(IntensityLevel::Medium));
+        let min = threshold.min_duration.unwrap();
+        assert!(min >= Duration::from_secs(5) && min <= Duration::from_secs(6));
+        assert!(threshold.probes <= 1 + 2 + DURATION_PROBES);
+    }
+
+    #[test]
+    fn test_flaky_crash_is_marked_unreproducible() {
+        let run = |config: &AttackConfig| -> Result<Vec<AttackResult>> {
+            Ok(vec![result(config.axes[0].clone(), false)])
+        };
+        let base = config(IntensityLevel::Heavy, 10);
+        let crashes = [
+            result(AttackAxis::Memory, true),
+            result(AttackAxis::Memory, true),
+        ];
+        let found = explore_crashes(&base, &crashes, false, run).unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(!found[0].reproducible);
+        assert_eq!(found[0].probes, 1);
+        assert_eq!(found[0].min_intensity, None);
+    }
+}
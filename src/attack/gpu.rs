@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! GPU memory stressor for accelerator-bound targets (`--gpu-stress`, `gpu` feature)
+//!
+//! ML-serving and other GPU-backed targets fail in ways host-side stressors never reach: a
+//! model load that assumes free VRAM, an allocator that does not handle device OOM, kernels
+//! that slow down when the memory bus is saturated. While an attack runs, this opens a Vulkan
+//! adapter through wgpu, claims device memory in buffer-sized chunks up to the requested size
+//! (or until the driver reports out-of-memory), and keeps copying between the buffers so the
+//! memory stays resident and busy. The peak held is recorded beside the result.
+//!
+//! Builds without the `gpu` feature still accept the settings but report that nothing ran.
+
+use crate::types::*;
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+/// Device memory held at light intensity; other levels scale it by the intensity multiplier.
+const BASE_BYTES: u64 = 256 * 1024 * 1024;
+/// Largest single buffer; the adapter's own limit applies when it is lower.
+#[cfg_attr(not(feature = "gpu"), allow(dead_code))]
+const CHUNK_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Whether this build can drive a GPU.
+pub fn available() -> bool {
+    cfg!(feature = "gpu")
+}
+
+/// Background stressor; [`GpuStressor::stop`] releases the memory and returns the summary.
+pub struct GpuStressor {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<GpuStressReport>,
+}
+
+/// Start holding and thrashing device memory.
+pub fn start(config: &GpuStressConfig, intensity: IntensityLevel) -> GpuStressor {
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = stop.clone();
+    let config = config.clone();
+    let requested = requested_bytes(&config, intensity);
+    let handle = thread::spawn(move || {
+        let mut report = GpuStressReport {
+            requested_bytes: requested,
+            ..GpuStressReport::default()
+        };
+        if let Err(err) = run(&config, requested, &flag, &mut report) {
+            report.note = Some(format!("{:#}", err));
+        }
+        report
+    });
+    GpuStressor { stop, handle }
+}
+
+impl GpuStressor {
+    pub fn stop(self) -> GpuStressReport {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_else(|_| GpuStressReport {
+            note: Some("GPU stressor panicked".to_string()),
+            ..GpuStressReport::default()
+        })
+    }
+}
+
+/// Device memory to hold for this run.
+pub fn requested_bytes(config: &GpuStressConfig, intensity: IntensityLevel) -> u64 {
+    config
+        .memory_bytes
+        .unwrap_or_else(|| (BASE_BYTES as f64 * intensity.multiplier()) as u64)
+}
+
+/// Index of the adapter `selector` names: a position in `names`, or a case-insensitive
+/// fragment of one. The first adapter when there is no selector.
+#[cfg_attr(not(feature = "gpu"), allow(dead_code))]
+pub fn select_adapter(names: &[String], selector: Option<&str>) -> Result<usize> {
+    if names.is_empty() {
+        return Err(anyhow!("no Vulkan GPU adapter found"));
+    }
+    let Some(selector) = selector.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Ok(0);
+    };
+    if let Ok(index) = selector.parse::<usize>() {
+        return (index < names.len()).then_some(index).ok_or_else(|| {
+            anyhow!(
+                "GPU adapter {} out of range ({} available)",
+                index,
+                names.len()
+            )
+        });
+    }
+    let needle = selector.to_lowercase();
+    names
+        .iter()
+        .position(|name| name.to_lowercase().contains(&needle))
+        .ok_or_else(|| {
+            anyhow!(
+                "no GPU adapter matches '{}' (available: {})",
+                selector,
+                names.join(", ")
+            )
+        })
+}
+
+#[cfg(not(feature = "gpu"))]
+fn run(
+    _config: &GpuStressConfig,
+    _requested: u64,
+    _stop: &AtomicBool,
+    _report: &mut GpuStressReport,
+) -> Result<()> {
+    Err(anyhow!(
+        "GPU stress needs a build with the `gpu` feature; nothing was allocated"
+    ))
+}
+
+#[cfg(feature = "gpu")]
+fn run(
+    config: &GpuStressConfig,
+    requested: u64,
+    stop: &AtomicBool,
+    report: &mut GpuStressReport,
+) -> Result<()> {
+    use anyhow::Context;
+
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::VULKAN,
+        ..Default::default()
+    });
+    let mut adapters = instance.enumerate_adapters(wgpu::Backends::VULKAN);
+    let names: Vec<String> = adapters.iter().map(|a| a.get_info().name).collect();
+    let adapter = adapters.swap_remove(select_adapter(&names, config.device.as_deref())?);
+    let info = adapter.get_info();
+    report.device = Some(info.name);
+    report.backend = Some(format!("{:?}", info.backend).to_lowercase());
+
+    let limits = adapter.limits();
+    let (device, queue) = block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: Some("panic-attack gpu stress"),
+            required_features: wgpu::Features::empty(),
+            required_limits: limits.clone(),
+        },
+        None,
+    ))
+    .context("opening GPU device")?;
+
+    // Claim memory chunk by chunk (at least two, so there is something to copy between);
+    // clearing each buffer makes the driver actually back it.
+    let chunk = CHUNK_BYTES.min(limits.max_buffer_size).min(requested / 2)
+        & !(wgpu::COPY_BUFFER_ALIGNMENT - 1);
+    let mut buffers = Vec::new();
+    while report.peak_vram_bytes < requested && !stop.load(Ordering::SeqCst) {
+        let size =
+            chunk.min(requested - report.peak_vram_bytes) & !(wgpu::COPY_BUFFER_ALIGNMENT - 1);
+        if size == 0 {
+            break;
+        }
+        device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.clear_buffer(&buffer, 0, None);
+        queue.submit([encoder.finish()]);
+        device.poll(wgpu::Maintain::Wait);
+        if block_on(device.pop_error_scope()).is_some() {
+            report.out_of_memory = true;
+            break;
+        }
+        report.peak_vram_bytes += size;
+        buffers.push(buffer);
+    }
+    report.buffers = buffers.len();
+    if buffers.is_empty() {
+        return Err(anyhow!("could not allocate any GPU memory"));
+    }
+
+    // Rotate data through the buffers until the attack ends.
+    while !stop.load(Ordering::SeqCst) {
+        let mut encoder = device.create_command_encoder(&Default::default());
+        if let [only] = buffers.as_slice() {
+            // A buffer cannot be copied onto itself; keep rewriting it instead.
+            encoder.clear_buffer(only, 0, None);
+        } else {
+            for (index, source) in buffers.iter().enumerate() {
+                let target = &buffers[(index + 1) % buffers.len()];
+                let size = source.size().min(target.size());
+                encoder.copy_buffer_to_buffer(source, 0, target, 0, size);
+            }
+        }
+        queue.submit([encoder.finish()]);
+        device.poll(wgpu::Maintain::Wait);
+        report.passes += 1;
+    }
+    if report.out_of_memory {
+        report.note = Some(format!(
+            "device ran out of memory after {} of {} bytes",
+            report.peak_vram_bytes, requested
+        ));
+    }
+    Ok(())
+}
+
+/// Drive a wgpu future to completion; native backends resolve them without a reactor.
+#[cfg(feature = "gpu")]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut future = std::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::yield_now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requested_bytes_scales_with_intensity() {
+        let config = GpuStressConfig::default();
+        assert_eq!(requested_bytes(&config, IntensityLevel::Light), BASE_BYTES);
+        assert_eq!(
+            requested_bytes(&config, IntensityLevel::Heavy),
+            BASE_BYTES * 10
+        );
+        let fixed = GpuStressConfig {
+            memory_bytes: Some(4096),
+            ..GpuStressConfig::default()
+        };
+        assert_eq!(requested_bytes(&fixed, IntensityLevel::Extreme), 4096);
+    }
+
+    #[test]
+    fn test_select_adapter_by_index_or_name() {
+        let names = vec![
+            "Intel(R) UHD Graphics".to_string(),
+            "NVIDIA GeForce RTX 4090".to_string(),
+        ];
+        assert_eq!(select_adapter(&names, None).unwrap(), 0);
+        assert_eq!(select_adapter(&names, Some("1")).unwrap(), 1);
+        assert_eq!(select_adapter(&names, Some("rtx")).unwrap(), 1);
+        assert!(select_adapter(&names, Some("2")).is_err());
+        let err = select_adapter(&names, Some("radeon")).unwrap_err();
+        assert!(err.to_string().contains("NVIDIA GeForce RTX 4090"));
+        assert!(select_adapter(&[], None).is_err());
+    }
+}
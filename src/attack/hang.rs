@@ -0,0 +1,690 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Hang detection for targets that outlive their deadline
+//!
+//! Targets are run with their stdout and stderr drained as they go, noting when output last
+//! arrived. A target still running at its deadline that has also gone quiet is sampled before
+//! it is killed: every thread's scheduler state, wait channel and CPU use from
+//! `/proc/<pid>/task`, the process's I/O counters from `/proc/<pid>/io`, plus user-space
+//! stacks from `eu-stack` when it is installed. A thread that stays on a core through every
+//! probe while the process does no I/O at all suggests an infinite loop, and is stack-sampled
+//! a few more times to show where it circles; other threads burning CPU without output suggest
+//! a livelock; threads all asleep on locks suggest a deadlock. Anything else is reported as a
+//! plain stall.
+//!
+//! The same supervision loop evaluates `--progress-check` probes (see
+//! [`crate::attack::progress`]) and stops a target as soon as its probe reports a stall.
+
+use crate::attack::progress::ProgressMonitor;
+use crate::types::*;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::io::Read;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Quiet period before a deadline that makes an overrunning target count as hung.
+const MAX_STALL_WINDOW: Duration = Duration::from_secs(5);
+/// Window that CPU use and I/O are measured over.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+/// Probes spread over the window to see which threads stay on a core.
+const PROBES: u32 = 5;
+/// Share of a core a thread on the CPU at every probe must use to count as pinning it.
+const PIN_SHARE: f64 = 0.8;
+/// Stack samples taken of the threads pinning a core.
+const IP_SAMPLES: usize = 4;
+/// Frames and wait channels of a thread asleep on a lock.
+const LOCK_WAITS: &[&str] = &[
+    "futex_wait",
+    "__lll_lock_wait",
+    "pthread_mutex_lock",
+    "pthread_rwlock_",
+    "sys::sync::mutex",
+    "sys::sync::rwlock",
+    "parking_lot",
+];
+
+/// A supervised run: the target's output, and why it was stopped early if it was.
+pub struct Supervised {
+    pub output: Output,
+    pub hang: Option<HangReport>,
+    pub stall: Option<StallSignal>,
+}
+
+/// Wait for `child`, killing it at `limit` or as soon as `progress` reports a stall. A target
+/// that reaches `limit` after producing no output during the last part of the run is sampled
+/// first and returned with a [`HangReport`].
+pub fn supervise(
+    mut child: Child,
+    limit: Option<Duration>,
+    progress: Option<&ProgressProbe>,
+) -> Result<Supervised> {
+    let start = Instant::now();
+    let monitor = match progress.map(|probe| ProgressMonitor::new(probe, start)) {
+        Some(Ok(monitor)) => Some(Arc::new(monitor)),
+        Some(Err(err)) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(err);
+        }
+        None => None,
+    };
+    // Milliseconds after `start` at which either stream last produced bytes.
+    let last_output = Arc::new(AtomicU64::new(0));
+    let stdout = child
+        .stdout
+        .take()
+        .map(|pipe| drain(pipe, start, last_output.clone(), monitor.clone()));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|pipe| drain(pipe, start, last_output.clone(), monitor.clone()));
+
+    let mut hang = None;
+    let mut stall = None;
+    loop {
+        if child.try_wait()?.is_some() {
+            break;
+        }
+        if let Some(signal) = monitor.as_ref().and_then(|monitor| monitor.poll()) {
+            stall = Some(signal);
+            let _ = child.kill();
+            break;
+        }
+        let elapsed = start.elapsed();
+        if let Some(limit) = limit.filter(|limit| elapsed >= *limit) {
+            let quiet_since = Duration::from_millis(last_output.load(Ordering::SeqCst));
+            let stalled_for = elapsed.saturating_sub(quiet_since);
+            if stalled_for >= stall_window(limit) {
+                hang = Some(sample(child.id(), stalled_for));
+            }
+            let _ = child.kill();
+            break;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let status = child.wait()?;
+    let collect = |reader: Option<JoinHandle<Vec<u8>>>| {
+        reader
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default()
+    };
+    let output = Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+    };
+    Ok(Supervised {
+        output,
+        hang,
+        stall,
+    })
+}
+
+/// Signature guessed from a hang, with the thread states behind it as evidence.
+pub fn signature(hang: &HangReport) -> Option<BugSignature> {
+    let signature_type = hang.guess?;
+    let mut evidence = vec![format!(
+        "No output for {:.1}s before the deadline",
+        hang.stalled_for.as_secs_f64()
+    )];
+    if signature_type == SignatureType::InfiniteLoopSuspected {
+        return Some(loop_signature(hang, evidence));
+    }
+    let (confidence, suspects): (f64, Vec<&HangThread>) = match signature_type {
+        SignatureType::Livelock => (0.5, hang.threads.iter().filter(|t| spinning(t)).collect()),
+        _ => (
+            0.6,
+            hang.threads.iter().filter(|t| blocked_on_lock(t)).collect(),
+        ),
+    };
+    let verb = if signature_type == SignatureType::Livelock {
+        "running"
+    } else {
+        "blocked on a lock"
+    };
+    evidence.push(format!(
+        "{} of {} threads {}",
+        suspects.len(),
+        hang.threads.len(),
+        verb
+    ));
+    for thread in &suspects {
+        let place = thread
+            .wchan
+            .clone()
+            .or_else(|| thread.stack.first().cloned())
+            .unwrap_or_else(|| "?".to_string());
+        evidence.push(format!(
+            "tid {} state {} in {} ({} ticks)",
+            thread.tid, thread.state, place, thread.cpu_ticks
+        ));
+    }
+    // The first frame outside the lock or wait machinery is where the target's code sits.
+    let location = suspects.iter().find_map(|thread| {
+        thread
+            .stack
+            .iter()
+            .find(|frame| !LOCK_WAITS.iter().any(|wait| frame.contains(wait)))
+            .cloned()
+    });
+    Some(BugSignature {
+        signature_type,
+        confidence,
+        evidence,
+        location,
+        rule: Some("hang".to_string()),
+        calibration: None,
+    })
+}
+
+/// Infinite-loop signature: the pinned threads, the idle I/O counters and where the stack
+/// samples landed. Samples that all land in one frame raise the confidence.
+fn loop_signature(hang: &HangReport, mut evidence: Vec<String>) -> BugSignature {
+    let pinned: Vec<&HangThread> = hang
+        .threads
+        .iter()
+        .filter(|t| pins_core(t, hang.probes))
+        .collect();
+    evidence.push(format!(
+        "{} of {} threads pinned a core",
+        pinned.len(),
+        hang.threads.len()
+    ));
+    if let Some(io) = &hang.io {
+        evidence.push(format!(
+            "{} bytes read, {} written, {} read/write syscalls over {:.1}s",
+            io.read_bytes,
+            io.written_bytes,
+            io.syscalls,
+            SAMPLE_INTERVAL.as_secs_f64()
+        ));
+    }
+    let mut confidence: f64 = 0.7;
+    let mut location = None;
+    for thread in &pinned {
+        evidence.push(format!(
+            "tid {} on a core in {}/{} probes, {:.0}% of a core ({} ticks)",
+            thread.tid,
+            thread.on_cpu,
+            hang.probes,
+            thread.cpu_share * 100.0,
+            thread.cpu_ticks
+        ));
+        let mut frames: Vec<&String> = thread.ip_samples.iter().collect();
+        frames.sort();
+        frames.dedup();
+        match frames.as_slice() {
+            [] => {}
+            [frame] => {
+                evidence.push(format!(
+                    "{} stack samples of tid {} all in {}",
+                    thread.ip_samples.len(),
+                    thread.tid,
+                    frame
+                ));
+                if thread.ip_samples.len() > 1 {
+                    confidence = 0.8;
+                }
+            }
+            _ => evidence.push(format!(
+                "{} stack samples of tid {} across {}",
+                thread.ip_samples.len(),
+                thread.tid,
+                frames
+                    .iter()
+                    .map(|frame| frame.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+        location = location.or_else(|| thread.ip_samples.first().cloned());
+    }
+    BugSignature {
+        signature_type: SignatureType::InfiniteLoopSuspected,
+        confidence,
+        evidence,
+        location,
+        rule: Some("infinite_loop".to_string()),
+        calibration: None,
+    }
+}
+
+fn drain<R: Read + Send + 'static>(
+    mut pipe: R,
+    start: Instant,
+    last_output: Arc<AtomicU64>,
+    monitor: Option<Arc<ProgressMonitor>>,
+) -> JoinHandle<Vec<u8>> {
+    let monitor = monitor.filter(|monitor| monitor.watches_output());
+    thread::spawn(move || {
+        let mut collected = Vec::new();
+        let mut buffer = [0u8; 8192];
+        // Start of the line not yet passed to the progress monitor.
+        let mut line_start = 0;
+        while let Ok(read) = pipe.read(&mut buffer) {
+            if read == 0 {
+                break;
+            }
+            collected.extend_from_slice(&buffer[..read]);
+            last_output.store(start.elapsed().as_millis() as u64, Ordering::SeqCst);
+            if let Some(monitor) = &monitor {
+                while let Some(end) = collected[line_start..].iter().position(|b| *b == b'\n') {
+                    let line = &collected[line_start..line_start + end];
+                    monitor.observe_line(&String::from_utf8_lossy(line));
+                    line_start += end + 1;
+                }
+            }
+        }
+        collected
+    })
+}
+
+fn stall_window(limit: Duration) -> Duration {
+    (limit / 2).min(MAX_STALL_WINDOW)
+}
+
+fn sample(pid: u32, stalled_for: Duration) -> HangReport {
+    let io_before = read_io(pid);
+    let before: HashMap<u32, u64> = read_tasks(pid)
+        .into_iter()
+        .map(|task| (task.tid, task.cpu_ticks))
+        .collect();
+    let mut on_cpu: HashMap<u32, u32> = HashMap::new();
+    let mut threads = Vec::new();
+    for _ in 0..PROBES {
+        thread::sleep(SAMPLE_INTERVAL / PROBES);
+        threads = read_tasks(pid);
+        for thread in &threads {
+            if thread.state == "R" && in_user_code(pid, thread.tid) {
+                *on_cpu.entry(thread.tid).or_default() += 1;
+            }
+        }
+    }
+    let io = io_before
+        .zip(read_io(pid))
+        .map(|(before, after)| IoProgress {
+            read_bytes: after.read_bytes.saturating_sub(before.read_bytes),
+            written_bytes: after.written_bytes.saturating_sub(before.written_bytes),
+            syscalls: after.syscalls.saturating_sub(before.syscalls),
+        });
+    let window_ticks = SAMPLE_INTERVAL.as_secs_f64() * crate::ambush::clock_ticks();
+    for thread in &mut threads {
+        let earlier = before.get(&thread.tid).copied().unwrap_or(thread.cpu_ticks);
+        thread.cpu_ticks = thread.cpu_ticks.saturating_sub(earlier);
+        thread.cpu_share = thread.cpu_ticks as f64 / window_ticks;
+        thread.on_cpu = on_cpu.get(&thread.tid).copied().unwrap_or(0);
+    }
+    let mut stacks = eu_stack(pid);
+    for thread in &mut threads {
+        thread.stack = stacks.remove(&thread.tid).unwrap_or_default();
+    }
+    // Further stacks of the threads pinning a core show whether they circle one spot.
+    if threads
+        .iter()
+        .any(|t| pins_core(t, PROBES) && !t.stack.is_empty())
+    {
+        let mut samples = vec![threads
+            .iter()
+            .map(|t| (t.tid, t.stack.clone()))
+            .collect::<HashMap<_, _>>()];
+        samples.extend((1..IP_SAMPLES).map(|_| eu_stack(pid)));
+        for thread in threads.iter_mut().filter(|t| pins_core(t, PROBES)) {
+            thread.ip_samples = samples
+                .iter()
+                .filter_map(|stacks| stacks.get(&thread.tid)?.first().cloned())
+                .collect();
+        }
+    }
+    let guess = classify(&threads, PROBES, io.as_ref());
+    HangReport {
+        pid,
+        stalled_for,
+        threads,
+        guess,
+        probes: PROBES,
+        io,
+    }
+}
+
+/// Whether a running thread is executing its own code; a thread inside a system call
+/// reports the call number instead. Unreadable counts as yes, leaving the state to decide.
+fn in_user_code(pid: u32, tid: u32) -> bool {
+    std::fs::read_to_string(format!("/proc/{}/task/{}/syscall", pid, tid))
+        .map(|syscall| syscall.starts_with("running"))
+        .unwrap_or(true)
+}
+
+/// Cumulative I/O of `pid`: bytes through read/write calls and the number of those calls.
+fn read_io(pid: u32) -> Option<IoProgress> {
+    parse_io(&std::fs::read_to_string(format!("/proc/{}/io", pid)).ok()?)
+}
+
+fn parse_io(text: &str) -> Option<IoProgress> {
+    let field = |name: &str| -> Option<u64> {
+        text.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))?
+            .trim()
+            .parse()
+            .ok()
+    };
+    Some(IoProgress {
+        read_bytes: field("rchar")?,
+        written_bytes: field("wchar")?,
+        syscalls: field("syscr")? + field("syscw")?,
+    })
+}
+
+/// Current state of every thread of `pid`; `cpu_ticks` holds the running total.
+fn read_tasks(pid: u32) -> Vec<HangThread> {
+    let task_dir = format!("/proc/{}/task", pid);
+    let Ok(entries) = std::fs::read_dir(&task_dir) else {
+        return Vec::new();
+    };
+    let mut threads: Vec<HangThread> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let tid: u32 = entry.file_name().to_str()?.parse().ok()?;
+            let path = entry.path();
+            let stat = std::fs::read_to_string(path.join("stat")).ok()?;
+            let (state, cpu_ticks) = parse_stat(&stat)?;
+            let name = std::fs::read_to_string(path.join("comm"))
+                .ok()
+                .map(|comm| comm.trim().to_string());
+            let wchan = std::fs::read_to_string(path.join("wchan"))
+                .ok()
+                .map(|wchan| wchan.trim().to_string())
+                .filter(|wchan| !wchan.is_empty() && wchan != "0");
+            Some(HangThread {
+                tid,
+                name,
+                state,
+                wchan,
+                cpu_ticks,
+                cpu_share: 0.0,
+                on_cpu: 0,
+                stack: Vec::new(),
+                ip_samples: Vec::new(),
+            })
+        })
+        .collect();
+    threads.sort_by_key(|thread| thread.tid);
+    threads
+}
+
+/// State letter and user+system ticks from a `stat` line. The command name can contain
+/// spaces and parentheses, so fields are counted from its closing parenthesis.
+fn parse_stat(stat: &str) -> Option<(String, u64)> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    let state = fields.first()?.to_string();
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some((state, utime + stime))
+}
+
+fn eu_stack(pid: u32) -> HashMap<u32, Vec<String>> {
+    Command::new("eu-stack")
+        .arg("-p")
+        .arg(pid.to_string())
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .map(|output| parse_eu_stack(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default()
+}
+
+fn parse_eu_stack(text: &str) -> HashMap<u32, Vec<String>> {
+    let mut stacks: HashMap<u32, Vec<String>> = HashMap::new();
+    let mut current = None;
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(tid) = line.strip_prefix("TID ") {
+            current = tid.trim_end_matches(':').trim().parse().ok();
+            continue;
+        }
+        let (Some(tid), Some(frame)) = (current, line.strip_prefix('#')) else {
+            continue;
+        };
+        // "#3  0x00007f0c2b8a  function" (the function is missing without symbols)
+        let mut parts = frame.split_whitespace().skip(1);
+        let address = parts.next().unwrap_or_default();
+        let function = parts.collect::<Vec<_>>().join(" ");
+        let function = function.split(" - ").next().unwrap_or_default();
+        let frame = if function.is_empty() {
+            address
+        } else {
+            function
+        };
+        stacks.entry(tid).or_default().push(frame.to_string());
+    }
+    stacks
+}
+
+fn spinning(thread: &HangThread) -> bool {
+    thread.state == "R" && thread.cpu_ticks > 0
+}
+
+/// On a core, running its own code, at every one of `probes` probes and for most of the window.
+fn pins_core(thread: &HangThread, probes: u32) -> bool {
+    probes > 0 && thread.on_cpu >= probes && thread.cpu_share >= PIN_SHARE
+}
+
+fn blocked_on_lock(thread: &HangThread) -> bool {
+    thread
+        .wchan
+        .iter()
+        .chain(thread.stack.iter())
+        .any(|place| LOCK_WAITS.iter().any(|wait| place.contains(wait)))
+}
+
+/// Without readable I/O counters a pinned thread is only a livelock: it may be making progress.
+fn classify(threads: &[HangThread], probes: u32, io: Option<&IoProgress>) -> Option<SignatureType> {
+    let pinned = threads.iter().any(|thread| pins_core(thread, probes));
+    if pinned && io.is_some_and(IoProgress::is_idle) {
+        Some(SignatureType::InfiniteLoopSuspected)
+    } else if threads.iter().any(spinning) {
+        Some(SignatureType::Livelock)
+    } else if threads.iter().any(blocked_on_lock) {
+        Some(SignatureType::Deadlock)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread(tid: u32, state: &str, wchan: Option<&str>, cpu_ticks: u64) -> HangThread {
+        HangThread {
+            tid,
+            name: None,
+            state: state.to_string(),
+            wchan: wchan.map(str::to_string),
+            cpu_ticks,
+            cpu_share: 0.0,
+            on_cpu: 0,
+            stack: Vec::new(),
+            ip_samples: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_proc_and_eu_stack() {
+        let stat = "4242 (worker (1)) S 1 4242 4242 0 -1 4194560 120 0 0 0 37 5 0 0 20 0 3 0";
+        assert_eq!(parse_stat(stat), Some(("S".to_string(), 42)));
+        assert_eq!(parse_stat("garbage"), None);
+
+        let stacks = parse_eu_stack(
+            "PID 10 - process\nTID 10:\n#0  0x00007f3d8b0c9d8d __lll_lock_wait\n\
+             #1  0x00007f3d8b0d0ef2 pthread_mutex_lock\n#2  0x000055d0c0ffee10\nTID 11:\n\
+             #0  0x00007f3d8b0c9d8d do_work - /usr/bin/app\n",
+        );
+        assert_eq!(
+            stacks[&10],
+            vec![
+                "__lll_lock_wait",
+                "pthread_mutex_lock",
+                "0x000055d0c0ffee10"
+            ]
+        );
+        assert_eq!(stacks[&11], vec!["do_work"]);
+    }
+
+    #[test]
+    fn test_classify_hangs() {
+        let idle = vec![thread(1, "S", Some("do_nanosleep"), 0)];
+        assert_eq!(classify(&idle, PROBES, None), None);
+
+        let locked = vec![
+            thread(1, "S", Some("futex_wait_queue"), 0),
+            thread(2, "S", Some("futex_wait_queue"), 0),
+        ];
+        assert_eq!(
+            classify(&locked, PROBES, None),
+            Some(SignatureType::Deadlock)
+        );
+
+        let spinning = vec![
+            thread(1, "R", None, 20),
+            thread(2, "S", Some("futex_wait_queue"), 0),
+        ];
+        assert_eq!(
+            classify(&spinning, PROBES, None),
+            Some(SignatureType::Livelock)
+        );
+
+        let report = HangReport {
+            pid: 1,
+            stalled_for: Duration::from_secs(5),
+            threads: locked,
+            guess: Some(SignatureType::Deadlock),
+            probes: PROBES,
+            io: None,
+        };
+        let signature = signature(&report).unwrap();
+        assert_eq!(signature.signature_type, SignatureType::Deadlock);
+        assert!(signature
+            .evidence
+            .iter()
+            .any(|e| e == "2 of 2 threads blocked on a lock"));
+    }
+
+    #[test]
+    fn test_pinned_thread_without_io_is_an_infinite_loop() {
+        let io =
+            parse_io("rchar: 3980\nwchar: 12\nsyscr: 9\nsyscw: 1\nread_bytes: 0\nwrite_bytes: 0\n")
+                .unwrap();
+        assert_eq!(
+            io,
+            IoProgress {
+                read_bytes: 3980,
+                written_bytes: 12,
+                syscalls: 10,
+            }
+        );
+        assert_eq!(parse_io("rchar: 1\n"), None);
+
+        let mut pinned = thread(7, "R", None, 20);
+        pinned.cpu_share = 1.0;
+        pinned.on_cpu = PROBES;
+        pinned.ip_samples = vec!["parse_header".to_string(); 4];
+        let mut threads = vec![pinned, thread(8, "S", Some("futex_wait_queue"), 0)];
+        let idle = IoProgress::default();
+        assert_eq!(
+            classify(&threads, PROBES, Some(&idle)),
+            Some(SignatureType::InfiniteLoopSuspected)
+        );
+        // Still reading or writing: busy, perhaps livelocked, but not stuck in a loop.
+        assert_eq!(
+            classify(&threads, PROBES, Some(&io)),
+            Some(SignatureType::Livelock)
+        );
+        assert_eq!(
+            classify(&threads, PROBES, None),
+            Some(SignatureType::Livelock)
+        );
+
+        let report = HangReport {
+            pid: 1,
+            stalled_for: Duration::from_secs(5),
+            threads: threads.clone(),
+            guess: Some(SignatureType::InfiniteLoopSuspected),
+            probes: PROBES,
+            io: Some(idle),
+        };
+        let signature = signature(&report).unwrap();
+        assert_eq!(signature.rule.as_deref(), Some("infinite_loop"));
+        assert_eq!(signature.location.as_deref(), Some("parse_header"));
+        assert_eq!(signature.confidence, 0.8);
+        assert!(signature
+            .evidence
+            .iter()
+            .any(|e| e == "tid 7 on a core in 5/5 probes, 100% of a core (20 ticks)"));
+        assert!(signature
+            .evidence
+            .iter()
+            .any(|e| e == "4 stack samples of tid 7 all in parse_header"));
+
+        // Preempted for part of the window: not pinned.
+        threads[0].on_cpu = PROBES - 2;
+        assert_eq!(
+            classify(&threads, PROBES, Some(&idle)),
+            Some(SignatureType::Livelock)
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_deadline_separates_stalled_from_busy_targets() {
+        let spawn = |script: &str| {
+            Command::new("/bin/sh")
+                .args(["-c", script])
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap()
+        };
+        let limit = Duration::from_millis(400);
+
+        let run = supervise(spawn("echo done"), Some(limit), None).unwrap();
+        assert!(run.output.status.success());
+        assert_eq!(run.output.stdout, b"done\n");
+        assert!(run.hang.is_none());
+
+        let run = supervise(spawn("while :; do :; done"), Some(limit), None).unwrap();
+        let hang = run.hang.expect("silent spinning target is a hang");
+        assert!(!hang.threads.is_empty());
+        assert_eq!(hang.probes, PROBES);
+        // A loaded machine can preempt the loop for part of the window.
+        assert!(matches!(
+            hang.guess,
+            Some(SignatureType::InfiniteLoopSuspected | SignatureType::Livelock)
+        ));
+
+        // Still talking at the deadline: a slow target, not a hung one.
+        let chatty = "while :; do echo tick; sleep 0.05; done";
+        let run = supervise(spawn(chatty), Some(limit), None).unwrap();
+        assert!(!run.output.status.success());
+        assert!(run.hang.is_none());
+
+        // Output that never matches the progress check stops the target without a deadline.
+        let probe = ProgressProbe {
+            check: ProgressCheck {
+                kind: ProgressKind::Output,
+                target: "^request done".to_string(),
+            },
+            window: limit,
+        };
+        let run = supervise(spawn(chatty), None, Some(&probe)).unwrap();
+        let stall = run.stall.expect("no matching line within the window");
+        assert!(stall.detected_at >= limit);
+        assert!(String::from_utf8_lossy(&run.output.stdout).contains("tick"));
+    }
+}
@@ -0,0 +1,851 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Syscall fault injection for the disk and network axes (`--io-faults`, `--net-faults`)
+//!
+//! Disk and network stress slow the target's I/O down but never make it fail, so error
+//! paths around reads, writes, opens and socket calls go untested. With fault injection on,
+//! the target starts under a seccomp filter that hands those calls to a supervisor thread
+//! here (`SECCOMP_RET_USER_NOTIF`), which fails some of them at a seeded rate:
+//!
+//! - [`Scope::Files`] (disk axis): reads, writes and opens of regular files return `EIO` or
+//!   `EAGAIN`, or reads come back short. Pipes, sockets, devices and the dynamic loader's
+//!   libraries run untouched.
+//! - [`Scope::Sockets`] (network axis): the target's own connects, sends and receives see
+//!   refused or timed-out connections, `ECONNRESET`, `EPIPE` (without `SIGPIPE`), partial
+//!   transfers and stalls.
+//!
+//! The faults injected last are kept with a crash so the report shows which failure the
+//! target did not survive. Linux on x86_64 or aarch64 only.
+
+use crate::types::{InjectedFault, IntensityLevel, IoFaultConfig, IoFaultReport};
+use anyhow::{anyhow, Result};
+use std::process::{Child, Command};
+use std::time::Duration;
+
+/// Which of the target's syscalls are intercepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// read, write and open on regular files.
+    Files,
+    /// connect, send and receive calls (including read and write) on sockets.
+    Sockets,
+}
+
+impl Scope {
+    /// Failure probability per intercepted call when no rate is given. Socket calls are
+    /// rarer than file calls, so they fail more often.
+    pub fn rate_for(self, intensity: IntensityLevel) -> f64 {
+        match self {
+            Scope::Files => (0.002 * intensity.multiplier()).clamp(0.001, 0.1),
+            Scope::Sockets => (0.01 * intensity.multiplier()).clamp(0.005, 0.25),
+        }
+    }
+}
+
+/// Whether this platform can intercept the target's syscalls.
+pub fn available() -> bool {
+    cfg!(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))
+}
+
+/// How long a stalled socket call is held before it proceeds.
+pub fn stall_for(intensity: IntensityLevel) -> Duration {
+    Duration::from_millis((250.0 * intensity.multiplier()) as u64)
+        .clamp(Duration::from_millis(50), Duration::from_secs(2))
+}
+
+/// Paths whose I/O is never failed: pseudo-filesystems and shared libraries being loaded.
+fn exempt(path: &str) -> bool {
+    if ["/dev/", "/proc/", "/sys/", "/etc/ld.so"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+    {
+        return true;
+    }
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.ends_with(".so") || name.contains(".so.")
+}
+
+/// Supervisor for one target run.
+pub struct Interceptor {
+    #[cfg(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    inner: notify::Supervisor,
+    rate: f64,
+}
+
+impl Interceptor {
+    /// Spawn `command` with the syscalls of `scope` routed through the supervisor.
+    pub fn spawn(
+        command: Command,
+        scope: Scope,
+        config: &IoFaultConfig,
+        intensity: IntensityLevel,
+        seed: u64,
+    ) -> Result<(Child, Self)> {
+        if !available() {
+            return Err(anyhow!(
+                "syscall fault injection needs Linux seccomp user notification (x86_64 or aarch64)"
+            ));
+        }
+        let rate = config.rate.unwrap_or_else(|| scope.rate_for(intensity));
+        #[cfg(all(
+            target_os = "linux",
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        ))]
+        {
+            let stall = stall_for(intensity);
+            let (child, inner) = notify::Supervisor::spawn(command, scope, rate, stall, seed)?;
+            Ok((child, Self { inner, rate }))
+        }
+        #[cfg(not(all(
+            target_os = "linux",
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        )))]
+        {
+            let _ = (command, rate, seed);
+            unreachable!("checked by available()")
+        }
+    }
+
+    /// Stop supervising; call once the target has exited. Returns the counts and the most
+    /// recent faults, oldest first.
+    pub fn finish(self) -> (IoFaultReport, Vec<InjectedFault>) {
+        #[cfg(all(
+            target_os = "linux",
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        ))]
+        {
+            let outcome = self.inner.finish();
+            let report = IoFaultReport {
+                rate: self.rate,
+                intercepted: outcome.intercepted,
+                injected: outcome.injected,
+                faults: outcome.faults,
+            };
+            (report, outcome.recent.into())
+        }
+        #[cfg(not(all(
+            target_os = "linux",
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        )))]
+        {
+            let report = IoFaultReport {
+                rate: self.rate,
+                ..IoFaultReport::default()
+            };
+            (report, Vec::new())
+        }
+    }
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod notify {
+    use super::{exempt, Scope};
+    use crate::seed::SeededRng;
+    use crate::types::InjectedFault;
+    use anyhow::{Context, Result};
+    use std::collections::{BTreeMap, VecDeque};
+    use std::fs::File;
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+    use std::os::unix::fs::FileExt;
+    use std::os::unix::process::CommandExt;
+    use std::process::{Child, Command};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+    use std::time::{Duration, Instant};
+
+    // From linux/seccomp.h; libc does not export the ioctl numbers.
+    const NOTIF_RECV: libc::c_ulong = 0xc050_2100;
+    const NOTIF_SEND: libc::c_ulong = 0xc018_2101;
+    const NOTIF_ID_VALID: libc::c_ulong = 0x4008_2102;
+
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xc000_003e;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xc000_00b7;
+
+    /// Faults kept for the crash report; a panicking target often hits a few more while
+    /// printing its backtrace, after the one that broke it.
+    const RECENT_FAULTS: usize = 8;
+
+    /// Largest partial transfer served, so a huge `count` does not make us buffer it.
+    const MAX_PARTIAL: usize = 64 * 1024;
+
+    /// Longest wait between checks for the stop flag.
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    #[derive(Default)]
+    pub struct Outcome {
+        pub intercepted: u64,
+        pub injected: u64,
+        pub faults: BTreeMap<String, u64>,
+        pub recent: VecDeque<InjectedFault>,
+    }
+
+    pub struct Supervisor {
+        stop: Arc<AtomicBool>,
+        server: JoinHandle<Outcome>,
+    }
+
+    impl Supervisor {
+        pub fn spawn(
+            mut command: Command,
+            scope: Scope,
+            rate: f64,
+            stall: Duration,
+            seed: u64,
+        ) -> Result<(Child, Self)> {
+            let (ours, theirs) = socket_pair().context("creating the seccomp listener socket")?;
+            let filter = filter(scope);
+            let sender = theirs.as_raw_fd();
+            // SAFETY: the hook only makes raw syscalls on memory it owns; it does not allocate.
+            unsafe {
+                command.pre_exec(move || install(&filter, sender));
+            }
+
+            // Serve before spawning: the target blocks on its first intercepted syscall until
+            // the listener is read, and a failed exec reports itself with an intercepted write.
+            let stop = Arc::new(AtomicBool::new(false));
+            let server = {
+                let stop = stop.clone();
+                let started = Instant::now();
+                std::thread::spawn(move || match receive_listener(&ours) {
+                    Some(listener) => {
+                        Server::new(scope, rate, stall, seed, started).serve(&listener, &stop)
+                    }
+                    None => Outcome::default(),
+                })
+            };
+            let spawned = command.spawn();
+            // Our copy of the target's end must go, or a target that never sends would
+            // leave the server waiting forever.
+            drop(theirs);
+            let child = spawned.context("Failed to execute program under seccomp")?;
+            Ok((child, Self { stop, server }))
+        }
+
+        pub fn finish(self) -> Outcome {
+            self.stop.store(true, Ordering::SeqCst);
+            self.server.join().unwrap_or_default()
+        }
+    }
+
+    fn socket_pair() -> io::Result<(OwnedFd, OwnedFd)> {
+        let mut fds = [0 as RawFd; 2];
+        // SAFETY: socketpair writes two descriptors into `fds`, which we then own.
+        let rc = unsafe {
+            libc::socketpair(
+                libc::AF_UNIX,
+                libc::SOCK_STREAM | libc::SOCK_CLOEXEC,
+                0,
+                fds.as_mut_ptr(),
+            )
+        };
+        if rc < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: both descriptors were just created and are not owned elsewhere.
+        Ok(unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) })
+    }
+
+    fn syscalls(scope: Scope) -> Vec<libc::c_long> {
+        let mut syscalls = vec![libc::SYS_read, libc::SYS_write];
+        match scope {
+            Scope::Files => {
+                syscalls.push(libc::SYS_openat);
+                #[cfg(target_arch = "x86_64")]
+                syscalls.push(libc::SYS_open);
+            }
+            Scope::Sockets => syscalls.extend([
+                libc::SYS_connect,
+                libc::SYS_sendto,
+                libc::SYS_recvfrom,
+                libc::SYS_sendmsg,
+                libc::SYS_recvmsg,
+            ]),
+        }
+        syscalls
+    }
+
+    /// Notify on the scope's syscalls of the native architecture; allow everything else.
+    fn filter(scope: Scope) -> Vec<libc::sock_filter> {
+        let syscalls = syscalls(scope);
+        let n = syscalls.len() as u8;
+        let load = (libc::BPF_LD | libc::BPF_W | libc::BPF_ABS) as u16;
+        let jeq = (libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K) as u16;
+        let ret = (libc::BPF_RET | libc::BPF_K) as u16;
+        // SAFETY: BPF_STMT and BPF_JUMP only build plain structs.
+        unsafe {
+            let mut program = vec![
+                libc::BPF_STMT(load, 4), // seccomp_data.arch
+                libc::BPF_JUMP(jeq, AUDIT_ARCH, 0, n + 1),
+                libc::BPF_STMT(load, 0), // seccomp_data.nr
+            ];
+            for (i, nr) in syscalls.iter().enumerate() {
+                program.push(libc::BPF_JUMP(jeq, *nr as u32, n - i as u8, 0));
+            }
+            program.push(libc::BPF_STMT(ret, libc::SECCOMP_RET_ALLOW));
+            program.push(libc::BPF_STMT(ret, libc::SECCOMP_RET_USER_NOTIF));
+            program
+        }
+    }
+
+    /// Runs in the forked child: install the filter and pass the listener to the parent.
+    fn install(filter: &[libc::sock_filter], sender: RawFd) -> io::Result<()> {
+        let program = libc::sock_fprog {
+            len: filter.len() as u16,
+            filter: filter.as_ptr() as *mut libc::sock_filter,
+        };
+        // SAFETY: raw syscalls on stack memory that outlives each call.
+        unsafe {
+            if libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let listener = libc::syscall(
+                libc::SYS_seccomp,
+                libc::SECCOMP_SET_MODE_FILTER,
+                libc::SECCOMP_FILTER_FLAG_NEW_LISTENER,
+                &program as *const libc::sock_fprog,
+            );
+            if listener < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let listener = listener as RawFd;
+            let sent = send_fd(sender, listener);
+            libc::close(listener);
+            libc::close(sender);
+            sent
+        }
+    }
+
+    /// Aligned room for one `SCM_RIGHTS` control message.
+    #[repr(C, align(8))]
+    struct ControlBuffer([u8; 32]);
+
+    fn send_fd(socket: RawFd, fd: RawFd) -> io::Result<()> {
+        let mut byte = 0u8;
+        let mut iov = libc::iovec {
+            iov_base: &mut byte as *mut u8 as *mut libc::c_void,
+            iov_len: 1,
+        };
+        let mut control = ControlBuffer([0; 32]);
+        // SAFETY: the header and control buffer live on this frame; CMSG_* stay within it.
+        unsafe {
+            let mut message: libc::msghdr = std::mem::zeroed();
+            message.msg_iov = &mut iov;
+            message.msg_iovlen = 1;
+            message.msg_control = control.0.as_mut_ptr() as *mut libc::c_void;
+            message.msg_controllen = libc::CMSG_SPACE(std::mem::size_of::<RawFd>() as u32) as _;
+            let header = libc::CMSG_FIRSTHDR(&message);
+            (*header).cmsg_level = libc::SOL_SOCKET;
+            (*header).cmsg_type = libc::SCM_RIGHTS;
+            (*header).cmsg_len = libc::CMSG_LEN(std::mem::size_of::<RawFd>() as u32) as _;
+            std::ptr::write_unaligned(libc::CMSG_DATA(header) as *mut RawFd, fd);
+            // The filter is already active and traps sendmsg in the socket scope, with nobody
+            // serving it yet; sendmmsg is never intercepted.
+            let mut batch = libc::mmsghdr {
+                msg_hdr: message,
+                msg_len: 0,
+            };
+            if libc::sendmmsg(socket, &mut batch, 1, 0) < 1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+
+    /// The listener the child sent, or `None` when it exited (or failed to exec) first.
+    fn receive_listener(socket: &OwnedFd) -> Option<OwnedFd> {
+        let mut byte = 0u8;
+        let mut iov = libc::iovec {
+            iov_base: &mut byte as *mut u8 as *mut libc::c_void,
+            iov_len: 1,
+        };
+        let mut control = ControlBuffer([0; 32]);
+        // SAFETY: as in `send_fd`; a received descriptor becomes ours.
+        unsafe {
+            let mut message: libc::msghdr = std::mem::zeroed();
+            message.msg_iov = &mut iov;
+            message.msg_iovlen = 1;
+            message.msg_control = control.0.as_mut_ptr() as *mut libc::c_void;
+            message.msg_controllen = control.0.len() as _;
+            if libc::recvmsg(socket.as_raw_fd(), &mut message, libc::MSG_CMSG_CLOEXEC) <= 0 {
+                return None;
+            }
+            let header = libc::CMSG_FIRSTHDR(&message);
+            if header.is_null() || (*header).cmsg_type != libc::SCM_RIGHTS {
+                return None;
+            }
+            let fd = std::ptr::read_unaligned(libc::CMSG_DATA(header) as *const RawFd);
+            Some(OwnedFd::from_raw_fd(fd))
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Call {
+        Read,
+        Write,
+        Open,
+        Connect,
+        SendTo,
+        RecvFrom,
+        SendMsg,
+        RecvMsg,
+    }
+
+    impl Call {
+        fn name(self) -> &'static str {
+            match self {
+                Call::Read => "read",
+                Call::Write => "write",
+                Call::Open => "open",
+                Call::Connect => "connect",
+                Call::SendTo => "sendto",
+                Call::RecvFrom => "recvfrom",
+                Call::SendMsg => "sendmsg",
+                Call::RecvMsg => "recvmsg",
+            }
+        }
+
+        fn sends(self) -> bool {
+            matches!(self, Call::Write | Call::SendTo | Call::SendMsg)
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum Fault {
+        Error(i32),
+        Partial,
+        Stall,
+    }
+
+    fn errno_name(errno: i32) -> &'static str {
+        match errno {
+            libc::EIO => "EIO",
+            libc::EAGAIN => "EAGAIN",
+            libc::ECONNREFUSED => "ECONNREFUSED",
+            libc::ETIMEDOUT => "ETIMEDOUT",
+            libc::ECONNRESET => "ECONNRESET",
+            libc::EPIPE => "EPIPE",
+            _ => "error",
+        }
+    }
+
+    /// How to answer one notification: now, or once a stall has run out.
+    enum Reply {
+        Now(libc::seccomp_notif_resp),
+        At(Instant, libc::seccomp_notif_resp),
+    }
+
+    struct Server {
+        scope: Scope,
+        rate: f64,
+        stall: Duration,
+        rng: SeededRng,
+        started: Instant,
+        outcome: Outcome,
+    }
+
+    impl Server {
+        fn new(scope: Scope, rate: f64, stall: Duration, seed: u64, started: Instant) -> Self {
+            let label = match scope {
+                Scope::Files => "io-faults",
+                Scope::Sockets => "net-faults",
+            };
+            Self {
+                scope,
+                rate,
+                stall,
+                rng: SeededRng::derive(seed, label),
+                started,
+                outcome: Outcome::default(),
+            }
+        }
+
+        fn serve(mut self, listener: &OwnedFd, stop: &AtomicBool) -> Outcome {
+            let fd = listener.as_raw_fd();
+            let mut stalled: Vec<(Instant, libc::seccomp_notif_resp)> = Vec::new();
+            while !stop.load(Ordering::SeqCst) {
+                let now = Instant::now();
+                let wait = stalled
+                    .iter()
+                    .map(|(due, _)| due.saturating_duration_since(now))
+                    .min()
+                    .unwrap_or(POLL_INTERVAL)
+                    .min(POLL_INTERVAL);
+                let mut poll = libc::pollfd {
+                    fd,
+                    events: libc::POLLIN,
+                    revents: 0,
+                };
+                // SAFETY: polling one descriptor we own.
+                let ready = unsafe { libc::poll(&mut poll, 1, wait.as_millis() as libc::c_int) };
+                let now = Instant::now();
+                stalled.retain(|(due, response)| {
+                    if *due > now {
+                        return true;
+                    }
+                    respond(fd, response);
+                    false
+                });
+                if ready <= 0 {
+                    continue;
+                }
+                if poll.revents & libc::POLLIN == 0 {
+                    // POLLHUP: every process under the filter has exited.
+                    break;
+                }
+                // SAFETY: the kernel fills the zeroed request; a failure leaves it unused.
+                let mut request: libc::seccomp_notif = unsafe { std::mem::zeroed() };
+                if unsafe { libc::ioctl(fd, NOTIF_RECV as _, &mut request) } < 0 {
+                    // The caller died before we read it, or a signal interrupted us.
+                    continue;
+                }
+                match self.handle(fd, &request) {
+                    Reply::Now(response) => respond(fd, &response),
+                    Reply::At(due, response) => stalled.push((due, response)),
+                }
+            }
+            self.outcome
+        }
+
+        fn handle(&mut self, listener: RawFd, request: &libc::seccomp_notif) -> Reply {
+            let mut response = libc::seccomp_notif_resp {
+                id: request.id,
+                val: 0,
+                error: 0,
+                flags: libc::SECCOMP_USER_NOTIF_FLAG_CONTINUE as u32,
+            };
+            let args = request.data.args;
+            let tid = request.pid as libc::pid_t;
+            let Some((call, target)) = self.classify(tid, request.data.nr as libc::c_long, &args)
+            else {
+                return Reply::Now(response);
+            };
+            // Another thread may have reused the caller's memory since it trapped.
+            // SAFETY: ID_VALID only reads the id.
+            if unsafe { libc::ioctl(listener, NOTIF_ID_VALID as _, &request.id) } < 0 {
+                return Reply::Now(response);
+            }
+            self.outcome.intercepted += 1;
+            if self.rng.unit() >= self.rate {
+                return Reply::Now(response);
+            }
+
+            let choices: &[Fault] = match (self.scope, call) {
+                (Scope::Files, Call::Read) => &[
+                    Fault::Error(libc::EIO),
+                    Fault::Error(libc::EAGAIN),
+                    Fault::Partial,
+                ],
+                (Scope::Files, Call::Write) => {
+                    &[Fault::Error(libc::EIO), Fault::Error(libc::EAGAIN)]
+                }
+                (Scope::Files, _) => &[Fault::Error(libc::EIO)],
+                (Scope::Sockets, Call::Connect) => &[
+                    Fault::Error(libc::ECONNREFUSED),
+                    Fault::Error(libc::ETIMEDOUT),
+                    Fault::Stall,
+                ],
+                (Scope::Sockets, call) if call.sends() => &[
+                    Fault::Error(libc::ECONNRESET),
+                    Fault::Error(libc::EPIPE),
+                    Fault::Partial,
+                    Fault::Stall,
+                ],
+                (Scope::Sockets, _) => {
+                    &[Fault::Error(libc::ECONNRESET), Fault::Partial, Fault::Stall]
+                }
+            };
+            let fault = choices[self.rng.below(choices.len())];
+            let (kind, description, reply) = match fault {
+                Fault::Stall => {
+                    let due = Instant::now() + self.stall;
+                    let description = format!("stall ({} ms)", self.stall.as_millis());
+                    ("stall".to_string(), description, Reply::At(due, response))
+                }
+                Fault::Partial => {
+                    let served = self.partial(tid, call, &args);
+                    response.flags = 0;
+                    match served {
+                        Some((served, asked)) => {
+                            response.val = served as i64;
+                            let kind = match (self.scope, call.sends()) {
+                                (Scope::Files, _) => "short read",
+                                (Scope::Sockets, true) => "partial write",
+                                (Scope::Sockets, false) => "partial read",
+                            };
+                            let description = format!("{} ({} of {} bytes)", kind, served, asked);
+                            (kind.to_string(), description, Reply::Now(response))
+                        }
+                        None => {
+                            // Fall back to the scope's plain error.
+                            let Fault::Error(errno) = choices[0] else {
+                                unreachable!("every scope lists an error first")
+                            };
+                            response.error = -errno;
+                            let name = errno_name(errno).to_string();
+                            (name.clone(), name, Reply::Now(response))
+                        }
+                    }
+                }
+                Fault::Error(errno) => {
+                    response.flags = 0;
+                    response.error = -errno;
+                    let name = errno_name(errno).to_string();
+                    (name.clone(), name, Reply::Now(response))
+                }
+            };
+
+            self.outcome.injected += 1;
+            *self.outcome.faults.entry(kind).or_default() += 1;
+            if self.outcome.recent.len() == RECENT_FAULTS {
+                self.outcome.recent.pop_front();
+            }
+            self.outcome.recent.push_back(InjectedFault {
+                at: self.started.elapsed(),
+                syscall: call.name().to_string(),
+                path: target,
+                fault: description,
+            });
+            reply
+        }
+
+        /// The call and what it acts on, for calls this scope may fail.
+        fn classify(
+            &self,
+            tid: libc::pid_t,
+            nr: libc::c_long,
+            args: &[u64; 6],
+        ) -> Option<(Call, String)> {
+            let call = match nr {
+                libc::SYS_read => Call::Read,
+                libc::SYS_write => Call::Write,
+                libc::SYS_openat => Call::Open,
+                #[cfg(target_arch = "x86_64")]
+                libc::SYS_open => Call::Open,
+                libc::SYS_connect => Call::Connect,
+                libc::SYS_sendto => Call::SendTo,
+                libc::SYS_recvfrom => Call::RecvFrom,
+                libc::SYS_sendmsg => Call::SendMsg,
+                libc::SYS_recvmsg => Call::RecvMsg,
+                _ => return None,
+            };
+            let target = match (self.scope, call) {
+                (Scope::Files, Call::Open) => {
+                    let address = if nr == libc::SYS_openat {
+                        args[1]
+                    } else {
+                        args[0]
+                    };
+                    string_at(tid, address)?
+                }
+                (Scope::Files, _) => fd_link(tid, args[0]).filter(|path| path.starts_with('/'))?,
+                (Scope::Sockets, Call::Connect) => {
+                    fd_link(tid, args[0]).filter(|link| link.starts_with("socket:"))?;
+                    sockaddr_at(tid, args[1], args[2] as usize)?
+                }
+                (Scope::Sockets, _) => {
+                    fd_link(tid, args[0]).filter(|link| link.starts_with("socket:"))?
+                }
+            };
+            if target.starts_with('/') && exempt(&target) {
+                return None;
+            }
+            Some((call, target))
+        }
+
+        /// Carry out at most half of the caller's read or write. `None` when the call cannot
+        /// be split (message calls, addressed or peeking sends and receives) or it failed.
+        fn partial(&self, tid: libc::pid_t, call: Call, args: &[u64; 6]) -> Option<(usize, usize)> {
+            let socket = self.scope == Scope::Sockets;
+            if matches!(call, Call::SendTo | Call::RecvFrom)
+                && (args[4] != 0 || args[3] as i32 & (libc::MSG_PEEK | libc::MSG_OOB) != 0)
+            {
+                return None;
+            }
+            if !matches!(
+                call,
+                Call::Read | Call::Write | Call::SendTo | Call::RecvFrom
+            ) {
+                return None;
+            }
+            let (fd, address, count) = (args[0], args[1], args[2] as usize);
+            if count < 2 {
+                return None;
+            }
+            let file = borrow_fd(tid, fd)?;
+            let mut buffer = vec![0u8; (count / 2).min(MAX_PARTIAL)];
+            let memory = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(format!("/proc/{}/mem", tid))
+                .ok()?;
+            let flags = libc::MSG_DONTWAIT | libc::MSG_NOSIGNAL;
+            let done = if call.sends() {
+                memory.read_exact_at(&mut buffer, address).ok()?;
+                // SAFETY: sending from a buffer we own on a descriptor we own.
+                unsafe {
+                    libc::send(
+                        file.as_raw_fd(),
+                        buffer.as_ptr() as *const libc::c_void,
+                        buffer.len(),
+                        flags,
+                    )
+                }
+            } else if socket {
+                // Never block the supervisor on an idle peer.
+                // SAFETY: receiving into a buffer we own on a descriptor we own.
+                unsafe {
+                    libc::recv(
+                        file.as_raw_fd(),
+                        buffer.as_mut_ptr() as *mut libc::c_void,
+                        buffer.len(),
+                        libc::MSG_DONTWAIT,
+                    )
+                }
+            } else {
+                // The duplicate shares the target's file offset, so a plain read advances it.
+                io::Read::read(&mut &file, &mut buffer).map_or(-1, |n| n as isize)
+            };
+            if done < 0 {
+                return None;
+            }
+            let done = done as usize;
+            if !call.sends() {
+                memory.write_all_at(&buffer[..done], address).ok()?;
+            }
+            Some((done, count))
+        }
+    }
+
+    fn respond(listener: RawFd, response: &libc::seccomp_notif_resp) {
+        // SAFETY: the response outlives the call; ENOENT means the caller is gone.
+        unsafe {
+            libc::ioctl(listener, NOTIF_SEND as _, response);
+        }
+    }
+
+    /// What descriptor `fd` of thread `tid` refers to; pipes and sockets come back as
+    /// `pipe:[...]` and `socket:[...]`.
+    fn fd_link(tid: libc::pid_t, fd: u64) -> Option<String> {
+        let link = std::fs::read_link(format!("/proc/{}/fd/{}", tid, fd as i32)).ok()?;
+        Some(link.to_string_lossy().to_string())
+    }
+
+    /// NUL-terminated string at `address` in the memory of thread `tid`.
+    fn string_at(tid: libc::pid_t, address: u64) -> Option<String> {
+        let memory = File::open(format!("/proc/{}/mem", tid)).ok()?;
+        let mut buffer = vec![0u8; libc::PATH_MAX as usize];
+        let read = memory.read_at(&mut buffer, address).ok()?;
+        let end = buffer[..read].iter().position(|&b| b == 0)?;
+        Some(String::from_utf8_lossy(&buffer[..end]).to_string())
+    }
+
+    /// IPv4 or IPv6 address at `address` in the memory of thread `tid`; other families are
+    /// not the target's network traffic.
+    fn sockaddr_at(tid: libc::pid_t, address: u64, len: usize) -> Option<String> {
+        let memory = File::open(format!("/proc/{}/mem", tid)).ok()?;
+        let mut buffer = vec![0u8; len.min(std::mem::size_of::<libc::sockaddr_storage>())];
+        memory.read_exact_at(&mut buffer, address).ok()?;
+        let family = u16::from_ne_bytes([*buffer.first()?, *buffer.get(1)?]) as i32;
+        let port = u16::from_be_bytes([*buffer.get(2)?, *buffer.get(3)?]);
+        let ip: std::net::IpAddr = match family {
+            libc::AF_INET => <[u8; 4]>::try_from(buffer.get(4..8)?).ok()?.into(),
+            libc::AF_INET6 => <[u8; 16]>::try_from(buffer.get(8..24)?).ok()?.into(),
+            _ => return None,
+        };
+        Some(std::net::SocketAddr::new(ip, port).to_string())
+    }
+
+    /// A duplicate of descriptor `fd` of thread `tid`, sharing its open file.
+    fn borrow_fd(tid: libc::pid_t, fd: u64) -> Option<File> {
+        let status = std::fs::read_to_string(format!("/proc/{}/status", tid)).ok()?;
+        let tgid: libc::pid_t = status
+            .lines()
+            .find_map(|line| line.strip_prefix("Tgid:"))?
+            .trim()
+            .parse()
+            .ok()?;
+        // SAFETY: pidfd_open and pidfd_getfd return new descriptors that we own.
+        unsafe {
+            let pidfd = libc::syscall(libc::SYS_pidfd_open, tgid, 0);
+            if pidfd < 0 {
+                return None;
+            }
+            let pidfd = OwnedFd::from_raw_fd(pidfd as RawFd);
+            let target = libc::syscall(libc::SYS_pidfd_getfd, pidfd.as_raw_fd(), fd as i32, 0);
+            if target < 0 {
+                return None;
+            }
+            Some(File::from_raw_fd(target as RawFd))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rates_and_exempt_paths() {
+        let files = Scope::Files;
+        assert!(files.rate_for(IntensityLevel::Light) < files.rate_for(IntensityLevel::Heavy));
+        assert!(files.rate_for(IntensityLevel::Extreme) <= 0.1);
+        assert!(
+            Scope::Sockets.rate_for(IntensityLevel::Medium)
+                > files.rate_for(IntensityLevel::Medium)
+        );
+        assert!(stall_for(IntensityLevel::Extreme) <= Duration::from_secs(2));
+        assert!(exempt("/usr/lib/x86_64-linux-gnu/libc.so.6"));
+        assert!(exempt("/etc/ld.so.cache"));
+        assert!(exempt("/dev/null"));
+        assert!(!exempt("/tmp/data.json"));
+        assert!(!exempt("/home/user/.sonar"));
+    }
+
+    #[cfg(all(
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    #[test]
+    #[ignore = "needs seccomp user notification"]
+    fn test_file_reads_fail_in_the_target() {
+        use std::process::Stdio;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let data = dir.path().join("data.txt");
+        std::fs::write(&data, "payload\n").unwrap();
+        let mut command = Command::new("cat");
+        command.env("LC_ALL", "C");
+        command
+            .arg(&data)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let config = IoFaultConfig { rate: Some(1.0) };
+        let (child, interceptor) =
+            Interceptor::spawn(command, Scope::Files, &config, IntensityLevel::Medium, 3)
+                .unwrap();
+        let output = child.wait_with_output().unwrap();
+        let (report, faults) = interceptor.finish();
+        assert!(!output.status.success());
+        assert!(report.injected >= faults.len() as u64);
+        assert_eq!(report.faults.values().sum::<u64>(), report.injected);
+        let fault = faults
+            .iter()
+            .find(|fault| fault.path == data.to_string_lossy())
+            .unwrap();
+        assert!(["open", "read"].contains(&fault.syscall.as_str()));
+    }
+}
@@ -0,0 +1,192 @@
+/* SPDX-License-Identifier: PMPL-1.0-or-later */
+
+/*
+ * LD_PRELOAD shim for the memory axis (`--malloc-faults`): malloc, calloc and realloc
+ * fail with ENOMEM at a seeded rate so allocation-failure paths actually run.
+ *
+ *   PANIC_ATTACK_MALLOC_FAIL_RATE  probability per call, 0..1
+ *   PANIC_ATTACK_MALLOC_SEED       seed; the n-th call fails the same way every run
+ *   PANIC_ATTACK_MALLOC_REPORT     private directory; every process the shim loads into,
+ *                                  forked children included, creates its own file there
+ *                                  holding "calls=N injected=M", rewritten on every injected
+ *                                  failure and at exit so it survives a crash
+ *
+ * Built by panic-attack with `cc -shared -fPIC`; glibc only (uses the __libc_* entry points,
+ * which avoids calling dlsym from inside malloc).
+ */
+
+#include <errno.h>
+#include <fcntl.h>
+#include <pthread.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <unistd.h>
+
+extern void *__libc_malloc(size_t size);
+extern void *__libc_calloc(size_t count, size_t size);
+extern void *__libc_realloc(void *ptr, size_t size);
+
+static uint64_t threshold;
+static uint64_t seed;
+static uint64_t calls;
+static uint64_t injected;
+static int report_fd = -1;
+/* Copied out of the environment, which the target may rewrite before it forks. */
+static char report_dir[4040];
+static int ready;
+
+static uint64_t parse_u64(const char *text) {
+    uint64_t value = 0;
+    while (text && *text >= '0' && *text <= '9') {
+        value = value * 10 + (uint64_t)(*text++ - '0');
+    }
+    return value;
+}
+
+/* Rate as a decimal fraction ("0.0025") scaled to the full u64 range, without libm.
+ * Negative rates clamp to 0 and rates of 1 or more to UINT64_MAX. */
+static uint64_t parse_rate(const char *text) {
+    if (!text || *text == '-') {
+        return 0;
+    }
+    for (; *text && *text != '.'; text++) {
+        if (*text >= '1' && *text <= '9') {
+            return UINT64_MAX;
+        }
+    }
+    if (*text == '.') {
+        text++;
+    }
+    uint64_t scale = UINT64_MAX / 10;
+    uint64_t value = 0;
+    for (; *text >= '0' && *text <= '9' && scale; text++) {
+        value += scale * (uint64_t)(*text - '0');
+        scale /= 10;
+    }
+    return value;
+}
+
+static size_t format_u64(char *out, uint64_t value) {
+    char digits[20];
+    size_t n = 0;
+    do {
+        digits[n++] = (char)('0' + value % 10);
+        value /= 10;
+    } while (value);
+    for (size_t i = 0; i < n; i++) {
+        out[i] = digits[n - 1 - i];
+    }
+    return n;
+}
+
+static void write_report(void) {
+    if (report_fd < 0) {
+        return;
+    }
+    char line[64];
+    size_t len = 0;
+    const char *a = "calls=";
+    const char *b = " injected=";
+    while (*a) {
+        line[len++] = *a++;
+    }
+    len += format_u64(line + len, __atomic_load_n(&calls, __ATOMIC_RELAXED));
+    while (*b) {
+        line[len++] = *b++;
+    }
+    len += format_u64(line + len, __atomic_load_n(&injected, __ATOMIC_RELAXED));
+    line[len++] = '\n';
+    if (pwrite(report_fd, line, len, 0) == (ssize_t)len) {
+        (void)ftruncate(report_fd, (off_t)len);
+    }
+}
+
+/*
+ * Create "<dir>/<pid>-<n>" for this process. O_EXCL|O_NOFOLLOW never follows or reuses
+ * an existing entry; the suffix moves past files an earlier image of the same pid (before
+ * an exec) left behind.
+ */
+static void open_report(void) {
+    if (!report_dir[0]) {
+        return;
+    }
+    char path[4096];
+    size_t dir_len = 0;
+    while (report_dir[dir_len]) {
+        dir_len++;
+    }
+    for (uint64_t attempt = 0; attempt < 64; attempt++) {
+        size_t len = 0;
+        for (size_t i = 0; i < dir_len; i++) {
+            path[len++] = report_dir[i];
+        }
+        path[len++] = '/';
+        len += format_u64(path + len, (uint64_t)getpid());
+        path[len++] = '-';
+        len += format_u64(path + len, attempt);
+        path[len] = '\0';
+        report_fd = open(path, O_WRONLY | O_CREAT | O_EXCL | O_NOFOLLOW | O_CLOEXEC, 0600);
+        if (report_fd >= 0 || errno != EEXIST) {
+            return;
+        }
+    }
+}
+
+/* A forked child counts its own calls into its own file; the parent keeps its counts. */
+static void forked_child(void) {
+    if (report_fd >= 0) {
+        close(report_fd);
+        report_fd = -1;
+    }
+    __atomic_store_n(&calls, 0, __ATOMIC_RELAXED);
+    __atomic_store_n(&injected, 0, __ATOMIC_RELAXED);
+    open_report();
+}
+
+__attribute__((constructor)) static void setup(void) {
+    threshold = parse_rate(getenv("PANIC_ATTACK_MALLOC_FAIL_RATE"));
+    seed = parse_u64(getenv("PANIC_ATTACK_MALLOC_SEED"));
+    const char *dir = getenv("PANIC_ATTACK_MALLOC_REPORT");
+    for (size_t i = 0; dir && dir[i] && i + 1 < sizeof(report_dir); i++) {
+        report_dir[i] = dir[i];
+        report_dir[i + 1] = '\0';
+    }
+    open_report();
+    pthread_atfork(NULL, NULL, forked_child);
+    ready = 1;
+}
+
+__attribute__((destructor)) static void finish(void) {
+    write_report();
+}
+
+/* SplitMix64 over the call index, so failures depend only on the seed and call order. */
+static int should_fail(void) {
+    if (!ready || !threshold) {
+        return 0;
+    }
+    uint64_t z = seed + (__atomic_fetch_add(&calls, 1, __ATOMIC_RELAXED) + 1) * 0x9e3779b97f4a7c15ULL;
+    z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9ULL;
+    z = (z ^ (z >> 27)) * 0x94d049bb133111ebULL;
+    z ^= z >> 31;
+    if (z >= threshold) {
+        return 0;
+    }
+    __atomic_fetch_add(&injected, 1, __ATOMIC_RELAXED);
+    write_report();
+    errno = ENOMEM;
+    return 1;
+}
+
+void *malloc(size_t size) {
+    return should_fail() ? NULL : __libc_malloc(size);
+}
+
+void *calloc(size_t count, size_t size) {
+    return should_fail() ? NULL : __libc_calloc(count, size);
+}
+
+void *realloc(void *ptr, size_t size) {
+    return should_fail() ? NULL : __libc_realloc(ptr, size);
+}
@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Allocation-failure injection for the memory axis (`--malloc-faults`)
+//!
+//! Memory stress rarely makes the target's own allocations fail, so its out-of-memory
+//! handling never runs. With `--malloc-faults` the memory axis preloads a small shim
+//! (`malloc_faults.c`, embedded here) whose malloc, calloc and realloc return NULL at a
+//! seeded rate. The shim is compiled once with `$CC` (default `cc`) into the user cache
+//! directory and reused while its source is unchanged. Linux/glibc only.
+//!
+//! Whatever gets preloaded runs inside the target, so the shim is only built in, and reused
+//! from, a directory that belongs to the current user and that nobody else can write to.
+//!
+//! Every process the shim loads into, forked children included, reports its own counts
+//! into a private temporary directory, and the run's report is their sum.
+
+use crate::storage::write_atomic;
+use crate::types::{IntensityLevel, MallocFaultConfig, MallocFaultReport};
+use anyhow::{anyhow, Context, Result};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+const SOURCE: &str = include_str!("malloc_faults.c");
+
+/// Whether the shim can be preloaded on this platform.
+pub fn available() -> bool {
+    cfg!(all(target_os = "linux", target_env = "gnu"))
+}
+
+/// Failure probability per allocation when `--malloc-fail-rate` is not given.
+pub fn rate_for(intensity: IntensityLevel) -> f64 {
+    (0.0005 * intensity.multiplier()).clamp(0.0001, 0.05)
+}
+
+/// Path of the compiled shim, building it on first use.
+pub fn shim() -> Result<PathBuf> {
+    let dir = match dirs::cache_dir() {
+        Some(cache) => cache.join("panic-attack"),
+        // The shared temporary directory gets a per-user name; `build_in` checks it is ours.
+        None => std::env::temp_dir().join(format!("panic-attack-{}", private::uid())),
+    };
+    let cc = std::env::var_os("CC").unwrap_or_else(|| OsString::from("cc"));
+    build_in(&dir, &cc)
+}
+
+fn build_in(dir: &Path, cc: &OsString) -> Result<PathBuf> {
+    let hash = blake3::hash(SOURCE.as_bytes()).to_hex();
+    let name = format!("malloc-faults-{}", &hash[..16]);
+    let library = dir.join(format!("{}.so", name));
+    private::create_dir(dir)?;
+    if library.is_file() {
+        private::ensure(&library)?;
+        return Ok(library);
+    }
+    let source = dir.join(format!("{}.c", name));
+    write_atomic(&source, SOURCE).with_context(|| format!("writing {}", source.display()))?;
+    // Build beside the final name and rename, so concurrent runs never load a partial file.
+    let partial = dir.join(format!("{}.so.{}", name, std::process::id()));
+    let output = Command::new(cc)
+        .args(["-shared", "-fPIC", "-pthread", "-O2", "-o"])
+        .arg(&partial)
+        .arg(&source)
+        .output()
+        .with_context(|| {
+            format!(
+                "running {} to build the malloc fault shim",
+                cc.to_string_lossy()
+            )
+        })?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(&partial);
+        return Err(anyhow!(
+            "building the malloc fault shim with {} failed: {}",
+            cc.to_string_lossy(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    std::fs::rename(&partial, &library)
+        .with_context(|| format!("installing {}", library.display()))?;
+    Ok(library)
+}
+
+/// Ownership checks on the shim directory and library.
+mod private {
+    use anyhow::{anyhow, Context, Result};
+    use std::path::Path;
+
+    #[cfg(unix)]
+    pub fn uid() -> u32 {
+        // SAFETY: geteuid(2) takes no arguments and always succeeds.
+        unsafe { libc::geteuid() }
+    }
+
+    #[cfg(not(unix))]
+    pub fn uid() -> u32 {
+        0
+    }
+
+    /// Create `dir` (mode 0700) unless it exists, then check it is private.
+    pub fn create_dir(dir: &Path) -> Result<()> {
+        let mut builder = std::fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder
+            .create(dir)
+            .with_context(|| format!("creating {}", dir.display()))?;
+        ensure(dir)
+    }
+
+    /// Fail unless `path` is no symlink, belongs to us and is not group- or world-writable.
+    #[cfg(unix)]
+    pub fn ensure(path: &Path) -> Result<()> {
+        use std::os::unix::fs::MetadataExt;
+        let meta = std::fs::symlink_metadata(path)
+            .with_context(|| format!("inspecting {}", path.display()))?;
+        if meta.file_type().is_symlink() || meta.uid() != uid() || meta.mode() & 0o022 != 0 {
+            return Err(anyhow!(
+                "{} is not private to this user (owner uid {}, mode {:o}); refusing to \
+                 preload the malloc fault shim from it",
+                path.display(),
+                meta.uid(),
+                meta.mode() & 0o7777
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    pub fn ensure(_path: &Path) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// One run's injection: the environment that preloads the shim and the directory it
+/// reports to.
+pub struct Injection {
+    library: PathBuf,
+    rate: f64,
+    seed: u64,
+    reports: TempDir,
+}
+
+impl Injection {
+    pub fn prepare(
+        config: &MallocFaultConfig,
+        intensity: IntensityLevel,
+        seed: u64,
+    ) -> Result<Self> {
+        if !available() {
+            return Err(anyhow!("malloc fault injection needs Linux with glibc"));
+        }
+        Self::with_library(shim()?, config, intensity, seed)
+    }
+
+    fn with_library(
+        library: PathBuf,
+        config: &MallocFaultConfig,
+        intensity: IntensityLevel,
+        seed: u64,
+    ) -> Result<Self> {
+        let reports = tempfile::Builder::new()
+            .prefix("panic-attack-malloc-")
+            .tempdir()
+            .context("creating the malloc fault report directory")?;
+        Ok(Self {
+            library,
+            rate: config.rate.unwrap_or_else(|| rate_for(intensity)),
+            seed,
+            reports,
+        })
+    }
+
+    /// Variables to set on the target; an existing `LD_PRELOAD` is kept after the shim.
+    pub fn env(&self) -> Vec<(String, String)> {
+        let mut preload = self.library.to_string_lossy().to_string();
+        if let Some(existing) = std::env::var_os("LD_PRELOAD").filter(|v| !v.is_empty()) {
+            preload.push(':');
+            preload.push_str(&existing.to_string_lossy());
+        }
+        vec![
+            ("LD_PRELOAD".to_string(), preload),
+            (
+                "PANIC_ATTACK_MALLOC_FAIL_RATE".to_string(),
+                format!("{:.9}", self.rate),
+            ),
+            (
+                "PANIC_ATTACK_MALLOC_SEED".to_string(),
+                self.seed.to_string(),
+            ),
+            (
+                "PANIC_ATTACK_MALLOC_REPORT".to_string(),
+                self.reports.path().to_string_lossy().to_string(),
+            ),
+        ]
+    }
+
+    /// Counts the shim reported, summed over every process; zero when the target never
+    /// loaded it.
+    pub fn finish(self) -> MallocFaultReport {
+        let texts: Vec<String> = std::fs::read_dir(self.reports.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| std::fs::read_to_string(entry.path()).ok())
+            .collect();
+        let count = |key: &str| -> u64 {
+            texts
+                .iter()
+                .filter_map(|text| {
+                    text.split_whitespace()
+                        .find_map(|field| field.strip_prefix(key)?.strip_prefix('='))
+                        .and_then(|value| value.parse::<u64>().ok())
+                })
+                .sum()
+        };
+        MallocFaultReport {
+            rate: self.rate,
+            calls: count("calls"),
+            injected: count("injected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_scales_with_intensity() {
+        assert!(rate_for(IntensityLevel::Light) < rate_for(IntensityLevel::Heavy));
+        assert!(rate_for(IntensityLevel::Extreme) <= 0.05);
+        let config = MallocFaultConfig { rate: Some(0.5) };
+        let injection =
+            Injection::with_library(PathBuf::from("shim.so"), &config, IntensityLevel::Light, 9)
+                .unwrap();
+        let env = injection.env();
+        assert!(env[0].1.starts_with("shim.so"));
+        assert_eq!(env[1].1, "0.500000000");
+        assert_eq!(env[2].1, "9");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_shim_is_only_reused_from_private_files() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::TempDir::new().unwrap();
+        let shared = dir.path().join("shared");
+        std::fs::create_dir(&shared).unwrap();
+        std::fs::set_permissions(&shared, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let err = build_in(&shared, &OsString::from("cc")).unwrap_err();
+        assert!(format!("{:#}", err).contains("not private"), "{:#}", err);
+
+        // A library anyone could have written is not preloaded either.
+        let hash = blake3::hash(SOURCE.as_bytes()).to_hex();
+        let planted = dir.path().join(format!("malloc-faults-{}.so", &hash[..16]));
+        std::fs::write(&planted, b"").unwrap();
+        std::fs::set_permissions(&planted, std::fs::Permissions::from_mode(0o666)).unwrap();
+        let err = build_in(dir.path(), &OsString::from("cc")).unwrap_err();
+        assert!(format!("{:#}", err).contains("not private"), "{:#}", err);
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    #[test]
+    fn test_shim_fails_allocations_in_the_target() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let Ok(library) = build_in(dir.path(), &OsString::from("cc")) else {
+            // No C compiler on this host.
+            return;
+        };
+        assert_eq!(
+            build_in(dir.path(), &OsString::from("cc")).unwrap(),
+            library
+        );
+
+        let config = MallocFaultConfig { rate: Some(1.0) };
+        let injection =
+            Injection::with_library(library.clone(), &config, IntensityLevel::Medium, 1).unwrap();
+        // With every allocation failing, sort cannot even set up its buffers.
+        let output = Command::new("sort")
+            .arg("/dev/null")
+            .envs(injection.env())
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let report = injection.finish();
+        assert_eq!(report.rate, 1.0);
+        assert!(report.injected > 0);
+        assert_eq!(report.injected, report.calls);
+
+        // The shell and both sorts (forked, then exec'd) each count their own calls.
+        let config = MallocFaultConfig {
+            rate: Some(0.000001),
+        };
+        let calls = |script: &str| {
+            let injection =
+                Injection::with_library(library.clone(), &config, IntensityLevel::Medium, 1)
+                    .unwrap();
+            Command::new("sh")
+                .args(["-c", script])
+                .envs(injection.env())
+                .output()
+                .unwrap();
+            injection.finish().calls
+        };
+        let one = calls("sort /dev/null");
+        let both = calls("sort /dev/null; sort /dev/null");
+        assert!(one > 0);
+        assert!(
+            both >= 2 * one,
+            "{} calls for two sorts, {} for one",
+            both,
+            one
+        );
+    }
+}
@@ -2,21 +2,42 @@
 
 //! Attack orchestration module
 
+pub mod budget;
+pub mod capability;
+pub mod checkpoint;
+pub mod custom;
+pub mod environment;
 pub mod executor;
+pub mod explore;
+pub mod gpu;
+pub mod hang;
+pub mod io_faults;
+pub mod malloc_faults;
 pub mod profile;
+pub mod progress;
+pub mod provenance;
+pub mod rr;
+pub mod schedule;
 pub mod strategies;
+pub mod telemetry;
 
+use crate::error::AttackError;
 use crate::types::*;
 use anyhow::Result;
+use std::fs;
+use std::path::Path;
 
+pub use checkpoint::Checkpoint;
 pub use executor::AttackExecutor;
+pub use explore::explore_crashes;
 pub use profile::AttackProfile;
 
 /// Execute an attack against a target program
-pub fn execute_attack(config: AttackConfig) -> Result<Vec<AttackResult>> {
+pub fn execute_attack(config: AttackConfig) -> Result<Vec<AttackResult>, AttackError> {
     // Thin wrapper keeps CLI and library callers on the same execution surface.
+    check_targets(&config)?;
     let executor = AttackExecutor::new(config);
-    executor.execute()
+    Ok(executor.execute()?)
 }
 
 /// Execute an attack with pattern-aware strategy selection
@@ -24,8 +45,66 @@ pub fn execute_attack_with_patterns(
     config: AttackConfig,
     language: Language,
     frameworks: &[Framework],
-) -> Result<Vec<AttackResult>> {
+) -> Result<Vec<AttackResult>, AttackError> {
     // Pattern-aware mode enriches axis execution with language/framework heuristics.
+    check_targets(&config)?;
     let executor = AttackExecutor::with_patterns(config, language, frameworks);
-    executor.execute()
+    Ok(executor.execute()?)
+}
+
+/// Execute a pattern-aware attack that resumes from, and records into, `checkpoint`
+pub fn execute_attack_with_checkpoint(
+    config: AttackConfig,
+    language: Language,
+    frameworks: &[Framework],
+    checkpoint: &mut Checkpoint,
+) -> Result<Vec<AttackResult>, AttackError> {
+    check_targets(&config)?;
+    let executor = AttackExecutor::with_patterns(config, language, frameworks);
+    Ok(executor.execute_with_checkpoint(checkpoint)?)
+}
+
+/// Fail with a typed error when `program` cannot be run: missing, or not executable.
+pub fn check_target(program: &Path) -> Result<(), AttackError> {
+    // Bare names are looked up on PATH when spawned.
+    if program.components().count() == 1 && !program.is_file() {
+        let on_path = std::env::var_os("PATH").is_some_and(|paths| {
+            std::env::split_paths(&paths).any(|dir| dir.join(program).is_file())
+        });
+        return if on_path {
+            Ok(())
+        } else {
+            Err(AttackError::TargetMissing(program.to_path_buf()))
+        };
+    }
+    let metadata = fs::metadata(program).map_err(|err| AttackError::from_io(program, err))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.is_file() && metadata.permissions().mode() & 0o111 == 0 {
+            return Err(AttackError::PermissionDenied(program.to_path_buf()));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = metadata;
+    Ok(())
+}
+
+fn check_targets(config: &AttackConfig) -> Result<(), AttackError> {
+    custom::check(config)?;
+    config
+        .target_programs
+        .iter()
+        .try_for_each(|program| check_target(program))
+}
+
+/// Parse an axis name as the CLI and profiles spell it (`cpu`, `memory`, ...); any other
+/// well-formed name is a custom axis, which must be registered by the time it runs.
+pub fn parse_axis(raw: &str) -> Result<AttackAxis, AttackError> {
+    let raw = raw.trim();
+    match AttackAxis::builtin(raw) {
+        Some(axis) => Ok(axis),
+        None if AttackAxis::valid_custom_name(raw) => Ok(AttackAxis::Custom(raw.into())),
+        None => Err(AttackError::UnsupportedAxis(raw.to_string())),
+    }
 }
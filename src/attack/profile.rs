@@ -2,7 +2,12 @@
 
 //! Attack profile loading for custom argument sets.
 
-use crate::types::{AttackAxis, ProbeMode};
+use crate::schema::{self, SchemaKind};
+use crate::types::{
+    AdaptiveConfig, AttackAxis, CpuChaosConfig, CustomAxisSpec, DiskThrottleConfig, IntensitySpec,
+    IoFaultConfig, MallocFaultConfig, MemoryPressureConfig, ProbeMode, RedactionConfig,
+    SignalStormConfig,
+};
 use anyhow::{anyhow, Context, Result};
 use serde::Deserialize;
 use serde_json;
@@ -18,13 +23,43 @@ pub struct AttackProfile {
     #[serde(default)]
     pub axes: HashMap<AttackAxis, Vec<String>>,
     #[serde(default)]
+    pub custom_axes: Vec<CustomAxisSpec>,
+    /// Named points on the 0.0-1.0 intensity scale, usable wherever an intensity is given.
+    #[serde(default)]
+    pub intensity_presets: HashMap<String, f64>,
+    /// Per-axis intensity (preset name or scale value) overriding `--intensity`.
+    #[serde(default)]
+    pub axis_intensity: HashMap<AttackAxis, IntensitySpec>,
+    /// Per-axis duration in seconds overriding `--duration`.
+    #[serde(default)]
+    pub axis_duration_secs: HashMap<AttackAxis, u64>,
+    #[serde(default)]
     pub probe_mode: Option<ProbeMode>,
+    #[serde(default)]
+    pub signal_storm: Option<SignalStormConfig>,
+    #[serde(default)]
+    pub memory_pressure: Option<MemoryPressureConfig>,
+    #[serde(default)]
+    pub malloc_faults: Option<MallocFaultConfig>,
+    #[serde(default)]
+    pub io_faults: Option<IoFaultConfig>,
+    #[serde(default)]
+    pub net_faults: Option<IoFaultConfig>,
+    #[serde(default)]
+    pub disk_throttle: Option<DiskThrottleConfig>,
+    #[serde(default)]
+    pub cpu_chaos: Option<CpuChaosConfig>,
+    #[serde(default)]
+    pub adaptive: Option<AdaptiveConfig>,
+    #[serde(default)]
+    pub redaction: Option<RedactionConfig>,
 }
 
 impl AttackProfile {
     pub fn load(path: &Path) -> Result<Self> {
         let content = fs::read_to_string(path)
             .with_context(|| format!("reading attack profile {}", path.display()))?;
+        schema::check_document(SchemaKind::Profile, &content, path)?;
         // Extension-based dispatch is explicit to avoid ambiguous parsing behavior.
         match path.extension().and_then(|ext| ext.to_str()) {
             Some("json") => serde_json::from_str(&content)
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Liveness probes evaluated while a target runs (`--progress-check`)
+//!
+//! Long attacks can leave a target alive but wedged: still running, no longer doing work. A
+//! progress check says what counts as work (an output line matching a regex, a file whose
+//! modification time advances, or a TCP port that still accepts connections), and a target
+//! that shows none of it for the stall window is stopped and reported as stalled rather than
+//! as crashed or passed.
+
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often file and TCP checks are evaluated.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Connect timeout for a TCP check.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Parse a `--progress-check` value: `output:REGEX`, `file:PATH` or `tcp:HOST:PORT`.
+pub fn parse_check(spec: &str) -> Result<ProgressCheck> {
+    let (kind, target) = spec
+        .split_once(':')
+        .ok_or_else(|| anyhow!("progress check must be output:REGEX, file:PATH or tcp:ADDR"))?;
+    let kind = match kind.trim().to_ascii_lowercase().as_str() {
+        "output" => ProgressKind::Output,
+        "file" => ProgressKind::File,
+        "tcp" => ProgressKind::Tcp,
+        other => {
+            return Err(anyhow!(
+                "unknown progress check '{}' (output, file, tcp)",
+                other
+            ))
+        }
+    };
+    if target.is_empty() {
+        return Err(anyhow!("progress check '{}' has no target", spec));
+    }
+    let check = ProgressCheck {
+        kind,
+        target: target.to_string(),
+    };
+    // Surface bad regexes and addresses before any target is started.
+    ProgressMonitor::new(
+        &ProgressProbe {
+            check: check.clone(),
+            window: Duration::ZERO,
+        },
+        Instant::now(),
+    )?;
+    Ok(check)
+}
+
+/// Stall signature for a target that stopped making progress.
+pub fn signature(stall: &StallSignal) -> BugSignature {
+    let mut evidence = vec![format!(
+        "No progress on {:?} check '{}' for {:.1}s",
+        stall.check.kind,
+        stall.check.target,
+        stall.window.as_secs_f64()
+    )];
+    evidence.push(match stall.last_progress {
+        Some(at) => format!("Last progress {:.1}s into the run", at.as_secs_f64()),
+        None => "No progress observed during the run".to_string(),
+    });
+    BugSignature {
+        signature_type: SignatureType::Stall,
+        confidence: 0.7,
+        evidence,
+        location: None,
+        rule: Some("stall".to_string()),
+        calibration: None,
+    }
+}
+
+/// Tracks the last time a running target showed progress.
+pub struct ProgressMonitor {
+    probe: ProgressProbe,
+    regex: Option<Regex>,
+    start: Instant,
+    /// Milliseconds after `start` of the latest progress; `u64::MAX` until the first.
+    last_progress: AtomicU64,
+    poll: Mutex<PollState>,
+}
+
+struct PollState {
+    last_poll: Option<Instant>,
+    mtime: Option<SystemTime>,
+}
+
+impl ProgressMonitor {
+    pub fn new(probe: &ProgressProbe, start: Instant) -> Result<Self> {
+        let target = &probe.check.target;
+        let regex = match probe.check.kind {
+            ProgressKind::Output => Some(
+                Regex::new(target)
+                    .with_context(|| format!("invalid progress regex '{}'", target))?,
+            ),
+            ProgressKind::Tcp => {
+                target
+                    .to_socket_addrs()
+                    .with_context(|| format!("invalid progress address '{}'", target))?;
+                None
+            }
+            ProgressKind::File => None,
+        };
+        Ok(Self {
+            probe: probe.clone(),
+            regex,
+            start,
+            last_progress: AtomicU64::new(u64::MAX),
+            // A file that already exists only counts once it is modified again.
+            poll: Mutex::new(PollState {
+                last_poll: None,
+                mtime: modified(Path::new(target)),
+            }),
+        })
+    }
+
+    /// Whether output lines should be fed to [`Self::observe_line`].
+    pub fn watches_output(&self) -> bool {
+        self.regex.is_some()
+    }
+
+    pub fn observe_line(&self, line: &str) {
+        if self
+            .regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(line))
+        {
+            self.mark_progress();
+        }
+    }
+
+    /// Run the file or TCP check if it is due; returns the stall once the window has passed
+    /// without progress.
+    pub fn poll(&self) -> Option<StallSignal> {
+        let now = Instant::now();
+        {
+            let mut state = self.poll.lock().expect("progress poll lock");
+            let due = state
+                .last_poll
+                .is_none_or(|last| now.duration_since(last) >= POLL_INTERVAL);
+            if due {
+                state.last_poll = Some(now);
+                let progressed = match self.probe.check.kind {
+                    ProgressKind::Output => false,
+                    ProgressKind::File => {
+                        let mtime = modified(Path::new(&self.probe.check.target));
+                        let changed = mtime.is_some() && mtime != state.mtime;
+                        state.mtime = mtime;
+                        changed
+                    }
+                    ProgressKind::Tcp => tcp_accepts(&self.probe.check.target),
+                };
+                if progressed {
+                    self.mark_progress();
+                }
+            }
+        }
+
+        let elapsed = now.duration_since(self.start);
+        let last_progress = match self.last_progress.load(Ordering::SeqCst) {
+            u64::MAX => None,
+            millis => Some(Duration::from_millis(millis)),
+        };
+        let quiet_for = elapsed.saturating_sub(last_progress.unwrap_or_default());
+        (quiet_for >= self.probe.window).then(|| StallSignal {
+            check: self.probe.check.clone(),
+            window: self.probe.window,
+            detected_at: elapsed,
+            last_progress,
+        })
+    }
+
+    fn mark_progress(&self) {
+        let millis = self.start.elapsed().as_millis() as u64;
+        self.last_progress.store(millis, Ordering::SeqCst);
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|meta| meta.modified()).ok()
+}
+
+fn tcp_accepts(address: &str) -> bool {
+    address
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .is_some_and(|addr| TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn monitor(kind: ProgressKind, target: &str, window_ms: u64) -> ProgressMonitor {
+        let probe = ProgressProbe {
+            check: ProgressCheck {
+                kind,
+                target: target.to_string(),
+            },
+            window: Duration::from_millis(window_ms),
+        };
+        ProgressMonitor::new(&probe, Instant::now()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_check() {
+        let check = parse_check("output:request \\d+ done").unwrap();
+        assert_eq!(check.kind, ProgressKind::Output);
+        assert_eq!(check.target, "request \\d+ done");
+        assert_eq!(
+            parse_check("tcp:127.0.0.1:8080").unwrap().target,
+            "127.0.0.1:8080"
+        );
+        assert!(parse_check("output:(unclosed").is_err());
+        assert!(parse_check("http:localhost").is_err());
+        assert!(parse_check("file:").is_err());
+    }
+
+    #[test]
+    fn test_output_lines_reset_the_window() {
+        let monitor = monitor(ProgressKind::Output, "^tick", 150);
+        assert!(monitor.watches_output());
+        std::thread::sleep(Duration::from_millis(100));
+        monitor.observe_line("tock");
+        monitor.observe_line("tick 1");
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(monitor.poll().is_none());
+
+        std::thread::sleep(Duration::from_millis(100));
+        let stall = monitor.poll().expect("no tick for the whole window");
+        assert!(stall.last_progress.is_some());
+        assert_eq!(signature(&stall).signature_type, SignatureType::Stall);
+    }
+
+    #[test]
+    fn test_file_and_tcp_checks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let heartbeat = dir.path().join("heartbeat");
+        std::fs::write(&heartbeat, "0").unwrap();
+        let files = monitor(ProgressKind::File, heartbeat.to_str().unwrap(), 10_000);
+        files.poll();
+        assert_eq!(files.last_progress.load(Ordering::SeqCst), u64::MAX);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let tcp = monitor(ProgressKind::Tcp, &address, 10_000);
+        tcp.poll();
+        assert_ne!(tcp.last_progress.load(Ordering::SeqCst), u64::MAX);
+
+        drop(listener);
+        let closed = monitor(ProgressKind::Tcp, &address, 0);
+        let stall = closed.poll().expect("closed port never shows progress");
+        assert_eq!(stall.last_progress, None);
+    }
+}
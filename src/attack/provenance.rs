@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Target binary provenance: which build a report actually stressed.
+//!
+//! Two reports for `./server` only compare if they tested the same file, and a rebuild between
+//! runs is easy to miss. Every assault and ambush report records, per target, its SHA-256 and
+//! size, the ELF build-id when the binary carries a GNU build-id note, and the first line of
+//! `--version`. The version probe runs the target with stdin closed and kills it after
+//! [`VERSION_TIMEOUT`]; a target that rejects the flag or prints nothing has no version.
+
+use crate::types::{AttackResult, TargetProvenance};
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Longest the `--version` probe may run before it is killed.
+pub const VERSION_TIMEOUT: Duration = Duration::from_secs(2);
+/// Longest version line kept; anything longer is not a version string.
+const MAX_VERSION_CHARS: usize = 200;
+
+const PT_NOTE: u32 = 4;
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// Provenance of every distinct program in `results`, in first-seen order. Programs that
+/// cannot be read are left out with a warning.
+pub fn capture_all(results: &[AttackResult]) -> Vec<TargetProvenance> {
+    let mut seen: Vec<&PathBuf> = Vec::new();
+    for result in results {
+        if !seen.contains(&&result.program) {
+            seen.push(&result.program);
+        }
+    }
+    seen.into_iter()
+        .filter_map(|program| match capture(program) {
+            Ok(provenance) => Some(provenance),
+            Err(err) => {
+                tracing::warn!("Target provenance for {}: {:#}", program.display(), err);
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn capture(program: &Path) -> Result<TargetProvenance> {
+    let resolved = resolve(program);
+    let bytes =
+        fs::read(&resolved).with_context(|| format!("reading target {}", resolved.display()))?;
+    Ok(TargetProvenance {
+        program: program.to_path_buf(),
+        sha256: hex::encode(Sha256::digest(&bytes)),
+        size_bytes: bytes.len() as u64,
+        build_id: elf_build_id(&bytes),
+        version: probe_version(&resolved),
+    })
+}
+
+/// The file a bare program name runs: `server` is looked up on `PATH` like `Command` does.
+fn resolve(program: &Path) -> PathBuf {
+    if program.components().count() > 1 || program.exists() {
+        return program.to_path_buf();
+    }
+    std::env::var_os("PATH")
+        .into_iter()
+        .flat_map(|paths| std::env::split_paths(&paths).collect::<Vec<_>>())
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| program.to_path_buf())
+}
+
+/// First non-empty line of `program --version`, when it exits successfully in time.
+fn probe_version(program: &Path) -> Option<String> {
+    let mut child = Command::new(program)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let started = Instant::now();
+    loop {
+        if child.try_wait().ok()?.is_some() {
+            break;
+        }
+        if started.elapsed() >= VERSION_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .filter(|line| line.chars().count() <= MAX_VERSION_CHARS)
+        .map(str::to_string)
+}
+
+/// GNU build-id from the ELF's `PT_NOTE` segments, as lowercase hex.
+pub fn elf_build_id(bytes: &[u8]) -> Option<String> {
+    if bytes.get(..4)? != b"\x7fELF" {
+        return None;
+    }
+    let wide = match bytes.get(4)? {
+        1 => false,
+        2 => true,
+        _ => return None,
+    };
+    let elf = Elf {
+        bytes,
+        big_endian: *bytes.get(5)? == 2,
+    };
+    let (phoff, phentsize, phnum) = if wide {
+        (elf.u64(0x20)?, elf.u16(0x36)?, elf.u16(0x38)?)
+    } else {
+        (elf.u32(0x1c)? as u64, elf.u16(0x2a)?, elf.u16(0x2c)?)
+    };
+    (0..phnum as u64).find_map(|index| {
+        let header = usize::try_from(phoff.checked_add(index * phentsize as u64)?).ok()?;
+        if elf.u32(header)? != PT_NOTE {
+            return None;
+        }
+        let (offset, size) = if wide {
+            (elf.u64(header + 8)?, elf.u64(header + 32)?)
+        } else {
+            (elf.u32(header + 4)? as u64, elf.u32(header + 16)? as u64)
+        };
+        elf.gnu_build_id(usize::try_from(offset).ok()?, usize::try_from(size).ok()?)
+    })
+}
+
+struct Elf<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+impl Elf<'_> {
+    fn field<const N: usize>(&self, at: usize) -> Option<[u8; N]> {
+        self.bytes.get(at..at.checked_add(N)?)?.try_into().ok()
+    }
+
+    fn u16(&self, at: usize) -> Option<u16> {
+        let raw = self.field(at)?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(raw)
+        } else {
+            u16::from_le_bytes(raw)
+        })
+    }
+
+    fn u32(&self, at: usize) -> Option<u32> {
+        let raw = self.field(at)?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(raw)
+        } else {
+            u32::from_le_bytes(raw)
+        })
+    }
+
+    fn u64(&self, at: usize) -> Option<u64> {
+        let raw = self.field(at)?;
+        Some(if self.big_endian {
+            u64::from_be_bytes(raw)
+        } else {
+            u64::from_le_bytes(raw)
+        })
+    }
+
+    /// Walk the notes in `offset..offset + size`; names and descriptors are 4-byte aligned.
+    fn gnu_build_id(&self, offset: usize, size: usize) -> Option<String> {
+        let end = offset.checked_add(size)?.min(self.bytes.len());
+        let mut at = offset;
+        while at + 12 <= end {
+            let name_size = self.u32(at)? as usize;
+            let desc_size = self.u32(at + 4)? as usize;
+            let kind = self.u32(at + 8)?;
+            let name_at = at + 12;
+            let desc_at = name_at.checked_add(name_size.checked_next_multiple_of(4)?)?;
+            let next = desc_at.checked_add(desc_size.checked_next_multiple_of(4)?)?;
+            if kind == NT_GNU_BUILD_ID && self.bytes.get(name_at..name_at + name_size)? == b"GNU\0"
+            {
+                return Some(hex::encode(self.bytes.get(desc_at..desc_at + desc_size)?));
+            }
+            at = next;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// A minimal little-endian ELF64 with one PT_NOTE segment holding a GNU build-id.
+    fn elf_with_build_id(id: &[u8]) -> Vec<u8> {
+        let mut elf = vec![0u8; 0x40];
+        elf[..4].copy_from_slice(b"\x7fELF");
+        elf[4] = 2;
+        elf[5] = 1;
+        elf[0x20..0x28].copy_from_slice(&0x40u64.to_le_bytes());
+        elf[0x36..0x38].copy_from_slice(&56u16.to_le_bytes());
+        elf[0x38..0x3a].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut note = Vec::new();
+        note.extend_from_slice(&4u32.to_le_bytes());
+        note.extend_from_slice(&(id.len() as u32).to_le_bytes());
+        note.extend_from_slice(&NT_GNU_BUILD_ID.to_le_bytes());
+        note.extend_from_slice(b"GNU\0");
+        note.extend_from_slice(id);
+
+        let mut header = vec![0u8; 56];
+        header[..4].copy_from_slice(&PT_NOTE.to_le_bytes());
+        header[8..16].copy_from_slice(&(0x40u64 + 56).to_le_bytes());
+        header[32..40].copy_from_slice(&(note.len() as u64).to_le_bytes());
+        elf.extend(header);
+        elf.extend(note);
+        elf
+    }
+
+    #[test]
+    fn test_elf_build_id() {
+        let elf = elf_with_build_id(&[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(elf_build_id(&elf).as_deref(), Some("deadbeef"));
+        assert_eq!(elf_build_id(&elf[..0x50]), None);
+        assert_eq!(elf_build_id(b"#!/bin/sh\n"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_capture_hashes_and_probes_version() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let script = dir.path().join("tool");
+        let content = "#!/bin/sh\necho\necho 'tool 1.2.3'\n";
+        fs::write(&script, content).unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let provenance = capture(&script).unwrap();
+        assert_eq!(provenance.size_bytes, content.len() as u64);
+        assert_eq!(provenance.sha256.len(), 64);
+        assert_eq!(provenance.build_id, None);
+        assert_eq!(provenance.version.as_deref(), Some("tool 1.2.3"));
+
+        fs::write(&script, "#!/bin/sh\nexit 2\n").unwrap();
+        let rebuilt = capture(&script).unwrap();
+        assert_ne!(rebuilt.sha256, provenance.sha256);
+        assert_eq!(rebuilt.version, None);
+
+        assert!(capture(&dir.path().join("missing")).is_err());
+    }
+}
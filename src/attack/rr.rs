@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! rr record/replay wrapper for the concurrency axis
+//!
+//! With `--wrapper rr` the concurrency attack runs the target under `rr record`, so a crash
+//! caused by one thread interleaving can be replayed exactly with `rr replay <trace>`. Traces
+//! are large; only the ones from crashing runs are kept.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+/// Directory, relative to the working directory, that keeps traces of crashing runs.
+pub const TRACE_DIR: &str = "rr-traces";
+
+/// Whether `rr` can be started on this host.
+pub fn available() -> bool {
+    Command::new("rr")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Run `program` under `rr record`, writing the trace to a fresh directory below `root`.
+/// The trace is removed again when the target exits cleanly.
+pub fn record(program: &Path, args: &[String], root: &Path) -> Result<(Output, Option<PathBuf>)> {
+    record_with(Path::new("rr"), program, args, root)
+}
+
+fn record_with(
+    rr: &Path,
+    program: &Path,
+    args: &[String],
+    root: &Path,
+) -> Result<(Output, Option<PathBuf>)> {
+    std::fs::create_dir_all(root)
+        .with_context(|| format!("creating rr trace directory {}", root.display()))?;
+    let trace = root.join(trace_name(program));
+    let output = Command::new(rr)
+        .arg("record")
+        .arg("--output-trace-dir")
+        .arg(&trace)
+        .arg(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .context("Failed to execute program under rr")?;
+
+    if output.status.success() {
+        let _ = std::fs::remove_dir_all(&trace);
+        return Ok((output, None));
+    }
+    // rr refuses to record on some hosts (perf counters, ptrace policy); no trace is left then.
+    Ok((output, trace.is_dir().then_some(trace)))
+}
+
+fn trace_name(program: &Path) -> String {
+    let stem = program
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "target".to_string());
+    format!(
+        "{}-{}",
+        stem,
+        chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_only_crashing_runs_keep_traces() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        // Stand-in for rr: create the trace directory, then run the target.
+        let fake_rr = dir.path().join("rr");
+        std::fs::write(
+            &fake_rr,
+            "#!/bin/sh\nshift 2\nmkdir -p \"$1\"\nshift\nexec \"$@\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&fake_rr, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let root = dir.path().join(TRACE_DIR);
+        let sh = Path::new("/bin/sh");
+
+        let clean = vec!["-c".to_string(), "exit 0".to_string()];
+        let (output, trace) = record_with(&fake_rr, sh, &clean, &root).unwrap();
+        assert!(output.status.success());
+        assert!(trace.is_none());
+        assert_eq!(std::fs::read_dir(&root).unwrap().count(), 0);
+
+        let crash = vec!["-c".to_string(), "exit 134".to_string()];
+        let (output, trace) = record_with(&fake_rr, sh, &crash, &root).unwrap();
+        assert_eq!(output.status.code(), Some(134));
+        let trace = trace.expect("crashing run keeps its trace");
+        assert!(trace.is_dir() && trace.starts_with(&root));
+    }
+
+    #[test]
+    fn test_trace_names_are_per_program() {
+        let name = trace_name(Path::new("/usr/local/bin/server"));
+        assert!(name.starts_with("server-"));
+        assert!(!name.contains('/'));
+    }
+}
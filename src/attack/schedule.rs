@@ -0,0 +1,532 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Seeded schedule fuzzing for the concurrency axis (`--wrapper schedule`)
+//!
+//! The target runs under ptrace and stops at every syscall boundary of every thread. At
+//! some boundaries, chosen from the campaign seed, the thread is held back while the
+//! other threads pass a number of their own boundaries, which forces interleavings that
+//! sleep-based stress rarely hits. A held thread is let go early when nothing else makes
+//! progress for a few milliseconds, so a lock it holds cannot wedge the run.
+//!
+//! Each decision is a pure function of the seed, the thread's ordinal and the boundary's
+//! index within that thread, so a seed replays the same schedule. Crashing runs also keep
+//! the decisions they applied in [`SCHEDULE_DIR`] below the report store;
+//! `--replay-schedule <file>` applies exactly those again. A target still running when the
+//! axis duration runs out is killed. Linux only.
+
+use crate::seed::SeededRng;
+use crate::storage::write_atomic;
+use crate::types::IntensityLevel;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::time::Duration;
+
+/// Directory, below the report store, that keeps schedules of crashing runs.
+pub const SCHEDULE_DIR: &str = "schedules";
+
+/// Schedule of one traced run, as written to [`SCHEDULE_DIR`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub seed: u64,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    /// Holds applied during the run, in the order they happened.
+    pub decisions: Vec<Decision>,
+}
+
+/// One thread held back at one syscall boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Decision {
+    /// Order in which the thread was first seen; the main thread is 0.
+    pub thread: usize,
+    /// Index of the boundary (syscall entry or exit) within that thread.
+    pub boundary: u64,
+    /// Boundaries other threads pass before this one resumes.
+    pub hold: u32,
+}
+
+/// Where holds come from.
+#[derive(Debug, Clone)]
+pub enum Plan {
+    Seeded {
+        seed: u64,
+        probability: f64,
+        max_hold: u32,
+    },
+    Replay(HashMap<(usize, u64), u32>),
+}
+
+impl Plan {
+    /// Hold more often and for longer as intensity rises.
+    pub fn seeded(seed: u64, intensity: IntensityLevel) -> Self {
+        let multiplier = intensity.multiplier();
+        Plan::Seeded {
+            seed,
+            probability: (0.01 * multiplier).clamp(0.002, 0.25),
+            max_hold: (4.0 * multiplier.sqrt()).ceil().max(1.0) as u32,
+        }
+    }
+
+    pub fn replay(schedule: &Schedule) -> Self {
+        Plan::Replay(
+            schedule
+                .decisions
+                .iter()
+                .map(|decision| ((decision.thread, decision.boundary), decision.hold))
+                .collect(),
+        )
+    }
+
+    /// How long to hold `thread` at its `boundary`-th syscall boundary, if at all.
+    pub fn decide(&self, thread: usize, boundary: u64) -> Option<u32> {
+        match self {
+            Plan::Seeded {
+                seed,
+                probability,
+                max_hold,
+            } => {
+                let mut rng =
+                    SeededRng::derive(*seed, &format!("schedule/{}/{}", thread, boundary));
+                (rng.unit() < *probability).then(|| 1 + rng.below(*max_hold as usize) as u32)
+            }
+            Plan::Replay(holds) => holds.get(&(thread, boundary)).copied(),
+        }
+    }
+}
+
+pub fn load(path: &Path) -> Result<Schedule> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("reading schedule {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("parsing schedule {}", path.display()))
+}
+
+/// Whether this platform can trace a target.
+pub fn available() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Run `program` under `plan` for at most `limit`. The schedule is written to a fresh file
+/// below `root` only when the target does not exit cleanly.
+pub fn record(
+    program: &Path,
+    args: &[String],
+    plan: &Plan,
+    seed: u64,
+    root: &Path,
+    limit: Duration,
+) -> Result<(Output, Option<PathBuf>)> {
+    let (output, decisions) = run(program, args, plan, limit)?;
+    if output.status.success() {
+        return Ok((output, None));
+    }
+    std::fs::create_dir_all(root)
+        .with_context(|| format!("creating schedule directory {}", root.display()))?;
+    let path = root.join(schedule_name(program));
+    let schedule = Schedule {
+        seed,
+        program: program.to_path_buf(),
+        args: args.to_vec(),
+        decisions,
+    };
+    write_atomic(&path, serde_json::to_string_pretty(&schedule)? + "\n")
+        .with_context(|| format!("writing schedule {}", path.display()))?;
+    Ok((output, Some(path)))
+}
+
+fn schedule_name(program: &Path) -> String {
+    let stem = program
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "target".to_string());
+    format!(
+        "{}-{}.json",
+        stem,
+        chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f")
+    )
+}
+
+/// Run `program` to completion under `plan`, killing it once `limit` passes; returns its
+/// output and the holds applied.
+#[cfg(target_os = "linux")]
+pub fn run(
+    program: &Path,
+    args: &[String],
+    plan: &Plan,
+    limit: Duration,
+) -> Result<(Output, Vec<Decision>)> {
+    use std::io;
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::process::{Command, Stdio};
+
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // SAFETY: PTRACE_TRACEME only marks the forked child as traced by this thread.
+    unsafe {
+        command.pre_exec(|| {
+            let null = std::ptr::null_mut::<libc::c_void>();
+            if libc::ptrace(libc::PTRACE_TRACEME, 0, null, null) < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    let mut child = command
+        .spawn()
+        .context("Failed to execute program under the schedule tracer")?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let pid = child.id() as libc::pid_t;
+    let deadline = match watchdog(pid, limit) {
+        Ok(deadline) => deadline,
+        Err(err) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(err);
+        }
+    };
+    let traced = tracer::Tracer::new(plan).run(pid);
+    if traced.is_err() {
+        // SAFETY: signalling and reaping our own child.
+        unsafe {
+            libc::kill(pid, libc::SIGKILL);
+            let mut status = 0;
+            libc::waitpid(pid, &mut status, libc::__WALL);
+        }
+    }
+    let _ = deadline.join();
+    let (status, decisions) = traced?;
+    let output = Output {
+        status: std::process::ExitStatus::from_raw(status),
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    };
+    Ok((output, decisions))
+}
+
+/// Kill `pid` with SIGKILL once `limit` passes, unless it exits first. The kill goes
+/// through a pidfd, so it cannot reach another process that reuses the pid once the tracer
+/// has reaped the target.
+#[cfg(target_os = "linux")]
+fn watchdog(pid: libc::pid_t, limit: Duration) -> Result<std::thread::JoinHandle<()>> {
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+    use std::time::Instant;
+
+    // SAFETY: pidfd_open returns a new descriptor that we own.
+    let pidfd = unsafe {
+        let pidfd = libc::syscall(libc::SYS_pidfd_open, pid, 0);
+        if pidfd < 0 {
+            anyhow::bail!(
+                "opening a pidfd for the traced target: {}",
+                io::Error::last_os_error()
+            );
+        }
+        OwnedFd::from_raw_fd(pidfd as RawFd)
+    };
+    let deadline = Instant::now() + limit;
+    Ok(std::thread::spawn(move || loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let mut exited = libc::pollfd {
+            fd: pidfd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = remaining.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+        // SAFETY: polling one descriptor we own; a pidfd turns readable when its process exits.
+        match unsafe { libc::poll(&mut exited, 1, timeout) } {
+            0 if remaining.is_zero() => {
+                // SAFETY: signalling through a pidfd we own; SIGKILL needs no siginfo.
+                unsafe {
+                    libc::syscall(
+                        libc::SYS_pidfd_send_signal,
+                        pidfd.as_raw_fd(),
+                        libc::SIGKILL,
+                        std::ptr::null::<libc::siginfo_t>(),
+                        0,
+                    );
+                }
+                return;
+            }
+            0 => continue,
+            -1 if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted => continue,
+            _ => return,
+        }
+    }))
+}
+
+#[cfg(target_os = "linux")]
+fn drain<R: std::io::Read + Send + 'static>(pipe: Option<R>) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn run(
+    _program: &Path,
+    _args: &[String],
+    _plan: &Plan,
+    _limit: Duration,
+) -> Result<(Output, Vec<Decision>)> {
+    anyhow::bail!("schedule fuzzing needs Linux ptrace")
+}
+
+#[cfg(target_os = "linux")]
+mod tracer {
+    use super::{Decision, Plan};
+    use anyhow::{bail, Result};
+    use std::collections::HashMap;
+    use std::io;
+    use std::time::Duration;
+
+    /// Empty polls (1 ms apart) before held threads are let go regardless.
+    const IDLE_POLLS: u32 = 5;
+
+    pub struct Tracer<'a> {
+        plan: &'a Plan,
+        /// Ordinal and boundaries seen so far, per live thread.
+        threads: HashMap<libc::pid_t, (usize, u64)>,
+        seen: usize,
+        /// Held threads and the boundaries still to pass before each resumes.
+        held: Vec<(libc::pid_t, u32)>,
+        decisions: Vec<Decision>,
+    }
+
+    impl<'a> Tracer<'a> {
+        pub fn new(plan: &'a Plan) -> Self {
+            Self {
+                plan,
+                threads: HashMap::new(),
+                seen: 0,
+                held: Vec::new(),
+                decisions: Vec::new(),
+            }
+        }
+
+        /// Trace `pid` (stopped at its exec) until it exits; returns its wait status.
+        pub fn run(mut self, pid: libc::pid_t) -> Result<(libc::c_int, Vec<Decision>)> {
+            let mut status = 0;
+            // SAFETY: waiting on our own child.
+            if unsafe { libc::waitpid(pid, &mut status, libc::__WALL) } < 0 {
+                bail!(
+                    "waiting for the traced target: {}",
+                    io::Error::last_os_error()
+                );
+            }
+            if !libc::WIFSTOPPED(status) {
+                return Ok((status, self.decisions));
+            }
+            let options =
+                libc::PTRACE_O_TRACESYSGOOD | libc::PTRACE_O_TRACECLONE | libc::PTRACE_O_EXITKILL;
+            // SAFETY: `pid` is our stopped tracee.
+            let set = unsafe {
+                libc::ptrace(
+                    libc::PTRACE_SETOPTIONS,
+                    pid,
+                    std::ptr::null_mut::<libc::c_void>(),
+                    options as usize as *mut libc::c_void,
+                )
+            };
+            if set < 0 {
+                bail!("configuring ptrace: {}", io::Error::last_os_error());
+            }
+            self.register(pid);
+            resume(pid, 0);
+
+            let mut idle = 0;
+            loop {
+                // __WNOTHREAD keeps us from reaping children other threads are waiting on.
+                let mut flags = libc::__WALL | libc::__WNOTHREAD;
+                if !self.held.is_empty() {
+                    flags |= libc::WNOHANG;
+                }
+                // SAFETY: waiting on tracees of this thread.
+                let tid = unsafe { libc::waitpid(-1, &mut status, flags) };
+                if tid < 0 {
+                    let err = io::Error::last_os_error();
+                    if err.kind() == io::ErrorKind::Interrupted {
+                        continue;
+                    }
+                    bail!("waiting for the traced target: {}", err);
+                }
+                if tid == 0 {
+                    idle += 1;
+                    if idle >= IDLE_POLLS {
+                        for (held, _) in self.held.drain(..) {
+                            resume(held, 0);
+                        }
+                    } else {
+                        std::thread::sleep(Duration::from_millis(1));
+                    }
+                    continue;
+                }
+                idle = 0;
+
+                if libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+                    self.threads.remove(&tid);
+                    self.held.retain(|(held, _)| *held != tid);
+                    if tid == pid {
+                        return Ok((status, self.decisions));
+                    }
+                    continue;
+                }
+                if !libc::WIFSTOPPED(status) {
+                    continue;
+                }
+                let signal = libc::WSTOPSIG(status);
+                if signal == libc::SIGTRAP | 0x80 {
+                    self.boundary(tid);
+                } else if signal == libc::SIGTRAP && status >> 16 != 0 {
+                    // Clone and exec events; a new thread reports its own SIGSTOP.
+                    resume(tid, 0);
+                } else if signal == libc::SIGSTOP && !self.threads.contains_key(&tid) {
+                    self.register(tid);
+                    resume(tid, 0);
+                } else {
+                    resume(tid, signal);
+                }
+            }
+        }
+
+        fn register(&mut self, tid: libc::pid_t) -> (usize, u64) {
+            let seen = &mut self.seen;
+            *self.threads.entry(tid).or_insert_with(|| {
+                *seen += 1;
+                (*seen - 1, 0)
+            })
+        }
+
+        fn boundary(&mut self, tid: libc::pid_t) {
+            let (thread, boundary) = self.register(tid);
+            if let Some(entry) = self.threads.get_mut(&tid) {
+                entry.1 += 1;
+            }
+            // Every boundary another thread passes brings held threads closer to release.
+            self.held.retain_mut(|(held, remaining)| {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    resume(*held, 0);
+                }
+                *remaining > 0
+            });
+            match self.plan.decide(thread, boundary) {
+                Some(hold) => {
+                    self.decisions.push(Decision {
+                        thread,
+                        boundary,
+                        hold,
+                    });
+                    self.held.push((tid, hold));
+                }
+                None => resume(tid, 0),
+            }
+        }
+    }
+
+    /// Continue a stopped tracee to its next syscall boundary, delivering `signal`.
+    fn resume(tid: libc::pid_t, signal: libc::c_int) {
+        // SAFETY: `tid` is a stopped tracee; a thread that already died just fails with ESRCH.
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_SYSCALL,
+                tid,
+                std::ptr::null_mut::<libc::c_void>(),
+                signal as usize as *mut libc::c_void,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_plan_is_a_pure_function_of_seed_thread_and_boundary() {
+        let plan = Plan::seeded(7, IntensityLevel::Heavy);
+        let first: Vec<_> = (0..2000).map(|b| plan.decide(1, b)).collect();
+        let again: Vec<_> = (0..2000).map(|b| plan.decide(1, b)).collect();
+        assert_eq!(first, again);
+        assert!(first.iter().any(Option::is_some));
+        assert!(first.iter().any(Option::is_none));
+        let other: Vec<_> = (0..2000)
+            .map(|b| Plan::seeded(8, IntensityLevel::Heavy).decide(1, b))
+            .collect();
+        assert_ne!(first, other);
+
+        let schedule = Schedule {
+            seed: 7,
+            program: PathBuf::from("app"),
+            args: Vec::new(),
+            decisions: vec![Decision {
+                thread: 2,
+                boundary: 40,
+                hold: 3,
+            }],
+        };
+        let replay = Plan::replay(&schedule);
+        assert_eq!(replay.decide(2, 40), Some(3));
+        assert_eq!(replay.decide(2, 41), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_traced_run_keeps_output_status_and_schedule() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().join(SCHEDULE_DIR);
+        let sh = Path::new("/bin/sh");
+        let plan = Plan::Seeded {
+            seed: 1,
+            probability: 0.5,
+            max_hold: 3,
+        };
+
+        let clean = vec!["-c".to_string(), "echo traced".to_string()];
+        let limit = Duration::from_secs(30);
+        let (output, schedule) = match record(sh, &clean, &plan, 1, &root, limit) {
+            Ok(run) => run,
+            // Containers may forbid ptrace altogether.
+            Err(err) if format!("{:#}", err).contains("Operation not permitted") => return,
+            Err(err) => panic!("{:#}", err),
+        };
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "traced\n");
+        assert!(schedule.is_none());
+
+        let crash = vec!["-c".to_string(), "echo oops >&2; exit 3".to_string()];
+        let (output, schedule) = record(sh, &crash, &plan, 1, &root, limit).unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "oops\n");
+        let schedule = load(&schedule.expect("crashing run keeps its schedule")).unwrap();
+        assert_eq!(schedule.seed, 1);
+        assert_eq!(schedule.args, crash);
+        assert!(!schedule.decisions.is_empty());
+
+        // Replaying the recorded holds applies exactly those.
+        let (_, replayed) = run(sh, &crash, &Plan::replay(&schedule), limit).unwrap();
+        assert!(replayed
+            .iter()
+            .all(|decision| schedule.decisions.contains(decision)));
+
+        // A target that outlives the axis duration is killed at it.
+        let sleep = vec!["-c".to_string(), "exec sleep 30".to_string()];
+        let started = std::time::Instant::now();
+        let (output, _) = run(sh, &sleep, &plan, Duration::from_millis(200)).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(output.status.signal(), Some(libc::SIGKILL));
+    }
+}
@@ -10,6 +10,9 @@ pub enum AttackStrategy {
     NetworkFlood,
     ConcurrencyStorm,
     TimeBomb,
+    SignalStorm,
+    /// A custom axis: registered arguments plus its stressor command
+    Custom,
 }
 
 impl AttackStrategy {
@@ -22,10 +25,14 @@ impl AttackStrategy {
             AttackStrategy::NetworkFlood => "Flood network connections",
             AttackStrategy::ConcurrencyStorm => "Create concurrency storm with many threads/tasks",
             AttackStrategy::TimeBomb => "Run for extended duration to find time-dependent bugs",
+            AttackStrategy::SignalStorm => {
+                "Deliver benign signal storms to exercise EINTR and signal-safety paths"
+            }
+            AttackStrategy::Custom => "Run a custom axis with its registered stressor",
         }
     }
 
-    /// All available strategies
+    /// All built-in strategies (custom axes are registered per config)
     #[allow(dead_code)]
     pub fn all() -> &'static [AttackStrategy] {
         &[
@@ -35,6 +42,7 @@ impl AttackStrategy {
             AttackStrategy::NetworkFlood,
             AttackStrategy::ConcurrencyStorm,
             AttackStrategy::TimeBomb,
+            AttackStrategy::SignalStorm,
         ]
     }
 }
@@ -52,8 +60,8 @@ mod tests {
     }
 
     #[test]
-    fn test_six_strategies() {
-        assert_eq!(AttackStrategy::all().len(), 6, "should have exactly 6 attack strategies");
+    fn test_seven_strategies() {
+        assert_eq!(AttackStrategy::all().len(), 7, "should have exactly 7 attack strategies");
     }
 
     #[test]
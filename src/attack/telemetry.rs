@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Energy and thermal telemetry sampled during attacks (`--telemetry`)
+//!
+//! On laptops and edge devices a failure under load can be the machine rather than the target:
+//! a package hitting its thermal limit clocks down and turns timing-sensitive code flaky. While
+//! an attack runs, this samples RAPL package energy (`/sys/class/powercap`), the hottest
+//! hwmon or thermal-zone sensor, and the kernel's thermal-throttle counters, and summarises them
+//! beside the result. Anything the host does not expose is left out; RAPL counters usually need
+//! root on recent kernels.
+
+use crate::types::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Background sampler; [`TelemetrySampler::stop`] returns the summary.
+pub struct TelemetrySampler {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<TelemetryReport>,
+}
+
+/// Start sampling the host's sysfs.
+pub fn start() -> TelemetrySampler {
+    start_at(PathBuf::from("/sys"))
+}
+
+fn start_at(sysfs: PathBuf) -> TelemetrySampler {
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = stop.clone();
+    let handle = thread::spawn(move || sample_until(&sysfs, &flag));
+    TelemetrySampler { stop, handle }
+}
+
+impl TelemetrySampler {
+    pub fn stop(self) -> TelemetryReport {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_else(|_| TelemetryReport {
+            note: Some("telemetry sampler panicked".to_string()),
+            ..TelemetryReport::default()
+        })
+    }
+}
+
+struct Reading {
+    at: Instant,
+    /// Per-package RAPL energy in microjoules with the counter's wrap range.
+    energy: Vec<(u64, u64)>,
+    temperature_c: Option<f64>,
+    throttle_count: Option<u64>,
+}
+
+fn sample_until(sysfs: &Path, stop: &AtomicBool) -> TelemetryReport {
+    let packages = rapl_packages(sysfs);
+    let sensors = temperature_sensors(sysfs);
+    let throttle_counters = throttle_counters(sysfs);
+    let read = || Reading {
+        at: Instant::now(),
+        energy: packages
+            .iter()
+            .filter_map(|(energy, range)| Some((read_u64(energy)?, read_u64(range)?)))
+            .collect(),
+        temperature_c: sensors
+            .iter()
+            .filter_map(|sensor| read_u64(sensor))
+            .map(|millidegrees| millidegrees as f64 / 1000.0)
+            .reduce(f64::max),
+        throttle_count: (!throttle_counters.is_empty()).then(|| {
+            throttle_counters
+                .iter()
+                .filter_map(|counter| read_u64(counter))
+                .sum()
+        }),
+    };
+
+    let first = read();
+    let mut report = TelemetryReport {
+        samples: 1,
+        start_temperature_c: first.temperature_c,
+        peak_temperature_c: first.temperature_c,
+        critical_temperature_c: critical_temperature(sysfs),
+        ..TelemetryReport::default()
+    };
+    let rapl_readable = !packages.is_empty() && first.energy.len() == packages.len();
+    let started = first.at;
+    let mut previous = first;
+    let mut energy_uj = 0u64;
+    let mut throttle_start = previous.throttle_count;
+
+    let mut next = Instant::now() + SAMPLE_INTERVAL;
+    loop {
+        let stopping = stop.load(Ordering::SeqCst);
+        if !stopping && Instant::now() < next {
+            thread::sleep(Duration::from_millis(25));
+            continue;
+        }
+        next += SAMPLE_INTERVAL;
+
+        let current = read();
+        report.samples += 1;
+        if let Some(temperature) = current.temperature_c {
+            report.peak_temperature_c = Some(
+                report
+                    .peak_temperature_c
+                    .map_or(temperature, |peak| peak.max(temperature)),
+            );
+        }
+        if rapl_readable && current.energy.len() == previous.energy.len() {
+            let delta: u64 = current
+                .energy
+                .iter()
+                .zip(&previous.energy)
+                .map(|((now, range), (before, _))| counter_delta(*before, *now, *range))
+                .sum();
+            energy_uj += delta;
+            let seconds = current.at.duration_since(previous.at).as_secs_f64();
+            if seconds > 0.0 {
+                let watts = delta as f64 / 1_000_000.0 / seconds;
+                report.peak_package_watts = Some(
+                    report
+                        .peak_package_watts
+                        .map_or(watts, |peak| peak.max(watts)),
+                );
+            }
+        }
+        if let (Some(start), Some(now)) = (throttle_start, current.throttle_count) {
+            // Counters can reset when a CPU goes offline; count from the new baseline.
+            if now < start {
+                throttle_start = Some(now);
+            } else {
+                report.throttle_events = now - start;
+            }
+        }
+        previous = current;
+        if stopping {
+            break;
+        }
+    }
+
+    if rapl_readable {
+        let joules = energy_uj as f64 / 1_000_000.0;
+        report.energy_joules = Some(joules);
+        let seconds = previous.at.duration_since(started).as_secs_f64();
+        if seconds > 0.0 {
+            report.avg_package_watts = Some(joules / seconds);
+        }
+    }
+    let at_critical = matches!(
+        (report.peak_temperature_c, report.critical_temperature_c),
+        (Some(peak), Some(critical)) if peak >= critical
+    );
+    report.throttled = report.throttle_events > 0 || at_critical;
+
+    let mut notes = Vec::new();
+    if report.throttled {
+        notes.push(
+            "thermal throttling during the run; failures or timeouts here may be heat-induced \
+             rather than a target bug"
+                .to_string(),
+        );
+    }
+    if packages.is_empty() {
+        notes.push("no RAPL power domains on this host".to_string());
+    } else if !rapl_readable {
+        notes.push("RAPL energy counters not readable (root is usually required)".to_string());
+    }
+    if sensors.is_empty() {
+        notes.push("no temperature sensors found".to_string());
+    }
+    report.note = (!notes.is_empty()).then(|| notes.join("; "));
+    report
+}
+
+/// Energy consumed between two readings of a counter that wraps at `range`.
+fn counter_delta(before: u64, now: u64, range: u64) -> u64 {
+    if now >= before {
+        now - before
+    } else {
+        range.saturating_sub(before) + now
+    }
+}
+
+/// Top-level RAPL package domains (`intel-rapl:N`, not the `intel-rapl:N:M` subzones).
+fn rapl_packages(sysfs: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut packages: Vec<(PathBuf, PathBuf)> = entries(&sysfs.join("class/powercap"))
+        .into_iter()
+        .filter(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("intel-rapl:"))
+                .is_some_and(|index| !index.contains(':'))
+        })
+        .map(|dir| (dir.join("energy_uj"), dir.join("max_energy_range_uj")))
+        .filter(|(energy, _)| energy.exists())
+        .collect();
+    packages.sort();
+    packages
+}
+
+/// hwmon `temp*_input` files, or thermal zones when no hwmon sensor exists.
+fn temperature_sensors(sysfs: &Path) -> Vec<PathBuf> {
+    let hwmon: Vec<PathBuf> = hwmon_files(sysfs, "_input");
+    if !hwmon.is_empty() {
+        return hwmon;
+    }
+    entries(&sysfs.join("class/thermal"))
+        .into_iter()
+        .filter(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("thermal_zone"))
+        })
+        .map(|dir| dir.join("temp"))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Lowest critical (or, failing that, max) trip point any hwmon sensor reports, in °C.
+fn critical_temperature(sysfs: &Path) -> Option<f64> {
+    let trips = |suffix: &str| {
+        hwmon_files(sysfs, suffix)
+            .iter()
+            .filter_map(|path| read_u64(path))
+            .filter(|millidegrees| *millidegrees > 0)
+            .map(|millidegrees| millidegrees as f64 / 1000.0)
+            .reduce(f64::min)
+    };
+    trips("_crit").or_else(|| trips("_max"))
+}
+
+fn hwmon_files(sysfs: &Path, suffix: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = entries(&sysfs.join("class/hwmon"))
+        .into_iter()
+        .flat_map(|dir| entries(&dir))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("temp") && name.ends_with(suffix))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Per-CPU core and package throttle counters (x86 `thermal_throttle`).
+fn throttle_counters(sysfs: &Path) -> Vec<PathBuf> {
+    let mut counters: Vec<PathBuf> = entries(&sysfs.join("devices/system/cpu"))
+        .into_iter()
+        .map(|cpu| cpu.join("thermal_throttle"))
+        .flat_map(|dir| {
+            ["core_throttle_count", "package_throttle_count"].map(|name| dir.join(name))
+        })
+        .filter(|path| path.exists())
+        .collect();
+    counters.sort();
+    counters
+}
+
+fn entries(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default()
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, value: u64) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, value.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_counter_delta_handles_wraparound() {
+        assert_eq!(counter_delta(100, 250, 1000), 150);
+        assert_eq!(counter_delta(900, 50, 1000), 150);
+    }
+
+    #[test]
+    fn test_sampler_reads_power_temperature_and_throttling() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let sysfs = dir.path();
+        write(sysfs, "class/powercap/intel-rapl:0/energy_uj", 1_000_000);
+        write(
+            sysfs,
+            "class/powercap/intel-rapl:0/max_energy_range_uj",
+            u32::MAX as u64,
+        );
+        // Subzones (cores, uncore) are part of the package and must not be added again.
+        write(sysfs, "class/powercap/intel-rapl:0:0/energy_uj", 0);
+        write(sysfs, "class/hwmon/hwmon0/temp1_input", 45_000);
+        write(sysfs, "class/hwmon/hwmon0/temp1_crit", 100_000);
+        write(
+            sysfs,
+            "devices/system/cpu/cpu0/thermal_throttle/core_throttle_count",
+            7,
+        );
+
+        let sampler = start_at(sysfs.to_path_buf());
+        thread::sleep(SAMPLE_INTERVAL / 2);
+        write(sysfs, "class/powercap/intel-rapl:0/energy_uj", 6_000_000);
+        write(sysfs, "class/hwmon/hwmon0/temp1_input", 92_500);
+        write(
+            sysfs,
+            "devices/system/cpu/cpu0/thermal_throttle/core_throttle_count",
+            9,
+        );
+        thread::sleep(SAMPLE_INTERVAL);
+        let report = sampler.stop();
+
+        assert!(report.samples >= 2);
+        assert_eq!(report.energy_joules, Some(5.0));
+        assert!(report.peak_package_watts.unwrap() > 0.0);
+        assert_eq!(report.start_temperature_c, Some(45.0));
+        assert_eq!(report.peak_temperature_c, Some(92.5));
+        assert_eq!(report.critical_temperature_c, Some(100.0));
+        assert_eq!(report.throttle_events, 2);
+        assert!(report.throttled);
+        assert!(report.note.unwrap().contains("thermal throttling"));
+    }
+
+    #[test]
+    fn test_sampler_notes_missing_sources() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let report = start_at(dir.path().to_path_buf()).stop();
+        assert_eq!(report.energy_joules, None);
+        assert!(!report.throttled);
+        let note = report.note.unwrap();
+        assert!(note.contains("no RAPL power domains"));
+        assert!(note.contains("no temperature sensors"));
+    }
+}
@@ -30,10 +30,11 @@
 //! 3. **Seal** (after scanning): `seal()` finalises the accumulator
 //!    (which records wall clock time, CPU time, and peak RSS), serialises
 //!    intent and evidence to JSON, then computes:
-//!      - `intent_hash`    = SHA-256 of the serialised intent JSON
-//!      - `evidence_hash`  = SHA-256 of the serialised evidence JSON
-//!      - `report_hash`    = SHA-256 of the report JSON bytes
-//!      - `chain_hash`     = SHA-256(intent_hash || evidence_hash || report_hash)
+//!    - `intent_hash`    = SHA-256 of the serialised intent JSON
+//!    - `evidence_hash`  = SHA-256 of the serialised evidence JSON
+//!    - `report_hash`    = SHA-256 of the report JSON bytes
+//!    - `chain_hash`     = SHA-256(intent_hash || evidence_hash || report_hash)
+//!
 //!    The chain_hash is the cryptographic root — if any component changes,
 //!    chain_hash changes. Optionally, chain_hash is signed with Ed25519.
 //!    Everything is wrapped in an A2ML envelope with `envelope_type: "trustfile"`.
@@ -195,10 +196,11 @@ impl AttestationChainBuilder {
     ///
     /// 3. **Create seal**: Calls `ReportSeal::create()` with the nonce
     ///    and the three JSON byte slices. The seal computes:
-    ///      - `intent_hash`   = SHA-256(intent_json)
-    ///      - `evidence_hash` = SHA-256(evidence_json)
-    ///      - `report_hash`   = SHA-256(report_json)
-    ///      - `chain_hash`    = SHA-256(intent_hash || evidence_hash || report_hash)
+    ///    - `intent_hash`   = SHA-256(intent_json)
+    ///    - `evidence_hash` = SHA-256(evidence_json)
+    ///    - `report_hash`   = SHA-256(report_json)
+    ///    - `chain_hash`    = SHA-256(intent_hash || evidence_hash || report_hash)
+    ///
     ///    The `||` is string concatenation of the hex digests — 64 + 64 + 64 = 192
     ///    ASCII characters fed into SHA-256.
     ///
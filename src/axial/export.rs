@@ -0,0 +1,517 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Built-in HTML and PDF export of the axial markdown (`--export html|pdf`).
+//!
+//! CI images often lack pandoc, so the two formats readers ask for most are rendered
+//! here without external tools. The markdown is parsed with pulldown-cmark (CommonMark
+//! plus tables and strikethrough); HTML is its rendering, and the PDF lays out the same
+//! events as headings, paragraphs, nested lists, table rows and code blocks, dropping
+//! inline styling. Formats beyond these two stay with `--pandoc-to`.
+//!
+//! The PDF uses the standard Helvetica and Courier fonts, so it needs no embedded font data but
+//! only covers Latin-1: other characters (Japanese, Chinese, Cyrillic headings) print
+//! as `?`. Use HTML, or pandoc with a font of your choice, for those languages.
+
+use crate::storage::write_atomic;
+use anyhow::{Context, Result};
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use std::fs;
+use std::path::Path;
+
+/// A4 in PostScript points.
+const PAGE_WIDTH: f64 = 595.0;
+const PAGE_HEIGHT: f64 = 842.0;
+const MARGIN: f64 = 56.0;
+
+/// Body text size; headings are larger.
+const BODY_SIZE: f64 = 10.0;
+
+/// Average Helvetica glyph width as a fraction of the font size, for wrapping.
+const GLYPH_WIDTH: f64 = 0.52;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Html,
+    Pdf,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// Render the markdown file at `markdown` to `output`; `lang` is the report's language code.
+pub fn export(markdown: &Path, format: ExportFormat, output: &Path, lang: &str) -> Result<()> {
+    let source = fs::read_to_string(markdown)
+        .with_context(|| format!("reading markdown report {}", markdown.display()))?;
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating export parent {}", parent.display()))?;
+    }
+    let rendered = match format {
+        ExportFormat::Html => html(&source, lang).into_bytes(),
+        ExportFormat::Pdf => pdf(&source),
+    };
+    write_atomic(output, rendered)
+        .with_context(|| format!("writing {} export {}", format.extension(), output.display()))
+}
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH
+}
+
+/// Standalone HTML page for the markdown.
+pub fn html(markdown: &str, lang: &str) -> String {
+    let mut body = String::new();
+    pulldown_cmark::html::push_html(&mut body, Parser::new_ext(markdown, options()));
+    [
+        "<!DOCTYPE html>".to_string(),
+        format!("<html lang=\"{}\">", escape(lang)),
+        "<head>".to_string(),
+        "<meta charset=\"utf-8\">".to_string(),
+        format!("<title>{}</title>", escape(&title(markdown))),
+        "</head>".to_string(),
+        "<body>".to_string(),
+        body + "</body>",
+        "</html>".to_string(),
+        String::new(),
+    ]
+    .join("\n")
+}
+
+/// Text of the first top-level heading.
+fn title(markdown: &str) -> String {
+    let mut title = String::new();
+    let mut inside = false;
+    for event in Parser::new_ext(markdown, options()) {
+        match event {
+            Event::Start(Tag::Heading {
+                level: HeadingLevel::H1,
+                ..
+            }) => inside = true,
+            Event::End(TagEnd::Heading(HeadingLevel::H1)) => break,
+            Event::Text(text) | Event::Code(text) if inside => title.push_str(&text),
+            _ => {}
+        }
+    }
+    title
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A block of PDF text before wrapping: font resource, size, list depth and the text.
+struct PdfBlock {
+    font: &'static str,
+    size: f64,
+    depth: usize,
+    /// List marker (`•`, `2.`) in front of the first line.
+    marker: Option<String>,
+    text: String,
+    /// Code blocks keep their line breaks and are not wrapped.
+    preformatted: bool,
+}
+
+impl PdfBlock {
+    fn new(font: &'static str, size: f64, depth: usize) -> Self {
+        Self {
+            font,
+            size,
+            depth,
+            marker: None,
+            text: String::new(),
+            preformatted: false,
+        }
+    }
+
+    fn blank() -> Self {
+        Self::new("F1", BODY_SIZE, 0)
+    }
+}
+
+/// The markdown's blocks in reading order, with a blank block after each top-level one.
+fn pdf_blocks(markdown: &str) -> Vec<PdfBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<PdfBlock> = None;
+    // Next number of every open list; `None` for bullet lists.
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    let flush = |current: &mut Option<PdfBlock>, blocks: &mut Vec<PdfBlock>| {
+        if let Some(block) = current.take().filter(|block| !block.text.trim().is_empty()) {
+            blocks.push(block);
+        }
+    };
+    for event in Parser::new_ext(markdown, options()) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                flush(&mut current, &mut blocks);
+                let size = if level == HeadingLevel::H1 {
+                    16.0
+                } else {
+                    13.0
+                };
+                current = Some(PdfBlock::new("F2", size, 0));
+            }
+            // A loose list item's first paragraph continues the item's own block.
+            Event::Start(Tag::Paragraph)
+                if current.as_ref().is_none_or(|block| !block.text.is_empty()) =>
+            {
+                flush(&mut current, &mut blocks);
+                let mut block = PdfBlock::new("F1", BODY_SIZE, lists.len());
+                // Later paragraphs of an item line up with its text, past the bullet.
+                if !lists.is_empty() {
+                    block.marker = Some(" ".to_string());
+                }
+                current = Some(block);
+            }
+            Event::Start(Tag::List(first)) => {
+                flush(&mut current, &mut blocks);
+                lists.push(first);
+            }
+            Event::Start(Tag::Item) => {
+                flush(&mut current, &mut blocks);
+                let marker = match lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}.", *number - 1)
+                    }
+                    _ => "\u{2022}".to_string(),
+                };
+                let mut block = PdfBlock::new("F1", BODY_SIZE, lists.len());
+                block.marker = Some(marker);
+                current = Some(block);
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                flush(&mut current, &mut blocks);
+                let mut block = PdfBlock::new("F3", BODY_SIZE, lists.len());
+                block.preformatted = true;
+                current = Some(block);
+            }
+            Event::Start(Tag::TableHead) | Event::Start(Tag::TableRow) => {
+                flush(&mut current, &mut blocks);
+                let font = if matches!(event, Event::Start(Tag::TableHead)) {
+                    "F2"
+                } else {
+                    "F1"
+                };
+                current = Some(PdfBlock::new(font, BODY_SIZE, 0));
+            }
+            Event::End(TagEnd::TableCell) => {
+                if let Some(block) = current.as_mut() {
+                    block.text.push_str(" | ");
+                }
+            }
+            Event::End(TagEnd::TableHead) | Event::End(TagEnd::TableRow) => {
+                if let Some(block) = current.as_mut() {
+                    block
+                        .text
+                        .truncate(block.text.trim_end_matches(" | ").len());
+                }
+                flush(&mut current, &mut blocks);
+            }
+            Event::End(TagEnd::List(_)) => {
+                flush(&mut current, &mut blocks);
+                lists.pop();
+                if lists.is_empty() {
+                    blocks.push(PdfBlock::blank());
+                }
+            }
+            Event::End(TagEnd::Heading(_)) | Event::End(TagEnd::CodeBlock) => {
+                flush(&mut current, &mut blocks);
+                blocks.push(PdfBlock::blank());
+            }
+            Event::End(TagEnd::Paragraph) if lists.is_empty() => {
+                flush(&mut current, &mut blocks);
+                blocks.push(PdfBlock::blank());
+            }
+            Event::End(TagEnd::Table) | Event::Rule => {
+                flush(&mut current, &mut blocks);
+                blocks.push(PdfBlock::blank());
+            }
+            Event::Text(text) | Event::Code(text) => {
+                let block = current.get_or_insert_with(|| PdfBlock::new("F1", BODY_SIZE, 0));
+                block.text.push_str(&text);
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(block) = current.as_mut() {
+                    block.text.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+    flush(&mut current, &mut blocks);
+    blocks
+}
+
+/// A line of the PDF: font resource, size and the text itself.
+struct PdfLine {
+    font: &'static str,
+    size: f64,
+    text: String,
+}
+
+/// Paginated PDF 1.4 document for the markdown.
+pub fn pdf(markdown: &str) -> Vec<u8> {
+    let mut lines = Vec::new();
+    for block in pdf_blocks(markdown) {
+        // List items indent two columns per level; continuation lines line up with the
+        // item's text.
+        let indent = "  ".repeat(block.depth);
+        let first = match &block.marker {
+            Some(marker) => format!("{}{} ", indent, marker),
+            None => indent.clone(),
+        };
+        let hanging = " ".repeat(first.chars().count());
+        let wrapped = if block.preformatted {
+            block
+                .text
+                .trim_end_matches('\n')
+                .lines()
+                .map(str::to_string)
+                .collect()
+        } else {
+            let width = ((PAGE_WIDTH - 2.0 * MARGIN) / (block.size * GLYPH_WIDTH)) as usize;
+            wrap(
+                block.text.trim(),
+                width.saturating_sub(first.chars().count()),
+            )
+        };
+        for (idx, text) in wrapped.into_iter().enumerate() {
+            let lead = if block.preformatted {
+                &indent
+            } else if idx == 0 {
+                &first
+            } else {
+                &hanging
+            };
+            lines.push(PdfLine {
+                font: block.font,
+                size: block.size,
+                text: format!("{}{}", lead, text),
+            });
+        }
+    }
+
+    let mut pages: Vec<String> = Vec::new();
+    let mut content = String::new();
+    let mut y = PAGE_HEIGHT - MARGIN;
+    for line in &lines {
+        let advance = line.size * 1.4;
+        if y - advance < MARGIN {
+            pages.push(std::mem::take(&mut content));
+            y = PAGE_HEIGHT - MARGIN;
+        }
+        y -= advance;
+        if !line.text.is_empty() {
+            content.push_str(&format!(
+                "BT /{} {} Tf {} {:.1} Td ({}) Tj ET\n",
+                line.font,
+                line.size,
+                MARGIN,
+                y,
+                pdf_string(&line.text)
+            ));
+        }
+    }
+    pages.push(content);
+
+    // Objects 1-5 are the catalog, page tree and fonts; each page adds a page object
+    // and its content stream.
+    let page_ids: Vec<usize> = (0..pages.len()).map(|idx| 6 + idx * 2).collect();
+    let kids: Vec<String> = page_ids.iter().map(|id| format!("{} 0 R", id)).collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Courier /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    ];
+    for (page, id) in pages.iter().zip(&page_ids) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            page.len(),
+            page
+        ));
+    }
+
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (idx, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend(format!("{} 0 obj\n", idx + 1).bytes());
+        out.extend(object.bytes());
+        out.extend(b"\nendobj\n");
+    }
+    let xref = out.len();
+    out.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).bytes());
+    for offset in offsets {
+        out.extend(format!("{:010} 00000 n \n", offset).bytes());
+    }
+    out.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .bytes(),
+    );
+    out
+}
+
+/// Greedy word wrap to `width` characters; overlong words are split.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split(' ') {
+        let mut word = word.to_string();
+        loop {
+            let len = current.chars().count();
+            let needed = word.chars().count() + usize::from(len > 0);
+            if len + needed <= width {
+                if len > 0 {
+                    current.push(' ');
+                }
+                current.push_str(&word);
+                break;
+            }
+            if len > 0 {
+                lines.push(std::mem::take(&mut current));
+                continue;
+            }
+            let split = word
+                .char_indices()
+                .nth(width)
+                .map_or(word.len(), |(idx, _)| idx);
+            let rest = word.split_off(split);
+            lines.push(word);
+            word = rest;
+        }
+    }
+    lines.push(current);
+    lines
+}
+
+/// PDF literal string body in WinAnsi encoding; characters outside it become `?`.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::new();
+    for ch in text.chars() {
+        match ch {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '\u{2022}' => out.push_str("\\225"),
+            ' '..='~' => out.push(ch),
+            '\u{a0}'..='\u{ff}' => out.push_str(&format!("\\{:03o}", ch as u32)),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKDOWN: &str = "# Axial Report\n\nTarget: `a<b>.rs`\n\n## Signals\n- `panic_signal`: 2\n- done\n\nSee (notes) \\ café 日本\n";
+
+    const RICH: &str = "# Run\n\n| axis | crashes |\n|------|---------|\n| `cpu` | 2 |\n\n- outer\n  1. first\n  2. second\n- \\*not emphasis\\*\n\n```\nfn main() {}\n```\n";
+
+    #[test]
+    fn test_html_groups_lists_and_escapes() {
+        let page = html(MARKDOWN, "en");
+        assert!(page.contains("<html lang=\"en\">"));
+        assert!(page.contains("<title>Axial Report</title>"));
+        assert!(page.contains("<p>Target: <code>a&lt;b&gt;.rs</code></p>"));
+        assert!(page.contains(
+            "<h2>Signals</h2>\n<ul>\n<li><code>panic_signal</code>: 2</li>\n<li>done</li>\n</ul>"
+        ));
+        assert!(page.ends_with("</body>\n</html>\n"));
+    }
+
+    #[test]
+    fn test_html_renders_tables_nested_lists_and_code() {
+        let page = html(RICH, "en");
+        assert!(page.contains("<th>axis</th>"));
+        assert!(page.contains("<td><code>cpu</code></td>"));
+        assert!(page.contains("<li>outer\n<ol>\n<li>first</li>"));
+        assert!(page.contains("<li>*not emphasis*</li>"));
+        assert!(page.contains("<pre><code>fn main() {}\n</code></pre>"));
+    }
+
+    #[test]
+    fn test_pdf_lays_out_tables_nested_lists_and_code() {
+        let lines: Vec<_> = pdf_blocks(RICH)
+            .into_iter()
+            .map(|block| (block.depth, block.marker, block.text))
+            .filter(|(_, _, text)| !text.is_empty())
+            .collect();
+        let bullet = Some("\u{2022}".to_string());
+        assert_eq!(
+            lines,
+            [
+                (0, None, "Run".to_string()),
+                (0, None, "axis | crashes".to_string()),
+                (0, None, "cpu | 2".to_string()),
+                (1, bullet.clone(), "outer".to_string()),
+                (2, Some("1.".to_string()), "first".to_string()),
+                (2, Some("2.".to_string()), "second".to_string()),
+                (1, bullet, "*not emphasis*".to_string()),
+                (0, None, "fn main() {}\n".to_string()),
+            ]
+        );
+        let doc = String::from_utf8_lossy(&pdf(RICH)).into_owned();
+        assert!(doc.contains("(axis | crashes) Tj"));
+        assert!(doc.contains("(    1. first) Tj"));
+        assert!(doc.contains("/F3 10 Tf"));
+    }
+
+    #[test]
+    fn test_pdf_is_paginated_with_valid_xref() {
+        let doc = pdf(MARKDOWN);
+        let text = String::from_utf8_lossy(&doc);
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.contains("(See \\(notes\\) \\\\ caf\\351 ??) Tj"));
+        assert!(text.contains("/Count 1"));
+
+        // Every xref entry points at its object header.
+        let xref_at: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        let entries = text[xref_at..].lines().skip(3);
+        for (idx, entry) in entries.take_while(|line| line.ends_with(" n ")).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", idx + 1)));
+        }
+
+        let long: String = (0..200).map(|idx| format!("- item {}\n", idx)).collect();
+        assert!(String::from_utf8_lossy(&pdf(&long)).contains("/Count 4"));
+        assert_eq!(wrap("aaaa bb cccccccc", 5), ["aaaa", "bb", "ccccc", "ccc"]);
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Service log sources for axial: journald units and syslog files.
+//!
+//! Daemons often react to an attack in their logs while the observed command stays quiet.
+//! A [`LogWindow`] is opened before the observation runs and closed after them; closing it
+//! pulls the journal entries of each unit between the two instants (`journalctl --since
+//! --until`) and the bytes appended to each syslog file in between.
+
+use anyhow::{anyhow, Context, Result};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Log text captured for one source over the window.
+#[derive(Debug, Clone)]
+pub struct CapturedLog {
+    /// `journald:<unit>` or the syslog file path.
+    pub source: PathBuf,
+    pub content: String,
+}
+
+pub struct LogWindow {
+    opened_at: SystemTime,
+    units: Vec<String>,
+    /// Syslog files with their length when the window opened.
+    files: Vec<(PathBuf, u64)>,
+}
+
+impl LogWindow {
+    /// Start the window; fails early when a source cannot be read at all.
+    pub fn open(units: &[String], files: &[PathBuf]) -> Result<Self> {
+        if !units.is_empty() {
+            Command::new("journalctl")
+                .arg("--version")
+                .output()
+                .context("--journal needs journalctl")?;
+        }
+        let files = files
+            .iter()
+            .map(|path| {
+                let len = std::fs::metadata(path)
+                    .with_context(|| format!("reading syslog file {}", path.display()))?
+                    .len();
+                Ok((path.clone(), len))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            opened_at: SystemTime::now(),
+            units: units.to_vec(),
+            files,
+        })
+    }
+
+    /// End the window and collect what every source logged during it.
+    pub fn close(self) -> Result<Vec<CapturedLog>> {
+        let closed_at = SystemTime::now();
+        let mut logs = Vec::new();
+        for unit in &self.units {
+            logs.push(CapturedLog {
+                source: PathBuf::from(format!("journald:{}", unit)),
+                content: journal_lines(unit, self.opened_at, closed_at)?,
+            });
+        }
+        for (path, offset) in &self.files {
+            logs.push(CapturedLog {
+                source: path.clone(),
+                content: appended_since(path, *offset)?,
+            });
+        }
+        Ok(logs)
+    }
+}
+
+fn journal_lines(unit: &str, since: SystemTime, until: SystemTime) -> Result<String> {
+    // journalctl takes whole seconds; widen the window outwards so no entry is cut off.
+    let since = since
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let until = until
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        + 1;
+    let output = Command::new("journalctl")
+        .args(["--unit", unit])
+        .arg(format!("--since=@{}", since))
+        .arg(format!("--until=@{}", until))
+        .args(["--output", "short-iso", "--no-pager", "--quiet"])
+        .output()
+        .context("running journalctl")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "journalctl for unit {} failed: {}",
+            unit,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Text appended to `path` after it was `offset` bytes long; the whole file when it was
+/// rotated or truncated in the meantime.
+fn appended_since(path: &Path, offset: u64) -> Result<String> {
+    let mut file =
+        File::open(path).with_context(|| format!("reading syslog file {}", path.display()))?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(if len < offset { 0 } else { offset }))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn syslog_window_keeps_only_appended_lines() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let path = dir.path().join("syslog");
+        std::fs::write(&path, "Oct 15 09:00:00 host app[1]: before the window\n")
+            .expect("syslog should write");
+
+        let window = LogWindow::open(&[], std::slice::from_ref(&path)).expect("window opens");
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .expect("syslog should open");
+        writeln!(file, "Oct 15 09:00:01 host app[1]: panic: pool exhausted")
+            .expect("syslog should append");
+        let logs = window.close().expect("window closes");
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].source, path);
+        assert!(logs[0].content.contains("pool exhausted"));
+        assert!(!logs[0].content.contains("before the window"));
+
+        // A rotated (shorter) file is read from the start.
+        std::fs::write(&path, "fresh\n").expect("syslog should rotate");
+        assert_eq!(appended_since(&path, 4096).expect("read"), "fresh\n");
+        assert!(LogWindow::open(&[], &[dir.path().join("missing")]).is_err());
+    }
+}
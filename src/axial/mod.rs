@@ -3,15 +3,24 @@
 //! Axial observer: observe target reactions across attack axes from tool
 //! executions and report artifacts.
 
+pub mod export;
+mod journal;
+mod probe;
+mod spell;
+pub mod variance;
+
 use crate::abduct::AbductReport;
 use crate::amuck::AmuckReport;
 use crate::i18n::{t, Lang};
 use crate::report;
+use crate::report::artifacts::{self, ArtifactStore};
+use crate::storage::write_atomic;
+use crate::syscalls::SyscallSummary;
+use crate::types::OutputArtifact;
 use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
@@ -29,6 +38,12 @@ pub struct AxialConfig {
     pub repeat: usize,
     pub timeout_secs: u64,
     pub reports: Vec<PathBuf>,
+    /// systemd units whose journal is observed over the execution runs.
+    pub journal_units: Vec<String>,
+    /// Syslog files whose lines appended during the execution runs are observed.
+    pub syslog_files: Vec<PathBuf>,
+    /// HTTP endpoint polled while the execution runs.
+    pub probe: Option<ProbeConfig>,
     pub head_lines: usize,
     pub tail_lines: usize,
     pub grep_patterns: Vec<String>,
@@ -37,11 +52,18 @@ pub struct AxialConfig {
     pub lang: Lang,
     pub aspell: bool,
     pub aspell_lang: Option<String>,
+    /// Hunspell `.dic` file or directory for the built-in spellchecker; implies `aspell`.
+    pub spell_dict: Option<PathBuf>,
+    /// Campaign seed, recorded in the report.
+    pub seed: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AxialReport {
     pub created_at: String,
+    /// Campaign seed of the run that produced the report.
+    #[serde(default)]
+    pub seed: u64,
     pub target: PathBuf,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub executed_program: Option<String>,
@@ -59,6 +81,51 @@ pub struct AxialReport {
     pub recommendations: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub aspell: Option<SpellcheckSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe: Option<ProbeObservation>,
+    /// How repeated runs differed (see `variance`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_variance: Option<variance::RunVariance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProbeConfig {
+    pub url: String,
+    pub interval: Duration,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbeObservation {
+    pub url: String,
+    pub interval_ms: u64,
+    pub requests: usize,
+    /// Requests that got no HTTP response at all.
+    pub failed_requests: usize,
+    #[serde(default)]
+    pub status_counts: BTreeMap<u16, usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<LatencyPercentiles>,
+    /// First body seen for each status code.
+    #[serde(default)]
+    pub body_snippets: Vec<BodySnippet>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    #[serde(default)]
+    pub signals: Vec<Signal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BodySnippet {
+    pub status: u16,
+    pub snippet: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +151,9 @@ pub struct RunObservation {
     pub signals: Vec<Signal>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spellcheck: Option<SpellcheckResult>,
+    /// Full stdout/stderr when too large to inline (see `report::artifacts`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<OutputArtifact>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -123,6 +193,9 @@ pub struct Signal {
 pub struct SpellcheckResult {
     pub enabled: bool,
     pub lang: String,
+    /// `builtin` or `aspell`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<String>,
     #[serde(default)]
     pub misspellings: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -158,11 +231,29 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
             "axial needs --exec-program or at least one --report"
         ));
     }
+    let watches_logs = !config.journal_units.is_empty() || !config.syslog_files.is_empty();
+    if watches_logs && config.execute.is_none() {
+        return Err(anyhow!(
+            "--journal/--syslog observe the window of --exec-program runs"
+        ));
+    }
+    if config.probe.is_some() && config.execute.is_none() {
+        return Err(anyhow!("--probe-url polls during --exec-program runs"));
+    }
 
     let aspell_lang = config
         .aspell_lang
         .clone()
         .unwrap_or_else(|| config.lang.aspell_code().to_string());
+    // The dictionary is loaded once here rather than per observed text.
+    let speller = if config.aspell || config.spell_dict.is_some() {
+        Some(spell::Speller::select(
+            config.spell_dict.as_deref(),
+            &aspell_lang,
+        )?)
+    } else {
+        None
+    };
 
     // Compile search strategy once so run and report observations stay consistent.
     let matcher = PatternMatcher {
@@ -171,6 +262,9 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
         agrep_distance: config.agrep_distance,
     };
 
+    // Service logs are read for exactly the span of the observation runs.
+    let log_window = journal::LogWindow::open(&config.journal_units, &config.syslog_files)?;
+    let http_probe = config.probe.as_ref().map(probe::start).transpose()?;
     let mut run_observations = Vec::new();
     if let Some(exec) = &config.execute {
         // Repeated observations help surface flaky, timing-dependent reactions.
@@ -183,12 +277,15 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
                 config.head_lines,
                 config.tail_lines,
                 &matcher,
-                config.aspell,
+                speller.as_ref(),
                 &aspell_lang,
             )?);
         }
     }
 
+    let probe = http_probe.map(probe::HttpProbe::stop);
+    let run_variance = variance::compare(&run_observations);
+
     let mut report_observations = Vec::new();
     for path in &config.reports {
         // Report observation reuses the same matcher to align artifact and runtime signals.
@@ -197,10 +294,20 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
             config.head_lines,
             config.tail_lines,
             &matcher,
-            config.aspell,
+            speller.as_ref(),
             &aspell_lang,
         )?);
     }
+    for log in log_window.close()? {
+        report_observations.push(observe_journal(
+            log,
+            config.head_lines,
+            config.tail_lines,
+            &matcher,
+            speller.as_ref(),
+            &aspell_lang,
+        ));
+    }
 
     // Aggregate cross-source signals into a compact map for triage and recommendations.
     let mut signal_counts = BTreeMap::<String, usize>::new();
@@ -214,9 +321,15 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
             *signal_counts.entry(signal.name.clone()).or_insert(0) += 1;
         }
     }
+    for signal in probe.iter().flat_map(|probe| &probe.signals) {
+        *signal_counts.entry(signal.name.clone()).or_insert(0) += 1;
+    }
+    for signal in run_variance.iter().flat_map(|variance| &variance.signals) {
+        *signal_counts.entry(signal.name.clone()).or_insert(0) += 1;
+    }
 
     let recommendations = build_recommendations(&signal_counts, config.lang);
-    let aspell_summary = if config.aspell {
+    let aspell_summary = if speller.is_some() {
         // Spellcheck metrics are useful when scanning social/UX payloads for suspicious wording drift.
         let (total_misspellings, runs_with, reports_with) =
             summarize_spellcheck(&run_observations, &report_observations);
@@ -237,6 +350,7 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
 
     Ok(AxialReport {
         created_at: chrono::Utc::now().to_rfc3339(),
+        seed: config.seed,
         target: config.target,
         executed_program: config.execute.as_ref().map(|e| e.program.clone()),
         repeat: config.repeat,
@@ -248,6 +362,8 @@ pub fn run(config: AxialConfig) -> Result<AxialReport> {
         signal_counts,
         recommendations,
         aspell: aspell_summary,
+        probe,
+        run_variance,
     })
 }
 
@@ -256,8 +372,14 @@ pub fn write_report(report: &AxialReport, path: &Path) -> Result<()> {
         fs::create_dir_all(parent)
             .with_context(|| format!("creating report parent directory {}", parent.display()))?;
     }
-    let json = serde_json::to_string_pretty(report).context("serializing axial report")?;
-    fs::write(path, json).with_context(|| format!("writing report {}", path.display()))?;
+    let mut report = report.clone();
+    let store = ArtifactStore::beside(path);
+    for run in &mut report.run_observations {
+        let fields = [("stdout", &mut run.stdout), ("stderr", &mut run.stderr)];
+        artifacts::spill_fields(&store, &mut run.artifacts, fields)?;
+    }
+    let json = serde_json::to_string_pretty(&report).context("serializing axial report")?;
+    write_atomic(path, json).with_context(|| format!("writing report {}", path.display()))?;
     Ok(())
 }
 
@@ -327,8 +449,53 @@ pub fn write_markdown(report: &AxialReport, path: &Path) -> Result<()> {
             spell.report_observations_with_misspellings
         ));
     }
+    if let Some(probe) = &report.probe {
+        lines.push(String::new());
+        lines.push(format!("## {}", t(lang, "axial.probe")));
+        lines.push(format!("- url: `{}`", probe.url));
+        lines.push(format!(
+            "- requests: {} ({} unanswered)",
+            probe.requests, probe.failed_requests
+        ));
+        for (status, count) in &probe.status_counts {
+            lines.push(format!("- status {}: {}", status, count));
+        }
+        if let Some(latency) = &probe.latency_ms {
+            lines.push(format!(
+                "- latency ms: p50 {:.1}, p90 {:.1}, p99 {:.1}, max {:.1}",
+                latency.p50, latency.p90, latency.p99, latency.max
+            ));
+        }
+        for snippet in &probe.body_snippets {
+            lines.push(format!("- body {}: `{}`", snippet.status, snippet.snippet));
+        }
+    }
+    if let Some(variance) = &report.run_variance {
+        lines.push(String::new());
+        lines.push(format!("## {}", t(lang, "axial.run_variance")));
+        for (code, count) in &variance.exit_codes {
+            lines.push(format!(
+                "- exit {}: {} of {} runs",
+                code, count, variance.runs
+            ));
+        }
+        let spread = &variance.duration_ms;
+        lines.push(format!(
+            "- duration ms: min {}, median {}, max {}, stddev {:.1}",
+            spread.min, spread.median, spread.max, spread.stddev
+        ));
+        for line in &variance.diverging_lines {
+            let runs: Vec<String> = line.runs.iter().map(ToString::to_string).collect();
+            lines.push(format!(
+                "- {} `{}` (runs {})",
+                line.stream,
+                line.line,
+                runs.join(", ")
+            ));
+        }
+    }
 
-    fs::write(path, lines.join("\n"))
+    write_atomic(path, lines.join("\n"))
         .with_context(|| format!("writing markdown report {}", path.display()))?;
     Ok(())
 }
@@ -356,6 +523,7 @@ pub fn convert_markdown_with_pandoc(markdown: &Path, to: &str, output: &Path) ->
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_once(
     command: &ExecutionCommand,
     run_index: usize,
@@ -364,7 +532,7 @@ fn run_once(
     head_lines: usize,
     tail_lines: usize,
     matcher: &PatternMatcher,
-    use_aspell: bool,
+    speller: Option<&spell::Speller>,
     aspell_lang: &str,
 ) -> Result<RunObservation> {
     let target_token = target.to_string_lossy().to_string();
@@ -402,8 +570,8 @@ fn run_once(
     }
 
     let output = child.wait_with_output()?;
-    let stdout = clamp_output(String::from_utf8_lossy(&output.stdout).to_string());
-    let stderr = clamp_output(String::from_utf8_lossy(&output.stderr).to_string());
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
     let stdout_head = head_lines_of(&stdout, head_lines);
     let stdout_tail = tail_lines_of(&stdout, tail_lines);
     let stderr_head = head_lines_of(&stderr, head_lines);
@@ -411,11 +579,7 @@ fn run_once(
 
     let combined = format!("{}\n{}", stdout, stderr);
     let matches = matcher.scan(&combined);
-    let spellcheck = if use_aspell {
-        Some(spellcheck_text(&combined, aspell_lang))
-    } else {
-        None
-    };
+    let spellcheck = speller.map(|speller| speller.check(&combined, aspell_lang));
     // Signal extraction remains heuristic-by-design: fast triage first, deep investigation later.
     let signals = detect_signals(
         &stdout,
@@ -440,6 +604,7 @@ fn run_once(
         matches,
         signals,
         spellcheck,
+        artifacts: Vec::new(),
     })
 }
 
@@ -448,7 +613,7 @@ fn observe_report(
     head_lines: usize,
     tail_lines: usize,
     matcher: &PatternMatcher,
-    use_aspell: bool,
+    speller: Option<&spell::Speller>,
     aspell_lang: &str,
 ) -> Result<ReportObservation> {
     let content =
@@ -456,11 +621,7 @@ fn observe_report(
     let excerpt_head = head_lines_of(&content, head_lines);
     let excerpt_tail = tail_lines_of(&content, tail_lines);
     let matches = matcher.scan(&content);
-    let spellcheck = if use_aspell {
-        Some(spellcheck_text(&content, aspell_lang))
-    } else {
-        None
-    };
+    let spellcheck = speller.map(|speller| speller.check(&content, aspell_lang));
 
     // Parse order prefers assault first because its schema overlaps less with custom report types.
     if let Ok(assault) = report::load_report(path) {
@@ -520,6 +681,12 @@ fn observe_report(
                 evidence: format!("{} amuck execution failures", exec_failures),
             });
         }
+        let summaries = amuck
+            .baseline
+            .iter()
+            .chain(amuck.outcomes.iter().filter_map(|o| o.execution.as_ref()))
+            .filter_map(|e| e.syscall_summary.as_ref());
+        signals.extend(syscall_signals(summaries, "amuck"));
         return Ok(ReportObservation {
             path: path.to_path_buf(),
             kind: "amuck".to_string(),
@@ -548,6 +715,11 @@ fn observe_report(
                 });
             }
         }
+        let summaries = abduct
+            .execution
+            .iter()
+            .filter_map(|e| e.syscall_summary.as_ref());
+        signals.extend(syscall_signals(summaries, "abduct"));
         return Ok(ReportObservation {
             path: path.to_path_buf(),
             kind: "abduct".to_string(),
@@ -562,6 +734,83 @@ fn observe_report(
     Err(anyhow!("unsupported report format: {}", path.display()))
 }
 
+/// Signals from `--trace-syscalls` summaries: exec runs that contacted internet addresses
+/// or started other programs.
+fn syscall_signals<'a>(
+    summaries: impl Iterator<Item = &'a SyscallSummary>,
+    tool: &str,
+) -> Vec<Signal> {
+    let mut contacts = BTreeSet::new();
+    let mut processes = BTreeSet::new();
+    let (mut contacting_runs, mut spawning_runs) = (0usize, 0usize);
+    for summary in summaries {
+        if summary.internet_contacts().next().is_some() {
+            contacting_runs += 1;
+            contacts.extend(summary.internet_contacts());
+        }
+        if !summary.extra_processes().is_empty() {
+            spawning_runs += 1;
+            processes.extend(summary.extra_processes());
+        }
+    }
+    let sample = |set: &BTreeSet<&String>| {
+        set.iter()
+            .take(3)
+            .map(|entry| entry.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut signals = Vec::new();
+    if contacting_runs > 0 {
+        signals.push(Signal {
+            severity: "high".to_string(),
+            name: "network_contact_signal".to_string(),
+            evidence: format!(
+                "{} {} exec runs contacted the network ({})",
+                contacting_runs,
+                tool,
+                sample(&contacts)
+            ),
+        });
+    }
+    if spawning_runs > 0 {
+        signals.push(Signal {
+            severity: "medium".to_string(),
+            name: "process_spawn_signal".to_string(),
+            evidence: format!(
+                "{} {} exec runs started other programs ({})",
+                spawning_runs,
+                tool,
+                sample(&processes)
+            ),
+        });
+    }
+    signals
+}
+
+fn observe_journal(
+    log: journal::CapturedLog,
+    head_lines: usize,
+    tail_lines: usize,
+    matcher: &PatternMatcher,
+    speller: Option<&spell::Speller>,
+    aspell_lang: &str,
+) -> ReportObservation {
+    let content = log.content;
+    let spellcheck = speller.map(|speller| speller.check(&content, aspell_lang));
+    // Service logs carry no exit status, so only the text markers apply.
+    let evidence = format!("journal {}", log.source.display());
+    ReportObservation {
+        kind: "journal".to_string(),
+        excerpt_head: head_lines_of(&content, head_lines),
+        excerpt_tail: tail_lines_of(&content, tail_lines),
+        matches: matcher.scan(&content),
+        signals: detect_signals(&content, "", None, false, &evidence),
+        spellcheck,
+        path: log.source,
+    }
+}
+
 fn detect_signals(
     stdout: &str,
     stderr: &str,
@@ -636,15 +885,6 @@ fn detect_signals(
     signals
 }
 
-fn clamp_output(mut value: String) -> String {
-    const MAX_LEN: usize = 8192;
-    if value.len() > MAX_LEN {
-        value.truncate(MAX_LEN);
-        value.push_str("\n...<truncated>");
-    }
-    value
-}
-
 fn build_recommendations(
     signal_counts: &BTreeMap<String, usize>,
     lang: Lang,
@@ -691,53 +931,6 @@ fn summarize_spellcheck(
     (total, runs_with, reports_with)
 }
 
-fn spellcheck_text(text: &str, lang: &str) -> SpellcheckResult {
-    let output = Command::new("aspell")
-        .arg("list")
-        .arg("--lang")
-        .arg(lang)
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .and_then(|mut child| {
-            if let Some(stdin) = child.stdin.as_mut() {
-                let _ = stdin.write_all(text.as_bytes());
-            }
-            child.wait_with_output()
-        });
-
-    match output {
-        Ok(out) if out.status.success() => {
-            let mut uniq = BTreeSet::new();
-            for word in String::from_utf8_lossy(&out.stdout).lines() {
-                let w = word.trim();
-                if !w.is_empty() {
-                    uniq.insert(w.to_string());
-                }
-            }
-            SpellcheckResult {
-                enabled: true,
-                lang: lang.to_string(),
-                misspellings: uniq.into_iter().collect(),
-                error: None,
-            }
-        }
-        Ok(out) => SpellcheckResult {
-            enabled: false,
-            lang: lang.to_string(),
-            misspellings: Vec::new(),
-            error: Some(String::from_utf8_lossy(&out.stderr).trim().to_string()),
-        },
-        Err(err) => SpellcheckResult {
-            enabled: false,
-            lang: lang.to_string(),
-            misspellings: Vec::new(),
-            error: Some(err.to_string()),
-        },
-    }
-}
-
 #[derive(Debug, Clone)]
 struct PatternMatcher {
     grep_patterns: Vec<String>,
@@ -893,17 +1086,22 @@ mod tests {
             source_spec: None,
             preset: "dangerous".to_string(),
             max_combinations: 1,
+            seed: 0,
             output_dir: PathBuf::from("runtime/amuck"),
             combinations_planned: 1,
             combinations_run: 0,
+            baseline: None,
             outcomes: vec![AmuckOutcome {
                 id: 1,
                 name: "bad".to_string(),
                 operations: vec!["x".to_string()],
                 applied_changes: 0,
+                weak_point: None,
                 mutated_file: None,
                 apply_error: Some("combination produced no change".to_string()),
                 execution: None,
+                comparison: None,
+                cleaned: false,
             }],
         };
         fs::write(
@@ -918,6 +1116,9 @@ mod tests {
             repeat: 1,
             timeout_secs: 30,
             reports: vec![path],
+            journal_units: Vec::new(),
+            syslog_files: Vec::new(),
+            probe: None,
             head_lines: 3,
             tail_lines: 3,
             grep_patterns: vec!["combination".to_string()],
@@ -926,6 +1127,8 @@ mod tests {
             lang: Lang::En,
             aspell: false,
             aspell_lang: None,
+            spell_dict: None,
+            seed: 0,
         })
         .expect("axial should run");
 
@@ -937,11 +1140,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn axial_observes_syslog_lines_written_during_runs() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let target = dir.path().join("target.sh");
+        fs::write(&target, "#!/bin/sh\n").expect("target should write");
+        let syslog = dir.path().join("syslog");
+        fs::write(&syslog, "Oct 15 08:59:59 host svc[7]: FATAL old entry\n")
+            .expect("syslog should write");
+
+        let line = "Oct 15 09:00:00 host svc[7]: segmentation fault in worker";
+        let out = run(AxialConfig {
+            target,
+            execute: Some(ExecutionCommand {
+                program: "sh".to_string(),
+                args: vec![
+                    "-c".to_string(),
+                    format!("echo '{}' >> '{}'", line, syslog.display()),
+                    "{target}".to_string(),
+                ],
+            }),
+            repeat: 1,
+            timeout_secs: 30,
+            reports: Vec::new(),
+            journal_units: Vec::new(),
+            syslog_files: vec![syslog.clone()],
+            probe: None,
+            head_lines: 3,
+            tail_lines: 3,
+            grep_patterns: vec!["worker".to_string()],
+            agrep_patterns: Vec::new(),
+            agrep_distance: 2,
+            lang: Lang::En,
+            aspell: false,
+            aspell_lang: None,
+            spell_dict: None,
+            seed: 0,
+        })
+        .expect("axial should run");
+
+        let journal = &out.report_observations[0];
+        assert_eq!(journal.kind, "journal");
+        assert_eq!(journal.path, syslog);
+        assert_eq!(journal.excerpt_head, vec![line.to_string()]);
+        assert_eq!(journal.matches.len(), 1);
+        let names: Vec<&str> = journal.signals.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["crash_signal"]);
+        assert_eq!(out.signal_counts.get("crash_signal"), Some(&1));
+    }
+
+    #[test]
+    fn syscall_summaries_become_signals() {
+        let quiet = SyscallSummary {
+            files_opened: vec!["/etc/ld.so.cache".to_string()],
+            network_contacts: vec!["unix:/var/run/nscd/socket".to_string()],
+            processes_spawned: vec!["/usr/bin/rustc".to_string()],
+            truncated: false,
+        };
+        assert!(syscall_signals([&quiet].into_iter(), "amuck").is_empty());
+
+        let noisy = SyscallSummary {
+            network_contacts: vec!["93.184.216.34:80".to_string()],
+            processes_spawned: vec!["/bin/sh".to_string(), "/usr/bin/curl".to_string()],
+            ..quiet.clone()
+        };
+        let signals = syscall_signals([&quiet, &noisy].into_iter(), "abduct");
+        let names: Vec<&str> = signals.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["network_contact_signal", "process_spawn_signal"]);
+        assert_eq!(
+            signals[0].evidence,
+            "1 abduct exec runs contacted the network (93.184.216.34:80)"
+        );
+    }
+
     #[test]
     fn markdown_writer_outputs_report() {
         let dir = TempDir::new().expect("tempdir should create");
         let report = AxialReport {
             created_at: chrono::Utc::now().to_rfc3339(),
+            seed: 0,
             target: PathBuf::from("src/main.rs"),
             executed_program: None,
             repeat: 1,
@@ -953,6 +1230,8 @@ mod tests {
             signal_counts: BTreeMap::new(),
             recommendations: vec!["no critical reaction signals observed".to_string()],
             aspell: None,
+            probe: None,
+            run_variance: None,
         };
         let path = dir.path().join("audience.md");
         write_markdown(&report, &path).expect("markdown should write");
@@ -0,0 +1,355 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! HTTP endpoint observation for axial (`--probe-url`).
+//!
+//! Networked targets often react to an attack in what they serve rather than in their output:
+//! a burst of 503s while a pool refills, a health check that suddenly takes seconds. While the
+//! observation runs execute, an [`HttpProbe`] polls a plain `http://` URL at a fixed interval
+//! and records status codes, latency and a body snippet per status. Two heuristics turn the
+//! samples into signals: a run of consecutive 5xx (or unanswered) requests, and a latency
+//! cliff where the median of a few consecutive requests jumps far above the early baseline.
+
+use super::{BodySnippet, LatencyPercentiles, ProbeConfig, ProbeObservation, Signal};
+use crate::ambush::http_request;
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const TICK: Duration = Duration::from_millis(50);
+/// Response bytes read per request; enough for status, headers and a snippet.
+const MAX_RESPONSE_BYTES: u64 = 256 * 1024;
+const SNIPPET_CHARS: usize = 200;
+const MAX_ERRORS: usize = 5;
+/// Consecutive 5xx or unanswered requests that make a burst.
+const BURST_LENGTH: usize = 3;
+/// Requests whose median latency forms the baseline, and the width of the sliding window.
+const LATENCY_WINDOW: usize = 5;
+/// A window median this many times the baseline (and at least `CLIFF_MIN_RISE` above it)
+/// is a cliff.
+const CLIFF_FACTOR: f64 = 5.0;
+const CLIFF_MIN_RISE: Duration = Duration::from_millis(100);
+
+/// One poll: the status code, or `None` when no response arrived.
+#[derive(Debug, Clone, PartialEq)]
+struct Sample {
+    status: Option<u16>,
+    latency: Duration,
+}
+
+/// Background poller; [`HttpProbe::stop`] ends polling and summarizes the samples.
+pub struct HttpProbe {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<ProbeObservation>,
+}
+
+pub fn start(config: &ProbeConfig) -> Result<HttpProbe> {
+    let endpoint = Endpoint::parse(&config.url)?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = stop.clone();
+    let config = config.clone();
+    let handle = thread::spawn(move || {
+        let mut samples = Vec::new();
+        let mut snippets = BTreeMap::new();
+        let mut errors = Vec::new();
+        while !flag.load(Ordering::SeqCst) {
+            let started = Instant::now();
+            match endpoint.get() {
+                Ok((status, body)) => {
+                    samples.push(Sample {
+                        status: Some(status),
+                        latency: started.elapsed(),
+                    });
+                    snippets.entry(status).or_insert_with(|| snippet(&body));
+                }
+                Err(err) => {
+                    samples.push(Sample {
+                        status: None,
+                        latency: started.elapsed(),
+                    });
+                    let err = format!("{:#}", err);
+                    if errors.len() < MAX_ERRORS && !errors.contains(&err) {
+                        errors.push(err);
+                    }
+                }
+            }
+            // Sleep in short slices so stopping does not wait out a long interval.
+            while started.elapsed() < config.interval && !flag.load(Ordering::SeqCst) {
+                thread::sleep((config.interval - started.elapsed()).min(TICK));
+            }
+        }
+        summarize(&config, &samples, snippets, errors)
+    });
+    Ok(HttpProbe { stop, handle })
+}
+
+impl HttpProbe {
+    pub fn stop(self) -> ProbeObservation {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle.join().unwrap_or_else(|_| ProbeObservation {
+            url: String::new(),
+            interval_ms: 0,
+            requests: 0,
+            failed_requests: 0,
+            status_counts: BTreeMap::new(),
+            latency_ms: None,
+            body_snippets: Vec::new(),
+            errors: vec!["HTTP probe panicked".to_string()],
+            signals: Vec::new(),
+        })
+    }
+}
+
+fn summarize(
+    config: &ProbeConfig,
+    samples: &[Sample],
+    snippets: BTreeMap<u16, String>,
+    errors: Vec<String>,
+) -> ProbeObservation {
+    let mut status_counts = BTreeMap::new();
+    for status in samples.iter().filter_map(|sample| sample.status) {
+        *status_counts.entry(status).or_insert(0) += 1;
+    }
+    let mut answered: Vec<Duration> = samples
+        .iter()
+        .filter(|sample| sample.status.is_some())
+        .map(|sample| sample.latency)
+        .collect();
+    answered.sort_unstable();
+    let latency_ms = (!answered.is_empty()).then(|| LatencyPercentiles {
+        p50: millis(percentile(&answered, 50)),
+        p90: millis(percentile(&answered, 90)),
+        p99: millis(percentile(&answered, 99)),
+        max: millis(answered[answered.len() - 1]),
+    });
+    ProbeObservation {
+        url: config.url.clone(),
+        interval_ms: config.interval.as_millis() as u64,
+        requests: samples.len(),
+        failed_requests: samples.iter().filter(|s| s.status.is_none()).count(),
+        status_counts,
+        latency_ms,
+        body_snippets: snippets
+            .into_iter()
+            .map(|(status, snippet)| BodySnippet { status, snippet })
+            .collect(),
+        errors,
+        signals: detect_signals(&config.url, samples),
+    }
+}
+
+fn detect_signals(url: &str, samples: &[Sample]) -> Vec<Signal> {
+    let mut signals = Vec::new();
+
+    // Longest run of server errors or unanswered requests.
+    let (mut longest, mut longest_start, mut run) = (0, 0, 0);
+    for (index, sample) in samples.iter().enumerate() {
+        if sample.status.is_none_or(|status| status >= 500) {
+            run += 1;
+            if run > longest {
+                longest = run;
+                longest_start = index + 1 - run;
+            }
+        } else {
+            run = 0;
+        }
+    }
+    if longest >= BURST_LENGTH {
+        signals.push(Signal {
+            severity: "high".to_string(),
+            name: "http_5xx_burst_signal".to_string(),
+            evidence: format!(
+                "probe {}: {} consecutive 5xx/unanswered requests from request {}",
+                url,
+                longest,
+                longest_start + 1
+            ),
+        });
+    }
+
+    // Latency cliff: a window of answered requests far slower than the first few.
+    let answered: Vec<(usize, Duration)> = samples
+        .iter()
+        .enumerate()
+        .filter(|(_, sample)| sample.status.is_some())
+        .map(|(index, sample)| (index, sample.latency))
+        .collect();
+    if answered.len() >= 2 * LATENCY_WINDOW {
+        let baseline = median(answered[..LATENCY_WINDOW].iter().map(|(_, l)| *l));
+        let cliff = answered[LATENCY_WINDOW..]
+            .windows(LATENCY_WINDOW)
+            .map(|window| (window[0].0, median(window.iter().map(|(_, l)| *l))))
+            .find(|(_, window)| {
+                window.as_secs_f64() >= baseline.as_secs_f64() * CLIFF_FACTOR
+                    && *window >= baseline + CLIFF_MIN_RISE
+            });
+        if let Some((index, window)) = cliff {
+            signals.push(Signal {
+                severity: "medium".to_string(),
+                name: "latency_cliff_signal".to_string(),
+                evidence: format!(
+                    "probe {}: median latency rose from {:.0}ms to {:.0}ms at request {}",
+                    url,
+                    millis(baseline),
+                    millis(window),
+                    index + 1
+                ),
+            });
+        }
+    }
+
+    signals
+}
+
+/// Nearest-rank percentile of sorted, non-empty `values`.
+fn percentile(values: &[Duration], pct: usize) -> Duration {
+    let rank = (pct * values.len()).div_ceil(100).max(1);
+    values[rank - 1]
+}
+
+fn median(values: impl Iterator<Item = Duration>) -> Duration {
+    let mut values: Vec<Duration> = values.collect();
+    values.sort_unstable();
+    values[values.len() / 2]
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn snippet(body: &str) -> String {
+    let flat = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    flat.chars().take(SNIPPET_CHARS).collect()
+}
+
+/// Host and path of an `http://` URL.
+#[derive(Debug, Clone, PartialEq)]
+struct Endpoint {
+    authority: String,
+    path: String,
+}
+
+impl Endpoint {
+    fn parse(url: &str) -> Result<Self> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| anyhow!("--probe-url must be a plain http:// URL: {}", url))?;
+        let (authority, path) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash..]),
+            None => (rest, "/"),
+        };
+        if authority.is_empty() {
+            return Err(anyhow!("--probe-url has no host: {}", url));
+        }
+        Ok(Self {
+            authority: authority.to_string(),
+            path: path.to_string(),
+        })
+    }
+
+    fn get(&self) -> Result<(u16, String)> {
+        http_request(
+            &self.authority,
+            "GET",
+            &self.path,
+            &[("User-Agent", "panic-attack-axial")],
+            "",
+            MAX_RESPONSE_BYTES,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn sample(status: Option<u16>, ms: u64) -> Sample {
+        Sample {
+            status,
+            latency: Duration::from_millis(ms),
+        }
+    }
+
+    #[test]
+    fn signals_for_5xx_bursts_and_latency_cliffs() {
+        let mut samples: Vec<Sample> = (0..6).map(|_| sample(Some(200), 10)).collect();
+        samples.extend([
+            sample(Some(503), 2),
+            sample(None, 5000),
+            sample(Some(502), 3),
+        ]);
+        samples.extend((0..6).map(|_| sample(Some(200), 900)));
+        let names: Vec<String> = detect_signals("http://svc", &samples)
+            .into_iter()
+            .map(|signal| format!("{} {}", signal.name, signal.evidence))
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(names[0].starts_with("http_5xx_burst_signal"));
+        assert!(names[0].contains("3 consecutive 5xx/unanswered requests from request 7"));
+        assert!(names[1].starts_with("latency_cliff_signal"));
+        assert!(names[1].contains("from 10ms to 900ms"));
+
+        let steady: Vec<Sample> = (0..20).map(|i| sample(Some(200), 10 + i % 3)).collect();
+        assert!(detect_signals("http://svc", &steady).is_empty());
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let values: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&values, 50), Duration::from_millis(50));
+        assert_eq!(percentile(&values, 99), Duration::from_millis(99));
+        assert_eq!(percentile(&values[..1], 90), Duration::from_millis(1));
+        assert!(Endpoint::parse("https://svc/health").is_err());
+        assert_eq!(
+            Endpoint::parse("http://svc:8080").unwrap(),
+            Endpoint {
+                authority: "svc:8080".to_string(),
+                path: "/".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn probe_polls_a_live_endpoint() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/health", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for (index, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request);
+                let response = if index % 2 == 0 {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok"
+                } else {
+                    "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 14\r\n\r\npool   drained"
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        let probe = start(&ProbeConfig {
+            url: url.clone(),
+            interval: Duration::from_millis(20),
+        })
+        .unwrap();
+        thread::sleep(Duration::from_millis(300));
+        let observation = probe.stop();
+        assert_eq!(observation.url, url);
+        assert!(observation.requests >= 4);
+        assert_eq!(observation.failed_requests, 0, "{:?}", observation.errors);
+        assert!(observation.status_counts[&200] >= 2);
+        assert!(observation.status_counts[&503] >= 2);
+        assert!(observation.latency_ms.is_some());
+        assert_eq!(
+            observation.body_snippets[1],
+            BodySnippet {
+                status: 503,
+                snippet: "pool drained".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,486 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Spellchecking of observed text (`--aspell`, `--spell-dict`).
+//!
+//! Text is checked in-process against a Hunspell dictionary (a `.dic` word list and the
+//! `.aff` affix file beside it) when one is found: the one given with `--spell-dict`, or
+//! `<lang>.dic` / `<lang>_*.dic` in the usual system directories. The dictionary is
+//! loaded once per axial run and no process is spawned per text. Without a dictionary
+//! the `aspell list` subprocess is used as before.
+//!
+//! No dictionary is bundled with the binary. A container with neither a Hunspell
+//! dictionary (installed, or mounted and given with `--spell-dict`) nor aspell reports
+//! the spellcheck as disabled, and raises no `spelling_signal`.
+//!
+//! The built-in checker understands prefix and suffix rules, their cross products and
+//! the `FLAG` and `SET` settings. Compound words and other Hunspell options are
+//! ignored, so it may flag a few more words than Hunspell would.
+
+use super::SpellcheckResult;
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Where distributions install Hunspell dictionaries.
+const DICTIONARY_DIRS: &[&str] = &[
+    "/usr/share/hunspell",
+    "/usr/share/myspell",
+    "/usr/share/myspell/dicts",
+    "/usr/local/share/hunspell",
+];
+
+/// Spellchecker picked for an axial run.
+#[derive(Debug)]
+pub enum Speller {
+    Builtin(Dictionary),
+    Aspell,
+}
+
+impl Speller {
+    /// The built-in checker when a dictionary for `lang` is found, aspell otherwise.
+    /// `dict` is a `.dic` file or a directory to search; a dictionary given this way
+    /// must exist.
+    pub fn select(dict: Option<&Path>, lang: &str) -> Result<Speller> {
+        let found = match dict {
+            Some(path) if path.is_dir() => {
+                Some(find_dictionary(&[path.to_path_buf()], lang).ok_or_else(|| {
+                    anyhow!("--spell-dict: no {} dictionary in {}", lang, path.display())
+                })?)
+            }
+            Some(path) => Some(path.to_path_buf()),
+            None => {
+                let dirs: Vec<PathBuf> = DICTIONARY_DIRS.iter().map(PathBuf::from).collect();
+                find_dictionary(&dirs, lang)
+            }
+        };
+        match found {
+            Some(path) => Ok(Speller::Builtin(Dictionary::load(&path)?)),
+            None => Ok(Speller::Aspell),
+        }
+    }
+
+    pub fn check(&self, text: &str, lang: &str) -> SpellcheckResult {
+        match self {
+            Speller::Builtin(dictionary) => SpellcheckResult {
+                enabled: true,
+                lang: lang.to_string(),
+                engine: Some("builtin".to_string()),
+                misspellings: dictionary.misspellings(text),
+                error: None,
+            },
+            Speller::Aspell => aspell(text, lang),
+        }
+    }
+}
+
+/// `<lang>.dic` in the first directory that has it, else the first `<lang>_*.dic`.
+fn find_dictionary(dirs: &[PathBuf], lang: &str) -> Option<PathBuf> {
+    for dir in dirs {
+        let exact = dir.join(format!("{}.dic", lang));
+        if exact.is_file() {
+            return Some(exact);
+        }
+        let prefix = format!("{}_", lang);
+        let mut regional: Vec<PathBuf> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "dic")
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix))
+            })
+            .collect();
+        regional.sort();
+        if let Some(path) = regional.into_iter().next() {
+            return Some(path);
+        }
+    }
+    None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FlagType {
+    Char,
+    Long,
+    Num,
+}
+
+impl FlagType {
+    fn parse(self, flags: &str) -> Vec<String> {
+        match self {
+            FlagType::Char => flags.chars().map(String::from).collect(),
+            FlagType::Long => flags
+                .chars()
+                .collect::<Vec<_>>()
+                .chunks(2)
+                .map(|pair| pair.iter().collect())
+                .collect(),
+            FlagType::Num => flags
+                .split(',')
+                .map(|flag| flag.trim().to_string())
+                .filter(|flag| !flag.is_empty())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Affix {
+    flag: String,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    /// Anchored at the start of the stem for prefixes, at its end for suffixes.
+    condition: Option<Regex>,
+}
+
+impl Affix {
+    fn matches(&self, stem: &str) -> bool {
+        self.condition.as_ref().is_none_or(|re| re.is_match(stem))
+    }
+}
+
+/// A Hunspell dictionary loaded for the built-in checker.
+#[derive(Debug)]
+pub struct Dictionary {
+    words: HashMap<String, HashSet<String>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+}
+
+impl Dictionary {
+    /// Load `dic` and, when present, the `.aff` file of the same name.
+    pub fn load(dic: &Path) -> Result<Dictionary> {
+        let aff_path = dic.with_extension("aff");
+        let aff = match fs::read(&aff_path) {
+            Ok(bytes) => Some(bytes),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err).with_context(|| format!("reading {}", aff_path.display())),
+        };
+        let dic_bytes =
+            fs::read(dic).with_context(|| format!("reading dictionary {}", dic.display()))?;
+        Ok(Dictionary::parse(
+            aff.as_deref().unwrap_or_default(),
+            &dic_bytes,
+        ))
+    }
+
+    fn parse(aff: &[u8], dic: &[u8]) -> Dictionary {
+        // SET comes first in practice; read it before decoding the rest.
+        let latin1 = String::from_utf8_lossy(aff).lines().any(|line| {
+            let mut fields = line.split_whitespace();
+            fields.next() == Some("SET")
+                && fields
+                    .next()
+                    .is_some_and(|set| set.eq_ignore_ascii_case("ISO8859-1"))
+        });
+        let decode = |bytes: &[u8]| {
+            if latin1 {
+                bytes.iter().map(|b| *b as char).collect()
+            } else {
+                String::from_utf8_lossy(bytes).into_owned()
+            }
+        };
+        let aff = decode(aff);
+        let dic = decode(dic);
+
+        let mut flag_type = FlagType::Char;
+        let mut prefixes = Vec::new();
+        let mut suffixes = Vec::new();
+        // Header cross-product setting per (PFX|SFX, flag).
+        let mut cross: HashMap<(String, String), bool> = HashMap::new();
+        for line in aff.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", kind, ..] => {
+                    flag_type = match *kind {
+                        "long" => FlagType::Long,
+                        "num" => FlagType::Num,
+                        _ => FlagType::Char,
+                    };
+                }
+                [kind @ ("PFX" | "SFX"), flag, product, count]
+                    if count.parse::<usize>().is_ok() && matches!(*product, "Y" | "N") =>
+                {
+                    cross.insert((kind.to_string(), flag.to_string()), *product == "Y");
+                }
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let is_prefix = *kind == "PFX";
+                    let strip = if *strip == "0" { "" } else { strip };
+                    // Continuation flags after `/` are not followed.
+                    let add = add.split('/').next().unwrap_or_default();
+                    let add = if add == "0" { "" } else { add };
+                    let affix = Affix {
+                        flag: flag.to_string(),
+                        cross_product: cross
+                            .get(&(kind.to_string(), flag.to_string()))
+                            .copied()
+                            .unwrap_or(false),
+                        strip: strip.to_string(),
+                        add: add.to_string(),
+                        condition: rest.first().and_then(|cond| condition(cond, is_prefix)),
+                    };
+                    if is_prefix {
+                        prefixes.push(affix);
+                    } else {
+                        suffixes.push(affix);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut words: HashMap<String, HashSet<String>> = HashMap::new();
+        // The first line is the approximate word count.
+        for line in dic.lines().skip(1) {
+            // Morphological fields follow a tab or space.
+            let entry = line.split(['\t', ' ']).next().unwrap_or_default();
+            if entry.is_empty() {
+                continue;
+            }
+            let (word, flags) = match entry.split_once('/') {
+                Some((word, flags)) => (word, flag_type.parse(flags)),
+                None => (entry, Vec::new()),
+            };
+            words.entry(word.to_string()).or_default().extend(flags);
+        }
+        Dictionary {
+            words,
+            prefixes,
+            suffixes,
+        }
+    }
+
+    fn has(&self, stem: &str, flag: &str) -> bool {
+        self.words
+            .get(stem)
+            .is_some_and(|flags| flags.contains(flag))
+    }
+
+    /// Whether `word` is in the dictionary, directly or through affix rules.
+    fn known(&self, word: &str) -> bool {
+        if self.words.contains_key(word) {
+            return true;
+        }
+        for suffix in &self.suffixes {
+            if let Some(stem) = strip_suffix(word, suffix) {
+                if self.has(&stem, &suffix.flag) {
+                    return true;
+                }
+            }
+        }
+        for prefix in &self.prefixes {
+            let Some(stem) = strip_prefix(word, prefix) else {
+                continue;
+            };
+            if self.has(&stem, &prefix.flag) {
+                return true;
+            }
+            if !prefix.cross_product {
+                continue;
+            }
+            for suffix in self.suffixes.iter().filter(|suffix| suffix.cross_product) {
+                if let Some(root) = strip_suffix(&stem, suffix) {
+                    if self.has(&root, &prefix.flag) && self.has(&root, &suffix.flag) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Whether `word` is spelled correctly; capitalized and upper-case forms of known
+    /// words are accepted.
+    pub fn check(&self, word: &str) -> bool {
+        if self.known(word) {
+            return true;
+        }
+        let lower = word.to_lowercase();
+        let mut chars = lower.chars();
+        let capitalized: String = chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default();
+        if word == word.to_uppercase() {
+            self.known(&lower) || self.known(&capitalized)
+        } else {
+            word == capitalized && self.known(&lower)
+        }
+    }
+
+    /// Distinct misspelled words of `text`, sorted.
+    pub fn misspellings(&self, text: &str) -> Vec<String> {
+        let mut misspelled = BTreeSet::new();
+        for word in words(text) {
+            if !self.check(word) {
+                misspelled.insert(word.to_string());
+            }
+        }
+        misspelled.into_iter().collect()
+    }
+}
+
+fn strip_suffix(word: &str, suffix: &Affix) -> Option<String> {
+    let base = word.strip_suffix(suffix.add.as_str())?;
+    if base.is_empty() {
+        return None;
+    }
+    let stem = format!("{}{}", base, suffix.strip);
+    suffix.matches(&stem).then_some(stem)
+}
+
+fn strip_prefix(word: &str, prefix: &Affix) -> Option<String> {
+    let base = word.strip_prefix(prefix.add.as_str())?;
+    if base.is_empty() {
+        return None;
+    }
+    let stem = format!("{}{}", prefix.strip, base);
+    prefix.matches(&stem).then_some(stem)
+}
+
+/// Hunspell affix condition (`.`, `[abc]`, `[^abc]`, literals) as an anchored regex;
+/// `None` for `.`, which matches every stem.
+fn condition(pattern: &str, is_prefix: bool) -> Option<Regex> {
+    if pattern == "." {
+        return None;
+    }
+    let mut body = String::new();
+    let mut in_class = false;
+    for ch in pattern.chars() {
+        match ch {
+            '[' => {
+                in_class = true;
+                body.push('[');
+            }
+            ']' => {
+                in_class = false;
+                body.push(']');
+            }
+            '^' if in_class && body.ends_with('[') => body.push('^'),
+            '.' if !in_class => body.push('.'),
+            _ => body.push_str(&regex::escape(&ch.to_string())),
+        }
+    }
+    let anchored = if is_prefix {
+        format!("^(?:{})", body)
+    } else {
+        format!("(?:{})$", body)
+    };
+    Regex::new(&anchored).ok()
+}
+
+/// Words of `text` worth checking: runs of letters, with inner apostrophes. Tokens
+/// touching digits or underscores (identifiers, hex, versions) and single letters are
+/// skipped.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '\''))
+        .map(|token| token.trim_matches('\''))
+        .filter(|token| {
+            token.chars().count() > 1 && token.chars().all(|ch| ch.is_alphabetic() || ch == '\'')
+        })
+}
+
+/// Misspellings reported by `aspell list`.
+fn aspell(text: &str, lang: &str) -> SpellcheckResult {
+    let output = Command::new("aspell")
+        .arg("list")
+        .arg("--lang")
+        .arg(lang)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .and_then(|mut child| {
+            if let Some(stdin) = child.stdin.as_mut() {
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            child.wait_with_output()
+        });
+
+    match output {
+        Ok(out) if out.status.success() => {
+            let mut uniq = BTreeSet::new();
+            for word in String::from_utf8_lossy(&out.stdout).lines() {
+                let w = word.trim();
+                if !w.is_empty() {
+                    uniq.insert(w.to_string());
+                }
+            }
+            SpellcheckResult {
+                enabled: true,
+                lang: lang.to_string(),
+                engine: Some("aspell".to_string()),
+                misspellings: uniq.into_iter().collect(),
+                error: None,
+            }
+        }
+        Ok(out) => SpellcheckResult {
+            enabled: false,
+            lang: lang.to_string(),
+            engine: Some("aspell".to_string()),
+            misspellings: Vec::new(),
+            error: Some(String::from_utf8_lossy(&out.stderr).trim().to_string()),
+        },
+        Err(err) => SpellcheckResult {
+            enabled: false,
+            lang: lang.to_string(),
+            engine: Some("aspell".to_string()),
+            misspellings: Vec::new(),
+            error: Some(err.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8\nTRY esianrtolcdugmphbyfvkwz\n\n\
+PFX U Y 1\nPFX U 0 un .\n\n\
+SFX S Y 2\nSFX S y ies [^aeiou]y\nSFX S 0 s [^y]\n\n\
+SFX D N 1\nSFX D 0 ed [^e]\n";
+
+    const DIC: &str = "4\nlock/USD\ncity/S\nmarry/S\npanic\tpo:noun\n";
+
+    #[test]
+    fn test_builtin_dictionary_applies_affix_rules() {
+        let dictionary = Dictionary::parse(AFF.as_bytes(), DIC.as_bytes());
+        for word in [
+            "lock", "locks", "locked", "unlock", "unlocks", "cities", "panic", "Panic", "PANIC",
+        ] {
+            assert!(dictionary.check(word), "{} should be known", word);
+        }
+        // `D` is not a cross product, and `citys` fails the suffix condition.
+        for word in ["unlocked", "citys", "panicy", "pAnic"] {
+            assert!(!dictionary.check(word), "{} should be misspelled", word);
+        }
+        assert_eq!(
+            dictionary.misspellings("Unlocks the citys: panik at 0x7ffd, retry_count v2 'lock'"),
+            ["at", "citys", "panik", "the"]
+        );
+    }
+
+    #[test]
+    fn test_select_finds_regional_dictionaries() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("en_US.aff"), AFF).unwrap();
+        fs::write(dir.path().join("en_US.dic"), DIC).unwrap();
+        let speller = Speller::select(Some(dir.path()), "en").unwrap();
+        let result = speller.check("panik locked", "en");
+        assert_eq!(result.engine.as_deref(), Some("builtin"));
+        assert_eq!(result.misspellings, ["panik"]);
+
+        assert!(Speller::select(Some(dir.path()), "de").is_err());
+        let latin1 = Dictionary::parse(b"SET ISO8859-1\n", b"1\ncaf\xe9\n");
+        assert!(latin1.check("café"));
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Cross-run comparison of repeated observation runs (`--repeat > 1`).
+//!
+//! A target that behaves differently on identical runs is flaky, and the
+//! observer sees it before any test does. The runs are compared on three
+//! things: output lines present in some runs but not others, exit codes, and
+//! the spread of durations. Diverging lines or differing exit codes raise a
+//! `nondeterminism_signal`. Durations are reported but never raise a signal
+//! on their own.
+//!
+//! Lines are compared after masking what changes on every run anyway:
+//! clock times, durations with a unit and hex addresses. A diverging line is
+//! shown as the first run that printed it wrote it.
+
+use super::{RunObservation, Signal};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// Diverging lines kept in a [`RunVariance`].
+const MAX_DIVERGING_LINES: usize = 20;
+
+/// Diverging lines quoted in the signal evidence.
+const EVIDENCE_LINES: usize = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunVariance {
+    pub runs: usize,
+    /// Runs per exit code; `"none"` for runs killed by a signal or timed out.
+    pub exit_codes: BTreeMap<String, usize>,
+    pub duration_ms: DurationSpread,
+    /// Distinct lines not printed by every run.
+    pub diverging_lines_total: usize,
+    /// The first [`MAX_DIVERGING_LINES`] of them.
+    #[serde(default)]
+    pub diverging_lines: Vec<DivergingLine>,
+    #[serde(default)]
+    pub signals: Vec<Signal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DurationSpread {
+    pub min: u128,
+    pub median: u128,
+    pub max: u128,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+/// An output line only some runs printed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DivergingLine {
+    /// `stdout` or `stderr`.
+    pub stream: String,
+    pub line: String,
+    /// 1-based indexes of the runs that printed it.
+    pub runs: Vec<usize>,
+}
+
+/// Compare repeated runs; `None` for fewer than two.
+pub fn compare(runs: &[RunObservation]) -> Option<RunVariance> {
+    if runs.len() < 2 {
+        return None;
+    }
+    let mut exit_codes = BTreeMap::new();
+    for run in runs {
+        let code = run
+            .exit_code
+            .filter(|_| !run.timed_out)
+            .map_or_else(|| "none".to_string(), |code| code.to_string());
+        *exit_codes.entry(code).or_insert(0) += 1;
+    }
+
+    let mut diverging = Vec::new();
+    for stream in ["stdout", "stderr"] {
+        // Masked line -> (line as first printed, runs that printed it).
+        let mut seen: BTreeMap<String, (String, Vec<usize>)> = BTreeMap::new();
+        let mut order = Vec::new();
+        for run in runs {
+            let output = if stream == "stdout" {
+                &run.stdout
+            } else {
+                &run.stderr
+            };
+            for line in output.lines() {
+                let key = mask(line.trim_end());
+                let entry = seen.entry(key.clone()).or_insert_with(|| {
+                    order.push(key);
+                    (line.trim_end().to_string(), Vec::new())
+                });
+                if entry.1.last() != Some(&run.run_index) {
+                    entry.1.push(run.run_index);
+                }
+            }
+        }
+        for key in order {
+            let (line, printed_by) = seen.remove(&key).unwrap_or_default();
+            if printed_by.len() < runs.len() {
+                diverging.push(DivergingLine {
+                    stream: stream.to_string(),
+                    line,
+                    runs: printed_by,
+                });
+            }
+        }
+    }
+
+    let signals = detect_signals(runs.len(), &exit_codes, &diverging);
+    let diverging_lines_total = diverging.len();
+    diverging.truncate(MAX_DIVERGING_LINES);
+    Some(RunVariance {
+        runs: runs.len(),
+        exit_codes,
+        duration_ms: spread(runs.iter().map(|run| run.duration_ms).collect()),
+        diverging_lines_total,
+        diverging_lines: diverging,
+        signals,
+    })
+}
+
+fn detect_signals(
+    runs: usize,
+    exit_codes: &BTreeMap<String, usize>,
+    diverging: &[DivergingLine],
+) -> Vec<Signal> {
+    let mut reasons = Vec::new();
+    if exit_codes.len() > 1 {
+        let codes: Vec<String> = exit_codes
+            .iter()
+            .map(|(code, count)| format!("{}x{}", code, count))
+            .collect();
+        reasons.push(format!("exit codes varied ({})", codes.join(", ")));
+    }
+    if !diverging.is_empty() {
+        let quoted: Vec<String> = diverging
+            .iter()
+            .take(EVIDENCE_LINES)
+            .map(|line| format!("{} `{}`", line.stream, line.line))
+            .collect();
+        reasons.push(format!(
+            "{} output lines differ: {}",
+            diverging.len(),
+            quoted.join("; ")
+        ));
+    }
+    if reasons.is_empty() {
+        return Vec::new();
+    }
+    vec![Signal {
+        severity: "medium".to_string(),
+        name: "nondeterminism_signal".to_string(),
+        evidence: format!("across {} runs, {}", runs, reasons.join("; ")),
+    }]
+}
+
+fn spread(mut durations: Vec<u128>) -> DurationSpread {
+    durations.sort_unstable();
+    let count = durations.len() as f64;
+    let mean = durations.iter().map(|ms| *ms as f64).sum::<f64>() / count;
+    let variance = durations
+        .iter()
+        .map(|ms| (*ms as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    DurationSpread {
+        min: durations[0],
+        median: durations[(durations.len() - 1) / 2],
+        max: durations[durations.len() - 1],
+        mean,
+        stddev: variance.sqrt(),
+    }
+}
+
+/// Mask clock times, durations and hex addresses so they do not count as divergence.
+fn mask(line: &str) -> String {
+    static VOLATILE: OnceLock<[(Regex, &str); 3]> = OnceLock::new();
+    let patterns = VOLATILE.get_or_init(|| {
+        [
+            (
+                Regex::new(r"\d{1,2}:\d{2}:\d{2}(\.\d+)?").expect("valid time pattern"),
+                "<time>",
+            ),
+            (
+                Regex::new(r"\b\d+(\.\d+)?\s?(ns|us|µs|ms|s)\b").expect("valid duration pattern"),
+                "<duration>",
+            ),
+            (
+                Regex::new(r"\b0x[0-9a-fA-F]+\b").expect("valid address pattern"),
+                "<addr>",
+            ),
+        ]
+    });
+    let mut masked = line.to_string();
+    for (pattern, replacement) in patterns {
+        masked = pattern.replace_all(&masked, *replacement).into_owned();
+    }
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(index: usize, exit_code: i32, duration_ms: u128, stdout: &str) -> RunObservation {
+        RunObservation {
+            run_index: index,
+            success: exit_code == 0,
+            exit_code: Some(exit_code),
+            duration_ms,
+            timed_out: false,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            stdout_head: Vec::new(),
+            stdout_tail: Vec::new(),
+            stderr_head: Vec::new(),
+            stderr_tail: Vec::new(),
+            matches: Vec::new(),
+            signals: Vec::new(),
+            spellcheck: None,
+            artifacts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_volatile_output_is_stable_and_real_differences_diverge() {
+        let steady = [
+            run(1, 0, 10, "12:00:01 start\nok in 3ms at 0x7ffd10\n"),
+            run(2, 0, 30, "12:00:02 start\nok in 41ms at 0x7ffe88\n"),
+        ];
+        let variance = compare(&steady).unwrap();
+        assert!(variance.diverging_lines.is_empty());
+        assert!(variance.signals.is_empty());
+        assert_eq!(variance.duration_ms.median, 10);
+        assert_eq!(variance.duration_ms.mean, 20.0);
+
+        let flaky = [
+            run(1, 0, 10, "start\nok\n"),
+            run(2, 1, 12, "start\nretrying\n"),
+            run(3, 0, 11, "start\nok\n"),
+        ];
+        let variance = compare(&flaky).unwrap();
+        assert_eq!(variance.exit_codes.get("1"), Some(&1));
+        let lines: Vec<(&str, &[usize])> = variance
+            .diverging_lines
+            .iter()
+            .map(|line| (line.line.as_str(), line.runs.as_slice()))
+            .collect();
+        assert_eq!(lines, [("ok", &[1, 3][..]), ("retrying", &[2][..])]);
+        assert_eq!(variance.signals[0].name, "nondeterminism_signal");
+        assert!(variance.signals[0]
+            .evidence
+            .starts_with("across 3 runs, exit codes varied (0x2, 1x1)"));
+
+        assert!(compare(&flaky[..1]).is_none());
+    }
+}
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Severity calibration from dynamic outcomes
+//!
+//! Assail's severities are static guesses. The calibration store remembers,
+//! per file and weak-point category, how many assault runs exercised the
+//! weak point (ran one of its recommended axes) and whether any of those runs
+//! crashed. Once a weak point survives `min_runs` exercised runs without a
+//! single crash, later reports lower its severity one step and attach a
+//! `calibrated` marker so the adjustment is never silent.
+
+use crate::storage::write_atomic;
+use crate::types::*;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Default calibration file, written next to the scanned source.
+pub const DEFAULT_CALIBRATION_FILE: &str = ".panic-attack-calibration.json";
+
+/// Clean exercised runs required before a weak point is downgraded.
+pub const DEFAULT_MIN_RUNS: u32 = 5;
+
+/// Outcome counters for one (file, category) pair.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationEntry {
+    /// Runs that exercised at least one of the weak point's recommended axes.
+    pub runs: u32,
+    /// Exercised runs in which one of those axes produced a crash.
+    pub crashes: u32,
+}
+
+/// Persistent outcome history shared by assail (which applies it) and the
+/// report generator (which records into it).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationStore {
+    #[serde(default = "default_min_runs")]
+    pub min_runs: u32,
+    #[serde(default)]
+    pub entries: BTreeMap<String, CalibrationEntry>,
+}
+
+fn default_min_runs() -> u32 {
+    DEFAULT_MIN_RUNS
+}
+
+impl Default for CalibrationStore {
+    fn default() -> Self {
+        Self {
+            min_runs: DEFAULT_MIN_RUNS,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl CalibrationStore {
+    /// Default store location for a scan target (a file or a directory).
+    pub fn default_path(target: &Path) -> PathBuf {
+        let dir = if target.is_dir() {
+            target
+        } else {
+            target.parent().unwrap_or_else(|| Path::new("."))
+        };
+        dir.join(DEFAULT_CALIBRATION_FILE)
+    }
+
+    /// Load a store, starting empty when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("reading calibration store {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("parsing calibration store {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write_atomic(path, json)
+            .with_context(|| format!("writing calibration store {}", path.display()))
+    }
+
+    /// Lower the severity of weak points that have survived enough clean runs.
+    /// Returns how many weak points were calibrated.
+    pub fn apply(&self, report: &mut AssailReport) -> usize {
+        let mut calibrated = 0;
+        for wp in &mut report.weak_points {
+            if wp.calibrated.is_some() {
+                continue;
+            }
+            let Some(key) = entry_key(wp) else {
+                continue;
+            };
+            let Some(entry) = self.entries.get(&key) else {
+                continue;
+            };
+            if entry.crashes > 0 || entry.runs < self.min_runs {
+                continue;
+            }
+            let Some(lowered) = lower(wp.severity) else {
+                continue;
+            };
+            wp.calibrated = Some(CalibrationMarker {
+                original_severity: wp.severity,
+                clean_runs: entry.runs,
+            });
+            wp.severity = lowered;
+            calibrated += 1;
+        }
+        calibrated
+    }
+
+    /// Record one assault run. Each (file, category) pair counts at most once
+    /// per run, and only when one of its recommended axes actually ran.
+    pub fn record(&mut self, report: &AssailReport, results: &[AttackResult]) {
+        let ran: Vec<&AttackResult> = results.iter().filter(|r| !r.skipped).collect();
+        if ran.is_empty() {
+            return;
+        }
+
+        let mut seen = HashSet::new();
+        for wp in &report.weak_points {
+            let Some(key) = entry_key(wp) else {
+                continue;
+            };
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+            // Weak points without a recommendation are exercised by any axis.
+            let relevant: Vec<&&AttackResult> = ran
+                .iter()
+                .filter(|r| {
+                    wp.recommended_attack.is_empty() || wp.recommended_attack.contains(&r.axis)
+                })
+                .collect();
+            if relevant.is_empty() {
+                continue;
+            }
+            let entry = self.entries.entry(key).or_default();
+            entry.runs += 1;
+            if relevant.iter().any(|r| !r.crashes.is_empty()) {
+                entry.crashes += 1;
+            }
+        }
+    }
+}
+
+fn entry_key(wp: &WeakPoint) -> Option<String> {
+    let location = wp.location.as_deref()?;
+    Some(format!("{}::{:?}", location, wp.category))
+}
+
+fn lower(severity: Severity) -> Option<Severity> {
+    match severity {
+        Severity::Critical => Some(Severity::High),
+        Severity::High => Some(Severity::Medium),
+        Severity::Medium => Some(Severity::Low),
+        Severity::Low => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(severity: Severity) -> AssailReport {
+        AssailReport {
+            program_path: PathBuf::from("target"),
+            language: Language::Rust,
+            weak_points: vec![WeakPoint {
+                category: WeakPointCategory::UncheckedAllocation,
+                location: Some("src/alloc.rs".to_string()),
+                severity,
+                description: "unbounded allocation".to_string(),
+                recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn result(axis: AttackAxis, crashed: bool) -> AttackResult {
+        fixtures::result(axis, if crashed { 1 } else { 0 })
+    }
+
+    #[test]
+    fn test_clean_runs_lower_severity_with_marker() {
+        let mut store = CalibrationStore::default();
+        for _ in 0..DEFAULT_MIN_RUNS {
+            // CPU runs do not exercise a memory weak point; only memory counts.
+            store.record(
+                &scan(Severity::High),
+                &[
+                    result(AttackAxis::Memory, false),
+                    result(AttackAxis::Cpu, true),
+                ],
+            );
+        }
+
+        let mut report = scan(Severity::High);
+        assert_eq!(store.apply(&mut report), 1);
+        let wp = &report.weak_points[0];
+        assert_eq!(wp.severity, Severity::Medium);
+        assert_eq!(
+            wp.calibrated,
+            Some(CalibrationMarker {
+                original_severity: Severity::High,
+                clean_runs: DEFAULT_MIN_RUNS,
+            })
+        );
+    }
+
+    #[test]
+    fn test_crash_or_too_few_runs_blocks_calibration() {
+        let mut store = CalibrationStore::default();
+        store.record(&scan(Severity::High), &[result(AttackAxis::Memory, false)]);
+        let mut report = scan(Severity::High);
+        assert_eq!(store.apply(&mut report), 0);
+
+        for _ in 0..DEFAULT_MIN_RUNS {
+            store.record(&scan(Severity::High), &[result(AttackAxis::Memory, false)]);
+        }
+        store.record(&scan(Severity::High), &[result(AttackAxis::Memory, true)]);
+        assert_eq!(store.apply(&mut report), 0);
+        assert_eq!(report.weak_points[0].severity, Severity::High);
+        assert!(report.weak_points[0].calibrated.is_none());
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! `panic-attack ci`: assail the files a branch changed and gate on the result.
+//!
+//! Changed files are everything that differs from the merge base with the base ref:
+//! commits on the branch, staged and unstaged edits, and untracked files git does not
+//! ignore. Binaries can additionally get a short assault on the axes assail recommends,
+//! bounded by one wall-clock budget.
+
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Refs tried, in order, when no base is given (after `origin/$GITHUB_BASE_REF`).
+const BASE_CANDIDATES: &[&str] = &[
+    "origin/HEAD",
+    "origin/main",
+    "origin/master",
+    "main",
+    "master",
+];
+
+/// The ref a pull request targets: `origin/$GITHUB_BASE_REF` in GitHub Actions, else the
+/// first of [`BASE_CANDIDATES`] that names a commit.
+pub fn default_base(repo: &Path) -> Option<String> {
+    std::env::var("GITHUB_BASE_REF")
+        .ok()
+        .filter(|name| !name.is_empty())
+        .map(|name| format!("origin/{}", name))
+        .into_iter()
+        .chain(BASE_CANDIDATES.iter().map(|name| name.to_string()))
+        .find(|name| {
+            git(
+                repo,
+                &[
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    &format!("{}^{{commit}}", name),
+                ],
+            )
+            .is_ok()
+        })
+}
+
+/// Paths under `repo` (relative to it) added, copied, modified or renamed since the merge
+/// base with `base`, including uncommitted and untracked files.
+pub fn changed_files(repo: &Path, base: &str) -> Result<Vec<String>> {
+    let merge_base = git(repo, &["merge-base", base, "HEAD"])
+        .with_context(|| format!("finding the merge base with {}", base))?;
+    let diff = git(
+        repo,
+        &[
+            "diff",
+            "--name-only",
+            "--relative",
+            "--diff-filter=ACMR",
+            merge_base.trim(),
+        ],
+    )?;
+    let untracked = git(repo, &["ls-files", "--others", "--exclude-standard"])?;
+    let files: BTreeSet<String> = diff
+        .lines()
+        .chain(untracked.lines())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    Ok(files.into_iter().collect())
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .context("running git")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Weak points at or above `fail_on`, which fail the gate.
+pub fn blocking(report: &AssailReport, fail_on: Severity) -> Vec<&WeakPoint> {
+    report
+        .weak_points
+        .iter()
+        .filter(|wp| wp.severity >= fail_on)
+        .collect()
+}
+
+/// A light assault on `binaries` over `axes`, all axes sharing `budget`.
+pub fn micro_assault_config(
+    binaries: Vec<std::path::PathBuf>,
+    axes: Vec<AttackAxis>,
+    budget: Duration,
+    seed: u64,
+) -> AttackConfig {
+    let per_axis = budget / axes.len().max(1) as u32;
+    AttackConfig {
+        axes,
+        duration: per_axis,
+        intensity: IntensityLevel::Light,
+        target_programs: binaries,
+        seed,
+        timeout: Some(budget),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn commit(repo: &Path, message: &str) -> bool {
+        git(repo, &["add", "."]).is_ok()
+            && git(
+                repo,
+                &[
+                    "-c",
+                    "user.name=t",
+                    "-c",
+                    "user.email=t@t",
+                    "commit",
+                    "-qm",
+                    message,
+                ],
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn test_changed_files_since_merge_base() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        if git(repo, &["init", "-q", "-b", "main"]).is_err() {
+            return; // git unavailable
+        }
+        fs::write(repo.join("kept.rs"), "fn kept() {}\n").unwrap();
+        fs::write(repo.join("gone.rs"), "fn gone() {}\n").unwrap();
+        assert!(commit(repo, "base"));
+        assert_eq!(default_base(repo).as_deref(), Some("main"));
+
+        git(repo, &["checkout", "-qb", "feature"]).unwrap();
+        fs::write(repo.join("branch.rs"), "fn branch() {}\n").unwrap();
+        fs::remove_file(repo.join("gone.rs")).unwrap();
+        assert!(commit(repo, "feature"));
+        fs::write(repo.join("kept.rs"), "fn kept() { edited() }\n").unwrap();
+        fs::write(repo.join("new.rs"), "fn new() {}\n").unwrap();
+
+        assert_eq!(
+            changed_files(repo, "main").unwrap(),
+            vec!["branch.rs", "kept.rs", "new.rs"]
+        );
+        assert!(changed_files(repo, "no-such-ref").is_err());
+    }
+
+    #[test]
+    fn test_micro_assault_budget_is_shared() {
+        let config = micro_assault_config(
+            vec!["./app".into()],
+            vec![AttackAxis::Cpu, AttackAxis::Memory],
+            Duration::from_secs(30),
+            7,
+        );
+        assert_eq!(config.duration, Duration::from_secs(15));
+        assert_eq!(config.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.intensity, IntensityLevel::Light);
+    }
+}
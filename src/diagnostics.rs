@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: PMPL-1.0-or-later
 
 use crate::a2ml::Manifest;
+use crate::lock;
+use crate::porcelain;
 use anyhow::{anyhow, Context, Result};
 use std::env;
 use std::fs;
@@ -10,7 +12,7 @@ const HYPATIA_ENV: &str = "HYPATIA_API_KEY";
 const GITBOT_FLEET_ENV: &str = "GITBOT_FLEET_ENDPOINT";
 const PANICBOT_DIRECTIVE_PATH: &str = ".machine_readable/bot_directives/panicbot.scm";
 
-pub fn run_self_diagnostics(manifest: &Manifest) -> Result<()> {
+pub fn run_self_diagnostics(manifest: &Manifest, store: &Path) -> Result<()> {
     println!("panic-attack self-diagnostics");
 
     let mut checks = Vec::new();
@@ -38,6 +40,7 @@ pub fn run_self_diagnostics(manifest: &Manifest) -> Result<()> {
         Severity::Warn,
     ));
     checks.push(check_verisimdb(Path::new("verisimdb-data/verisimdb")));
+    checks.push(check_campaign_lock(store));
     checks.push(check_file(
         "ambush timeline spec",
         Path::new("docs/ambush-timeline.md"),
@@ -55,6 +58,10 @@ pub fn run_self_diagnostics(manifest: &Manifest) -> Result<()> {
     println!();
     for entry in &checks {
         entry.print();
+        porcelain::record(
+            &entry.label.to_lowercase().replace(' ', "_"),
+            entry.level.tag().to_lowercase(),
+        );
     }
 
     if checks
@@ -209,6 +216,9 @@ fn check_panicbot_readiness() -> Diagnostic {
             severity: crate::types::Severity::High,
             description: "test".to_string(),
             recommended_attack: vec![],
+            provenance: None,
+            calibrated: None,
+            owner: None,
         }],
         statistics: crate::types::ProgramStatistics::default(),
         file_statistics: vec![],
@@ -216,6 +226,9 @@ fn check_panicbot_readiness() -> Diagnostic {
         dependency_graph: Default::default(),
         taint_matrix: Default::default(),
         migration_metrics: None,
+        annotations: Vec::new(),
+        policies: Vec::new(),
+        manifests: Vec::new(),
     };
 
     let json_ok = match serde_json::to_value(&test_report) {
@@ -235,7 +248,7 @@ fn check_panicbot_readiness() -> Diagnostic {
     match (has_directives, json_ok) {
         (true, true) => Diagnostic::ok(
             "panicbot integration",
-            "directives present, JSON contract verified (PA001–PA020)".to_string(),
+            "directives present, JSON contract verified (PA001–PA025)".to_string(),
         ),
         (false, true) => Diagnostic::warning(
             "panicbot integration",
@@ -256,6 +269,34 @@ fn check_panicbot_readiness() -> Diagnostic {
     }
 }
 
+/// Report who holds the campaign lock on `store` and how often campaigns contended for it.
+fn check_campaign_lock(store: &Path) -> Diagnostic {
+    let holder = lock::current_holder(store);
+    let events = match lock::contention_events(store) {
+        Ok(events) => events,
+        Err(err) => return Diagnostic::warning("campaign lock", format!("{:#}", err)),
+    };
+    let held = match &holder {
+        Some(holder) => format!("held by {}", lock::describe(Some(holder))),
+        None => "free".to_string(),
+    };
+    match events.last() {
+        None => Diagnostic::ok("campaign lock", held),
+        Some(last) => Diagnostic::warning(
+            "campaign lock",
+            format!(
+                "{}; {} contention event(s), last: {} {} at {} ({})",
+                held,
+                events.len(),
+                last.command,
+                last.action,
+                last.at,
+                lock::describe(last.holder.as_ref())
+            ),
+        ),
+    }
+}
+
 /// Check attestation readiness: whether signing is available and if a
 /// signing key is configured via the `PANIC_ATTACK_SIGNING_KEY` env var.
 fn check_attestation_health() -> Diagnostic {
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Typed errors for the library surface
+//!
+//! `assail::analyze*`, `attack::execute_attack*`, the `storage` entry points and `schema`
+//! validation return these instead of a bare `anyhow::Error`, so callers can tell "target missing" from "permission
+//! denied" from "unsupported axis" without matching on message text. Each variant has a
+//! stable [`code`](AttackError::code) (`attack.target_missing`, ...) that the CLI prints as
+//! `Error [code]: ...` and that pipeline manifests and kin heartbeats carry as `error_code`.
+//!
+//! Failures nobody needs to branch on stay wrapped in `Other`, which is transparent: its
+//! message and source chain are those of the wrapped error.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Code for errors no typed variant covers.
+pub const UNCLASSIFIED: &str = "unclassified";
+
+#[derive(Debug)]
+pub enum AttackError {
+    TargetMissing(PathBuf),
+    PermissionDenied(PathBuf),
+    UnsupportedAxis(String),
+    Other(anyhow::Error),
+}
+
+#[derive(Debug)]
+pub enum AssailError {
+    TargetMissing(PathBuf),
+    PermissionDenied(PathBuf),
+    Other(anyhow::Error),
+}
+
+#[derive(Debug)]
+pub enum StorageError {
+    DirectoryMissing(PathBuf),
+    NotEnoughReports {
+        dir: PathBuf,
+        needed: usize,
+        found: usize,
+    },
+    PermissionDenied(PathBuf),
+    /// A `reports query` expression that does not parse.
+    InvalidQuery(String),
+    Other(anyhow::Error),
+}
+
+#[derive(Debug)]
+pub enum SchemaError {
+    /// The document is not valid json/yaml.
+    Unparseable(anyhow::Error),
+    /// The document parses but breaks its schema; one entry per issue.
+    Invalid {
+        label: &'static str,
+        path: PathBuf,
+        issues: Vec<String>,
+    },
+}
+
+impl AttackError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AttackError::TargetMissing(_) => "attack.target_missing",
+            AttackError::PermissionDenied(_) => "attack.permission_denied",
+            AttackError::UnsupportedAxis(_) => "attack.unsupported_axis",
+            AttackError::Other(err) => typed_code(err).unwrap_or("attack.failed"),
+        }
+    }
+
+    /// Classify a failure to reach `path`.
+    pub fn from_io(path: &Path, err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => AttackError::TargetMissing(path.to_path_buf()),
+            io::ErrorKind::PermissionDenied => AttackError::PermissionDenied(path.to_path_buf()),
+            _ => AttackError::Other(anyhow::Error::new(err)),
+        }
+    }
+}
+
+impl AssailError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AssailError::TargetMissing(_) => "assail.target_missing",
+            AssailError::PermissionDenied(_) => "assail.permission_denied",
+            AssailError::Other(err) => typed_code(err).unwrap_or("assail.failed"),
+        }
+    }
+
+    pub fn from_io(path: &Path, err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => AssailError::TargetMissing(path.to_path_buf()),
+            io::ErrorKind::PermissionDenied => AssailError::PermissionDenied(path.to_path_buf()),
+            _ => AssailError::Other(anyhow::Error::new(err)),
+        }
+    }
+}
+
+impl StorageError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            StorageError::DirectoryMissing(_) => "storage.directory_missing",
+            StorageError::NotEnoughReports { .. } => "storage.not_enough_reports",
+            StorageError::PermissionDenied(_) => "storage.permission_denied",
+            StorageError::InvalidQuery(_) => "storage.invalid_query",
+            StorageError::Other(err) => typed_code(err).unwrap_or("storage.failed"),
+        }
+    }
+
+    pub fn from_io(path: &Path, err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::PermissionDenied => StorageError::PermissionDenied(path.to_path_buf()),
+            _ => StorageError::Other(anyhow::Error::new(err)),
+        }
+    }
+}
+
+impl SchemaError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            SchemaError::Unparseable(_) => "schema.unparseable",
+            SchemaError::Invalid { .. } => "schema.invalid",
+        }
+    }
+}
+
+impl fmt::Display for AttackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttackError::TargetMissing(path) => {
+                write!(f, "target program {} does not exist", path.display())
+            }
+            AttackError::PermissionDenied(path) => {
+                write!(f, "permission denied running {}", path.display())
+            }
+            AttackError::UnsupportedAxis(axis) => write!(
+                f,
+                "unsupported axis `{}` (expected cpu, memory, disk, network, concurrency, time, \
+                 signal or a custom axis registered in the profile's custom_axes)",
+                axis
+            ),
+            AttackError::Other(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl fmt::Display for AssailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssailError::TargetMissing(path) => {
+                write!(f, "Target does not exist: {}", path.display())
+            }
+            AssailError::PermissionDenied(path) => {
+                write!(f, "permission denied reading {}", path.display())
+            }
+            AssailError::Other(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::DirectoryMissing(dir) => {
+                write!(f, "storage directory not found: {}", dir.display())
+            }
+            StorageError::NotEnoughReports { dir, needed, found } => write!(
+                f,
+                "not enough reports in {} (need {}, found {})",
+                dir.display(),
+                needed,
+                found
+            ),
+            StorageError::PermissionDenied(path) => {
+                write!(f, "permission denied writing {}", path.display())
+            }
+            StorageError::InvalidQuery(message) => f.write_str(message),
+            StorageError::Other(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::Unparseable(err) => fmt::Display::fmt(err, f),
+            SchemaError::Invalid {
+                label,
+                path,
+                issues,
+            } => {
+                write!(f, "{} {} is invalid:", label, path.display())?;
+                for issue in issues {
+                    write!(f, "\n  - {}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SchemaError::Unparseable(err) => err.source(),
+            SchemaError::Invalid { .. } => None,
+        }
+    }
+}
+
+macro_rules! transparent_other {
+    ($($ty:ident),*) => {$(
+        impl std::error::Error for $ty {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $ty::Other(err) => err.source(),
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<anyhow::Error> for $ty {
+            fn from(err: anyhow::Error) -> Self {
+                $ty::Other(err)
+            }
+        }
+    )*};
+}
+
+transparent_other!(AttackError, AssailError, StorageError);
+
+impl From<io::Error> for StorageError {
+    fn from(err: io::Error) -> Self {
+        StorageError::Other(err.into())
+    }
+}
+
+impl From<serde_json::Error> for StorageError {
+    fn from(err: serde_json::Error) -> Self {
+        StorageError::Other(err.into())
+    }
+}
+
+/// Stable code for `err`: the first typed error in its chain, else the kind of an I/O error
+/// in it, else [`UNCLASSIFIED`].
+pub fn code(err: &anyhow::Error) -> &'static str {
+    typed_code(err)
+        .or_else(|| {
+            err.chain()
+                .find_map(|cause| cause.downcast_ref::<io::Error>())
+                .and_then(|io| match io.kind() {
+                    io::ErrorKind::NotFound => Some("io.not_found"),
+                    io::ErrorKind::PermissionDenied => Some("io.permission_denied"),
+                    _ => None,
+                })
+        })
+        .unwrap_or(UNCLASSIFIED)
+}
+
+fn typed_code(err: &anyhow::Error) -> Option<&'static str> {
+    err.chain().find_map(|cause| {
+        if let Some(err) = cause.downcast_ref::<AttackError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<AssailError>() {
+            Some(err.code())
+        } else if let Some(err) = cause.downcast_ref::<SchemaError>() {
+            Some(err.code())
+        } else {
+            cause.downcast_ref::<StorageError>().map(StorageError::code)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn codes_survive_context_and_wrapping() {
+        let missing: anyhow::Error = AttackError::TargetMissing(PathBuf::from("./app")).into();
+        let wrapped = Err::<(), _>(missing)
+            .context("running stage `stress`")
+            .unwrap_err();
+        assert_eq!(code(&wrapped), "attack.target_missing");
+        assert_eq!(
+            format!("{:#}", wrapped),
+            "running stage `stress`: target program ./app does not exist"
+        );
+
+        // A typed error inside `Other` keeps its own code.
+        let nested = AttackError::Other(AssailError::PermissionDenied(PathBuf::from("src")).into());
+        assert_eq!(nested.code(), "assail.permission_denied");
+
+        let io = anyhow::Error::new(io::Error::from(io::ErrorKind::PermissionDenied))
+            .context("opening report");
+        assert_eq!(code(&io), "io.permission_denied");
+        assert_eq!(code(&anyhow::anyhow!("boom")), UNCLASSIFIED);
+        assert_eq!(
+            AttackError::from_io(Path::new("x"), io::ErrorKind::NotFound.into()).code(),
+            "attack.target_missing"
+        );
+
+        let invalid: anyhow::Error = SchemaError::Invalid {
+            label: "timeline",
+            path: PathBuf::from("t.yaml"),
+            issues: vec!["$.events: missing".to_string()],
+        }
+        .into();
+        assert_eq!(code(&invalid.context("validating")), "schema.invalid");
+    }
+}
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Crash-bucket history for telling recurring failures from new ones
+//!
+//! The flakiness store remembers every crash bucket (see
+//! [`crash_bucket`](crate::attack::explore::crash_bucket)) that assault and
+//! ambush runs have hit: when it was first and last seen, how many runs hit
+//! it, and on which hosts. Each new report gets a `crash_history` entry per
+//! bucket saying whether it is a new failure, a known one, or one a
+//! maintainer has acknowledged with `panic-attack flaky ack`.
+
+use crate::attack::explore::crash_bucket;
+use crate::storage::write_atomic;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Default store, in the directory panic-attack runs from.
+pub const DEFAULT_FLAKY_FILE: &str = ".panic-attack-flaky.json";
+
+/// History of one crash bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlakyEntry {
+    /// RFC 3339 time of the first run that hit the bucket.
+    pub first_seen: String,
+    pub last_seen: String,
+    /// Runs that hit the bucket; a run counts once however many results crashed.
+    pub occurrences: u32,
+    /// Hosts the bucket crashed on, as `os arch [container]`.
+    #[serde(default)]
+    pub environments: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acknowledged: Option<Acknowledgement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Acknowledgement {
+    pub at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlakyStore {
+    #[serde(default)]
+    pub buckets: BTreeMap<String, FlakyEntry>,
+}
+
+impl FlakyStore {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(DEFAULT_FLAKY_FILE)
+    }
+
+    /// Load a store, starting empty when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("reading flakiness store {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("parsing flakiness store {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write_atomic(path, json)
+            .with_context(|| format!("writing flakiness store {}", path.display()))
+    }
+
+    /// Record the crash buckets of one run at time `now` and return how each stands,
+    /// classified against the history before this run.
+    pub fn record(&mut self, report: &AssaultReport, now: &str) -> Vec<CrashHistory> {
+        let environment = report.host_environment.as_ref().map(environment_label);
+        let buckets: BTreeSet<String> = report
+            .attack_results
+            .iter()
+            .filter_map(crash_bucket)
+            .collect();
+
+        buckets
+            .into_iter()
+            .map(|bucket| {
+                let known = self.buckets.contains_key(&bucket);
+                let entry = self
+                    .buckets
+                    .entry(bucket.clone())
+                    .or_insert_with(|| FlakyEntry {
+                        first_seen: now.to_string(),
+                        last_seen: now.to_string(),
+                        occurrences: 0,
+                        environments: Vec::new(),
+                        acknowledged: None,
+                    });
+                entry.last_seen = now.to_string();
+                entry.occurrences += 1;
+                if let Some(environment) = &environment {
+                    if !entry.environments.contains(environment) {
+                        entry.environments.push(environment.clone());
+                    }
+                }
+                let status = match (&entry.acknowledged, known) {
+                    (Some(_), _) => CrashHistoryStatus::Acknowledged,
+                    (None, true) => CrashHistoryStatus::KnownFlaky,
+                    (None, false) => CrashHistoryStatus::NewFailure,
+                };
+                CrashHistory {
+                    bucket,
+                    status,
+                    first_seen: entry.first_seen.clone(),
+                    occurrences: entry.occurrences,
+                    note: entry.acknowledged.as_ref().and_then(|ack| ack.note.clone()),
+                }
+            })
+            .collect()
+    }
+
+    /// Mark a bucket as a known, accepted failure.
+    pub fn acknowledge(&mut self, bucket: &str, note: Option<String>, now: &str) -> Result<()> {
+        let entry = self
+            .buckets
+            .get_mut(bucket)
+            .ok_or_else(|| anyhow!("no crash bucket `{}` in the flakiness store", bucket))?;
+        entry.acknowledged = Some(Acknowledgement {
+            at: now.to_string(),
+            note,
+        });
+        Ok(())
+    }
+}
+
+fn environment_label(host: &HostEnvironment) -> String {
+    match &host.container {
+        Some(container) => format!("{} {} [{}]", host.os, host.arch, container),
+        None => format!("{} {}", host.os, host.arch),
+    }
+}
+
+impl CrashHistory {
+    /// One-line summary, e.g. `known flaky since 2026-03-02 (4 runs)`.
+    pub fn describe(&self) -> String {
+        let since = self.first_seen.get(..10).unwrap_or(&self.first_seen);
+        match self.status {
+            CrashHistoryStatus::NewFailure => "new failure".to_string(),
+            CrashHistoryStatus::KnownFlaky => {
+                format!("known flaky since {} ({} runs)", since, self.occurrences)
+            }
+            CrashHistoryStatus::Acknowledged => match &self.note {
+                Some(note) => format!("acknowledged, seen since {}: {}", since, note),
+                None => format!("acknowledged, seen since {}", since),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn crash(axis: AttackAxis, exit_code: i32) -> AttackResult {
+        fixtures::result(axis, exit_code)
+    }
+
+    fn report(results: Vec<AttackResult>, container: Option<&str>) -> AssaultReport {
+        AssaultReport {
+            assail_report: AssailReport {
+                program_path: PathBuf::from("app"),
+                language: Language::Rust,
+                frameworks: Vec::new(),
+                weak_points: Vec::new(),
+                statistics: ProgramStatistics::default(),
+                file_statistics: Vec::new(),
+                recommended_attacks: Vec::new(),
+                dependency_graph: DependencyGraph::default(),
+                taint_matrix: TaintMatrix::default(),
+                migration_metrics: None,
+                annotations: Vec::new(),
+                policies: Vec::new(),
+                manifests: Vec::new(),
+            },
+            total_crashes: results.iter().map(|r| r.crashes.len()).sum(),
+            attack_results: results,
+            total_signatures: 0,
+            overall_assessment: OverallAssessment {
+                robustness_score: 50.0,
+                critical_issues: Vec::new(),
+                recommendations: Vec::new(),
+            },
+            host_environment: Some(HostEnvironment {
+                os: "linux".to_string(),
+                kernel: None,
+                arch: "x86_64".to_string(),
+                cpu_model: None,
+                cpu_count: 4,
+                total_memory_bytes: None,
+                cgroup_version: None,
+                container: container.map(str::to_string),
+                limits: ResourceLimits::default(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_classifies_new_known_and_acknowledged() {
+        let mut store = FlakyStore::default();
+        let first = report(
+            vec![
+                crash(AttackAxis::Memory, 101),
+                crash(AttackAxis::Memory, 101),
+            ],
+            None,
+        );
+        let history = store.record(&first, "2026-03-02T10:00:00+00:00");
+        assert_eq!(history.len(), 1, "one bucket, counted once per run");
+        assert_eq!(history[0].bucket, "memory:exit-101");
+        assert_eq!(history[0].status, CrashHistoryStatus::NewFailure);
+        assert_eq!(history[0].describe(), "new failure");
+
+        let second = report(
+            vec![crash(AttackAxis::Memory, 101), crash(AttackAxis::Cpu, 134)],
+            Some("docker"),
+        );
+        let history = store.record(&second, "2026-03-05T10:00:00+00:00");
+        assert_eq!(history[0].bucket, "cpu:exit-134");
+        assert_eq!(history[0].status, CrashHistoryStatus::NewFailure);
+        assert_eq!(history[1].status, CrashHistoryStatus::KnownFlaky);
+        assert_eq!(
+            history[1].describe(),
+            "known flaky since 2026-03-02 (2 runs)"
+        );
+        let entry = &store.buckets["memory:exit-101"];
+        assert_eq!(entry.last_seen, "2026-03-05T10:00:00+00:00");
+        assert_eq!(
+            entry.environments,
+            vec!["linux x86_64", "linux x86_64 [docker]"]
+        );
+
+        store
+            .acknowledge(
+                "memory:exit-101",
+                Some("upstream allocator bug".into()),
+                "now",
+            )
+            .unwrap();
+        assert!(store.acknowledge("disk:killed", None, "now").is_err());
+        let history = store.record(&first, "2026-03-06T10:00:00+00:00");
+        assert_eq!(history[0].status, CrashHistoryStatus::Acknowledged);
+        assert_eq!(
+            history[0].describe(),
+            "acknowledged, seen since 2026-03-02: upstream allocator bug"
+        );
+    }
+}
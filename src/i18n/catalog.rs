@@ -30,8 +30,9 @@ use serde::{Deserialize, Serialize};
 /// Each variant maps to an ISO 639-1 two-letter code. The enum is used by
 /// the CLI `--lang` flag and by report generators that emit human-readable
 /// text (axial markdown, assault recommendations, adjudicate verdicts).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum Lang {
+    #[default]
     En,
     Es,
     Fr,
@@ -111,12 +112,6 @@ impl Lang {
     }
 }
 
-impl Default for Lang {
-    fn default() -> Self {
-        Lang::En
-    }
-}
-
 impl std::fmt::Display for Lang {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.code())
@@ -162,7 +157,7 @@ pub fn t(lang: Lang, key: &str) -> &'static str {
 /// Non-static variant: returns the translation or the key itself if missing.
 /// Useful when you need to own the result.
 #[allow(dead_code)]
-pub fn t_or_key<'a>(lang: Lang, key: &'a str) -> &'a str {
+pub fn t_or_key(lang: Lang, key: &str) -> &str {
     let result = t(lang, key);
     if result.is_empty() {
         key
@@ -211,6 +206,8 @@ const EN: &[(&str, &str)] = &[
     ("axial.signals", "Signals"),
     ("axial.recommendations", "Recommendations"),
     ("axial.spelling", "Spelling"),
+    ("axial.probe", "HTTP Probe"),
+    ("axial.run_variance", "Run Variance"),
     ("axial.none", "none"),
     // Axial recommendations
     ("rec.crash", "prioritize crash triage and backtrace collection"),
@@ -244,6 +241,13 @@ const EN: &[(&str, &str)] = &[
     ("adjudicate.title", "Adjudicate Verdict"),
     ("adjudicate.campaigns", "Campaigns Analyzed"),
     ("adjudicate.verdict", "Overall Verdict"),
+    ("adjudicate.summary", "Executive Summary"),
+    ("adjudicate.risk_score", "Risk Score"),
+    ("adjudicate.trend", "Trend"),
+    ("adjudicate.priorities", "Top Priorities"),
+    ("adjudicate.reports", "Underlying Reports"),
+    ("adjudicate.no_previous", "no previous campaign"),
+    ("adjudicate.since_previous", "since previous campaign"),
     // Ambush
     ("ambush.title", "Ambush Report"),
     ("ambush.timeline", "Timeline Events"),
@@ -256,6 +260,19 @@ const EN: &[(&str, &str)] = &[
     ("abduct.title", "Abduct Isolation Report"),
     ("abduct.isolated_files", "Isolated Files"),
     ("abduct.scope", "Dependency Scope"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "Program crashed under {axis} attack ({count} crashes)"),
+    ("assess.high_confidence", "High-confidence {signature} detected (confidence: {confidence})"),
+    ("assess.rec.error_handling", "Add comprehensive error handling for edge cases"),
+    ("assess.rec.unwrap", "Replace {count} unwrap() calls with proper error handling"),
+    ("assess.rec.unsafe", "Audit {count} unsafe blocks for memory safety violations"),
+    ("assess.rec.data_race", "Add synchronization primitives to prevent data races"),
+    ("assess.rec.deadlock", "Review lock ordering to prevent deadlocks"),
+    ("assess.rec.livelock", "Add backoff or progress checks to retry and spin loops"),
+    ("assess.rec.leak", "Check for memory, file descriptor or thread leaks: usage kept growing under load"),
+    ("assess.rec.infinite_loop", "Check the loop exit condition where the hung target was sampled"),
+    ("assess.rec.stall", "Investigate why the target stops making progress under load"),
+    ("assess.rec.refactor", "Consider comprehensive refactoring for robustness (score {score}/100)"),
 ];
 
 // ─── Spanish ────────────────────────────────────────────────────────
@@ -270,6 +287,8 @@ const ES: &[(&str, &str)] = &[
     ("axial.signals", "Señales"),
     ("axial.recommendations", "Recomendaciones"),
     ("axial.spelling", "Ortografía"),
+    ("axial.probe", "Sonda HTTP"),
+    ("axial.run_variance", "Variación entre ejecuciones"),
     ("axial.none", "ninguno"),
     ("rec.crash", "priorizar triage de fallos y recolección de trazas"),
     ("rec.panic", "auditar rutas panic/fatal por supuestos inseguros"),
@@ -298,6 +317,13 @@ const ES: &[(&str, &str)] = &[
     ("adjudicate.title", "Veredicto de Adjudicación"),
     ("adjudicate.campaigns", "Campañas Analizadas"),
     ("adjudicate.verdict", "Veredicto General"),
+    ("adjudicate.summary", "Resumen Ejecutivo"),
+    ("adjudicate.risk_score", "Puntuación de Riesgo"),
+    ("adjudicate.trend", "Tendencia"),
+    ("adjudicate.priorities", "Prioridades Principales"),
+    ("adjudicate.reports", "Informes Subyacentes"),
+    ("adjudicate.no_previous", "sin campaña anterior"),
+    ("adjudicate.since_previous", "desde la campaña anterior"),
     ("ambush.title", "Informe de Emboscada"),
     ("ambush.timeline", "Eventos de Línea Temporal"),
     ("ambush.stressors", "Estresores Activos"),
@@ -307,6 +333,19 @@ const ES: &[(&str, &str)] = &[
     ("abduct.title", "Informe de Aislamiento Abduct"),
     ("abduct.isolated_files", "Archivos Aislados"),
     ("abduct.scope", "Alcance de Dependencias"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "El programa falló bajo el ataque {axis} ({count} fallos)"),
+    ("assess.high_confidence", "{signature} detectado con alta confianza (confianza: {confidence})"),
+    ("assess.rec.error_handling", "Añadir manejo de errores completo para casos límite"),
+    ("assess.rec.unwrap", "Reemplazar {count} llamadas a unwrap() por manejo de errores adecuado"),
+    ("assess.rec.unsafe", "Auditar {count} bloques unsafe en busca de violaciones de seguridad de memoria"),
+    ("assess.rec.data_race", "Añadir primitivas de sincronización para evitar carreras de datos"),
+    ("assess.rec.deadlock", "Revisar el orden de bloqueo para evitar interbloqueos"),
+    ("assess.rec.livelock", "Añadir espera progresiva o comprobaciones de progreso a los bucles de reintento y espera activa"),
+    ("assess.rec.leak", "Buscar fugas de memoria, descriptores de archivo o hilos: el uso siguió creciendo bajo carga"),
+    ("assess.rec.infinite_loop", "Revisar la condición de salida del bucle donde se muestreó el objetivo bloqueado"),
+    ("assess.rec.stall", "Investigar por qué el objetivo deja de progresar bajo carga"),
+    ("assess.rec.refactor", "Considerar una refactorización completa para mejorar la robustez (puntuación {score}/100)"),
 ];
 
 // ─── French ─────────────────────────────────────────────────────────
@@ -321,6 +360,8 @@ const FR: &[(&str, &str)] = &[
     ("axial.signals", "Signaux"),
     ("axial.recommendations", "Recommandations"),
     ("axial.spelling", "Orthographe"),
+    ("axial.probe", "Sonde HTTP"),
+    ("axial.run_variance", "Variation entre exécutions"),
     ("axial.none", "aucun"),
     ("rec.crash", "prioriser le triage des crashs et la collecte des traces"),
     ("rec.panic", "auditer les chemins panic/fatal pour hypothèses dangereuses"),
@@ -349,6 +390,13 @@ const FR: &[(&str, &str)] = &[
     ("adjudicate.title", "Verdict d'Adjudication"),
     ("adjudicate.campaigns", "Campagnes Analysées"),
     ("adjudicate.verdict", "Verdict Global"),
+    ("adjudicate.summary", "Résumé Exécutif"),
+    ("adjudicate.risk_score", "Score de Risque"),
+    ("adjudicate.trend", "Tendance"),
+    ("adjudicate.priorities", "Priorités Principales"),
+    ("adjudicate.reports", "Rapports Sources"),
+    ("adjudicate.no_previous", "aucune campagne précédente"),
+    ("adjudicate.since_previous", "depuis la campagne précédente"),
     ("ambush.title", "Rapport d'Embuscade"),
     ("ambush.timeline", "Événements Chronologiques"),
     ("ambush.stressors", "Stresseurs Actifs"),
@@ -358,6 +406,19 @@ const FR: &[(&str, &str)] = &[
     ("abduct.title", "Rapport d'Isolation Abduct"),
     ("abduct.isolated_files", "Fichiers Isolés"),
     ("abduct.scope", "Portée des Dépendances"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "Le programme a planté sous l'attaque {axis} ({count} crashs)"),
+    ("assess.high_confidence", "{signature} détecté avec une confiance élevée (confiance : {confidence})"),
+    ("assess.rec.error_handling", "Ajouter une gestion d'erreurs complète pour les cas limites"),
+    ("assess.rec.unwrap", "Remplacer {count} appels à unwrap() par une gestion d'erreurs appropriée"),
+    ("assess.rec.unsafe", "Auditer {count} blocs unsafe pour les violations de sécurité mémoire"),
+    ("assess.rec.data_race", "Ajouter des primitives de synchronisation pour éviter les accès concurrents"),
+    ("assess.rec.deadlock", "Revoir l'ordre des verrous pour éviter les interblocages"),
+    ("assess.rec.livelock", "Ajouter un délai progressif ou des contrôles de progression aux boucles de réessai et d'attente active"),
+    ("assess.rec.leak", "Rechercher des fuites de mémoire, de descripteurs de fichiers ou de threads : l'utilisation n'a cessé de croître sous charge"),
+    ("assess.rec.infinite_loop", "Vérifier la condition de sortie de la boucle là où la cible bloquée a été échantillonnée"),
+    ("assess.rec.stall", "Déterminer pourquoi la cible cesse de progresser sous charge"),
+    ("assess.rec.refactor", "Envisager une refonte complète pour la robustesse (score {score}/100)"),
 ];
 
 // ─── German ─────────────────────────────────────────────────────────
@@ -372,6 +433,8 @@ const DE: &[(&str, &str)] = &[
     ("axial.signals", "Signale"),
     ("axial.recommendations", "Empfehlungen"),
     ("axial.spelling", "Rechtschreibung"),
+    ("axial.probe", "HTTP-Sonde"),
+    ("axial.run_variance", "Abweichung zwischen Läufen"),
     ("axial.none", "keine"),
     ("rec.crash", "Crash-Triage und Backtrace-Erfassung priorisieren"),
     ("rec.panic", "Panic/Fatal-Pfade auf unsichere Annahmen prüfen"),
@@ -400,6 +463,13 @@ const DE: &[(&str, &str)] = &[
     ("adjudicate.title", "Urteil der Adjudikation"),
     ("adjudicate.campaigns", "Analysierte Kampagnen"),
     ("adjudicate.verdict", "Gesamturteil"),
+    ("adjudicate.summary", "Management-Zusammenfassung"),
+    ("adjudicate.risk_score", "Risikowert"),
+    ("adjudicate.trend", "Trend"),
+    ("adjudicate.priorities", "Wichtigste Prioritäten"),
+    ("adjudicate.reports", "Zugrunde liegende Berichte"),
+    ("adjudicate.no_previous", "keine vorherige Kampagne"),
+    ("adjudicate.since_previous", "seit der vorherigen Kampagne"),
     ("ambush.title", "Hinterhalt-Bericht"),
     ("ambush.timeline", "Zeitleisten-Ereignisse"),
     ("ambush.stressors", "Aktive Stressoren"),
@@ -409,6 +479,19 @@ const DE: &[(&str, &str)] = &[
     ("abduct.title", "Abduct Isolationsbericht"),
     ("abduct.isolated_files", "Isolierte Dateien"),
     ("abduct.scope", "Abhängigkeitsbereich"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "Programm ist unter {axis}-Angriff abgestürzt ({count} Abstürze)"),
+    ("assess.high_confidence", "{signature} mit hoher Konfidenz erkannt (Konfidenz: {confidence})"),
+    ("assess.rec.error_handling", "Umfassende Fehlerbehandlung für Grenzfälle hinzufügen"),
+    ("assess.rec.unwrap", "{count} unwrap()-Aufrufe durch ordentliche Fehlerbehandlung ersetzen"),
+    ("assess.rec.unsafe", "{count} unsafe-Blöcke auf Speichersicherheitsverletzungen prüfen"),
+    ("assess.rec.data_race", "Synchronisationsprimitive hinzufügen, um Data Races zu verhindern"),
+    ("assess.rec.deadlock", "Sperrreihenfolge prüfen, um Deadlocks zu verhindern"),
+    ("assess.rec.livelock", "Backoff oder Fortschrittsprüfungen zu Wiederholungs- und Warteschleifen hinzufügen"),
+    ("assess.rec.leak", "Auf Speicher-, Dateideskriptor- oder Thread-Lecks prüfen: die Nutzung wuchs unter Last stetig"),
+    ("assess.rec.infinite_loop", "Abbruchbedingung der Schleife prüfen, in der das hängende Ziel erfasst wurde"),
+    ("assess.rec.stall", "Untersuchen, warum das Ziel unter Last keinen Fortschritt mehr macht"),
+    ("assess.rec.refactor", "Umfassendes Refactoring für Robustheit erwägen (Bewertung {score}/100)"),
 ];
 
 // ─── Japanese ───────────────────────────────────────────────────────
@@ -423,6 +506,8 @@ const JA: &[(&str, &str)] = &[
     ("axial.signals", "シグナル"),
     ("axial.recommendations", "推奨事項"),
     ("axial.spelling", "スペルチェック"),
+    ("axial.probe", "HTTPプローブ"),
+    ("axial.run_variance", "実行間のばらつき"),
     ("axial.none", "なし"),
     ("rec.crash", "クラッシュのトリアージとバックトレース収集を優先する"),
     ("rec.panic", "panic/fatalパスの安全でない前提を監査する"),
@@ -451,6 +536,13 @@ const JA: &[(&str, &str)] = &[
     ("adjudicate.title", "Adjudicate判定"),
     ("adjudicate.campaigns", "分析されたキャンペーン"),
     ("adjudicate.verdict", "総合判定"),
+    ("adjudicate.summary", "エグゼクティブサマリー"),
+    ("adjudicate.risk_score", "リスクスコア"),
+    ("adjudicate.trend", "傾向"),
+    ("adjudicate.priorities", "最優先事項"),
+    ("adjudicate.reports", "元のレポート"),
+    ("adjudicate.no_previous", "前回のキャンペーンなし"),
+    ("adjudicate.since_previous", "前回のキャンペーンから"),
     ("ambush.title", "待ち伏せレポート"),
     ("ambush.timeline", "タイムラインイベント"),
     ("ambush.stressors", "アクティブストレッサー"),
@@ -460,6 +552,19 @@ const JA: &[(&str, &str)] = &[
     ("abduct.title", "Abduct隔離レポート"),
     ("abduct.isolated_files", "隔離されたファイル"),
     ("abduct.scope", "依存関係スコープ"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "{axis}攻撃でプログラムがクラッシュした（{count}回）"),
+    ("assess.high_confidence", "高信頼度の{signature}を検出（信頼度: {confidence}）"),
+    ("assess.rec.error_handling", "境界ケースに対する包括的なエラー処理を追加する"),
+    ("assess.rec.unwrap", "{count}個のunwrap()呼び出しを適切なエラー処理に置き換える"),
+    ("assess.rec.unsafe", "{count}個のunsafeブロックをメモリ安全性違反について監査する"),
+    ("assess.rec.data_race", "データ競合を防ぐため同期プリミティブを追加する"),
+    ("assess.rec.deadlock", "デッドロックを防ぐためロック順序を見直す"),
+    ("assess.rec.livelock", "リトライ・スピンループにバックオフまたは進捗チェックを追加する"),
+    ("assess.rec.leak", "メモリ・ファイルディスクリプタ・スレッドのリークを確認する：負荷下で使用量が増え続けた"),
+    ("assess.rec.infinite_loop", "ハングしたターゲットをサンプリングした箇所のループ終了条件を確認する"),
+    ("assess.rec.stall", "負荷下でターゲットが進捗しなくなる原因を調査する"),
+    ("assess.rec.refactor", "堅牢性のため包括的なリファクタリングを検討する（スコア {score}/100）"),
 ];
 
 // ─── Portuguese ────────────────────────────────────────────────────
@@ -474,6 +579,8 @@ const PT: &[(&str, &str)] = &[
     ("axial.signals", "Sinais"),
     ("axial.recommendations", "Recomendações"),
     ("axial.spelling", "Ortografia"),
+    ("axial.probe", "Sonda HTTP"),
+    ("axial.run_variance", "Variação entre execuções"),
     ("axial.none", "nenhum"),
     ("rec.crash", "priorizar triagem de falhas e coleta de rastreamentos"),
     ("rec.panic", "auditar caminhos panic/fatal por suposições inseguras"),
@@ -502,6 +609,13 @@ const PT: &[(&str, &str)] = &[
     ("adjudicate.title", "Veredicto de Adjudicação"),
     ("adjudicate.campaigns", "Campanhas Analisadas"),
     ("adjudicate.verdict", "Veredicto Geral"),
+    ("adjudicate.summary", "Resumo Executivo"),
+    ("adjudicate.risk_score", "Pontuação de Risco"),
+    ("adjudicate.trend", "Tendência"),
+    ("adjudicate.priorities", "Principais Prioridades"),
+    ("adjudicate.reports", "Relatórios de Origem"),
+    ("adjudicate.no_previous", "nenhuma campanha anterior"),
+    ("adjudicate.since_previous", "desde a campanha anterior"),
     ("ambush.title", "Relatório de Emboscada"),
     ("ambush.timeline", "Eventos da Linha do Tempo"),
     ("ambush.stressors", "Estressores Ativos"),
@@ -511,6 +625,19 @@ const PT: &[(&str, &str)] = &[
     ("abduct.title", "Relatório de Isolamento Abduct"),
     ("abduct.isolated_files", "Arquivos Isolados"),
     ("abduct.scope", "Escopo de Dependências"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "O programa falhou sob o ataque {axis} ({count} falhas)"),
+    ("assess.high_confidence", "{signature} detectado com alta confiança (confiança: {confidence})"),
+    ("assess.rec.error_handling", "Adicionar tratamento de erros abrangente para casos extremos"),
+    ("assess.rec.unwrap", "Substituir {count} chamadas unwrap() por tratamento de erros adequado"),
+    ("assess.rec.unsafe", "Auditar {count} blocos unsafe em busca de violações de segurança de memória"),
+    ("assess.rec.data_race", "Adicionar primitivas de sincronização para evitar condições de corrida"),
+    ("assess.rec.deadlock", "Revisar a ordem de bloqueio para evitar deadlocks"),
+    ("assess.rec.livelock", "Adicionar backoff ou verificações de progresso aos laços de repetição e espera ativa"),
+    ("assess.rec.leak", "Verificar vazamentos de memória, descritores de arquivo ou threads: o uso continuou crescendo sob carga"),
+    ("assess.rec.infinite_loop", "Verificar a condição de saída do laço onde o alvo travado foi amostrado"),
+    ("assess.rec.stall", "Investigar por que o alvo deixa de progredir sob carga"),
+    ("assess.rec.refactor", "Considerar uma refatoração abrangente para robustez (pontuação {score}/100)"),
 ];
 
 // ─── Chinese (Simplified) ─────────────────────────────────────────
@@ -525,6 +652,8 @@ const ZH: &[(&str, &str)] = &[
     ("axial.signals", "信号"),
     ("axial.recommendations", "建议"),
     ("axial.spelling", "拼写检查"),
+    ("axial.probe", "HTTP 探测"),
+    ("axial.run_variance", "运行间差异"),
     ("axial.none", "无"),
     ("rec.crash", "优先处理崩溃分类和堆栈跟踪收集"),
     ("rec.panic", "审查panic/fatal路径中的不安全假设"),
@@ -553,6 +682,13 @@ const ZH: &[(&str, &str)] = &[
     ("adjudicate.title", "裁决结果"),
     ("adjudicate.campaigns", "已分析的战役"),
     ("adjudicate.verdict", "总体裁决"),
+    ("adjudicate.summary", "执行摘要"),
+    ("adjudicate.risk_score", "风险评分"),
+    ("adjudicate.trend", "趋势"),
+    ("adjudicate.priorities", "首要事项"),
+    ("adjudicate.reports", "原始报告"),
+    ("adjudicate.no_previous", "无先前战役"),
+    ("adjudicate.since_previous", "自上次战役以来"),
     ("ambush.title", "伏击报告"),
     ("ambush.timeline", "时间线事件"),
     ("ambush.stressors", "活动压力源"),
@@ -562,6 +698,19 @@ const ZH: &[(&str, &str)] = &[
     ("abduct.title", "Abduct隔离报告"),
     ("abduct.isolated_files", "已隔离文件"),
     ("abduct.scope", "依赖范围"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "程序在{axis}攻击下崩溃（{count}次）"),
+    ("assess.high_confidence", "检测到高置信度的{signature}（置信度：{confidence}）"),
+    ("assess.rec.error_handling", "为边界情况添加全面的错误处理"),
+    ("assess.rec.unwrap", "将{count}处unwrap()调用替换为正确的错误处理"),
+    ("assess.rec.unsafe", "审计{count}个unsafe块是否存在内存安全问题"),
+    ("assess.rec.data_race", "添加同步原语以防止数据竞争"),
+    ("assess.rec.deadlock", "检查加锁顺序以防止死锁"),
+    ("assess.rec.livelock", "为重试和自旋循环添加退避或进度检查"),
+    ("assess.rec.leak", "检查内存、文件描述符或线程泄漏：负载下使用量持续增长"),
+    ("assess.rec.infinite_loop", "检查挂起目标采样处的循环退出条件"),
+    ("assess.rec.stall", "调查目标在负载下停止推进的原因"),
+    ("assess.rec.refactor", "考虑进行全面重构以提高健壮性（得分 {score}/100）"),
 ];
 
 // ─── Korean ───────────────────────────────────────────────────────
@@ -576,6 +725,8 @@ const KO: &[(&str, &str)] = &[
     ("axial.signals", "신호"),
     ("axial.recommendations", "권장사항"),
     ("axial.spelling", "맞춤법 검사"),
+    ("axial.probe", "HTTP 프로브"),
+    ("axial.run_variance", "실행 간 편차"),
     ("axial.none", "없음"),
     ("rec.crash", "크래시 분류 및 백트레이스 수집 우선"),
     ("rec.panic", "panic/fatal 경로의 안전하지 않은 가정 감사"),
@@ -604,6 +755,13 @@ const KO: &[(&str, &str)] = &[
     ("adjudicate.title", "판결 결과"),
     ("adjudicate.campaigns", "분석된 캠페인"),
     ("adjudicate.verdict", "종합 판결"),
+    ("adjudicate.summary", "경영진 요약"),
+    ("adjudicate.risk_score", "위험 점수"),
+    ("adjudicate.trend", "추세"),
+    ("adjudicate.priorities", "주요 우선순위"),
+    ("adjudicate.reports", "원본 보고서"),
+    ("adjudicate.no_previous", "이전 캠페인 없음"),
+    ("adjudicate.since_previous", "이전 캠페인 대비"),
     ("ambush.title", "매복 보고서"),
     ("ambush.timeline", "타임라인 이벤트"),
     ("ambush.stressors", "활성 스트레서"),
@@ -613,6 +771,19 @@ const KO: &[(&str, &str)] = &[
     ("abduct.title", "Abduct 격리 보고서"),
     ("abduct.isolated_files", "격리된 파일"),
     ("abduct.scope", "의존성 범위"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "{axis} 공격에서 프로그램 크래시 발생 ({count}회)"),
+    ("assess.high_confidence", "높은 신뢰도의 {signature} 감지 (신뢰도: {confidence})"),
+    ("assess.rec.error_handling", "경계 사례에 대한 포괄적인 오류 처리 추가"),
+    ("assess.rec.unwrap", "{count}개의 unwrap() 호출을 적절한 오류 처리로 교체"),
+    ("assess.rec.unsafe", "{count}개의 unsafe 블록에서 메모리 안전성 위반 감사"),
+    ("assess.rec.data_race", "데이터 경쟁을 막기 위해 동기화 프리미티브 추가"),
+    ("assess.rec.deadlock", "교착 상태를 막기 위해 잠금 순서 검토"),
+    ("assess.rec.livelock", "재시도 및 스핀 루프에 백오프 또는 진행 확인 추가"),
+    ("assess.rec.leak", "메모리, 파일 디스크립터 또는 스레드 누수 확인: 부하에서 사용량이 계속 증가함"),
+    ("assess.rec.infinite_loop", "멈춘 대상이 샘플링된 위치의 루프 종료 조건 확인"),
+    ("assess.rec.stall", "부하에서 대상이 진행을 멈추는 이유 조사"),
+    ("assess.rec.refactor", "견고성을 위해 포괄적인 리팩터링 고려 (점수 {score}/100)"),
 ];
 
 // ─── Italian ──────────────────────────────────────────────────────
@@ -627,6 +798,8 @@ const IT: &[(&str, &str)] = &[
     ("axial.signals", "Segnali"),
     ("axial.recommendations", "Raccomandazioni"),
     ("axial.spelling", "Ortografia"),
+    ("axial.probe", "Sonda HTTP"),
+    ("axial.run_variance", "Variazione tra esecuzioni"),
     ("axial.none", "nessuno"),
     ("rec.crash", "dare priorità al triage dei crash e alla raccolta dei backtrace"),
     ("rec.panic", "verificare percorsi panic/fatal per ipotesi non sicure"),
@@ -655,6 +828,13 @@ const IT: &[(&str, &str)] = &[
     ("adjudicate.title", "Verdetto di Aggiudicazione"),
     ("adjudicate.campaigns", "Campagne Analizzate"),
     ("adjudicate.verdict", "Verdetto Complessivo"),
+    ("adjudicate.summary", "Sintesi Esecutiva"),
+    ("adjudicate.risk_score", "Punteggio di Rischio"),
+    ("adjudicate.trend", "Tendenza"),
+    ("adjudicate.priorities", "Priorità Principali"),
+    ("adjudicate.reports", "Report Sottostanti"),
+    ("adjudicate.no_previous", "nessuna campagna precedente"),
+    ("adjudicate.since_previous", "dalla campagna precedente"),
     ("ambush.title", "Rapporto di Imboscata"),
     ("ambush.timeline", "Eventi della Cronologia"),
     ("ambush.stressors", "Stressori Attivi"),
@@ -664,6 +844,19 @@ const IT: &[(&str, &str)] = &[
     ("abduct.title", "Rapporto di Isolamento Abduct"),
     ("abduct.isolated_files", "File Isolati"),
     ("abduct.scope", "Ambito delle Dipendenze"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "Il programma è andato in crash sotto l'attacco {axis} ({count} crash)"),
+    ("assess.high_confidence", "{signature} rilevato con alta confidenza (confidenza: {confidence})"),
+    ("assess.rec.error_handling", "Aggiungere una gestione completa degli errori per i casi limite"),
+    ("assess.rec.unwrap", "Sostituire {count} chiamate a unwrap() con una corretta gestione degli errori"),
+    ("assess.rec.unsafe", "Verificare {count} blocchi unsafe per violazioni della sicurezza della memoria"),
+    ("assess.rec.data_race", "Aggiungere primitive di sincronizzazione per prevenire data race"),
+    ("assess.rec.deadlock", "Rivedere l'ordine dei lock per prevenire deadlock"),
+    ("assess.rec.livelock", "Aggiungere backoff o controlli di avanzamento ai cicli di retry e di attesa attiva"),
+    ("assess.rec.leak", "Cercare perdite di memoria, descrittori di file o thread: l'utilizzo ha continuato a crescere sotto carico"),
+    ("assess.rec.infinite_loop", "Verificare la condizione di uscita del ciclo dove è stato campionato il target bloccato"),
+    ("assess.rec.stall", "Indagare perché il target smette di avanzare sotto carico"),
+    ("assess.rec.refactor", "Valutare un refactoring completo per la robustezza (punteggio {score}/100)"),
 ];
 
 // ─── Russian ──────────────────────────────────────────────────────
@@ -678,6 +871,8 @@ const RU: &[(&str, &str)] = &[
     ("axial.signals", "Сигналы"),
     ("axial.recommendations", "Рекомендации"),
     ("axial.spelling", "Правописание"),
+    ("axial.probe", "HTTP-зонд"),
+    ("axial.run_variance", "Расхождения между запусками"),
     ("axial.none", "нет"),
     ("rec.crash", "приоритизировать сортировку аварий и сбор трассировок"),
     ("rec.panic", "проверить пути panic/fatal на небезопасные допущения"),
@@ -706,6 +901,13 @@ const RU: &[(&str, &str)] = &[
     ("adjudicate.title", "Вердикт вынесения решения"),
     ("adjudicate.campaigns", "Проанализированные кампании"),
     ("adjudicate.verdict", "Общий вердикт"),
+    ("adjudicate.summary", "Краткое резюме"),
+    ("adjudicate.risk_score", "Оценка риска"),
+    ("adjudicate.trend", "Тенденция"),
+    ("adjudicate.priorities", "Главные приоритеты"),
+    ("adjudicate.reports", "Исходные отчёты"),
+    ("adjudicate.no_previous", "нет предыдущей кампании"),
+    ("adjudicate.since_previous", "с предыдущей кампании"),
     ("ambush.title", "Отчёт о засаде"),
     ("ambush.timeline", "События хронологии"),
     ("ambush.stressors", "Активные стрессоры"),
@@ -715,6 +917,19 @@ const RU: &[(&str, &str)] = &[
     ("abduct.title", "Отчёт об изоляции Abduct"),
     ("abduct.isolated_files", "Изолированные файлы"),
     ("abduct.scope", "Область зависимостей"),
+    // Assault assessment; {placeholders} are filled in by the report generator
+    ("assess.crashed", "Программа аварийно завершилась при атаке {axis} ({count} аварий)"),
+    ("assess.high_confidence", "Обнаружено {signature} с высокой уверенностью (уверенность: {confidence})"),
+    ("assess.rec.error_handling", "Добавить полноценную обработку ошибок для граничных случаев"),
+    ("assess.rec.unwrap", "Заменить {count} вызовов unwrap() на корректную обработку ошибок"),
+    ("assess.rec.unsafe", "Проверить {count} блоков unsafe на нарушения безопасности памяти"),
+    ("assess.rec.data_race", "Добавить примитивы синхронизации для предотвращения гонок данных"),
+    ("assess.rec.deadlock", "Пересмотреть порядок захвата блокировок для предотвращения взаимоблокировок"),
+    ("assess.rec.livelock", "Добавить экспоненциальную задержку или проверки прогресса в циклы повторов и активного ожидания"),
+    ("assess.rec.leak", "Проверить утечки памяти, файловых дескрипторов или потоков: потребление росло под нагрузкой"),
+    ("assess.rec.infinite_loop", "Проверить условие выхода из цикла в месте, где была снята выборка зависшей цели"),
+    ("assess.rec.stall", "Выяснить, почему цель перестаёт продвигаться под нагрузкой"),
+    ("assess.rec.refactor", "Рассмотреть полный рефакторинг для повышения устойчивости (оценка {score}/100)"),
 ];
 
 #[cfg(test)]
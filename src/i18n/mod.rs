@@ -29,12 +29,17 @@
 //! string itself is returned (fail-open, never panics).
 //!
 //! The catalog is embedded at compile time as static data — no file I/O,
-//! no async, no allocator pressure during translation lookups.
+//! no async, no allocator pressure during translation lookups. Entries may
+//! carry `{name}` placeholders filled in by [`render`]; a [`TemplateSet`]
+//! loaded from an organization's template file overrides catalog entries.
 
 mod catalog;
 mod iso639;
+mod template;
 
 #[allow(unused_imports)]
 pub use catalog::{t, t_or_key, Lang};
 #[allow(unused_imports)]
 pub use iso639::{is_valid_iso639_1, language_name, native_name};
+#[allow(unused_imports)]
+pub use template::{render, TemplateSet};
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Parameterized message templates.
+//!
+//! Catalog entries may contain `{name}` placeholders that [`render`] fills in,
+//! so word order stays the translator's choice instead of being fixed by
+//! `format!` calls in the generator.
+//!
+//! Organizations can override any catalog template with their own wording
+//! (a wiki link, an internal policy reference) through a template file keyed
+//! by language code, then by catalog key:
+//!
+//! ```yaml
+//! en:
+//!   assess.rec.unwrap: "Replace {count} unwrap() calls; see wiki/errors"
+//!   assess.rec.refactor: ""   # empty: drop this recommendation
+//! de:
+//!   assess.rec.unwrap: "{count} unwrap()-Aufrufe ersetzen, siehe wiki/errors"
+//! ```
+
+use super::catalog::{t, Lang};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Replace each `{name}` in `template` with its value from `args`; unknown placeholders are
+/// left as they are so a typo in a custom template stays visible.
+pub fn render(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{}}}", name), value);
+    }
+    out
+}
+
+/// Custom templates per language code, overriding the built-in catalog.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TemplateSet {
+    templates: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl TemplateSet {
+    /// Load a YAML or JSON template file (JSON when the extension is `.json`).
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("reading templates {}", path.display()))?;
+        let set: Self = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&content)
+                .with_context(|| format!("parsing templates {}", path.display()))?,
+            _ => serde_yaml::from_str(&content)
+                .with_context(|| format!("parsing templates {}", path.display()))?,
+        };
+        if let Some(code) = set
+            .templates
+            .keys()
+            .find(|code| Lang::from_code(code).is_none())
+        {
+            anyhow::bail!(
+                "templates {}: unsupported language code `{}`",
+                path.display(),
+                code
+            );
+        }
+        Ok(set)
+    }
+
+    /// Render `key` in `lang`: the custom template when there is one, else the catalog's
+    /// (falling back to English). `None` when a custom template is empty, which suppresses
+    /// the message.
+    pub fn text(&self, lang: Lang, key: &str, args: &[(&str, String)]) -> Option<String> {
+        let template = match self
+            .templates
+            .get(lang.code())
+            .and_then(|templates| templates.get(key))
+        {
+            Some(custom) => custom.as_str(),
+            None => t(lang, key),
+        };
+        (!template.is_empty()).then(|| render(template, args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_fills_placeholders() {
+        assert_eq!(
+            render(
+                "{count} x {axis}, {missing}",
+                &[("count", "3".to_string()), ("axis", "Cpu".to_string())]
+            ),
+            "3 x Cpu, {missing}"
+        );
+    }
+
+    #[test]
+    fn test_custom_templates_override_and_suppress() {
+        let set: TemplateSet = serde_yaml::from_str(
+            "de:\n  assess.rec.unwrap: \"{count} unwrap() ersetzen, siehe wiki/errors\"\n  assess.rec.deadlock: \"\"\n",
+        )
+        .unwrap();
+        let count = [("count", "12".to_string())];
+        assert_eq!(
+            set.text(Lang::De, "assess.rec.unwrap", &count).as_deref(),
+            Some("12 unwrap() ersetzen, siehe wiki/errors")
+        );
+        assert_eq!(set.text(Lang::De, "assess.rec.deadlock", &[]), None);
+        // Other languages keep the catalog's wording.
+        assert_eq!(
+            set.text(Lang::Fr, "assess.rec.unwrap", &count).as_deref(),
+            Some("Remplacer 12 appels à unwrap() par une gestion d'erreurs appropriée")
+        );
+    }
+}
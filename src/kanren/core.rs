@@ -386,6 +386,12 @@ pub struct LogicEngine {
     pub db: FactDB,
 }
 
+impl Default for LogicEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LogicEngine {
     pub fn new() -> Self {
         Self { db: FactDB::new() }
@@ -86,7 +86,7 @@ pub fn prioritise_files(report: &AssailReport, strategy: SearchStrategy) -> Vec<
     let mut scored: Vec<FileRisk> = report
         .file_statistics
         .iter()
-        .map(|fs| score_file(fs))
+        .map(score_file)
         .collect();
 
     // Sorting policy is strategy-dependent, but all strategies operate on the same base score set.
@@ -303,9 +303,7 @@ mod tests {
             },
             file_statistics: vec![make_file_stats("src/main.rs", 0, 0)],
             recommended_attacks: vec![],
-            dependency_graph: Default::default(),
-            taint_matrix: Default::default(),
-            migration_metrics: None,
+            ..Default::default()
         };
 
         // Small, single-language, no high risk: should be DepthFirst
@@ -337,9 +335,7 @@ mod tests {
                 make_file_stats("src/moderate.rs", 1, 0),
             ],
             recommended_attacks: vec![],
-            dependency_graph: Default::default(),
-            taint_matrix: Default::default(),
-            migration_metrics: None,
+            ..Default::default()
         };
 
         let ordered = prioritise_files(&report, SearchStrategy::RiskWeighted);
@@ -83,6 +83,10 @@ impl TaintAnalyzer {
                 }
                 WeakPointCategory::UnsafeDeserialization => {
                     Self::assert_source(db, file, TaintSource::Deserialization);
+                    // Assail recommends the network axis when the payload comes off the wire.
+                    if wp.recommended_attack.contains(&AttackAxis::Network) {
+                        Self::assert_source(db, file, TaintSource::NetworkRead);
+                    }
                     Self::assert_sink(db, file, TaintSink::DeserializeSink);
                 }
                 WeakPointCategory::DynamicCodeExecution => {
@@ -105,14 +109,14 @@ impl TaintAnalyzer {
                     Self::assert_source(db, file, TaintSource::NetworkRead);
                     Self::assert_sink(db, file, TaintSink::NetworkWrite);
                 }
-                WeakPointCategory::UnsafeCode => {
+                WeakPointCategory::UnsafeCode | WeakPointCategory::RawPointerDeref => {
                     Self::assert_sink(db, file, TaintSink::MemoryOperation);
                 }
                 WeakPointCategory::HardcodedSecret => {
                     Self::assert_source(db, file, TaintSource::EnvVar);
                     Self::assert_sink(db, file, TaintSink::LogOutput);
                 }
-                WeakPointCategory::UnsafeTypeCoercion => {
+                WeakPointCategory::UnsafeTypeCoercion | WeakPointCategory::UnsafeTransmute => {
                     Self::assert_sink(db, file, TaintSink::UnsafeCast);
                 }
                 _ => {}
@@ -167,6 +171,9 @@ impl TaintAnalyzer {
                 matches!(
                     wp.category,
                     WeakPointCategory::UnsafeCode
+                        | WeakPointCategory::RawPointerDeref
+                        | WeakPointCategory::CommandInjection
+                        | WeakPointCategory::UnsafeDeserialization
                         | WeakPointCategory::UnsafeFFI
                         | WeakPointCategory::AtomExhaustion
                         | WeakPointCategory::PathTraversal
@@ -365,4 +372,46 @@ mod tests {
         );
         assert!(db.fact_count("tainted_file") > 0);
     }
+
+    #[test]
+    fn test_injection_weak_points_are_exploitable_sinks() {
+        let weak_point = |category, recommended_attack| WeakPoint {
+            category,
+            location: Some("app/worker.py".to_string()),
+            severity: Severity::Critical,
+            description: String::new(),
+            recommended_attack,
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        };
+        let report = AssailReport {
+            program_path: "app".into(),
+            language: Language::Python,
+            weak_points: vec![
+                weak_point(
+                    WeakPointCategory::CommandInjection,
+                    vec![AttackAxis::Cpu, AttackAxis::Disk],
+                ),
+                weak_point(
+                    WeakPointCategory::UnsafeDeserialization,
+                    vec![AttackAxis::Network, AttackAxis::Cpu],
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let mut db = FactDB::new();
+        TaintAnalyzer::extract_facts(&mut db, &report);
+        TaintAnalyzer::load_rules(&mut db);
+        db.forward_chain();
+
+        let flows = TaintAnalyzer::query_flows(&db);
+        assert!(flows
+            .iter()
+            .any(|f| f.source == TaintSource::UserInput && f.sink == TaintSink::ShellCommand));
+        assert!(flows
+            .iter()
+            .any(|f| f.source == TaintSource::NetworkRead && f.sink == TaintSink::DeserializeSink));
+    }
 }
@@ -24,6 +24,9 @@ pub struct Heartbeat {
     pub version: &'static str,
     pub last_run: Option<RunMetrics>,
     pub errors: Vec<String>,
+    /// Stable code of the error the run failed with (see `crate::error`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<&'static str>,
     pub capabilities: Vec<&'static str>,
 }
 
@@ -73,6 +76,7 @@ pub fn write_heartbeat(metrics: RunMetrics, errors: Vec<String>) -> Result<()> {
         version: env!("CARGO_PKG_VERSION"),
         last_run: Some(metrics),
         errors,
+        error_code: None,
         capabilities: vec!["scan", "assail", "assemblyline", "sarif", "kanren", "attestation"],
     };
 
@@ -94,6 +98,7 @@ pub fn write_startup_heartbeat() -> Result<()> {
         version: env!("CARGO_PKG_VERSION"),
         last_run: None,
         errors: vec![],
+        error_code: None,
         capabilities: vec!["scan", "assail", "assemblyline", "sarif", "kanren", "attestation"],
     };
 
@@ -103,7 +108,7 @@ pub fn write_startup_heartbeat() -> Result<()> {
 }
 
 /// Write an error heartbeat when something goes wrong.
-pub fn write_error_heartbeat(error_msg: String) -> Result<()> {
+pub fn write_error_heartbeat(error_msg: String, error_code: &'static str) -> Result<()> {
     let dir = kin_dir();
     fs::create_dir_all(&dir)?;
 
@@ -115,6 +120,7 @@ pub fn write_error_heartbeat(error_msg: String) -> Result<()> {
         version: env!("CARGO_PKG_VERSION"),
         last_run: None,
         errors: vec![error_msg],
+        error_code: Some(error_code),
         capabilities: vec!["scan", "assail", "assemblyline", "sarif", "kanren", "attestation"],
     };
 
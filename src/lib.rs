@@ -23,12 +23,29 @@ pub mod assail;
 pub mod attestation;
 pub mod attack;
 pub mod axial;
+pub mod calibration;
+pub mod ci;
+pub mod error;
+pub mod flaky;
 pub mod i18n;
 pub mod kanren;
+pub mod lock;
+pub mod logging;
+pub mod lsp;
 pub mod panll;
+pub mod pipeline;
+pub mod preflight;
+pub mod progress;
+pub mod redact;
 pub mod report;
+pub mod run_as;
+pub mod schema;
+pub mod seed;
 pub mod signatures;
+pub mod snapshot;
 pub mod assemblyline;
 pub mod notify;
 pub mod storage;
+pub mod syscalls;
+pub mod triage;
 pub mod types;
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Campaign lock on the report store
+//!
+//! Two campaigns sharing a store would interleave their stressors and clobber each other's
+//! `runtime/` directories, so destructive commands take an advisory `flock` on
+//! `<store>/.panic-attack.lock` for as long as they run. The lock file names its holder; a
+//! second invocation refuses to start, waits (`--wait`) or takes the lock over
+//! (`--steal-lock`), and every such contention is appended to
+//! `<store>/.panic-attack.lock.events.jsonl` for `panic-attack diagnostics` to show.
+//!
+//! Stealing replaces the lock file with a fresh one rather than breaking the holder's lock;
+//! the displaced process keeps running, but later invocations, and any already waiting,
+//! contend with the thief.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Instant;
+
+pub const LOCK_FILE: &str = ".panic-attack.lock";
+pub const EVENTS_FILE: &str = ".panic-attack.lock.events.jsonl";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Contention {
+    /// Refuse to start while another campaign holds the lock.
+    #[default]
+    Fail,
+    /// Block until the holder finishes.
+    Wait,
+    /// Take the lock over from the holder.
+    Steal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockHolder {
+    pub pid: u32,
+    pub command: String,
+    pub target: String,
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentionEvent {
+    pub at: String,
+    pub pid: u32,
+    pub command: String,
+    pub target: String,
+    /// `refused`, `waited` or `stolen`.
+    pub action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<LockHolder>,
+    #[serde(default)]
+    pub waited_ms: u64,
+}
+
+/// Held until dropped.
+#[derive(Debug)]
+pub struct CampaignLock {
+    file: File,
+}
+
+impl CampaignLock {
+    /// Lock `store` for a `command` run against `target`.
+    pub fn acquire(
+        store: &Path,
+        command: &str,
+        target: &Path,
+        contention: Contention,
+    ) -> Result<Self> {
+        fs::create_dir_all(store)
+            .with_context(|| format!("creating store directory {}", store.display()))?;
+        let path = store.join(LOCK_FILE);
+        let me = LockHolder {
+            pid: std::process::id(),
+            command: command.to_string(),
+            target: target.display().to_string(),
+            started_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let mut file = open(&path)?;
+        let mut free = try_lock(&file, false)?;
+        // Locking a file a thief has already unlinked guards nothing; retry on the new one.
+        while free && !is_current(&file, &path)? {
+            file = open(&path)?;
+            free = try_lock(&file, false)?;
+        }
+        if !free {
+            let holder = read_holder(&mut file);
+            let started = Instant::now();
+            let action = match contention {
+                Contention::Fail => "refused",
+                Contention::Wait => {
+                    tracing::info!(
+                        "Waiting for the campaign lock on {} ({})",
+                        store.display(),
+                        describe(holder.as_ref())
+                    );
+                    // A thief may have replaced the file while we waited; follow it.
+                    loop {
+                        try_lock(&file, true)?;
+                        if is_current(&file, &path)? {
+                            break;
+                        }
+                        file = open(&path)?;
+                    }
+                    "waited"
+                }
+                Contention::Steal => {
+                    // A new inode under the same name: the old holder's lock no longer
+                    // guards the path.
+                    fs::remove_file(&path)
+                        .with_context(|| format!("removing lock file {}", path.display()))?;
+                    file = open(&path)?;
+                    if !try_lock(&file, false)? || !is_current(&file, &path)? {
+                        return Err(anyhow!(
+                            "campaign lock {} was taken again while stealing it",
+                            path.display()
+                        ));
+                    }
+                    tracing::warn!(
+                        "took the campaign lock on {} from {}",
+                        store.display(),
+                        describe(holder.as_ref())
+                    );
+                    "stolen"
+                }
+            };
+            record_event(
+                store,
+                &ContentionEvent {
+                    at: chrono::Utc::now().to_rfc3339(),
+                    pid: me.pid,
+                    command: me.command.clone(),
+                    target: me.target.clone(),
+                    action: action.to_string(),
+                    holder: holder.clone(),
+                    waited_ms: started.elapsed().as_millis() as u64,
+                },
+            )?;
+            if contention == Contention::Fail {
+                return Err(anyhow!(
+                    "another campaign holds the lock on {} ({}); rerun with --wait or --steal-lock",
+                    store.display(),
+                    describe(holder.as_ref())
+                ));
+            }
+        }
+
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(serde_json::to_string(&me)?.as_bytes())
+            .with_context(|| format!("writing lock file {}", path.display()))?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for CampaignLock {
+    fn drop(&mut self) {
+        // Only the holder description is cleared; the lock itself goes with the descriptor.
+        let _ = self.file.set_len(0);
+    }
+}
+
+/// The campaign currently holding `store`'s lock, if any.
+pub fn current_holder(store: &Path) -> Option<LockHolder> {
+    let mut file = File::open(store.join(LOCK_FILE)).ok()?;
+    if try_lock(&file, false).ok()? {
+        return None;
+    }
+    read_holder(&mut file)
+}
+
+/// Contention events recorded for `store`, oldest first.
+pub fn contention_events(store: &Path) -> Result<Vec<ContentionEvent>> {
+    let path = store.join(EVENTS_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).with_context(|| format!("parsing {}", path.display()))
+        })
+        .collect()
+}
+
+pub fn describe(holder: Option<&LockHolder>) -> String {
+    match holder {
+        Some(holder) => format!(
+            "pid {} running {} on {} since {}",
+            holder.pid, holder.command, holder.target, holder.started_at
+        ),
+        None => "holder unknown".to_string(),
+    }
+}
+
+fn open(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .with_context(|| format!("opening lock file {}", path.display()))
+}
+
+fn read_holder(file: &mut File) -> Option<LockHolder> {
+    let mut content = String::new();
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.read_to_string(&mut content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn record_event(store: &Path, event: &ContentionEvent) -> Result<()> {
+    let path = store.join(EVENTS_FILE);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("opening {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(event)?)
+        .with_context(|| format!("writing {}", path.display()))
+}
+
+/// Whether `file` is still the inode at `path`; a stolen lock file is not.
+#[cfg(unix)]
+fn is_current(file: &File, path: &Path) -> Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+    let held = file
+        .metadata()
+        .with_context(|| format!("inspecting lock file {}", path.display()))?;
+    match fs::metadata(path) {
+        Ok(named) => Ok(named.dev() == held.dev() && named.ino() == held.ino()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err).with_context(|| format!("inspecting lock file {}", path.display())),
+    }
+}
+
+#[cfg(not(unix))]
+fn is_current(_file: &File, _path: &Path) -> Result<bool> {
+    Ok(true)
+}
+
+/// Take an exclusive flock; `false` when another descriptor holds it and `block` is unset.
+#[cfg(unix)]
+fn try_lock(file: &File, block: bool) -> Result<bool> {
+    use std::os::unix::io::AsRawFd;
+    let operation = if block {
+        libc::LOCK_EX
+    } else {
+        libc::LOCK_EX | libc::LOCK_NB
+    };
+    loop {
+        // SAFETY: the descriptor belongs to `file`, which outlives the call.
+        if unsafe { libc::flock(file.as_raw_fd(), operation) } == 0 {
+            return Ok(true);
+        }
+        let err = std::io::Error::last_os_error();
+        match err.raw_os_error() {
+            Some(libc::EINTR) => continue,
+            Some(libc::EWOULDBLOCK) => return Ok(false),
+            _ => return Err(err).context("locking campaign store"),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn try_lock(_file: &File, _block: bool) -> Result<bool> {
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn second_campaign_is_refused_then_steals() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let target = Path::new("./app");
+        let first = CampaignLock::acquire(dir.path(), "assault", target, Contention::Fail)
+            .expect("first lock");
+        let holder = current_holder(dir.path()).expect("holder recorded");
+        assert_eq!(holder.command, "assault");
+        assert_eq!(holder.pid, std::process::id());
+
+        let err = CampaignLock::acquire(dir.path(), "ambush", target, Contention::Fail)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("pid"), "{}", err);
+        assert!(err.contains("--wait"), "{}", err);
+
+        let thief =
+            CampaignLock::acquire(dir.path(), "ambush", target, Contention::Steal).expect("steal");
+        assert_eq!(
+            current_holder(dir.path()).map(|holder| holder.command),
+            Some("ambush".to_string())
+        );
+        drop(first);
+        assert!(current_holder(dir.path()).is_some());
+        drop(thief);
+        assert!(current_holder(dir.path()).is_none());
+
+        let events = contention_events(dir.path()).expect("events");
+        let actions: Vec<_> = events.iter().map(|event| event.action.as_str()).collect();
+        assert_eq!(actions, ["refused", "stolen"]);
+        assert_eq!(
+            events[0]
+                .holder
+                .as_ref()
+                .map(|holder| holder.command.as_str()),
+            Some("assault")
+        );
+    }
+
+    #[test]
+    fn waiting_campaign_starts_once_the_holder_finishes() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let store = dir.path().to_path_buf();
+        let first = CampaignLock::acquire(&store, "attack", Path::new("a"), Contention::Fail)
+            .expect("first lock");
+        let waiter = std::thread::spawn(move || {
+            CampaignLock::acquire(&store, "attack", Path::new("b"), Contention::Wait).map(|_| ())
+        });
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        drop(first);
+        waiter.join().expect("join").expect("lock after wait");
+
+        let events = contention_events(dir.path()).expect("events");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "waited");
+    }
+
+    #[test]
+    fn waiting_campaign_does_not_run_beside_a_thief() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let store = dir.path().to_path_buf();
+        let first = CampaignLock::acquire(&store, "attack", Path::new("a"), Contention::Fail)
+            .expect("first lock");
+        let waiter = std::thread::spawn(move || {
+            CampaignLock::acquire(&store, "attack", Path::new("b"), Contention::Wait).map(|_| ())
+        });
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let thief = CampaignLock::acquire(dir.path(), "ambush", Path::new("c"), Contention::Steal)
+            .expect("steal");
+        // The waiter's flock on the unlinked file now succeeds, but the thief still holds
+        // the lock that counts.
+        drop(first);
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!waiter.is_finished());
+        drop(thief);
+        waiter.join().expect("join").expect("lock after wait");
+    }
+}
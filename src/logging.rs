@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Diagnostic logging
+//!
+//! Progress and warnings from the library go through `tracing`; reports and summaries stay
+//! on stdout. Logs are written to stderr at `info` by default, `debug` with `-v` and `trace`
+//! with `-vv`, or `warn` with `--quiet`; `RUST_LOG` replaces that filter entirely (for
+//! example `RUST_LOG=panic_attack::ambush=trace`).
+//!
+//! Every command runs inside a `run{run_id, command}` span, and attack/ambush axes inside
+//! an `axis{program, axis}` span. Plain text output keeps to `warning: ...` lines until
+//! `-v` asks for timestamps, targets and that span context; `--log-format json` always
+//! carries them, one object per line, for unattended campaigns.
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use std::fmt;
+use std::io::IsTerminal;
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::{self, FormatEvent, FormatFields};
+use tracing_subscriber::fmt::{FmtContext, MakeWriter};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Install the global subscriber writing to stderr, coloured only on a terminal.
+pub fn init(verbosity: u8, quiet: bool, format: LogFormat) -> Result<()> {
+    let ansi = std::io::stderr().is_terminal();
+    tracing::subscriber::set_global_default(subscriber(
+        verbosity,
+        quiet,
+        format,
+        ansi,
+        crate::progress::stderr,
+    ))
+    .map_err(|err| anyhow!("installing log subscriber: {}", err))
+}
+
+/// Filter used when `RUST_LOG` is unset: dependencies stay at `warn`.
+pub fn default_filter(verbosity: u8, quiet: bool) -> String {
+    let level = match (quiet, verbosity) {
+        (true, _) => "warn",
+        (false, 0) => "info",
+        (false, 1) => "debug",
+        (false, _) => "trace",
+    };
+    format!("warn,panic_attack={}", level)
+}
+
+/// Identifier for one invocation, attached to every log line through the `run` span.
+pub fn run_id() -> String {
+    format!(
+        "{}-{}",
+        chrono::Utc::now().format("%Y%m%dT%H%M%S"),
+        std::process::id()
+    )
+}
+
+pub fn subscriber<W>(
+    verbosity: u8,
+    quiet: bool,
+    format: LogFormat,
+    ansi: bool,
+    writer: W,
+) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(default_filter(verbosity, quiet)));
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_ansi(ansi)
+        .with_writer(writer);
+    match format {
+        LogFormat::Json => Box::new(
+            builder
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .finish(),
+        ),
+        LogFormat::Text if verbosity > 0 => Box::new(builder.finish()),
+        LogFormat::Text => Box::new(builder.event_format(Plain).finish()),
+    }
+}
+
+/// `warning: message` without timestamps or span context, like the CLI's own output.
+struct Plain;
+
+impl<S, N> FormatEvent<S, N> for Plain
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        match *event.metadata().level() {
+            Level::ERROR => write!(writer, "error: ")?,
+            Level::WARN => write!(writer, "warning: ")?,
+            _ => {}
+        }
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn capture(verbosity: u8, format: LogFormat) -> String {
+        let buffer = Buffer::default();
+        let sink = buffer.clone();
+        let subscriber = subscriber(verbosity, false, format, false, move || sink.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let _run = tracing::info_span!("run", run_id = "r1", command = "ambush").entered();
+            let _axis = tracing::info_span!("axis", axis = "Cpu").entered();
+            tracing::warn!("stressor exited early");
+            tracing::trace!("sampled load");
+        });
+        let out = buffer.0.lock().unwrap().clone();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn levels_and_span_context_follow_verbosity() {
+        if std::env::var_os("RUST_LOG").is_some() {
+            return;
+        }
+        assert_eq!(default_filter(0, false), "warn,panic_attack=info");
+        assert_eq!(default_filter(2, true), "warn,panic_attack=warn");
+
+        assert_eq!(
+            capture(0, LogFormat::Text),
+            "warning: stressor exited early\n"
+        );
+
+        let json = capture(0, LogFormat::Json);
+        let line: serde_json::Value =
+            serde_json::from_str(json.lines().next().unwrap()).expect("json log line");
+        assert_eq!(line["fields"]["message"], "stressor exited early");
+        assert_eq!(line["spans"][0]["run_id"], "r1");
+        assert_eq!(line["span"]["axis"], "Cpu");
+        assert_eq!(json.lines().count(), 1, "trace is filtered at -v0");
+    }
+}
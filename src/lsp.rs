@@ -0,0 +1,635 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Language Server Protocol front end (`panic-attack lsp`).
+//!
+//! Speaks JSON-RPC over stdio. The workspace is analyzed on `initialized` and again on every
+//! save, reusing per-file results for unchanged files, and each weak point is published as
+//! diagnostics on the lines that triggered it. Quick fixes replace `unwrap`/`expect` with
+//! `?` and insert a `panic-attack: ignore` annotation.
+
+use crate::assail::analyzer::{Analyzer, FileCache};
+use crate::report::sarif::{parse_location, rule_id};
+use crate::types::*;
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Serve LSP on `input`/`output` until the client sends `exit` or closes the stream.
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> Result<()> {
+    let mut server = Server::new();
+    loop {
+        let replies = match read_message(&mut input)? {
+            None => return Ok(()),
+            Some(Ok(message)) => match server.handle(&message) {
+                Some(replies) => replies,
+                None => return Ok(()),
+            },
+            Some(Err(err)) => vec![error_response(Value::Null, PARSE_ERROR, &err.to_string())],
+        };
+        for reply in replies {
+            write_message(&mut output, &reply)?;
+        }
+    }
+}
+
+/// Read one `Content-Length` framed message; `None` at end of stream, `Some(Err)` when
+/// the body is not JSON.
+pub fn read_message(input: &mut impl BufRead) -> Result<Option<serde_json::Result<Value>>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+    let length = length.ok_or_else(|| anyhow!("LSP message without Content-Length"))?;
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)))
+}
+
+pub fn write_message(output: &mut impl Write, message: &Value) -> Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    output.flush()?;
+    Ok(())
+}
+
+/// Protocol state, independent of the transport.
+pub struct Server {
+    root: Option<PathBuf>,
+    cache: FileCache,
+    report: Option<AssailReport>,
+    /// Text of open documents by URI; closed files are read from disk.
+    documents: HashMap<String, String>,
+    /// URIs with diagnostics currently shown, so resolved files can be cleared.
+    published: BTreeSet<String>,
+    shutdown: bool,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            cache: FileCache::new(),
+            report: None,
+            documents: HashMap::new(),
+            published: BTreeSet::new(),
+            shutdown: false,
+        }
+    }
+
+    /// Handle one message and return what to send back; `None` once the client sends `exit`.
+    pub fn handle(&mut self, message: &Value) -> Option<Vec<Value>> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        if method == "exit" {
+            return None;
+        }
+        if let Some(id) = id {
+            if self.shutdown {
+                return Some(vec![error_response(
+                    id,
+                    INVALID_REQUEST,
+                    "server is shutting down",
+                )]);
+            }
+            let result = match method {
+                "initialize" => self.initialize(params),
+                "shutdown" => {
+                    self.shutdown = true;
+                    Value::Null
+                }
+                "textDocument/codeAction" => self.code_actions(params),
+                _ => {
+                    return Some(vec![error_response(
+                        id,
+                        METHOD_NOT_FOUND,
+                        &format!("unsupported method {}", method),
+                    )])
+                }
+            };
+            return Some(vec![json!({"jsonrpc": "2.0", "id": id, "result": result})]);
+        }
+
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        Some(match method {
+            "initialized" => self.analyze(),
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.clone(), text.to_string());
+                self.publish_document(&uri)
+            }
+            "textDocument/didChange" => {
+                // Full sync: the last change carries the whole document.
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                self.publish_document(&uri)
+            }
+            "textDocument/didSave" => self.analyze(),
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                Vec::new()
+            }
+            _ => Vec::new(),
+        })
+    }
+
+    fn initialize(&mut self, params: &Value) -> Value {
+        self.root = params["rootUri"]
+            .as_str()
+            .or_else(|| params["workspaceFolders"][0]["uri"].as_str())
+            .and_then(uri_path)
+            .or_else(|| params["rootPath"].as_str().map(PathBuf::from));
+        json!({
+            "capabilities": {
+                "textDocumentSync": {"openClose": true, "change": 1, "save": true},
+                "codeActionProvider": {"codeActionKinds": ["quickfix"]}
+            },
+            "serverInfo": {"name": "panic-attack", "version": env!("CARGO_PKG_VERSION")}
+        })
+    }
+
+    /// Rescan the workspace and republish every file's diagnostics.
+    fn analyze(&mut self) -> Vec<Value> {
+        let Some(root) = self.root.clone() else {
+            return vec![log_message(2, "no workspace root; diagnostics disabled")];
+        };
+        match Analyzer::new(&root).and_then(|analyzer| analyzer.analyze_cached(&mut self.cache)) {
+            Ok(report) => self.report = Some(report),
+            Err(err) => {
+                return vec![log_message(1, &format!("assail failed: {:#}", err))];
+            }
+        }
+
+        let mut files = BTreeSet::new();
+        for wp in self.report.iter().flat_map(|r| &r.weak_points) {
+            if let Some(location) = &wp.location {
+                files.insert(file_uri(&root.join(parse_location(location).0)));
+            }
+        }
+        let mut messages: Vec<Value> = files
+            .iter()
+            .flat_map(|uri| self.publish_document(uri))
+            .collect();
+        for stale in self.published.difference(&files) {
+            messages.push(publish_diagnostics(stale, Vec::new()));
+        }
+        self.published = files;
+        messages
+    }
+
+    fn publish_document(&self, uri: &str) -> Vec<Value> {
+        let (Some(root), Some(report), Some(path)) = (&self.root, &self.report, uri_path(uri))
+        else {
+            return Vec::new();
+        };
+        let Ok(rel) = path.strip_prefix(root) else {
+            return Vec::new();
+        };
+        let rel = rel.to_string_lossy();
+        let text = self.text(uri, &path);
+        let diagnostics = report
+            .weak_points
+            .iter()
+            .filter_map(|wp| {
+                let (file, line) = parse_location(wp.location.as_deref()?);
+                (file == rel).then(|| diagnostics(wp, line, &text))
+            })
+            .flatten()
+            .collect();
+        vec![publish_diagnostics(uri, diagnostics)]
+    }
+
+    fn text(&self, uri: &str, path: &Path) -> String {
+        self.documents
+            .get(uri)
+            .cloned()
+            .unwrap_or_else(|| fs::read_to_string(path).unwrap_or_default())
+    }
+
+    fn code_actions(&self, params: &Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(path) = uri_path(uri) else {
+            return json!([]);
+        };
+        let text = self.text(uri, &path);
+        let lines: Vec<&str> = text.lines().collect();
+        let mut actions = Vec::new();
+        let mut ignored = BTreeSet::new();
+
+        for diagnostic in params["context"]["diagnostics"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            if diagnostic["source"] != "panic-attack" {
+                continue;
+            }
+            let range = &diagnostic["range"];
+            let line_index = range["start"]["line"].as_u64().unwrap_or(0) as usize;
+            let line = lines.get(line_index).copied().unwrap_or_default();
+
+            if diagnostic["code"] == rule_id(&WeakPointCategory::PanicPath) {
+                let start = byte_offset(line, range["start"]["character"].as_u64().unwrap_or(0));
+                let site = &line[start..];
+                let call = if site.starts_with(".unwrap()") {
+                    Some("unwrap")
+                } else if site.starts_with(".expect(") {
+                    Some("expect")
+                } else {
+                    None
+                };
+                if let Some(call) = call {
+                    actions.push(json!({
+                        "title": format!("Replace {} with ?", call),
+                        "kind": "quickfix",
+                        "diagnostics": [diagnostic],
+                        "isPreferred": true,
+                        "edit": {"changes": {uri: [{"range": range, "newText": "?"}]}}
+                    }));
+                }
+            }
+
+            let category = diagnostic["data"]["category"].as_str().unwrap_or_default();
+            let Some(prefix) = comment_prefix(&path) else {
+                continue;
+            };
+            if category.is_empty() || !ignored.insert(category.to_string()) {
+                continue;
+            }
+            let indent: String = line.chars().take_while(|c| c.is_whitespace()).collect();
+            actions.push(json!({
+                "title": format!("Ignore {} findings in this file", category),
+                "kind": "quickfix",
+                "diagnostics": [diagnostic],
+                "edit": {"changes": {uri: [{
+                    "range": range_json(line_index, 0, 0),
+                    "newText": format!(
+                        "{}{} panic-attack: ignore category={} reason=\"TODO\"\n",
+                        indent, prefix, category
+                    )
+                }]}}
+            }));
+        }
+        Value::Array(actions)
+    }
+}
+
+/// Diagnostics for one weak point: one per call site its category can be traced to, else
+/// one at the reported line (or the top of the file).
+fn diagnostics(wp: &WeakPoint, line: Option<u32>, text: &str) -> Vec<Value> {
+    let mut sites = match line {
+        Some(line) => {
+            let index = line.saturating_sub(1) as usize;
+            let len = text.lines().nth(index).map(utf16_len).unwrap_or(0);
+            vec![(index, 0, len)]
+        }
+        None => sites(wp.category, text),
+    };
+    if sites.is_empty() {
+        sites.push((0, 0, 0));
+    }
+    sites
+        .into_iter()
+        .map(|(line, start, end)| {
+            json!({
+                "range": range_json(line, start, end),
+                "severity": severity(wp.severity),
+                "code": rule_id(&wp.category),
+                "source": "panic-attack",
+                "message": wp.description,
+                "data": {"category": format!("{:?}", wp.category)}
+            })
+        })
+        .collect()
+}
+
+/// Source text that triggers each category, for the categories that have one.
+fn anchors(category: WeakPointCategory) -> &'static [&'static str] {
+    use WeakPointCategory::*;
+    match category {
+        PanicPath => &[
+            ".unwrap()",
+            ".expect(",
+            "panic!(",
+            "unreachable!(",
+            "todo!(",
+            "unimplemented!(",
+        ],
+        UnsafeCode => &["unsafe {", "unsafe fn", "unsafe impl"],
+        UnsafeTransmute => &["transmute"],
+        MutableStatic => &["static mut "],
+        ManualSendSync => &["unsafe impl Send", "unsafe impl Sync"],
+        InsecureProtocol => &["http://"],
+        DynamicCodeExecution => &["eval("],
+        UnsafeDeserialization => &["pickle.load", "yaml.load("],
+        CommandInjection => &["Command::new(", "os.system(", "subprocess."],
+        _ => &[],
+    }
+}
+
+/// `(line, start, end)` of every anchor match, in UTF-16 columns. Calls cover their
+/// parenthesised arguments so a quick fix can replace the whole call.
+fn sites(category: WeakPointCategory, text: &str) -> Vec<(usize, usize, usize)> {
+    let mut sites = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("//") || trimmed.starts_with('#') {
+            continue;
+        }
+        for anchor in anchors(category) {
+            for (start, _) in line.match_indices(anchor) {
+                let mut end = start + anchor.len();
+                if anchor.ends_with('(') {
+                    end = closing_paren(line, end).unwrap_or(line.len());
+                }
+                sites.push((index, utf16_len(&line[..start]), utf16_len(&line[..end])));
+            }
+        }
+    }
+    sites.sort_unstable();
+    sites.dedup();
+    sites
+}
+
+/// Byte offset just past the `)` closing a call whose arguments start at `from`.
+fn closing_paren(line: &str, from: usize) -> Option<usize> {
+    let mut depth = 1;
+    for (offset, c) in line[from..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(from + offset + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Same split as SARIF: critical and high are errors.
+fn severity(severity: Severity) -> u8 {
+    match severity {
+        Severity::Critical | Severity::High => 1,
+        Severity::Medium => 2,
+        Severity::Low => 3,
+    }
+}
+
+/// Line-comment leader for the annotation quick fix; `None` where the annotation syntax has
+/// no matching comment form.
+fn comment_prefix(path: &Path) -> Option<&'static str> {
+    match Language::detect(path.to_str().unwrap_or("")) {
+        Language::Rust
+        | Language::C
+        | Language::Cpp
+        | Language::Go
+        | Language::JavaScript
+        | Language::Java
+        | Language::Gleam
+        | Language::ReScript
+        | Language::Zig
+        | Language::Odin
+        | Language::Pony
+        | Language::DLang => Some("//"),
+        Language::Python
+        | Language::Ruby
+        | Language::Shell
+        | Language::Elixir
+        | Language::Julia
+        | Language::Nim
+        | Language::Nix
+        | Language::Nickel => Some("#"),
+        Language::Haskell
+        | Language::PureScript
+        | Language::Idris
+        | Language::Agda
+        | Language::Lean
+        | Language::Ada
+        | Language::Lua => Some("--"),
+        Language::Scheme | Language::Racket => Some(";"),
+        _ => None,
+    }
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+fn byte_offset(line: &str, utf16: u64) -> usize {
+    let mut units = 0;
+    for (offset, c) in line.char_indices() {
+        if units >= utf16 as usize {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+fn range_json(line: usize, start: usize, end: usize) -> Value {
+    json!({
+        "start": {"line": line, "character": start},
+        "end": {"line": line, "character": end}
+    })
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {"uri": uri, "diagnostics": diagnostics}
+    })
+}
+
+fn log_message(kind: u8, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "window/logMessage",
+        "params": {"type": kind, "message": message}
+    })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "error": {"code": code, "message": message}})
+}
+
+/// `file://` URI for an absolute path, percent-encoding everything outside the unreserved set.
+pub fn file_uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+/// Path named by a `file://` URI.
+pub fn uri_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?.as_bytes();
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+    while i < encoded.len() {
+        let decoded = (encoded[i] == b'%')
+            .then(|| std::str::from_utf8(encoded.get(i + 1..i + 3)?).ok())
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match decoded {
+            Some(byte) => {
+                bytes.push(byte);
+                i += 3;
+            }
+            None => {
+                bytes.push(encoded[i]);
+                i += 1;
+            }
+        }
+    }
+    Some(PathBuf::from(String::from_utf8(bytes).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+
+    fn notification<'a>(replies: &'a [Value], uri: &str) -> &'a Vec<Value> {
+        replies
+            .iter()
+            .find(|reply| reply["params"]["uri"] == uri)
+            .and_then(|reply| reply["params"]["diagnostics"].as_array())
+            .expect("diagnostics published")
+    }
+
+    #[test]
+    fn test_uri_round_trip() {
+        let path = Path::new("/tmp/my project/naïve.rs");
+        let uri = file_uri(path);
+        assert_eq!(uri, "file:///tmp/my%20project/na%C3%AFve.rs");
+        assert_eq!(uri_path(&uri).unwrap(), path);
+        assert!(uri_path("untitled:1").is_none());
+    }
+
+    #[test]
+    fn test_framing_round_trip() {
+        let mut wire = Vec::new();
+        write_message(&mut wire, &json!({"jsonrpc": "2.0", "method": "exit"})).unwrap();
+        let mut input = Cursor::new(wire);
+        let message = read_message(&mut input).unwrap().unwrap().unwrap();
+        assert_eq!(message["method"], "exit");
+        assert!(read_message(&mut input).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_diagnostics_and_quick_fixes() {
+        let dir = TempDir::new().unwrap();
+        let source = "fn load() -> Option<u8> {
+    let a = first().unwrap();
+    let b = second().expect(\"b (always set)\");
+    let c = third().unwrap();
+    let d = fourth().unwrap();
+    let e = fifth().unwrap();
+    let f = sixth().unwrap();
+    Some(a + b + c + d + e + f)
+}
+";
+        fs::write(dir.path().join("lib.rs"), source).unwrap();
+        let uri = file_uri(&dir.path().join("lib.rs"));
+
+        let mut server = Server::new();
+        let replies = server
+            .handle(&json!({"jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": {"rootUri": file_uri(dir.path())}}))
+            .unwrap();
+        assert_eq!(
+            replies[0]["result"]["capabilities"]["textDocumentSync"]["save"],
+            true
+        );
+
+        let replies = server
+            .handle(&json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}))
+            .unwrap();
+        let diagnostics = notification(&replies, &uri);
+        assert_eq!(diagnostics.len(), 6, "one per unwrap/expect call");
+        assert_eq!(diagnostics[0]["code"], "PA005");
+        assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+        assert_eq!(diagnostics[1]["range"]["start"]["character"], 20);
+        assert_eq!(diagnostics[1]["range"]["end"]["character"], 45);
+
+        let replies = server
+            .handle(
+                &json!({"jsonrpc": "2.0", "id": 2, "method": "textDocument/codeAction",
+                "params": {"textDocument": {"uri": uri},
+                    "range": diagnostics[1]["range"],
+                    "context": {"diagnostics": [diagnostics[1]]}}}),
+            )
+            .unwrap();
+        let actions = replies[0]["result"].as_array().unwrap();
+        assert_eq!(actions[0]["title"], "Replace expect with ?");
+        assert_eq!(actions[0]["edit"]["changes"][&uri][0]["newText"], "?");
+        assert_eq!(
+            actions[1]["title"],
+            "Ignore PanicPath findings in this file"
+        );
+        assert_eq!(
+            actions[1]["edit"]["changes"][&uri][0]["newText"],
+            "    // panic-attack: ignore category=PanicPath reason=\"TODO\"\n"
+        );
+
+        // Fix the file and save: its diagnostics are cleared.
+        fs::write(dir.path().join("lib.rs"), "fn load() {}\n").unwrap();
+        let replies = server
+            .handle(&json!({"jsonrpc": "2.0", "method": "textDocument/didSave",
+                "params": {"textDocument": {"uri": uri}}}))
+            .unwrap();
+        assert!(notification(&replies, &uri).is_empty());
+
+        let replies = server
+            .handle(&json!({"jsonrpc": "2.0", "id": 3, "method": "shutdown"}))
+            .unwrap();
+        assert_eq!(replies[0]["result"], Value::Null);
+        assert!(server
+            .handle(&json!({"jsonrpc": "2.0", "method": "exit"}))
+            .is_none());
+    }
+}
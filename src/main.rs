@@ -15,14 +15,33 @@ mod assail;
 mod attestation;
 mod attack;
 mod axial;
+mod calibration;
+mod ci;
 mod diagnostics;
+mod error;
+mod flaky;
 mod i18n;
 mod kanren;
 mod kin;
+mod lock;
+mod logging;
+mod lsp;
 mod panll;
+mod pipeline;
+mod porcelain;
+mod preflight;
+mod progress;
+mod redact;
 mod report;
+mod run_as;
+mod schema;
+mod seed;
 mod signatures;
+mod snapshot;
 mod storage;
+mod summary;
+mod syscalls;
+mod triage;
 mod assemblyline;
 mod notify;
 mod types;
@@ -34,18 +53,39 @@ use crate::abduct::{
     AbductConfig, DependencyScope, ExecutionCommand as AbductExecutionCommand, TimeMode,
 };
 use crate::adjudicate::AdjudicateConfig;
+use crate::amuck::clean::{CleanOptions as AmuckCleanOptions, KeepMode};
 use crate::amuck::{AmuckConfig, AmuckPreset, ExecutionCommand as AmuckExecutionCommand};
+use crate::assail::owners::{self, Ownership};
 use crate::attack::AttackProfile;
-use crate::axial::{AxialConfig, ExecutionCommand as AxialExecutionCommand};
-use crate::i18n::Lang;
-use crate::report::{format_diff, load_report, ReportOutputFormat, ReportTui, ReportView};
-use crate::storage::{latest_reports, persist_report};
+use crate::axial::export::ExportFormat;
+use crate::axial::{
+    AxialConfig, ExecutionCommand as AxialExecutionCommand, ProbeConfig as AxialProbeConfig,
+};
+use crate::calibration::CalibrationStore;
+use crate::i18n::{Lang, TemplateSet};
+use crate::logging::LogFormat;
+use crate::report::chart::{Chart, ChartFormat, ChartKind};
+use crate::report::sbom::SbomFormat;
+use crate::report::{
+    format_diff, load_report, DiffOptions, DiffReport, FindingFilter, ReportGenerator,
+    ReportOutputFormat, ReportTui, ReportView, ReproOptions,
+};
+use crate::run_as::RunAs;
+use crate::schema::SchemaKind;
+use crate::signatures::feedback::{SignatureFeedback, Verdict};
+use crate::signatures::symbolize::{self, Symbolizer};
+use crate::signatures::SignatureEngine;
+use crate::storage::{
+    latest_reports, latest_reports_of_one_program, persist_any_report, persist_report,
+    write_atomic, ReportFilter, ReportKind, StorageMode, StoredReport,
+};
 use anyhow::{anyhow, Context, Result};
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use std::collections::HashMap;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::time::Duration;
 use types::*;
 
@@ -73,22 +113,193 @@ struct Cli {
     #[arg(long, default_value_t = false, global = true)]
     expand_sections: bool,
 
-    #[arg(long, value_enum, default_value_t = ReportOutputFormat::Json, global = true)]
-    output_format: ReportOutputFormat,
+    /// Format for saved reports (default: json); `diff` prints text unless this is set
+    #[arg(long, value_enum, global = true)]
+    output_format: Option<ReportOutputFormat>,
 
     #[arg(long, default_value_t = false, global = true)]
     pivot: bool,
 
+    /// Print only weak points of this severity or higher, and only attacks that found
+    /// something (console output only; saved reports are complete)
+    #[arg(long, value_enum, value_name = "SEVERITY", global = true)]
+    min_severity: Option<SeverityArg>,
+
+    /// Print only attacks on these axes and weak points that recommend them (comma-separated)
+    #[arg(long, value_name = "AXIS", value_delimiter = ',', value_parser = parse_axis_value, global = true)]
+    only_axis: Vec<AttackAxis>,
+
+    /// Print only weak points of these categories, e.g. panic-path,unsafe-code (comma-separated)
+    #[arg(long, value_enum, value_delimiter = ',', global = true)]
+    only_category: Vec<WeakPointCategory>,
+
     #[arg(long, value_name = "DIR", global = true)]
     store: Option<PathBuf>,
 
     #[arg(long, default_value_t = false, global = true)]
     quiet: bool,
 
+    /// Screen-reader and restricted-terminal output for reports and the TUI: no color,
+    /// ASCII-only glyphs, severities spelled out and the assessment first
+    #[arg(long, default_value_t = false, global = true)]
+    accessible: bool,
+
+    /// Print only a versioned key=value summary on stdout (human output goes to stderr);
+    /// unix only, and ignored by lsp
+    #[arg(long, default_value_t = false, global = true)]
+    porcelain: bool,
+
+    /// Also write a JSON exit summary (status, verdict, counts, report paths, run id,
+    /// timings) when the command finishes, to PATH or, when none follows, reports/summary.json
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = summary::DEFAULT_PATH,
+        global = true
+    )]
+    summary_out: Option<PathBuf>,
+
+    /// More log detail on stderr: -v for debug, -vv for trace (RUST_LOG overrides)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Log line format on stderr
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, global = true)]
+    log_format: LogFormat,
+
     #[arg(long, default_value_t = false, global = true)]
     parallel: bool,
+
+    /// Seed for stress jitter, CPU chaos and amuck selection (default: random, recorded in reports)
+    #[arg(long, value_name = "SEED", global = true)]
+    seed: Option<u64>,
+
+    /// Wall-clock budget in seconds for all axes of an attack, assault or ambush; when time
+    /// runs short the remaining axes are shortened (or skipped) so the report still gets written
+    #[arg(long, value_name = "SECS", global = true)]
+    campaign_timeout: Option<u64>,
+
+    /// Wait for another campaign holding the store's lock to finish instead of refusing to start
+    #[arg(
+        long,
+        default_value_t = false,
+        global = true,
+        conflicts_with = "steal_lock"
+    )]
+    wait: bool,
+
+    /// Take the store's campaign lock over from the campaign holding it
+    #[arg(long, default_value_t = false, global = true)]
+    steal_lock: bool,
+
+    /// Start destructive runs even when the pre-flight estimate exceeds free disk or memory
+    #[arg(long, default_value_t = false, global = true)]
+    force: bool,
+
+    /// Keep secrets in crash output instead of scrubbing reports before they are written
+    #[arg(long, default_value_t = false, global = true)]
+    no_redact: bool,
+
+    /// Extra redaction rule, format: NAME=REGEX (a `secret` group limits what is replaced)
+    #[arg(long = "redact-pattern", value_name = "NAME=REGEX", global = true)]
+    redact_patterns: Vec<String>,
+
+    /// Reject saved reports with fields this version does not know instead of ignoring them
+    #[arg(long, default_value_t = false, global = true)]
+    strict: bool,
+
+    /// Downgrade weak points that past assaults exercised without crashing, and record
+    /// assault/ambush outcomes for future calibration
+    #[arg(long, default_value_t = false, global = true)]
+    calibrate: bool,
+
+    /// Calibration store (default: .panic-attack-calibration.json next to the source; implies
+    /// --calibrate)
+    #[arg(long, value_name = "PATH", global = true)]
+    calibration_file: Option<PathBuf>,
+
+    /// Record assault/ambush crash buckets in the flakiness store and mark each as a new
+    /// failure or a known one
+    #[arg(long, default_value_t = false, global = true)]
+    flaky: bool,
+
+    /// Flakiness store (default: .panic-attack-flaky.json in the working directory; implies
+    /// --flaky)
+    #[arg(long, value_name = "PATH", global = true)]
+    flaky_db: Option<PathBuf>,
+
+    /// Signature feedback file whose true/false positive labels calibrate signature
+    /// confidences (default: .panic-attack-signature-feedback.json, used when it exists)
+    #[arg(long, value_name = "PATH", global = true)]
+    signature_feedback: Option<PathBuf>,
+
+    /// Directory of separate debug files (`.build-id/` tree or `NAME.debug`) used to
+    /// symbolize crash backtraces from stripped binaries; may be repeated
+    #[arg(long = "debug-dir", value_name = "DIR", global = true)]
+    debug_dirs: Vec<PathBuf>,
+
+    /// Symbolize only from debug directories and the symbol cache, never from debuginfod
+    #[arg(long, default_value_t = false, global = true)]
+    offline_symbols: bool,
+
+    /// Attribute findings CODEOWNERS does not cover to their `git blame` author
+    #[arg(long, default_value_t = false, global = true)]
+    blame_owners: bool,
+
+    /// Show only the weak points and crashes this CODEOWNERS owner (e.g. @backend-team) owns
+    /// in `report`, `tui`, `gui` and `diff`
+    #[arg(long, value_name = "OWNER", global = true)]
+    owner: Option<String>,
+
+    /// Language of generated critical issues and recommendations (en, es, fr, de, ja, pt,
+    /// zh, ko, it, ru)
+    #[arg(
+        long,
+        value_name = "CODE",
+        value_parser = parse_lang_value,
+        default_value = "en",
+        global = true
+    )]
+    report_lang: Lang,
+
+    /// YAML or JSON file of organization templates overriding the built-in recommendation
+    /// wording, keyed by language code and then by `assess.*` key
+    #[arg(long, value_name = "FILE", global = true)]
+    recommendation_templates: Option<PathBuf>,
+
+    /// Write a Rust #[test] skeleton beside each saved crash's reproduction script
+    #[arg(long, default_value_t = false, global = true)]
+    repro_tests: bool,
+
+    /// Liveness probe for attacked targets: output:REGEX, file:PATH or tcp:HOST:PORT; a target
+    /// showing no progress for --stall-window seconds is stopped and reported as stalled
+    #[arg(long, value_name = "CHECK", global = true)]
+    progress_check: Option<String>,
+
+    /// Seconds without progress before --progress-check reports a stall
+    #[arg(long, value_name = "SECS", default_value_t = 30, global = true)]
+    stall_window: u64,
+
+    /// Sample package power (RAPL), temperatures and thermal throttling during attacks
+    #[arg(long, default_value_t = false, global = true)]
+    telemetry: bool,
+
+    /// Hold and thrash GPU memory through Vulkan during attacks (needs the `gpu` feature)
+    #[arg(long, default_value_t = false, global = true)]
+    gpu_stress: bool,
+
+    /// GPU adapter for --gpu-stress: index or name fragment (default: first adapter)
+    #[arg(long, value_name = "DEVICE", global = true)]
+    gpu_device: Option<String>,
+
+    /// Device memory for --gpu-stress to hold, in MiB (default: scaled from intensity)
+    #[arg(long, value_name = "MIB", global = true)]
+    gpu_memory: Option<u64>,
 }
 
+// Parsed once per process; boxing the ambush options would only add noise.
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
     /// Run assail static analysis on a target program
@@ -101,10 +312,6 @@ enum Commands {
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Verbose output
-        #[arg(short, long)]
-        verbose: bool,
-
         /// Enable attestation chain (writes .attestation.json sidecar)
         #[arg(long, default_value_t = false)]
         attest: bool,
@@ -113,6 +320,25 @@ enum Commands {
         /// Requires the `signing` feature.
         #[arg(long, value_name = "PATH")]
         signing_key: Option<PathBuf>,
+
+        /// External analyzer to run and merge (repeatable; overrides `(analyzers (enabled ...))`
+        /// in AI.a2ml)
+        #[arg(long = "analyzer", value_enum, value_name = "NAME")]
+        analyzers: Vec<AnalyzerArg>,
+
+        /// Skip external analyzers even if AI.a2ml enables them
+        #[arg(long, conflicts_with = "analyzers")]
+        no_analyzers: bool,
+
+        /// OSV advisory snapshot (file or directory, e.g. a RustSec export) to match declared
+        /// dependencies against (default: `(advisories (database ...))` in AI.a2ml)
+        #[arg(long, value_name = "PATH")]
+        advisories: Option<PathBuf>,
+
+        /// Keep running and print new and resolved weak points whenever a file under TARGET
+        /// changes
+        #[arg(long, conflicts_with_all = ["output", "attest"])]
+        watch: bool,
     },
 
     /// Execute a single attack on a target program
@@ -137,13 +363,79 @@ enum Commands {
         #[arg(long, value_enum)]
         probe: Option<ProbeModeArg>,
 
-        /// Attack axis to use
-        #[arg(short, long, value_enum)]
-        axis: AttackAxisArg,
+        /// Run the concurrency axis under a recorder; rr keeps traces of crashing runs,
+        /// schedule perturbs thread scheduling from the seed and keeps crashing schedules
+        #[arg(long, value_enum)]
+        wrapper: Option<WrapperArg>,
+
+        /// Apply a schedule kept from a crashing run to the concurrency axis (implies
+        /// --wrapper schedule)
+        #[arg(long, value_name = "FILE")]
+        replay_schedule: Option<PathBuf>,
+
+        /// Make the target's malloc/calloc/realloc fail now and then on the memory axis
+        /// (LD_PRELOAD shim built with cc; Linux/glibc; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        malloc_faults: bool,
+
+        /// Probability that one allocation fails, 0-1 (implies --malloc-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        malloc_fail_rate: Option<f64>,
+
+        /// Make the target's file reads, writes and opens fail now and then on the disk axis
+        /// (seccomp user notification; Linux; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        io_faults: bool,
+
+        /// Probability that one file syscall fails, 0-1 (implies --io-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        io_fault_rate: Option<f64>,
+
+        /// Make the target's own connects, sends and receives fail or stall now and then on
+        /// the network axis (seccomp user notification; Linux; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        net_faults: bool,
+
+        /// Probability that one socket syscall fails, 0-1 (implies --net-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        net_fault_rate: Option<f64>,
+
+        /// Signal-storm delivery rate in Hz for the signal axis (default scales with intensity)
+        #[arg(long, value_name = "HZ")]
+        signal_hz: Option<f64>,
+
+        /// Signals rotated by the signal axis, comma-separated (e.g. usr1,winch,chld)
+        #[arg(long, value_name = "SIGNALS", value_delimiter = ',')]
+        signals: Vec<String>,
+
+        /// Interleave SIGSTOP/SIGCONT pulses into the signal storm
+        #[arg(long, default_value_t = false)]
+        signal_pulse: bool,
+
+        /// Attack axis to use: cpu, memory, disk, network, concurrency, time, signal, or a
+        /// custom axis registered under `custom_axes` in the profile
+        #[arg(short, long, value_parser = parse_axis_value)]
+        axis: AttackAxis,
 
-        /// Attack intensity
-        #[arg(short, long, default_value = "medium")]
-        intensity: IntensityArg,
+        /// Attack intensity: light, medium, heavy, extreme, a 0.0-1.0 value (e.g. 0.65) or a
+        /// preset from the profile's intensity_presets
+        #[arg(short, long, default_value = "medium", value_parser = parse_intensity_value)]
+        intensity: IntensitySpec,
 
         /// Attack duration in seconds
         #[arg(short, long, default_value = "60")]
@@ -176,18 +468,110 @@ enum Commands {
         #[arg(long, value_enum)]
         probe: Option<ProbeModeArg>,
 
-        /// Attack axes (default: all)
-        #[arg(short, long, value_delimiter = ',')]
-        axes: Option<Vec<AttackAxisArg>>,
+        /// Run the concurrency axis under a recorder; rr keeps traces of crashing runs,
+        /// schedule perturbs thread scheduling from the seed and keeps crashing schedules
+        #[arg(long, value_enum)]
+        wrapper: Option<WrapperArg>,
+
+        /// Apply a schedule kept from a crashing run to the concurrency axis (implies
+        /// --wrapper schedule)
+        #[arg(long, value_name = "FILE")]
+        replay_schedule: Option<PathBuf>,
+
+        /// Make the target's malloc/calloc/realloc fail now and then on the memory axis
+        /// (LD_PRELOAD shim built with cc; Linux/glibc; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        malloc_faults: bool,
+
+        /// Probability that one allocation fails, 0-1 (implies --malloc-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        malloc_fail_rate: Option<f64>,
+
+        /// Make the target's file reads, writes and opens fail now and then on the disk axis
+        /// (seccomp user notification; Linux; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        io_faults: bool,
+
+        /// Probability that one file syscall fails, 0-1 (implies --io-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        io_fault_rate: Option<f64>,
+
+        /// Make the target's own connects, sends and receives fail or stall now and then on
+        /// the network axis (seccomp user notification; Linux; the rate scales with intensity)
+        #[arg(long, default_value_t = false)]
+        net_faults: bool,
+
+        /// Probability that one socket syscall fails, 0-1 (implies --net-faults)
+        #[arg(
+            long,
+            value_name = "RATE",
+            value_parser = parse_fault_rate,
+            allow_negative_numbers = true
+        )]
+        net_fault_rate: Option<f64>,
+
+        /// Signal-storm delivery rate in Hz for the signal axis (default scales with intensity)
+        #[arg(long, value_name = "HZ")]
+        signal_hz: Option<f64>,
+
+        /// Signals rotated by the signal axis, comma-separated (e.g. usr1,winch,chld)
+        #[arg(long, value_name = "SIGNALS", value_delimiter = ',')]
+        signals: Vec<String>,
+
+        /// Interleave SIGSTOP/SIGCONT pulses into the signal storm
+        #[arg(long, default_value_t = false)]
+        signal_pulse: bool,
+
+        /// Attack axes (default: all built-in axes but signal); custom axes come from the profile
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_axis_value)]
+        axes: Option<Vec<AttackAxis>>,
 
-        /// Attack intensity
-        #[arg(short, long, default_value = "medium")]
-        intensity: IntensityArg,
+        /// Attack intensity: light, medium, heavy, extreme, a 0.0-1.0 value (e.g. 0.65) or a
+        /// preset from the profile's intensity_presets
+        #[arg(short, long, default_value = "medium", value_parser = parse_intensity_value)]
+        intensity: IntensitySpec,
 
         /// Attack duration per axis in seconds
         #[arg(short, long, default_value = "30")]
         duration: u64,
 
+        /// Rerun crashing axes to bisect the least intensity/duration that reproduces each crash
+        #[arg(long, default_value_t = false)]
+        explore_crashes: bool,
+
+        /// Save progress to DIR/checkpoint.json after each axis so the campaign can resume
+        #[arg(long, value_name = "DIR", conflicts_with = "resume")]
+        checkpoint: Option<PathBuf>,
+
+        /// Continue the interrupted campaign checkpointed in DIR, skipping finished axes
+        #[arg(long, value_name = "DIR")]
+        resume: Option<PathBuf>,
+
+        /// Hash the files under DIR before and after the attacks and report what changed
+        /// (repeatable)
+        #[arg(long = "snapshot-dir", value_name = "DIR", action = clap::ArgAction::Append)]
+        snapshot_dirs: Vec<PathBuf>,
+
+        /// Read a SQLite database's tables and rows before and after the attacks and report
+        /// what changed (needs the sqlite3 client)
+        #[arg(long, value_name = "FILE")]
+        snapshot_sqlite: Option<PathBuf>,
+
+        /// Fetch the state served by an http:// endpoint before and after the attacks and
+        /// report what changed, by JSON pointer
+        #[arg(long, value_name = "URL")]
+        snapshot_url: Option<String>,
+
         /// Output report to file
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -207,6 +591,17 @@ enum Commands {
         #[arg(long, value_name = "TIMELINE")]
         timeline: Option<PathBuf>,
 
+        /// Run the timeline N times (seeds SEED, SEED+1, ...) and rank its events by how
+        /// often a crash fell while they were active
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 1,
+            requires = "timeline",
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        repeat: u32,
+
         /// Attack profile file (json/yaml) for target args
         #[arg(long, value_name = "PROFILE")]
         profile: Option<PathBuf>,
@@ -219,28 +614,140 @@ enum Commands {
         #[arg(long = "axis-arg", value_name = "AXIS=ARG", action = clap::ArgAction::Append)]
         axis_args: Vec<String>,
 
-        /// Stress axes to apply (default: all)
-        #[arg(short, long, value_delimiter = ',')]
-        axes: Option<Vec<AttackAxisArg>>,
+        /// Signal-storm delivery rate in Hz for the signal axis (default scales with intensity)
+        #[arg(long, value_name = "HZ")]
+        signal_hz: Option<f64>,
+
+        /// Signals rotated by the signal axis, comma-separated (e.g. usr1,winch,chld)
+        #[arg(long, value_name = "SIGNALS", value_delimiter = ',')]
+        signals: Vec<String>,
+
+        /// Interleave SIGSTOP/SIGCONT pulses into the signal storm
+        #[arg(long, default_value_t = false)]
+        signal_pulse: bool,
+
+        /// Memory-axis pressure: allocate in-process, or squeeze the target's own cgroup
+        #[arg(long, value_enum)]
+        memory_mode: Option<MemoryModeArg>,
+
+        /// Initial cgroup memory.high in MiB (implies --memory-mode cgroup)
+        #[arg(long, value_name = "MB")]
+        memory_start_mb: Option<u64>,
 
-        /// Stress intensity
-        #[arg(short, long, default_value = "medium")]
-        intensity: IntensityArg,
+        /// Final cgroup memory.high in MiB (default derived from intensity)
+        #[arg(long, value_name = "MB")]
+        memory_floor_mb: Option<u64>,
+
+        /// Disk-axis io.max limit on the target's cgroup, format: KEY=VALUE
+        /// (rbps/wbps accept K/M/G suffixes; riops/wiops are plain counts)
+        #[arg(long = "io-limit", value_name = "KEY=VALUE", action = clap::ArgAction::Append)]
+        io_limits: Vec<String>,
+
+        /// Directory whose backing device is throttled (default: working directory)
+        #[arg(long, value_name = "DIR")]
+        io_data_dir: Option<PathBuf>,
+
+        /// CPU axis: migrate the target across cores and toggle its nice value
+        #[arg(long, default_value_t = false)]
+        cpu_chaos: bool,
+
+        /// Milliseconds between CPU chaos migrations (implies --cpu-chaos)
+        #[arg(long, value_name = "MS")]
+        cpu_chaos_interval_ms: Option<u64>,
+
+        /// Nice value CPU chaos toggles against 0, 0-19 (implies --cpu-chaos)
+        #[arg(long, value_name = "NICE")]
+        cpu_chaos_nice: Option<i32>,
+
+        /// Adapt intensity during the run to hold this metric at --setpoint
+        /// (--intensity becomes the ceiling)
+        #[arg(long, value_enum, value_name = "METRIC", requires = "setpoint")]
+        adaptive: Option<AdaptiveMetricArg>,
+
+        /// Adaptive setpoint: percent for cpu/memory metrics, MiB for target-rss
+        #[arg(long, value_name = "VALUE", requires = "adaptive")]
+        setpoint: Option<f64>,
+
+        /// Milliseconds between adaptive controller steps (default 500)
+        #[arg(long, value_name = "MS", requires = "adaptive")]
+        adaptive_interval_ms: Option<u64>,
+
+        /// Stress axes to apply (default: all built-in axes but signal); custom axes come from
+        /// the profile
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_axis_value)]
+        axes: Option<Vec<AttackAxis>>,
+
+        /// Stress intensity: light, medium, heavy, extreme, a 0.0-1.0 value (e.g. 0.65) or a
+        /// preset from the profile's intensity_presets
+        #[arg(short, long, default_value = "medium", value_parser = parse_intensity_value)]
+        intensity: IntensitySpec,
 
         /// Ambush duration per axis in seconds
         #[arg(short, long, default_value = "30")]
         duration: u64,
 
+        /// Rerun crashing axes to bisect the least intensity/duration that reproduces each crash
+        #[arg(long, default_value_t = false)]
+        explore_crashes: bool,
+
+        /// WebDriver endpoint (chromedriver/geckodriver) that storms --browser-url with random
+        /// clicks, input and navigation during the ambush
+        #[arg(long, value_name = "URL", requires = "browser_url")]
+        webdriver: Option<String>,
+
+        /// Page of the target's web UI for the WebDriver storm
+        #[arg(long, value_name = "URL", requires = "webdriver")]
+        browser_url: Option<String>,
+
+        /// Browser behind --webdriver
+        #[arg(long, value_enum, default_value = "chrome")]
+        browser: BrowserArg,
+
+        /// Test database (postgres:// or mysql://) to exhaust, kill and slow down through
+        /// psql/mysql during the ambush; symptoms are lined up with target failures
+        #[arg(long, value_name = "DSN")]
+        db: Option<String>,
+
+        /// Table --db takes exclusive locks on in bursts
+        #[arg(long, value_name = "TABLE", requires = "db")]
+        db_lock_table: Option<String>,
+
+        /// Connections --db holds open (default scales with intensity)
+        #[arg(long, value_name = "N", requires = "db")]
+        db_connections: Option<usize>,
+
+        /// Broker topic (mqtt://host/topic or kafka://host/topic) to flood with malformed,
+        /// oversized, duplicate and out-of-order messages at rising rates during the ambush
+        #[arg(long, value_name = "URL")]
+        message_flood: Option<String>,
+
+        /// Rate phases --message-flood splits the ambush into, doubling each time
+        #[arg(
+            long,
+            value_name = "N",
+            default_value = "4",
+            requires = "message_flood"
+        )]
+        flood_phases: usize,
+
+        /// Messages per second in the first flood phase (default scales with intensity)
+        #[arg(long, value_name = "HZ", requires = "message_flood")]
+        flood_rate: Option<f64>,
+
         /// Output report to file
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
 
     /// Amuck: mutate a file with dangerous/user-defined combinations and optionally execute checks
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Amuck {
+        #[command(subcommand)]
+        action: Option<AmuckAction>,
+
         /// Target file to mutate (never modified in place)
-        #[arg(value_name = "TARGET")]
-        target: PathBuf,
+        #[arg(value_name = "TARGET", required = true)]
+        target: Option<PathBuf>,
 
         /// Mutation preset when no --spec is provided
         #[arg(long, value_enum, default_value = "dangerous")]
@@ -250,6 +757,21 @@ enum Commands {
         #[arg(long, value_name = "SPEC")]
         spec: Option<PathBuf>,
 
+        /// Generate the spec from the weak points an assail or assault report found in the
+        /// target; it is written to the output directory and each outcome names its weak point
+        #[arg(long, value_name = "REPORT", conflicts_with = "spec")]
+        from_assail: Option<PathBuf>,
+
+        /// Mutate the target as raw bytes (byte flips, header corruption, truncation,
+        /// length-field inflation) to robustness-test file-format parsers
+        #[arg(long, conflicts_with = "from_assail")]
+        binary: bool,
+
+        /// Variants to keep on disk: `failed-only` deletes each variant whose exec check
+        /// passed as soon as it has run
+        #[arg(long, value_enum, default_value = "all")]
+        keep: KeepMode,
+
         /// Maximum combinations to execute
         #[arg(long, default_value_t = 16)]
         max_combinations: usize,
@@ -266,6 +788,15 @@ enum Commands {
         #[arg(long = "exec-arg", value_name = "ARG", action = clap::ArgAction::Append)]
         exec_args: Vec<String>,
 
+        /// Run --exec-program as this user (name or uid) instead of the operator; needs root
+        #[arg(long, value_name = "USER", requires = "exec_program")]
+        run_as: Option<String>,
+
+        /// Run --exec-program under strace and record the files, network contacts and
+        /// processes it touched
+        #[arg(long, requires = "exec_program")]
+        trace_syscalls: bool,
+
         /// Optional report output path (JSON)
         #[arg(short, long, value_name = "OUT")]
         output: Option<PathBuf>,
@@ -273,9 +804,10 @@ enum Commands {
 
     /// Abduct: isolate, lock, and time-skew a target file (optionally with dependencies)
     Abduct {
-        /// Target file to abduct into an isolated workspace
-        #[arg(value_name = "TARGET")]
-        target: PathBuf,
+        /// Target files to abduct into one isolated workspace (e.g. a module and its tests);
+        /// the first is the one {file} names
+        #[arg(value_name = "TARGET", required = true, num_args = 1..)]
+        targets: Vec<PathBuf>,
 
         /// Optional source root used to resolve dependency graph paths
         #[arg(long, value_name = "PATH")]
@@ -313,7 +845,7 @@ enum Commands {
         #[arg(long, value_name = "PROGRAM")]
         exec_program: Option<String>,
 
-        /// Arguments for --exec-program; placeholders: {file}, {workspace}
+        /// Arguments for --exec-program; placeholders: {file}, {files}, {workspace}
         #[arg(long = "exec-arg", value_name = "ARG", action = clap::ArgAction::Append)]
         exec_args: Vec<String>,
 
@@ -321,6 +853,20 @@ enum Commands {
         #[arg(long, default_value_t = 120)]
         exec_timeout: u64,
 
+        /// Run --exec-program without network access (Linux, user namespaces) and report
+        /// attempted connections
+        #[arg(long, requires = "exec_program")]
+        no_network: bool,
+
+        /// Run --exec-program as this user (name or uid) instead of the operator; needs root
+        #[arg(long, value_name = "USER", requires = "exec_program")]
+        run_as: Option<String>,
+
+        /// Run --exec-program under strace and record the files, network contacts and
+        /// processes it touched
+        #[arg(long, requires = "exec_program")]
+        trace_syscalls: bool,
+
         /// Optional report output path (JSON)
         #[arg(short, long, value_name = "OUT")]
         output: Option<PathBuf>,
@@ -335,6 +881,29 @@ enum Commands {
         /// Optional report output path (JSON)
         #[arg(short, long, value_name = "OUT")]
         output: Option<PathBuf>,
+
+        /// Earlier adjudicate report to compare the risk score with (default: the newest
+        /// adjudicate report in reports/)
+        #[arg(long, value_name = "REPORT")]
+        previous: Option<PathBuf>,
+
+        /// Exit non-zero when the risk score (0-100) reaches this value
+        #[arg(long, value_name = "SCORE")]
+        fail_score: Option<f64>,
+
+        /// Exit non-zero when the risk score rose by at least this much since the previous
+        /// campaign
+        #[arg(long, value_name = "POINTS")]
+        fail_score_increase: Option<f64>,
+
+        /// Also write a short executive summary (verdict, risk trend, top priorities, report
+        /// links); format from the extension, .md or .html
+        #[arg(long, value_name = "FILE")]
+        summary_output: Option<PathBuf>,
+
+        /// Summary language (ISO 639-1 code: en, es, fr, de, ja)
+        #[arg(long, value_enum, default_value = "en")]
+        lang: LangArg,
     },
 
     /// Axial: observe target reactions across attack axes from tool outputs and report artifacts
@@ -363,6 +932,22 @@ enum Commands {
         #[arg(long = "report", value_name = "PATH", action = clap::ArgAction::Append)]
         reports: Vec<PathBuf>,
 
+        /// systemd unit whose journal entries during the runs are observed (repeatable)
+        #[arg(long = "journal", value_name = "UNIT", action = clap::ArgAction::Append)]
+        journal: Vec<String>,
+
+        /// Syslog file whose lines appended during the runs are observed (repeatable)
+        #[arg(long = "syslog", value_name = "FILE", action = clap::ArgAction::Append)]
+        syslog: Vec<PathBuf>,
+
+        /// http:// endpoint polled during the runs for status codes, latency and 5xx bursts
+        #[arg(long, value_name = "URL")]
+        probe_url: Option<String>,
+
+        /// Milliseconds between --probe-url requests
+        #[arg(long, value_name = "MS", default_value_t = 250, requires = "probe_url")]
+        probe_interval_ms: u64,
+
         /// Include the first N lines from observed output/content
         #[arg(long, default_value_t = 20)]
         head: usize,
@@ -387,7 +972,8 @@ enum Commands {
         #[arg(long, value_enum, default_value = "en")]
         lang: LangArg,
 
-        /// Enable aspell checks on observed text
+        /// Enable spellchecks on observed text (built-in with a Hunspell dictionary,
+        /// aspell otherwise)
         #[arg(long, default_value_t = false)]
         aspell: bool,
 
@@ -395,11 +981,24 @@ enum Commands {
         #[arg(long, value_name = "CODE")]
         aspell_lang: Option<String>,
 
+        /// Hunspell .dic file, or a directory holding <lang>.dic, for the built-in
+        /// spellchecker (implies --aspell)
+        #[arg(long, value_name = "PATH")]
+        spell_dict: Option<PathBuf>,
+
         /// Optional markdown output path
         #[arg(long, value_name = "OUT")]
         markdown_output: Option<PathBuf>,
 
-        /// Optional pandoc target format (e.g. html, docx, gfm, latex)
+        /// Render the markdown report to HTML or PDF without external tools
+        #[arg(long, value_enum, value_name = "FMT")]
+        export: Option<AxialExportArg>,
+
+        /// Output path for --export (default: the markdown path with the format's extension)
+        #[arg(long, value_name = "OUT", requires = "export")]
+        export_output: Option<PathBuf>,
+
+        /// Optional pandoc target format for formats --export lacks (e.g. docx, latex)
         #[arg(long, value_name = "FMT")]
         pandoc_to: Option<String>,
 
@@ -412,6 +1011,22 @@ enum Commands {
         output: Option<PathBuf>,
     },
 
+    /// Run a pipeline file: stages of assail/attack/ambush/amuck/axial/adjudicate with
+    /// dependencies and conditions, all writing into one run directory
+    Run {
+        /// Pipeline file (YAML or JSON)
+        #[arg(value_name = "PIPELINE")]
+        pipeline: PathBuf,
+
+        /// Run id naming the output directory (overrides the pipeline's run_id)
+        #[arg(long, value_name = "ID")]
+        run_id: Option<String>,
+
+        /// Directory the run directory is created in (overrides the pipeline's output_dir)
+        #[arg(long, value_name = "DIR")]
+        output_dir: Option<PathBuf>,
+    },
+
     /// Analyze crash reports for bug signatures
     Analyze {
         /// Crash report file (JSON)
@@ -440,6 +1055,72 @@ enum Commands {
         report: PathBuf,
     },
 
+    /// Draw a saved report's charts as SVG or PNG without the GUI
+    Chart {
+        /// Assault report JSON file
+        #[arg(value_name = "REPORT")]
+        report: PathBuf,
+
+        /// Image file; .svg or .png picks the format (SVG on stdout by default)
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<PathBuf>,
+
+        /// Chart to draw
+        #[arg(long, value_enum, default_value = "dashboard")]
+        chart: ChartKindArg,
+    },
+
+    /// Query stored assault runs
+    Reports {
+        #[command(subcommand)]
+        action: ReportsAction,
+    },
+
+    /// Check report stores for damage
+    Storage {
+        #[command(subcommand)]
+        action: StorageAction,
+    },
+
+    /// Inspect and acknowledge crash buckets in the flakiness store (see --flaky-db)
+    Flaky {
+        #[command(subcommand)]
+        action: FlakyAction,
+    },
+
+    /// Label detected signatures as true or false positives (see --signature-feedback)
+    Signatures {
+        #[command(subcommand)]
+        action: SignaturesAction,
+    },
+
+    /// Record who is looking at a finding and what they decided, beside the report
+    Annotate {
+        /// Assault report the finding appears in
+        #[arg(value_name = "REPORT")]
+        report: PathBuf,
+
+        /// Finding ID: RULE@LOCATION for weak points, the crash bucket for crashes
+        #[arg(long, value_name = "ID", required_unless_present = "list")]
+        finding: Option<String>,
+
+        /// New triage state
+        #[arg(long, value_enum, required_unless_present = "list")]
+        state: Option<triage::TriageState>,
+
+        /// Note shown with the state
+        #[arg(long, value_name = "TEXT")]
+        comment: Option<String>,
+
+        /// Who is making the change (default: $USER)
+        #[arg(long, value_name = "NAME")]
+        author: Option<String>,
+
+        /// List the report's finding IDs with their current state instead
+        #[arg(long, default_value_t = false, conflicts_with_all = ["finding", "state"])]
+        list: bool,
+    },
+
     /// Compare two assault reports (defaults to latest VerisimDB runs)
     Diff {
         /// Base report path
@@ -453,10 +1134,63 @@ enum Commands {
         /// VerisimDB directory to scan for latest reports
         #[arg(long, value_name = "DIR", default_value = "verisimdb-data/verisimdb")]
         verisimdb_dir: PathBuf,
+
+        /// Relative duration change (percent) treated as noise
+        #[arg(long, value_name = "PCT", default_value_t = 10.0)]
+        duration_threshold: f64,
+
+        /// Relative peak-memory change (percent) treated as noise
+        #[arg(long, value_name = "PCT", default_value_t = 10.0)]
+        memory_threshold: f64,
+
+        /// Earlier runs of the base configuration; with 3+ samples per axis the noise band is
+        /// learned from their variance instead of the fixed thresholds (repeatable)
+        #[arg(long = "history", value_name = "REPORT")]
+        history: Vec<PathBuf>,
+
+        /// Standard deviations a change must exceed when the noise band is learned
+        #[arg(long, value_name = "N", default_value_t = 2.0)]
+        sigma: f64,
+
+        /// Also list duration/memory changes judged to be noise
+        #[arg(long)]
+        show_noise: bool,
+
+        /// Without BASE/COMPARE, compare runs of this program (default: the program of the
+        /// newest run)
+        #[arg(long, value_name = "PATH")]
+        program: Option<String>,
+
+        /// Without BASE/COMPARE, only consider runs at or after this age or date (7d, 2026-01-31)
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Without BASE/COMPARE, only consider runs before this age or date
+        #[arg(long, value_name = "WHEN")]
+        until: Option<String>,
+    },
+
+    /// Compare the static attack surface of two source revisions or directories
+    AssailDiff {
+        /// Base revision (e.g. main) or directory
+        #[arg(value_name = "BASE")]
+        base: String,
+
+        /// Compare revision (e.g. HEAD) or directory
+        #[arg(value_name = "COMPARE")]
+        compare: String,
+
+        /// Git repository the revisions belong to
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        repo: PathBuf,
     },
 
-    /// Export the AI manifest as Nickel
+    /// Export the AI manifest as Nickel, with contracts for the sections panic-attack reads
+    #[command(args_conflicts_with_subcommands = true)]
     Manifest {
+        #[command(subcommand)]
+        action: Option<ManifestAction>,
+
         /// Alternate AI manifest file
         #[arg(short, long, value_name = "PATH")]
         path: Option<PathBuf>,
@@ -507,6 +1241,71 @@ enum Commands {
         output: Option<PathBuf>,
     },
 
+    /// Export a CycloneDX or SPDX SBOM of the dependencies declared in Cargo.toml,
+    /// package.json and go.mod manifests
+    Sbom {
+        /// Source directory to scan, or a saved assail or assault report
+        #[arg(value_name = "TARGET")]
+        target: PathBuf,
+
+        /// SBOM format
+        #[arg(long, value_enum, default_value_t = SbomFormat::Cyclonedx)]
+        format: SbomFormat,
+
+        /// Write the SBOM to a file instead of stdout
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<PathBuf>,
+    },
+
+    /// Assail the files changed since a base ref, optionally micro-assault binaries, write
+    /// SARIF, and exit non-zero when the gate fails (for pre-push hooks and PR checks)
+    Ci {
+        /// Repository to check
+        #[arg(value_name = "REPO", default_value = ".")]
+        repo: PathBuf,
+
+        /// Base ref (default: origin/$GITHUB_BASE_REF, then origin/HEAD, origin/main,
+        /// origin/master, main, master)
+        #[arg(long, value_name = "REF")]
+        base: Option<String>,
+
+        /// Fail on weak points in changed files at or above this severity
+        #[arg(long, value_enum, default_value_t = SeverityArg::High)]
+        fail_on: SeverityArg,
+
+        /// SARIF output file
+        #[arg(long, value_name = "PATH", default_value = "panic-attack.sarif")]
+        sarif: PathBuf,
+
+        /// Binary to micro-assault when source files changed (repeatable); any crash fails
+        /// the gate
+        #[arg(long = "binary", value_name = "PATH")]
+        binaries: Vec<PathBuf>,
+
+        /// Wall-clock budget for the micro-assault, shared by all axes
+        #[arg(long, value_name = "SECS", default_value_t = 30)]
+        assault_secs: u64,
+    },
+
+    /// Serve assail findings to editors as diagnostics over the Language Server Protocol
+    /// (stdio)
+    Lsp,
+
+    /// Print the JSON Schema for an input file kind, or validate a file against it
+    Schema {
+        /// Input kind: profile, timeline, amuck-spec, or report
+        #[arg(value_enum, value_name = "KIND")]
+        kind: SchemaKindArg,
+
+        /// Validate this json/yaml file instead of printing the schema
+        #[arg(long, value_name = "FILE")]
+        validate: Option<PathBuf>,
+
+        /// Write the schema to a file instead of stdout
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<PathBuf>,
+    },
+
     /// Print detailed help text (man-style)
     Help {
         /// Optional subcommand name to display help for
@@ -622,49 +1421,185 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum ReportsAction {
+    /// Find stored runs, e.g. 'crashes > 0 AND axis = memory AND since = 7d'
+    Query {
+        /// Clauses joined by AND (fields: crashes, signatures, score, weak_points, critical,
+        /// seed, language, program, axis, crashed_axis, since)
+        #[arg(value_name = "QUERY")]
+        query: String,
+
+        /// Store directory to search (defaults to --store, then ./reports)
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+
+        /// Print matching runs as JSON instead of a table
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ManifestAction {
+    /// Check the manifest's structure: sections, keys, values and duplicates, with
+    /// line/column positions; fails when there are errors
+    Lint {
+        /// AI manifest file
+        #[arg(value_name = "PATH", default_value = "AI.a2ml")]
+        path: PathBuf,
+
+        /// Print the diagnostics as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Convert an edited Nickel export back into an A2ML manifest
+    FromNickel {
+        /// Nickel file, as written by `panic-attack manifest`
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Save the A2ML manifest to file (stdout by default)
+        #[arg(short, long, value_name = "OUT")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AmuckAction {
+    /// Delete mutated variants (`<stem>.amuck.NNN.<ext>`) from an amuck output directory
+    Clean {
+        /// Directory the variants were written to
+        #[arg(long, value_name = "DIR", default_value = "runtime/amuck")]
+        dir: PathBuf,
+
+        /// Only delete variants older than this age (30m, 12h, 7d, 2w) or date
+        #[arg(long, value_name = "AGE")]
+        older_than: Option<String>,
+
+        /// Always keep the N most recently written variants
+        #[arg(long, value_name = "N")]
+        keep_last: Option<usize>,
+
+        /// `failed-only` deletes just the variants whose exec check passed in --report
+        #[arg(long, value_enum, default_value = "all", requires = "report")]
+        keep: KeepMode,
+
+        /// Amuck report of a run whose variants are in DIR (repeatable)
+        #[arg(long, value_name = "REPORT", action = clap::ArgAction::Append)]
+        report: Vec<PathBuf>,
+
+        /// List the variants that would be deleted without deleting them
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Print the result as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum StorageAction {
+    /// Find reports that no longer parse (and leftover temp files) and move them to
+    /// <store>/quarantine/
+    Fsck {
+        /// Store directory to check (defaults to --store, then ./reports)
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+
+        /// Only list damaged files; exits non-zero when any are found
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Print findings as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum FlakyAction {
+    /// List every recorded crash bucket with its history
+    List {
+        /// Print the store as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Mark a crash bucket as a known, accepted failure
+    Ack {
+        /// Crash bucket, e.g. memory:exit-101
+        #[arg(value_name = "BUCKET")]
+        bucket: String,
+
+        /// Why the failure is accepted
+        #[arg(long, value_name = "TEXT")]
+        note: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SignaturesAction {
+    /// Show label counts per rule and how they move its confidence
+    List {
+        /// Print the feedback file as JSON
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Record whether a rule's signature was a real bug
+    Mark {
+        /// Rule named in the report, e.g. stderr_deadlock
+        #[arg(value_name = "RULE")]
+        rule: String,
+
+        #[arg(value_enum, value_name = "VERDICT")]
+        verdict: VerdictArg,
+
+        /// Context for the label, e.g. the report or issue it came from
+        #[arg(long, value_name = "TEXT")]
+        note: Option<String>,
+    },
+}
+
 // CLI argument types
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
-enum AttackAxisArg {
-    Cpu,
-    Memory,
-    Disk,
-    Network,
-    Concurrency,
-    Time,
+
+/// Built-in axis names, or a custom axis name to be resolved against the profile.
+fn parse_axis_value(value: &str) -> Result<AttackAxis, String> {
+    attack::parse_axis(value).map_err(|err| err.to_string())
 }
 
-impl From<AttackAxisArg> for AttackAxis {
-    fn from(arg: AttackAxisArg) -> Self {
-        match arg {
-            AttackAxisArg::Cpu => AttackAxis::Cpu,
-            AttackAxisArg::Memory => AttackAxis::Memory,
-            AttackAxisArg::Disk => AttackAxis::Disk,
-            AttackAxisArg::Network => AttackAxis::Network,
-            AttackAxisArg::Concurrency => AttackAxis::Concurrency,
-            AttackAxisArg::Time => AttackAxis::Time,
-        }
-    }
+fn parse_lang_value(value: &str) -> Result<Lang, String> {
+    Lang::from_code(value).ok_or_else(|| format!("unsupported language `{}`", value))
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
-enum IntensityArg {
-    Light,
-    Medium,
-    Heavy,
-    Extreme,
+/// Scale values are range-checked here; names wait for the profile's presets.
+fn parse_intensity_value(value: &str) -> Result<IntensitySpec, String> {
+    let spec = IntensitySpec::parse(value);
+    if let IntensitySpec::Scale(scale) = spec {
+        IntensityLevel::from_scale(scale)?;
+    }
+    Ok(spec)
 }
 
-impl From<IntensityArg> for IntensityLevel {
-    fn from(arg: IntensityArg) -> Self {
-        match arg {
-            IntensityArg::Light => IntensityLevel::Light,
-            IntensityArg::Medium => IntensityLevel::Medium,
-            IntensityArg::Heavy => IntensityLevel::Heavy,
-            IntensityArg::Extreme => IntensityLevel::Extreme,
-        }
+/// Fault rates are probabilities; anything outside 0-1 would reach the injectors unchanged.
+fn check_fault_rate(rate: f64) -> Result<f64, String> {
+    if (0.0..=1.0).contains(&rate) {
+        Ok(rate)
+    } else {
+        Err(format!("expected a rate between 0 and 1, got {}", rate))
     }
 }
 
+fn parse_fault_rate(value: &str) -> Result<f64, String> {
+    let rate = value
+        .parse()
+        .map_err(|_| format!("expected a rate between 0 and 1, got `{}`", value))?;
+    check_fault_rate(rate)
+}
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 enum ProbeModeArg {
     Auto,
@@ -683,9 +1618,92 @@ impl From<ProbeModeArg> for ProbeMode {
 }
 
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
-enum AmuckPresetArg {
-    Light,
-    Dangerous,
+enum SeverityArg {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl From<SeverityArg> for Severity {
+    fn from(arg: SeverityArg) -> Self {
+        match arg {
+            SeverityArg::Low => Severity::Low,
+            SeverityArg::Medium => Severity::Medium,
+            SeverityArg::High => Severity::High,
+            SeverityArg::Critical => Severity::Critical,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum WrapperArg {
+    Rr,
+    Schedule,
+}
+
+impl From<WrapperArg> for TargetWrapper {
+    fn from(arg: WrapperArg) -> Self {
+        match arg {
+            WrapperArg::Rr => TargetWrapper::Rr,
+            WrapperArg::Schedule => TargetWrapper::Schedule,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum BrowserArg {
+    Chrome,
+    Firefox,
+}
+
+impl From<BrowserArg> for BrowserKind {
+    fn from(arg: BrowserArg) -> Self {
+        match arg {
+            BrowserArg::Chrome => BrowserKind::Chrome,
+            BrowserArg::Firefox => BrowserKind::Firefox,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum MemoryModeArg {
+    Allocate,
+    Cgroup,
+}
+
+impl From<MemoryModeArg> for MemoryPressureMode {
+    fn from(arg: MemoryModeArg) -> Self {
+        match arg {
+            MemoryModeArg::Allocate => MemoryPressureMode::Allocate,
+            MemoryModeArg::Cgroup => MemoryPressureMode::Cgroup,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum AdaptiveMetricArg {
+    HostCpu,
+    HostMemory,
+    TargetCpu,
+    TargetRss,
+}
+
+impl From<AdaptiveMetricArg> for AdaptiveMetric {
+    fn from(arg: AdaptiveMetricArg) -> Self {
+        match arg {
+            AdaptiveMetricArg::HostCpu => AdaptiveMetric::HostCpu,
+            AdaptiveMetricArg::HostMemory => AdaptiveMetric::HostMemory,
+            AdaptiveMetricArg::TargetCpu => AdaptiveMetric::TargetCpu,
+            AdaptiveMetricArg::TargetRss => AdaptiveMetric::TargetRss,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum AmuckPresetArg {
+    Light,
+    Dangerous,
 }
 
 impl From<AmuckPresetArg> for AmuckPreset {
@@ -697,6 +1715,57 @@ impl From<AmuckPresetArg> for AmuckPreset {
     }
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum VerdictArg {
+    TruePositive,
+    FalsePositive,
+}
+
+impl From<VerdictArg> for Verdict {
+    fn from(arg: VerdictArg) -> Self {
+        match arg {
+            VerdictArg::TruePositive => Verdict::TruePositive,
+            VerdictArg::FalsePositive => Verdict::FalsePositive,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum AnalyzerArg {
+    Clippy,
+    Semgrep,
+    Bandit,
+}
+
+impl From<AnalyzerArg> for assail::ExternalAnalyzer {
+    fn from(arg: AnalyzerArg) -> Self {
+        match arg {
+            AnalyzerArg::Clippy => assail::ExternalAnalyzer::Clippy,
+            AnalyzerArg::Semgrep => assail::ExternalAnalyzer::Semgrep,
+            AnalyzerArg::Bandit => assail::ExternalAnalyzer::Bandit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum SchemaKindArg {
+    Profile,
+    Timeline,
+    AmuckSpec,
+    Report,
+}
+
+impl From<SchemaKindArg> for SchemaKind {
+    fn from(arg: SchemaKindArg) -> Self {
+        match arg {
+            SchemaKindArg::Profile => SchemaKind::Profile,
+            SchemaKindArg::Timeline => SchemaKind::Timeline,
+            SchemaKindArg::AmuckSpec => SchemaKind::AmuckSpec,
+            SchemaKindArg::Report => SchemaKind::Report,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 enum AbductScopeArg {
     None,
@@ -763,9 +1832,45 @@ enum A2mlReportKindArg {
     Amuck,
     Abduct,
     Adjudicate,
+    #[value(alias = "audience")]
     Axial,
 }
 
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum AxialExportArg {
+    Html,
+    Pdf,
+}
+
+impl From<AxialExportArg> for ExportFormat {
+    fn from(arg: AxialExportArg) -> Self {
+        match arg {
+            AxialExportArg::Html => ExportFormat::Html,
+            AxialExportArg::Pdf => ExportFormat::Pdf,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum ChartKindArg {
+    /// Outcomes, robustness gauge and crash timeline together
+    Dashboard,
+    Outcomes,
+    Timeline,
+    Robustness,
+}
+
+impl From<ChartKindArg> for ChartKind {
+    fn from(arg: ChartKindArg) -> Self {
+        match arg {
+            ChartKindArg::Dashboard => ChartKind::Dashboard,
+            ChartKindArg::Outcomes => ChartKind::Outcomes,
+            ChartKindArg::Timeline => ChartKind::Timeline,
+            ChartKindArg::Robustness => ChartKind::Robustness,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 enum MigrationDiffFormatArg {
     Markdown,
@@ -787,18 +1892,69 @@ impl From<A2mlReportKindArg> for ReportBundleKind {
     }
 }
 
+/// Target args and stressor settings merged from profile + CLI flags.
+struct AttackOverrides {
+    intensity: IntensityLevel,
+    axis_intensity: HashMap<AttackAxis, IntensityLevel>,
+    axis_duration: HashMap<AttackAxis, Duration>,
+    common_args: Vec<String>,
+    axis_args: HashMap<AttackAxis, Vec<String>>,
+    custom_axes: Vec<CustomAxisSpec>,
+    probe_mode: ProbeMode,
+    signal_storm: SignalStormConfig,
+    memory_pressure: MemoryPressureConfig,
+    malloc_faults: Option<MallocFaultConfig>,
+    io_faults: Option<IoFaultConfig>,
+    net_faults: Option<IoFaultConfig>,
+    disk_throttle: DiskThrottleConfig,
+    cpu_chaos: CpuChaosConfig,
+    adaptive: Option<AdaptiveConfig>,
+    redaction: RedactionConfig,
+}
+
+/// Stressor settings given on the command line; these win over profile values.
+#[derive(Default)]
+struct StressorFlags {
+    signal_storm: SignalStormConfig,
+    memory_pressure: Option<MemoryPressureConfig>,
+    malloc_faults: Option<MallocFaultConfig>,
+    io_faults: Option<IoFaultConfig>,
+    net_faults: Option<IoFaultConfig>,
+    disk_throttle: Option<DiskThrottleConfig>,
+    cpu_chaos: Option<CpuChaosConfig>,
+    adaptive: Option<AdaptiveConfig>,
+}
+
 fn build_attack_overrides(
     profile_path: Option<PathBuf>,
+    intensity: &IntensitySpec,
     args: Vec<String>,
     axis_args: Vec<String>,
     probe: Option<ProbeModeArg>,
-) -> Result<(Vec<String>, HashMap<AttackAxis, Vec<String>>, ProbeMode)> {
+    flags: StressorFlags,
+) -> Result<AttackOverrides> {
     let profile = if let Some(path) = profile_path {
         Some(AttackProfile::load(&path)?)
     } else {
         None
     };
 
+    let presets = profile
+        .as_ref()
+        .map(|p| p.intensity_presets.clone())
+        .unwrap_or_default();
+    let resolve = |spec: &IntensitySpec| spec.resolve(&presets).map_err(|err| anyhow!(err));
+    let intensity = resolve(intensity)?;
+    let mut axis_intensity = HashMap::new();
+    for (axis, spec) in profile.iter().flat_map(|p| &p.axis_intensity) {
+        let level = resolve(spec).with_context(|| format!("axis_intensity for {}", axis))?;
+        axis_intensity.insert(axis.clone(), level);
+    }
+    let mut axis_duration = HashMap::new();
+    for (axis, secs) in profile.iter().flat_map(|p| &p.axis_duration_secs) {
+        axis_duration.insert(axis.clone(), Duration::from_secs(*secs));
+    }
+
     let mut common_args = profile
         .as_ref()
         .map(|p| p.common_args.clone())
@@ -813,33 +1969,485 @@ fn build_attack_overrides(
 
     let probe_mode = probe
         .map(ProbeMode::from)
-        .or_else(|| profile.and_then(|p| p.probe_mode))
+        .or_else(|| profile.as_ref().and_then(|p| p.probe_mode))
+        .unwrap_or_default();
+
+    // CLI flags override profile values field by field.
+    let profile_storm = profile
+        .as_ref()
+        .and_then(|p| p.signal_storm.clone())
+        .unwrap_or_default();
+    let signal_flags = flags.signal_storm;
+    let signal_storm = SignalStormConfig {
+        frequency_hz: signal_flags.frequency_hz.or(profile_storm.frequency_hz),
+        signals: if signal_flags.signals.is_empty() {
+            profile_storm.signals
+        } else {
+            signal_flags.signals
+        },
+        stop_pulses: signal_flags.stop_pulses || profile_storm.stop_pulses,
+    };
+    validate_signal_storm(&signal_storm)?;
+
+    let memory_pressure = flags
+        .memory_pressure
+        .or_else(|| profile.as_ref().and_then(|p| p.memory_pressure.clone()))
+        .unwrap_or_default();
+
+    let malloc_faults = flags
+        .malloc_faults
+        .or_else(|| profile.as_ref().and_then(|p| p.malloc_faults.clone()));
+    if let Some(rate) = malloc_faults.as_ref().and_then(|faults| faults.rate) {
+        check_fault_rate(rate).map_err(|err| anyhow!("malloc fail rate: {}", err))?;
+    }
+
+    let io_faults = flags
+        .io_faults
+        .or_else(|| profile.as_ref().and_then(|p| p.io_faults.clone()));
+    if let Some(rate) = io_faults.as_ref().and_then(|faults| faults.rate) {
+        check_fault_rate(rate).map_err(|err| anyhow!("io fault rate: {}", err))?;
+    }
+
+    let net_faults = flags
+        .net_faults
+        .or_else(|| profile.as_ref().and_then(|p| p.net_faults.clone()));
+    if let Some(rate) = net_faults.as_ref().and_then(|faults| faults.rate) {
+        check_fault_rate(rate).map_err(|err| anyhow!("net fault rate: {}", err))?;
+    }
+
+    let disk_throttle = flags
+        .disk_throttle
+        .or_else(|| profile.as_ref().and_then(|p| p.disk_throttle.clone()))
+        .unwrap_or_default();
+
+    let cpu_chaos = flags
+        .cpu_chaos
+        .or_else(|| profile.as_ref().and_then(|p| p.cpu_chaos.clone()))
         .unwrap_or_default();
+    if let Some(nice) = cpu_chaos.max_nice {
+        if !(0..=19).contains(&nice) {
+            return Err(anyhow!("cpu chaos nice must be between 0 and 19, got {}", nice));
+        }
+    }
+
+    let adaptive = flags
+        .adaptive
+        .or_else(|| profile.as_ref().and_then(|p| p.adaptive.clone()));
+    if let Some(adaptive) = &adaptive {
+        if !(adaptive.setpoint.is_finite() && adaptive.setpoint > 0.0) {
+            return Err(anyhow!(
+                "adaptive setpoint must be positive, got {}",
+                adaptive.setpoint
+            ));
+        }
+    }
+
+    Ok(AttackOverrides {
+        intensity,
+        axis_intensity,
+        axis_duration,
+        common_args,
+        axis_args: merged_axis_args,
+        custom_axes: profile
+            .as_ref()
+            .map(|p| p.custom_axes.clone())
+            .unwrap_or_default(),
+        probe_mode,
+        signal_storm,
+        memory_pressure,
+        malloc_faults,
+        io_faults,
+        net_faults,
+        disk_throttle,
+        cpu_chaos,
+        adaptive,
+        redaction: profile.and_then(|p| p.redaction).unwrap_or_default(),
+    })
+}
+
+/// Merge the profile's redaction settings with `--no-redact` / `--redact-pattern`.
+fn build_redactor(
+    mut config: RedactionConfig,
+    no_redact: bool,
+    patterns: &[String],
+) -> Result<Option<redact::Redactor>> {
+    config.disabled |= no_redact;
+    for spec in patterns {
+        config.patterns.push(redact::parse_pattern_flag(spec)?);
+    }
+    redact::Redactor::new(&config)
+}
+
+/// Open the calibration store when `--calibrate` or `--calibration-file` is given.
+/// Print the pre-flight estimate for a run writing under `output_dir`, warn where it
+/// crowds the host, and stop unless `--force` when it would not fit at all.
+fn preflight_check(
+    estimate: &preflight::Estimate,
+    output_dir: &Path,
+    campaign_timeout: Option<u64>,
+    quiet: bool,
+    force: bool,
+) -> Result<()> {
+    let timeout = campaign_timeout.map(Duration::from_secs);
+    let assessment = preflight::assess(estimate, &preflight::Limits::probe(output_dir, timeout));
+    if !quiet {
+        println!(
+            "Pre-flight: ~{} disk, ~{} stressor memory, ~{}s",
+            preflight::format_bytes(estimate.disk_bytes),
+            preflight::format_bytes(estimate.memory_bytes),
+            estimate.duration.as_secs()
+        );
+        for line in &estimate.breakdown {
+            println!("  - {}", line);
+        }
+    }
+    for warning in &assessment.warnings {
+        tracing::warn!("pre-flight: {}", warning);
+    }
+    if assessment.blockers.is_empty() {
+        return Ok(());
+    }
+    if force {
+        for blocker in &assessment.blockers {
+            tracing::warn!("pre-flight (forced): {}", blocker);
+        }
+        return Ok(());
+    }
+    Err(anyhow!(
+        "pre-flight estimate does not fit this host: {}; rerun with --force to proceed anyway",
+        assessment.blockers.join("; ")
+    ))
+}
+
+/// Lock the report store (default `reports/`) for a destructive `command` on `target`.
+fn campaign_lock(
+    store: Option<&Path>,
+    wait: bool,
+    steal: bool,
+    command: &str,
+    target: &Path,
+) -> Result<lock::CampaignLock> {
+    let contention = if steal {
+        lock::Contention::Steal
+    } else if wait {
+        lock::Contention::Wait
+    } else {
+        lock::Contention::Fail
+    };
+    let store = store.unwrap_or(Path::new("reports"));
+    lock::CampaignLock::acquire(store, command, target, contention)
+}
+
+fn open_calibration(
+    enabled: bool,
+    file: Option<&Path>,
+    target: &Path,
+) -> Result<Option<(PathBuf, CalibrationStore)>> {
+    if !enabled && file.is_none() {
+        return Ok(None);
+    }
+    let path = file
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| CalibrationStore::default_path(target));
+    let store = CalibrationStore::load(&path)?;
+    Ok(Some((path, store)))
+}
+
+/// Apply calibrated severities to a fresh assail report and say how many changed.
+fn apply_calibration(
+    calibration: &Option<(PathBuf, CalibrationStore)>,
+    report: &mut AssailReport,
+    quiet: bool,
+) {
+    if let Some((path, store)) = calibration {
+        let calibrated = store.apply(report);
+        qprintln!(
+            quiet,
+            "Calibration: {} weak point(s) downgraded after clean runs ({})",
+            calibrated,
+            path.display()
+        );
+    }
+}
+
+/// `assail --watch`: print the initial summary, then the finding delta after every change.
+fn watch_assail(
+    analyzer: assail::Analyzer,
+    calibration: &Option<(PathBuf, CalibrationStore)>,
+    quiet: bool,
+) -> Result<()> {
+    let (mut session, mut previous) = assail::watch::WatchSession::start(analyzer)?;
+    apply_calibration(calibration, &mut previous, quiet);
+    println!(
+        "Watching {} ({} weak points); Ctrl-C to stop",
+        previous.program_path.display(),
+        previous.weak_points.len()
+    );
+    loop {
+        std::thread::sleep(assail::watch::DEFAULT_INTERVAL);
+        let Some(rescan) = session.poll()? else {
+            continue;
+        };
+        let mut report = rescan.report;
+        apply_calibration(calibration, &mut report, true);
+        let changes = report::diff::diff_findings(&previous.weak_points, &report.weak_points);
+        println!(
+            "\n[{}] {} changed; {} new, {} resolved, {} changed ({} weak points, {} file(s) rescanned in {} ms)",
+            chrono::Local::now().format("%H:%M:%S"),
+            rescan.changed.join(", "),
+            changes.added.len(),
+            changes.removed.len(),
+            changes.changed.len(),
+            report.weak_points.len(),
+            rescan.reanalyzed,
+            rescan.elapsed.as_millis()
+        );
+        for wp in &changes.added {
+            println!("  + {}", report::assail_diff::describe(wp));
+        }
+        for wp in &changes.removed {
+            println!("  - {}", report::assail_diff::describe(wp));
+        }
+        for change in &changes.changed {
+            println!("  ~ {}", report::assail_diff::describe(&change.after));
+        }
+        previous = report;
+    }
+}
+
+/// Report generator speaking `--report-lang`, with `--recommendation-templates` applied.
+fn report_generator(lang: Lang, templates: Option<&Path>) -> Result<ReportGenerator> {
+    let generator = ReportGenerator::new().with_lang(lang);
+    Ok(match templates {
+        Some(path) => generator.with_templates(TemplateSet::load(path)?),
+        None => generator,
+    })
+}
+
+/// Generate the assault report, recording outcomes into the calibration store when enabled.
+fn generate_with_calibration(
+    generator: &ReportGenerator,
+    calibration: &mut Option<(PathBuf, CalibrationStore)>,
+    assail_report: AssailReport,
+    attack_results: Vec<AttackResult>,
+) -> Result<AssaultReport> {
+    match calibration {
+        Some((path, store)) => {
+            let assault_report =
+                generator.generate_calibrated(assail_report, attack_results, store)?;
+            store.save(path)?;
+            Ok(assault_report)
+        }
+        None => generator.generate(assail_report, attack_results),
+    }
+}
+
+/// Engine calibrated by the signature feedback file: `--signature-feedback`, or the default
+/// file when it exists.
+fn signature_engine(file: Option<&Path>) -> Result<SignatureEngine> {
+    let path = file
+        .map(Path::to_path_buf)
+        .unwrap_or_else(SignatureFeedback::default_path);
+    if file.is_none() && !path.exists() {
+        return Ok(SignatureEngine::new());
+    }
+    Ok(SignatureEngine::with_feedback(
+        &SignatureFeedback::load(&path)?,
+        &path,
+    ))
+}
+
+fn apply_signature_feedback(file: Option<&Path>, results: &mut [AttackResult]) -> Result<()> {
+    let engine = signature_engine(file)?;
+    for result in results {
+        engine.calibrate(&mut result.signatures_detected);
+    }
+    Ok(())
+}
+
+/// Resolve crash frames of stripped binaries from separate debug info, caching what was learned.
+fn symbolize_crashes(
+    debug_dirs: &[PathBuf],
+    offline: bool,
+    quiet: bool,
+    results: &mut [AttackResult],
+) -> Result<()> {
+    let mut symbolizer = Symbolizer::new(
+        debug_dirs.to_vec(),
+        offline,
+        symbolize::default_cache_path(),
+    );
+    let resolved = symbolizer.symbolize_results(results);
+    if resolved > 0 {
+        qprintln!(quiet, "Symbolized {} backtrace frames", resolved);
+    }
+    symbolizer.save()
+}
+
+/// Attribute each crash bucket to the owner of its crashing frame's file.
+fn assign_crash_owners(assault_report: &mut AssaultReport, blame: bool) -> Result<()> {
+    let mut ownership =
+        Ownership::discover(&assault_report.assail_report.program_path)?.with_blame(blame);
+    assault_report.crash_owners = ownership.crash_owners(&assault_report.attack_results);
+    Ok(())
+}
+
+/// Narrow a loaded report to `--owner`'s findings, when given.
+/// Console filter from `--min-severity`, `--only-axis` and `--only-category`.
+fn finding_filter(cli: &Cli) -> FindingFilter {
+    FindingFilter {
+        min_severity: cli.min_severity.map(Severity::from),
+        axes: cli.only_axis.clone(),
+        categories: cli.only_category.clone(),
+    }
+}
+
+fn slice_by_owner(report: &mut AssaultReport, owner: Option<&str>) {
+    if let Some(owner) = owner {
+        owners::slice(report, owner);
+    }
+}
+
+/// Classify the report's crash buckets against the flakiness store when `--flaky` or
+/// `--flaky-db` is given, then record them.
+fn record_flakiness(
+    enabled: bool,
+    file: Option<&Path>,
+    assault_report: &mut AssaultReport,
+) -> Result<()> {
+    if !enabled && file.is_none() {
+        return Ok(());
+    }
+    let path = file
+        .map(Path::to_path_buf)
+        .unwrap_or_else(flaky::FlakyStore::default_path);
+    let mut store = flaky::FlakyStore::load(&path)?;
+    let now = chrono::Utc::now().to_rfc3339();
+    assault_report.crash_history = store.record(assault_report, &now);
+    store.save(&path)
+}
+
+/// Store a non-assault report in the manifest's storage targets when the manifest lists its kind.
+fn persist_kind<R: StoredReport>(
+    report: &R,
+    store: Option<&Path>,
+    modes: &[StorageMode],
+    kinds: &[ReportKind],
+    quiet: bool,
+) -> Result<()> {
+    if modes.is_empty() || !kinds.contains(&R::KIND) {
+        return Ok(());
+    }
+    for path in persist_any_report(report, store, modes)? {
+        porcelain::record("report", path.display());
+        qprintln!(quiet, "Stored report: {}", path.display());
+    }
+    Ok(())
+}
 
-    Ok((common_args, merged_axis_args, probe_mode))
+/// Load a saved assault report, checking it against the report schema first under `--strict`.
+fn read_report(path: &Path, strict: bool) -> Result<AssaultReport> {
+    if strict {
+        report::load_report_strict(path)
+    } else {
+        load_report(path)
+    }
+}
+
+/// Scan a source tree, or load the assail inventory (and assault totals) from a saved report.
+fn read_sbom_source(target: &Path, strict: bool) -> Result<(AssailReport, Option<AssaultReport>)> {
+    let is_manifest = matches!(
+        target.file_name().and_then(|name| name.to_str()),
+        Some("Cargo.toml" | "package.json" | "go.mod")
+    );
+    if target.is_dir() || is_manifest {
+        return Ok((assail::analyze(target)?, None));
+    }
+    if let Ok(assault) = read_report(target, strict) {
+        return Ok((assault.assail_report.clone(), Some(assault)));
+    }
+    let content =
+        fs::read_to_string(target).with_context(|| format!("reading {}", target.display()))?;
+    let assail = match target.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&content).ok(),
+        _ => serde_json::from_str(&content).ok(),
+    };
+    let assail = assail.ok_or_else(|| {
+        anyhow!(
+            "{} is neither a source directory, a manifest, nor an assail or assault report",
+            target.display()
+        )
+    })?;
+    Ok((assail, None))
+}
+
+fn build_disk_throttle(
+    limits: Vec<String>,
+    data_dir: Option<PathBuf>,
+) -> Result<Option<DiskThrottleConfig>> {
+    if limits.is_empty() {
+        return Ok(None);
+    }
+    let mut throttle = DiskThrottleConfig {
+        data_dir,
+        ..Default::default()
+    };
+    for spec in limits {
+        let (key, raw) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow!("io limit must be in the form KEY=VALUE"))?;
+        let value = parse_size(raw)
+            .ok_or_else(|| anyhow!("invalid io limit value '{}' for {}", raw, key))?;
+        match key.trim() {
+            "rbps" => throttle.read_bps = Some(value),
+            "wbps" => throttle.write_bps = Some(value),
+            "riops" => throttle.read_iops = Some(value),
+            "wiops" => throttle.write_iops = Some(value),
+            other => {
+                return Err(anyhow!(
+                    "unknown io limit '{}' (rbps, wbps, riops, wiops)",
+                    other
+                ))
+            }
+        }
+    }
+    Ok(Some(throttle))
+}
+
+/// Parse a byte count with an optional binary K/M/G suffix.
+fn parse_size(raw: &str) -> Option<u64> {
+    let trimmed = raw.trim().to_ascii_uppercase();
+    let (digits, scale) = match trimmed.chars().last()? {
+        'K' => (&trimmed[..trimmed.len() - 1], 1024),
+        'M' => (&trimmed[..trimmed.len() - 1], 1024 * 1024),
+        'G' => (&trimmed[..trimmed.len() - 1], 1024 * 1024 * 1024),
+        _ => (trimmed.as_str(), 1),
+    };
+    digits.parse::<u64>().ok()?.checked_mul(scale)
+}
+
+fn validate_signal_storm(config: &SignalStormConfig) -> Result<()> {
+    if let Some(hz) = config.frequency_hz {
+        if !hz.is_finite() || hz <= 0.0 {
+            return Err(anyhow!("signal frequency must be a positive number of Hz"));
+        }
+    }
+    for raw in &config.signals {
+        if ambush::canonical_signal(raw).is_none() {
+            return Err(anyhow!("unsupported signal '{}' for signal storm", raw));
+        }
+    }
+    Ok(())
 }
 
 fn parse_axis_arg(spec: &str) -> Result<(AttackAxis, String)> {
     let (axis_raw, arg) = spec
         .split_once('=')
         .ok_or_else(|| anyhow!("axis arg must be in the form AXIS=ARG"))?;
-    let axis =
-        parse_axis(axis_raw).ok_or_else(|| anyhow!("unknown axis '{}' in axis arg", axis_raw))?;
+    let axis = attack::parse_axis(axis_raw)?;
     Ok((axis, arg.to_string()))
 }
 
-fn parse_axis(value: &str) -> Option<AttackAxis> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "cpu" => Some(AttackAxis::Cpu),
-        "memory" => Some(AttackAxis::Memory),
-        "disk" => Some(AttackAxis::Disk),
-        "network" => Some(AttackAxis::Network),
-        "concurrency" => Some(AttackAxis::Concurrency),
-        "time" => Some(AttackAxis::Time),
-        _ => None,
-    }
-}
-
 fn default_amuck_report_path() -> PathBuf {
     let ts = chrono::Utc::now().format("%Y%m%d%H%M%S");
     PathBuf::from(format!("reports/amuck-{}.json", ts))
@@ -865,7 +2473,7 @@ fn default_axial_markdown_path() -> PathBuf {
     PathBuf::from(format!("reports/axial-{}.md", ts))
 }
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     // Write startup heartbeat for kin coordination
     let _ = kin::write_startup_heartbeat();
 
@@ -886,32 +2494,115 @@ fn main() -> Result<()> {
             );
         }
         Err(e) => {
-            let _ = kin::write_error_heartbeat(format!("{}", e));
+            let _ = kin::write_error_heartbeat(format!("{}", e), error::code(e));
         }
     }
 
-    result
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            // Same rendering as returning the error from main, plus its stable code.
+            eprintln!("Error [{}]: {:?}", error::code(&e), e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
 fn run_main() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    logging::init(cli.verbose, cli.quiet, cli.log_format)?;
+    progress::set_enabled(!cli.quiet && !cli.porcelain && io::stderr().is_terminal());
+    if cli.accessible {
+        colored::control::set_override(false);
+    }
+    let command = matches.subcommand_name().unwrap_or_default();
+    let run_id = logging::run_id();
+    let started = chrono::Utc::now();
+    let _run = tracing::info_span!("run", run_id = %run_id, command).entered();
+    let summary_out = cli.summary_out.clone();
+    if !cli.porcelain && summary_out.is_none() {
+        return execute(cli);
+    }
+
+    // The language server speaks JSON-RPC on stdout, so it neither moves it nor ends it
+    // with a summary.
+    let porcelain = cli.porcelain && command != "lsp";
+    let redirect = porcelain
+        .then(porcelain::StdoutToStderr::begin)
+        .transpose()?;
+    let mut result = execute(cli);
+    drop(redirect);
+    let failure = |result: &Result<()>| {
+        result
+            .as_ref()
+            .err()
+            .map(|err| (error::code(err), format!("{:#}", err)))
+    };
+    if let Some(path) = summary_out {
+        let summary = summary::ExitSummary::from_fields(
+            command,
+            &run_id,
+            started,
+            chrono::Utc::now(),
+            porcelain::fields(),
+            failure(&result),
+        );
+        match summary.write(&path) {
+            Err(err) if result.is_ok() => result = Err(err),
+            Err(err) => tracing::warn!("{:#}", err),
+            Ok(()) => porcelain::record("exit_summary", path.display()),
+        }
+    }
+    if porcelain {
+        print!("{}", porcelain::render(command, failure(&result)));
+    }
+    result
+}
+
+fn execute(cli: Cli) -> Result<()> {
     let manifest = match Manifest::load_default() {
         Ok(manifest) => manifest,
         Err(err) => {
-            eprintln!("warning: failed to read AI.a2ml: {}", err);
+            tracing::warn!("failed to read AI.a2ml: {:#}", err);
             Manifest::default()
         }
     };
     let storage_modes = manifest.storage_modes();
+    let persisted_kinds = manifest.persisted_kinds();
     let manifest_formats = manifest.report_formats();
+    let seed = cli.seed.unwrap_or_else(seed::fresh_seed);
+    let repro_options = ReproOptions {
+        rust_tests: cli.repro_tests,
+    };
+    let progress = match cli.progress_check.as_deref() {
+        Some(spec) => Some(ProgressProbe {
+            check: attack::progress::parse_check(spec)?,
+            window: Duration::from_secs(cli.stall_window),
+        }),
+        None => None,
+    };
+    if cli.gpu_stress && !attack::gpu::available() {
+        return Err(anyhow!(
+            "--gpu-stress needs a build with the `gpu` feature (cargo build --features gpu)"
+        ));
+    }
+    let gpu = cli.gpu_stress.then(|| GpuStressConfig {
+        device: cli.gpu_device.clone(),
+        memory_bytes: cli.gpu_memory.map(|mib| mib * 1024 * 1024),
+    });
+    let filter = finding_filter(&cli);
 
     match cli.command {
         Commands::Assail {
             target,
             output,
-            verbose,
             attest,
             signing_key,
+            analyzers,
+            no_analyzers,
+            advisories,
+            watch,
         } => {
             qprintln!(
                 cli.quiet,
@@ -930,29 +2621,73 @@ fn run_main() -> Result<()> {
                 None
             };
 
-            let report = if let Some(ref mut builder) = chain_builder {
+            let external: Vec<assail::ExternalAnalyzer> = if no_analyzers {
+                Vec::new()
+            } else if !analyzers.is_empty() {
+                analyzers.into_iter().map(Into::into).collect()
+            } else {
+                manifest.external_analyzers()
+            };
+            let mut analyzer = assail::analyzer::Analyzer::new(&target)?
+                .with_external_analyzers(external)
+                .with_blame_owners(cli.blame_owners);
+            if let Some(path) = advisories.or_else(|| manifest.advisory_database()) {
+                let database = assail::advisories::AdvisoryDatabase::load(&path)?;
+                if database.is_empty() {
+                    tracing::warn!(
+                        "{} holds no denial-of-service or memory-safety advisories",
+                        path.display()
+                    );
+                }
+                qprintln!(
+                    cli.quiet,
+                    "Loaded {} denial-of-service/memory-safety advisories from {}",
+                    database.len(),
+                    path.display()
+                );
+                analyzer = analyzer.with_advisories(database);
+            }
+
+            let calibration =
+                open_calibration(cli.calibrate, cli.calibration_file.as_deref(), &target)?;
+            if watch {
+                return watch_assail(analyzer, &calibration, cli.quiet);
+            }
+            let mut report = if let Some(ref mut builder) = chain_builder {
                 // Attested mode: use the analyzer with an evidence accumulator
-                let analyzer = if verbose {
-                    assail::analyzer::Analyzer::new_verbose(&target)?
-                } else {
-                    assail::analyzer::Analyzer::new(&target)?
-                };
                 analyzer.analyze_with_accumulator(Some(builder.accumulator()))?
-            } else if verbose {
-                assail::analyze_verbose(&target)?
+            } else if cli.verbose > 0 {
+                assail::analyze_verbose_with(&analyzer)?
             } else {
-                assail::analyze(&target)?
+                analyzer.analyze()?
             };
+            apply_calibration(&calibration, &mut report, cli.quiet);
+            porcelain::record_assail(&report);
 
             let report_json = serde_json::to_string_pretty(&report)?;
 
             if let Some(output_path) = &output {
-                fs::write(output_path, &report_json)?;
+                write_atomic(output_path, &report_json)?;
+                porcelain::record("report", output_path.display());
                 qprintln!(cli.quiet, "Report saved to: {}", output_path.display());
             } else if !cli.quiet {
                 println!("\nAssail Summary:");
                 println!("  Language: {:?}", report.language);
                 println!("  Weak points: {}", report.weak_points.len());
+                if !report.annotations.is_empty() {
+                    let suppressed: usize = report.annotations.iter().map(|a| a.suppressed).sum();
+                    println!(
+                        "  Annotations: {} ({} finding(s) suppressed)",
+                        report.annotations.len(),
+                        suppressed
+                    );
+                }
+                for policy in &report.policies {
+                    println!(
+                        "  Policy {}: {} excluded, {} adjusted",
+                        policy.path, policy.excluded, policy.adjusted
+                    );
+                }
                 println!("  Recommended attacks: {:?}", report.recommended_attacks);
             }
 
@@ -972,7 +2707,8 @@ fn run_main() -> Result<()> {
                     PathBuf::from("assail-report.attestation.json")
                 };
 
-                fs::write(&sidecar_path, attestation_json)?;
+                write_atomic(&sidecar_path, attestation_json)?;
+                porcelain::record("attestation", sidecar_path.display());
                 qprintln!(
                     cli.quiet,
                     "Attestation written to: {}",
@@ -987,40 +2723,121 @@ fn run_main() -> Result<()> {
             args,
             axis_args,
             probe,
+            wrapper,
+            replay_schedule,
+            malloc_faults,
+            malloc_fail_rate,
+            io_faults,
+            io_fault_rate,
+            net_faults,
+            net_fault_rate,
+            signal_hz,
+            signals,
+            signal_pulse,
             axis,
             intensity,
             duration,
         } => {
+            let _lock = campaign_lock(
+                cli.store.as_deref(),
+                cli.wait,
+                cli.steal_lock,
+                "attack",
+                &program,
+            )?;
+            let flags = StressorFlags {
+                signal_storm: SignalStormConfig {
+                    frequency_hz: signal_hz,
+                    signals,
+                    stop_pulses: signal_pulse,
+                },
+                malloc_faults: (malloc_faults || malloc_fail_rate.is_some()).then_some(
+                    MallocFaultConfig {
+                        rate: malloc_fail_rate,
+                    },
+                ),
+                io_faults: (io_faults || io_fault_rate.is_some()).then_some(IoFaultConfig {
+                    rate: io_fault_rate,
+                }),
+                net_faults: (net_faults || net_fault_rate.is_some()).then_some(IoFaultConfig {
+                    rate: net_fault_rate,
+                }),
+                ..Default::default()
+            };
+            let overrides =
+                build_attack_overrides(profile, &intensity, args, axis_args, probe, flags)?;
+            let intensity = overrides
+                .axis_intensity
+                .get(&axis)
+                .copied()
+                .unwrap_or(overrides.intensity);
+            let duration = overrides
+                .axis_duration
+                .get(&axis)
+                .copied()
+                .unwrap_or(Duration::from_secs(duration));
             qprintln!(
                 cli.quiet,
                 "Attacking {} with {:?} (intensity: {:?}, duration: {}s)",
                 program.display(),
                 axis,
                 intensity,
-                duration
+                duration.as_secs()
             );
-
-            let (common_args, axis_args, probe_mode) =
-                build_attack_overrides(profile, args, axis_args, probe)?;
+            qprintln!(cli.quiet, "Seed: {}", seed);
 
             let config = AttackConfig {
-                axes: vec![axis.into()],
-                duration: Duration::from_secs(duration),
-                intensity: intensity.into(),
+                axes: vec![axis],
+                duration,
+                intensity,
                 target_programs: vec![program],
-                data_corpus: None,
                 parallel_attacks: cli.parallel,
-                common_args,
-                axis_args,
-                probe_mode,
+                common_args: overrides.common_args,
+                axis_args: overrides.axis_args,
+                custom_axes: overrides.custom_axes,
+                probe_mode: overrides.probe_mode,
+                signal_storm: overrides.signal_storm,
+                memory_pressure: overrides.memory_pressure,
+                malloc_faults: overrides.malloc_faults,
+                io_faults: overrides.io_faults,
+                net_faults: overrides.net_faults,
+                disk_throttle: overrides.disk_throttle,
+                cpu_chaos: overrides.cpu_chaos,
+                adaptive: overrides.adaptive,
+                seed,
+                wrapper: wrapper
+                    .map(TargetWrapper::from)
+                    .or(replay_schedule.is_some().then_some(TargetWrapper::Schedule)),
+                schedule_replay: replay_schedule,
+                schedule_dir: Some(
+                    cli.store
+                        .clone()
+                        .unwrap_or_else(|| PathBuf::from("reports"))
+                        .join(attack::schedule::SCHEDULE_DIR),
+                ),
+                progress: progress.clone(),
+                telemetry: cli.telemetry,
+                gpu: gpu.clone(),
+                timeout: cli.campaign_timeout.map(Duration::from_secs),
+                ..Default::default()
             };
 
+            preflight_check(
+                &preflight::attack(&config),
+                Path::new("."),
+                cli.campaign_timeout,
+                cli.quiet,
+                cli.force,
+            )?;
             let results = attack::execute_attack(config)?;
+            porcelain::record_results(&results);
 
             for result in &results {
                 qprintln!(cli.quiet, "\nResult:");
                 let status = if result.skipped {
                     "skipped"
+                } else if result.stall.is_some() {
+                    "stalled"
                 } else if result.success {
                     "passed"
                 } else {
@@ -1038,6 +2855,52 @@ fn run_main() -> Result<()> {
                     "  Duration: {:.2}s",
                     result.duration.as_secs_f64()
                 );
+                if let Some(storm) = &result.signal_storm {
+                    qprintln!(
+                        cli.quiet,
+                        "  Signals delivered: {} @ {:.1} Hz ({})",
+                        storm.delivered,
+                        storm.frequency_hz,
+                        storm.signals.join(", ")
+                    );
+                }
+                if let Some(faults) = result
+                    .stress_profile
+                    .as_ref()
+                    .and_then(|profile| profile.malloc_faults.as_ref())
+                {
+                    qprintln!(
+                        cli.quiet,
+                        "  Malloc faults: {} of {} allocations failed (rate {})",
+                        faults.injected,
+                        faults.calls,
+                        faults.rate
+                    );
+                }
+                if let Some(faults) = result
+                    .stress_profile
+                    .as_ref()
+                    .and_then(|profile| profile.io_faults.as_ref())
+                {
+                    qprintln!(
+                        cli.quiet,
+                        "  I/O faults: {} of {} file calls failed (rate {}){}",
+                        faults.injected,
+                        faults.intercepted,
+                        faults.rate,
+                        report::formatter::fault_kinds(faults)
+                    );
+                }
+                if let Some(faults) = &result.net_faults {
+                    qprintln!(
+                        cli.quiet,
+                        "  Network faults: {} of {} socket calls failed (rate {}){}",
+                        faults.injected,
+                        faults.intercepted,
+                        faults.rate,
+                        report::formatter::fault_kinds(faults)
+                    );
+                }
                 qprintln!(cli.quiet, "  Crashes: {}", result.crashes.len());
                 if !result.crashes.is_empty() {
                     for (i, crash) in result.crashes.iter().enumerate() {
@@ -1054,11 +2917,52 @@ fn run_main() -> Result<()> {
             args,
             axis_args,
             probe,
+            wrapper,
+            replay_schedule,
+            malloc_faults,
+            malloc_fail_rate,
+            io_faults,
+            io_fault_rate,
+            net_faults,
+            net_fault_rate,
+            signal_hz,
+            signals,
+            signal_pulse,
             axes,
             intensity,
             duration,
+            explore_crashes,
+            checkpoint,
+            resume,
+            snapshot_dirs,
+            snapshot_sqlite,
+            snapshot_url,
             output,
         } => {
+            let _lock = campaign_lock(
+                cli.store.as_deref(),
+                cli.wait,
+                cli.steal_lock,
+                "assault",
+                &program,
+            )?;
+            let resumed = resume
+                .as_deref()
+                .map(attack::Checkpoint::resume)
+                .transpose()?;
+            // A resumed campaign replays the rest of its stress schedule under its own seed.
+            let seed = match &resumed {
+                Some(checkpoint) => {
+                    if cli.seed.is_some_and(|seed| seed != checkpoint.seed) {
+                        return Err(anyhow!(
+                            "--seed conflicts with the checkpoint's seed {}",
+                            checkpoint.seed
+                        ));
+                    }
+                    checkpoint.seed
+                }
+                None => seed,
+            };
             qprintln!(
                 cli.quiet,
                 "Launching full assault on: {}",
@@ -1067,61 +2971,216 @@ fn run_main() -> Result<()> {
 
             qprintln!(cli.quiet, "\nPhase 1: Assail Analysis");
             let assail_target = source.as_ref().unwrap_or(&program);
-            let assail_report = assail::analyze_verbose(assail_target)?;
+            assail::check_target(assail_target)?;
+            let mut assail_report = assail::analyze_verbose_with(
+                &assail::Analyzer::new(assail_target)?.with_blame_owners(cli.blame_owners),
+            )?;
+            let mut calibration = open_calibration(
+                cli.calibrate,
+                cli.calibration_file.as_deref(),
+                assail_target,
+            )?;
+            apply_calibration(&calibration, &mut assail_report, cli.quiet);
+            let generator =
+                report_generator(cli.report_lang, cli.recommendation_templates.as_deref())?;
 
             qprintln!(cli.quiet, "\nPhase 2: Attack Execution");
-            let attack_axes = if let Some(axes_arg) = axes {
-                axes_arg.into_iter().map(|a| a.into()).collect()
-            } else {
-                AttackAxis::all()
-            };
+            let attack_axes = axes.unwrap_or_else(AttackAxis::defaults);
 
-            let (common_args, axis_args, probe_mode) =
-                build_attack_overrides(profile, args, axis_args, probe)?;
+            let flags = StressorFlags {
+                signal_storm: SignalStormConfig {
+                    frequency_hz: signal_hz,
+                    signals,
+                    stop_pulses: signal_pulse,
+                },
+                malloc_faults: (malloc_faults || malloc_fail_rate.is_some()).then_some(
+                    MallocFaultConfig {
+                        rate: malloc_fail_rate,
+                    },
+                ),
+                io_faults: (io_faults || io_fault_rate.is_some()).then_some(IoFaultConfig {
+                    rate: io_fault_rate,
+                }),
+                net_faults: (net_faults || net_fault_rate.is_some()).then_some(IoFaultConfig {
+                    rate: net_fault_rate,
+                }),
+                ..Default::default()
+            };
+            let overrides =
+                build_attack_overrides(profile, &intensity, args, axis_args, probe, flags)?;
+            let redactor = build_redactor(
+                overrides.redaction.clone(),
+                cli.no_redact,
+                &cli.redact_patterns,
+            )?;
 
             let config = AttackConfig {
                 axes: attack_axes,
                 duration: Duration::from_secs(duration),
-                intensity: intensity.into(),
+                intensity: overrides.intensity,
+                axis_intensity: overrides.axis_intensity,
+                axis_duration: overrides.axis_duration,
                 target_programs: vec![program],
-                data_corpus: None,
                 parallel_attacks: cli.parallel,
-                common_args,
-                axis_args,
-                probe_mode,
+                common_args: overrides.common_args,
+                axis_args: overrides.axis_args,
+                custom_axes: overrides.custom_axes,
+                probe_mode: overrides.probe_mode,
+                signal_storm: overrides.signal_storm,
+                memory_pressure: overrides.memory_pressure,
+                malloc_faults: overrides.malloc_faults,
+                io_faults: overrides.io_faults,
+                net_faults: overrides.net_faults,
+                disk_throttle: overrides.disk_throttle,
+                cpu_chaos: overrides.cpu_chaos,
+                adaptive: overrides.adaptive,
+                seed,
+                wrapper: wrapper
+                    .map(TargetWrapper::from)
+                    .or(replay_schedule.is_some().then_some(TargetWrapper::Schedule)),
+                schedule_replay: replay_schedule,
+                schedule_dir: Some(
+                    cli.store
+                        .clone()
+                        .unwrap_or_else(|| PathBuf::from("reports"))
+                        .join(attack::schedule::SCHEDULE_DIR),
+                ),
+                progress: progress.clone(),
+                telemetry: cli.telemetry,
+                gpu: gpu.clone(),
+                timeout: cli.campaign_timeout.map(Duration::from_secs),
+                ..Default::default()
             };
 
-            let attack_results = attack::execute_attack_with_patterns(
-                config,
-                assail_report.language,
-                &assail_report.frameworks,
+            let mut checkpoint = match resumed {
+                Some(checkpoint) => {
+                    checkpoint.check_matches(&config)?;
+                    qprintln!(
+                        cli.quiet,
+                        "Resuming from {}: {} of {} axis runs already finished",
+                        checkpoint.path().display(),
+                        checkpoint.completed.len(),
+                        config.target_programs.len() * config.axes.len()
+                    );
+                    Some(checkpoint)
+                }
+                None => checkpoint
+                    .map(|dir| attack::Checkpoint::create(&dir, &config))
+                    .transpose()?,
+            };
+            preflight_check(
+                &preflight::attack(&config),
+                Path::new("."),
+                cli.campaign_timeout,
+                cli.quiet,
+                cli.force,
             )?;
+            let snapshot_config = SnapshotConfig {
+                dirs: snapshot_dirs,
+                sqlite: snapshot_sqlite,
+                url: snapshot_url,
+            };
+            let snapshot_before =
+                (!snapshot_config.is_empty()).then(|| snapshot::capture(&snapshot_config));
+            let mut attack_results = match checkpoint.as_mut() {
+                Some(checkpoint) => {
+                    let results = attack::execute_attack_with_checkpoint(
+                        config.clone(),
+                        assail_report.language,
+                        &assail_report.frameworks,
+                        checkpoint,
+                    )?;
+                    checkpoint.finish()?;
+                    results
+                }
+                None => attack::execute_attack_with_patterns(
+                    config.clone(),
+                    assail_report.language,
+                    &assail_report.frameworks,
+                )?,
+            };
+            let state_diff = snapshot_before
+                .map(|before| snapshot::diff(&before, &snapshot::capture(&snapshot_config)));
+            let reproduction = if explore_crashes {
+                qprintln!(cli.quiet, "\nPhase 2b: Crash Exploration");
+                attack::explore_crashes(&config, &attack_results, false, |probe| {
+                    Ok(attack::execute_attack_with_patterns(
+                        probe.clone(),
+                        assail_report.language,
+                        &assail_report.frameworks,
+                    )?)
+                })?
+            } else {
+                Vec::new()
+            };
 
             qprintln!(cli.quiet, "\nPhase 3: Report Generation");
-            let assault_report = report::generate_assault_report(assail_report, attack_results)?;
+            apply_signature_feedback(cli.signature_feedback.as_deref(), &mut attack_results)?;
+            symbolize_crashes(
+                &cli.debug_dirs,
+                cli.offline_symbols,
+                cli.quiet,
+                &mut attack_results,
+            )?;
+            let mut assault_report = generate_with_calibration(
+                &generator,
+                &mut calibration,
+                assail_report,
+                attack_results,
+            )?;
+            assault_report.seed = Some(seed);
+            assault_report.reproduction = reproduction;
+            assault_report.state_diff = state_diff;
+            assault_report.targets =
+                attack::provenance::capture_all(&assault_report.attack_results);
+            assault_report.host_environment = Some(attack::environment::capture());
+            assign_crash_owners(&mut assault_report, cli.blame_owners)?;
+            record_flakiness(cli.flaky, cli.flaky_db.as_deref(), &mut assault_report)?;
+            if let Some(redactor) = &redactor {
+                let applied = redactor.redact_assault_report(&mut assault_report);
+                assault_report.redactions_applied = Some(applied);
+                if applied > 0 {
+                    qprintln!(
+                        cli.quiet,
+                        "Redacted {} secret(s) from crash output",
+                        applied
+                    );
+                }
+            }
 
+            porcelain::record_assault(&assault_report);
             if !cli.quiet {
                 report::print_report(
                     &assault_report,
                     cli.report_view,
                     cli.expand_sections,
                     cli.pivot,
+                    cli.accessible,
+                    &filter,
                 );
             }
 
             if let Some(output_path) = output {
-                report::save_report(&assault_report, &output_path, cli.output_format)?;
+                report::save_report_with_repro(
+                    &assault_report,
+                    &output_path,
+                    cli.output_format.unwrap_or(ReportOutputFormat::Json),
+                    repro_options,
+                )?;
+                porcelain::record("report", output_path.display());
                 qprintln!(cli.quiet, "Report saved to: {}", output_path.display());
             }
 
-            if !storage_modes.is_empty() {
+            if !storage_modes.is_empty() && persisted_kinds.contains(&ReportKind::Assault) {
                 let stored = persist_report(
                     &assault_report,
                     cli.store.as_deref(),
                     &manifest_formats,
                     &storage_modes,
+                    repro_options,
                 )?;
                 for path in stored {
+                    porcelain::record("report", path.display());
                     qprintln!(cli.quiet, "Stored report: {}", path.display());
                 }
             }
@@ -1131,151 +3190,517 @@ fn run_main() -> Result<()> {
             program,
             source,
             timeline,
+            repeat,
             profile,
             args,
             axis_args,
+            signal_hz,
+            signals,
+            signal_pulse,
+            memory_mode,
+            memory_start_mb,
+            memory_floor_mb,
+            io_limits,
+            io_data_dir,
+            cpu_chaos,
+            cpu_chaos_interval_ms,
+            cpu_chaos_nice,
+            adaptive,
+            setpoint,
+            adaptive_interval_ms,
             axes,
             intensity,
             duration,
+            explore_crashes,
+            webdriver,
+            browser_url,
+            browser,
+            db,
+            db_lock_table,
+            db_connections,
+            message_flood,
+            flood_phases,
+            flood_rate,
             output,
         } => {
+            let _lock = campaign_lock(
+                cli.store.as_deref(),
+                cli.wait,
+                cli.steal_lock,
+                "ambush",
+                &program,
+            )?;
             qprintln!(cli.quiet, "Launching ambush on: {}", program.display());
+            let browser = webdriver
+                .zip(browser_url)
+                .map(|(webdriver, url)| BrowserConfig {
+                    webdriver,
+                    url,
+                    browser: browser.into(),
+                });
+            let database = db.map(|dsn| DbChaosConfig {
+                dsn,
+                lock_table: db_lock_table,
+                connections: db_connections,
+            });
+            let flood = message_flood.map(|broker| MessageFloodConfig {
+                broker,
+                phases: flood_phases,
+                start_rate_hz: flood_rate,
+            });
 
             qprintln!(cli.quiet, "\nPhase 1: Assail Analysis");
             let assail_target = source.as_ref().unwrap_or(&program);
-            let assail_report = assail::analyze_verbose(assail_target)?;
+            assail::check_target(assail_target)?;
+            let mut assail_report = assail::analyze_verbose_with(
+                &assail::Analyzer::new(assail_target)?.with_blame_owners(cli.blame_owners),
+            )?;
+            let mut calibration = open_calibration(
+                cli.calibrate,
+                cli.calibration_file.as_deref(),
+                assail_target,
+            )?;
+            apply_calibration(&calibration, &mut assail_report, cli.quiet);
+            let generator =
+                report_generator(cli.report_lang, cli.recommendation_templates.as_deref())?;
 
             qprintln!(cli.quiet, "\nPhase 2: Ambush Execution");
+            let memory_pressure = if memory_mode.is_some()
+                || memory_start_mb.is_some()
+                || memory_floor_mb.is_some()
+            {
+                Some(MemoryPressureConfig {
+                    mode: memory_mode
+                        .map(MemoryPressureMode::from)
+                        .unwrap_or(MemoryPressureMode::Cgroup),
+                    start_mb: memory_start_mb,
+                    floor_mb: memory_floor_mb,
+                })
+            } else {
+                None
+            };
+            let flags = StressorFlags {
+                signal_storm: SignalStormConfig {
+                    frequency_hz: signal_hz,
+                    signals,
+                    stop_pulses: signal_pulse,
+                },
+                memory_pressure,
+                disk_throttle: build_disk_throttle(io_limits, io_data_dir)?,
+                cpu_chaos: (cpu_chaos
+                    || cpu_chaos_interval_ms.is_some()
+                    || cpu_chaos_nice.is_some())
+                .then_some(CpuChaosConfig {
+                    enabled: true,
+                    interval_ms: cpu_chaos_interval_ms,
+                    max_nice: cpu_chaos_nice,
+                }),
+                adaptive: adaptive.zip(setpoint).map(|(metric, setpoint)| AdaptiveConfig {
+                    metric: metric.into(),
+                    setpoint,
+                    interval_ms: adaptive_interval_ms,
+                }),
+                ..Default::default()
+            };
             let mut timeline_report = None;
-            let attack_results = if let Some(timeline_path) = timeline {
-                let timeline_plan =
-                    ambush::load_timeline_with_default(&timeline_path, Some(intensity.into()))?;
+            let mut reproduction = Vec::new();
+            let redactor;
+            let mut attack_results = if let Some(timeline_path) = timeline {
+                if explore_crashes {
+                    tracing::warn!("--explore-crashes is ignored for timeline runs");
+                }
+                if cli.campaign_timeout.is_some() {
+                    tracing::warn!("--campaign-timeout is ignored for timeline runs");
+                }
+                let overrides =
+                    build_attack_overrides(profile, &intensity, args, Vec::new(), None, flags)?;
+                redactor = build_redactor(
+                    overrides.redaction.clone(),
+                    cli.no_redact,
+                    &cli.redact_patterns,
+                )?;
+                let timeline_plan = ambush::load_timeline_with_default(
+                    &timeline_path,
+                    Some(overrides.intensity),
+                    seed,
+                )?;
                 if let Some(timeline_program) = &timeline_plan.program {
                     if timeline_program != &program {
-                        eprintln!(
-                            "warning: timeline program {} overrides CLI program {}",
+                        tracing::warn!(
+                            "timeline program {} overrides CLI program {}",
                             timeline_program.display(),
                             program.display()
                         );
                     }
                 }
 
-                let (common_args, _axis_args, _probe_mode) =
-                    build_attack_overrides(profile, args, Vec::new(), None)?;
-
                 let config = AttackConfig {
                     axes: AttackAxis::all(),
                     duration: timeline_plan.duration,
-                    intensity: intensity.into(),
+                    intensity: overrides.intensity,
                     target_programs: vec![program.clone()],
-                    data_corpus: None,
                     parallel_attacks: cli.parallel,
-                    common_args,
-                    axis_args: HashMap::new(),
+                    common_args: overrides.common_args,
                     probe_mode: ProbeMode::Never,
+                    signal_storm: overrides.signal_storm,
+                    memory_pressure: overrides.memory_pressure,
+                    malloc_faults: overrides.malloc_faults,
+                    io_faults: overrides.io_faults,
+                    net_faults: overrides.net_faults,
+                    disk_throttle: overrides.disk_throttle,
+                    cpu_chaos: overrides.cpu_chaos,
+                    adaptive: overrides.adaptive,
+                    seed,
+                    progress: progress.clone(),
+                    telemetry: cli.telemetry,
+                    gpu: gpu.clone(),
+                    browser: browser.clone(),
+                    database: database.clone(),
+                    flood: flood.clone(),
+                    ..Default::default()
                 };
 
-                let (results, timeline) = ambush::execute_timeline(config, &timeline_plan)?;
+                // Timeline events overlap within one window rather than running axis by axis.
+                let estimate = preflight::Estimate {
+                    duration: timeline_plan.duration * repeat,
+                    ..preflight::ambush(&config)
+                };
+                preflight_check(
+                    &estimate,
+                    Path::new("."),
+                    cli.campaign_timeout,
+                    cli.quiet,
+                    cli.force,
+                )?;
+                let mut runs = vec![ambush::execute_timeline(config.clone(), &timeline_plan)?];
+                for run in 1..repeat {
+                    // A fresh seed per run re-draws event jitter.
+                    let run_seed = seed.wrapping_add(u64::from(run));
+                    qprintln!(
+                        cli.quiet,
+                        "Timeline run {}/{} (seed {})",
+                        run + 1,
+                        repeat,
+                        run_seed
+                    );
+                    let plan = ambush::load_timeline_with_default(
+                        &timeline_path,
+                        Some(config.intensity),
+                        run_seed,
+                    )?;
+                    let config = AttackConfig {
+                        seed: run_seed,
+                        ..config.clone()
+                    };
+                    runs.push(ambush::execute_timeline(config, &plan)?);
+                }
+                let (results, timeline) = ambush::merge_timeline_runs(runs);
                 timeline_report = Some(timeline);
                 results
             } else {
-                let ambush_axes = if let Some(axes_arg) = axes {
-                    axes_arg.into_iter().map(|a| a.into()).collect()
-                } else {
-                    AttackAxis::all()
-                };
-
-                let (common_args, axis_args, _probe_mode) =
-                    build_attack_overrides(profile, args, axis_args, None)?;
+                let ambush_axes = axes.unwrap_or_else(AttackAxis::defaults);
+
+                let overrides =
+                    build_attack_overrides(profile, &intensity, args, axis_args, None, flags)?;
+                redactor = build_redactor(
+                    overrides.redaction.clone(),
+                    cli.no_redact,
+                    &cli.redact_patterns,
+                )?;
 
                 let config = AttackConfig {
                     axes: ambush_axes,
                     duration: Duration::from_secs(duration),
-                    intensity: intensity.into(),
+                    intensity: overrides.intensity,
+                    axis_intensity: overrides.axis_intensity,
+                    axis_duration: overrides.axis_duration,
                     target_programs: vec![program],
-                    data_corpus: None,
                     parallel_attacks: cli.parallel,
-                    common_args,
-                    axis_args,
+                    common_args: overrides.common_args,
+                    axis_args: overrides.axis_args,
+                    custom_axes: overrides.custom_axes,
                     probe_mode: ProbeMode::Never,
+                    signal_storm: overrides.signal_storm,
+                    memory_pressure: overrides.memory_pressure,
+                    malloc_faults: overrides.malloc_faults,
+                    io_faults: overrides.io_faults,
+                    net_faults: overrides.net_faults,
+                    disk_throttle: overrides.disk_throttle,
+                    cpu_chaos: overrides.cpu_chaos,
+                    adaptive: overrides.adaptive,
+                    seed,
+                    progress: progress.clone(),
+                    telemetry: cli.telemetry,
+                    gpu: gpu.clone(),
+                    browser: browser.clone(),
+                    database: database.clone(),
+                    flood: flood.clone(),
+                    timeout: cli.campaign_timeout.map(Duration::from_secs),
+                    ..Default::default()
                 };
 
-                ambush::execute(config)?
+                preflight_check(
+                    &preflight::ambush(&config),
+                    Path::new("."),
+                    cli.campaign_timeout,
+                    cli.quiet,
+                    cli.force,
+                )?;
+                let results = ambush::execute(config.clone())?;
+                if explore_crashes {
+                    qprintln!(cli.quiet, "\nPhase 2b: Crash Exploration");
+                    reproduction = attack::explore_crashes(&config, &results, true, |probe| {
+                        ambush::execute(probe.clone())
+                    })?;
+                }
+                results
             };
 
             qprintln!(cli.quiet, "\nPhase 3: Report Generation");
-            let mut assault_report =
-                report::generate_assault_report(assail_report, attack_results)?;
+            apply_signature_feedback(cli.signature_feedback.as_deref(), &mut attack_results)?;
+            symbolize_crashes(
+                &cli.debug_dirs,
+                cli.offline_symbols,
+                cli.quiet,
+                &mut attack_results,
+            )?;
+            let mut assault_report = generate_with_calibration(
+                &generator,
+                &mut calibration,
+                assail_report,
+                attack_results,
+            )?;
             if let Some(timeline) = timeline_report {
                 assault_report.timeline = Some(timeline);
             }
+            assault_report.seed = Some(seed);
+            assault_report.reproduction = reproduction;
+            assault_report.targets =
+                attack::provenance::capture_all(&assault_report.attack_results);
+            assault_report.host_environment = Some(attack::environment::capture());
+            assign_crash_owners(&mut assault_report, cli.blame_owners)?;
+            record_flakiness(cli.flaky, cli.flaky_db.as_deref(), &mut assault_report)?;
+            if let Some(redactor) = &redactor {
+                let applied = redactor.redact_assault_report(&mut assault_report);
+                assault_report.redactions_applied = Some(applied);
+                if applied > 0 {
+                    qprintln!(
+                        cli.quiet,
+                        "Redacted {} secret(s) from crash output",
+                        applied
+                    );
+                }
+            }
 
+            porcelain::record_assault(&assault_report);
             if !cli.quiet {
                 report::print_report(
                     &assault_report,
                     cli.report_view,
                     cli.expand_sections,
                     cli.pivot,
+                    cli.accessible,
+                    &filter,
                 );
             }
 
             if let Some(output_path) = output {
-                report::save_report(&assault_report, &output_path, cli.output_format)?;
+                report::save_report_with_repro(
+                    &assault_report,
+                    &output_path,
+                    cli.output_format.unwrap_or(ReportOutputFormat::Json),
+                    repro_options,
+                )?;
+                porcelain::record("report", output_path.display());
                 qprintln!(cli.quiet, "Report saved to: {}", output_path.display());
             }
 
-            if !storage_modes.is_empty() {
+            if !storage_modes.is_empty() && persisted_kinds.contains(&ReportKind::Assault) {
                 let stored = persist_report(
                     &assault_report,
                     cli.store.as_deref(),
                     &manifest_formats,
                     &storage_modes,
+                    repro_options,
                 )?;
                 for path in stored {
+                    porcelain::record("report", path.display());
                     qprintln!(cli.quiet, "Stored report: {}", path.display());
                 }
             }
         }
 
         Commands::Amuck {
+            action: Some(action),
+            ..
+        } => match action {
+            AmuckAction::Clean {
+                dir,
+                older_than,
+                keep_last,
+                keep,
+                report,
+                dry_run,
+                json,
+            } => {
+                let now = chrono::Utc::now();
+                let options = AmuckCleanOptions {
+                    dir,
+                    older_than: older_than
+                        .map(|value| storage::query::parse_time(&value, now))
+                        .transpose()?,
+                    keep_last,
+                    keep,
+                    reports: report,
+                    dry_run,
+                };
+                let cleaned = amuck::clean::clean(&options)?;
+                porcelain::record("removed", cleaned.removed.len());
+                porcelain::record("kept", cleaned.kept);
+                porcelain::record("freed_bytes", cleaned.freed_bytes);
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&cleaned)?);
+                } else {
+                    for path in &cleaned.removed {
+                        qprintln!(cli.quiet, "{}", path.display());
+                    }
+                    qprintln!(
+                        cli.quiet,
+                        "amuck clean {}: {} {} variant(s) ({} KiB), kept {}",
+                        options.dir.display(),
+                        if dry_run { "would delete" } else { "deleted" },
+                        cleaned.removed.len(),
+                        cleaned.freed_bytes / 1024,
+                        cleaned.kept
+                    );
+                }
+            }
+        },
+
+        Commands::Amuck {
+            action: None,
             target,
             preset,
             spec,
+            from_assail,
+            binary,
+            keep,
             max_combinations,
             output_dir,
             exec_program,
             exec_args,
+            run_as,
+            trace_syscalls,
             output,
         } => {
+            // clap requires TARGET unless a subcommand is given.
+            let target = target.ok_or_else(|| anyhow!("amuck needs a TARGET file"))?;
+            let run_as = run_as.as_deref().map(RunAs::resolve).transpose()?;
+            let _lock = campaign_lock(
+                cli.store.as_deref(),
+                cli.wait,
+                cli.steal_lock,
+                "amuck",
+                &target,
+            )?;
             let execute = exec_program.map(|program| AmuckExecutionCommand {
                 program,
                 args: exec_args,
             });
-            let report = amuck::run(AmuckConfig {
+            let spec = match from_assail {
+                Some(report) => {
+                    let generated = amuck::from_assail::write_spec(&report, &target, &output_dir)?;
+                    qprintln!(
+                        cli.quiet,
+                        "amuck spec generated from {}: {}",
+                        report.display(),
+                        generated.display()
+                    );
+                    porcelain::record("spec", generated.display());
+                    Some(generated)
+                }
+                None => spec,
+            };
+            let config = AmuckConfig {
                 target,
                 spec_path: spec,
                 preset: preset.into(),
                 max_combinations,
                 output_dir,
                 execute,
-            })?;
+                seed,
+                binary,
+                keep,
+                run_as,
+                trace_syscalls,
+            };
+            // A missing target is left for amuck::run to report.
+            if let Ok(metadata) = fs::metadata(&config.target) {
+                preflight_check(
+                    &preflight::amuck(metadata.len(), config.max_combinations),
+                    &config.output_dir,
+                    cli.campaign_timeout,
+                    cli.quiet,
+                    cli.force,
+                )?;
+            }
+            let report = amuck::run(config)?;
             let report_path = output.unwrap_or_else(default_amuck_report_path);
             amuck::write_report(&report, &report_path)?;
+            porcelain::record("combinations_run", report.combinations_run);
+            porcelain::record("combinations_planned", report.combinations_planned);
+            porcelain::record("seed", report.seed);
             qprintln!(
                 cli.quiet,
-                "amuck complete: {}/{} combinations wrote mutated files",
+                "amuck complete: {}/{} combinations wrote mutated files (seed {})",
                 report.combinations_run,
-                report.combinations_planned
+                report.combinations_planned,
+                report.seed
             );
+            if report.baseline.is_some() {
+                let (killed, survived, equivalent) = amuck::baseline::tally(&report.outcomes);
+                porcelain::record("killed", killed);
+                porcelain::record("survived", survived);
+                porcelain::record("equivalent_output", equivalent);
+                qprintln!(
+                    cli.quiet,
+                    "against the baseline run: {} killed, {} survived, {} equivalent-output",
+                    killed,
+                    survived,
+                    equivalent
+                );
+                for outcome in &report.outcomes {
+                    if let Some(comparison) = outcome
+                        .comparison
+                        .as_ref()
+                        .filter(|comparison| comparison.verdict != amuck::baseline::Verdict::Killed)
+                    {
+                        qprintln!(
+                            cli.quiet,
+                            "  {}: {}",
+                            outcome.name,
+                            comparison.verdict.label()
+                        );
+                    }
+                }
+            }
+            porcelain::record("report", report_path.display());
             qprintln!(
                 cli.quiet,
                 "amuck report saved to: {}",
                 report_path.display()
             );
+            persist_kind(
+                &report,
+                cli.store.as_deref(),
+                &storage_modes,
+                &persisted_kinds,
+                cli.quiet,
+            )?;
         }
 
         Commands::Abduct {
-            target,
+            targets,
             source_root,
             scope,
             output_dir,
@@ -1287,14 +3712,25 @@ fn run_main() -> Result<()> {
             exec_program,
             exec_args,
             exec_timeout,
+            no_network,
+            run_as,
+            trace_syscalls,
             output,
         } => {
+            let run_as = run_as.as_deref().map(RunAs::resolve).transpose()?;
+            let _lock = campaign_lock(
+                cli.store.as_deref(),
+                cli.wait,
+                cli.steal_lock,
+                "abduct",
+                &targets[0],
+            )?;
             let execute = exec_program.map(|program| AbductExecutionCommand {
                 program,
                 args: exec_args,
             });
-            let report = abduct::run(AbductConfig {
-                target,
+            let config = AbductConfig {
+                targets,
                 source_root,
                 output_root: output_dir,
                 dependency_scope: scope.into(),
@@ -1305,9 +3741,33 @@ fn run_main() -> Result<()> {
                 virtual_now,
                 execute,
                 exec_timeout_secs: exec_timeout,
-            })?;
+                no_network,
+                run_as,
+                trace_syscalls,
+                seed,
+            };
+            if let Ok((files, bytes)) = abduct::planned_copy(&config) {
+                let exec_timeout = config
+                    .execute
+                    .as_ref()
+                    .map(|_| Duration::from_secs(config.exec_timeout_secs));
+                let estimate = preflight::abduct(files, bytes, exec_timeout);
+                preflight_check(
+                    &estimate,
+                    &config.output_root,
+                    cli.campaign_timeout,
+                    cli.quiet,
+                    cli.force,
+                )?;
+            }
+            let report = abduct::run(config)?;
             let report_path = output.unwrap_or_else(default_abduct_report_path);
             abduct::write_report(&report, &report_path)?;
+            porcelain::record("selected_files", report.selected_files);
+            porcelain::record("locked_files", report.locked_files);
+            porcelain::record("mtime_shifted_files", report.mtime_shifted_files);
+            porcelain::record("workspace", report.workspace_dir.display());
+            porcelain::record("seed", report.seed);
             qprintln!(
                 cli.quiet,
                 "abduct complete: {} files copied ({} locked, {} mtime-shifted)",
@@ -1320,17 +3780,61 @@ fn run_main() -> Result<()> {
                 "abduct workspace: {}",
                 report.workspace_dir.display()
             );
+            if let Some(signal) = &report.network_escape_signal {
+                porcelain::record("network_escape_attempts", signal.attempts);
+                qprintln!(
+                    cli.quiet,
+                    "network escape signal: {} blocked connection attempt(s)",
+                    signal.attempts
+                );
+                for line in &signal.evidence {
+                    qprintln!(cli.quiet, "  {}", line);
+                }
+            }
+            porcelain::record("report", report_path.display());
             qprintln!(
                 cli.quiet,
                 "abduct report saved to: {}",
                 report_path.display()
             );
+            persist_kind(
+                &report,
+                cli.store.as_deref(),
+                &storage_modes,
+                &persisted_kinds,
+                cli.quiet,
+            )?;
         }
 
-        Commands::Adjudicate { reports, output } => {
-            let report = adjudicate::run(AdjudicateConfig { reports })?;
+        Commands::Adjudicate {
+            reports,
+            output,
+            previous,
+            fail_score,
+            fail_score_increase,
+            summary_output,
+            lang,
+        } => {
+            let previous = previous.or_else(|| {
+                let filter = ReportFilter {
+                    kind: Some(ReportKind::Adjudicate),
+                    ..ReportFilter::default()
+                };
+                latest_reports(Path::new("reports"), 1, &filter)
+                    .ok()
+                    .and_then(|paths| paths.into_iter().next())
+            });
+            let report = adjudicate::run(AdjudicateConfig {
+                reports,
+                previous,
+                seed,
+            })?;
             let report_path = output.unwrap_or_else(default_adjudicate_report_path);
             adjudicate::write_report(&report, &report_path)?;
+            porcelain::record("verdict", &report.verdict);
+            porcelain::record("seed", report.seed);
+            porcelain::record("processed_reports", report.processed_reports);
+            porcelain::record("failed_reports", report.failed_reports);
             qprintln!(
                 cli.quiet,
                 "adjudicate verdict: {} (processed {}, failed {})",
@@ -1338,11 +3842,69 @@ fn run_main() -> Result<()> {
                 report.processed_reports,
                 report.failed_reports
             );
+            let risk = report.risk.as_ref();
+            if let Some(risk) = risk {
+                porcelain::record("risk_score", risk.score);
+                match risk.delta {
+                    Some(delta) => {
+                        porcelain::record("risk_delta", delta);
+                        qprintln!(
+                            cli.quiet,
+                            "risk score: {:.1}/100 ({:+.1} since previous campaign)",
+                            risk.score,
+                            delta
+                        );
+                    }
+                    None => qprintln!(cli.quiet, "risk score: {:.1}/100", risk.score),
+                }
+                for component in risk.components.iter().take(3) {
+                    qprintln!(
+                        cli.quiet,
+                        "  {} x{}: {:.0}%",
+                        component.signal,
+                        component.count,
+                        component.risk * 100.0
+                    );
+                }
+            }
+            porcelain::record("report", report_path.display());
             qprintln!(
                 cli.quiet,
                 "adjudicate report saved to: {}",
                 report_path.display()
             );
+            persist_kind(
+                &report,
+                cli.store.as_deref(),
+                &storage_modes,
+                &persisted_kinds,
+                cli.quiet,
+            )?;
+            if let Some(path) = summary_output {
+                adjudicate::summary::write_summary(&report, &path, lang.into())?;
+                porcelain::record("summary", path.display());
+                qprintln!(cli.quiet, "executive summary saved to: {}", path.display());
+            }
+
+            let score = risk.map_or(0.0, |risk| risk.score);
+            if let Some(limit) = fail_score {
+                if score >= limit {
+                    return Err(anyhow!(
+                        "campaign risk score {:.1} reached --fail-score {:.1}",
+                        score,
+                        limit
+                    ));
+                }
+            }
+            if let (Some(limit), Some(delta)) = (fail_score_increase, risk.and_then(|r| r.delta)) {
+                if delta >= limit {
+                    return Err(anyhow!(
+                        "campaign risk score rose by {:.1} (limit --fail-score-increase {:.1})",
+                        delta,
+                        limit
+                    ));
+                }
+            }
         }
 
         Commands::Axial {
@@ -1352,6 +3914,10 @@ fn run_main() -> Result<()> {
             repeat,
             timeout,
             reports,
+            journal,
+            syslog,
+            probe_url,
+            probe_interval_ms,
             head,
             tail,
             grep,
@@ -1360,7 +3926,10 @@ fn run_main() -> Result<()> {
             lang,
             aspell,
             aspell_lang,
+            spell_dict,
             markdown_output,
+            export,
+            export_output,
             pandoc_to,
             pandoc_output,
             output,
@@ -1375,6 +3944,12 @@ fn run_main() -> Result<()> {
                 repeat,
                 timeout_secs: timeout,
                 reports,
+                journal_units: journal,
+                syslog_files: syslog,
+                probe: probe_url.map(|url| AxialProbeConfig {
+                    url,
+                    interval: Duration::from_millis(probe_interval_ms),
+                }),
                 head_lines: head,
                 tail_lines: tail,
                 grep_patterns: grep,
@@ -1383,11 +3958,32 @@ fn run_main() -> Result<()> {
                 lang: lang.into(),
                 aspell,
                 aspell_lang,
+                spell_dict,
+                seed,
             })?;
             let report_path = output.unwrap_or_else(default_axial_report_path);
             axial::write_report(&report, &report_path)?;
+            porcelain::record("observed_runs", report.observed_runs);
+            porcelain::record("observed_reports", report.observed_reports);
+            porcelain::record("seed", report.seed);
             let markdown_path = markdown_output.unwrap_or_else(default_axial_markdown_path);
             axial::write_markdown(&report, &markdown_path)?;
+            if let Some(format) = export {
+                let format = ExportFormat::from(format);
+                let export_path = export_output.unwrap_or_else(|| {
+                    let mut p = markdown_path.clone();
+                    p.set_extension(format.extension());
+                    p
+                });
+                axial::export::export(&markdown_path, format, &export_path, &report.language)?;
+                porcelain::record("export", export_path.display());
+                qprintln!(
+                    cli.quiet,
+                    "axial {} export saved to: {}",
+                    format.extension(),
+                    export_path.display()
+                );
+            }
             if let Some(target_format) = pandoc_to {
                 let pandoc_path = pandoc_output.unwrap_or_else(|| {
                     let mut p = markdown_path.clone();
@@ -1399,6 +3995,7 @@ fn run_main() -> Result<()> {
                     &target_format,
                     &pandoc_path,
                 )?;
+                porcelain::record("export", pandoc_path.display());
                 qprintln!(
                     cli.quiet,
                     "axial pandoc export ({}) saved to: {}",
@@ -1412,11 +4009,20 @@ fn run_main() -> Result<()> {
                 report.observed_runs,
                 report.observed_reports
             );
+            porcelain::record("report", report_path.display());
             qprintln!(
                 cli.quiet,
                 "axial report saved to: {}",
                 report_path.display()
             );
+            persist_kind(
+                &report,
+                cli.store.as_deref(),
+                &storage_modes,
+                &persisted_kinds,
+                cli.quiet,
+            )?;
+            porcelain::record("report", markdown_path.display());
             qprintln!(
                 cli.quiet,
                 "axial markdown saved to: {}",
@@ -1424,6 +4030,59 @@ fn run_main() -> Result<()> {
             );
         }
 
+        Commands::Run {
+            pipeline: pipeline_path,
+            run_id,
+            output_dir,
+        } => {
+            let spec = pipeline::load(&pipeline_path)?;
+            let report = pipeline::run(
+                &spec,
+                &pipeline::RunOptions {
+                    run_id,
+                    output_dir,
+                    seed,
+                },
+            )?;
+            for stage in &report.stages {
+                let detail = stage
+                    .reason
+                    .clone()
+                    .or_else(|| stage.output.as_ref().map(|path| path.display().to_string()))
+                    .unwrap_or_default();
+                qprintln!(
+                    cli.quiet,
+                    "{:<20} {:<10} {:<9} {}",
+                    stage.id,
+                    stage.kind,
+                    format!("{:?}", stage.status).to_lowercase(),
+                    detail
+                );
+            }
+            if let Some(verdict) = &report.verdict {
+                porcelain::record("verdict", verdict);
+                qprintln!(cli.quiet, "pipeline verdict: {}", verdict);
+            }
+            porcelain::record("run_id", &report.run_id);
+            porcelain::record("stages", report.stages.len());
+            porcelain::record("failed_stages", report.failed_stages());
+            porcelain::record("run_dir", report.output_dir.display());
+            qprintln!(
+                cli.quiet,
+                "pipeline run {} saved to: {} (seed {})",
+                report.run_id,
+                report.output_dir.display(),
+                report.seed
+            );
+            if report.failed_stages() > 0 {
+                return Err(anyhow!(
+                    "{} pipeline stage(s) failed; see {}",
+                    report.failed_stages(),
+                    report.output_dir.join("pipeline.json").display()
+                ));
+            }
+        }
+
         Commands::Analyze {
             report: report_path,
         } => {
@@ -1436,7 +4095,9 @@ fn run_main() -> Result<()> {
             let content = fs::read_to_string(&report_path)?;
             let crash: CrashReport = serde_json::from_str(&content)?;
 
-            let signatures = signatures::detect_signatures(&crash);
+            let mut signatures = signatures::detect_signatures(&crash);
+            signature_engine(cli.signature_feedback.as_deref())?.calibrate(&mut signatures);
+            porcelain::record("signatures", signatures.len());
 
             if !cli.quiet {
                 println!("\nSignatures Detected: {}", signatures.len());
@@ -1445,6 +4106,12 @@ fn run_main() -> Result<()> {
                         "\n  {:?} (confidence: {:.2})",
                         sig.signature_type, sig.confidence
                     );
+                    if let Some(rule) = &sig.rule {
+                        println!("  Rule: {}", rule);
+                    }
+                    if let Some(calibration) = &sig.calibration {
+                        println!("  {}", calibration.describe());
+                    }
                     println!("  Evidence:");
                     for evidence in &sig.evidence {
                         println!("    - {}", evidence);
@@ -1457,39 +4124,303 @@ fn run_main() -> Result<()> {
         }
 
         Commands::Report { report } => {
-            let content = fs::read_to_string(&report)?;
-            let assault_report: AssaultReport = serde_json::from_str(&content)?;
+            let mut assault_report = read_report(&report, cli.strict)?;
+            slice_by_owner(&mut assault_report, cli.owner.as_deref());
+            porcelain::record_assault(&assault_report);
             if !cli.quiet {
                 report::print_report(
                     &assault_report,
                     cli.report_view,
                     cli.expand_sections,
                     cli.pivot,
+                    cli.accessible,
+                    &filter,
                 );
             }
         }
 
         Commands::Tui { report } => {
-            let content = fs::read_to_string(&report)?;
-            let assault_report: AssaultReport = serde_json::from_str(&content)?;
-            ReportTui::run(&assault_report)?;
+            let mut assault_report = read_report(&report, cli.strict)?;
+            slice_by_owner(&mut assault_report, cli.owner.as_deref());
+            let store = report::ArtifactStore::beside(&report);
+            let triage = triage::TriageStore::load(&triage::TriageStore::path_beside(&report))?;
+            ReportTui::run(&assault_report, Some(&store), &triage, cli.accessible)?;
         }
 
         Commands::Gui { report } => {
-            let content = fs::read_to_string(&report)?;
-            let assault_report: AssaultReport = serde_json::from_str(&content)?;
-            report::ReportGui::run(assault_report)?;
+            let mut assault_report = read_report(&report, cli.strict)?;
+            slice_by_owner(&mut assault_report, cli.owner.as_deref());
+            let store = report::ArtifactStore::beside(&report);
+            let triage = triage::TriageStore::load(&triage::TriageStore::path_beside(&report))?;
+            report::ReportGui::run(assault_report, Some(store), triage)?;
+        }
+
+        Commands::Chart {
+            report,
+            output,
+            chart,
+        } => {
+            let mut assault_report = read_report(&report, cli.strict)?;
+            slice_by_owner(&mut assault_report, cli.owner.as_deref());
+            let chart = Chart::build(&assault_report, chart.into());
+            match output {
+                Some(output_path) => {
+                    chart.write(&output_path, ChartFormat::from_path(&output_path)?)?;
+                    porcelain::record("report", output_path.display());
+                    qprintln!(cli.quiet, "Chart written to {}", output_path.display());
+                }
+                None => print!("{}", chart.to_svg()),
+            }
+        }
+
+        Commands::Reports { action } => match action {
+            ReportsAction::Query { query, dir, json } => {
+                let query = storage::query::Query::parse(&query)?;
+                let dir = dir
+                    .or_else(|| cli.store.clone())
+                    .unwrap_or_else(|| PathBuf::from("reports"));
+                let runs = storage::query::query_runs(&dir, &query)?;
+                porcelain::record("runs", runs.len());
+                for run in &runs {
+                    porcelain::record("report", run.path.display());
+                }
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&runs)?);
+                } else if runs.is_empty() {
+                    qprintln!(cli.quiet, "No stored runs match in {}", dir.display());
+                } else {
+                    println!(
+                        "{:<28} {:<20} {:>7} {:>10} {:>6}  {:<24} PATH",
+                        "ID", "RECORDED", "CRASHES", "SIGNATURES", "SCORE", "CRASHED AXES"
+                    );
+                    for run in &runs {
+                        println!(
+                            "{:<28} {:<20} {:>7} {:>10} {:>6.1}  {:<24} {}",
+                            run.id,
+                            &run.recorded_at[..run.recorded_at.len().min(19)],
+                            run.crashes,
+                            run.signatures,
+                            run.robustness_score,
+                            run.crashed_axes.join(","),
+                            run.path.display()
+                        );
+                    }
+                    qprintln!(cli.quiet, "{} run(s) matched", runs.len());
+                }
+            }
+        },
+
+        Commands::Storage { action } => match action {
+            StorageAction::Fsck { dir, dry_run, json } => {
+                let dir = dir
+                    .or_else(|| cli.store.clone())
+                    .unwrap_or_else(|| PathBuf::from("reports"));
+                let report = storage::fsck::fsck(&dir, !dry_run)?;
+                porcelain::record("checked", report.checked);
+                porcelain::record("damaged", report.findings.len());
+                for finding in &report.findings {
+                    porcelain::record("damaged_file", finding.path.display());
+                }
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&report)?);
+                } else {
+                    for finding in &report.findings {
+                        match &finding.quarantined_to {
+                            Some(to) => println!(
+                                "{}: {} (quarantined to {})",
+                                finding.path.display(),
+                                finding.problem,
+                                to.display()
+                            ),
+                            None => {
+                                println!("{}: {}", finding.path.display(), finding.problem)
+                            }
+                        }
+                    }
+                    qprintln!(
+                        cli.quiet,
+                        "fsck {}: {} report(s) checked, {} damaged",
+                        dir.display(),
+                        report.checked,
+                        report.findings.len()
+                    );
+                }
+                if dry_run && !report.findings.is_empty() {
+                    return Err(anyhow!(
+                        "{} damaged file(s) in {}; rerun without --dry-run to quarantine them",
+                        report.findings.len(),
+                        dir.display()
+                    ));
+                }
+            }
+        },
+
+        Commands::Flaky { action } => {
+            let path = cli
+                .flaky_db
+                .clone()
+                .unwrap_or_else(flaky::FlakyStore::default_path);
+            let mut store = flaky::FlakyStore::load(&path)?;
+            match action {
+                FlakyAction::List { json } => {
+                    porcelain::record("buckets", store.buckets.len());
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&store)?);
+                    } else {
+                        for (bucket, entry) in &store.buckets {
+                            println!(
+                                "{}: {} run(s), first {}, last {}",
+                                bucket, entry.occurrences, entry.first_seen, entry.last_seen
+                            );
+                            if !entry.environments.is_empty() {
+                                println!("  environments: {}", entry.environments.join(", "));
+                            }
+                            if let Some(ack) = &entry.acknowledged {
+                                match &ack.note {
+                                    Some(note) => {
+                                        println!("  acknowledged {}: {}", ack.at, note)
+                                    }
+                                    None => println!("  acknowledged {}", ack.at),
+                                }
+                            }
+                        }
+                        qprintln!(
+                            cli.quiet,
+                            "{} crash bucket(s) in {}",
+                            store.buckets.len(),
+                            path.display()
+                        );
+                    }
+                }
+                FlakyAction::Ack { bucket, note } => {
+                    store.acknowledge(&bucket, note, &chrono::Utc::now().to_rfc3339())?;
+                    store.save(&path)?;
+                    porcelain::record("acknowledged", &bucket);
+                    qprintln!(cli.quiet, "Acknowledged {} in {}", bucket, path.display());
+                }
+            }
+        }
+
+        Commands::Annotate {
+            report,
+            finding,
+            state,
+            comment,
+            author,
+            list,
+        } => {
+            let assault_report = read_report(&report, cli.strict)?;
+            let path = triage::TriageStore::path_beside(&report);
+            let mut store = triage::TriageStore::load(&path)?;
+            if list {
+                let ids = triage::finding_ids(&assault_report);
+                porcelain::record("findings", ids.len());
+                for (id, description) in &ids {
+                    match store.current(id) {
+                        Some(annotation) => println!("{}: {}", id, annotation.describe()),
+                        None => println!("{}: untriaged ({})", id, description),
+                    }
+                }
+            } else if let (Some(finding), Some(state)) = (finding, state) {
+                let annotation = triage::Annotation {
+                    state,
+                    author: author.unwrap_or_else(triage::default_author),
+                    at: chrono::Utc::now().to_rfc3339(),
+                    comment,
+                    report: report
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned()),
+                };
+                store.annotate(&assault_report, &finding, annotation)?;
+                store.save(&path)?;
+                porcelain::record("annotated", &finding);
+                qprintln!(
+                    cli.quiet,
+                    "Marked {} as {} in {}",
+                    finding,
+                    state.label(),
+                    path.display()
+                );
+            }
+        }
+
+        Commands::Signatures { action } => {
+            let path = cli
+                .signature_feedback
+                .clone()
+                .unwrap_or_else(SignatureFeedback::default_path);
+            let mut feedback = SignatureFeedback::load(&path)?;
+            match action {
+                SignaturesAction::List { json } => {
+                    let outcomes = feedback.outcomes();
+                    porcelain::record("rules", outcomes.len());
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&feedback)?);
+                    } else {
+                        for (rule, outcome) in &outcomes {
+                            println!(
+                                "{}: {} true / {} false positive(s); a 0.80 confidence becomes {:.2}",
+                                rule,
+                                outcome.true_positives,
+                                outcome.false_positives,
+                                outcome.posterior(0.8)
+                            );
+                        }
+                        qprintln!(
+                            cli.quiet,
+                            "{} label(s) for {} rule(s) in {}",
+                            feedback.labels.len(),
+                            outcomes.len(),
+                            path.display()
+                        );
+                    }
+                }
+                SignaturesAction::Mark {
+                    rule,
+                    verdict,
+                    note,
+                } => {
+                    feedback.mark(
+                        &rule,
+                        verdict.into(),
+                        note,
+                        &chrono::Utc::now().to_rfc3339(),
+                    );
+                    feedback.save(&path)?;
+                    porcelain::record("labeled", &rule);
+                    qprintln!(cli.quiet, "Labeled {} in {}", rule, path.display());
+                }
+            }
         }
 
         Commands::Diff {
             base,
             compare,
             verisimdb_dir,
+            duration_threshold,
+            memory_threshold,
+            history,
+            sigma,
+            show_noise,
+            program,
+            since,
+            until,
         } => {
             let (base_path, compare_path) = match (base, compare) {
                 (Some(base_path), Some(compare_path)) => (base_path, compare_path),
                 (None, None) => {
-                    let latest = latest_reports(&verisimdb_dir, 2)?;
+                    let now = chrono::Utc::now();
+                    let filter = ReportFilter {
+                        kind: Some(ReportKind::Assault),
+                        program,
+                        since: since
+                            .map(|value| storage::query::parse_time(&value, now))
+                            .transpose()?,
+                        until: until
+                            .map(|value| storage::query::parse_time(&value, now))
+                            .transpose()?,
+                    };
+                    let latest = latest_reports_of_one_program(&verisimdb_dir, 2, &filter)?;
                     (latest[0].clone(), latest[1].clone())
                 }
                 _ => {
@@ -1499,23 +4430,168 @@ fn run_main() -> Result<()> {
                 }
             };
 
-            let base_report = load_report(&base_path)?;
-            let compare_report = load_report(&compare_path)?;
-            let diff = format_diff(
+            let mut base_report = read_report(&base_path, cli.strict)?;
+            let mut compare_report = read_report(&compare_path, cli.strict)?;
+            slice_by_owner(&mut base_report, cli.owner.as_deref());
+            slice_by_owner(&mut compare_report, cli.owner.as_deref());
+            let options = DiffOptions {
+                duration_threshold: duration_threshold / 100.0,
+                memory_threshold: memory_threshold / 100.0,
+                history: history
+                    .iter()
+                    .map(|path| read_report(path, cli.strict))
+                    .collect::<Result<Vec<_>>>()?,
+                sigma,
+                show_noise,
+                triage: triage::TriageStore::load(&triage::TriageStore::path_beside(
+                    &compare_path,
+                ))?,
+            };
+            let base_label = base_path.display().to_string();
+            let compare_label = compare_path.display().to_string();
+            porcelain::record("base", &base_label);
+            porcelain::record("compare", &compare_label);
+            match cli.output_format {
+                None => println!(
+                    "{}",
+                    format_diff(
+                        &base_report,
+                        &compare_report,
+                        &base_label,
+                        &compare_label,
+                        &options,
+                    )
+                ),
+                Some(format @ (ReportOutputFormat::Json | ReportOutputFormat::Yaml)) => {
+                    let diff = DiffReport::build(
+                        &base_report,
+                        &compare_report,
+                        &base_label,
+                        &compare_label,
+                        &options,
+                    );
+                    if format == ReportOutputFormat::Json {
+                        println!("{}", serde_json::to_string_pretty(&diff)?);
+                    } else {
+                        print!("{}", serde_yaml::to_string(&diff)?);
+                    }
+                }
+                Some(other) => {
+                    return Err(anyhow!(
+                        "diff supports --output-format json or yaml, not {:?}",
+                        other
+                    ))
+                }
+            }
+        }
+
+        Commands::AssailDiff {
+            base,
+            compare,
+            repo,
+        } => {
+            let base_tree = assail::revision::SourceTree::resolve(&base, &repo)?;
+            let compare_tree = assail::revision::SourceTree::resolve(&compare, &repo)?;
+            let base_report = assail::analyze(&base_tree.path)?;
+            let compare_report = assail::analyze(&compare_tree.path)?;
+            let diff = report::assail_diff::AssailDiff::build(
                 &base_report,
                 &compare_report,
-                &base_path.display().to_string(),
-                &compare_path.display().to_string(),
+                &base_tree.label,
+                &compare_tree.label,
+            );
+            porcelain::record("new_weak_points", diff.findings.added.len());
+            porcelain::record("removed_weak_points", diff.findings.removed.len());
+            match cli.output_format {
+                None => println!("{}", diff.format()),
+                Some(ReportOutputFormat::Json) => {
+                    println!("{}", serde_json::to_string_pretty(&diff)?)
+                }
+                Some(ReportOutputFormat::Yaml) => print!("{}", serde_yaml::to_string(&diff)?),
+                Some(other) => {
+                    return Err(anyhow!(
+                        "assail-diff supports --output-format json or yaml, not {:?}",
+                        other
+                    ))
+                }
+            }
+        }
+
+        Commands::Manifest {
+            action: Some(ManifestAction::Lint { path, json }),
+            ..
+        } => {
+            let diagnostics = a2ml::lint::lint_file(&path)?;
+            let errors = diagnostics
+                .iter()
+                .filter(|d| d.severity == a2ml::lint::Severity::Error)
+                .count();
+            porcelain::record("errors", errors);
+            porcelain::record("warnings", diagnostics.len() - errors);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&diagnostics)?);
+            } else {
+                for d in &diagnostics {
+                    println!(
+                        "{}:{}:{}: {}: {}",
+                        path.display(),
+                        d.line,
+                        d.column,
+                        d.severity,
+                        d.message
+                    );
+                }
+            }
+            if errors > 0 {
+                return Err(anyhow!(
+                    "{} has {} error(s) and {} warning(s)",
+                    path.display(),
+                    errors,
+                    diagnostics.len() - errors
+                ));
+            }
+            qprintln!(
+                cli.quiet,
+                "{} is well-formed ({} warning(s))",
+                path.display(),
+                diagnostics.len()
             );
-            println!("{}", diff);
         }
 
-        Commands::Manifest { path, output } => {
+        Commands::Manifest {
+            action: Some(ManifestAction::FromNickel { file, output }),
+            ..
+        } => {
+            let source = fs::read_to_string(&file)
+                .with_context(|| format!("reading Nickel manifest {}", file.display()))?;
+            let a2ml = a2ml::nickel::from_nickel(&source)
+                .with_context(|| format!("converting {}", file.display()))?;
+            for d in a2ml::lint::lint(&a2ml) {
+                eprintln!(
+                    "warning: converted manifest {}:{}: {}",
+                    d.line, d.column, d.message
+                );
+            }
+            if let Some(output_path) = output {
+                write_atomic(&output_path, a2ml.as_bytes())?;
+                porcelain::record("report", output_path.display());
+                qprintln!(cli.quiet, "Manifest imported to {}", output_path.display());
+            } else {
+                print!("{}", a2ml);
+            }
+        }
+
+        Commands::Manifest {
+            action: None,
+            path,
+            output,
+        } => {
             let target = path.unwrap_or_else(|| PathBuf::from("AI.a2ml"));
             let manifest = Manifest::load(&target).unwrap_or_default();
             let nickel = manifest.to_nickel();
             if let Some(output_path) = output {
                 fs::write(&output_path, nickel)?;
+                porcelain::record("report", output_path.display());
                 qprintln!(cli.quiet, "Manifest exported to {}", output_path.display());
             } else {
                 println!("{}", nickel);
@@ -1528,7 +4604,16 @@ fn run_main() -> Result<()> {
             output,
         } => {
             let report_kind: ReportBundleKind = kind.into();
+            if cli.strict
+                && matches!(
+                    report_kind,
+                    ReportBundleKind::Assault | ReportBundleKind::Ambush
+                )
+            {
+                report::load_report_strict(&input)?;
+            }
             a2ml::export_report_file(report_kind, &input, &output)?;
+            porcelain::record("report", output.display());
             qprintln!(
                 cli.quiet,
                 "A2ML export [{}] written to {}",
@@ -1553,6 +4638,7 @@ fn run_main() -> Result<()> {
                     ));
                 }
             }
+            porcelain::record("report", output.display());
             qprintln!(
                 cli.quiet,
                 "A2ML import [{}] written to {}",
@@ -1562,9 +4648,10 @@ fn run_main() -> Result<()> {
         }
 
         Commands::Panll { report, output } => {
-            let assault_report = load_report(&report)?;
+            let assault_report = read_report(&report, cli.strict)?;
             let output_path = output.unwrap_or_else(|| PathBuf::from("panll-event-chain.json"));
             panll::write_export(&assault_report, Some(&report), &output_path)?;
+            porcelain::record("report", output_path.display());
             qprintln!(
                 cli.quiet,
                 "PanLL export written to {}",
@@ -1572,6 +4659,155 @@ fn run_main() -> Result<()> {
             );
         }
 
+        Commands::Ci {
+            repo,
+            base,
+            fail_on,
+            sarif,
+            binaries,
+            assault_secs,
+        } => {
+            let base = base
+                .or_else(|| ci::default_base(&repo))
+                .ok_or_else(|| anyhow!("no base ref found; pass --base"))?;
+            let changed = ci::changed_files(&repo, &base)?;
+            qprintln!(
+                cli.quiet,
+                "{} file(s) changed since {}",
+                changed.len(),
+                base
+            );
+
+            let analyzer = assail::Analyzer::new(&repo)?
+                .with_only_files(changed.iter().map(|file| repo.join(file)).collect());
+            let mut report = analyzer.analyze()?;
+            let calibration =
+                open_calibration(cli.calibrate, cli.calibration_file.as_deref(), &repo)?;
+            apply_calibration(&calibration, &mut report, cli.quiet);
+            write_atomic(&sarif, report::sarif::to_sarif_json(&report)?)?;
+            porcelain::record("sarif", sarif.display());
+            qprintln!(cli.quiet, "SARIF written to: {}", sarif.display());
+
+            let fail_on = Severity::from(fail_on);
+            let blocking = ci::blocking(&report, fail_on);
+            for wp in &blocking {
+                qprintln!(cli.quiet, "  {}", report::assail_diff::describe(wp));
+            }
+
+            let sources_changed = changed
+                .iter()
+                .any(|file| Language::detect(file) != Language::Unknown);
+            let mut crashes = 0;
+            if !binaries.is_empty() && sources_changed {
+                let axes = if report.recommended_attacks.is_empty() {
+                    vec![AttackAxis::Cpu, AttackAxis::Memory]
+                } else {
+                    report.recommended_attacks.clone()
+                };
+                qprintln!(
+                    cli.quiet,
+                    "Micro-assault: {} binary(ies), axes {:?}, {}s budget",
+                    binaries.len(),
+                    axes,
+                    assault_secs
+                );
+                let config = ci::micro_assault_config(
+                    binaries,
+                    axes,
+                    Duration::from_secs(assault_secs),
+                    seed,
+                );
+                let results = attack::execute_attack_with_patterns(
+                    config,
+                    report.language,
+                    &report.frameworks,
+                )?;
+                for result in results.iter().filter(|result| !result.crashes.is_empty()) {
+                    crashes += result.crashes.len();
+                    qprintln!(
+                        cli.quiet,
+                        "  {} crashed under {:?} ({} crash(es))",
+                        result.program.display(),
+                        result.axis,
+                        result.crashes.len()
+                    );
+                }
+            }
+
+            porcelain::record("base", &base);
+            porcelain::record("changed_files", changed.len());
+            porcelain::record("weak_points", report.weak_points.len());
+            porcelain::record("blocking", blocking.len());
+            porcelain::record("crashes", crashes);
+            if !blocking.is_empty() || crashes > 0 {
+                return Err(anyhow!(
+                    "ci gate failed: {} weak point(s) at or above {} in changed files, {} crash(es)",
+                    blocking.len(),
+                    fail_on,
+                    crashes
+                ));
+            }
+            qprintln!(
+                cli.quiet,
+                "ci gate passed: {} weak point(s) below {} in changed files",
+                report.weak_points.len(),
+                fail_on
+            );
+        }
+
+        Commands::Lsp => {
+            lsp::run(io::stdin().lock(), io::stdout().lock())?;
+        }
+
+        Commands::Sbom {
+            target,
+            format,
+            output,
+        } => {
+            let (assail_report, assault_report) = read_sbom_source(&target, cli.strict)?;
+            // Stdout may be the SBOM itself, so the notice goes to stderr.
+            if assail_report.manifests.is_empty() && !cli.quiet {
+                eprintln!(
+                    "No dependency manifests recorded for {}; the SBOM lists the target only",
+                    target.display()
+                );
+            }
+            let sbom = report::sbom::to_sbom_json(format, &assail_report, assault_report.as_ref())?;
+            if let Some(output_path) = output {
+                write_atomic(&output_path, &sbom)?;
+                porcelain::record("report", output_path.display());
+                qprintln!(cli.quiet, "SBOM written to {}", output_path.display());
+            } else {
+                println!("{}", sbom);
+            }
+        }
+
+        Commands::Schema {
+            kind,
+            validate,
+            output,
+        } => {
+            let kind = SchemaKind::from(kind);
+            if let Some(path) = validate {
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?;
+                let document = schema::parse_document(&content, &path)?;
+                schema::ensure_valid(kind, &document, &path)?;
+                porcelain::record("valid", path.display());
+                qprintln!(cli.quiet, "{} {} is valid", kind.label(), path.display());
+            } else {
+                let rendered = serde_json::to_string_pretty(&schema::schema(kind))?;
+                if let Some(path) = output {
+                    fs::write(&path, format!("{}\n", rendered))
+                        .with_context(|| format!("writing schema {}", path.display()))?;
+                    porcelain::record("report", path.display());
+                    qprintln!(cli.quiet, "Schema written to {}", path.display());
+                } else {
+                    println!("{}", rendered);
+                }
+            }
+        }
+
         Commands::Help { command } => {
             let mut app = Cli::command();
             match command {
@@ -1613,12 +4849,11 @@ fn run_main() -> Result<()> {
                 output: output.clone(),
                 findings_only,
                 min_findings,
-                sarif: cli.output_format == report::output::ReportOutputFormat::Sarif,
+                sarif: cli.output_format == Some(ReportOutputFormat::Sarif),
                 cache_file: cache_file.clone(),
             };
 
-            if cache_file.is_some() && !cli.quiet {
-                let cf = cache_file.as_ref().unwrap();
+            if let (Some(cf), false) = (cache_file.as_ref(), cli.quiet) {
                 if cf.exists() {
                     println!("Incremental mode: loading cache from {}", cf.display());
                 } else {
@@ -1627,6 +4862,11 @@ fn run_main() -> Result<()> {
             }
 
             let report = assemblyline::run(&config)?;
+            porcelain::record("repos_scanned", report.repos_scanned);
+            porcelain::record("repos_with_findings", report.repos_with_findings);
+            porcelain::record("repos_skipped", report.repos_skipped);
+            porcelain::record("weak_points", report.total_weak_points);
+            porcelain::record("critical", report.total_critical);
             assemblyline::print_summary(&report, cli.quiet);
 
             if report.repos_skipped > 0 && !cli.quiet {
@@ -1638,6 +4878,7 @@ fn run_main() -> Result<()> {
 
             if let Some(out_path) = output {
                 assemblyline::write_report(&report, &out_path)?;
+                porcelain::record("report", out_path.display());
                 if !cli.quiet {
                     println!("Report written to {}", out_path.display());
                 }
@@ -1651,6 +4892,7 @@ fn run_main() -> Result<()> {
                     &storage_modes,
                 )?;
                 for path in stored {
+                    porcelain::record("report", path.display());
                     qprintln!(cli.quiet, "Stored report: {}", path.display());
                 }
             }
@@ -1667,7 +4909,11 @@ fn run_main() -> Result<()> {
             } else {
                 manifest.clone()
             };
-            diagnostics::run_self_diagnostics(&diag_manifest)?;
+            let store = cli
+                .store
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("reports"));
+            diagnostics::run_self_diagnostics(&diag_manifest, &store)?;
             return Ok(());
         }
 
@@ -1694,10 +4940,14 @@ fn run_main() -> Result<()> {
 
             let output_path = output.unwrap_or_else(|| PathBuf::from("reports/notification.md"));
             notify::write_notification(&asmline_report, &config, &output_path)?;
+            porcelain::record("report", output_path.display());
             qprintln!(cli.quiet, "Notification written to: {}", output_path.display());
 
             if create_issues {
                 let created = notify::create_github_issues(&asmline_report, &config)?;
+                for url in &created {
+                    porcelain::record("issue", url);
+                }
                 qprintln!(cli.quiet, "Created {} GitHub issues", created.len());
                 for url in &created {
                     qprintln!(cli.quiet, "  {}", url);
@@ -1734,7 +4984,7 @@ fn run_main() -> Result<()> {
                 .migration_metrics
                 .clone()
                 .unwrap_or_else(|| {
-                    eprintln!("warning: target does not appear to be a ReScript project");
+                    tracing::warn!("target does not appear to be a ReScript project");
                     // Return empty metrics as fallback
                     types::MigrationMetrics {
                         deprecated_api_count: 0,
@@ -1769,13 +5019,13 @@ fn run_main() -> Result<()> {
                         qprintln!(cli.quiet, "Build time: {}ms", elapsed.as_millis());
                     }
                     Ok(out) => {
-                        eprintln!(
-                            "warning: rescript build failed (exit {})",
+                        tracing::warn!(
+                            "rescript build failed (exit {})",
                             out.status.code().unwrap_or(-1)
                         );
                     }
                     Err(e) => {
-                        eprintln!("warning: could not run rescript build: {}", e);
+                        tracing::warn!("could not run rescript build: {}", e);
                     }
                 }
             }
@@ -1799,7 +5049,7 @@ fn run_main() -> Result<()> {
                     metrics.bundle_size_bytes = Some(total);
                     qprintln!(cli.quiet, "Bundle size: {} bytes", total);
                 } else {
-                    eprintln!("warning: lib/ directory not found (run build first?)");
+                    tracing::warn!("lib/ directory not found (run build first?)");
                 }
             }
 
@@ -1814,7 +5064,8 @@ fn run_main() -> Result<()> {
             let json = serde_json::to_string_pretty(&snapshot)?;
 
             if let Some(out_path) = output {
-                fs::write(&out_path, &json)?;
+                write_atomic(&out_path, &json)?;
+                porcelain::record("report", out_path.display());
                 qprintln!(cli.quiet, "Snapshot written to: {}", out_path.display());
             } else {
                 println!("{}", json);
@@ -1847,7 +5098,8 @@ fn run_main() -> Result<()> {
             };
 
             if let Some(out_path) = output {
-                fs::write(&out_path, &content)?;
+                write_atomic(&out_path, &content)?;
+                porcelain::record("report", out_path.display());
                 qprintln!(cli.quiet, "Migration diff written to: {}", out_path.display());
             } else {
                 println!("{}", content);
@@ -11,6 +11,7 @@
 //! - GitHub issues via `gh issue create` (optional)
 
 use crate::assemblyline::AssemblylineReport;
+use crate::storage::write_atomic;
 use crate::types::Severity;
 use anyhow::{Context, Result};
 use std::fs;
@@ -171,7 +172,7 @@ pub fn write_notification(
     if let Some(parent) = output.parent() {
         fs::create_dir_all(parent)?;
     }
-    fs::write(output, &markdown)
+    write_atomic(output, &markdown)
         .with_context(|| format!("writing notification to {}", output.display()))?;
     Ok(())
 }
@@ -257,16 +258,14 @@ pub fn create_github_issues(
             }
             Ok(o) => {
                 let stderr = String::from_utf8_lossy(&o.stderr);
-                eprintln!(
-                    "Warning: failed to create issue for {}: {}",
-                    result.repo_name, stderr
+                tracing::warn!(
+                    "failed to create issue for {}: {}",
+                    result.repo_name,
+                    stderr
                 );
             }
             Err(e) => {
-                eprintln!(
-                    "Warning: gh not available for {}: {}",
-                    result.repo_name, e
-                );
+                tracing::warn!("gh not available for {}: {}", result.repo_name, e);
             }
         }
     }
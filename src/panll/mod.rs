@@ -2,10 +2,10 @@
 
 //! PanLL export helpers.
 
-use crate::types::{AssaultReport, AttackAxis, Severity, WeakPointCategory};
+use crate::storage::write_atomic;
+use crate::types::{AssaultReport, Severity, WeakPointCategory};
 use anyhow::{Context, Result};
 use serde::Serialize;
-use std::fs;
 use std::path::Path;
 
 #[derive(Debug, Serialize)]
@@ -70,7 +70,7 @@ fn export_report(report: &AssaultReport, report_path: Option<&Path>) -> PanllExp
             let status = if event.ran { "ran" } else { "skipped" };
             event_chain.push(PanllEvent {
                 id: event.id.clone(),
-                axis: axis_label(event.axis),
+                axis: event.axis.to_string(),
                 start_ms: Some(event.start_offset.as_millis() as u64),
                 duration_ms: event.duration.as_millis() as u64,
                 intensity: format!("{:?}", event.intensity),
@@ -89,8 +89,8 @@ fn export_report(report: &AssaultReport, report_path: Option<&Path>) -> PanllExp
                 "failed"
             };
             event_chain.push(PanllEvent {
-                id: format!("attack-{}-{}", axis_label(result.axis), index + 1),
-                axis: axis_label(result.axis),
+                id: format!("attack-{}-{}", result.axis, index + 1),
+                axis: result.axis.to_string(),
                 start_ms: None,
                 duration_ms: result.duration.as_millis() as u64,
                 intensity: "unknown".to_string(),
@@ -139,7 +139,7 @@ pub fn write_export(
 ) -> Result<()> {
     let export = export_report(report, report_path);
     let json = serde_json::to_string_pretty(&export)?;
-    fs::write(output, json)
+    write_atomic(output, json)
         .with_context(|| format!("writing panll export {}", output.display()))?;
     Ok(())
 }
@@ -202,7 +202,7 @@ fn extract_constraints(report: &AssaultReport) -> Vec<PanllConstraint> {
                 id: format!("attack-fail-{}", id_counter),
                 description: format!(
                     "Failed {} stress test: {} crashes, {} signatures detected",
-                    axis_label(result.axis),
+                    result.axis,
                     crash_count,
                     result.signatures_detected.len()
                 ),
@@ -241,9 +241,7 @@ fn extract_constraints(report: &AssaultReport) -> Vec<PanllConstraint> {
             id_counter += 1;
             constraints.push(PanllConstraint {
                 id: format!("migration-config-{}", id_counter),
-                description: format!(
-                    "bsconfig.json still present (migrate to rescript.json)"
-                ),
+                description: "bsconfig.json still present (migrate to rescript.json)".to_string(),
             });
         }
 
@@ -306,17 +304,10 @@ fn category_label(cat: WeakPointCategory) -> &'static str {
         WeakPointCategory::UncheckedError => "unchecked-error",
         WeakPointCategory::InfiniteRecursion => "infinite-recursion",
         WeakPointCategory::UnsafeTypeCoercion => "unsafe-coercion",
+        WeakPointCategory::UnsafeTransmute => "transmute",
+        WeakPointCategory::RawPointerDeref => "raw-deref",
+        WeakPointCategory::MutableStatic => "static-mut",
+        WeakPointCategory::ManualSendSync => "manual-send-sync",
+        WeakPointCategory::VulnerableDependency => "vuln-dep",
     }
 }
-
-fn axis_label(axis: AttackAxis) -> String {
-    match axis {
-        AttackAxis::Cpu => "cpu",
-        AttackAxis::Memory => "memory",
-        AttackAxis::Disk => "disk",
-        AttackAxis::Network => "network",
-        AttackAxis::Concurrency => "concurrency",
-        AttackAxis::Time => "time",
-    }
-    .to_string()
-}
@@ -0,0 +1,950 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Declarative campaign pipelines (`panic-attack run`)
+//!
+//! A pipeline file chains the subcommands of a campaign — assail, attack or ambush,
+//! amuck, axial, adjudicate — as stages with dependencies (`needs`) and conditions
+//! (`when: "verdict != fail"`). Stages run in dependency order under one run id and seed,
+//! and every stage writes `<stage id>.json` into `<output_dir>/<run_id>/` beside a
+//! `pipeline.json` manifest recording what ran, what was skipped and why.
+//!
+//! A stage whose dependency failed or was skipped is skipped too; a failing stage does not
+//! stop stages that do not depend on it.
+
+use crate::adjudicate::{self, AdjudicateConfig};
+use crate::amuck::clean::KeepMode;
+use crate::amuck::{self, AmuckConfig, AmuckPreset, ExecutionCommand as AmuckExecutionCommand};
+use crate::axial::{self, AxialConfig, ExecutionCommand as AxialExecutionCommand, ProbeConfig};
+use crate::i18n::Lang;
+use crate::report::{self, ReportOutputFormat, ReproOptions};
+use crate::run_as::RunAs;
+use crate::storage::write_atomic;
+use crate::types::*;
+use crate::{ambush, assail, attack};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PipelineSpec {
+    /// Fixed run id; by default one is derived from the start time and seed.
+    #[serde(default)]
+    pub run_id: Option<String>,
+    #[serde(default = "default_output_dir")]
+    pub output_dir: PathBuf,
+    /// Program (or file) the stages work on unless they name their own.
+    pub target: PathBuf,
+    /// Source tree for assail when `target` is a binary.
+    #[serde(default)]
+    pub source: Option<PathBuf>,
+    pub stages: Vec<StageSpec>,
+}
+
+fn default_output_dir() -> PathBuf {
+    PathBuf::from("runs")
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StageSpec {
+    pub id: String,
+    #[serde(flatten)]
+    pub kind: StageKind,
+    #[serde(default)]
+    pub needs: Vec<String>,
+    /// Condition checked just before the stage runs, e.g. `verdict != fail`.
+    #[serde(default)]
+    pub when: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum StageKind {
+    Assail,
+    #[serde(alias = "assault")]
+    Attack(AttackStage),
+    Ambush(AttackStage),
+    Amuck(AmuckStage),
+    #[serde(alias = "audience")]
+    Axial(AxialStage),
+    Adjudicate,
+}
+
+impl StageKind {
+    fn label(&self) -> &'static str {
+        match self {
+            StageKind::Assail => "assail",
+            StageKind::Attack(_) => "attack",
+            StageKind::Ambush(_) => "ambush",
+            StageKind::Amuck(_) => "amuck",
+            StageKind::Axial(_) => "axial",
+            StageKind::Adjudicate => "adjudicate",
+        }
+    }
+
+    /// Whether adjudicate reads this stage's report.
+    fn adjudicable(&self) -> bool {
+        matches!(
+            self,
+            StageKind::Attack(_) | StageKind::Ambush(_) | StageKind::Amuck(_)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AttackStage {
+    #[serde(default)]
+    pub target: Option<PathBuf>,
+    /// Axes to stress; all of them when empty.
+    #[serde(default)]
+    pub axes: Vec<AttackAxis>,
+    /// Preset name or 0.0-1.0 value (default medium).
+    #[serde(default)]
+    pub intensity: Option<IntensityLevel>,
+    #[serde(default = "default_duration_secs")]
+    pub duration_secs: u64,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+fn default_duration_secs() -> u64 {
+    30
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AmuckStage {
+    #[serde(default)]
+    pub target: Option<PathBuf>,
+    #[serde(default)]
+    pub spec: Option<PathBuf>,
+    #[serde(default)]
+    pub preset: AmuckPresetSpec,
+    #[serde(default = "default_max_combinations")]
+    pub max_combinations: usize,
+    #[serde(default)]
+    pub exec: Option<ExecSpec>,
+    #[serde(default)]
+    pub binary: bool,
+    #[serde(default)]
+    pub keep: KeepMode,
+    /// Account the exec program runs as (see `run_as`).
+    #[serde(default)]
+    pub run_as: Option<String>,
+    #[serde(default)]
+    pub trace_syscalls: bool,
+}
+
+fn default_max_combinations() -> usize {
+    16
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AmuckPresetSpec {
+    Light,
+    #[default]
+    Dangerous,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AxialStage {
+    #[serde(default)]
+    pub target: Option<PathBuf>,
+    #[serde(default)]
+    pub exec: Option<ExecSpec>,
+    #[serde(default = "default_repeat")]
+    pub repeat: usize,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    #[serde(default)]
+    pub grep: Vec<String>,
+    #[serde(default)]
+    pub journal: Vec<String>,
+    #[serde(default)]
+    pub syslog: Vec<PathBuf>,
+    #[serde(default)]
+    pub probe_url: Option<String>,
+}
+
+fn default_repeat() -> usize {
+    1
+}
+
+fn default_timeout_secs() -> u64 {
+    60
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecSpec {
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// Command-line overrides for a run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub run_id: Option<String>,
+    pub output_dir: Option<PathBuf>,
+    pub seed: u64,
+}
+
+/// The `pipeline.json` manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineReport {
+    pub run_id: String,
+    pub created_at: String,
+    pub finished_at: String,
+    pub seed: u64,
+    pub target: PathBuf,
+    pub output_dir: PathBuf,
+    pub stages: Vec<StageRecord>,
+    /// Verdict of the last adjudicate stage that ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+}
+
+impl PipelineReport {
+    pub fn failed_stages(&self) -> usize {
+        self.stages
+            .iter()
+            .filter(|stage| stage.status == StageStatus::Failed)
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageRecord {
+    pub id: String,
+    pub kind: String,
+    pub status: StageStatus,
+    /// Why the stage was skipped, or the error it failed with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    #[serde(default)]
+    pub duration_ms: u128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    /// Stable code of the error a failed stage stopped with (see `crate::error`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StageStatus {
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+impl StageStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StageStatus::Succeeded => "succeeded",
+            StageStatus::Failed => "failed",
+            StageStatus::Skipped => "skipped",
+        }
+    }
+}
+
+pub fn load(path: &Path) -> Result<PipelineSpec> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("reading pipeline {}", path.display()))?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&content)
+            .with_context(|| format!("parsing json pipeline {}", path.display())),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+            .with_context(|| format!("parsing yaml pipeline {}", path.display())),
+        _ => Err(anyhow!(
+            "unsupported pipeline extension for {}",
+            path.display()
+        )),
+    }
+}
+
+/// Run every stage of `spec` and write the manifest; stage failures are recorded, not
+/// returned.
+pub fn run(spec: &PipelineSpec, options: &RunOptions) -> Result<PipelineReport> {
+    let order = plan(spec)?;
+    let conditions = spec
+        .stages
+        .iter()
+        .map(|stage| stage.when.as_deref().map(parse_condition).transpose())
+        .collect::<Result<Vec<_>>>()?;
+
+    let created_at = chrono::Utc::now();
+    let run_id = options
+        .run_id
+        .clone()
+        .or_else(|| spec.run_id.clone())
+        .unwrap_or_else(|| {
+            format!(
+                "{}-{:016x}",
+                created_at.format("%Y%m%d%H%M%S"),
+                options.seed
+            )
+        });
+    check_id("run id", &run_id)?;
+    let _pipeline = tracing::info_span!("pipeline", run_id = %run_id).entered();
+    let output_dir = options
+        .output_dir
+        .clone()
+        .unwrap_or_else(|| spec.output_dir.clone())
+        .join(&run_id);
+    fs::create_dir_all(&output_dir)
+        .with_context(|| format!("creating run directory {}", output_dir.display()))?;
+
+    let mut state = RunState {
+        dir: output_dir.clone(),
+        seed: options.seed,
+        target: spec.target.clone(),
+        source: spec.source.clone(),
+        assail: None,
+        records: HashMap::new(),
+        outputs: Vec::new(),
+        crashes: 0,
+        signatures: 0,
+        verdict: None,
+    };
+    let mut records = Vec::new();
+    for index in order {
+        let stage = &spec.stages[index];
+        let _stage = tracing::info_span!("stage", stage = %stage.id).entered();
+        let mut record = StageRecord {
+            id: stage.id.clone(),
+            kind: stage.kind.label().to_string(),
+            status: StageStatus::Skipped,
+            reason: None,
+            output: None,
+            duration_ms: 0,
+            verdict: None,
+            error_code: None,
+        };
+        if let Some(reason) = blocked_by(stage, &state) {
+            tracing::info!("skipping stage: {}", reason);
+            record.reason = Some(reason);
+        } else if let Some(reason) = conditions[index]
+            .as_ref()
+            .and_then(|condition| unmet(condition, &state))
+        {
+            tracing::info!("skipping stage: {}", reason);
+            record.reason = Some(reason);
+        } else {
+            let started = Instant::now();
+            match run_stage(stage, &mut state) {
+                Ok((output, verdict)) => {
+                    record.status = StageStatus::Succeeded;
+                    record.output = Some(output);
+                    record.verdict = verdict;
+                }
+                Err(err) => {
+                    tracing::warn!("stage failed: {:#}", err);
+                    record.status = StageStatus::Failed;
+                    record.reason = Some(format!("{:#}", err));
+                    record.error_code = Some(crate::error::code(&err).to_string());
+                }
+            }
+            record.duration_ms = started.elapsed().as_millis();
+        }
+        state.records.insert(stage.id.clone(), record.status);
+        records.push(record);
+    }
+
+    let report = PipelineReport {
+        run_id,
+        created_at: created_at.to_rfc3339(),
+        finished_at: chrono::Utc::now().to_rfc3339(),
+        seed: options.seed,
+        target: spec.target.clone(),
+        output_dir: output_dir.clone(),
+        stages: records,
+        verdict: state.verdict,
+    };
+    let manifest = output_dir.join("pipeline.json");
+    write_atomic(&manifest, serde_json::to_string_pretty(&report)?)
+        .with_context(|| format!("writing {}", manifest.display()))?;
+    Ok(report)
+}
+
+/// Stage indices in an order that honours `needs`, keeping file order where it is free.
+fn plan(spec: &PipelineSpec) -> Result<Vec<usize>> {
+    if spec.stages.is_empty() {
+        return Err(anyhow!("pipeline has no stages"));
+    }
+    let mut ids = HashSet::new();
+    for stage in &spec.stages {
+        check_id("stage id", &stage.id)?;
+        if stage.id == "pipeline" {
+            return Err(anyhow!("stage id `pipeline` is reserved for the manifest"));
+        }
+        if !ids.insert(stage.id.as_str()) {
+            return Err(anyhow!("duplicate stage id `{}`", stage.id));
+        }
+    }
+    for stage in &spec.stages {
+        for need in &stage.needs {
+            if !ids.contains(need.as_str()) {
+                return Err(anyhow!(
+                    "stage `{}` needs unknown stage `{}`",
+                    stage.id,
+                    need
+                ));
+            }
+            if need == &stage.id {
+                return Err(anyhow!("stage `{}` needs itself", stage.id));
+            }
+        }
+        if let Some(raw) = &stage.when {
+            if let Variable::Status(other) = parse_condition(raw)?.variable {
+                if !ids.contains(other.as_str()) {
+                    return Err(anyhow!(
+                        "stage `{}` condition refers to unknown stage `{}`",
+                        stage.id,
+                        other
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut placed: HashSet<&str> = HashSet::new();
+    let mut order = Vec::with_capacity(spec.stages.len());
+    while order.len() < spec.stages.len() {
+        let next = spec.stages.iter().position(|stage| {
+            !placed.contains(stage.id.as_str())
+                && stage
+                    .needs
+                    .iter()
+                    .all(|need| placed.contains(need.as_str()))
+        });
+        let Some(index) = next else {
+            let stuck: Vec<&str> = spec
+                .stages
+                .iter()
+                .filter(|stage| !placed.contains(stage.id.as_str()))
+                .map(|stage| stage.id.as_str())
+                .collect();
+            return Err(anyhow!(
+                "stage dependencies form a cycle among: {}",
+                stuck.join(", ")
+            ));
+        };
+        placed.insert(spec.stages[index].id.as_str());
+        order.push(index);
+    }
+    Ok(order)
+}
+
+/// Ids name files in the run directory.
+fn check_id(what: &str, id: &str) -> Result<()> {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        || id.starts_with('.')
+    {
+        return Err(anyhow!(
+            "{} `{}` must be letters, digits, '-', '_' or '.'",
+            what,
+            id
+        ));
+    }
+    Ok(())
+}
+
+struct RunState {
+    dir: PathBuf,
+    seed: u64,
+    target: PathBuf,
+    source: Option<PathBuf>,
+    assail: Option<AssailReport>,
+    records: HashMap<String, StageStatus>,
+    /// Reports adjudicate can read, by stage id, in run order.
+    outputs: Vec<(String, PathBuf)>,
+    crashes: usize,
+    signatures: usize,
+    verdict: Option<String>,
+}
+
+fn blocked_by(stage: &StageSpec, state: &RunState) -> Option<String> {
+    stage.needs.iter().find_map(|need| {
+        let status = state.records.get(need).copied()?;
+        let outcome = match status {
+            StageStatus::Succeeded => return None,
+            StageStatus::Failed => "failed",
+            StageStatus::Skipped => "was skipped",
+        };
+        Some(format!("needs `{}`, which {}", need, outcome))
+    })
+}
+
+fn run_stage(stage: &StageSpec, state: &mut RunState) -> Result<(PathBuf, Option<String>)> {
+    let path = state.dir.join(format!("{}.json", stage.id));
+    let mut verdict = None;
+    match &stage.kind {
+        StageKind::Assail => {
+            let report = assail::analyze(state.source.as_ref().unwrap_or(&state.target))?;
+            write_atomic(&path, serde_json::to_string_pretty(&report)?)
+                .with_context(|| format!("writing {}", path.display()))?;
+            state.assail = Some(report);
+        }
+        StageKind::Attack(options) | StageKind::Ambush(options) => {
+            let assail_report = match &state.assail {
+                Some(report) => report.clone(),
+                None => assail::analyze(state.source.as_ref().unwrap_or(&state.target))?,
+            };
+            let config = attack_config(options, &state.target, state.seed)?;
+            let results = if matches!(stage.kind, StageKind::Ambush(_)) {
+                ambush::execute(config)?
+            } else {
+                attack::execute_attack_with_patterns(
+                    config,
+                    assail_report.language,
+                    &assail_report.frameworks,
+                )?
+            };
+            let mut report = report::generate_assault_report(assail_report, results)?;
+            report.seed = Some(state.seed);
+            report.targets = attack::provenance::capture_all(&report.attack_results);
+            report.host_environment = Some(attack::environment::capture());
+            report::save_report_with_repro(
+                &report,
+                &path,
+                ReportOutputFormat::Json,
+                ReproOptions::default(),
+            )?;
+            state.crashes += report.total_crashes;
+            state.signatures += report.total_signatures;
+        }
+        StageKind::Amuck(options) => {
+            let report = amuck::run(AmuckConfig {
+                target: options
+                    .target
+                    .clone()
+                    .unwrap_or_else(|| state.source.clone().unwrap_or(state.target.clone())),
+                spec_path: options.spec.clone(),
+                preset: match options.preset {
+                    AmuckPresetSpec::Light => AmuckPreset::Light,
+                    AmuckPresetSpec::Dangerous => AmuckPreset::Dangerous,
+                },
+                max_combinations: options.max_combinations,
+                output_dir: state.dir.join(&stage.id),
+                execute: options.exec.as_ref().map(|exec| AmuckExecutionCommand {
+                    program: exec.program.clone(),
+                    args: exec.args.clone(),
+                }),
+                seed: state.seed,
+                binary: options.binary,
+                keep: options.keep,
+                run_as: options.run_as.as_deref().map(RunAs::resolve).transpose()?,
+                trace_syscalls: options.trace_syscalls,
+            })?;
+            amuck::write_report(&report, &path)?;
+        }
+        StageKind::Axial(options) => {
+            let report = axial::run(AxialConfig {
+                target: options.target.clone().unwrap_or(state.target.clone()),
+                execute: options.exec.as_ref().map(|exec| AxialExecutionCommand {
+                    program: exec.program.clone(),
+                    args: exec.args.clone(),
+                }),
+                repeat: options.repeat,
+                timeout_secs: options.timeout_secs,
+                reports: stage_inputs(stage, state),
+                journal_units: options.journal.clone(),
+                syslog_files: options.syslog.clone(),
+                probe: options.probe_url.clone().map(|url| ProbeConfig {
+                    url,
+                    interval: Duration::from_millis(250),
+                }),
+                head_lines: 20,
+                tail_lines: 20,
+                grep_patterns: options.grep.clone(),
+                agrep_patterns: Vec::new(),
+                agrep_distance: 2,
+                lang: Lang::default(),
+                aspell: false,
+                aspell_lang: None,
+                spell_dict: None,
+                seed: state.seed,
+            })?;
+            axial::write_report(&report, &path)?;
+            axial::write_markdown(&report, &path.with_extension("md"))?;
+        }
+        StageKind::Adjudicate => {
+            let reports = stage_inputs(stage, state);
+            if reports.is_empty() {
+                return Err(anyhow!("no attack, ambush or amuck report to adjudicate"));
+            }
+            let report = adjudicate::run(AdjudicateConfig {
+                reports,
+                previous: None,
+                seed: state.seed,
+            })?;
+            adjudicate::write_report(&report, &path)?;
+            state.verdict = Some(report.verdict.clone());
+            verdict = Some(report.verdict);
+        }
+    }
+    if stage.kind.adjudicable() {
+        state.outputs.push((stage.id.clone(), path.clone()));
+    }
+    Ok((path, verdict))
+}
+
+/// Reports a stage reads: those of its `needs`, or every one so far when it has none.
+fn stage_inputs(stage: &StageSpec, state: &RunState) -> Vec<PathBuf> {
+    state
+        .outputs
+        .iter()
+        .filter(|(id, _)| stage.needs.is_empty() || stage.needs.contains(id))
+        .map(|(_, path)| path.clone())
+        .collect()
+}
+
+fn attack_config(options: &AttackStage, target: &Path, seed: u64) -> Result<AttackConfig> {
+    let intensity = options.intensity.unwrap_or(IntensityLevel::Medium);
+    Ok(AttackConfig {
+        axes: if options.axes.is_empty() {
+            AttackAxis::defaults()
+        } else {
+            options.axes.clone()
+        },
+        duration: Duration::from_secs(options.duration_secs),
+        intensity,
+        target_programs: vec![options.target.clone().unwrap_or(target.to_path_buf())],
+        common_args: options.args.clone(),
+        seed,
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition {
+    variable: Variable,
+    comparison: Comparison,
+    value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Variable {
+    /// Last adjudicate verdict, or an adjudication of the reports written so far.
+    Verdict,
+    Crashes,
+    Signatures,
+    /// `<stage id>.status`
+    Status(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Parse `<variable> <op> <value>` with op one of `== != < <= > >=`.
+fn parse_condition(raw: &str) -> Result<Condition> {
+    const OPERATORS: [(&str, Comparison); 6] = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+    let (lhs, comparison, rhs) = OPERATORS
+        .iter()
+        .find_map(|(op, comparison)| {
+            raw.split_once(op)
+                .map(|(lhs, rhs)| (lhs.trim(), *comparison, rhs.trim()))
+        })
+        .ok_or_else(|| anyhow!("condition `{}` has no comparison operator", raw))?;
+    let value = rhs.trim_matches(|c| c == '"' || c == '\'').to_string();
+    let variable = match lhs {
+        "verdict" => Variable::Verdict,
+        "crashes" => Variable::Crashes,
+        "signatures" => Variable::Signatures,
+        other => match other.strip_suffix(".status") {
+            Some(id) if !id.is_empty() => Variable::Status(id.to_string()),
+            _ => {
+                return Err(anyhow!(
+                    "condition `{}`: unknown variable `{}` (verdict, crashes, signatures, <stage>.status)",
+                    raw,
+                    other
+                ))
+            }
+        },
+    };
+    let allowed: &[&str] = match variable {
+        Variable::Verdict => &["pass", "warn", "fail"],
+        Variable::Status(_) => &["succeeded", "failed", "skipped", "pending"],
+        Variable::Crashes | Variable::Signatures => {
+            value
+                .parse::<usize>()
+                .map_err(|_| anyhow!("condition `{}`: `{}` is not a count", raw, value))?;
+            &[]
+        }
+    };
+    if !allowed.is_empty() {
+        if !allowed.contains(&value.as_str()) {
+            return Err(anyhow!(
+                "condition `{}`: expected one of {}",
+                raw,
+                allowed.join(", ")
+            ));
+        }
+        if !matches!(comparison, Comparison::Eq | Comparison::Ne) {
+            return Err(anyhow!("condition `{}`: only == and != compare words", raw));
+        }
+    }
+    Ok(Condition {
+        variable,
+        comparison,
+        value,
+    })
+}
+
+/// Why `condition` keeps the stage from running, or `None` when it holds.
+fn unmet(condition: &Condition, state: &RunState) -> Option<String> {
+    let actual = match &condition.variable {
+        Variable::Verdict => interim_verdict(state),
+        Variable::Crashes => state.crashes.to_string(),
+        Variable::Signatures => state.signatures.to_string(),
+        Variable::Status(id) => state
+            .records
+            .get(id)
+            .map_or("pending", StageStatus::as_str)
+            .to_string(),
+    };
+    let holds = match (actual.parse::<usize>(), condition.value.parse::<usize>()) {
+        (Ok(actual), Ok(expected)) => compare(actual.cmp(&expected), condition.comparison),
+        _ => compare(
+            actual.as_str().cmp(condition.value.as_str()),
+            condition.comparison,
+        ),
+    };
+    (!holds).then(|| {
+        format!(
+            "condition `{}` does not hold ({} is {})",
+            display_condition(condition),
+            variable_name(&condition.variable),
+            actual
+        )
+    })
+}
+
+fn compare(ordering: std::cmp::Ordering, comparison: Comparison) -> bool {
+    use std::cmp::Ordering::*;
+    match comparison {
+        Comparison::Eq => ordering == Equal,
+        Comparison::Ne => ordering != Equal,
+        Comparison::Lt => ordering == Less,
+        Comparison::Le => ordering != Greater,
+        Comparison::Gt => ordering == Greater,
+        Comparison::Ge => ordering != Less,
+    }
+}
+
+/// The last adjudicate verdict; before any adjudicate stage, the verdict the reports written
+/// so far would get, and `pass` when there are none.
+fn interim_verdict(state: &RunState) -> String {
+    if let Some(verdict) = &state.verdict {
+        return verdict.clone();
+    }
+    let reports: Vec<PathBuf> = state.outputs.iter().map(|(_, path)| path.clone()).collect();
+    if reports.is_empty() {
+        return "pass".to_string();
+    }
+    adjudicate::run(AdjudicateConfig {
+        reports,
+        previous: None,
+        seed: state.seed,
+    })
+    .map(|report| report.verdict)
+    .unwrap_or_else(|_| "fail".to_string())
+}
+
+fn variable_name(variable: &Variable) -> String {
+    match variable {
+        Variable::Verdict => "verdict".to_string(),
+        Variable::Crashes => "crashes".to_string(),
+        Variable::Signatures => "signatures".to_string(),
+        Variable::Status(id) => format!("{}.status", id),
+    }
+}
+
+fn display_condition(condition: &Condition) -> String {
+    let op = match condition.comparison {
+        Comparison::Eq => "==",
+        Comparison::Ne => "!=",
+        Comparison::Lt => "<",
+        Comparison::Le => "<=",
+        Comparison::Gt => ">",
+        Comparison::Ge => ">=",
+    };
+    format!(
+        "{} {} {}",
+        variable_name(&condition.variable),
+        op,
+        condition.value
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn spec(yaml: &str) -> PipelineSpec {
+        serde_yaml::from_str(yaml).expect("pipeline should parse")
+    }
+
+    #[test]
+    fn stages_run_in_dependency_order() {
+        let pipeline = spec(
+            r#"
+target: ./app
+stages:
+  - { id: verdict, kind: adjudicate, needs: [stress, mutate] }
+  - { id: stress, kind: assault, needs: [scan], axes: [cpu], intensity: light }
+  - { id: scan, kind: assail }
+  - { id: mutate, kind: amuck, preset: light }
+  - { id: watch, kind: audience, needs: [stress], when: "verdict != fail" }
+"#,
+        );
+        let ids: Vec<&str> = plan(&pipeline)
+            .unwrap()
+            .into_iter()
+            .map(|index| pipeline.stages[index].id.as_str())
+            .collect();
+        assert_eq!(ids, ["scan", "stress", "mutate", "verdict", "watch"]);
+        assert!(matches!(pipeline.stages[1].kind, StageKind::Attack(_)));
+        assert!(matches!(pipeline.stages[4].kind, StageKind::Axial(_)));
+
+        let cyclic = spec(
+            r#"
+target: ./app
+stages:
+  - { id: a, kind: assail, needs: [b] }
+  - { id: b, kind: assail, needs: [a] }
+"#,
+        );
+        let err = plan(&cyclic).unwrap_err().to_string();
+        assert!(err.contains("cycle among: a, b"), "{}", err);
+        let unknown = spec("target: x\nstages:\n  - { id: a, kind: assail, needs: [zz] }\n");
+        assert!(plan(&unknown).is_err());
+        let duplicate =
+            spec("target: x\nstages:\n  - { id: a, kind: assail }\n  - { id: a, kind: assail }\n");
+        assert!(plan(&duplicate).is_err());
+    }
+
+    #[test]
+    fn conditions_parse_and_compare() {
+        let condition = parse_condition("verdict != 'fail'").unwrap();
+        assert_eq!(condition.variable, Variable::Verdict);
+        assert_eq!(condition.comparison, Comparison::Ne);
+        assert_eq!(condition.value, "fail");
+        assert_eq!(
+            parse_condition("crashes >= 2").unwrap().comparison,
+            Comparison::Ge
+        );
+        assert!(parse_condition("verdict == maybe").is_err());
+        assert!(parse_condition("verdict > pass").is_err());
+        assert!(parse_condition("crashes > lots").is_err());
+        assert!(parse_condition("weather == fine").is_err());
+
+        let mut state = RunState {
+            dir: PathBuf::new(),
+            seed: 0,
+            target: PathBuf::new(),
+            source: None,
+            assail: None,
+            records: HashMap::new(),
+            outputs: Vec::new(),
+            crashes: 3,
+            signatures: 0,
+            verdict: None,
+        };
+        assert!(unmet(&parse_condition("crashes >= 2").unwrap(), &state).is_none());
+        assert!(unmet(&parse_condition("crashes < 2").unwrap(), &state).is_some());
+        // No reports yet: nothing to fail on.
+        assert!(unmet(&parse_condition("verdict != fail").unwrap(), &state).is_none());
+        state.verdict = Some("fail".to_string());
+        let reason = unmet(&parse_condition("verdict != fail").unwrap(), &state).unwrap();
+        assert!(reason.contains("verdict is fail"), "{}", reason);
+        assert!(unmet(&parse_condition("scan.status == pending").unwrap(), &state).is_none());
+    }
+
+    #[test]
+    fn run_writes_stage_outputs_and_manifest() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let target = dir.path().join("main.rs");
+        fs::write(
+            &target,
+            "fn main() {\n    let v: Option<u8> = None;\n    v.unwrap();\n}\n",
+        )
+        .expect("target should write");
+        let pipeline = spec(&format!(
+            r#"
+target: {}
+stages:
+  - {{ id: scan, kind: assail }}
+  - {{ id: mutate, kind: amuck, needs: [scan], preset: light, max_combinations: 2 }}
+  - {{ id: verdict, kind: adjudicate, needs: [mutate] }}
+  - {{ id: rerun, kind: amuck, needs: [verdict], when: "verdict == fail", preset: light }}
+  - {{ id: after-rerun, kind: adjudicate, needs: [rerun] }}
+"#,
+            target.display()
+        ));
+        let report = run(
+            &pipeline,
+            &RunOptions {
+                run_id: Some("smoke".to_string()),
+                output_dir: Some(dir.path().join("runs")),
+                seed: 7,
+            },
+        )
+        .expect("pipeline should run");
+
+        let run_dir = dir.path().join("runs/smoke");
+        assert_eq!(report.output_dir, run_dir);
+        assert!(run_dir.join("pipeline.json").exists());
+        assert!(run_dir.join("scan.json").exists());
+        assert!(run_dir.join("mutate.json").exists());
+        let statuses: Vec<StageStatus> = report.stages.iter().map(|stage| stage.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                StageStatus::Succeeded,
+                StageStatus::Succeeded,
+                StageStatus::Succeeded,
+                StageStatus::Skipped,
+                StageStatus::Skipped,
+            ]
+        );
+        let verdict = report
+            .verdict
+            .clone()
+            .expect("adjudicate should record a verdict");
+        assert_ne!(verdict, "fail");
+        assert!(report.stages[3]
+            .reason
+            .as_deref()
+            .unwrap()
+            .starts_with("condition `verdict == fail`"));
+        assert_eq!(
+            report.stages[4].reason.as_deref(),
+            Some("needs `rerun`, which was skipped")
+        );
+        assert_eq!(report.failed_stages(), 0);
+    }
+}
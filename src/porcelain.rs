@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! `--porcelain` output contract
+//!
+//! With `--porcelain`, stdout carries nothing but `key=value` lines, whatever the
+//! subcommand: human-facing output is moved to stderr for the duration of the command, and
+//! a summary is printed when it finishes. Every summary starts with `porcelain=<VERSION>`
+//! and `command=<name>` and ends with `status=ok` or `status=error` (plus `error_code=` and
+//! `error=`); commands add their own keys in between. Keys are lowercase snake_case and are
+//! only ever added within a version; renaming or removing one bumps [`VERSION`].
+//!
+//! Values are single-line: `\`, newlines and carriage returns are written as `\\`, `\n`
+//! and `\r`. A key may repeat (one `report=` line per file written).
+
+use crate::types::{AssailReport, AssaultReport, AttackResult, Severity};
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+pub const VERSION: u32 = 1;
+
+static FIELDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+/// Add `key=value` to the summary of the running command.
+pub fn record(key: &str, value: impl Display) {
+    if let Ok(mut fields) = FIELDS.lock() {
+        fields.push((key.to_string(), value.to_string()));
+    }
+}
+
+/// The fields recorded so far, without consuming them.
+pub fn fields() -> Vec<(String, String)> {
+    FIELDS
+        .lock()
+        .map(|fields| fields.clone())
+        .unwrap_or_default()
+}
+
+/// `language`, `weak_points` and per-severity counts of an assail report.
+pub fn record_assail(report: &AssailReport) {
+    record("language", format!("{:?}", report.language).to_lowercase());
+    record("weak_points", report.weak_points.len());
+    for severity in [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+    ] {
+        let count = report
+            .weak_points
+            .iter()
+            .filter(|wp| wp.severity == severity)
+            .count();
+        record(&severity.to_string().to_lowercase(), count);
+    }
+}
+
+/// Run outcome counts and the total crash count of attack/ambush results.
+pub fn record_results(results: &[AttackResult]) {
+    let skipped = results.iter().filter(|result| result.skipped).count();
+    let passed = results
+        .iter()
+        .filter(|result| !result.skipped && result.success)
+        .count();
+    record("runs", results.len());
+    record("passed", passed);
+    record("failed", results.len() - passed - skipped);
+    record("skipped", skipped);
+    record(
+        "crashes",
+        results
+            .iter()
+            .map(|result| result.crashes.len())
+            .sum::<usize>(),
+    );
+}
+
+pub fn record_assault(report: &AssaultReport) {
+    record_assail(&report.assail_report);
+    record_results(&report.attack_results);
+    record("signatures", report.total_signatures);
+    record(
+        "robustness_score",
+        format!("{:.1}", report.overall_assessment.robustness_score),
+    );
+    if let Some(seed) = report.seed {
+        record("seed", seed);
+    }
+    if let Some(diff) = &report.state_diff {
+        record("state_changes", diff.change_count());
+    }
+}
+
+/// Render the summary for `command`; `error` is the code and message of a failure.
+pub fn render(command: &str, error: Option<(&str, String)>) -> String {
+    let fields = FIELDS
+        .lock()
+        .map(|mut fields| std::mem::take(&mut *fields))
+        .unwrap_or_default();
+    let mut out = format!("porcelain={}\ncommand={}\n", VERSION, escape(command));
+    for (key, value) in fields {
+        out.push_str(&format!("{}={}\n", key, escape(&value)));
+    }
+    match error {
+        None => out.push_str("status=ok\n"),
+        Some((code, message)) => {
+            out.push_str("status=error\n");
+            out.push_str(&format!("error_code={}\n", code));
+            out.push_str(&format!("error={}\n", escape(&message)));
+        }
+    }
+    out
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Points stdout at stderr until dropped.
+pub struct StdoutToStderr {
+    #[cfg(unix)]
+    saved: i32,
+}
+
+impl StdoutToStderr {
+    #[cfg(unix)]
+    pub fn begin() -> io::Result<Self> {
+        io::stdout().flush()?;
+        // SAFETY: plain descriptor calls on the process's own stdout/stderr.
+        let saved = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if saved < 0 || unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { saved })
+    }
+
+    /// Other hosts have no descriptor to swap, and human output would corrupt the summary.
+    #[cfg(not(unix))]
+    pub fn begin() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--porcelain needs a unix host to move human output off stdout",
+        ))
+    }
+}
+
+impl Drop for StdoutToStderr {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        #[cfg(unix)]
+        // SAFETY: `saved` is the descriptor duplicated in `begin`, closed exactly once here.
+        unsafe {
+            libc::dup2(self.saved, libc::STDOUT_FILENO);
+            libc::close(self.saved);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_is_versioned_and_single_line_per_key() {
+        record("report", "reports/a.json");
+        record("note", "two\nlines \\ here");
+        let out = render("assault", Some(("attack.target_missing", "gone".into())));
+        assert_eq!(
+            out,
+            "porcelain=1\ncommand=assault\nreport=reports/a.json\nnote=two\\nlines \\\\ here\n\
+             status=error\nerror_code=attack.target_missing\nerror=gone\n"
+        );
+        assert_eq!(
+            render("diff", None),
+            "porcelain=1\ncommand=diff\nstatus=ok\n"
+        );
+    }
+}
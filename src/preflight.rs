@@ -0,0 +1,353 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Pre-flight cost estimates for destructive runs
+//!
+//! Before an attack, assault, ambush, amuck or abduct run starts, the resolved plan is turned
+//! into an [`Estimate`] of the disk space panic-attack will write (amuck variants, abduct
+//! copies, disk-stressor files, crash-output artifacts), the peak memory its own stressors hold
+//! and how long the run should take. [`assess`] compares that against the host: use of more
+//! than half of what is free is a warning, more than all of it blocks the run unless
+//! `--force` is given.
+//!
+//! Figures are upper bounds from the same formulas the stressors use; memory the target is
+//! merely asked to allocate (`--allocate-mb` on the memory axis) is reported but never blocks,
+//! since the target may ignore it.
+
+use crate::attack::capability;
+use crate::report::artifacts::MAX_ARTIFACT_BYTES;
+use crate::types::*;
+use std::path::Path;
+use std::time::Duration;
+
+const MIB: u64 = 1024 * 1024;
+/// A run's own report, before crash artifacts.
+const REPORT_BYTES_PER_RUN: u64 = 256 * 1024;
+/// Share of free disk or available memory above which the estimate warns.
+const WARN_FRACTION: f64 = 0.5;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Estimate {
+    /// Disk space panic-attack itself writes.
+    pub disk_bytes: u64,
+    /// Peak memory held by panic-attack's own stressors.
+    pub memory_bytes: u64,
+    /// Memory the target is asked to allocate; it may ignore the request.
+    pub target_memory_bytes: u64,
+    pub duration: Duration,
+    /// Where the figures come from, one line per contributor.
+    pub breakdown: Vec<String>,
+}
+
+/// What the host has to offer; `None` where it cannot be read.
+#[derive(Debug, Clone, Default)]
+pub struct Limits {
+    pub free_disk_bytes: Option<u64>,
+    pub available_memory_bytes: Option<u64>,
+    pub timeout: Option<Duration>,
+}
+
+impl Limits {
+    /// Probe free space where output lands (`dir`) and currently available memory.
+    pub fn probe(dir: &Path, timeout: Option<Duration>) -> Self {
+        // The output directory may not exist yet; its nearest existing ancestor shares the
+        // filesystem.
+        let existing = dir
+            .ancestors()
+            .find(|ancestor| ancestor.exists())
+            .unwrap_or(Path::new("."));
+        Self {
+            free_disk_bytes: capability::free_disk_bytes(existing),
+            available_memory_bytes: capability::available_memory(),
+            timeout,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Assessment {
+    pub warnings: Vec<String>,
+    /// Reasons the run should not start without `--force`.
+    pub blockers: Vec<String>,
+}
+
+/// Attack and assault: the target does the work the axis flags ask for, so panic-attack only
+/// pays for reports and crash artifacts.
+pub fn attack(config: &AttackConfig) -> Estimate {
+    let runs = runs(config);
+    let per_program: Duration = config
+        .axes
+        .iter()
+        .map(|axis| {
+            config
+                .axis_duration
+                .get(axis)
+                .copied()
+                .unwrap_or(config.duration)
+        })
+        .sum();
+    let mut estimate = Estimate {
+        duration: per_program * config.target_programs.len() as u32,
+        ..Default::default()
+    };
+    if config.axis_duration.is_empty() {
+        estimate
+            .breakdown
+            .push(format!("{} run(s) of {}s", runs, config.duration.as_secs()));
+    } else {
+        estimate.breakdown.push(format!(
+            "{} run(s) totalling {}s",
+            runs,
+            estimate.duration.as_secs()
+        ));
+    }
+    add_reports(&mut estimate, runs);
+    if config.axes.contains(&AttackAxis::Memory) {
+        let intensity = axis_intensity(config, &AttackAxis::Memory);
+        estimate.target_memory_bytes = (1024.0 * intensity.multiplier()) as u64 * MIB;
+        estimate.breakdown.push(format!(
+            "memory axis asks the target for {}",
+            format_bytes(estimate.target_memory_bytes)
+        ));
+    }
+    add_gpu(&mut estimate, config);
+    estimate
+}
+
+/// Ambush: panic-attack's own stressors run beside the target for the whole duration.
+pub fn ambush(config: &AttackConfig) -> Estimate {
+    let mut estimate = attack(config);
+    estimate.target_memory_bytes = 0;
+    estimate
+        .breakdown
+        .retain(|line| !line.starts_with("memory axis"));
+    if config.axes.contains(&AttackAxis::Memory) {
+        let held = crate::ambush::memory_target_bytes(axis_intensity(config, &AttackAxis::Memory));
+        estimate.memory_bytes += held;
+        estimate
+            .breakdown
+            .push(format!("memory stressor holds {}", format_bytes(held)));
+    }
+    if config.axes.contains(&AttackAxis::Disk) {
+        let written =
+            crate::ambush::disk_bytes_per_cycle(axis_intensity(config, &AttackAxis::Disk));
+        estimate.disk_bytes += written;
+        estimate.breakdown.push(format!(
+            "disk stressor keeps {} of scratch files",
+            format_bytes(written)
+        ));
+    }
+    estimate
+}
+
+/// Amuck: one mutated copy of the target per combination.
+pub fn amuck(target_bytes: u64, max_combinations: usize) -> Estimate {
+    let disk_bytes = target_bytes * max_combinations as u64 + REPORT_BYTES_PER_RUN;
+    Estimate {
+        disk_bytes,
+        breakdown: vec![format!(
+            "up to {} mutated copies of a {} file",
+            max_combinations,
+            format_bytes(target_bytes)
+        )],
+        ..Default::default()
+    }
+}
+
+/// Abduct: the selected files are copied into an isolated workspace.
+pub fn abduct(copied_files: usize, copied_bytes: u64, exec_timeout: Option<Duration>) -> Estimate {
+    Estimate {
+        disk_bytes: copied_bytes + REPORT_BYTES_PER_RUN,
+        duration: exec_timeout.unwrap_or_default(),
+        breakdown: vec![format!(
+            "{} file(s) copied, {}",
+            copied_files,
+            format_bytes(copied_bytes)
+        )],
+        ..Default::default()
+    }
+}
+
+/// Compare `estimate` with `limits`.
+pub fn assess(estimate: &Estimate, limits: &Limits) -> Assessment {
+    let mut assessment = Assessment::default();
+    check_resource(
+        &mut assessment,
+        "disk space",
+        estimate.disk_bytes,
+        limits.free_disk_bytes,
+        "free",
+    );
+    check_resource(
+        &mut assessment,
+        "stressor memory",
+        estimate.memory_bytes,
+        limits.available_memory_bytes,
+        "available",
+    );
+    if let Some(available) = limits.available_memory_bytes {
+        if estimate.target_memory_bytes > available {
+            assessment.warnings.push(format!(
+                "the memory axis asks the target for {} but only {} is available",
+                format_bytes(estimate.target_memory_bytes),
+                format_bytes(available)
+            ));
+        }
+    }
+    if let Some(timeout) = limits.timeout {
+        if estimate.duration > timeout {
+            assessment.warnings.push(format!(
+                "planned {}s exceeds the {}s campaign timeout; later axes will be trimmed",
+                estimate.duration.as_secs(),
+                timeout.as_secs()
+            ));
+        }
+    }
+    assessment
+}
+
+fn check_resource(
+    assessment: &mut Assessment,
+    what: &str,
+    needed: u64,
+    available: Option<u64>,
+    adjective: &str,
+) {
+    let Some(available) = available else {
+        return;
+    };
+    let message = format!(
+        "{} estimate {} against {} {}",
+        what,
+        format_bytes(needed),
+        format_bytes(available),
+        adjective
+    );
+    if needed > available {
+        assessment.blockers.push(message);
+    } else if needed as f64 > available as f64 * WARN_FRACTION {
+        assessment.warnings.push(message);
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1024 * MIB {
+        format!("{:.1} GiB", bytes as f64 / (1024 * MIB) as f64)
+    } else if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else {
+        format!("{} KiB", bytes.div_ceil(1024))
+    }
+}
+
+fn runs(config: &AttackConfig) -> usize {
+    config.target_programs.len() * config.axes.len()
+}
+
+fn axis_intensity(config: &AttackConfig, axis: &AttackAxis) -> IntensityLevel {
+    config
+        .axis_intensity
+        .get(axis)
+        .copied()
+        .unwrap_or(config.intensity)
+}
+
+/// Every run writes its share of the report; a crashing run can also keep full stdout and
+/// stderr as artifacts.
+fn add_reports(estimate: &mut Estimate, runs: usize) {
+    let worst_case = runs as u64 * (REPORT_BYTES_PER_RUN + 2 * MAX_ARTIFACT_BYTES as u64);
+    estimate.disk_bytes += worst_case;
+    estimate.breakdown.push(format!(
+        "reports and crash artifacts up to {}",
+        format_bytes(worst_case)
+    ));
+}
+
+fn add_gpu(estimate: &mut Estimate, config: &AttackConfig) {
+    if let Some(bytes) = config.gpu.as_ref().and_then(|gpu| gpu.memory_bytes) {
+        estimate.breakdown.push(format!(
+            "GPU stressor holds {} of device memory",
+            format_bytes(bytes)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(axes: Vec<AttackAxis>, intensity: IntensityLevel) -> AttackConfig {
+        AttackConfig {
+            duration: Duration::from_secs(30),
+            intensity,
+            ..fixtures::config(axes)
+        }
+    }
+
+    #[test]
+    fn ambush_counts_its_own_stressors() {
+        let plan = config(
+            vec![AttackAxis::Memory, AttackAxis::Disk, AttackAxis::Cpu],
+            IntensityLevel::Heavy,
+        );
+        let attack = attack(&plan);
+        assert_eq!(attack.duration, Duration::from_secs(90));
+        assert_eq!(attack.memory_bytes, 0);
+        assert_eq!(attack.target_memory_bytes, 10 * 1024 * MIB);
+
+        let ambush = ambush(&plan);
+        assert_eq!(ambush.target_memory_bytes, 0);
+        assert_eq!(ambush.memory_bytes, 640 * MIB);
+        assert!(ambush.disk_bytes > attack.disk_bytes);
+        assert!(ambush
+            .breakdown
+            .iter()
+            .any(|line| line == "memory stressor holds 640.0 MiB"));
+    }
+
+    #[test]
+    fn axis_overrides_shape_the_estimate() {
+        let mut plan = config(
+            vec![AttackAxis::Memory, AttackAxis::Disk],
+            IntensityLevel::Light,
+        );
+        plan.axis_duration
+            .insert(AttackAxis::Memory, Duration::from_secs(120));
+        plan.axis_intensity
+            .insert(AttackAxis::Memory, IntensityLevel::Heavy);
+        let attack = attack(&plan);
+        assert_eq!(attack.duration, Duration::from_secs(150));
+        assert_eq!(attack.target_memory_bytes, 10 * 1024 * MIB);
+        assert!(attack
+            .breakdown
+            .iter()
+            .any(|line| line == "2 run(s) totalling 150s"));
+    }
+
+    #[test]
+    fn assessment_warns_then_blocks() {
+        let estimate = amuck(10 * MIB, 16);
+        let roomy = Limits {
+            free_disk_bytes: Some(1024 * MIB),
+            ..Default::default()
+        };
+        assert_eq!(assess(&estimate, &roomy), Assessment::default());
+
+        let tight = Limits {
+            free_disk_bytes: Some(200 * MIB),
+            ..Default::default()
+        };
+        let assessment = assess(&estimate, &tight);
+        assert_eq!(assessment.warnings.len(), 1);
+        assert!(assessment.blockers.is_empty());
+
+        let full = Limits {
+            free_disk_bytes: Some(100 * MIB),
+            available_memory_bytes: Some(MIB),
+            timeout: Some(Duration::from_secs(1)),
+        };
+        let assessment = assess(&abduct(3, 150 * MIB, Some(Duration::from_secs(60))), &full);
+        assert_eq!(assessment.blockers.len(), 1);
+        assert!(assessment.blockers[0].starts_with("disk space estimate 150.2 MiB"));
+        assert!(assessment.warnings[0].contains("campaign timeout"));
+    }
+}
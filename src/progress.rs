@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Progress bars for long operations
+//!
+//! Directory scans, amuck combinations and multi-axis assaults draw an indicatif
+//! bar on stderr with a count, the current item and an ETA. Assault steps carry
+//! the duration their axis is configured to run, so the bar shows the current
+//! axis against its own plan and estimates the rest from the plans of the axes
+//! still to come; other operations use indicatif's rate-based estimate.
+//!
+//! Bars are hidden unless `main` turns them on through [`set_enabled`], which it
+//! does only when stderr is a terminal and neither `--quiet` nor `--porcelain`
+//! is set, so library callers and tests never draw. Log lines written through
+//! [`stderr`] suspend the bars so the two do not share a line.
+
+use indicatif::{
+    FormattedDuration, HumanDuration, MultiProgress, ProgressBar, ProgressDrawTarget,
+    ProgressState, ProgressStyle,
+};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Every bar of the process, drawn together so concurrent bars do not fight over the line.
+static BARS: OnceLock<MultiProgress> = OnceLock::new();
+
+const TICK: Duration = Duration::from_millis(250);
+
+fn bars() -> &'static MultiProgress {
+    BARS.get_or_init(|| MultiProgress::with_draw_target(ProgressDrawTarget::hidden()))
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    bars().set_draw_target(if enabled {
+        ProgressDrawTarget::stderr()
+    } else {
+        ProgressDrawTarget::hidden()
+    });
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// A bar over `total` items, ticking while it lives; dropping it clears the line. Every
+/// method is a no-op when bars are disabled.
+pub struct Progress {
+    bar: ProgressBar,
+    /// When the current item started, for planned steps.
+    step_started: Arc<Mutex<Instant>>,
+}
+
+impl Progress {
+    pub fn new(label: &str, total: usize) -> Self {
+        let style = ProgressStyle::with_template("{prefix} [{bar:20}] {pos}/{len} {msg} ETA {eta}")
+            .expect("progress template")
+            .progress_chars("=> ");
+        Self::start(label, total, style, Arc::new(Mutex::new(Instant::now())))
+    }
+
+    /// A bar whose items each have a planned duration; the ETA sums the plans still ahead.
+    pub fn with_plan(label: &str, plan: Vec<Duration>) -> Self {
+        let step_started = Arc::new(Mutex::new(Instant::now()));
+        let plan = Arc::new(plan);
+        let step = {
+            let (plan, step_started) = (Arc::clone(&plan), Arc::clone(&step_started));
+            move |state: &ProgressState, out: &mut dyn std::fmt::Write| {
+                let planned = plan.get(state.pos() as usize).filter(|p| !p.is_zero());
+                if let (Some(planned), Ok(started)) = (planned, step_started.lock()) {
+                    let elapsed = FormattedDuration(started.elapsed());
+                    let _ = write!(out, "({}/{})", elapsed, FormattedDuration(*planned));
+                }
+            }
+        };
+        let eta = {
+            let (plan, step_started) = (Arc::clone(&plan), Arc::clone(&step_started));
+            move |state: &ProgressState, out: &mut dyn std::fmt::Write| {
+                let elapsed = step_started
+                    .lock()
+                    .map(|started| started.elapsed())
+                    .unwrap_or_default();
+                if let Some(left) = planned_remaining(&plan, state.pos() as usize, elapsed) {
+                    let _ = write!(out, "ETA {}", HumanDuration(left));
+                }
+            }
+        };
+        let style =
+            ProgressStyle::with_template("{prefix} [{bar:20}] {pos}/{len} {msg} {step} {plan_eta}")
+                .expect("progress template")
+                .progress_chars("=> ")
+                .with_key("step", step)
+                .with_key("plan_eta", eta);
+        let total = plan.len();
+        Self::start(label, total, style, step_started)
+    }
+
+    fn start(
+        label: &str,
+        total: usize,
+        style: ProgressStyle,
+        step_started: Arc<Mutex<Instant>>,
+    ) -> Self {
+        let bar = if enabled() && total > 0 {
+            let bar = bars().add(ProgressBar::new(total as u64));
+            bar.enable_steady_tick(TICK);
+            bar
+        } else {
+            ProgressBar::hidden()
+        };
+        bar.set_style(style);
+        bar.set_prefix(label.to_string());
+        Self { bar, step_started }
+    }
+
+    /// Start the next item, described by `message`.
+    pub fn begin(&self, message: impl Into<String>) {
+        self.bar.set_message(message.into());
+        self.restart_step();
+    }
+
+    /// Mark the current item done.
+    pub fn inc(&self) {
+        self.bar.inc(1);
+        self.restart_step();
+    }
+
+    fn restart_step(&self) {
+        if let Ok(mut started) = self.step_started.lock() {
+            *started = Instant::now();
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.bar.finish_and_clear();
+        bars().remove(&self.bar);
+    }
+}
+
+/// The rest of step `done`'s plan after `elapsed` plus the plans after it; `None` once
+/// every step is done.
+fn planned_remaining(plan: &[Duration], done: usize, elapsed: Duration) -> Option<Duration> {
+    let current = plan.get(done)?.saturating_sub(elapsed);
+    Some(current + plan[done + 1..].iter().sum::<Duration>())
+}
+
+/// Stderr for log output: suspends the bars while writing so the two do not share a line.
+pub fn stderr() -> LogWriter {
+    LogWriter
+}
+
+pub struct LogWriter;
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        bars().suspend(|| io::stderr().lock().write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_steps_estimate_from_configured_durations() {
+        let plan = [30, 30, 60, 120].map(Duration::from_secs);
+        // 48s left of the current 60s axis plus the 120s one after it.
+        assert_eq!(
+            planned_remaining(&plan, 2, Duration::from_secs(12)),
+            Some(Duration::from_secs(168))
+        );
+        assert_eq!(planned_remaining(&plan, 4, Duration::ZERO), None);
+    }
+}
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Content-addressed storage for captured output too large to inline in a report.
+//!
+//! Reports keep the first [`INLINE_LIMIT`] bytes of each stdout/stderr/backtrace as a preview
+//! and move the full text to `artifacts/<sha256>.log` beside the report file, recording the
+//! hash, size and relative path as an [`OutputArtifact`]. Identical outputs share one file, so
+//! a crash that repeats across axes or runs is stored once. Viewers resolve artifacts lazily
+//! and page through them instead of loading every output up front.
+
+use crate::storage::write_atomic;
+use crate::types::{AssaultReport, OutputArtifact};
+use anyhow::{anyhow, Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Output up to this size stays inline in the report.
+pub const INLINE_LIMIT: usize = 8 * 1024;
+/// Largest output kept per field; anything beyond is cut before hashing and storing.
+pub const MAX_ARTIFACT_BYTES: usize = 64 * 1024 * 1024;
+/// Directory, relative to the report, holding the stored outputs.
+pub const ARTIFACT_DIR: &str = "artifacts";
+/// Lines per page in the TUI and GUI output viewers.
+pub const PAGE_LINES: usize = 40;
+
+/// Artifact directory rooted at the directory that holds (or will hold) a report.
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    base: PathBuf,
+}
+
+impl ArtifactStore {
+    pub fn new(base: impl Into<PathBuf>) -> Self {
+        Self { base: base.into() }
+    }
+
+    /// Store for the report written to (or read from) `report_path`.
+    pub fn beside(report_path: &Path) -> Self {
+        let base = report_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        Self::new(base)
+    }
+
+    /// Move `text` into the store when it exceeds [`INLINE_LIMIT`], leaving a preview in place.
+    pub fn spill(&self, field: &str, text: &mut String) -> Result<Option<OutputArtifact>> {
+        if text.len() <= INLINE_LIMIT {
+            return Ok(None);
+        }
+        let truncated = text.len() > MAX_ARTIFACT_BYTES;
+        let stored = &text[..floor_char_boundary(text, MAX_ARTIFACT_BYTES)];
+        let sha256 = hex::encode(Sha256::digest(stored.as_bytes()));
+        let relative = Path::new(ARTIFACT_DIR).join(format!("{}.log", sha256));
+        let path = self.base.join(&relative);
+        if !path.exists() {
+            let dir = self.base.join(ARTIFACT_DIR);
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("creating artifact directory {}", dir.display()))?;
+            write_atomic(&path, stored)
+                .with_context(|| format!("writing artifact {}", path.display()))?;
+        }
+        let artifact = OutputArtifact {
+            field: field.to_string(),
+            sha256,
+            bytes: stored.len() as u64,
+            path: relative,
+            truncated,
+        };
+
+        let preview_end = floor_char_boundary(text, INLINE_LIMIT);
+        let total = text.len();
+        text.truncate(preview_end);
+        text.push_str(&format!(
+            "\n...<{} bytes total; full output in {}>",
+            total,
+            artifact.path.display()
+        ));
+        Ok(Some(artifact))
+    }
+
+    /// Read an artifact back, checking it still matches the recorded hash.
+    pub fn load(&self, artifact: &OutputArtifact) -> Result<String> {
+        let path = self.base.join(&artifact.path);
+        let bytes =
+            fs::read(&path).with_context(|| format!("reading artifact {}", path.display()))?;
+        let actual = hex::encode(Sha256::digest(&bytes));
+        if actual != artifact.sha256 {
+            return Err(anyhow!(
+                "artifact {} does not match its recorded hash",
+                path.display()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Copy of `report` with oversized crash output moved into `store`.
+pub fn externalize_assault_report(
+    report: &AssaultReport,
+    store: &ArtifactStore,
+) -> Result<AssaultReport> {
+    let mut report = report.clone();
+    for result in &mut report.attack_results {
+        for crash in &mut result.crashes {
+            let fields = [
+                ("stdout", Some(&mut crash.stdout)),
+                ("stderr", Some(&mut crash.stderr)),
+                ("backtrace", crash.backtrace.as_mut()),
+            ];
+            let fields = fields
+                .into_iter()
+                .filter_map(|(field, text)| text.map(|text| (field, text)));
+            spill_fields(store, &mut crash.artifacts, fields)?;
+        }
+    }
+    Ok(report)
+}
+
+/// Spill each named field, skipping any that already refer to an artifact (a report being
+/// re-saved keeps its existing preview).
+pub fn spill_fields<'a>(
+    store: &ArtifactStore,
+    artifacts: &mut Vec<OutputArtifact>,
+    fields: impl IntoIterator<Item = (&'static str, &'a mut String)>,
+) -> Result<()> {
+    for (field, text) in fields {
+        if artifacts.iter().any(|artifact| artifact.field == field) {
+            continue;
+        }
+        artifacts.extend(store.spill(field, text)?);
+    }
+    Ok(())
+}
+
+/// One captured output a viewer can page through.
+#[derive(Debug, Clone)]
+pub struct CrashOutput {
+    pub label: String,
+    pub inline: String,
+    pub artifact: Option<OutputArtifact>,
+}
+
+impl CrashOutput {
+    /// Full text when the artifact is available, otherwise the inline preview with the reason.
+    pub fn load(&self, store: Option<&ArtifactStore>) -> String {
+        match (&self.artifact, store) {
+            (Some(artifact), Some(store)) => match store.load(artifact) {
+                Ok(text) => text,
+                Err(err) => format!("{}\n\n<{:#}>", self.inline, err),
+            },
+            _ => self.inline.clone(),
+        }
+    }
+}
+
+/// Every non-empty crash output in report order.
+pub fn crash_outputs(report: &AssaultReport) -> Vec<CrashOutput> {
+    let mut outputs = Vec::new();
+    for result in &report.attack_results {
+        for (index, crash) in result.crashes.iter().enumerate() {
+            let fields = [
+                ("stdout", Some(&crash.stdout)),
+                ("stderr", Some(&crash.stderr)),
+                ("backtrace", crash.backtrace.as_ref()),
+            ];
+            for (field, text) in fields {
+                let Some(text) = text.filter(|text| !text.is_empty()) else {
+                    continue;
+                };
+                let artifact = crash
+                    .artifacts
+                    .iter()
+                    .find(|artifact| artifact.field == field)
+                    .cloned();
+                outputs.push(CrashOutput {
+                    label: format!("{:?} crash {} {}", result.axis, index + 1, field),
+                    inline: text.clone(),
+                    artifact,
+                });
+            }
+        }
+    }
+    outputs
+}
+
+/// Lines of `page` (0-based, clamped to the last page) and the total page count.
+pub fn page_of(text: &str, page: usize, lines_per_page: usize) -> (Vec<&str>, usize) {
+    let lines: Vec<&str> = text.lines().collect();
+    let per_page = lines_per_page.max(1);
+    let pages = lines.len().div_ceil(per_page).max(1);
+    let start = page.min(pages - 1) * per_page;
+    let end = (start + per_page).min(lines.len());
+    (lines[start..end].to_vec(), pages)
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    if index >= text.len() {
+        return text.len();
+    }
+    (0..=index)
+        .rev()
+        .find(|i| text.is_char_boundary(*i))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_small_output_stays_inline() {
+        let dir = TempDir::new().unwrap();
+        let store = ArtifactStore::new(dir.path());
+        let mut text = "short".to_string();
+        assert!(store.spill("stdout", &mut text).unwrap().is_none());
+        assert_eq!(text, "short");
+        assert!(!dir.path().join(ARTIFACT_DIR).exists());
+    }
+
+    #[test]
+    fn test_large_output_is_stored_once_and_verified() {
+        let dir = TempDir::new().unwrap();
+        let store = ArtifactStore::beside(&dir.path().join("report.json"));
+        let full = "é".repeat(INLINE_LIMIT);
+        let mut first = full.clone();
+        let mut second = full.clone();
+
+        let artifact = store.spill("stderr", &mut first).unwrap().unwrap();
+        let again = store.spill("stderr", &mut second).unwrap().unwrap();
+        assert_eq!(artifact, again);
+        assert_eq!(artifact.bytes, full.len() as u64);
+        assert!(first.len() < full.len());
+        assert!(first.contains("full output in artifacts/"));
+        assert_eq!(
+            fs::read_dir(dir.path().join(ARTIFACT_DIR)).unwrap().count(),
+            1
+        );
+        assert_eq!(store.load(&artifact).unwrap(), full);
+
+        fs::write(dir.path().join(&artifact.path), "tampered").unwrap();
+        assert!(store.load(&artifact).is_err());
+    }
+
+    #[test]
+    fn test_paging_clamps_to_last_page() {
+        let text = (1..=5)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(page_of(&text, 0, 2), (vec!["1", "2"], 3));
+        assert_eq!(page_of(&text, 9, 2), (vec!["5"], 3));
+        assert_eq!(page_of("", 0, 2), (Vec::new(), 1));
+    }
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Static attack-surface diff between two assail reports (`panic-attack assail-diff`).
+//!
+//! The pre-merge counterpart of the assault report diff: weak points introduced, removed or
+//! changed between two source trees, and per-file risk scores that moved. Findings are
+//! matched the same way `diff` matches them; risk scores come from the assail prioritiser.
+
+use crate::kanren::strategy::{self, SearchStrategy};
+use crate::report::diff::{diff_findings, fmt_delta_i64, Delta, FindingChanges};
+use crate::types::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Risk-score moves smaller than this are rounding, not change.
+const MIN_RISK_DELTA: f64 = 0.05;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssailDiff {
+    pub base: String,
+    pub compare: String,
+    pub weak_points: Delta<i64>,
+    pub findings: FindingChanges,
+    /// Files whose risk score moved, largest increase first; a file missing from one side
+    /// (no findings there) scores `0.0`.
+    pub files: Vec<FileRiskChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileRiskChange {
+    pub file: String,
+    pub base: f64,
+    pub compare: f64,
+    pub delta: f64,
+}
+
+impl AssailDiff {
+    pub fn build(
+        base: &AssailReport,
+        compare: &AssailReport,
+        base_label: &str,
+        compare_label: &str,
+    ) -> Self {
+        let base_risk = file_risks(base);
+        let compare_risk = file_risks(compare);
+        let mut files: Vec<FileRiskChange> = base_risk
+            .keys()
+            .chain(compare_risk.keys())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .map(|file| {
+                let before = base_risk.get(file).copied().unwrap_or(0.0);
+                let after = compare_risk.get(file).copied().unwrap_or(0.0);
+                FileRiskChange {
+                    file: file.clone(),
+                    base: before,
+                    compare: after,
+                    delta: after - before,
+                }
+            })
+            .filter(|change| change.delta.abs() >= MIN_RISK_DELTA)
+            .collect();
+        files.sort_by(|a, b| b.delta.total_cmp(&a.delta).then(a.file.cmp(&b.file)));
+
+        Self {
+            base: base_label.to_string(),
+            compare: compare_label.to_string(),
+            weak_points: Delta::new(
+                base.weak_points.len() as i64,
+                compare.weak_points.len() as i64,
+            ),
+            findings: diff_findings(&base.weak_points, &compare.weak_points),
+            files,
+        }
+    }
+
+    pub fn format(&self) -> String {
+        let mut lines = vec![
+            "=== PANIC-ATTACK ASSAIL DIFF ===".to_string(),
+            format!("Base: {}", self.base),
+            format!("Compare: {}", self.compare),
+            String::new(),
+        ];
+        lines.push(format!(
+            "Weak points: {} -> {} ({})",
+            self.weak_points.base,
+            self.weak_points.compare,
+            fmt_delta_i64(self.weak_points.delta)
+        ));
+        lines.push(format!(
+            "Findings: {} new, {} removed, {} changed",
+            self.findings.added.len(),
+            self.findings.removed.len(),
+            self.findings.changed.len()
+        ));
+
+        if !self.findings.added.is_empty() {
+            lines.push(String::new());
+            lines.push("New weak points:".to_string());
+            lines.extend(
+                self.findings
+                    .added
+                    .iter()
+                    .map(|wp| format!("  + {}", describe(wp))),
+            );
+        }
+        if !self.findings.removed.is_empty() {
+            lines.push(String::new());
+            lines.push("Removed weak points:".to_string());
+            lines.extend(
+                self.findings
+                    .removed
+                    .iter()
+                    .map(|wp| format!("  - {}", describe(wp))),
+            );
+        }
+        if !self.findings.changed.is_empty() {
+            lines.push(String::new());
+            lines.push("Changed weak points:".to_string());
+            for change in &self.findings.changed {
+                lines.push(format!("  ~ {}", describe(&change.after)));
+                if change.before.severity != change.after.severity {
+                    lines.push(format!(
+                        "    severity {:?} -> {:?}",
+                        change.before.severity, change.after.severity
+                    ));
+                }
+            }
+        }
+
+        lines.push(String::new());
+        if self.files.is_empty() {
+            lines.push("File risk: unchanged".to_string());
+        } else {
+            lines.push("File risk:".to_string());
+            lines.extend(self.files.iter().map(|change| {
+                format!(
+                    "  {}: {:.1} -> {:.1} ({:+.1})",
+                    change.file, change.base, change.compare, change.delta
+                )
+            }));
+        }
+        lines.join("\n")
+    }
+}
+
+/// One-line weak point rendering shared with `assail --watch`.
+pub(crate) fn describe(wp: &WeakPoint) -> String {
+    format!(
+        "[{:?}] {:?} at {}: {}",
+        wp.severity,
+        wp.category,
+        wp.location.as_deref().unwrap_or("unknown"),
+        wp.description
+    )
+}
+
+fn file_risks(report: &AssailReport) -> BTreeMap<String, f64> {
+    strategy::prioritise_files(report, SearchStrategy::RiskWeighted)
+        .into_iter()
+        .map(|risk| (risk.file_path, risk.risk_score))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn weak_point(category: WeakPointCategory, file: &str, severity: Severity) -> WeakPoint {
+        WeakPoint {
+            category,
+            location: Some(file.to_string()),
+            severity,
+            description: format!("{:?} in {}", category, file),
+            recommended_attack: vec![AttackAxis::Memory],
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    fn file(path: &str, unwrap_calls: usize) -> FileStatistics {
+        FileStatistics {
+            file_path: path.to_string(),
+            lines: 10,
+            unsafe_blocks: 0,
+            panic_sites: 0,
+            unwrap_calls,
+            allocation_sites: 0,
+            io_operations: 0,
+            threading_constructs: 0,
+        }
+    }
+
+    fn report(weak_points: Vec<WeakPoint>, files: Vec<FileStatistics>) -> AssailReport {
+        AssailReport {
+            program_path: PathBuf::from("."),
+            language: Language::Rust,
+            weak_points,
+            file_statistics: files,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_assail_diff_findings_and_file_risk() {
+        let base = report(
+            vec![
+                weak_point(WeakPointCategory::PanicPath, "src/a.rs", Severity::Low),
+                weak_point(WeakPointCategory::UnsafeCode, "src/old.rs", Severity::High),
+            ],
+            vec![
+                file("src/a.rs", 2),
+                file("src/old.rs", 1),
+                file("src/same.rs", 3),
+            ],
+        );
+        let compare = report(
+            vec![
+                weak_point(WeakPointCategory::PanicPath, "src/a.rs", Severity::Medium),
+                weak_point(
+                    WeakPointCategory::RaceCondition,
+                    "src/new.rs",
+                    Severity::High,
+                ),
+            ],
+            vec![
+                file("src/a.rs", 5),
+                file("src/new.rs", 1),
+                file("src/same.rs", 3),
+            ],
+        );
+
+        let diff = AssailDiff::build(&base, &compare, "main", "feature");
+        assert_eq!(diff.findings.added.len(), 1);
+        assert_eq!(diff.findings.removed.len(), 1);
+        assert_eq!(diff.findings.changed.len(), 1);
+
+        let files: Vec<(&str, f64)> = diff
+            .files
+            .iter()
+            .map(|change| (change.file.as_str(), change.delta))
+            .collect();
+        assert_eq!(
+            files,
+            vec![("src/a.rs", 3.0), ("src/new.rs", 1.0), ("src/old.rs", -1.0)]
+        );
+
+        let text = diff.format();
+        assert!(text.contains("Findings: 1 new, 1 removed, 1 changed"));
+        assert!(text.contains("  + [High] RaceCondition at src/new.rs"));
+        assert!(text.contains("    severity Low -> Medium"));
+        assert!(text.contains("  src/a.rs: 2.0 -> 5.0 (+3.0)"));
+    }
+}
@@ -0,0 +1,1020 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Charts of an assault report: per-axis outcomes, crash timeline and robustness gauge.
+//!
+//! A chart is a list of plain shapes in chart units, so the same chart is painted by the
+//! report GUI and written headlessly by `panic-attack chart` as SVG or PNG. PNG output is
+//! rasterised here, with the GUI's bundled font, so no display or image tool is needed.
+
+use crate::report::formatter::score_rating;
+use crate::types::{AssaultReport, AttackResult};
+use ab_glyph::{Font, FontArc, PxScale, ScaleFont};
+use anyhow::{anyhow, Context, Result};
+use std::f32::consts::PI;
+use std::path::Path;
+
+/// PNG pixels per chart unit; 2 keeps text sharp on high-density screens.
+pub const PNG_SCALE: f32 = 2.0;
+
+const TEXT: Color = Color(36, 41, 47);
+const MUTED: Color = Color(110, 118, 129);
+const GRID: Color = Color(208, 215, 222);
+const PASSED: Color = Color(46, 160, 67);
+const FAILED: Color = Color(207, 34, 46);
+const SKIPPED: Color = Color(175, 184, 193);
+const FAIR: Color = Color(212, 167, 44);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Horizontal alignment of text against its position; the position's `y` is the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        fill: Color,
+    },
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+        width: f32,
+        color: Color,
+    },
+    /// Closed, convex polygon.
+    Polygon {
+        points: Vec<(f32, f32)>,
+        fill: Color,
+    },
+    /// Arc stroke between two angles (radians, counter-clockwise from the positive x axis,
+    /// y pointing up as on paper).
+    Arc {
+        center: (f32, f32),
+        radius: f32,
+        from: f32,
+        to: f32,
+        width: f32,
+        color: Color,
+    },
+    Text {
+        x: f32,
+        y: f32,
+        size: f32,
+        anchor: Anchor,
+        color: Color,
+        text: String,
+    },
+}
+
+impl Shape {
+    fn translated(self, dx: f32, dy: f32) -> Self {
+        let at = |(x, y): (f32, f32)| (x + dx, y + dy);
+        match self {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+            } => Shape::Rect {
+                x: x + dx,
+                y: y + dy,
+                width,
+                height,
+                fill,
+            },
+            Shape::Line {
+                from,
+                to,
+                width,
+                color,
+            } => Shape::Line {
+                from: at(from),
+                to: at(to),
+                width,
+                color,
+            },
+            Shape::Polygon { points, fill } => Shape::Polygon {
+                points: points.into_iter().map(at).collect(),
+                fill,
+            },
+            Shape::Arc {
+                center,
+                radius,
+                from,
+                to,
+                width,
+                color,
+            } => Shape::Arc {
+                center: at(center),
+                radius,
+                from,
+                to,
+                width,
+                color,
+            },
+            Shape::Text {
+                x,
+                y,
+                size,
+                anchor,
+                color,
+                text,
+            } => Shape::Text {
+                x: x + dx,
+                y: y + dy,
+                size,
+                anchor,
+                color,
+                text,
+            },
+        }
+    }
+}
+
+/// Which chart to draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    /// All three charts on one canvas.
+    Dashboard,
+    Outcomes,
+    Timeline,
+    Robustness,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartFormat {
+    Svg,
+    Png,
+}
+
+impl ChartFormat {
+    /// Format named by the file extension of `path`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("svg") => Ok(ChartFormat::Svg),
+            Some("png") => Ok(ChartFormat::Png),
+            _ => Err(anyhow!(
+                "cannot tell the chart format of {}; use a .svg or .png file name",
+                path.display()
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chart {
+    pub width: f32,
+    pub height: f32,
+    pub shapes: Vec<Shape>,
+}
+
+impl Chart {
+    fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Draw `kind` for `report`.
+    pub fn build(report: &AssaultReport, kind: ChartKind) -> Self {
+        match kind {
+            ChartKind::Outcomes => outcomes(&report.attack_results),
+            ChartKind::Timeline => timeline(&report.attack_results),
+            ChartKind::Robustness => robustness(report.overall_assessment.robustness_score),
+            ChartKind::Dashboard => {
+                let outcomes = outcomes(&report.attack_results);
+                let robustness = robustness(report.overall_assessment.robustness_score);
+                let timeline = timeline(&report.attack_results);
+                // The outcome bars grow with the number of axes; the timeline goes below them.
+                let top = 48.0;
+                let below = top + outcomes.height.max(robustness.height) + 10.0;
+                let mut chart = Chart::new(960.0, below + timeline.height);
+                let program = report
+                    .assail_report
+                    .program_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| report.assail_report.program_path.display().to_string());
+                chart.text(
+                    24.0,
+                    36.0,
+                    20.0,
+                    Anchor::Start,
+                    TEXT,
+                    format!("panic-attack: {}", program),
+                );
+                chart.place(outcomes, 0.0, top);
+                chart.place(robustness, 600.0, top);
+                chart.place(timeline, 0.0, below);
+                chart
+            }
+        }
+    }
+
+    /// Copy `other`'s shapes into this chart with its origin at (`dx`, `dy`).
+    fn place(&mut self, other: Chart, dx: f32, dy: f32) {
+        self.shapes.extend(
+            other
+                .shapes
+                .into_iter()
+                .map(|shape| shape.translated(dx, dy)),
+        );
+    }
+
+    fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, fill: Color) {
+        self.shapes.push(Shape::Rect {
+            x,
+            y,
+            width,
+            height,
+            fill,
+        });
+    }
+
+    fn line(&mut self, from: (f32, f32), to: (f32, f32), color: Color) {
+        self.shapes.push(Shape::Line {
+            from,
+            to,
+            width: 1.0,
+            color,
+        });
+    }
+
+    fn text(&mut self, x: f32, y: f32, size: f32, anchor: Anchor, color: Color, text: String) {
+        self.shapes.push(Shape::Text {
+            x,
+            y,
+            size,
+            anchor,
+            color,
+            text,
+        });
+    }
+
+    pub fn to_svg(&self) -> String {
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"Ubuntu, Helvetica, Arial, sans-serif\">\n<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+            w = self.width,
+            h = self.height
+        );
+        for shape in &self.shapes {
+            match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    fill,
+                } => out.push_str(&format!(
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>\n",
+                    x,
+                    y,
+                    width,
+                    height,
+                    fill.hex()
+                )),
+                Shape::Line {
+                    from,
+                    to,
+                    width,
+                    color,
+                } => out.push_str(&format!(
+                    "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                    from.0,
+                    from.1,
+                    to.0,
+                    to.1,
+                    color.hex(),
+                    width
+                )),
+                Shape::Polygon { points, fill } => {
+                    let points: Vec<String> = points
+                        .iter()
+                        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+                        .collect();
+                    out.push_str(&format!(
+                        "<polygon points=\"{}\" fill=\"{}\"/>\n",
+                        points.join(" "),
+                        fill.hex()
+                    ));
+                }
+                Shape::Arc {
+                    center,
+                    radius,
+                    from,
+                    to,
+                    width,
+                    color,
+                } => {
+                    let start = arc_point(*center, *radius, *from);
+                    let end = arc_point(*center, *radius, *to);
+                    // Angles grow counter-clockwise on paper, which is sweep 0 in SVG.
+                    let large = u8::from((to - from).abs() > PI);
+                    let sweep = u8::from(to < from);
+                    out.push_str(&format!(
+                        "<path d=\"M {:.1} {:.1} A {:.1} {:.1} 0 {} {} {:.1} {:.1}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                        start.0,
+                        start.1,
+                        radius,
+                        radius,
+                        large,
+                        sweep,
+                        end.0,
+                        end.1,
+                        color.hex(),
+                        width
+                    ));
+                }
+                Shape::Text {
+                    x,
+                    y,
+                    size,
+                    anchor,
+                    color,
+                    text,
+                } => {
+                    let anchor = match anchor {
+                        Anchor::Start => "start",
+                        Anchor::Middle => "middle",
+                        Anchor::End => "end",
+                    };
+                    out.push_str(&format!(
+                        "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{}\" text-anchor=\"{}\" fill=\"{}\">{}</text>\n",
+                        x,
+                        y,
+                        size,
+                        anchor,
+                        color.hex(),
+                        xml_escape(text)
+                    ));
+                }
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// PNG image at `scale` pixels per chart unit.
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>> {
+        let mut canvas = Canvas::new(
+            (self.width * scale).ceil() as usize,
+            (self.height * scale).ceil() as usize,
+        );
+        let font = chart_font()?;
+        let s = |(x, y): (f32, f32)| (x * scale, y * scale);
+        for shape in &self.shapes {
+            match shape {
+                Shape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    fill,
+                } => canvas.fill_polygon(
+                    &[
+                        s((*x, *y)),
+                        s((x + width, *y)),
+                        s((x + width, y + height)),
+                        s((*x, y + height)),
+                    ],
+                    *fill,
+                ),
+                Shape::Line {
+                    from,
+                    to,
+                    width,
+                    color,
+                } => {
+                    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+                    let length = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+                    let (nx, ny) = (-dy / length * width / 2.0, dx / length * width / 2.0);
+                    canvas.fill_polygon(
+                        &[
+                            s((from.0 + nx, from.1 + ny)),
+                            s((to.0 + nx, to.1 + ny)),
+                            s((to.0 - nx, to.1 - ny)),
+                            s((from.0 - nx, from.1 - ny)),
+                        ],
+                        *color,
+                    );
+                }
+                Shape::Polygon { points, fill } => {
+                    let points: Vec<(f32, f32)> = points.iter().copied().map(s).collect();
+                    canvas.fill_polygon(&points, *fill);
+                }
+                Shape::Arc {
+                    center,
+                    radius,
+                    from,
+                    to,
+                    width,
+                    color,
+                } => {
+                    let steps = 64;
+                    let angle = |i: usize| from + (to - from) * i as f32 / steps as f32;
+                    let outer =
+                        (0..=steps).map(|i| arc_point(*center, radius + width / 2.0, angle(i)));
+                    let inner = (0..=steps)
+                        .rev()
+                        .map(|i| arc_point(*center, radius - width / 2.0, angle(i)));
+                    let points: Vec<(f32, f32)> = outer.chain(inner).map(s).collect();
+                    canvas.fill_polygon(&points, *color);
+                }
+                Shape::Text {
+                    x,
+                    y,
+                    size,
+                    anchor,
+                    color,
+                    text,
+                } => canvas.draw_text(&font, s((*x, *y)), size * scale, *anchor, *color, text),
+            }
+        }
+        canvas.encode_png()
+    }
+
+    /// Write the chart to `path` as `format`.
+    pub fn write(&self, path: &Path, format: ChartFormat) -> Result<()> {
+        let bytes = match format {
+            ChartFormat::Svg => self.to_svg().into_bytes(),
+            ChartFormat::Png => self.to_png(PNG_SCALE)?,
+        };
+        crate::storage::write_atomic(path, bytes)
+            .with_context(|| format!("writing chart {}", path.display()))
+    }
+}
+
+/// Outcome counts of one axis.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct AxisOutcome {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    crashes: usize,
+}
+
+/// Stacked passed/failed/skipped bar per axis, in run order.
+fn outcomes(results: &[AttackResult]) -> Chart {
+    let mut axes: Vec<(String, AxisOutcome)> = Vec::new();
+    for result in results {
+        let name = result.axis.name().to_string();
+        let index = match axes.iter().position(|(axis, _)| *axis == name) {
+            Some(index) => index,
+            None => {
+                axes.push((name, AxisOutcome::default()));
+                axes.len() - 1
+            }
+        };
+        let outcome = &mut axes[index].1;
+        if result.skipped {
+            outcome.skipped += 1;
+        } else if result.success {
+            outcome.passed += 1;
+        } else {
+            outcome.failed += 1;
+        }
+        outcome.crashes += result.crashes.len();
+    }
+
+    let row = 28.0;
+    let height = (96.0 + row * axes.len() as f32).max(260.0);
+    let mut chart = Chart::new(600.0, height);
+    chart.text(
+        24.0,
+        30.0,
+        15.0,
+        Anchor::Start,
+        TEXT,
+        "Outcomes per axis".to_string(),
+    );
+    if axes.is_empty() {
+        chart.text(
+            24.0,
+            64.0,
+            12.0,
+            Anchor::Start,
+            MUTED,
+            "No attacks ran".to_string(),
+        );
+        return chart;
+    }
+    let (left, right) = (120.0, 440.0);
+    let most = axes
+        .iter()
+        .map(|(_, o)| o.passed + o.failed + o.skipped)
+        .max()
+        .unwrap_or(1)
+        .max(1) as f32;
+    for (i, (axis, outcome)) in axes.iter().enumerate() {
+        let y = 52.0 + row * i as f32;
+        chart.text(left - 8.0, y + 15.0, 12.0, Anchor::End, TEXT, axis.clone());
+        let mut x = left;
+        for (count, color) in [
+            (outcome.passed, PASSED),
+            (outcome.failed, FAILED),
+            (outcome.skipped, SKIPPED),
+        ] {
+            let width = (right - left) * count as f32 / most;
+            if count > 0 {
+                chart.rect(x, y, width, 20.0, color);
+            }
+            x += width;
+        }
+        let parts: Vec<String> = [
+            (outcome.passed, "passed"),
+            (outcome.failed, "failed"),
+            (outcome.skipped, "skipped"),
+            (outcome.crashes, "crash(es)"),
+        ]
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, name)| format!("{} {}", count, name))
+        .collect();
+        chart.text(
+            x + 6.0,
+            y + 15.0,
+            11.0,
+            Anchor::Start,
+            MUTED,
+            parts.join(", "),
+        );
+    }
+    let legend = 52.0 + row * axes.len() as f32 + 20.0;
+    let mut x = left;
+    for (name, color) in [("passed", PASSED), ("failed", FAILED), ("skipped", SKIPPED)] {
+        chart.rect(x, legend - 10.0, 10.0, 10.0, color);
+        chart.text(
+            x + 14.0,
+            legend,
+            11.0,
+            Anchor::Start,
+            MUTED,
+            name.to_string(),
+        );
+        x += 80.0;
+    }
+    chart
+}
+
+/// Attacks laid end to end by duration, coloured by outcome, with each attack's crashes
+/// marked where it ended.
+fn timeline(results: &[AttackResult]) -> Chart {
+    let mut chart = Chart::new(960.0, 200.0);
+    chart.text(
+        24.0,
+        30.0,
+        15.0,
+        Anchor::Start,
+        TEXT,
+        "Crash timeline".to_string(),
+    );
+    if results.is_empty() {
+        chart.text(
+            24.0,
+            64.0,
+            12.0,
+            Anchor::Start,
+            MUTED,
+            "No attacks ran".to_string(),
+        );
+        return chart;
+    }
+    let (left, right, top) = (24.0, 936.0, 80.0);
+    let total: f32 = results.iter().map(|r| r.duration.as_secs_f32()).sum();
+    // Without recorded durations every attack gets the same width.
+    let span = |result: &AttackResult| {
+        if total > 0.0 {
+            result.duration.as_secs_f32() / total
+        } else {
+            1.0 / results.len() as f32
+        }
+    };
+    let mut x = left;
+    for result in results {
+        let width = (right - left) * span(result);
+        let color = if result.skipped {
+            SKIPPED
+        } else if result.success {
+            PASSED
+        } else {
+            FAILED
+        };
+        chart.rect(x, top, (width - 1.0).max(0.5), 36.0, color);
+        if width > 48.0 {
+            chart.text(
+                x + width / 2.0,
+                top + 23.0,
+                11.0,
+                Anchor::Middle,
+                Color(255, 255, 255),
+                result.axis.name().to_string(),
+            );
+        }
+        if !result.crashes.is_empty() {
+            let end = x + width - 1.0;
+            chart.shapes.push(Shape::Polygon {
+                points: vec![
+                    (end - 6.0, top - 16.0),
+                    (end + 6.0, top - 16.0),
+                    (end, top - 4.0),
+                ],
+                fill: TEXT,
+            });
+            if result.crashes.len() > 1 {
+                chart.text(
+                    end,
+                    top - 20.0,
+                    11.0,
+                    Anchor::Middle,
+                    TEXT,
+                    format!("×{}", result.crashes.len()),
+                );
+            }
+        }
+        x += width;
+    }
+    let axis = top + 48.0;
+    chart.line((left, axis), (right, axis), GRID);
+    let ticks = 4;
+    for i in 0..=ticks {
+        let fraction = i as f32 / ticks as f32;
+        let tick_x = left + (right - left) * fraction;
+        chart.line((tick_x, axis), (tick_x, axis + 5.0), GRID);
+        let label = if total > 0.0 {
+            seconds(total * fraction)
+        } else {
+            format!("{:.0}%", fraction * 100.0)
+        };
+        let anchor = match i {
+            0 => Anchor::Start,
+            i if i == ticks => Anchor::End,
+            _ => Anchor::Middle,
+        };
+        chart.text(tick_x, axis + 20.0, 11.0, anchor, MUTED, label);
+    }
+    chart
+}
+
+/// Half-circle gauge of the robustness score with the report's poor/fair/good bands.
+fn robustness(score: f64) -> Chart {
+    let mut chart = Chart::new(360.0, 260.0);
+    chart.text(
+        24.0,
+        30.0,
+        15.0,
+        Anchor::Start,
+        TEXT,
+        "Robustness".to_string(),
+    );
+    let center = (180.0, 190.0);
+    let radius = 110.0;
+    let angle = |score: f32| PI * (1.0 - score.clamp(0.0, 100.0) / 100.0);
+    for (from, to, color) in [
+        (0.0, 50.0, FAILED),
+        (50.0, 80.0, FAIR),
+        (80.0, 100.0, PASSED),
+    ] {
+        chart.shapes.push(Shape::Arc {
+            center,
+            radius,
+            from: angle(from),
+            to: angle(to),
+            width: 22.0,
+            color,
+        });
+    }
+    let needle = angle(score as f32);
+    let tip = arc_point(center, radius - 16.0, needle);
+    let base_left = arc_point(center, 6.0, needle + PI / 2.0);
+    let base_right = arc_point(center, 6.0, needle - PI / 2.0);
+    chart.shapes.push(Shape::Polygon {
+        points: vec![base_left, tip, base_right],
+        fill: TEXT,
+    });
+    chart.text(
+        center.0 - radius,
+        center.1 + 24.0,
+        11.0,
+        Anchor::Middle,
+        MUTED,
+        "0".to_string(),
+    );
+    chart.text(
+        center.0 + radius,
+        center.1 + 24.0,
+        11.0,
+        Anchor::Middle,
+        MUTED,
+        "100".to_string(),
+    );
+    chart.text(
+        center.0,
+        center.1 + 44.0,
+        22.0,
+        Anchor::Middle,
+        TEXT,
+        format!("{:.1} ({})", score, score_rating(score)),
+    );
+    chart
+}
+
+/// Tick label for a campaign offset, precise enough to tell short runs' ticks apart.
+fn seconds(value: f32) -> String {
+    if value < 1.0 {
+        format!("{:.0}ms", value * 1000.0)
+    } else if value < 10.0 {
+        format!("{:.1}s", value)
+    } else {
+        format!("{:.0}s", value)
+    }
+}
+
+fn arc_point(center: (f32, f32), radius: f32, angle: f32) -> (f32, f32) {
+    (
+        center.0 + radius * angle.cos(),
+        center.1 - radius * angle.sin(),
+    )
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The GUI's proportional font, so PNG charts match what the report GUI shows.
+fn chart_font() -> Result<FontArc> {
+    let fonts = eframe::egui::FontDefinitions::default();
+    let data = fonts
+        .font_data
+        .get("Ubuntu-Light")
+        .ok_or_else(|| anyhow!("the bundled chart font is missing"))?;
+    FontArc::try_from_vec(data.font.to_vec()).map_err(|err| anyhow!("loading chart font: {err}"))
+}
+
+/// White RGB raster with anti-aliased fills.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 3]>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[255.0; 3]; width * height],
+        }
+    }
+
+    fn blend(&mut self, x: i64, y: i64, color: Color, coverage: f32) {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return;
+        }
+        let coverage = coverage.clamp(0.0, 1.0);
+        let pixel = &mut self.pixels[y as usize * self.width + x as usize];
+        for (channel, value) in pixel.iter_mut().zip([color.0, color.1, color.2]) {
+            *channel += (f32::from(value) - *channel) * coverage;
+        }
+    }
+
+    /// Even-odd scanline fill with 4 sub-rows per pixel and exact horizontal coverage.
+    fn fill_polygon(&mut self, points: &[(f32, f32)], color: Color) {
+        const SUB: usize = 4;
+        if points.len() < 3 {
+            return;
+        }
+        let min_y = points
+            .iter()
+            .map(|p| p.1)
+            .fold(f32::INFINITY, f32::min)
+            .floor()
+            .max(0.0);
+        let max_y = points
+            .iter()
+            .map(|p| p.1)
+            .fold(f32::NEG_INFINITY, f32::max)
+            .ceil()
+            .min(self.height as f32);
+        let min_x = points
+            .iter()
+            .map(|p| p.0)
+            .fold(f32::INFINITY, f32::min)
+            .floor()
+            .max(0.0);
+        let max_x = points
+            .iter()
+            .map(|p| p.0)
+            .fold(f32::NEG_INFINITY, f32::max)
+            .ceil()
+            .min(self.width as f32);
+        if min_x >= max_x || min_y >= max_y {
+            return;
+        }
+        let origin = min_x as usize;
+        let mut coverage = vec![0.0f32; max_x as usize - origin];
+        for row in min_y as usize..max_y as usize {
+            coverage.iter_mut().for_each(|c| *c = 0.0);
+            for sub in 0..SUB {
+                let sample_y = row as f32 + (sub as f32 + 0.5) / SUB as f32;
+                let mut crossings: Vec<f32> = Vec::new();
+                for (i, a) in points.iter().enumerate() {
+                    let b = points[(i + 1) % points.len()];
+                    if (a.1 <= sample_y) != (b.1 <= sample_y) {
+                        crossings.push(a.0 + (sample_y - a.1) / (b.1 - a.1) * (b.0 - a.0));
+                    }
+                }
+                crossings.sort_by(f32::total_cmp);
+                for span in crossings.chunks_exact(2) {
+                    let (start, end) = (span[0].max(min_x), span[1].min(max_x));
+                    let mut x = start;
+                    while x < end {
+                        let cell = x.floor();
+                        let next = (cell + 1.0).min(end);
+                        coverage[cell as usize - origin] += (next - x) / SUB as f32;
+                        x = next;
+                    }
+                }
+            }
+            for (i, amount) in coverage.iter().enumerate() {
+                if *amount > 0.0 {
+                    self.blend((origin + i) as i64, row as i64, color, *amount);
+                }
+            }
+        }
+    }
+
+    fn draw_text(
+        &mut self,
+        font: &FontArc,
+        (x, y): (f32, f32),
+        size: f32,
+        anchor: Anchor,
+        color: Color,
+        text: &str,
+    ) {
+        // Chart sizes are em sizes, as in SVG; ab_glyph scales by ascent-to-descent height.
+        let em = font.height_unscaled() / font.units_per_em().unwrap_or(1000.0);
+        let scaled = font.as_scaled(PxScale::from(size * em));
+        let width: f32 = text
+            .chars()
+            .map(|ch| scaled.h_advance(font.glyph_id(ch)))
+            .sum();
+        let mut pen = match anchor {
+            Anchor::Start => x,
+            Anchor::Middle => x - width / 2.0,
+            Anchor::End => x - width,
+        };
+        for ch in text.chars() {
+            let id = font.glyph_id(ch);
+            let glyph = id.with_scale_and_position(scaled.scale(), ab_glyph::point(pen, y));
+            if let Some(outline) = font.outline_glyph(glyph) {
+                let bounds = outline.px_bounds();
+                outline.draw(|gx, gy, coverage| {
+                    self.blend(
+                        bounds.min.x as i64 + i64::from(gx),
+                        bounds.min.y as i64 + i64::from(gy),
+                        color,
+                        coverage,
+                    );
+                });
+            }
+            pen += scaled.h_advance(id);
+        }
+    }
+
+    fn encode_png(&self) -> Result<Vec<u8>> {
+        let data: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| pixel.map(|channel| channel.round() as u8))
+            .collect();
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().context("encoding chart PNG")?;
+        writer
+            .write_image_data(&data)
+            .context("encoding chart PNG")?;
+        writer.finish().context("encoding chart PNG")?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AttackAxis;
+
+    fn attack(
+        axis: AttackAxis,
+        success: bool,
+        skipped: bool,
+        crashes: usize,
+        secs: u64,
+    ) -> AttackResult {
+        let crash = serde_json::json!({
+            "timestamp": "2026-01-01T00:00:00Z",
+            "signal": "SIGSEGV",
+            "backtrace": null,
+            "stderr": "",
+            "stdout": ""
+        });
+        serde_json::from_value(serde_json::json!({
+            "program": "app",
+            "axis": axis,
+            "success": success,
+            "skipped": skipped,
+            "exit_code": null,
+            "duration": { "secs": secs, "nanos": 0 },
+            "peak_memory": 0,
+            "crashes": vec![crash; crashes],
+            "signatures_detected": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_outcomes_and_timeline_follow_run_order() {
+        let results = vec![
+            attack(AttackAxis::Cpu, true, false, 0, 3),
+            attack(AttackAxis::Memory, false, false, 2, 1),
+            attack(AttackAxis::Cpu, false, false, 1, 0),
+            attack(AttackAxis::Disk, false, true, 0, 0),
+        ];
+        let svg = outcomes(&results).to_svg();
+        let cpu = svg.find(">cpu</text>").unwrap();
+        assert!(cpu < svg.find(">memory</text>").unwrap());
+        assert!(svg.contains(">1 passed, 1 failed, 1 crash(es)</text>"));
+        assert!(svg.contains(">1 failed, 2 crash(es)</text>"));
+        assert!(svg.contains(">1 skipped</text>"));
+
+        let timeline = timeline(&results);
+        let markers = timeline
+            .shapes
+            .iter()
+            .filter(|shape| matches!(shape, Shape::Polygon { .. }))
+            .count();
+        assert_eq!(markers, 2);
+        assert!(timeline.to_svg().contains(">×2</text>"));
+        assert!(timeline.to_svg().contains(">4.0s</text>"));
+        assert_eq!(seconds(0.25), "250ms");
+        assert_eq!(seconds(42.4), "42s");
+    }
+
+    #[test]
+    fn test_gauge_svg_and_png_render() {
+        let chart = robustness(72.5);
+        let svg = chart.to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"360\""));
+        assert!(svg.contains(">72.5 (fair)</text>"));
+        assert_eq!(svg.matches("<path d=\"M ").count(), 3);
+
+        let png = chart.to_png(1.0).unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let frame = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((frame.width, frame.height), (360, 260));
+        // The poor band sits on the gauge's left end, the good band on its right.
+        let at = |x: usize, y: usize| {
+            let i = (y * frame.width as usize + x) * 3;
+            (pixels[i], pixels[i + 1], pixels[i + 2])
+        };
+        assert_eq!(at(70, 188), (FAILED.0, FAILED.1, FAILED.2));
+        assert_eq!(at(290, 188), (PASSED.0, PASSED.1, PASSED.2));
+        assert_eq!(at(5, 5), (255, 255, 255));
+
+        assert_eq!(
+            ChartFormat::from_path(Path::new("out.PNG")).unwrap(),
+            ChartFormat::Png
+        );
+        assert!(ChartFormat::from_path(Path::new("out.jpg")).is_err());
+    }
+}
@@ -2,14 +2,103 @@
 
 //! Diff utilities for assault reports.
 
+use crate::schema::{self, SchemaKind};
+use crate::triage::{self, Annotation, TriageStore};
 use crate::types::*;
 use anyhow::{Context, Result};
+use serde::Serialize;
 use serde_json;
 use serde_yaml;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+/// Deltas below both floors are never significant, whatever the thresholds say.
+const MIN_DURATION_DELTA_SECS: f64 = 0.1;
+const MIN_MEMORY_DELTA_BYTES: f64 = 1024.0 * 1024.0;
+/// Samples per axis needed before learned variance replaces the fixed thresholds.
+const MIN_VARIANCE_SAMPLES: usize = 3;
+
+/// How per-axis duration and memory changes are judged against noise.
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Relative duration change (0.10 = 10%) below which a delta is noise.
+    pub duration_threshold: f64,
+    /// Relative peak-memory change below which a delta is noise.
+    pub memory_threshold: f64,
+    /// Repeated runs of the base configuration; with enough samples per axis the delta must
+    /// exceed `sigma` standard deviations of base + history instead of the fixed thresholds.
+    pub history: Vec<AssaultReport>,
+    pub sigma: f64,
+    /// List noise-level deltas too instead of only counting them.
+    pub show_noise: bool,
+    /// Annotations carried over to findings present in either run.
+    pub triage: TriageStore,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            duration_threshold: 0.10,
+            memory_threshold: 0.10,
+            history: Vec::new(),
+            sigma: 2.0,
+            show_noise: false,
+            triage: TriageStore::default(),
+        }
+    }
+}
+
+/// Change in one per-axis metric and whether it clears the noise bar.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub axis: AttackAxis,
+    pub metric: &'static str,
+    pub base: f64,
+    pub compare: f64,
+    pub delta: f64,
+    pub significant: bool,
+    /// How significance was decided, e.g. `threshold 10%` or `2.0σ over 5 runs`.
+    pub basis: String,
+}
+
+impl MetricDelta {
+    fn describe(&self) -> String {
+        let (base, compare, delta) = match self.metric {
+            "peak_memory" => (
+                fmt_bytes(self.base),
+                fmt_bytes(self.compare),
+                format!(
+                    "{}{}",
+                    if self.delta >= 0.0 { "+" } else { "-" },
+                    fmt_bytes(self.delta.abs())
+                ),
+            ),
+            _ => (
+                format!("{:.2}s", self.base),
+                format!("{:.2}s", self.compare),
+                format!("{:+.2}s", self.delta),
+            ),
+        };
+        let relative = if self.base > 0.0 {
+            format!(", {:+.1}%", self.delta / self.base * 100.0)
+        } else {
+            String::new()
+        };
+        format!(
+            "{:?} {}: {} -> {} ({}{}; {}{})",
+            self.axis,
+            self.metric,
+            base,
+            compare,
+            delta,
+            relative,
+            if self.significant { "" } else { "noise, " },
+            self.basis
+        )
+    }
+}
+
 pub fn load_report(path: &Path) -> Result<AssaultReport> {
     let content =
         fs::read_to_string(path).with_context(|| format!("reading report {}", path.display()))?;
@@ -22,17 +111,292 @@ pub fn load_report(path: &Path) -> Result<AssaultReport> {
     }
 }
 
+/// Like [`load_report`], but rejects fields this version does not know (`--strict`), so drift
+/// between the writing and reading versions fails loudly instead of dropping data.
+pub fn load_report_strict(path: &Path) -> Result<AssaultReport> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("reading report {}", path.display()))?;
+    let value = schema::parse_document(&content, path)?;
+    schema::ensure_valid(SchemaKind::Report, &value, path)?;
+    serde_json::from_value(value).with_context(|| format!("parsing report {}", path.display()))
+}
+
+/// Machine-readable form of a diff, for bots that gate merges on specific changes.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffReport {
+    pub base: String,
+    pub compare: String,
+    pub robustness_score: Delta<f64>,
+    pub total_crashes: Delta<i64>,
+    pub total_signatures: Delta<i64>,
+    pub weak_points: Delta<i64>,
+    pub findings: FindingChanges,
+    pub signatures: SignatureChanges,
+    /// Axes whose overall status (passed/failed/skipped) differs between the runs.
+    pub axes: Vec<AxisChange>,
+    /// Per-axis duration/memory deltas; check `significant` to ignore noise.
+    pub metrics: Vec<MetricDelta>,
+    /// Targets whose binary differs between the runs, so the runs did not stress the same build.
+    pub targets: Vec<TargetChange>,
+    /// Annotated findings of either run with their current triage state.
+    pub triage: Vec<TriagedFinding>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Delta<T> {
+    pub base: T,
+    pub compare: T,
+    pub delta: T,
+}
+
+impl<T: Copy + std::ops::Sub<Output = T>> Delta<T> {
+    pub(crate) fn new(base: T, compare: T) -> Self {
+        Self {
+            base,
+            compare,
+            delta: compare - base,
+        }
+    }
+}
+
+/// Weak points matched by category and location; a match with a different severity or
+/// description is reported as changed rather than as a removal plus an addition.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FindingChanges {
+    pub added: Vec<WeakPoint>,
+    pub removed: Vec<WeakPoint>,
+    pub changed: Vec<FindingChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FindingChange {
+    pub before: WeakPoint,
+    pub after: WeakPoint,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SignatureChanges {
+    pub added: Vec<SignatureType>,
+    pub removed: Vec<SignatureType>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TargetChange {
+    pub base: TargetProvenance,
+    pub compare: TargetProvenance,
+}
+
+/// A finding with a triage annotation and the runs it appears in.
+#[derive(Debug, Clone, Serialize)]
+pub struct TriagedFinding {
+    pub finding: String,
+    pub annotation: Annotation,
+    pub in_base: bool,
+    pub in_compare: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AxisChange {
+    pub axis: AttackAxis,
+    pub base: String,
+    pub compare: String,
+}
+
+impl DiffReport {
+    pub fn build(
+        base: &AssaultReport,
+        compare: &AssaultReport,
+        base_label: &str,
+        compare_label: &str,
+        options: &DiffOptions,
+    ) -> Self {
+        let base_axes = axis_status_map(&base.attack_results);
+        let compare_axes = axis_status_map(&compare.attack_results);
+        let axes = AttackAxis::all()
+            .into_iter()
+            .filter_map(|axis| {
+                let base_status = base_axes.get(&axis).cloned().unwrap_or("-".to_string());
+                let compare_status = compare_axes.get(&axis).cloned().unwrap_or("-".to_string());
+                (base_status != compare_status).then_some(AxisChange {
+                    axis,
+                    base: base_status,
+                    compare: compare_status,
+                })
+            })
+            .collect();
+
+        let base_signatures = signature_types(base);
+        let compare_signatures = signature_types(compare);
+        let signatures = SignatureChanges {
+            added: compare_signatures
+                .iter()
+                .filter(|kind| !base_signatures.contains(kind))
+                .copied()
+                .collect(),
+            removed: base_signatures
+                .iter()
+                .filter(|kind| !compare_signatures.contains(kind))
+                .copied()
+                .collect(),
+        };
+
+        Self {
+            base: base_label.to_string(),
+            compare: compare_label.to_string(),
+            robustness_score: Delta::new(
+                base.overall_assessment.robustness_score,
+                compare.overall_assessment.robustness_score,
+            ),
+            total_crashes: Delta::new(base.total_crashes as i64, compare.total_crashes as i64),
+            total_signatures: Delta::new(
+                base.total_signatures as i64,
+                compare.total_signatures as i64,
+            ),
+            weak_points: Delta::new(
+                base.assail_report.weak_points.len() as i64,
+                compare.assail_report.weak_points.len() as i64,
+            ),
+            findings: diff_findings(
+                &base.assail_report.weak_points,
+                &compare.assail_report.weak_points,
+            ),
+            signatures,
+            axes,
+            metrics: metric_deltas(base, compare, options),
+            targets: target_changes(base, compare),
+            triage: triaged_findings(base, compare, &options.triage),
+        }
+    }
+}
+
+/// Findings of either run that carry an annotation, in base order then new ones.
+fn triaged_findings(
+    base: &AssaultReport,
+    compare: &AssaultReport,
+    store: &TriageStore,
+) -> Vec<TriagedFinding> {
+    let base_ids: Vec<String> = triage::finding_ids(base)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    let compare_ids: Vec<String> = triage::finding_ids(compare)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    let mut triaged: Vec<TriagedFinding> = Vec::new();
+    for id in base_ids.iter().chain(&compare_ids) {
+        if triaged.iter().any(|known| known.finding == *id) {
+            continue;
+        }
+        if let Some(annotation) = store.current(id) {
+            triaged.push(TriagedFinding {
+                finding: id.clone(),
+                annotation: annotation.clone(),
+                in_base: base_ids.contains(id),
+                in_compare: compare_ids.contains(id),
+            });
+        }
+    }
+    triaged
+}
+
+fn format_triage_summary(triaged: &[TriagedFinding]) -> Vec<String> {
+    let mut lines = vec!["Triage:".to_string()];
+    for finding in triaged {
+        let presence = match (finding.in_base, finding.in_compare) {
+            (true, true) => "still present",
+            (true, false) => "gone in compare",
+            _ => "new in compare",
+        };
+        lines.push(format!(
+            "  {} [{}]: {}",
+            finding.finding,
+            presence,
+            finding.annotation.describe()
+        ));
+    }
+    lines
+}
+
+/// Targets paired across the runs with a different SHA-256. Single-target reports pair up
+/// whatever the paths; otherwise targets pair by file name, since builds often move directory.
+fn target_changes(base: &AssaultReport, compare: &AssaultReport) -> Vec<TargetChange> {
+    let paired = |before: &TargetProvenance, after: &TargetProvenance| {
+        (base.targets.len() == 1 && compare.targets.len() == 1)
+            || before.program.file_name() == after.program.file_name()
+    };
+    base.targets
+        .iter()
+        .filter_map(|before| {
+            let after = compare.targets.iter().find(|after| paired(before, after))?;
+            (before.sha256 != after.sha256).then(|| TargetChange {
+                base: before.clone(),
+                compare: after.clone(),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn diff_findings(base: &[WeakPoint], compare: &[WeakPoint]) -> FindingChanges {
+    let same_place =
+        |a: &WeakPoint, b: &WeakPoint| a.category == b.category && a.location == b.location;
+    let mut unmatched: Vec<&WeakPoint> = base.iter().collect();
+    let mut fresh = Vec::new();
+    for point in compare {
+        let identical = unmatched.iter().position(|old| {
+            same_place(old, point)
+                && old.severity == point.severity
+                && old.description == point.description
+        });
+        match identical {
+            Some(index) => {
+                unmatched.remove(index);
+            }
+            None => fresh.push(point),
+        }
+    }
+
+    let mut changes = FindingChanges::default();
+    for point in fresh {
+        match unmatched.iter().position(|old| same_place(old, point)) {
+            Some(index) => changes.changed.push(FindingChange {
+                before: unmatched.remove(index).clone(),
+                after: point.clone(),
+            }),
+            None => changes.added.push(point.clone()),
+        }
+    }
+    changes.removed = unmatched.into_iter().cloned().collect();
+    changes
+}
+
+fn signature_types(report: &AssaultReport) -> Vec<SignatureType> {
+    let mut kinds = Vec::new();
+    for signature in report
+        .attack_results
+        .iter()
+        .flat_map(|result| &result.signatures_detected)
+    {
+        if !kinds.contains(&signature.signature_type) {
+            kinds.push(signature.signature_type);
+        }
+    }
+    kinds
+}
+
 pub fn format_diff(
     base: &AssaultReport,
     compare: &AssaultReport,
     base_label: &str,
     compare_label: &str,
+    options: &DiffOptions,
 ) -> String {
     // Diff output is human-first text, optimized for terminal/code-review workflows.
     let mut lines = Vec::new();
     lines.push("=== PANIC-ATTACK REPORT DIFF ===".to_string());
     lines.push(format!("Base: {}", base_label));
     lines.push(format!("Compare: {}", compare_label));
+    lines.extend(format_target_summary(base, compare));
     lines.push(String::new());
 
     let score_delta =
@@ -68,15 +432,68 @@ pub fn format_diff(
         compare.assail_report.weak_points.len(),
         fmt_delta_i64(weak_delta)
     ));
+    let findings = diff_findings(
+        &base.assail_report.weak_points,
+        &compare.assail_report.weak_points,
+    );
+    lines.push(format!(
+        "Findings: {} added, {} removed, {} changed",
+        findings.added.len(),
+        findings.removed.len(),
+        findings.changed.len()
+    ));
 
     lines.push(String::new());
     lines.extend(format_attack_summary(base, compare));
     lines.push(String::new());
+    lines.extend(format_metric_summary(
+        &metric_deltas(base, compare, options),
+        options,
+    ));
+    lines.push(String::new());
     lines.extend(format_assail_summary(base, compare));
 
+    let triaged = triaged_findings(base, compare, &options.triage);
+    if !triaged.is_empty() {
+        lines.push(String::new());
+        lines.extend(format_triage_summary(&triaged));
+    }
+
     lines.join("\n")
 }
 
+fn format_target_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<String> {
+    if base.targets.is_empty() || compare.targets.is_empty() {
+        return Vec::new();
+    }
+    let changes = target_changes(base, compare);
+    if changes.is_empty() {
+        return vec!["Target: same build in both runs".to_string()];
+    }
+    let mut lines = Vec::new();
+    for change in changes {
+        lines.push(format!(
+            "WARNING: different target build: {} {} -> {} {}",
+            change.base.program.display(),
+            short_hash(&change.base.sha256),
+            change.compare.program.display(),
+            short_hash(&change.compare.sha256)
+        ));
+        if change.base.version != change.compare.version {
+            lines.push(format!(
+                "  Version: {} -> {}",
+                change.base.version.as_deref().unwrap_or("-"),
+                change.compare.version.as_deref().unwrap_or("-")
+            ));
+        }
+    }
+    lines
+}
+
+fn short_hash(sha256: &str) -> String {
+    format!("sha256:{}", &sha256[..sha256.len().min(12)])
+}
+
 fn format_attack_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<String> {
     let mut lines = Vec::new();
     lines.push("Attack outcomes:".to_string());
@@ -128,6 +545,164 @@ fn format_attack_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<S
     lines
 }
 
+/// Per-axis mean duration and peak memory deltas, each judged against noise.
+pub fn metric_deltas(
+    base: &AssaultReport,
+    compare: &AssaultReport,
+    options: &DiffOptions,
+) -> Vec<MetricDelta> {
+    let mut deltas = Vec::new();
+    for axis in AttackAxis::all() {
+        let (Some(base_metrics), Some(compare_metrics)) = (
+            axis_metrics(base, axis.clone()),
+            axis_metrics(compare, axis.clone()),
+        ) else {
+            continue;
+        };
+        let history: Vec<AxisMetrics> = std::iter::once(base_metrics)
+            .chain(
+                options
+                    .history
+                    .iter()
+                    .filter_map(|report| axis_metrics(report, axis.clone().clone())),
+            )
+            .collect();
+
+        let metrics = [
+            (
+                "duration",
+                base_metrics.duration,
+                compare_metrics.duration,
+                options.duration_threshold,
+                MIN_DURATION_DELTA_SECS,
+                history.iter().map(|m| m.duration).collect::<Vec<_>>(),
+            ),
+            (
+                "peak_memory",
+                base_metrics.peak_memory,
+                compare_metrics.peak_memory,
+                options.memory_threshold,
+                MIN_MEMORY_DELTA_BYTES,
+                history.iter().map(|m| m.peak_memory).collect::<Vec<_>>(),
+            ),
+        ];
+        for (metric, base_value, compare_value, threshold, floor, samples) in metrics {
+            if base_value == compare_value {
+                continue;
+            }
+            let (significant, basis) = judge(
+                base_value,
+                compare_value,
+                threshold,
+                floor,
+                &samples,
+                options.sigma,
+            );
+            deltas.push(MetricDelta {
+                axis: axis.clone(),
+                metric,
+                base: base_value,
+                compare: compare_value,
+                delta: compare_value - base_value,
+                significant,
+                basis,
+            });
+        }
+    }
+    deltas
+}
+
+fn judge(
+    base: f64,
+    compare: f64,
+    threshold: f64,
+    floor: f64,
+    samples: &[f64],
+    sigma: f64,
+) -> (bool, String) {
+    if (compare - base).abs() < floor {
+        return (false, "below absolute floor".to_string());
+    }
+    if samples.len() >= MIN_VARIANCE_SAMPLES {
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (samples.len() - 1) as f64;
+        let deviation = variance.sqrt();
+        let significant = (compare - mean).abs() > sigma * deviation;
+        return (
+            significant,
+            format!("{:.1}σ over {} runs", sigma, samples.len()),
+        );
+    }
+    let relative = if base > 0.0 {
+        (compare - base).abs() / base
+    } else {
+        f64::INFINITY
+    };
+    (
+        relative > threshold,
+        format!("threshold {:.0}%", threshold * 100.0),
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AxisMetrics {
+    /// Mean seconds across the axis's non-skipped runs.
+    duration: f64,
+    /// Largest peak memory in bytes across those runs.
+    peak_memory: f64,
+}
+
+fn axis_metrics(report: &AssaultReport, axis: AttackAxis) -> Option<AxisMetrics> {
+    let runs: Vec<&AttackResult> = report
+        .attack_results
+        .iter()
+        .filter(|result| result.axis == axis && !result.skipped)
+        .collect();
+    if runs.is_empty() {
+        return None;
+    }
+    let duration = runs.iter().map(|r| r.duration.as_secs_f64()).sum::<f64>() / runs.len() as f64;
+    let peak_memory = runs.iter().map(|r| r.peak_memory).max().unwrap_or(0) as f64;
+    Some(AxisMetrics {
+        duration,
+        peak_memory,
+    })
+}
+
+fn format_metric_summary(deltas: &[MetricDelta], options: &DiffOptions) -> Vec<String> {
+    let mut lines = vec!["Per-axis metrics:".to_string()];
+    let noise = deltas.iter().filter(|delta| !delta.significant).count();
+    for delta in deltas {
+        if delta.significant || options.show_noise {
+            lines.push(format!("  {}", delta.describe()));
+        }
+    }
+    if deltas.iter().all(|delta| !delta.significant) {
+        lines.push("  No significant duration or memory changes".to_string());
+    }
+    if noise > 0 && !options.show_noise {
+        lines.push(format!(
+            "  {} change(s) within noise hidden (--show-noise to list)",
+            noise
+        ));
+    }
+    lines
+}
+
+fn fmt_bytes(bytes: f64) -> String {
+    if bytes >= 1024.0 * 1024.0 {
+        format!("{:.1} MiB", bytes / (1024.0 * 1024.0))
+    } else if bytes >= 1024.0 {
+        format!("{:.1} KiB", bytes / 1024.0)
+    } else {
+        format!("{:.0} B", bytes)
+    }
+}
+
 fn format_assail_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<String> {
     let mut lines = Vec::new();
     lines.push("Assail summary:".to_string());
@@ -147,13 +722,13 @@ fn format_assail_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<S
         .assail_report
         .recommended_attacks
         .iter()
-        .copied()
+        .cloned()
         .collect();
     let cmp_rec: HashSet<_> = compare
         .assail_report
         .recommended_attacks
         .iter()
-        .copied()
+        .cloned()
         .collect();
     let added_rec: Vec<_> = cmp_rec.difference(&base_rec).collect();
     let removed_rec: Vec<_> = base_rec.difference(&cmp_rec).collect();
@@ -205,7 +780,7 @@ fn format_assail_summary(base: &AssaultReport, compare: &AssaultReport) -> Vec<S
     lines
 }
 
-fn fmt_delta_i64(delta: i64) -> String {
+pub(crate) fn fmt_delta_i64(delta: i64) -> String {
     if delta > 0 {
         format!("+{}", delta)
     } else {
@@ -232,7 +807,7 @@ fn count_attack_status(results: &[AttackResult]) -> (usize, usize, usize) {
 fn axis_status_map(results: &[AttackResult]) -> HashMap<AttackAxis, String> {
     let mut map: HashMap<AttackAxis, Vec<&AttackResult>> = HashMap::new();
     for result in results {
-        map.entry(result.axis).or_default().push(result);
+        map.entry(result.axis.clone()).or_default().push(result);
     }
 
     map.into_iter()
@@ -272,3 +847,62 @@ fn count_severity(points: &[WeakPoint]) -> BTreeMap<Severity, usize> {
     }
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weak_point(location: &str, severity: Severity, description: &str) -> WeakPoint {
+        WeakPoint {
+            category: WeakPointCategory::PanicPath,
+            location: Some(location.to_string()),
+            severity,
+            description: description.to_string(),
+            recommended_attack: vec![],
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn test_findings_added_removed_changed() {
+        let base = vec![
+            weak_point("src/a.rs", Severity::Low, "2 unwrap calls"),
+            weak_point("src/b.rs", Severity::Medium, "panic!"),
+            weak_point("src/c.rs", Severity::Low, "expect"),
+        ];
+        let compare = vec![
+            weak_point("src/a.rs", Severity::High, "9 unwrap calls"),
+            weak_point("src/c.rs", Severity::Low, "expect"),
+            weak_point("src/d.rs", Severity::Low, "unreachable!"),
+        ];
+        let changes = diff_findings(&base, &compare);
+        assert_eq!(changes.changed.len(), 1);
+        assert_eq!(changes.changed[0].after.severity, Severity::High);
+        assert_eq!(changes.added.len(), 1);
+        assert_eq!(changes.added[0].location.as_deref(), Some("src/d.rs"));
+        assert_eq!(changes.removed.len(), 1);
+        assert_eq!(changes.removed[0].location.as_deref(), Some("src/b.rs"));
+    }
+
+    #[test]
+    fn test_threshold_and_floor() {
+        assert!(!judge(10.0, 10.5, 0.10, 0.1, &[10.0], 2.0).0);
+        assert!(judge(10.0, 12.0, 0.10, 0.1, &[10.0], 2.0).0);
+        // 50% slower but only 50ms: under the absolute floor.
+        assert!(!judge(0.1, 0.15, 0.10, 0.1, &[0.1], 2.0).0);
+    }
+
+    #[test]
+    fn test_learned_variance_overrides_threshold() {
+        // A noisy axis: 12s is within two standard deviations of the history.
+        let noisy = [10.0, 13.0, 8.0, 12.0, 9.0];
+        let (significant, basis) = judge(10.0, 12.0, 0.10, 0.1, &noisy, 2.0);
+        assert!(!significant);
+        assert_eq!(basis, "2.0σ over 5 runs");
+        // A steady axis: the same change stands out.
+        let steady = [10.0, 10.1, 9.9, 10.0];
+        assert!(judge(10.0, 12.0, 0.10, 0.1, &steady, 2.0).0);
+    }
+}
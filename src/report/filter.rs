@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Console-only narrowing of a report (`--min-severity`, `--only-axis`, `--only-category`).
+//!
+//! CI logs that truncate a full accordion dump can print just the findings that matter.
+//! Saved reports are never filtered, and the robustness score and assessment still describe
+//! the whole run.
+
+use crate::types::{AssaultReport, AttackAxis, Severity, WeakPointCategory};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindingFilter {
+    /// Weak points below this severity are hidden, and so are attacks that found nothing.
+    pub min_severity: Option<Severity>,
+    /// Attacks on other axes are hidden, and weak points that recommend none of these axes.
+    pub axes: Vec<AttackAxis>,
+    /// Weak points (and taint rows) of other categories are hidden.
+    pub categories: Vec<WeakPointCategory>,
+}
+
+impl FindingFilter {
+    pub fn is_empty(&self) -> bool {
+        self.min_severity.is_none() && self.axes.is_empty() && self.categories.is_empty()
+    }
+
+    /// Narrow `report` to the matching findings, recount its crashes and signatures and note
+    /// what was hidden.
+    pub fn apply(&self, report: &mut AssaultReport) {
+        if self.is_empty() {
+            return;
+        }
+        let before = report.assail_report.weak_points.len() + report.attack_results.len();
+        report.assail_report.weak_points.retain(|wp| {
+            self.min_severity.is_none_or(|min| wp.severity >= min)
+                && (self.axes.is_empty()
+                    || wp.recommended_attack.iter().any(|a| self.axes.contains(a)))
+                && (self.categories.is_empty() || self.categories.contains(&wp.category))
+        });
+        report.attack_results.retain(|result| {
+            (self.axes.is_empty() || self.axes.contains(&result.axis))
+                && (self.min_severity.is_none()
+                    || !result.crashes.is_empty()
+                    || !result.signatures_detected.is_empty())
+        });
+        report.assail_report.taint_matrix.rows.retain(|row| {
+            (self.axes.is_empty() || self.axes.contains(&row.sink_axis))
+                && (self.categories.is_empty() || self.categories.contains(&row.source_category))
+        });
+        report.total_crashes = report
+            .attack_results
+            .iter()
+            .map(|result| result.crashes.len())
+            .sum();
+        report.total_signatures = report
+            .attack_results
+            .iter()
+            .map(|result| result.signatures_detected.len())
+            .sum();
+        let hidden = before - report.assail_report.weak_points.len() - report.attack_results.len();
+        report.notes.push(format!(
+            "Filtered to {}; {} weak point(s) and attack(s) hidden",
+            self.describe(),
+            hidden
+        ));
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(min) = self.min_severity {
+            parts.push(format!("severity {} and above", min));
+        }
+        if !self.axes.is_empty() {
+            let axes: Vec<&str> = self.axes.iter().map(AttackAxis::name).collect();
+            parts.push(format!("axis {}", axes.join("/")));
+        }
+        if !self.categories.is_empty() {
+            let categories: Vec<String> =
+                self.categories.iter().map(|c| format!("{:?}", c)).collect();
+            parts.push(format!("category {}", categories.join("/")));
+        }
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    fn report() -> AssaultReport {
+        let weak_point = |category, severity, axes: Vec<AttackAxis>| WeakPoint {
+            category,
+            location: Some("src/lib.rs:1".to_string()),
+            severity,
+            description: String::new(),
+            recommended_attack: axes,
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        };
+        let attack = |axis: &str, crashes: usize| {
+            let crash = serde_json::json!({
+                "timestamp": "", "signal": null, "backtrace": null, "stderr": "", "stdout": ""
+            });
+            serde_json::from_value::<AttackResult>(serde_json::json!({
+                "program": "app",
+                "axis": axis,
+                "success": crashes == 0,
+                "exit_code": null,
+                "duration": { "secs": 1, "nanos": 0 },
+                "peak_memory": 0,
+                "crashes": vec![crash; crashes],
+                "signatures_detected": []
+            }))
+            .unwrap()
+        };
+        let mut report: AssaultReport = serde_json::from_str(include_str!(
+            "../../verisimdb-data/verisimdb/panic-attack-20260211180017.json"
+        ))
+        .unwrap();
+        report.assail_report.weak_points = vec![
+            weak_point(
+                WeakPointCategory::PanicPath,
+                Severity::Critical,
+                vec![AttackAxis::Memory],
+            ),
+            weak_point(
+                WeakPointCategory::UnsafeCode,
+                Severity::High,
+                vec![AttackAxis::Cpu],
+            ),
+            weak_point(
+                WeakPointCategory::PanicPath,
+                Severity::Low,
+                vec![AttackAxis::Cpu],
+            ),
+        ];
+        report.attack_results = vec![attack("cpu", 0), attack("memory", 2), attack("cpu", 1)];
+        report.notes.clear();
+        report
+    }
+
+    #[test]
+    fn test_filter_narrows_findings_and_recounts() {
+        let mut narrowed = report();
+        FindingFilter {
+            min_severity: Some(Severity::High),
+            ..FindingFilter::default()
+        }
+        .apply(&mut narrowed);
+        assert_eq!(narrowed.assail_report.weak_points.len(), 2);
+        // Attacks that found nothing are not findings.
+        assert_eq!(narrowed.attack_results.len(), 2);
+        assert_eq!(narrowed.total_crashes, 3);
+        assert_eq!(
+            narrowed.notes,
+            ["Filtered to severity HIGH and above; 2 weak point(s) and attack(s) hidden"]
+        );
+
+        let mut narrowed = report();
+        FindingFilter {
+            axes: vec![AttackAxis::Cpu],
+            categories: vec![WeakPointCategory::PanicPath],
+            ..FindingFilter::default()
+        }
+        .apply(&mut narrowed);
+        assert_eq!(narrowed.assail_report.weak_points.len(), 1);
+        assert_eq!(
+            narrowed.assail_report.weak_points[0].severity,
+            Severity::Low
+        );
+        assert_eq!(narrowed.attack_results.len(), 2);
+        assert_eq!(narrowed.total_crashes, 1);
+        assert_eq!(
+            narrowed.notes,
+            ["Filtered to axis cpu, category PanicPath; 3 weak point(s) and attack(s) hidden"]
+        );
+
+        let mut untouched = report();
+        FindingFilter::default().apply(&mut untouched);
+        assert_eq!(untouched.attack_results.len(), 3);
+        assert!(untouched.notes.is_empty());
+    }
+}
@@ -2,13 +2,14 @@
 
 //! Report formatting and output
 
+use crate::signatures::backtrace;
+use crate::storage::write_atomic;
 use crate::types::*;
 use anyhow::Result;
 use clap::ValueEnum;
 use colored::*;
 use serde_json;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
 
 #[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
@@ -19,11 +20,27 @@ pub enum ReportView {
     Matrix,
 }
 
-pub struct ReportFormatter;
+pub struct ReportFormatter {
+    accessible: bool,
+}
+
+impl Default for ReportFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ReportFormatter {
     pub fn new() -> Self {
-        Self
+        Self { accessible: false }
+    }
+
+    /// Output for screen readers and restricted terminals: ASCII-only glyphs, severities and
+    /// the score rating spelled out, and the overall assessment read first. Colors are left
+    /// to the caller (`colored::control`), but nothing relies on them.
+    pub fn with_accessible(mut self, accessible: bool) -> Self {
+        self.accessible = accessible;
+        self
     }
 
     #[allow(dead_code)]
@@ -40,7 +57,52 @@ impl ReportFormatter {
     ) {
         // `view` controls the primary lens; `show_matrix` can append pivot data to non-matrix views.
         println!("\n{}", "=== PANIC-ATTACK ASSAULT REPORT ===".bold().cyan());
+        if let Some(seed) = report.seed {
+            println!("Seed: {}", seed);
+        }
+        if let Some(applied) = report.redactions_applied {
+            println!("Redactions applied: {}", applied);
+        }
+        for target in &report.targets {
+            let mut identity = format!("sha256:{}", &target.sha256[..target.sha256.len().min(12)]);
+            if let Some(build_id) = &target.build_id {
+                identity.push_str(&format!(", build-id {}", build_id));
+            }
+            if let Some(version) = &target.version {
+                identity.push_str(&format!(", {}", version));
+            }
+            println!("Target: {} ({})", target.program.display(), identity);
+        }
+        if let Some(host) = &report.host_environment {
+            let mut parts = vec![host.os.clone()];
+            if let Some(kernel) = &host.kernel {
+                parts.push(format!("kernel {}", kernel));
+            }
+            parts.push(format!("{} CPUs", host.cpu_count));
+            if let Some(bytes) = host.total_memory_bytes {
+                parts.push(format!("{} MB", bytes / (1024 * 1024)));
+            }
+            if let Some(runtime) = &host.container {
+                parts.push(format!("in {}", runtime));
+            }
+            println!("Host: {}", parts.join(", "));
+        }
+        for note in &report.notes {
+            println!("Note: {}", note.yellow());
+        }
+        for history in &report.crash_history {
+            let status = match history.status {
+                CrashHistoryStatus::NewFailure => history.describe().red().to_string(),
+                _ => history.describe().yellow().to_string(),
+            };
+            println!("Crash {}: {}", history.bucket, status);
+        }
         println!();
+        if self.accessible {
+            // Verdict first, so a linear reader does not have to get through every section.
+            self.print_overall_assessment(&report.overall_assessment);
+            println!();
+        }
 
         let assail = &report.assail_report;
 
@@ -73,15 +135,25 @@ impl ReportFormatter {
         self.print_attack_summary(&report.attack_results);
         println!();
         self.print_signatures(&report.attack_results);
-        println!();
-        self.print_overall_assessment(&report.overall_assessment);
+        if !report.reproduction.is_empty() {
+            println!();
+            self.print_reproduction(&report.reproduction);
+        }
+        if let Some(diff) = &report.state_diff {
+            println!();
+            self.print_state_diff(diff);
+        }
+        if !self.accessible {
+            println!();
+            self.print_overall_assessment(&report.overall_assessment);
+        }
         println!();
     }
 
     #[allow(dead_code)]
     pub fn save<P: AsRef<Path>>(&self, report: &AssaultReport, path: P) -> Result<()> {
         let serialized = serde_json::to_string_pretty(report)?;
-        fs::write(path, serialized)?;
+        write_atomic(path, serialized)?;
         Ok(())
     }
 
@@ -118,6 +190,73 @@ impl ReportFormatter {
         }
     }
 
+    fn print_reproduction(&self, thresholds: &[ReproductionThreshold]) {
+        println!("{}", "REPRODUCTION THRESHOLDS".bold().yellow());
+        for threshold in thresholds {
+            if !threshold.reproducible {
+                println!(
+                    "  {}: did not reproduce at {:?} ({} probe)",
+                    threshold.bucket, threshold.original_intensity, threshold.probes
+                );
+                continue;
+            }
+            let duration = threshold
+                .min_duration
+                .map(|duration| format!(" for {}s", duration.as_secs()))
+                .unwrap_or_default();
+            println!(
+                "  {}: reproduces from {:?}{} (found at {:?}, {} probes)",
+                threshold.bucket,
+                threshold
+                    .min_intensity
+                    .unwrap_or(threshold.original_intensity),
+                duration,
+                threshold.original_intensity,
+                threshold.probes
+            );
+        }
+    }
+
+    fn print_state_diff(&self, diff: &StateDiff) {
+        println!("{}", "STATE CHANGES".bold().yellow());
+        for source in &diff.sources {
+            if let Some(error) = &source.error {
+                println!("  {}: not captured ({})", source.source, error);
+                continue;
+            }
+            let count = source.changes.len() + source.omitted.unwrap_or(0);
+            if count == 0 {
+                println!("  {}: unchanged", source.source);
+                continue;
+            }
+            println!("  {}: {} change(s)", source.source, count);
+            for change in &source.changes {
+                let line = match change.kind {
+                    StateChangeKind::Added => format!(
+                        "+ {} = {}",
+                        change.path,
+                        change.after.as_deref().unwrap_or_default()
+                    ),
+                    StateChangeKind::Removed => format!(
+                        "- {} (was {})",
+                        change.path,
+                        change.before.as_deref().unwrap_or_default()
+                    ),
+                    StateChangeKind::Modified => format!(
+                        "~ {}: {} -> {}",
+                        change.path,
+                        change.before.as_deref().unwrap_or_default(),
+                        change.after.as_deref().unwrap_or_default()
+                    ),
+                };
+                println!("    {}", line);
+            }
+            if let Some(omitted) = source.omitted {
+                println!("    ... and {} more", omitted);
+            }
+        }
+    }
+
     fn print_timeline_summary(&self, timeline: &TimelineReport) {
         println!("{}", "TIMELINE".bold().yellow());
         println!("  Duration: {:.2}s", timeline.duration.as_secs_f64());
@@ -133,10 +272,28 @@ impl ReportFormatter {
                 event.duration.as_secs_f64(),
                 event.intensity
             );
+            if let Some(trigger) = &event.trigger {
+                println!("      triggered by {}", trigger);
+            }
+            if let Some(reason) = &event.stopped_by {
+                println!("      stopped by {}", reason);
+            }
         }
         if timeline.events.len() > 5 {
             println!("    ... and {} more events", timeline.events.len() - 5);
         }
+        if let Some(reason) = &timeline.stopped_by {
+            println!("  Stopped early: {}", reason);
+        }
+        if let Some(runs) = timeline.runs {
+            println!("  Crash attribution over {} runs:", runs);
+            for item in timeline.attribution.iter().take(5) {
+                println!(
+                    "    {} ({:?}): active at {} crash(es), p={:.2}",
+                    item.event, item.axis, item.crashes_while_active, item.crash_probability
+                );
+            }
+        }
     }
 
     fn print_accordion_sections(&self, report: &AssailReport, expand_details: bool) {
@@ -258,6 +415,10 @@ impl ReportFormatter {
                 + fs.panic_sites * 2
                 + fs.unwrap_calls
                 + fs.threading_constructs * 2;
+            if self.accessible {
+                println!("  {}: risk {} of {}", fs.file_path, risk, max_risk);
+                continue;
+            }
             let bar = Self::health_bar(risk as f64, max_risk as f64);
             println!(
                 "  {} | {} {}",
@@ -303,7 +464,7 @@ impl ReportFormatter {
         let mut aggregate: HashMap<(WeakPointCategory, AttackAxis), f64> = HashMap::new();
         // Aggregate severity by source/sink pair to expose dominant taint channels.
         for row in &report.taint_matrix.rows {
-            let key = (row.source_category, row.sink_axis);
+            let key = (row.source_category, row.sink_axis.clone());
             *aggregate.entry(key).or_insert(0.0) += row.severity_value;
         }
 
@@ -391,12 +552,281 @@ impl ReportFormatter {
                     if let Some(bt) = &crash.backtrace {
                         println!("         Backtrace available: {} bytes", bt.len());
                     }
+                    if let Some(frame) = backtrace::top_frame(&crash.frames) {
+                        println!("         Crashed in: {}", frame.describe());
+                    }
+                    if let Some(context) = crash.stressor_context() {
+                        println!("         Timeline: {}", context);
+                    }
+                    if let Some(trace) = &crash.rr_trace {
+                        println!("         Replay: rr replay {}", trace.display());
+                    }
+                    if let Some(schedule) = &crash.schedule {
+                        println!("         Replay: --replay-schedule {}", schedule.display());
+                    }
+                    for fault in &crash.injected_faults {
+                        println!(
+                            "         Injected fault: {} on {}: {} at {:.2}s",
+                            fault.syscall,
+                            fault.path,
+                            fault.fault,
+                            fault.at.as_secs_f64()
+                        );
+                    }
+                    if let Some(postmortem) = &crash.postmortem {
+                        let blocked = postmortem
+                            .threads
+                            .iter()
+                            .filter(|thread| thread.waiting_on.is_some())
+                            .count();
+                        println!(
+                            "         Post-mortem: {} threads, {} blocked on mutexes ({})",
+                            postmortem.threads.len(),
+                            blocked,
+                            postmortem.core_file.display()
+                        );
+                    }
                 }
             }
 
+            if let Some(hang) = &result.hang {
+                let guess = hang
+                    .guess
+                    .map(|guess| format!("{:?}", guess))
+                    .unwrap_or_else(|| "no lock or spin evidence".to_string());
+                println!(
+                    "    Hung: no output for {:.1}s at the deadline, {} threads ({})",
+                    hang.stalled_for.as_secs_f64(),
+                    hang.threads.len(),
+                    guess
+                );
+            }
+
+            if let Some(stall) = &result.stall {
+                println!(
+                    "    Stalled: no {:?} progress on '{}' for {}s (stopped at {:.1}s)",
+                    stall.check.kind,
+                    stall.check.target,
+                    stall.window.as_secs(),
+                    stall.detected_at.as_secs_f64()
+                );
+            }
+
+            if let Some(profile) = &result.stress_profile {
+                println!("    Stress: {}", stress_profile_summary(profile));
+            }
+
             if result.peak_memory > 0 {
                 println!("    Peak memory: {} MB", result.peak_memory / (1024 * 1024));
             }
+
+            if let Some(storm) = &result.signal_storm {
+                println!(
+                    "    Signals: {} delivered, {} failed @ {:.1} Hz ({})",
+                    storm.delivered,
+                    storm.failed,
+                    storm.frequency_hz,
+                    storm.signals.join(", ")
+                );
+                if let Some(note) = &storm.note {
+                    println!("    Signal note: {}", note);
+                }
+            }
+
+            if let Some(pressure) = &result.memory_pressure {
+                if pressure.mode == MemoryPressureMode::Cgroup {
+                    println!(
+                        "    cgroup memory.high: {} MB -> {} MB (reclaim events: {}, OOM kills: {})",
+                        pressure.start_bytes / (1024 * 1024),
+                        pressure.floor_bytes / (1024 * 1024),
+                        pressure.high_events,
+                        pressure.oom_kills
+                    );
+                }
+                if let Some(note) = &pressure.note {
+                    println!("    Memory note: {}", note);
+                }
+            }
+
+            if let Some(throttle) = &result.disk_throttle {
+                if !throttle.limits.is_empty() {
+                    println!(
+                        "    io.max: {} (read {} KB, wrote {} KB)",
+                        throttle.limits,
+                        throttle.read_bytes / 1024,
+                        throttle.write_bytes / 1024
+                    );
+                }
+                if let Some(note) = &throttle.note {
+                    println!("    Disk note: {}", note);
+                }
+            }
+
+            if let Some(faults) = &result.net_faults {
+                println!(
+                    "    Network faults: {} of {} socket calls failed (rate {}){}",
+                    faults.injected,
+                    faults.intercepted,
+                    faults.rate,
+                    fault_kinds(faults)
+                );
+            }
+
+            if let Some(chaos) = &result.cpu_chaos {
+                let max_delay = chaos
+                    .intervals
+                    .iter()
+                    .filter_map(|interval| interval.run_delay_us)
+                    .max()
+                    .unwrap_or(0);
+                println!(
+                    "    CPU chaos: {} migrations, {}/{} renices denied, max run delay {} us",
+                    chaos.migrations, chaos.renice_denied, chaos.renice_attempts, max_delay
+                );
+                if let Some(note) = &chaos.note {
+                    println!("    CPU note: {}", note);
+                }
+            }
+
+            if let Some(adaptive) = &result.adaptive {
+                let settled = adaptive
+                    .settled_error
+                    .map(|error| format!(", settled within {:.1}", error))
+                    .unwrap_or_default();
+                println!(
+                    "    Adaptive: {} held at {} with mean level {:.0}% over {} steps{}",
+                    adaptive.metric.label(),
+                    adaptive.setpoint,
+                    adaptive.mean_level * 100.0,
+                    adaptive.trajectory.len(),
+                    settled
+                );
+                if let Some(note) = &adaptive.note {
+                    println!("    Adaptive note: {}", note);
+                }
+            }
+
+            if let Some(telemetry) = &result.telemetry {
+                let mut parts = Vec::new();
+                if let (Some(avg), Some(peak)) =
+                    (telemetry.avg_package_watts, telemetry.peak_package_watts)
+                {
+                    parts.push(format!("{:.1} W avg, {:.1} W peak", avg, peak));
+                }
+                if let (Some(start), Some(peak)) =
+                    (telemetry.start_temperature_c, telemetry.peak_temperature_c)
+                {
+                    let unit = if self.accessible { " C" } else { "°C" };
+                    parts.push(format!("{:.1}{unit} -> {:.1}{unit}", start, peak));
+                }
+                if telemetry.throttled {
+                    parts.push(format!("throttled ({} events)", telemetry.throttle_events));
+                }
+                if !parts.is_empty() {
+                    println!("    Telemetry: {}", parts.join(", "));
+                }
+                if let Some(note) = &telemetry.note {
+                    println!("    Telemetry note: {}", note);
+                }
+            }
+
+            if let Some(gpu) = &result.gpu {
+                if let Some(device) = &gpu.device {
+                    println!(
+                        "    GPU stress: {} MiB peak of {} MiB on {} ({} passes{})",
+                        gpu.peak_vram_bytes / (1024 * 1024),
+                        gpu.requested_bytes / (1024 * 1024),
+                        device,
+                        gpu.passes,
+                        if gpu.out_of_memory {
+                            ", device OOM"
+                        } else {
+                            ""
+                        }
+                    );
+                }
+                if let Some(note) = &gpu.note {
+                    println!("    GPU stress note: {}", note);
+                }
+            }
+
+            if let Some(browser) = &result.browser {
+                let count = |kind| browser.errors.iter().filter(|e| e.kind == kind).count();
+                println!(
+                    "    Browser storm: {} actions ({} rejected), {} console errors, {} crash pages",
+                    browser.actions,
+                    browser.failed_actions,
+                    count(BrowserErrorKind::Console),
+                    count(BrowserErrorKind::CrashPage)
+                );
+                if let Some(note) = &browser.note {
+                    println!("    Browser note: {}", note);
+                }
+            }
+
+            if let Some(database) = &result.database {
+                let mut parts = vec![format!(
+                    "{} connections held, {} killed, {} slow queries",
+                    database.peak_held_connections,
+                    database.killed_connections,
+                    database.slow_queries
+                )];
+                if database.lock_holds > 0 {
+                    parts.push(format!("{} lock holds", database.lock_holds));
+                }
+                if let (Some(peak), Some(max)) =
+                    (database.peak_server_connections, database.max_connections)
+                {
+                    parts.push(format!("server at {}/{} connections", peak, max));
+                }
+                println!("    Database chaos: {}", parts.join(", "));
+                for symptom in &database.symptoms {
+                    println!(
+                        "      {:>6.1}s {:?}: {}",
+                        symptom.at.as_secs_f64(),
+                        symptom.kind,
+                        symptom.message
+                    );
+                }
+                if let Some(correlation) = &database.correlation {
+                    println!("    Database correlation: {}", correlation);
+                }
+                if let Some(note) = &database.note {
+                    println!("    Database note: {}", note);
+                }
+            }
+
+            if let Some(flood) = &result.flood {
+                println!(
+                    "    Message flood: {:?} topic {} on {}",
+                    flood.protocol, flood.topic, flood.broker
+                );
+                for phase in &flood.phases {
+                    println!(
+                        "      phase {} @ {:.0}/s (achieved {:.1}/s): {} sent, {} delivered, {} rejected, {} failed; {} malformed, {} oversized, {} duplicate, {} out of order",
+                        phase.index + 1,
+                        phase.target_rate_hz,
+                        phase.achieved_rate_hz,
+                        phase.sent,
+                        phase.delivered,
+                        phase.rejected,
+                        phase.failed,
+                        phase.malformed,
+                        phase.oversized,
+                        phase.duplicated,
+                        phase.out_of_order
+                    );
+                    for error in &phase.errors {
+                        println!("        {}", error);
+                    }
+                }
+                if let Some(correlation) = &flood.correlation {
+                    println!("    Flood correlation: {}", correlation);
+                }
+                if let Some(note) = &flood.note {
+                    println!("    Flood note: {}", note);
+                }
+            }
         }
     }
 
@@ -412,10 +842,19 @@ impl ReportFormatter {
                 if !result.signatures_detected.is_empty() {
                     println!("  During {:?} attack:", result.axis);
                     for sig in &result.signatures_detected {
-                        println!(
-                            "    - {:?} (confidence: {:.2})",
-                            sig.signature_type, sig.confidence
-                        );
+                        match &sig.rule {
+                            Some(rule) => println!(
+                                "    - {:?} [{}] (confidence: {:.2})",
+                                sig.signature_type, rule, sig.confidence
+                            ),
+                            None => println!(
+                                "    - {:?} (confidence: {:.2})",
+                                sig.signature_type, sig.confidence
+                            ),
+                        }
+                        if let Some(calibration) = &sig.calibration {
+                            println!("      {}", calibration.describe().dimmed());
+                        }
                         for evidence in &sig.evidence {
                             println!("      Evidence: {}", evidence.dimmed());
                         }
@@ -442,11 +881,17 @@ impl ReportFormatter {
             "red"
         };
 
+        let rating = if self.accessible {
+            format!(" ({})", score_rating(assessment.robustness_score))
+        } else {
+            String::new()
+        };
         println!(
-            "  Robustness Score: {}/100",
+            "  Robustness Score: {}/100{}",
             format!("{:.1}", assessment.robustness_score)
                 .color(score_color)
-                .bold()
+                .bold(),
+            rating
         );
         println!();
 
@@ -469,7 +914,29 @@ impl ReportFormatter {
     fn collect_weak_point_summary(&self, scan: &AssailReport) -> Vec<String> {
         scan.weak_points
             .iter()
-            .map(|wp| format!("[{:?}] {}", wp.category, wp.description))
+            .map(|wp| {
+                let mut line = if self.accessible {
+                    format!("{} severity: ", wp.severity)
+                } else {
+                    String::new()
+                };
+                line.push_str(&match &wp.calibrated {
+                    Some(marker) => format!(
+                        "[{:?}] {} (calibrated {} -> {} after {} clean runs)",
+                        wp.category,
+                        wp.description,
+                        marker.original_severity,
+                        wp.severity,
+                        marker.clean_runs
+                    ),
+                    None => format!("[{:?}] {}", wp.category, wp.description),
+                });
+                if let Some(owner) = &wp.owner {
+                    let dash = if self.accessible { "-" } else { "—" };
+                    line.push_str(&format!(" {} {}", dash, owner));
+                }
+                line
+            })
             .collect()
     }
 
@@ -489,6 +956,76 @@ impl ReportFormatter {
     }
 }
 
+/// Rating spoken alongside the robustness score, with the thresholds of its color.
+pub(crate) fn score_rating(score: f64) -> &'static str {
+    if score >= 80.0 {
+        "good"
+    } else if score >= 50.0 {
+        "fair"
+    } else {
+        "poor"
+    }
+}
+
+/// One-line view of the resolved stressor parameters that matter for the axis.
+fn stress_profile_summary(profile: &StressProfile) -> String {
+    let mut parts = vec![format!(
+        "{:?} for {}s",
+        profile.intensity,
+        profile.duration.as_secs()
+    )];
+    if let Some(workers) = profile.workers {
+        parts.push(format!("{} workers", workers));
+    }
+    if let Some(bytes) = profile.memory_target_bytes {
+        parts.push(format!("{} MB allocation target", bytes / (1024 * 1024)));
+    }
+    if let Some(files) = profile.disk_files_per_cycle {
+        parts.push(format!("{} files/cycle", files));
+    }
+    if let Some(clients) = profile.network_clients {
+        parts.push(format!("{} clients", clients));
+    }
+    if let Some(hz) = profile.signal_hz {
+        parts.push(format!("{:.1} Hz signals", hz));
+    }
+    if let Some(faults) = &profile.malloc_faults {
+        parts.push(format!(
+            "{} of {} allocations failed (rate {})",
+            faults.injected, faults.calls, faults.rate
+        ));
+    }
+    if let Some(faults) = &profile.io_faults {
+        parts.push(format!(
+            "{} of {} file calls failed (rate {}){}",
+            faults.injected,
+            faults.intercepted,
+            faults.rate,
+            fault_kinds(faults)
+        ));
+    }
+    if let Some(timeout) = profile.timeout {
+        parts.push(format!("{}s limit", timeout.as_secs()));
+    }
+    if !profile.target_args.is_empty() {
+        parts.push(format!("args: {}", profile.target_args.join(" ")));
+    }
+    parts.join(", ")
+}
+
+/// Injected faults by kind, as ": ECONNRESET 2, stall 1"; empty when none were injected.
+pub(crate) fn fault_kinds(report: &IoFaultReport) -> String {
+    if report.faults.is_empty() {
+        return String::new();
+    }
+    let kinds: Vec<String> = report
+        .faults
+        .iter()
+        .map(|(kind, count)| format!("{} {}", kind, count))
+        .collect();
+    format!(": {}", kinds.join(", "))
+}
+
 pub(crate) fn nickel_escape_string(value: &str) -> String {
     serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
 }
@@ -498,3 +1035,40 @@ struct AccordionSection {
     summary: String,
     details: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_accessible_weak_points_spell_out_severity_in_ascii() {
+        let scan = AssailReport {
+            program_path: PathBuf::from("app"),
+            language: Language::Rust,
+            weak_points: vec![WeakPoint {
+                category: WeakPointCategory::UnsafeCode,
+                location: Some("src/ffi.rs:42".to_string()),
+                severity: Severity::Critical,
+                description: "unsafe block".to_string(),
+                recommended_attack: Vec::new(),
+                provenance: None,
+                calibrated: None,
+                owner: Some("@core".to_string()),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            ReportFormatter::new().collect_weak_point_summary(&scan),
+            vec!["[UnsafeCode] unsafe block — @core"]
+        );
+        assert_eq!(
+            ReportFormatter::new()
+                .with_accessible(true)
+                .collect_weak_point_summary(&scan),
+            vec!["CRITICAL severity: [UnsafeCode] unsafe block - @core"]
+        );
+        assert_eq!(score_rating(80.0), "good");
+        assert_eq!(score_rating(49.9), "poor");
+    }
+}
@@ -2,14 +2,41 @@
 
 //! Report generation logic
 
+use crate::attack::budget;
+use crate::calibration::CalibrationStore;
+use crate::i18n::{Lang, TemplateSet};
 use crate::types::*;
 use anyhow::Result;
 
-pub struct ReportGenerator;
+/// Builds assault reports; critical issues and recommendations are rendered from the
+/// `assess.*` i18n templates in `lang`, with `templates` overriding the built-in wording.
+pub struct ReportGenerator {
+    lang: Lang,
+    templates: TemplateSet,
+}
 
 impl ReportGenerator {
     pub fn new() -> Self {
-        Self
+        Self {
+            lang: Lang::default(),
+            templates: TemplateSet::default(),
+        }
+    }
+
+    /// Language of the generated critical issues and recommendations.
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// Organization templates overriding the catalog (see [`TemplateSet`]).
+    pub fn with_templates(mut self, templates: TemplateSet) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    fn text(&self, key: &str, args: &[(&str, String)]) -> Option<String> {
+        self.templates.text(self.lang, key, args)
     }
 
     pub fn generate(
@@ -26,6 +53,7 @@ impl ReportGenerator {
             .sum();
 
         let overall_assessment = self.assess_results(&assail_report, &attack_results);
+        let notes = budget::notes(&attack_results);
 
         Ok(AssaultReport {
             assail_report,
@@ -33,10 +61,23 @@ impl ReportGenerator {
             total_crashes,
             total_signatures,
             overall_assessment,
-            timeline: None,
+            notes,
+            ..Default::default()
         })
     }
 
+    /// Generate the report and record this run's outcomes into the calibration store,
+    /// so weak points exercised without crashing are downgraded in later runs.
+    pub fn generate_calibrated(
+        &self,
+        assail_report: AssailReport,
+        attack_results: Vec<AttackResult>,
+        calibration: &mut CalibrationStore,
+    ) -> Result<AssaultReport> {
+        calibration.record(&assail_report, &attack_results);
+        self.generate(assail_report, attack_results)
+    }
+
     fn assess_results(&self, scan: &AssailReport, results: &[AttackResult]) -> OverallAssessment {
         let mut critical_issues = Vec::new();
         let mut recommendations = Vec::new();
@@ -66,55 +107,81 @@ impl ReportGenerator {
         // Identify critical issues
         for result in results {
             if !result.crashes.is_empty() {
-                critical_issues.push(format!(
-                    "Program crashed under {:?} attack ({} crashes)",
-                    result.axis,
-                    result.crashes.len()
+                critical_issues.extend(self.text(
+                    "assess.crashed",
+                    &[
+                        ("axis", format!("{:?}", result.axis)),
+                        ("count", result.crashes.len().to_string()),
+                    ],
                 ));
             }
 
             for sig in &result.signatures_detected {
                 if sig.confidence > 0.8 {
-                    critical_issues.push(format!(
-                        "High-confidence {:?} detected (confidence: {:.2})",
-                        sig.signature_type, sig.confidence
+                    critical_issues.extend(self.text(
+                        "assess.high_confidence",
+                        &[
+                            ("signature", format!("{:?}", sig.signature_type)),
+                            ("confidence", format!("{:.2}", sig.confidence)),
+                        ],
                     ));
                 }
             }
         }
 
+        let detected = |matches: fn(SignatureType) -> bool| {
+            results
+                .iter()
+                .flat_map(|r| &r.signatures_detected)
+                .any(|s| matches(s.signature_type))
+        };
+
         // Recommendations are additive heuristics keyed to observed risk traits.
+        let mut recommend = |key: &str, args: &[(&str, String)]| {
+            recommendations.extend(self.text(key, args));
+        };
         if crash_count > 0.0 {
-            recommendations.push("Add comprehensive error handling for edge cases".to_string());
+            recommend("assess.rec.error_handling", &[]);
         }
-
         if scan.statistics.unwrap_calls > 10 {
-            recommendations.push("Replace unwrap() calls with proper error handling".to_string());
+            recommend(
+                "assess.rec.unwrap",
+                &[("count", scan.statistics.unwrap_calls.to_string())],
+            );
         }
-
         if scan.statistics.unsafe_blocks > 0 {
-            recommendations.push("Audit unsafe blocks for memory safety violations".to_string());
+            recommend(
+                "assess.rec.unsafe",
+                &[("count", scan.statistics.unsafe_blocks.to_string())],
+            );
         }
-
-        if results.iter().any(|r| {
-            r.signatures_detected
-                .iter()
-                .any(|s| matches!(s.signature_type, SignatureType::DataRace))
-        }) {
-            recommendations
-                .push("Add synchronization primitives to prevent data races".to_string());
+        if detected(|kind| kind == SignatureType::DataRace) {
+            recommend("assess.rec.data_race", &[]);
         }
-
-        if results.iter().any(|r| {
-            r.signatures_detected
-                .iter()
-                .any(|s| matches!(s.signature_type, SignatureType::Deadlock))
+        if detected(|kind| kind == SignatureType::Deadlock) {
+            recommend("assess.rec.deadlock", &[]);
+        }
+        if detected(|kind| kind == SignatureType::Livelock) {
+            recommend("assess.rec.livelock", &[]);
+        }
+        if detected(|kind| {
+            matches!(
+                kind,
+                SignatureType::MemoryGrowthSuspected
+                    | SignatureType::FdLeakSuspected
+                    | SignatureType::ResourceLeakSuspected
+            )
         }) {
-            recommendations.push("Review lock ordering to prevent deadlocks".to_string());
+            recommend("assess.rec.leak", &[]);
+        }
+        if detected(|kind| kind == SignatureType::InfiniteLoopSuspected) {
+            recommend("assess.rec.infinite_loop", &[]);
+        }
+        if results.iter().any(|r| r.stall.is_some()) {
+            recommend("assess.rec.stall", &[]);
         }
-
         if score < 50.0 {
-            recommendations.push("Consider comprehensive refactoring for robustness".to_string());
+            recommend("assess.rec.refactor", &[("score", format!("{:.0}", score))]);
         }
 
         OverallAssessment {
@@ -2,10 +2,14 @@
 
 //! Minimal GUI for reviewing assault reports.
 
+use crate::report::artifacts::{self, ArtifactStore, CrashOutput, PAGE_LINES};
+use crate::report::chart::{Anchor, Chart, ChartFormat, ChartKind, Shape};
 use crate::report::formatter::ReportFormatter;
+use crate::triage::{self, TriageStore};
 use crate::types::{AssaultReport, FileStatistics};
 use anyhow::{anyhow, Result};
 use eframe::{egui, App, Frame, NativeOptions};
+use std::path::PathBuf;
 
 pub struct ReportGui {
     report: AssaultReport,
@@ -13,6 +17,17 @@ pub struct ReportGui {
     file_filter: String,
     weak_filter: String,
     attack_filter: String,
+    store: Option<ArtifactStore>,
+    outputs: Vec<CrashOutput>,
+    selected_output: usize,
+    output_page: usize,
+    /// Full text of the selected output, loaded when it is first shown.
+    loaded_output: Option<(usize, String)>,
+    triage: TriageStore,
+    chart_kind: ChartKind,
+    /// Export target of the Charts tab; its extension picks SVG or PNG.
+    chart_path: String,
+    chart_status: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,18 +36,36 @@ enum ReportTab {
     Assail,
     Matrix,
     Attacks,
+    Output,
+    Triage,
     Assessment,
+    Charts,
 }
 
 impl ReportGui {
-    pub fn run(report: AssaultReport) -> Result<()> {
+    /// `store` resolves crash output kept outside the report; `None` shows previews only.
+    /// `triage` supplies the annotations shown in the Triage tab.
+    pub fn run(
+        report: AssaultReport,
+        store: Option<ArtifactStore>,
+        triage: TriageStore,
+    ) -> Result<()> {
         let options = NativeOptions::default();
         let app = Self {
+            outputs: artifacts::crash_outputs(&report),
             report,
             tab: ReportTab::Summary,
             file_filter: String::new(),
             weak_filter: String::new(),
             attack_filter: String::new(),
+            store,
+            selected_output: 0,
+            output_page: 0,
+            loaded_output: None,
+            triage,
+            chart_kind: ChartKind::Dashboard,
+            chart_path: "panic-attack-chart.svg".to_string(),
+            chart_status: None,
         };
         eframe::run_native(
             "panic-attack report",
@@ -55,7 +88,10 @@ impl App for ReportGui {
             ui.selectable_value(&mut self.tab, ReportTab::Assail, "Assail");
             ui.selectable_value(&mut self.tab, ReportTab::Matrix, "Matrix");
             ui.selectable_value(&mut self.tab, ReportTab::Attacks, "Attacks");
+            ui.selectable_value(&mut self.tab, ReportTab::Output, "Crash output");
+            ui.selectable_value(&mut self.tab, ReportTab::Triage, "Triage");
             ui.selectable_value(&mut self.tab, ReportTab::Assessment, "Assessment");
+            ui.selectable_value(&mut self.tab, ReportTab::Charts, "Charts");
         });
 
         egui::CentralPanel::default().show(ctx, |ui| match self.tab {
@@ -63,7 +99,10 @@ impl App for ReportGui {
             ReportTab::Assail => self.render_assail(ui),
             ReportTab::Matrix => self.render_matrix(ui),
             ReportTab::Attacks => self.render_attacks(ui),
+            ReportTab::Output => self.render_output(ui),
+            ReportTab::Triage => self.render_triage(ui),
             ReportTab::Assessment => self.render_assessment(ui),
+            ReportTab::Charts => self.render_charts(ui),
         });
     }
 }
@@ -149,9 +188,19 @@ impl ReportGui {
                         .contains(&self.weak_filter.to_lowercase())
                     || format!("{:?}", wp.category)
                         .to_lowercase()
-                        .contains(&self.weak_filter.to_lowercase());
+                        .contains(&self.weak_filter.to_lowercase())
+                    || wp.owner.as_deref().is_some_and(|owner| {
+                        owner
+                            .to_lowercase()
+                            .contains(&self.weak_filter.to_lowercase())
+                    });
                 if match_filter {
-                    ui.label(format!("[{:?}] {}", wp.category, desc));
+                    let owner = wp
+                        .owner
+                        .as_deref()
+                        .map(|owner| format!(" — {}", owner))
+                        .unwrap_or_default();
+                    ui.label(format!("[{:?}] {}{}", wp.category, desc, owner));
                 }
             }
         });
@@ -233,6 +282,72 @@ impl ReportGui {
         });
     }
 
+    fn render_output(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Crash output");
+        if self.outputs.is_empty() {
+            ui.label("No crash output captured");
+            return;
+        }
+        ui.horizontal_wrapped(|ui| {
+            for (index, output) in self.outputs.iter().enumerate() {
+                let label = match &output.artifact {
+                    Some(artifact) => format!("{} ({} bytes)", output.label, artifact.bytes),
+                    None => output.label.clone(),
+                };
+                if ui
+                    .selectable_label(self.selected_output == index, label)
+                    .clicked()
+                {
+                    self.selected_output = index;
+                    self.output_page = 0;
+                }
+            }
+        });
+        ui.separator();
+
+        let index = self.selected_output;
+        if self.loaded_output.as_ref().map(|(loaded, _)| *loaded) != Some(index) {
+            let text = self.outputs[index].load(self.store.as_ref());
+            self.loaded_output = Some((index, text));
+        }
+        let Some((_, text)) = &self.loaded_output else {
+            return;
+        };
+        let (lines, pages) = artifacts::page_of(text, self.output_page, PAGE_LINES);
+        self.output_page = self.output_page.min(pages - 1);
+        ui.horizontal(|ui| {
+            if ui.button("Previous").clicked() {
+                self.output_page = self.output_page.saturating_sub(1);
+            }
+            ui.label(format!("Page {}/{}", self.output_page + 1, pages));
+            if ui.button("Next").clicked() && self.output_page + 1 < pages {
+                self.output_page += 1;
+            }
+        });
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.monospace(lines.join("\n"));
+        });
+    }
+
+    fn render_triage(&self, ui: &mut egui::Ui) {
+        ui.heading("Triage");
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (id, description) in triage::finding_ids(&self.report) {
+                match self.triage.findings.get(&id) {
+                    Some(history) => {
+                        ui.label(format!("{} ({})", id, description));
+                        for annotation in history {
+                            ui.label(format!("  - {}", annotation.describe()));
+                        }
+                    }
+                    None => {
+                        ui.label(format!("{} ({}): untriaged", id, description));
+                    }
+                }
+            }
+        });
+    }
+
     fn render_assessment(&self, ui: &mut egui::Ui) {
         let assessment = &self.report.overall_assessment;
         ui.heading("Overall assessment");
@@ -255,6 +370,127 @@ impl ReportGui {
             }
         }
     }
+
+    fn render_charts(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Charts");
+        ui.horizontal(|ui| {
+            for (kind, label) in [
+                (ChartKind::Dashboard, "Dashboard"),
+                (ChartKind::Outcomes, "Outcomes"),
+                (ChartKind::Timeline, "Crash timeline"),
+                (ChartKind::Robustness, "Robustness"),
+            ] {
+                ui.selectable_value(&mut self.chart_kind, kind, label);
+            }
+        });
+        let chart = Chart::build(&self.report, self.chart_kind);
+        ui.horizontal(|ui| {
+            ui.label("Export to:");
+            ui.text_edit_singleline(&mut self.chart_path);
+            for (format, extension) in [(ChartFormat::Svg, "svg"), (ChartFormat::Png, "png")] {
+                if ui
+                    .button(format!("Export {}", extension.to_uppercase()))
+                    .clicked()
+                {
+                    let path = PathBuf::from(&self.chart_path).with_extension(extension);
+                    self.chart_status = Some(match chart.write(&path, format) {
+                        Ok(()) => format!("Wrote {}", path.display()),
+                        Err(err) => format!("Export failed: {err:#}"),
+                    });
+                    self.chart_path = path.display().to_string();
+                }
+            }
+        });
+        if let Some(status) = &self.chart_status {
+            ui.label(status);
+        }
+        ui.separator();
+        egui::ScrollArea::both().show(ui, |ui| paint_chart(ui, &chart));
+    }
+}
+
+/// Paint `chart` at its own size, scaled down to the available width if needed.
+fn paint_chart(ui: &mut egui::Ui, chart: &Chart) {
+    let scale = (ui.available_width() / chart.width).min(1.0);
+    let size = egui::vec2(chart.width * scale, chart.height * scale);
+    let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+    let origin = response.rect.min;
+    let at = |(x, y): (f32, f32)| origin + egui::vec2(x * scale, y * scale);
+    let color = |c: crate::report::chart::Color| egui::Color32::from_rgb(c.0, c.1, c.2);
+    painter.rect_filled(response.rect, 0.0, egui::Color32::WHITE);
+    for shape in &chart.shapes {
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+            } => {
+                let rect = egui::Rect::from_min_max(at((*x, *y)), at((x + width, y + height)));
+                painter.rect_filled(rect, 0.0, color(*fill));
+            }
+            Shape::Line {
+                from,
+                to,
+                width,
+                color: stroke,
+            } => {
+                painter.line_segment([at(*from), at(*to)], (width * scale, color(*stroke)));
+            }
+            Shape::Polygon { points, fill } => {
+                painter.add(egui::Shape::convex_polygon(
+                    points.iter().copied().map(at).collect(),
+                    color(*fill),
+                    egui::Stroke::NONE,
+                ));
+            }
+            Shape::Arc {
+                center,
+                radius,
+                from,
+                to,
+                width,
+                color: stroke,
+            } => {
+                let steps = 48;
+                let points = (0..=steps)
+                    .map(|i| {
+                        let angle = from + (to - from) * i as f32 / steps as f32;
+                        at((
+                            center.0 + radius * angle.cos(),
+                            center.1 - radius * angle.sin(),
+                        ))
+                    })
+                    .collect();
+                painter.add(egui::Shape::line(
+                    points,
+                    egui::Stroke::new(width * scale, color(*stroke)),
+                ));
+            }
+            Shape::Text {
+                x,
+                y,
+                size,
+                anchor,
+                color: fill,
+                text,
+            } => {
+                let align = match anchor {
+                    Anchor::Start => egui::Align2::LEFT_BOTTOM,
+                    Anchor::Middle => egui::Align2::CENTER_BOTTOM,
+                    Anchor::End => egui::Align2::RIGHT_BOTTOM,
+                };
+                painter.text(
+                    at((*x, *y)),
+                    align,
+                    text,
+                    egui::FontId::proportional(size * scale),
+                    color(*fill),
+                );
+            }
+        }
+    }
 }
 
 fn file_risk(fs: &FileStatistics) -> usize {
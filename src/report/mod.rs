@@ -2,25 +2,35 @@
 
 //! Report generation module
 
+pub mod artifacts;
+pub mod assail_diff;
+pub mod chart;
 pub mod diff;
+pub mod filter;
 pub mod formatter;
 pub mod generator;
 pub mod gui;
 pub mod migration;
 pub mod output;
+pub mod pivot;
+pub mod repro;
 pub mod sarif;
+pub mod sbom;
 pub mod tui;
 
+use crate::storage::write_atomic;
 use crate::types::*;
 use anyhow::Result;
-use std::fs;
 use std::path::Path;
 
-pub use diff::{format_diff, load_report};
+pub use artifacts::ArtifactStore;
+pub use diff::{format_diff, load_report, load_report_strict, DiffOptions, DiffReport};
+pub use filter::FindingFilter;
 pub use formatter::{ReportFormatter, ReportView};
 pub use generator::ReportGenerator;
 pub use gui::ReportGui;
 pub use output::ReportOutputFormat;
+pub use repro::ReproOptions;
 pub use tui::ReportTui;
 
 /// Generate a comprehensive assault report
@@ -34,25 +44,56 @@ pub fn generate_assault_report(
 }
 
 /// Save report to file with the requested format
+#[allow(dead_code)]
 pub fn save_report<P: AsRef<Path>>(
     report: &AssaultReport,
     path: P,
     format: ReportOutputFormat,
 ) -> Result<()> {
+    save_report_with_repro(report, path, format, ReproOptions::default())
+}
+
+/// Save report, writing crash reproduction files beside it as `options` asks.
+pub fn save_report_with_repro<P: AsRef<Path>>(
+    report: &AssaultReport,
+    path: P,
+    format: ReportOutputFormat,
+    options: ReproOptions,
+) -> Result<()> {
+    // Large crash output goes to the artifact store beside the report, not into it.
+    let path = path.as_ref();
+    let dir = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let mut report = artifacts::externalize_assault_report(report, &ArtifactStore::new(dir))?;
+    let name = path.file_stem().map_or_else(
+        || "report".to_string(),
+        |stem| stem.to_string_lossy().to_string(),
+    );
+    repro::write_repro_scripts(&mut report, dir, &name, options)?;
     // Output format selection is delegated to the formatter enum for consistency.
-    let serialized = format.serialize(report)?;
-    fs::write(path, serialized)?;
+    let serialized = format.serialize(&report)?;
+    write_atomic(path, serialized)?;
     Ok(())
 }
 
-/// Print report to console with view/depth controls
+/// Print report to console with view/depth controls, narrowed by `filter`
 pub fn print_report(
     report: &AssaultReport,
     view: ReportView,
     expand_details: bool,
     show_matrix: bool,
+    accessible: bool,
+    filter: &FindingFilter,
 ) {
     // Console rendering always flows through ReportFormatter view contracts.
-    let formatter = ReportFormatter::new();
-    formatter.print_with_view(report, view, expand_details, show_matrix);
+    let formatter = ReportFormatter::new().with_accessible(accessible);
+    if filter.is_empty() {
+        formatter.print_with_view(report, view, expand_details, show_matrix);
+    } else {
+        let mut narrowed = report.clone();
+        filter.apply(&mut narrowed);
+        formatter.print_with_view(&narrowed, view, expand_details, show_matrix);
+    }
 }
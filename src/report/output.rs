@@ -43,11 +43,9 @@ impl ReportOutputFormat {
             ReportOutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
             // Nickel output is a compact projection for config-centric consumers.
             ReportOutputFormat::Nickel => Ok(format_report_as_nickel(report)),
-            // SARIF output targets GitHub Security tab and other SARIF consumers.
-            // Uses the assail_report (static findings) since SARIF is for static analysis results.
-            ReportOutputFormat::Sarif => {
-                crate::report::sarif::to_sarif_json(&report.assail_report)
-            }
+            // SARIF output targets GitHub Security tab and other SARIF consumers: the
+            // assail_report's static findings plus the crashes, located by their backtraces.
+            ReportOutputFormat::Sarif => crate::report::sarif::to_sarif_assault_json(report),
         }
     }
 }
@@ -69,6 +67,9 @@ fn format_report_as_nickel(report: &AssaultReport) -> String {
     lines.push(format!("  weak_points = {};", assail.weak_points.len()));
     lines.push(format!("  total_crashes = {};", report.total_crashes));
     lines.push(format!("  total_signatures = {};", report.total_signatures));
+    if let Some(seed) = report.seed {
+        lines.push(format!("  seed = {};", seed));
+    }
     let axes: Vec<String> = report
         .attack_results
         .iter()
@@ -142,6 +143,32 @@ fn format_report_as_nickel(report: &AssaultReport) -> String {
         }
     }
 
+    let reproduction: Vec<String> = report
+        .reproduction
+        .iter()
+        .map(|threshold| {
+            format!(
+                "{{ bucket = {}, reproducible = {}, min_intensity = {}, min_duration = {} }}",
+                nickel_escape_string(&threshold.bucket),
+                threshold.reproducible,
+                threshold
+                    .min_intensity
+                    .map(|level| nickel_escape_string(&format!("{:?}", level)))
+                    .unwrap_or_else(|| "null".to_string()),
+                threshold
+                    .min_duration
+                    .map(|duration| duration.as_secs_f64().to_string())
+                    .unwrap_or_else(|| "null".to_string())
+            )
+        })
+        .collect();
+    if !reproduction.is_empty() {
+        lines.push(format!("  reproduction = [{}];", reproduction.join(", ")));
+    }
+    if let Some(applied) = report.redactions_applied {
+        lines.push(format!("  redactions_applied = {};", applied));
+    }
+
     lines.push(format!(
         "  robustness_score = {:.1};",
         report.overall_assessment.robustness_score
@@ -0,0 +1,384 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Pivot tables over a report's findings, for the TUI's pivot explorer.
+//!
+//! Findings are the assail weak points and the bug signatures the attacks detected. A weak
+//! point counts under every axis it recommends; a signature counts under the axis of the
+//! attack that found it. Attack runs that found nothing still carry their duration, so
+//! mean duration covers every run in a cell, each run once.
+
+use crate::signatures::backtrace::split_location;
+use crate::types::{AssaultReport, Severity};
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+/// Value shown for findings that have nothing in a dimension (a weak point's signature).
+pub const NONE: &str = "-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Axis,
+    File,
+    Severity,
+    Signature,
+}
+
+impl Dimension {
+    pub const ALL: [Dimension; 4] = [
+        Dimension::Axis,
+        Dimension::File,
+        Dimension::Severity,
+        Dimension::Signature,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Dimension::Axis => "axis",
+            Dimension::File => "file",
+            Dimension::Severity => "severity",
+            Dimension::Signature => "signature type",
+        }
+    }
+
+    /// The next dimension after `self` that is not `skip`.
+    pub fn next(self, skip: Dimension) -> Self {
+        let at = Self::ALL.iter().position(|d| *d == self).unwrap_or(0);
+        (1..=Self::ALL.len())
+            .map(|step| Self::ALL[(at + step) % Self::ALL.len()])
+            .find(|d| *d != skip)
+            .unwrap_or(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    Count,
+    MaxSeverity,
+    MeanDuration,
+}
+
+impl Measure {
+    pub fn label(self) -> &'static str {
+        match self {
+            Measure::Count => "count",
+            Measure::MaxSeverity => "max severity",
+            Measure::MeanDuration => "mean duration",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Measure::Count => Measure::MaxSeverity,
+            Measure::MaxSeverity => Measure::MeanDuration,
+            Measure::MeanDuration => Measure::Count,
+        }
+    }
+}
+
+/// One finding, or one attack run that found nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fact {
+    pub axis: String,
+    pub file: String,
+    pub severity: Option<Severity>,
+    pub signature: String,
+    /// Counted by `Measure::Count`; false for a clean attack run.
+    pub finding: bool,
+    /// Attack run behind the fact, as an index into `attack_results`.
+    pub run: Option<usize>,
+}
+
+impl Fact {
+    fn value(&self, dimension: Dimension) -> String {
+        match dimension {
+            Dimension::Axis => self.axis.clone(),
+            Dimension::File => self.file.clone(),
+            Dimension::Severity => self
+                .severity
+                .map_or_else(|| NONE.to_string(), |s| s.to_string()),
+            Dimension::Signature => self.signature.clone(),
+        }
+    }
+}
+
+/// Facts of `report`: weak points, detected signatures and clean attack runs.
+pub fn facts(report: &AssaultReport) -> Vec<Fact> {
+    let mut facts = Vec::new();
+    for wp in &report.assail_report.weak_points {
+        let file = wp
+            .location
+            .as_deref()
+            .map_or_else(|| NONE.to_string(), file_of);
+        let axes: Vec<String> = if wp.recommended_attack.is_empty() {
+            vec![NONE.to_string()]
+        } else {
+            wp.recommended_attack
+                .iter()
+                .map(|axis| axis.name().to_string())
+                .collect()
+        };
+        for axis in axes {
+            facts.push(Fact {
+                axis,
+                file: file.clone(),
+                severity: Some(wp.severity),
+                signature: NONE.to_string(),
+                finding: true,
+                run: None,
+            });
+        }
+    }
+    for (run, result) in report.attack_results.iter().enumerate() {
+        let axis = result.axis.name().to_string();
+        if result.signatures_detected.is_empty() {
+            facts.push(Fact {
+                axis: axis.clone(),
+                file: NONE.to_string(),
+                severity: None,
+                signature: NONE.to_string(),
+                finding: false,
+                run: Some(run),
+            });
+        }
+        for signature in &result.signatures_detected {
+            facts.push(Fact {
+                axis: axis.clone(),
+                file: signature
+                    .location
+                    .as_deref()
+                    .map_or_else(|| NONE.to_string(), file_of),
+                severity: None,
+                signature: format!("{:?}", signature.signature_type),
+                finding: true,
+                run: Some(run),
+            });
+        }
+    }
+    facts
+}
+
+/// `src/lib.rs:12:5` -> `src/lib.rs`.
+fn file_of(location: &str) -> String {
+    split_location(location)
+        .map_or(location, |(file, _)| file)
+        .to_string()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotTable {
+    pub rows: Vec<String>,
+    pub columns: Vec<String>,
+    /// `cells[row][column]`, already formatted; empty cells are `NONE`.
+    pub cells: Vec<Vec<String>>,
+}
+
+#[derive(Default)]
+struct Cell {
+    count: usize,
+    max_severity: Option<Severity>,
+    runs: BTreeSet<usize>,
+}
+
+/// Aggregate `facts` into `row` x `column` cells of `measure`. Rows and columns are ordered
+/// by their number of findings, most first (severities from critical down).
+pub fn pivot(
+    facts: &[Fact],
+    row: Dimension,
+    column: Dimension,
+    measure: Measure,
+    durations: &[Duration],
+) -> PivotTable {
+    let mut cells: HashMap<(String, String), Cell> = HashMap::new();
+    let mut row_totals: HashMap<String, usize> = HashMap::new();
+    let mut column_totals: HashMap<String, usize> = HashMap::new();
+    for fact in facts {
+        let (r, c) = (fact.value(row), fact.value(column));
+        let cell = cells.entry((r.clone(), c.clone())).or_default();
+        let weight = usize::from(fact.finding);
+        cell.count += weight;
+        cell.max_severity = cell.max_severity.max(fact.severity);
+        cell.runs.extend(fact.run);
+        *row_totals.entry(r).or_default() += weight;
+        *column_totals.entry(c).or_default() += weight;
+    }
+    let rows = ordered(row, row_totals);
+    let columns = ordered(column, column_totals);
+    let cells = rows
+        .iter()
+        .map(|r| {
+            columns
+                .iter()
+                .map(|c| match cells.get(&(r.clone(), c.clone())) {
+                    Some(cell) => format_cell(cell, measure, durations),
+                    None => NONE.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    PivotTable {
+        rows,
+        columns,
+        cells,
+    }
+}
+
+fn ordered(dimension: Dimension, totals: HashMap<String, usize>) -> Vec<String> {
+    let mut values: Vec<(String, usize)> = totals.into_iter().collect();
+    if dimension == Dimension::Severity {
+        let rank = |value: &str| {
+            ["CRITICAL", "HIGH", "MEDIUM", "LOW"]
+                .iter()
+                .position(|s| *s == value)
+                .unwrap_or(usize::MAX)
+        };
+        values.sort_by_key(|(value, _)| rank(value));
+    } else {
+        values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    }
+    values.into_iter().map(|(value, _)| value).collect()
+}
+
+fn format_cell(cell: &Cell, measure: Measure, durations: &[Duration]) -> String {
+    match measure {
+        Measure::Count if cell.count > 0 => cell.count.to_string(),
+        Measure::MaxSeverity => cell
+            .max_severity
+            .map_or_else(|| NONE.to_string(), |s| s.to_string()),
+        Measure::MeanDuration if !cell.runs.is_empty() => {
+            let total: Duration = cell.runs.iter().filter_map(|run| durations.get(*run)).sum();
+            let mean = total.as_secs_f64() / cell.runs.len() as f64;
+            if mean < 1.0 {
+                format!("{:.0}ms", mean * 1000.0)
+            } else {
+                format!("{:.1}s", mean)
+            }
+        }
+        _ => NONE.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use std::path::PathBuf;
+
+    fn weak_point(severity: Severity, location: &str, axes: Vec<AttackAxis>) -> WeakPoint {
+        WeakPoint {
+            category: WeakPointCategory::PanicPath,
+            location: Some(location.to_string()),
+            severity,
+            description: String::new(),
+            recommended_attack: axes,
+            provenance: None,
+            calibrated: None,
+            owner: None,
+        }
+    }
+
+    fn report() -> AssaultReport {
+        let attack = |signatures: usize, millis: u64| {
+            let signature = serde_json::json!({
+                "signature_type": "DoubleFree",
+                "confidence": 0.9,
+                "evidence": [],
+                "location": "src/b.rs:5"
+            });
+            serde_json::from_value(serde_json::json!({
+                "program": "app",
+                "axis": "memory",
+                "success": false,
+                "exit_code": null,
+                "duration": { "secs": millis / 1000, "nanos": (millis % 1000) * 1_000_000 },
+                "peak_memory": 0,
+                "crashes": [],
+                "signatures_detected": vec![signature; signatures]
+            }))
+            .unwrap()
+        };
+        AssaultReport {
+            assail_report: AssailReport {
+                program_path: PathBuf::from("app"),
+                language: Language::Rust,
+                frameworks: Vec::new(),
+                weak_points: vec![
+                    weak_point(Severity::High, "src/a.rs:3", vec![AttackAxis::Cpu]),
+                    weak_point(
+                        Severity::Critical,
+                        "src/b.rs:9:1",
+                        vec![AttackAxis::Cpu, AttackAxis::Memory],
+                    ),
+                    weak_point(Severity::Low, "src/a.rs:40", vec![]),
+                ],
+                statistics: ProgramStatistics::default(),
+                file_statistics: Vec::new(),
+                recommended_attacks: Vec::new(),
+                dependency_graph: DependencyGraph::default(),
+                taint_matrix: TaintMatrix::default(),
+                migration_metrics: None,
+                annotations: Vec::new(),
+                policies: Vec::new(),
+                manifests: Vec::new(),
+            },
+            // One memory run found a double free twice, the other found nothing.
+            attack_results: vec![attack(2, 500), attack(0, 2000)],
+            total_crashes: 0,
+            total_signatures: 2,
+            overall_assessment: OverallAssessment {
+                robustness_score: 50.0,
+                critical_issues: Vec::new(),
+                recommendations: Vec::new(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pivot_aggregates_each_measure() {
+        let report = report();
+        let facts = facts(&report);
+        let durations: Vec<Duration> = report.attack_results.iter().map(|r| r.duration).collect();
+        let table = pivot(
+            &facts,
+            Dimension::Axis,
+            Dimension::Severity,
+            Measure::Count,
+            &durations,
+        );
+        assert_eq!(table.rows, ["memory", "cpu", "-"]);
+        assert_eq!(table.columns, ["CRITICAL", "HIGH", "LOW", "-"]);
+        assert_eq!(table.cells[0], ["1", "-", "-", "2"]);
+        assert_eq!(table.cells[1], ["1", "1", "-", "-"]);
+
+        let table = pivot(
+            &facts,
+            Dimension::File,
+            Dimension::Axis,
+            Measure::MaxSeverity,
+            &durations,
+        );
+        assert_eq!(table.rows, ["src/b.rs", "src/a.rs", "-"]);
+        assert_eq!(table.cells[0], ["CRITICAL", "CRITICAL", "-"]);
+
+        let table = pivot(
+            &facts,
+            Dimension::Axis,
+            Dimension::Signature,
+            Measure::MeanDuration,
+            &durations,
+        );
+        assert_eq!(table.columns, ["-", "DoubleFree"]);
+        // Memory's "-" cell holds the clean 2s run; its DoubleFree cell the 500ms run, once.
+        assert_eq!(table.cells[0], ["2.0s", "500ms"]);
+        assert_eq!(table.cells[1], ["-", "-"]);
+    }
+
+    #[test]
+    fn test_dimension_cycle_skips_the_other_axis() {
+        assert_eq!(Dimension::Axis.next(Dimension::File), Dimension::Severity);
+        assert_eq!(Dimension::Signature.next(Dimension::File), Dimension::Axis);
+        assert_eq!(Measure::MeanDuration.next(), Measure::Count);
+        assert_eq!(file_of("src/a.rs:3:7"), "src/a.rs");
+        assert_eq!(file_of("Cargo.toml"), "Cargo.toml");
+    }
+}
@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Crash reproduction scripts.
+//!
+//! Every crash in a saved report gets a POSIX shell script under `repro/<report name>/` beside
+//! the report that reruns the target exactly as the attack did: same argv (including the axis
+//! load flags), the environment variables that change crash behaviour, the run limit, and for
+//! the signal axis the same signal rotation and rate. The seed and the resolved stressor schedule
+//! are written into the script header, with the closest `panic-attack` invocation for stressors
+//! that run beside the target rather than through its arguments. Optionally a Rust integration
+//! test skeleton is written next to each script.
+
+use crate::storage::write_atomic;
+use crate::types::{AssaultReport, AttackResult, CrashReport, StressProfile};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory, relative to the report, holding the generated scripts.
+pub const REPRO_DIR: &str = "repro";
+
+/// Environment variables copied into scripts when set, by exact name or `_`-terminated prefix.
+const CAPTURED_ENV: &[&str] = &[
+    "RUST_",
+    "MALLOC_",
+    "ASAN_",
+    "UBSAN_",
+    "TSAN_",
+    "LSAN_",
+    "GLIBC_TUNABLES",
+    "LD_PRELOAD",
+    "LD_LIBRARY_PATH",
+    "LANG",
+    "LC_ALL",
+    "TZ",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReproOptions {
+    /// Also write a `#[test]` skeleton per crash.
+    pub rust_tests: bool,
+}
+
+/// Write reproduction files for every crash into `base/repro/<report_name>/` and record their
+/// paths on the crashes. Crashes that already list scripts (a report being re-saved) are left
+/// alone.
+pub fn write_repro_scripts(
+    report: &mut AssaultReport,
+    base: &Path,
+    report_name: &str,
+    options: ReproOptions,
+) -> Result<usize> {
+    let relative_dir = Path::new(REPRO_DIR).join(report_name);
+    let env = captured_env();
+    let mut written = 0;
+    let mut number = 0;
+    for result in &mut report.attack_results {
+        let mut generated = Vec::new();
+        for (index, crash) in result.crashes.iter().enumerate() {
+            number += 1;
+            if !crash.repro_scripts.is_empty() {
+                continue;
+            }
+            let stem = format!(
+                "{}-{}-crash-{}",
+                file_stem(&result.program),
+                format!("{:?}", result.axis).to_lowercase(),
+                number
+            );
+            let dir = base.join(&relative_dir);
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("creating repro directory {}", dir.display()))?;
+
+            let view = CrashView {
+                result,
+                crash,
+                number,
+                env: &env,
+            };
+            let script = relative_dir.join(format!("{}.sh", stem));
+            write_file(&base.join(&script), &view.shell_script(), true)?;
+            let mut paths = vec![script];
+            if options.rust_tests {
+                let test = relative_dir.join(format!("{}.rs", stem.replace('-', "_")));
+                write_file(&base.join(&test), &view.rust_test(), false)?;
+                paths.push(test);
+            }
+            written += paths.len();
+            generated.push((index, paths));
+        }
+        for (index, paths) in generated {
+            result.crashes[index].repro_scripts = paths;
+        }
+    }
+    Ok(written)
+}
+
+struct CrashView<'a> {
+    result: &'a AttackResult,
+    crash: &'a CrashReport,
+    number: usize,
+    env: &'a [(String, String)],
+}
+
+impl CrashView<'_> {
+    /// Absolute target path; symlinks are kept so multi-call binaries see the same argv[0].
+    fn program(&self) -> PathBuf {
+        std::path::absolute(&self.result.program).unwrap_or_else(|_| self.result.program.clone())
+    }
+
+    fn args(&self) -> &[String] {
+        self.result
+            .stress_profile
+            .as_ref()
+            .map_or(&[], |profile| profile.target_args.as_slice())
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "{:?} crash {} of {} (recorded {}, exit {}, signal {})",
+            self.result.axis,
+            self.number,
+            self.result.program.display(),
+            self.crash.timestamp,
+            self.result
+                .exit_code
+                .map_or_else(|| "none".to_string(), |code| code.to_string()),
+            self.crash.signal.as_deref().unwrap_or("none")
+        )
+    }
+
+    /// Signal rotation and interval, when the run delivered a storm.
+    fn signal_storm(&self) -> Option<(Vec<String>, f64)> {
+        let storm = self.result.signal_storm.as_ref()?;
+        if storm.signals.is_empty() || storm.frequency_hz <= 0.0 {
+            return None;
+        }
+        let mut signals: Vec<String> = storm
+            .signals
+            .iter()
+            .map(|name| name.trim_start_matches("SIG").to_string())
+            .collect();
+        if storm.stop_pulses {
+            signals.extend(["STOP".to_string(), "CONT".to_string()]);
+        }
+        Some((signals, 1.0 / storm.frequency_hz))
+    }
+
+    fn shell_script(&self) -> String {
+        let profile = self.result.stress_profile.as_ref();
+        let mut out = String::from("#!/bin/sh\n");
+        out.push_str(&format!("# Reproduce {}.\n", self.title()));
+        out.push_str(&format!(
+            "# Generated by panic-attack {}; needs only a POSIX shell.\n#\n",
+            env!("CARGO_PKG_VERSION")
+        ));
+        match profile {
+            Some(profile) => {
+                for line in schedule_lines(profile) {
+                    out.push_str(&format!("# {}\n", line));
+                }
+                out.push_str(&format!(
+                    "# Stressors running beside the target are replayed by:\n#   {}\n",
+                    replay_command(self.result, profile)
+                ));
+            }
+            None => out.push_str("# The report recorded no stress profile; argv is empty.\n"),
+        }
+        if let Some(trace) = &self.crash.rr_trace {
+            out.push_str(&format!(
+                "# The failing schedule was recorded; replay it exactly with:\n#   rr replay {}\n",
+                shell_quote(&trace.to_string_lossy())
+            ));
+        }
+        if let Some(schedule) = &self.crash.schedule {
+            out.push_str(&format!(
+                "# The failing thread schedule was recorded; apply it again with:\n\
+                 #   panic-attack attack {} --axis concurrency --replay-schedule {}\n",
+                shell_quote(&self.result.program.to_string_lossy()),
+                shell_quote(&schedule.to_string_lossy())
+            ));
+        }
+        out.push_str("\nset -u\n\n");
+
+        for (name, value) in self.env {
+            out.push_str(&format!("export {}={}\n", name, shell_quote(value)));
+        }
+        out.push_str(&format!(
+            "PROGRAM={}\n",
+            shell_quote(&self.program().to_string_lossy())
+        ));
+        out.push_str("set --");
+        for arg in self.args() {
+            out.push(' ');
+            out.push_str(&shell_quote(arg));
+        }
+        out.push_str("\n\n\"$PROGRAM\" \"$@\" &\npid=$!\nhelpers=\n");
+
+        if let Some((signals, interval)) = self.signal_storm() {
+            out.push_str(&format!(
+                "\n# Signal storm: {} in rotation, one every {:.4}s.\n\
+                 (\n  while kill -0 \"$pid\" 2>/dev/null; do\n    for sig in {}; do\n      \
+                 kill -s \"$sig\" \"$pid\" 2>/dev/null || exit 0\n      sleep {:.4}\n    done\n  \
+                 done\n) &\nhelpers=\"$helpers $!\"\n",
+                signals.join(","),
+                interval,
+                signals.join(" "),
+                interval
+            ));
+        }
+        if let Some(limit) = profile.and_then(|p| p.timeout).filter(|t| !t.is_zero()) {
+            out.push_str(&format!(
+                "\n# Run limit used by the attack.\n\
+                 ( sleep {}; kill -s KILL \"$pid\" 2>/dev/null ) &\nhelpers=\"$helpers $!\"\n",
+                limit.as_secs().max(1)
+            ));
+        }
+
+        out.push_str(&format!(
+            "\nwait \"$pid\"\nstatus=$?\n[ -n \"$helpers\" ] && kill $helpers 2>/dev/null\n\
+             echo \"exit status $status (recorded: {})\" >&2\nexit \"$status\"\n",
+            self.result
+                .exit_code
+                .map_or_else(|| "killed by signal".to_string(), |code| code.to_string())
+        ));
+        out
+    }
+
+    fn rust_test(&self) -> String {
+        let mut out = format!(
+            "// Reproduce {}.\n// Generated by panic-attack {}; copy into tests/ and drop the \
+             #[ignore] once the crash is fixed.\n",
+            self.title(),
+            env!("CARGO_PKG_VERSION")
+        );
+        if let Some(profile) = &self.result.stress_profile {
+            for line in schedule_lines(profile) {
+                out.push_str(&format!("// {}\n", line));
+            }
+        }
+        if self.signal_storm().is_some()
+            || self
+                .result
+                .stress_profile
+                .iter()
+                .any(|p| p.timeout.is_some())
+        {
+            out.push_str("// The signal storm and run limit are only in the shell script.\n");
+        }
+        out.push_str("\nuse std::process::Command;\n\n#[test]\n");
+        out.push_str("#[ignore = \"reruns a crash recorded by panic-attack\"]\n");
+        out.push_str(&format!(
+            "fn repro_{}_crash_{}() {{\n",
+            format!("{:?}", self.result.axis).to_lowercase(),
+            self.number
+        ));
+        out.push_str(&format!(
+            "    let status = Command::new({:?})\n",
+            self.program().to_string_lossy()
+        ));
+        if !self.args().is_empty() {
+            out.push_str(&format!("        .args({:?})\n", self.args()));
+        }
+        for (name, value) in self.env {
+            out.push_str(&format!("        .env({:?}, {:?})\n", name, value));
+        }
+        out.push_str(
+            "        .status()\n        .expect(\"target should start\");\n    \
+             assert!(status.success(), \"target still crashes: {status}\");\n}\n",
+        );
+        out
+    }
+}
+
+/// Human-readable stressor schedule from a resolved profile.
+fn schedule_lines(profile: &StressProfile) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Stress schedule: intensity {:?} (x{}), duration {}s, seed {}",
+        profile.intensity,
+        profile.multiplier,
+        profile.duration.as_secs(),
+        profile.seed
+    )];
+    let mut extra = Vec::new();
+    if let Some(workers) = profile.workers {
+        extra.push(format!("{} workers", workers));
+    }
+    if let Some(bytes) = profile.memory_target_bytes {
+        extra.push(format!("memory target {} bytes", bytes));
+    }
+    if let (Some(files), Some(bytes)) = (profile.disk_files_per_cycle, profile.disk_file_bytes) {
+        extra.push(format!("{} files of {} bytes per cycle", files, bytes));
+    }
+    if let Some(clients) = profile.network_clients {
+        extra.push(format!("{} network clients", clients));
+    }
+    if let Some(hz) = profile.signal_hz {
+        extra.push(format!("signals at {} Hz", hz));
+    }
+    if let Some(timeout) = profile.timeout {
+        extra.push(format!("run limit {}s", timeout.as_secs()));
+    }
+    if !extra.is_empty() {
+        lines.push(format!("  {}", extra.join(", ")));
+    }
+    lines
+}
+
+fn replay_command(result: &AttackResult, profile: &StressProfile) -> String {
+    let mut command = format!(
+        "panic-attack --seed {} attack {} --axis {} --intensity {} --duration {}",
+        profile.seed,
+        shell_quote(&result.program.to_string_lossy()),
+        format!("{:?}", result.axis).to_lowercase(),
+        format!("{:?}", profile.intensity).to_lowercase(),
+        profile.duration.as_secs()
+    );
+    if let Some(hz) = profile.signal_hz {
+        command.push_str(&format!(" --signal-hz {}", hz));
+    }
+    command
+}
+
+fn captured_env() -> Vec<(String, String)> {
+    let mut env: Vec<(String, String)> = std::env::vars()
+        .filter(|(name, _)| {
+            CAPTURED_ENV.iter().any(|pattern| {
+                if pattern.ends_with('_') {
+                    name.starts_with(pattern)
+                } else {
+                    name == pattern
+                }
+            })
+        })
+        .collect();
+    env.sort();
+    env
+}
+
+fn file_stem(program: &Path) -> String {
+    program
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or_else(|| "target".to_string())
+}
+
+fn shell_quote(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c))
+    {
+        return value.to_string();
+    }
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn write_file(path: &Path, content: &str, executable: bool) -> Result<()> {
+    write_atomic(path, content).with_context(|| format!("writing {}", path.display()))?;
+    #[cfg(unix)]
+    if executable {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("making {} executable", path.display()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = executable;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("--allocate-mb"), "--allocate-mb");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+
+    #[test]
+    fn test_schedule_lists_resolved_stressors() {
+        let mut profile = StressProfile::new(
+            crate::types::IntensityLevel::Heavy,
+            std::time::Duration::from_secs(30),
+            42,
+        );
+        profile.workers = Some(8);
+        profile.timeout = Some(std::time::Duration::from_secs(60));
+        let lines = schedule_lines(&profile);
+        assert!(lines[0].contains("Heavy") && lines[0].ends_with("seed 42"));
+        assert_eq!(lines[1], "  8 workers, run limit 60s");
+    }
+}
@@ -2,15 +2,20 @@
 
 //! SARIF 2.1.0 output for GitHub Security tab integration
 //!
-//! Converts AssailReport weak points into OASIS SARIF format.
+//! Converts AssailReport weak points into OASIS SARIF format. Assault reports add one result
+//! per crash, located at the innermost application frame of its parsed backtrace.
 //! See: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
 
-use crate::types::{AssailReport, Severity, WeakPointCategory};
+use crate::attack::explore::crash_bucket;
+use crate::signatures::backtrace;
+use crate::types::{AssailReport, AssaultReport, Severity, WeakPointCategory};
 use anyhow::Result;
 use serde::Serialize;
 
 const SARIF_SCHEMA: &str = "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json";
 const SARIF_VERSION: &str = "2.1.0";
+/// Rule of crashes observed under attack, as opposed to static findings.
+const CRASH_RULE: &str = "PA100";
 
 /// Top-level SARIF log
 #[derive(Debug, Serialize)]
@@ -72,6 +77,16 @@ pub struct SarifResult {
     pub level: String,
     pub message: SarifMessage,
     pub locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SarifProperties>,
+}
+
+/// Property bag of a result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifProperties {
+    /// CODEOWNERS owner(s) of the location, space-separated.
+    pub owner: String,
 }
 
 /// Message with text
@@ -112,7 +127,7 @@ pub struct SarifRegion {
 }
 
 /// Map WeakPointCategory to a stable rule ID
-fn rule_id(category: &WeakPointCategory) -> &'static str {
+pub(crate) fn rule_id(category: &WeakPointCategory) -> &'static str {
     match category {
         WeakPointCategory::UncheckedAllocation => "PA001",
         WeakPointCategory::UnboundedLoop => "PA002",
@@ -134,6 +149,11 @@ fn rule_id(category: &WeakPointCategory) -> &'static str {
         WeakPointCategory::UncheckedError => "PA018",
         WeakPointCategory::InfiniteRecursion => "PA019",
         WeakPointCategory::UnsafeTypeCoercion => "PA020",
+        WeakPointCategory::UnsafeTransmute => "PA021",
+        WeakPointCategory::RawPointerDeref => "PA022",
+        WeakPointCategory::MutableStatic => "PA023",
+        WeakPointCategory::ManualSendSync => "PA024",
+        WeakPointCategory::VulnerableDependency => "PA025",
     }
 }
 
@@ -160,6 +180,11 @@ fn rule_name(category: &WeakPointCategory) -> &'static str {
         WeakPointCategory::UncheckedError => "unchecked-error",
         WeakPointCategory::InfiniteRecursion => "infinite-recursion",
         WeakPointCategory::UnsafeTypeCoercion => "unsafe-type-coercion",
+        WeakPointCategory::UnsafeTransmute => "unsafe-transmute",
+        WeakPointCategory::RawPointerDeref => "raw-pointer-deref",
+        WeakPointCategory::MutableStatic => "mutable-static",
+        WeakPointCategory::ManualSendSync => "manual-send-sync",
+        WeakPointCategory::VulnerableDependency => "vulnerable-dependency",
     }
 }
 
@@ -174,7 +199,7 @@ fn sarif_level(severity: &Severity) -> &'static str {
 }
 
 /// Parse a location string like "src/main.rs:42" into (path, optional line)
-fn parse_location(loc: &str) -> (&str, Option<u32>) {
+pub(crate) fn parse_location(loc: &str) -> (&str, Option<u32>) {
     if let Some(colon_pos) = loc.rfind(':') {
         let (path, rest) = loc.split_at(colon_pos);
         if let Ok(line) = rest[1..].parse::<u32>() {
@@ -227,6 +252,7 @@ pub fn to_sarif(report: &AssailReport) -> Result<SarifLog> {
                         region: line.map(|l| SarifRegion { start_line: l }),
                     },
                 }],
+                properties: wp.owner.clone().map(|owner| SarifProperties { owner }),
             }
         })
         .collect();
@@ -254,3 +280,68 @@ pub fn to_sarif_json(report: &AssailReport) -> Result<String> {
     let json = serde_json::to_string_pretty(&log)?;
     Ok(json)
 }
+
+/// Convert an AssaultReport to SARIF: its static findings plus one result per crash.
+pub fn to_sarif_assault(report: &AssaultReport) -> Result<SarifLog> {
+    let mut log = to_sarif(&report.assail_report)?;
+    let crashes: Vec<SarifResult> = report
+        .attack_results
+        .iter()
+        .flat_map(|result| result.crashes.iter().map(move |crash| (result, crash)))
+        .map(|(result, crash)| {
+            let frame = backtrace::top_frame(&crash.frames);
+            // Without a source location the crash is pinned to the binary itself.
+            let (uri, line) = match frame.and_then(|frame| Some((frame.file.clone()?, frame.line)))
+            {
+                Some(location) => location,
+                None => (result.program.display().to_string(), None),
+            };
+            let cause = crash
+                .signal
+                .clone()
+                .or_else(|| result.exit_code.map(|code| format!("exit code {}", code)))
+                .unwrap_or_else(|| "killed".to_string());
+            let text = match frame {
+                Some(frame) => format!(
+                    "{:?} attack crashed the target ({}) in {}",
+                    result.axis, cause, frame.function
+                ),
+                None => format!("{:?} attack crashed the target ({})", result.axis, cause),
+            };
+            SarifResult {
+                rule_id: CRASH_RULE.to_string(),
+                level: "error".to_string(),
+                message: SarifMessage { text },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation { uri },
+                        region: line.map(|l| SarifRegion { start_line: l }),
+                    },
+                }],
+                properties: crash_bucket(result)
+                    .and_then(|bucket| report.crash_owners.get(&bucket).cloned())
+                    .map(|owner| SarifProperties { owner }),
+            }
+        })
+        .collect();
+    if let (Some(run), false) = (log.runs.first_mut(), crashes.is_empty()) {
+        run.tool.driver.rules.push(SarifRule {
+            id: CRASH_RULE.to_string(),
+            name: "crash-under-attack".to_string(),
+            short_description: SarifMessage {
+                text: "Target crashed under attack".to_string(),
+            },
+            default_configuration: SarifConfiguration {
+                level: "error".to_string(),
+            },
+        });
+        run.results.extend(crashes);
+    }
+    Ok(log)
+}
+
+/// Serialize an assault report's SARIF log to JSON string
+pub fn to_sarif_assault_json(report: &AssaultReport) -> Result<String> {
+    let log = to_sarif_assault(report)?;
+    Ok(serde_json::to_string_pretty(&log)?)
+}
@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! CycloneDX 1.5 and SPDX 2.3 SBOM output from assail's manifest inventory
+//!
+//! Lists the package each discovered manifest declares and every dependency it declares,
+//! with versions as written. A declared requirement such as `^1.2` is not a resolved
+//! version, so it goes in the component's version field but only an exact pin reaches the
+//! purl. The scanned target is the document's root component and carries the run's headline
+//! findings as properties, so an inventory can be lined up with the stress results.
+//! See: https://cyclonedx.org/docs/1.5/json/ and https://spdx.github.io/spdx-spec/v2.3/
+
+use crate::types::{AssailReport, AssaultReport, DependencyManifest, DependencyScope, Ecosystem};
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+const CYCLONEDX_SCHEMA: &str = "http://cyclonedx.org/schema/bom-1.5.schema.json";
+const CYCLONEDX_VERSION: &str = "1.5";
+const SPDX_VERSION: &str = "SPDX-2.3";
+const ROOT_REF: &str = "panic-attack:target";
+const NOASSERTION: &str = "NOASSERTION";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SbomFormat {
+    Cyclonedx,
+    Spdx,
+}
+
+/// Serialize the manifest inventory of `assail` (plus crash totals from `assault`, when the
+/// SBOM is built from an assault report) in the requested format.
+pub fn to_sbom_json(
+    format: SbomFormat,
+    assail: &AssailReport,
+    assault: Option<&AssaultReport>,
+) -> Result<String> {
+    let inventory = Inventory::build(assail, assault);
+    Ok(match format {
+        SbomFormat::Cyclonedx => serde_json::to_string_pretty(&inventory.to_cyclonedx())?,
+        SbomFormat::Spdx => serde_json::to_string_pretty(&inventory.to_spdx())?,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Format-neutral inventory
+// ---------------------------------------------------------------------------
+
+struct Package {
+    ecosystem: Ecosystem,
+    name: String,
+    version: Option<String>,
+    /// Manifest that declares this package, or first declared it as a dependency.
+    manifest: String,
+    /// `None` for packages a manifest declares itself.
+    scope: Option<DependencyScope>,
+}
+
+impl Package {
+    fn bom_ref(&self) -> String {
+        format!(
+            "{}:{}@{}",
+            ecosystem_label(self.ecosystem),
+            self.name,
+            self.version.as_deref().unwrap_or("*")
+        )
+    }
+
+    fn purl(&self) -> String {
+        let (kind, name) = match self.ecosystem {
+            Ecosystem::Cargo => ("cargo", self.name.clone()),
+            Ecosystem::Npm => ("npm", self.name.replacen('@', "%40", 1)),
+            Ecosystem::Go => ("golang", self.name.clone()),
+        };
+        match self.exact_version() {
+            Some(version) => format!("pkg:{}/{}@{}", kind, name, version),
+            None => format!("pkg:{}/{}", kind, name),
+        }
+    }
+
+    /// The version when it pins one release: a manifest's own version, a Cargo `=x.y.z`, a
+    /// bare npm version, or any Go requirement (Go modules always name one version).
+    fn exact_version(&self) -> Option<&str> {
+        let version = self.version.as_deref()?.trim();
+        if self.scope.is_none() || self.ecosystem == Ecosystem::Go {
+            return Some(version);
+        }
+        let pinned = match self.ecosystem {
+            Ecosystem::Cargo => version.strip_prefix('=')?.trim(),
+            _ => version,
+        };
+        let plain = !pinned.is_empty()
+            && pinned.starts_with(|c: char| c.is_ascii_digit())
+            && pinned
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'));
+        plain.then_some(pinned)
+    }
+}
+
+struct Edge {
+    /// `None` is the scanned target.
+    from: Option<usize>,
+    to: usize,
+    scope: DependencyScope,
+}
+
+struct Inventory {
+    root_name: String,
+    root_properties: Vec<(String, String)>,
+    packages: Vec<Package>,
+    edges: Vec<Edge>,
+}
+
+impl Inventory {
+    fn build(assail: &AssailReport, assault: Option<&AssaultReport>) -> Self {
+        // A lone manifest stands for the project directory holding it.
+        let root_path = match assail.program_path.file_name().and_then(|n| n.to_str()) {
+            Some("Cargo.toml" | "package.json" | "go.mod") => assail
+                .program_path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(std::path::Path::new("."))
+                .to_path_buf(),
+            _ => assail.program_path.clone(),
+        };
+        let root_name = root_path
+            .canonicalize()
+            .unwrap_or_else(|_| root_path.clone())
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| root_path.display().to_string());
+        let mut root_properties = vec![
+            ("language".to_string(), format!("{:?}", assail.language)),
+            (
+                "weak-points".to_string(),
+                assail.weak_points.len().to_string(),
+            ),
+        ];
+        if !assail.frameworks.is_empty() {
+            let frameworks: Vec<String> = assail
+                .frameworks
+                .iter()
+                .map(|f| format!("{:?}", f))
+                .collect();
+            root_properties.push(("frameworks".to_string(), frameworks.join(",")));
+        }
+        if let Some(assault) = assault {
+            root_properties.push((
+                "total-crashes".to_string(),
+                assault.total_crashes.to_string(),
+            ));
+            root_properties.push((
+                "robustness-score".to_string(),
+                format!("{:.1}", assault.overall_assessment.robustness_score),
+            ));
+        }
+
+        let mut inventory = Inventory {
+            root_name,
+            root_properties,
+            packages: Vec::new(),
+            edges: Vec::new(),
+        };
+        // Manifest packages first, so a path dependency on a sibling crate resolves to it.
+        let parents: Vec<Option<usize>> = assail
+            .manifests
+            .iter()
+            .map(|manifest| {
+                let name = manifest.name.as_ref()?;
+                let index = inventory.package(manifest, name, manifest.version.as_ref(), None);
+                inventory.edge(None, index, DependencyScope::Runtime);
+                Some(index)
+            })
+            .collect();
+        for (manifest, parent) in assail.manifests.iter().zip(parents) {
+            for dependency in &manifest.dependencies {
+                let index = inventory.package(
+                    manifest,
+                    &dependency.name,
+                    dependency.version.as_ref(),
+                    Some(dependency.scope),
+                );
+                inventory.edge(parent, index, dependency.scope);
+            }
+        }
+        inventory
+    }
+
+    fn package(
+        &mut self,
+        manifest: &DependencyManifest,
+        name: &str,
+        version: Option<&String>,
+        scope: Option<DependencyScope>,
+    ) -> usize {
+        let existing = self.packages.iter().position(|package| {
+            package.ecosystem == manifest.ecosystem
+                && package.name == name
+                && (version.is_none() || package.version.as_ref() == version)
+        });
+        existing.unwrap_or_else(|| {
+            self.packages.push(Package {
+                ecosystem: manifest.ecosystem,
+                name: name.to_string(),
+                version: version.cloned(),
+                manifest: manifest.path.clone(),
+                scope,
+            });
+            self.packages.len() - 1
+        })
+    }
+
+    fn edge(&mut self, from: Option<usize>, to: usize, scope: DependencyScope) {
+        if from != Some(to)
+            && !self
+                .edges
+                .iter()
+                .any(|edge| edge.from == from && edge.to == to)
+        {
+            self.edges.push(Edge { from, to, scope });
+        }
+    }
+
+    fn document_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.root_name.as_bytes());
+        for package in &self.packages {
+            hasher.update(package.bom_ref().as_bytes());
+        }
+        hasher.update(chrono::Utc::now().to_rfc3339().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    // -----------------------------------------------------------------------
+    // CycloneDX
+    // -----------------------------------------------------------------------
+
+    fn to_cyclonedx(&self) -> CycloneDxBom {
+        let hash = self.document_hash();
+        let components = self
+            .packages
+            .iter()
+            .map(|package| CycloneDxComponent {
+                kind: if package.scope.is_none() {
+                    "application"
+                } else {
+                    "library"
+                },
+                bom_ref: package.bom_ref(),
+                name: package.name.clone(),
+                version: package.version.clone(),
+                purl: Some(package.purl()),
+                scope: package.scope.map(|scope| match scope {
+                    DependencyScope::Optional => "optional",
+                    DependencyScope::Dev => "excluded",
+                    _ => "required",
+                }),
+                properties: std::iter::once(property("manifest", &package.manifest))
+                    .chain(
+                        package
+                            .scope
+                            .map(|scope| property("dependency-scope", scope_label(scope))),
+                    )
+                    .collect(),
+            })
+            .collect();
+
+        let mut dependencies = vec![self.cyclonedx_dependency(None, ROOT_REF.to_string())];
+        dependencies.extend(
+            self.packages
+                .iter()
+                .enumerate()
+                .map(|(index, package)| self.cyclonedx_dependency(Some(index), package.bom_ref())),
+        );
+
+        CycloneDxBom {
+            schema: CYCLONEDX_SCHEMA,
+            bom_format: "CycloneDX",
+            spec_version: CYCLONEDX_VERSION,
+            serial_number: format!(
+                "urn:uuid:{}-{}-4{}-8{}-{}",
+                &hash[..8],
+                &hash[8..12],
+                &hash[13..16],
+                &hash[17..20],
+                &hash[20..32]
+            ),
+            version: 1,
+            metadata: CycloneDxMetadata {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                tools: CycloneDxTools {
+                    components: vec![CycloneDxTool {
+                        kind: "application",
+                        name: "panic-attack",
+                        version: env!("CARGO_PKG_VERSION"),
+                    }],
+                },
+                component: CycloneDxComponent {
+                    kind: "application",
+                    bom_ref: ROOT_REF.to_string(),
+                    name: self.root_name.clone(),
+                    version: None,
+                    purl: None,
+                    scope: None,
+                    properties: self
+                        .root_properties
+                        .iter()
+                        .map(|(name, value)| property(name, value))
+                        .collect(),
+                },
+            },
+            components,
+            dependencies,
+        }
+    }
+
+    fn cyclonedx_dependency(&self, from: Option<usize>, reference: String) -> CycloneDxDependency {
+        CycloneDxDependency {
+            reference,
+            depends_on: self
+                .edges
+                .iter()
+                .filter(|edge| edge.from == from)
+                .map(|edge| self.packages[edge.to].bom_ref())
+                .collect(),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // SPDX
+    // -----------------------------------------------------------------------
+
+    fn to_spdx(&self) -> SpdxDocument {
+        let spdx_id = |index: Option<usize>| match index {
+            Some(index) => format!("SPDXRef-Package-{}", index + 1),
+            None => "SPDXRef-Target".to_string(),
+        };
+        let mut packages = vec![SpdxPackage {
+            name: self.root_name.clone(),
+            spdx_id: spdx_id(None),
+            version_info: None,
+            download_location: NOASSERTION,
+            files_analyzed: false,
+            license_concluded: NOASSERTION,
+            license_declared: NOASSERTION,
+            copyright_text: NOASSERTION,
+            external_refs: Vec::new(),
+            comment: Some(
+                self.root_properties
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ),
+        }];
+        packages.extend(
+            self.packages
+                .iter()
+                .enumerate()
+                .map(|(index, package)| SpdxPackage {
+                    name: package.name.clone(),
+                    spdx_id: spdx_id(Some(index)),
+                    version_info: package.version.clone(),
+                    download_location: NOASSERTION,
+                    files_analyzed: false,
+                    license_concluded: NOASSERTION,
+                    license_declared: NOASSERTION,
+                    copyright_text: NOASSERTION,
+                    external_refs: vec![SpdxExternalRef {
+                        reference_category: "PACKAGE-MANAGER",
+                        reference_type: "purl",
+                        reference_locator: package.purl(),
+                    }],
+                    comment: Some(match package.scope {
+                        Some(scope) => {
+                            format!(
+                                "{} dependency declared in {}",
+                                scope_label(scope),
+                                package.manifest
+                            )
+                        }
+                        None => format!("declared by {}", package.manifest),
+                    }),
+                }),
+        );
+
+        let mut relationships = vec![SpdxRelationship {
+            spdx_element_id: "SPDXRef-DOCUMENT".to_string(),
+            relationship_type: "DESCRIBES",
+            related_spdx_element: spdx_id(None),
+        }];
+        relationships.extend(self.edges.iter().map(|edge| {
+            let (parent, dependency) = (spdx_id(edge.from), spdx_id(Some(edge.to)));
+            // SPDX names the non-runtime kinds from the dependency's side.
+            let reverse = |kind| SpdxRelationship {
+                spdx_element_id: dependency.clone(),
+                relationship_type: kind,
+                related_spdx_element: parent.clone(),
+            };
+            match edge.scope {
+                DependencyScope::Dev => reverse("DEV_DEPENDENCY_OF"),
+                DependencyScope::Build => reverse("BUILD_DEPENDENCY_OF"),
+                DependencyScope::Optional => reverse("OPTIONAL_DEPENDENCY_OF"),
+                DependencyScope::Runtime | DependencyScope::Indirect => SpdxRelationship {
+                    spdx_element_id: parent.clone(),
+                    relationship_type: "DEPENDS_ON",
+                    related_spdx_element: dependency.clone(),
+                },
+            }
+        }));
+
+        SpdxDocument {
+            spdx_version: SPDX_VERSION,
+            data_license: "CC0-1.0",
+            spdx_id: "SPDXRef-DOCUMENT",
+            name: format!("{}-sbom", self.root_name),
+            document_namespace: format!(
+                "https://github.com/hyperpolymath/panic-attacker/spdx/{}-{}",
+                self.root_name
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "-"),
+                &self.document_hash()[..16]
+            ),
+            creation_info: SpdxCreationInfo {
+                created: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                creators: vec![format!("Tool: panic-attack-{}", env!("CARGO_PKG_VERSION"))],
+            },
+            packages,
+            relationships,
+        }
+    }
+}
+
+fn ecosystem_label(ecosystem: Ecosystem) -> &'static str {
+    match ecosystem {
+        Ecosystem::Cargo => "cargo",
+        Ecosystem::Npm => "npm",
+        Ecosystem::Go => "go",
+    }
+}
+
+fn scope_label(scope: DependencyScope) -> &'static str {
+    match scope {
+        DependencyScope::Runtime => "runtime",
+        DependencyScope::Dev => "dev",
+        DependencyScope::Build => "build",
+        DependencyScope::Optional => "optional",
+        DependencyScope::Indirect => "indirect",
+    }
+}
+
+fn property(name: &str, value: &str) -> CycloneDxProperty {
+    CycloneDxProperty {
+        name: format!("panic-attack:{}", name),
+        value: value.to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Wire types
+// ---------------------------------------------------------------------------
+
+/// CycloneDX 1.5 BOM
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxBom {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub bom_format: &'static str,
+    pub spec_version: &'static str,
+    pub serial_number: String,
+    pub version: u32,
+    pub metadata: CycloneDxMetadata,
+    pub components: Vec<CycloneDxComponent>,
+    pub dependencies: Vec<CycloneDxDependency>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CycloneDxMetadata {
+    pub timestamp: String,
+    pub tools: CycloneDxTools,
+    pub component: CycloneDxComponent,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CycloneDxTools {
+    pub components: Vec<CycloneDxTool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CycloneDxTool {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub name: &'static str,
+    pub version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CycloneDxComponent {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<CycloneDxProperty>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CycloneDxProperty {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxDependency {
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub depends_on: Vec<String>,
+}
+
+/// SPDX 2.3 document
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    pub spdx_version: &'static str,
+    pub data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: &'static str,
+    pub name: String,
+    pub document_namespace: String,
+    pub creation_info: SpdxCreationInfo,
+    pub packages: Vec<SpdxPackage>,
+    pub relationships: Vec<SpdxRelationship>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SpdxCreationInfo {
+    pub created: String,
+    pub creators: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxPackage {
+    pub name: String,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+    pub download_location: &'static str,
+    pub files_analyzed: bool,
+    pub license_concluded: &'static str,
+    pub license_declared: &'static str,
+    pub copyright_text: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<SpdxExternalRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxExternalRef {
+    pub reference_category: &'static str,
+    pub reference_type: &'static str,
+    pub reference_locator: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxRelationship {
+    pub spdx_element_id: String,
+    pub relationship_type: &'static str,
+    pub related_spdx_element: String,
+}
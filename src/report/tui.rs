@@ -2,7 +2,11 @@
 
 //! Lightweight terminal UI for reviewing assault reports
 
+use crate::report::artifacts::{self, ArtifactStore, CrashOutput, PAGE_LINES};
 use crate::report::formatter::ReportFormatter;
+use crate::report::pivot::{self, Dimension, Fact, Measure, PivotTable};
+use crate::signatures::backtrace;
+use crate::triage::TriageStore;
 use crate::types::*;
 use anyhow::Result;
 use colored::*;
@@ -15,17 +19,41 @@ use crossterm::{
 use std::io::{stdout, Write};
 use std::time::Duration;
 
+/// Innermost frames shown per parsed backtrace.
+const TRACE_FRAMES: usize = 8;
+
+/// Rows and columns the pivot explorer shows; the rest are counted below the table.
+const PIVOT_ROWS: usize = 20;
+const PIVOT_COLUMNS: usize = 8;
+/// Widest a pivot row label or column may grow before it is cut.
+const PIVOT_LABEL_WIDTH: usize = 36;
+const PIVOT_CELL_WIDTH: usize = 16;
+
 pub struct ReportTui;
 
 impl ReportTui {
-    pub fn run(report: &AssaultReport) -> Result<()> {
+    /// `store` resolves crash output kept outside the report; `None` shows previews only.
+    /// `triage` supplies the annotations shown in the Triage section. `accessible` trades the
+    /// glyphs for ASCII, spells out the selection and expansion state, puts the assessment
+    /// first and redraws only after a key press, so screen readers do not re-read the screen.
+    pub fn run(
+        report: &AssaultReport,
+        store: Option<&ArtifactStore>,
+        triage: &TriageStore,
+        accessible: bool,
+    ) -> Result<()> {
         terminal::enable_raw_mode()?;
-        let result = Self::run_inner(report);
+        let result = Self::run_inner(report, store, triage, accessible);
         terminal::disable_raw_mode()?;
         result
     }
 
-    fn run_inner(report: &AssaultReport) -> Result<()> {
+    fn run_inner(
+        report: &AssaultReport,
+        store: Option<&ArtifactStore>,
+        triage: &TriageStore,
+        accessible: bool,
+    ) -> Result<()> {
         let mut stdout = stdout();
         execute!(
             stdout,
@@ -35,22 +63,88 @@ impl ReportTui {
         let mut selected = 0;
         let mut expanded = Vec::new();
         let mut show_pivot = false;
-        let formatter = ReportFormatter::new();
+        let formatter = ReportFormatter::new().with_accessible(accessible);
+        let outputs = artifacts::crash_outputs(report);
+        let mut viewer: Option<OutputViewer> = None;
+        let mut explorer: Option<PivotExplorer> = None;
+        let mut redraw = true;
 
         loop {
-            let sections = Self::build_sections(report, &formatter, show_pivot);
+            if let Some(open) = explorer.as_mut() {
+                if redraw || !accessible {
+                    open.render(&mut stdout, accessible)?;
+                    redraw = false;
+                }
+                if event::poll(Duration::from_millis(200))? {
+                    if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                        redraw = true;
+                        match code {
+                            KeyCode::Char('q') | KeyCode::Char('v') | KeyCode::Esc => {
+                                explorer = None;
+                            }
+                            KeyCode::Char('r') => open.row = open.row.next(open.column),
+                            KeyCode::Char('c') => open.column = open.column.next(open.row),
+                            KeyCode::Char('m') => open.measure = open.measure.next(),
+                            KeyCode::Char('t') => {
+                                std::mem::swap(&mut open.row, &mut open.column);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                continue;
+            }
+
+            if let Some(open) = viewer.as_mut() {
+                if redraw || !accessible {
+                    open.render(&mut stdout, &outputs)?;
+                    redraw = false;
+                }
+                if event::poll(Duration::from_millis(200))? {
+                    if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                        redraw = true;
+                        match code {
+                            KeyCode::Char('q') | KeyCode::Char('o') | KeyCode::Esc => {
+                                viewer = None;
+                            }
+                            KeyCode::Char('n') | KeyCode::PageDown | KeyCode::Char(' ') => {
+                                open.page += 1;
+                            }
+                            KeyCode::Char('b') | KeyCode::PageUp => {
+                                open.page = open.page.saturating_sub(1);
+                            }
+                            KeyCode::Char(']') => {
+                                let next = (open.index + 1) % outputs.len();
+                                viewer = Some(OutputViewer::open(&outputs, next, store));
+                            }
+                            KeyCode::Char('[') => {
+                                let prev = (open.index + outputs.len() - 1) % outputs.len();
+                                viewer = Some(OutputViewer::open(&outputs, prev, store));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let sections = Self::build_sections(report, &formatter, show_pivot, triage, accessible);
             if expanded.len() != sections.len() {
                 expanded = vec![false; sections.len()];
                 selected = selected.min(sections.len().saturating_sub(1));
             }
 
-            Self::render(&mut stdout, &sections, selected, &expanded)?;
+            if redraw || !accessible {
+                Self::render(&mut stdout, &sections, selected, &expanded, accessible)?;
+                redraw = false;
+            }
 
             if event::poll(Duration::from_millis(200))? {
                 if let Event::Key(KeyEvent {
                     code, modifiers, ..
                 }) = event::read()?
                 {
+                    redraw = true;
                     match code {
                         KeyCode::Char('q') => break,
                         KeyCode::Tab => {
@@ -67,6 +161,12 @@ impl ReportTui {
                         KeyCode::Char('p') => {
                             show_pivot = !show_pivot;
                         }
+                        KeyCode::Char('o') if !outputs.is_empty() => {
+                            viewer = Some(OutputViewer::open(&outputs, 0, store));
+                        }
+                        KeyCode::Char('v') => {
+                            explorer = Some(PivotExplorer::new(report));
+                        }
                         KeyCode::Char('j') | KeyCode::Down => {
                             selected = (selected + 1) % sections.len();
                         }
@@ -91,6 +191,7 @@ impl ReportTui {
         sections: &[Section],
         selected: usize,
         expanded: &[bool],
+        accessible: bool,
     ) -> Result<()> {
         execute!(
             stdout,
@@ -105,19 +206,34 @@ impl ReportTui {
         writeln!(stdout)?;
 
         for (idx, section) in sections.iter().enumerate() {
-            let indicator = if idx == selected {
-                "➤".green()
+            let open = expanded.get(idx).copied().unwrap_or(false);
+            if accessible {
+                // Position, selection and state in words, in the order they are read.
+                writeln!(
+                    stdout,
+                    "{} Section {} of {}: {}, {}. {}",
+                    if idx == selected { ">" } else { " " },
+                    idx + 1,
+                    sections.len(),
+                    section.title,
+                    if open { "expanded" } else { "collapsed" },
+                    section.summary
+                )?;
             } else {
-                "  ".normal()
-            };
-            writeln!(
-                stdout,
-                "{} {} {}",
-                indicator,
-                section.title.bold(),
-                section.summary.dimmed()
-            )?;
-            if expanded.get(idx).copied().unwrap_or(false) {
+                let indicator = if idx == selected {
+                    "➤".green()
+                } else {
+                    "  ".normal()
+                };
+                writeln!(
+                    stdout,
+                    "{} {} {}",
+                    indicator,
+                    section.title.bold(),
+                    section.summary.dimmed()
+                )?;
+            }
+            if open {
                 for detail in &section.details {
                     writeln!(stdout, "    {}", detail)?;
                 }
@@ -128,7 +244,7 @@ impl ReportTui {
         writeln!(
             stdout,
             "{}",
-            "Controls: [Tab/j] Next, [Shift+Tab/k] Prev, [Space] Toggle, [p] Pivot, [q] Quit"
+            "Controls: [Tab/j] Next, [Shift+Tab/k] Prev, [Space] Toggle, [p] Pivot, [v] Pivot explorer, [o] Output, [q] Quit"
                 .dimmed()
         )?;
         stdout.flush()?;
@@ -139,15 +255,23 @@ impl ReportTui {
         report: &AssaultReport,
         formatter: &ReportFormatter,
         include_pivot: bool,
+        triage: &TriageStore,
+        accessible: bool,
     ) -> Vec<Section> {
         let assail = &report.assail_report;
         let mut sections = Vec::new();
+        let (separator, arrow) = if accessible {
+            ("|", "->")
+        } else {
+            ("┃", "→")
+        };
 
         sections.push(Section {
             title: "Assail Summary",
             summary: format!(
-                "{} weak points ┃ {} files",
+                "{} weak points {} {} files",
                 assail.weak_points.len(),
+                separator,
                 assail.file_statistics.len()
             ),
             details: vec![
@@ -230,17 +354,80 @@ impl ReportTui {
                 .collect(),
         });
 
+        let mut traces = 0;
+        let mut trace_lines = Vec::new();
+        for result in &report.attack_results {
+            for crash in result.crashes.iter().filter(|c| !c.frames.is_empty()) {
+                traces += 1;
+                trace_lines.push(format!(
+                    "{}. {:?} crash, {} frames",
+                    traces,
+                    result.axis,
+                    crash.frames.len()
+                ));
+                let top = backtrace::top_frame(&crash.frames);
+                for (depth, frame) in crash.frames.iter().take(TRACE_FRAMES).enumerate() {
+                    let marker = if top == Some(frame) { arrow } else { "" };
+                    trace_lines.push(format!("  {:<2} {:>2} {}", marker, depth, frame.describe()));
+                }
+            }
+        }
+        sections.push(Section {
+            title: "Backtraces",
+            summary: format!("{} parsed {} {} crashing frame", traces, separator, arrow),
+            details: trace_lines,
+        });
+
+        let outputs = artifacts::crash_outputs(report);
+        let stored = outputs
+            .iter()
+            .filter(|output| output.artifact.is_some())
+            .count();
+        sections.push(Section {
+            title: "Crash Output",
+            summary: format!(
+                "{} outputs {sep} {} stored externally {sep} [o] to page",
+                outputs.len(),
+                stored,
+                sep = separator
+            ),
+            details: outputs
+                .iter()
+                .map(|output| match &output.artifact {
+                    Some(artifact) => format!(
+                        "{} ({} bytes in {})",
+                        output.label,
+                        artifact.bytes,
+                        artifact.path.display()
+                    ),
+                    None => format!("{} ({} bytes inline)", output.label, output.inline.len()),
+                })
+                .collect(),
+        });
+
+        let triage_lines = triage.summary_lines(report);
+        sections.push(Section {
+            title: "Triage",
+            summary: format!("{} annotated", triage_lines.len()),
+            details: triage_lines,
+        });
+
         let mut assessment_notes = Vec::new();
         assessment_notes.extend(report.overall_assessment.critical_issues.iter().cloned());
         assessment_notes.extend(report.overall_assessment.recommendations.iter().cloned());
-        sections.push(Section {
+        let assessment = Section {
             title: "Assessment",
             summary: format!(
                 "Score {:.1}/100",
                 report.overall_assessment.robustness_score
             ),
             details: assessment_notes,
-        });
+        };
+        if accessible {
+            sections.insert(0, assessment);
+        } else {
+            sections.push(assessment);
+        }
 
         if include_pivot {
             let pivot_rows = formatter.pivot_rows(assail);
@@ -277,3 +464,282 @@ struct Section {
     summary: String,
     details: Vec<String>,
 }
+
+/// Pager over one crash output; artifacts are read when the output is opened.
+struct OutputViewer {
+    index: usize,
+    page: usize,
+    text: String,
+}
+
+impl OutputViewer {
+    fn open(outputs: &[CrashOutput], index: usize, store: Option<&ArtifactStore>) -> Self {
+        Self {
+            index,
+            page: 0,
+            text: outputs[index].load(store),
+        }
+    }
+
+    fn render(&mut self, stdout: &mut impl Write, outputs: &[CrashOutput]) -> Result<()> {
+        let (lines, pages) = artifacts::page_of(&self.text, self.page, PAGE_LINES);
+        self.page = self.page.min(pages - 1);
+        execute!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        writeln!(
+            stdout,
+            "{} {}",
+            outputs[self.index].label.bold().cyan(),
+            format!(
+                "(output {}/{}, page {}/{})",
+                self.index + 1,
+                outputs.len(),
+                self.page + 1,
+                pages
+            )
+            .dimmed()
+        )?;
+        writeln!(stdout)?;
+        for line in lines {
+            writeln!(stdout, "{}", line)?;
+        }
+        writeln!(stdout)?;
+        writeln!(
+            stdout,
+            "{}",
+            "Controls: [n/Space] Next page, [b] Prev page, []] Next output, [[] Prev output, [Esc] Back"
+                .dimmed()
+        )?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+/// Interactive pivot of the report's findings (`pivot::facts`) by two dimensions.
+struct PivotExplorer {
+    row: Dimension,
+    column: Dimension,
+    measure: Measure,
+    facts: Vec<Fact>,
+    durations: Vec<Duration>,
+}
+
+impl PivotExplorer {
+    fn new(report: &AssaultReport) -> Self {
+        Self {
+            row: Dimension::Axis,
+            column: Dimension::Severity,
+            measure: Measure::Count,
+            facts: pivot::facts(report),
+            durations: report.attack_results.iter().map(|r| r.duration).collect(),
+        }
+    }
+
+    fn table(&self) -> PivotTable {
+        pivot::pivot(
+            &self.facts,
+            self.row,
+            self.column,
+            self.measure,
+            &self.durations,
+        )
+    }
+
+    fn render(&self, stdout: &mut impl Write, accessible: bool) -> Result<()> {
+        execute!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        writeln!(stdout, "{}", "PIVOT EXPLORER".bold().cyan())?;
+        writeln!(
+            stdout,
+            "{}",
+            format!(
+                "Rows: {}. Columns: {}. Measure: {}.",
+                self.row.label(),
+                self.column.label(),
+                self.measure.label()
+            )
+            .dimmed()
+        )?;
+        writeln!(stdout)?;
+        for line in pivot_lines(&self.table(), accessible) {
+            writeln!(stdout, "{}", line)?;
+        }
+        writeln!(stdout)?;
+        writeln!(
+            stdout,
+            "{}",
+            "Controls: [r] Rows, [c] Columns, [m] Measure, [t] Transpose, [Esc] Back".dimmed()
+        )?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+/// The table as aligned text, or in accessible mode one sentence-like line per row.
+fn pivot_lines(table: &PivotTable, accessible: bool) -> Vec<String> {
+    if table.rows.is_empty() {
+        return vec!["No findings or attack runs to pivot".to_string()];
+    }
+    let columns = table.columns.len().min(PIVOT_COLUMNS);
+    let rows = table.rows.len().min(PIVOT_ROWS);
+    let cut = |text: &str, width: usize| {
+        if text.chars().count() <= width {
+            text.to_string()
+        } else {
+            let kept: String = text.chars().take(width.saturating_sub(3)).collect();
+            format!("{}...", kept)
+        }
+    };
+    let mut lines = Vec::new();
+    if accessible {
+        for (label, cells) in table.rows.iter().zip(&table.cells).take(rows) {
+            let cells: Vec<String> = table
+                .columns
+                .iter()
+                .zip(cells)
+                .take(columns)
+                .map(|(column, cell)| format!("{} {}", column, cell))
+                .collect();
+            lines.push(format!("{}: {}", label, cells.join(", ")));
+        }
+    } else {
+        let label_width = table
+            .rows
+            .iter()
+            .take(rows)
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(PIVOT_LABEL_WIDTH);
+        let widths: Vec<usize> = (0..columns)
+            .map(|c| {
+                table
+                    .cells
+                    .iter()
+                    .take(rows)
+                    .map(|cells| cells[c].chars().count())
+                    .chain([table.columns[c].chars().count()])
+                    .max()
+                    .unwrap_or(0)
+                    .min(PIVOT_CELL_WIDTH)
+            })
+            .collect();
+        let mut header = format!("{:label_width$}", "");
+        for (column, width) in table.columns.iter().zip(&widths) {
+            header.push_str(&format!("  {:>width$}", cut(column, *width)));
+        }
+        lines.push(header.trim_end().to_string());
+        for (label, cells) in table.rows.iter().zip(&table.cells).take(rows) {
+            let mut line = format!("{:label_width$}", cut(label, label_width));
+            for (cell, width) in cells.iter().zip(&widths) {
+                line.push_str(&format!("  {:>width$}", cut(cell, *width)));
+            }
+            lines.push(line.trim_end().to_string());
+        }
+    }
+    let (more_rows, more_columns) = (table.rows.len() - rows, table.columns.len() - columns);
+    if more_rows > 0 || more_columns > 0 {
+        lines.push(format!(
+            "({} more row(s), {} more column(s) not shown; transpose with [t] to see others)",
+            more_rows, more_columns
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn report() -> AssaultReport {
+        AssaultReport {
+            assail_report: AssailReport {
+                program_path: PathBuf::from("app"),
+                language: Language::Rust,
+                frameworks: Vec::new(),
+                weak_points: Vec::new(),
+                statistics: ProgramStatistics::default(),
+                file_statistics: Vec::new(),
+                recommended_attacks: Vec::new(),
+                dependency_graph: DependencyGraph::default(),
+                taint_matrix: TaintMatrix::default(),
+                migration_metrics: None,
+                annotations: Vec::new(),
+                policies: Vec::new(),
+                manifests: Vec::new(),
+            },
+            total_crashes: 0,
+            total_signatures: 0,
+            overall_assessment: OverallAssessment {
+                robustness_score: 42.0,
+                critical_issues: vec!["Program crashed under Memory attack (1 crashes)".into()],
+                recommendations: Vec::new(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_accessible_sections_are_ascii_and_lead_with_the_assessment() {
+        let report = report();
+        let formatter = ReportFormatter::new().with_accessible(true);
+        let sections =
+            ReportTui::build_sections(&report, &formatter, true, &TriageStore::default(), true);
+        assert_eq!(sections[0].title, "Assessment");
+        assert!(sections
+            .iter()
+            .all(|section| section.summary.is_ascii() && section.title.is_ascii()));
+
+        let mut screen = Vec::new();
+        ReportTui::render(&mut screen, &sections, 0, &[true], true).unwrap();
+        let screen = String::from_utf8(screen).unwrap();
+        assert!(screen.contains("> Section 1 of"));
+        assert!(screen.contains("Assessment, expanded. Score 42.0/100"));
+        assert!(screen.contains("Taint Matrix, collapsed."));
+
+        // The default layout keeps the assessment at the end.
+        let sections = ReportTui::build_sections(
+            &report,
+            &ReportFormatter::new(),
+            false,
+            &TriageStore::default(),
+            false,
+        );
+        assert_eq!(sections.last().unwrap().title, "Assessment");
+    }
+
+    #[test]
+    fn test_pivot_lines_align_cells_and_spell_rows_out_when_accessible() {
+        let table = PivotTable {
+            rows: vec!["memory".into(), "cpu".into()],
+            columns: vec!["CRITICAL".into(), "-".into()],
+            cells: vec![vec!["1".into(), "2".into()], vec!["12".into(), "-".into()]],
+        };
+        assert_eq!(
+            pivot_lines(&table, false),
+            [
+                "        CRITICAL  -",
+                "memory         1  2",
+                "cpu           12  -",
+            ]
+        );
+        assert_eq!(
+            pivot_lines(&table, true),
+            ["memory: CRITICAL 1, - 2", "cpu: CRITICAL 12, - -"]
+        );
+
+        let explorer = PivotExplorer::new(&report());
+        assert!(explorer.facts.is_empty());
+        assert_eq!(
+            pivot_lines(&explorer.table(), false),
+            ["No findings or attack runs to pivot"]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Dropping privileges for exec programs (`--run-as USER`)
+//!
+//! Amuck and abduct exec programs run mutated or abducted code. With
+//! `--run-as` the child switches to another account after fork and before
+//! exec: supplementary groups are cleared, then the gid and uid are set, so
+//! the code never runs with the operator's privileges. Switching to a
+//! different account needs root. Exec outcomes record the uid and gid the
+//! program ran with.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Account an exec program is switched to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunAs {
+    pub user: String,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+/// Effective uid/gid an exec program ran with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessIdentity {
+    /// Account given with `--run-as`; absent when the program ran as the operator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+impl RunAs {
+    /// Look up `user`, a user name or a numeric uid, and check that this process can
+    /// switch to it.
+    #[cfg(unix)]
+    pub fn resolve(user: &str) -> Result<RunAs> {
+        let (uid, gid) = match user.parse::<u32>() {
+            // A uid without an account keeps its own number as gid.
+            Ok(uid) => passwd_entry(Lookup::Uid(uid))?.unwrap_or((uid, uid)),
+            Err(_) => passwd_entry(Lookup::Name(user))?
+                .ok_or_else(|| anyhow!("--run-as: no such user {}", user))?,
+        };
+        // SAFETY: geteuid(2) takes no arguments and always succeeds.
+        let euid = unsafe { libc::geteuid() };
+        if euid != 0 && euid != uid {
+            return Err(anyhow!(
+                "--run-as {} needs root; panic-attack is running as uid {}",
+                user,
+                euid
+            ));
+        }
+        Ok(RunAs {
+            user: user.to_string(),
+            uid,
+            gid,
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn resolve(_user: &str) -> Result<RunAs> {
+        Err(anyhow!("--run-as is only supported on Unix"))
+    }
+
+    /// Make `command` switch to this account before it execs.
+    #[cfg(unix)]
+    pub fn apply(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+        // std clears supplementary groups when a uid is set.
+        command.gid(self.gid).uid(self.uid);
+    }
+
+    #[cfg(not(unix))]
+    pub fn apply(&self, _command: &mut Command) {}
+}
+
+/// Identity an exec program runs with: `run_as` when given, the operator otherwise.
+pub fn identity(run_as: Option<&RunAs>) -> Option<ProcessIdentity> {
+    match run_as {
+        Some(run_as) => Some(ProcessIdentity {
+            user: Some(run_as.user.clone()),
+            uid: run_as.uid,
+            gid: run_as.gid,
+        }),
+        None => operator_identity(),
+    }
+}
+
+#[cfg(unix)]
+fn operator_identity() -> Option<ProcessIdentity> {
+    // SAFETY: geteuid(2) and getegid(2) take no arguments and always succeed.
+    let (uid, gid) = unsafe { (libc::geteuid(), libc::getegid()) };
+    Some(ProcessIdentity {
+        user: None,
+        uid,
+        gid,
+    })
+}
+
+#[cfg(not(unix))]
+fn operator_identity() -> Option<ProcessIdentity> {
+    None
+}
+
+#[cfg(unix)]
+enum Lookup<'a> {
+    Name(&'a str),
+    Uid(u32),
+}
+
+/// uid and gid of a passwd entry, `None` when there is no such account.
+#[cfg(unix)]
+fn passwd_entry(lookup: Lookup) -> Result<Option<(u32, u32)>> {
+    // SAFETY: `passwd` is a plain C struct of integers and pointers; all zeroes is valid.
+    let mut entry: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut found: *mut libc::passwd = std::ptr::null_mut();
+    let mut buf = vec![0 as libc::c_char; 16 * 1024];
+    let rc = match lookup {
+        Lookup::Name(name) => {
+            let name = std::ffi::CString::new(name)
+                .map_err(|_| anyhow!("--run-as: invalid user name {:?}", name))?;
+            // SAFETY: `name` is NUL-terminated, and `entry`, `buf` and `found` outlive the
+            // call, which writes at most `buf.len()` bytes into `buf`.
+            unsafe {
+                libc::getpwnam_r(
+                    name.as_ptr(),
+                    &mut entry,
+                    buf.as_mut_ptr(),
+                    buf.len(),
+                    &mut found,
+                )
+            }
+        }
+        // SAFETY: as above, without the name.
+        Lookup::Uid(uid) => unsafe {
+            libc::getpwuid_r(uid, &mut entry, buf.as_mut_ptr(), buf.len(), &mut found)
+        },
+    };
+    if rc != 0 {
+        return Err(anyhow!(
+            "--run-as: looking up the account failed: {}",
+            std::io::Error::from_raw_os_error(rc)
+        ));
+    }
+    Ok((!found.is_null()).then_some((entry.pw_uid, entry.pw_gid)))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_accounts_and_identity() {
+        // SAFETY: geteuid(2) takes no arguments and always succeeds.
+        let euid = unsafe { libc::geteuid() };
+        let me = RunAs::resolve(&euid.to_string()).unwrap();
+        assert_eq!(me.uid, euid);
+        assert!(RunAs::resolve("no-such-user-panic-attack").is_err());
+        if euid != 0 {
+            assert!(RunAs::resolve("0").is_err());
+        }
+
+        let recorded = identity(Some(&me)).unwrap();
+        assert_eq!(recorded.user.as_deref(), Some(euid.to_string().as_str()));
+        assert_eq!(identity(None).unwrap().uid, euid);
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Seeded randomness so a campaign can be replayed exactly.
+//!
+//! Every run has one campaign seed: the global `--seed` flag, or a fresh value from the OS when
+//! the flag is absent. The seed is recorded in the report. Each consumer derives its own stream
+//! with [`SeededRng::derive`], keyed by a stable label. That keeps consumers independent: an
+//! extra draw in the signal storm never shifts the sequence amuck sees.
+//!
+//! The generator is SplitMix64. It is small and fast, and it is stable across versions and
+//! platforms, which is what replay needs. It is not meant for anything cryptographic.
+
+use std::time::Duration;
+
+/// Draw a campaign seed from the OS, falling back to the clock if that fails.
+pub fn fresh_seed() -> u64 {
+    let mut bytes = [0_u8; 8];
+    if getrandom::getrandom(&mut bytes).is_ok() {
+        return u64::from_le_bytes(bytes);
+    }
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Independent stream for one consumer of the campaign seed.
+    pub fn derive(seed: u64, label: &str) -> Self {
+        // FNV-1a over the label, mixed into the seed with one SplitMix64 round.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in label.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        let mut rng = Self::new(seed ^ hash);
+        rng.next_u64();
+        rng
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0, 1)`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// Uniform index in `0..bound` (0 when `bound` is 0).
+    pub fn below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            return 0;
+        }
+        (self.unit() * bound as f64) as usize
+    }
+
+    /// `base` scaled by a random factor in `[1 - fraction, 1 + fraction)`.
+    pub fn jitter(&mut self, base: Duration, fraction: f64) -> Duration {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let factor = 1.0 - fraction + 2.0 * fraction * self.unit();
+        base.mul_f64(factor)
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// `count` distinct indices from `0..len`, in ascending order.
+    pub fn sample_indices(&mut self, len: usize, count: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..len).collect();
+        self.shuffle(&mut indices);
+        indices.truncate(count);
+        indices.sort_unstable();
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = SeededRng::new(42);
+        let mut b = SeededRng::new(42);
+        let left: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let right: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(left, right);
+        // Pinned so the stream cannot drift between releases without a test change.
+        assert_eq!(SeededRng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_derived_streams_differ_by_label() {
+        let mut a = SeededRng::derive(7, "amuck");
+        let mut b = SeededRng::derive(7, "signal-storm");
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn test_jitter_and_sample_stay_in_bounds() {
+        let mut rng = SeededRng::new(3);
+        for _ in 0..100 {
+            let d = rng.jitter(Duration::from_millis(100), 0.2);
+            assert!(d >= Duration::from_millis(80) && d < Duration::from_millis(120));
+        }
+        let picked = rng.sample_indices(10, 4);
+        assert_eq!(picked.len(), 4);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+        assert!(picked.iter().all(|i| *i < 10));
+    }
+}
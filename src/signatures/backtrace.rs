@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Structured frames from captured backtraces
+//!
+//! Crash output keeps the backtrace as text; this module turns the common formats into
+//! [`StackFrame`]s, innermost first: Rust panics (`stack backtrace:`), Go panics
+//! (`goroutine N [running]:`), Python tracebacks, and native traces, meaning glibc
+//! `backtrace_symbols` lines (`./app(parse+0x1d)[0x55d0...]`) and the `#N` frames gdb and
+//! the sanitizers print. Frames feed crash bucketing, SARIF locations and the report views.
+
+use crate::types::StackFrame;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Frames kept per backtrace; deeper frames are start-up plumbing.
+const MAX_FRAMES: usize = 64;
+
+/// Prefixes of language runtime, panic machinery and libc frames, which say how a crash
+/// was reported rather than where it happened.
+const RUNTIME_PREFIXES: &[&str] = &[
+    "std::",
+    "core::",
+    "alloc::",
+    "__rust",
+    "<unknown>",
+    "runtime.",
+    "__GI_",
+    "__libc_",
+    "__pthread_kill",
+    "__sanitizer",
+    "__asan",
+    "__ubsan",
+];
+
+/// Runtime and libc functions matched by their whole name.
+const RUNTIME_FUNCTIONS: &[&str] = &[
+    "rust_begin_unwind",
+    "rust_panic",
+    "panic",
+    "raise",
+    "abort",
+    "gsignal",
+    "pthread_kill",
+    "_start",
+];
+
+static RE_GLIBC: OnceLock<Regex> = OnceLock::new();
+static RE_NUMBERED: OnceLock<Regex> = OnceLock::new();
+
+/// Frames of the first recognised backtrace in `text`; empty when there is none.
+pub fn parse(text: &str) -> Vec<StackFrame> {
+    let mut frames = [parse_python, parse_go, parse_rust, parse_native]
+        .iter()
+        .map(|parse| parse(text))
+        .find(|frames| !frames.is_empty())
+        .unwrap_or_default();
+    frames.truncate(MAX_FRAMES);
+    frames
+}
+
+/// The innermost frame outside the runtime, panic and libc machinery.
+pub fn top_frame(frames: &[StackFrame]) -> Option<&StackFrame> {
+    frames.iter().find(|frame| !is_runtime(frame))
+}
+
+fn is_runtime(frame: &StackFrame) -> bool {
+    RUNTIME_FUNCTIONS.contains(&frame.function.as_str())
+        || RUNTIME_PREFIXES
+            .iter()
+            .any(|prefix| frame.function.starts_with(prefix))
+}
+
+fn frame(function: &str, file: Option<&str>, line: Option<u32>) -> StackFrame {
+    StackFrame {
+        function: function.to_string(),
+        file: file.map(str::to_string),
+        line,
+        module: None,
+        offset: None,
+    }
+}
+
+/// `path:line` or `path:line:column`.
+pub(crate) fn split_location(location: &str) -> Option<(&str, u32)> {
+    let (rest, last) = location.rsplit_once(':')?;
+    let last: u32 = last.parse().ok()?;
+    match rest.rsplit_once(':') {
+        Some((file, line)) => match line.parse() {
+            Ok(line) => Some((file, line)),
+            Err(_) => Some((rest, last)),
+        },
+        None => Some((rest, last)),
+    }
+}
+
+/// `Traceback (most recent call last):` blocks list the outermost call first; the last block
+/// is the exception that escaped.
+fn parse_python(text: &str) -> Vec<StackFrame> {
+    let mut frames = Vec::new();
+    for line in text.lines() {
+        if line.starts_with("Traceback (most recent call last)") {
+            frames.clear();
+            continue;
+        }
+        let Some(rest) = line.trim_start().strip_prefix("File \"") else {
+            continue;
+        };
+        let Some((file, rest)) = rest.split_once("\", line ") else {
+            continue;
+        };
+        let (line_no, function) = rest.split_once(", in ").unwrap_or((rest, "<module>"));
+        frames.push(frame(
+            function.trim(),
+            Some(file),
+            line_no.trim().parse().ok(),
+        ));
+    }
+    frames.reverse();
+    frames
+}
+
+/// The panicking goroutine: `pkg.function(args)` lines each followed by a tab-indented
+/// `path:line +0xoffset`.
+fn parse_go(text: &str) -> Vec<StackFrame> {
+    let mut frames = Vec::new();
+    let mut lines = text
+        .lines()
+        .skip_while(|line| !(line.starts_with("goroutine ") && line.ends_with("]:")))
+        .skip(1);
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with('\t') {
+            continue;
+        }
+        let call = line.strip_prefix("created by ").unwrap_or(line);
+        let call = call.split(" in goroutine ").next().unwrap_or(call);
+        let function = match call.rfind('(') {
+            Some(open) if call.ends_with(')') => &call[..open],
+            _ => call,
+        };
+        let location = lines.next().and_then(|next| {
+            let location = next.strip_prefix('\t')?;
+            split_location(location.split(" +0x").next()?)
+        });
+        frames.push(frame(
+            function,
+            location.map(|(file, _)| file),
+            location.map(|(_, line)| line),
+        ));
+    }
+    frames
+}
+
+/// `N: function` lines after `stack backtrace:`, optionally `N: 0xaddr - function`, each
+/// optionally followed by `at path:line:column`. Symbol hashes are dropped.
+fn parse_rust(text: &str) -> Vec<StackFrame> {
+    let mut frames: Vec<StackFrame> = Vec::new();
+    let lines = text
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with("stack backtrace:"))
+        .skip(1);
+    for line in lines {
+        let line = line.trim();
+        if let Some(location) = line.strip_prefix("at ") {
+            if let (Some(last), Some((file, line))) = (frames.last_mut(), split_location(location))
+            {
+                if last.file.is_none() {
+                    last.file = Some(file.to_string());
+                    last.line = Some(line);
+                }
+            }
+            continue;
+        }
+        let Some((index, function)) = line.split_once(':') else {
+            break;
+        };
+        if index.parse::<u32>().is_err() {
+            break;
+        }
+        let function = function.trim();
+        let function = function
+            .split_once(" - ")
+            .map_or(function, |(_, name)| name);
+        let function = match function.rsplit_once("::h") {
+            Some((name, hash))
+                if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+            {
+                name
+            }
+            _ => function,
+        };
+        frames.push(frame(function, None, None));
+    }
+    frames
+}
+
+fn parse_native(text: &str) -> Vec<StackFrame> {
+    let glibc = RE_GLIBC.get_or_init(|| {
+        Regex::new(r"^\s*(\S*?)\(([^)+]*)(\+0x[0-9a-fA-F]+)?\)\s*\[0x[0-9a-fA-F]+\]\s*$").unwrap()
+    });
+    let numbered = RE_NUMBERED.get_or_init(|| {
+        Regex::new(
+            r"^\s*#\d+\s+(?:0x[0-9a-fA-F]+\s+)?(?:in\s+)?([^\s(]+)?\s*(?:\(([^)]*)\))?(?:\s+(?:at\s+)?(\S+?):(\d+)(?::\d+)?)?\s*$",
+        )
+        .unwrap()
+    });
+    let mut frames = Vec::new();
+    for line in text.lines() {
+        if let Some(caps) = glibc.captures(line) {
+            let module = caps[1].to_string();
+            let symbol = &caps[2];
+            if symbol.is_empty() {
+                // `app(+0x1234)`: no symbol, but the offset is into the module.
+                let offset = caps.get(3).and_then(|offset| hex(offset.as_str()));
+                frames.push(unresolved(module, offset));
+            } else {
+                let mut frame = frame(symbol, None, None);
+                frame.module = Some(module).filter(|module| !module.is_empty());
+                frames.push(frame);
+            }
+        } else if let Some(caps) = numbered.captures(line) {
+            // Sanitizers add `(module+0xoffset)`; gdb puts the arguments there.
+            let location = caps.get(2).and_then(|inner| {
+                let (module, offset) = inner.as_str().rsplit_once('+')?;
+                Some((module.to_string(), hex(offset)?))
+            });
+            match (caps.get(1), location) {
+                (Some(function), location) => {
+                    let mut frame = frame(
+                        function.as_str(),
+                        caps.get(3).map(|file| file.as_str()),
+                        caps.get(4).and_then(|line| line.as_str().parse().ok()),
+                    );
+                    if let Some((module, offset)) = location {
+                        frame.module = Some(module);
+                        frame.offset = Some(offset);
+                    }
+                    frames.push(frame);
+                }
+                (None, Some((module, offset))) => frames.push(unresolved(module, Some(offset))),
+                (None, None) => {}
+            }
+        }
+    }
+    frames
+}
+
+/// A frame known only by module and offset, named `module+0xoffset` until symbolized.
+fn unresolved(module: String, offset: Option<u64>) -> StackFrame {
+    let name = module.rsplit('/').next().unwrap_or_default();
+    let function = match offset {
+        Some(offset) => format!("{}+0x{:x}", name, offset),
+        None => name.to_string(),
+    };
+    StackFrame {
+        function,
+        file: None,
+        line: None,
+        module: Some(module),
+        offset,
+    }
+}
+
+fn hex(text: &str) -> Option<u64> {
+    let digits = text.trim_start_matches('+');
+    u64::from_str_radix(digits.strip_prefix("0x")?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(frames: &[StackFrame]) -> Vec<&str> {
+        frames.iter().map(|frame| frame.function.as_str()).collect()
+    }
+
+    #[test]
+    fn test_rust_backtrace() {
+        let text = "thread 'main' panicked at src/main.rs:10:5:\nboom\nstack backtrace:\n   \
+                    0: rust_begin_unwind\n             at /rustc/abc/library/std/src/panicking.rs:645:5\n   \
+                    1: core::panicking::panic_fmt\n   \
+                    2:     0x55d0c0ffee10 - app::parse::h0123456789abcdef\n             at ./src/parse.rs:42:9\n   \
+                    3: app::main\n             at ./src/main.rs:10:5\n\
+                    note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.\n";
+        let frames = parse(text);
+        assert_eq!(
+            names(&frames),
+            vec![
+                "rust_begin_unwind",
+                "core::panicking::panic_fmt",
+                "app::parse",
+                "app::main"
+            ]
+        );
+        let top = top_frame(&frames).unwrap();
+        assert_eq!(top.function, "app::parse");
+        assert_eq!(top.file.as_deref(), Some("./src/parse.rs"));
+        assert_eq!(top.line, Some(42));
+    }
+
+    #[test]
+    fn test_go_panic() {
+        let text = "panic: runtime error: index out of range [3] with length 3\n\n\
+                    goroutine 1 [running]:\n\
+                    main.(*Parser).next(...)\n\t/home/u/app/parser.go:17 +0x1d\n\
+                    main.main()\n\t/home/u/app/main.go:9 +0x25\n\n\
+                    goroutine 6 [chan receive]:\nmain.worker()\n\t/home/u/app/worker.go:3 +0x10\n\
+                    exit status 2\n";
+        let frames = parse(text);
+        assert_eq!(names(&frames), vec!["main.(*Parser).next", "main.main"]);
+        assert_eq!(frames[0].file.as_deref(), Some("/home/u/app/parser.go"));
+        assert_eq!(frames[0].line, Some(17));
+    }
+
+    #[test]
+    fn test_python_traceback_innermost_first() {
+        let text = "Traceback (most recent call last):\n  \
+                    File \"/app/main.py\", line 12, in <module>\n    main()\n  \
+                    File \"/app/main.py\", line 8, in main\n    parse(data)\n  \
+                    File \"/app/parse.py\", line 3, in parse\n    return int(data)\n\
+                    ValueError: invalid literal for int() with base 10: 'x'\n";
+        let frames = parse(text);
+        assert_eq!(names(&frames), vec!["parse", "main", "<module>"]);
+        assert_eq!(frames[0].file.as_deref(), Some("/app/parse.py"));
+        assert_eq!(frames[0].line, Some(3));
+    }
+
+    #[test]
+    fn test_glibc_and_sanitizer_frames() {
+        let glibc = "Obtained 4 stack frames.\n\
+                     ./app(handle_request+0x1d)[0x55d0c0ffee10]\n\
+                     ./app(+0x1234)[0x55d0c0ff1234]\n\
+                     /lib/x86_64-linux-gnu/libc.so.6(__libc_start_main+0xf3)[0x7f3d8b0c9d8d]\n";
+        let frames = parse(glibc);
+        assert_eq!(
+            names(&frames),
+            vec!["handle_request", "app+0x1234", "__libc_start_main"]
+        );
+        assert_eq!(frames[1].module.as_deref(), Some("./app"));
+        assert_eq!(frames[1].offset, Some(0x1234));
+        assert_eq!(
+            frames[0].offset, None,
+            "offset from a symbol, not the module"
+        );
+
+        let asan = "==1==ERROR: AddressSanitizer: heap-use-after-free\n    \
+                    #0 0x4f5a10 in __asan_memcpy (/app+0x4f5a10)\n    \
+                    #1 0x55d0c0 in copy_name /src/app/name.c:27:5\n    \
+                    #2 0x7f3d8b in __libc_start_main (/lib/libc.so.6+0x29d8f)\n    \
+                    #3 0x55d0c1  (/usr/bin/app+0x2a1f)\n";
+        let frames = parse(asan);
+        assert_eq!(
+            names(&frames),
+            vec![
+                "__asan_memcpy",
+                "copy_name",
+                "__libc_start_main",
+                "app+0x2a1f"
+            ]
+        );
+        assert_eq!(frames[2].module.as_deref(), Some("/lib/libc.so.6"));
+        assert_eq!(frames[3].offset, Some(0x2a1f));
+        let top = top_frame(&frames).unwrap();
+        assert_eq!(top.function, "copy_name");
+        assert_eq!(top.file.as_deref(), Some("/src/app/name.c"));
+        assert_eq!(top.line, Some(27));
+
+        let gdb = "#0  0x00007f in raise (sig=6) at ../sysdeps/unix/sysv/linux/raise.c:50\n\
+                   #1  main () at app.c:5\n";
+        assert_eq!(names(&parse(gdb)), vec!["raise", "main"]);
+        assert!(parse("all good\n").is_empty());
+    }
+}
@@ -10,9 +10,12 @@
 //! The rule evaluation is real — rule body predicates are matched against
 //! extracted facts using variable unification, not hardcoded dispatch.
 
+use crate::signatures::feedback::{RuleOutcomes, SignatureFeedback};
+use crate::signatures::postmortem;
 use crate::signatures::rules::RuleSet;
 use crate::types::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Variable bindings accumulated during rule body evaluation.
 /// Variable names (like "X", "M1") are bound to concrete values from facts.
@@ -27,12 +30,49 @@ enum BoundValue {
 
 pub struct SignatureEngine {
     rules: RuleSet,
+    /// Labeled outcomes per rule and the feedback file they came from.
+    feedback: Option<(BTreeMap<String, RuleOutcomes>, PathBuf)>,
 }
 
 impl SignatureEngine {
     pub fn new() -> Self {
         Self {
             rules: RuleSet::default(),
+            feedback: None,
+        }
+    }
+
+    /// An engine whose confidences are calibrated by the labels in `feedback`, read from
+    /// `source`.
+    pub fn with_feedback(feedback: &SignatureFeedback, source: &Path) -> Self {
+        Self {
+            feedback: Some((feedback.outcomes(), source.to_path_buf())),
+            ..Self::new()
+        }
+    }
+
+    /// Replace each signature's confidence with the posterior for its rule, recording the
+    /// prior and label counts. Also applies to signatures detected outside the engine (hangs,
+    /// stalls); already calibrated signatures are left alone.
+    pub fn calibrate(&self, signatures: &mut [BugSignature]) {
+        let Some((outcomes, source)) = &self.feedback else {
+            return;
+        };
+        for signature in signatures
+            .iter_mut()
+            .filter(|signature| signature.calibration.is_none())
+        {
+            let Some(rule) = signature.rule.as_ref().and_then(|rule| outcomes.get(rule)) else {
+                continue;
+            };
+            let prior = signature.confidence;
+            signature.confidence = rule.posterior(prior);
+            signature.calibration = Some(SignatureCalibration {
+                prior,
+                true_positives: rule.true_positives,
+                false_positives: rule.false_positives,
+                feedback: source.clone(),
+            });
         }
     }
 
@@ -61,6 +101,7 @@ impl SignatureEngine {
         // that the fact extraction might miss (e.g., sanitizer output).
         signatures.extend(self.match_stderr_patterns(crash));
 
+        self.calibrate(&mut signatures);
         signatures
     }
 
@@ -402,6 +443,8 @@ impl SignatureEngine {
             confidence,
             evidence,
             location,
+            rule: Some(rule.name.clone()),
+            calibration: None,
         }
     }
 
@@ -413,6 +456,7 @@ impl SignatureEngine {
     /// - Thread spawn patterns for race conditions
     /// - Read/write patterns for data races
     /// - Signal information for null derefs and overflows
+    /// - Core-file post-mortem lock state (mutexes in a wait-for cycle)
     fn extract_facts(&self, crash: &CrashReport) -> HashSet<Fact> {
         let mut facts = HashSet::new();
         let stderr = &crash.stderr;
@@ -508,6 +552,19 @@ impl SignatureEngine {
             });
         }
 
+        // Post-mortem facts name the real mutexes and threads from the core file.
+        if let Some(postmortem) = &crash.postmortem {
+            for mutex in postmortem::lock_cycle(postmortem) {
+                facts.insert(Fact::Lock { mutex, location: 0 });
+            }
+            for thread in postmortem.threads.iter().skip(1) {
+                facts.insert(Fact::ThreadSpawn {
+                    id: format!("lwp{}", thread.lwp),
+                    location: 0,
+                });
+            }
+        }
+
         facts
     }
 
@@ -529,6 +586,8 @@ impl SignatureEngine {
                 confidence: 0.95,
                 evidence: vec!["Direct use-after-free mention in error output".to_string()],
                 location: None,
+                rule: Some("stderr_use_after_free".to_string()),
+                calibration: None,
             });
         }
 
@@ -542,6 +601,8 @@ impl SignatureEngine {
                 confidence: 0.95,
                 evidence: vec!["Direct double-free mention in error output".to_string()],
                 location: None,
+                rule: Some("stderr_double_free".to_string()),
+                calibration: None,
             });
         }
 
@@ -555,6 +616,8 @@ impl SignatureEngine {
                 confidence: 0.90,
                 evidence: vec!["Deadlock pattern in error output".to_string()],
                 location: None,
+                rule: Some("stderr_deadlock".to_string()),
+                calibration: None,
             });
         }
 
@@ -568,6 +631,8 @@ impl SignatureEngine {
                 confidence: 0.95,
                 evidence: vec!["Race condition detected by sanitizer or error output".to_string()],
                 location: None,
+                rule: Some("stderr_data_race".to_string()),
+                calibration: None,
             });
         }
 
@@ -583,6 +648,8 @@ impl SignatureEngine {
                 confidence: 0.90,
                 evidence: vec!["SIGSEGV or null pointer pattern in error output".to_string()],
                 location: None,
+                rule: Some("stderr_null_deref".to_string()),
+                calibration: None,
             });
         }
 
@@ -597,6 +664,8 @@ impl SignatureEngine {
                 confidence: 0.95,
                 evidence: vec!["Buffer overflow pattern in error output".to_string()],
                 location: None,
+                rule: Some("stderr_buffer_overflow".to_string()),
+                calibration: None,
             });
         }
 
@@ -610,6 +679,8 @@ impl SignatureEngine {
                 confidence: 0.85,
                 evidence: vec!["Memory leak pattern in error output".to_string()],
                 location: None,
+                rule: Some("stderr_memory_leak".to_string()),
+                calibration: None,
             });
         }
 
@@ -623,6 +694,8 @@ impl SignatureEngine {
                 confidence: 0.90,
                 evidence: vec!["Integer overflow pattern in error output".to_string()],
                 location: None,
+                rule: Some("stderr_integer_overflow".to_string()),
+                calibration: None,
             });
         }
 
@@ -644,9 +717,8 @@ mod tests {
         CrashReport {
             timestamp: "2026-02-28T00:00:00Z".to_string(),
             signal: signal.map(|s| s.to_string()),
-            backtrace: None,
             stderr: stderr.to_string(),
-            stdout: String::new(),
+            ..Default::default()
         }
     }
 
@@ -698,6 +770,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deadlock_from_postmortem_lock_cycle() {
+        let engine = SignatureEngine::new();
+        let mut crash = make_crash("", Some("SIGABRT"));
+        let thread = |lwp: u64, waiting_on: &str| PostmortemThread {
+            lwp,
+            name: None,
+            frames: vec!["pthread_mutex_lock".to_string()],
+            waiting_on: Some(waiting_on.to_string()),
+        };
+        let mutex = |address: &str, owner: u64| MutexState {
+            address: address.to_string(),
+            owner: Some(owner),
+            waiters: Vec::new(),
+        };
+        crash.postmortem = Some(Postmortem {
+            core_file: std::path::PathBuf::from("core"),
+            threads: vec![thread(7, "0xa0"), thread(8, "0xb0")],
+            mutexes: vec![mutex("0xa0", 8), mutex("0xb0", 7)],
+            heap: None,
+        });
+        let deadlock = engine
+            .detect_from_crash(&crash)
+            .into_iter()
+            .find(|s| s.signature_type == SignatureType::Deadlock)
+            .expect("lock cycle should be a deadlock");
+        assert!(deadlock.evidence.iter().any(|e| e.ends_with("0xa0")));
+    }
+
     #[test]
     fn test_data_race_from_rule_evaluation() {
         let engine = SignatureEngine::new();
@@ -756,4 +857,31 @@ mod tests {
             "Clean crash report should produce no signatures"
         );
     }
+
+    #[test]
+    fn test_feedback_calibrates_rule_confidence() {
+        use crate::signatures::feedback::Verdict;
+
+        let mut feedback = SignatureFeedback::default();
+        for _ in 0..10 {
+            feedback.mark("stderr_deadlock", Verdict::FalsePositive, None, "now");
+        }
+        let engine = SignatureEngine::with_feedback(&feedback, Path::new("fb.json"));
+        let crash = make_crash("deadlock detected", None);
+        let sigs = engine.detect_from_crash(&crash);
+        let sig = sigs
+            .iter()
+            .find(|s| s.rule.as_deref() == Some("stderr_deadlock"))
+            .expect("stderr deadlock signature");
+        // (0.9 * 10 + 0) / (10 + 10)
+        assert!((sig.confidence - 0.45).abs() < 1e-9);
+        let calibration = sig.calibration.as_ref().expect("provenance recorded");
+        assert_eq!(calibration.prior, 0.90);
+        assert_eq!(calibration.false_positives, 10);
+        assert_eq!(calibration.feedback, PathBuf::from("fb.json"));
+
+        let mut again = sigs.clone();
+        engine.calibrate(&mut again);
+        assert_eq!(again[0].confidence, sigs[0].confidence, "calibrated once");
+    }
 }
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Signature feedback: per-project true/false positive labels for detection rules
+//!
+//! Every signature names the rule that produced it. Labels recorded with
+//! `panic-attack signatures mark RULE true-positive|false-positive` are
+//! counted per rule, and the engine turns each rule's built-in confidence into
+//! a posterior: the confidence is treated as a Beta prior worth
+//! [`PRIOR_STRENGTH`] observations and updated with the labeled outcomes.
+
+use crate::storage::write_atomic;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Default feedback file, in the directory panic-attack runs from.
+pub const DEFAULT_FEEDBACK_FILE: &str = ".panic-attack-signature-feedback.json";
+
+/// Pseudo-observations the built-in confidence is worth; ten labels carry as much weight as
+/// the rule author's guess.
+pub const PRIOR_STRENGTH: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    TruePositive,
+    FalsePositive,
+}
+
+/// One user judgement of a detected signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureLabel {
+    pub rule: String,
+    pub verdict: Verdict,
+    pub labeled_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Labeled outcomes of one rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleOutcomes {
+    pub true_positives: u32,
+    pub false_positives: u32,
+}
+
+impl RuleOutcomes {
+    /// Posterior mean of the rule's precision given its built-in confidence as the prior.
+    pub fn posterior(&self, prior: f64) -> f64 {
+        let prior = prior.clamp(0.0, 1.0);
+        let labeled = f64::from(self.true_positives + self.false_positives);
+        (prior * PRIOR_STRENGTH + f64::from(self.true_positives)) / (PRIOR_STRENGTH + labeled)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignatureFeedback {
+    #[serde(default)]
+    pub labels: Vec<SignatureLabel>,
+}
+
+impl SignatureFeedback {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(DEFAULT_FEEDBACK_FILE)
+    }
+
+    /// Load a feedback file, starting empty when it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("reading signature feedback {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("parsing signature feedback {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write_atomic(path, json)
+            .with_context(|| format!("writing signature feedback {}", path.display()))
+    }
+
+    pub fn mark(&mut self, rule: &str, verdict: Verdict, note: Option<String>, now: &str) {
+        self.labels.push(SignatureLabel {
+            rule: rule.to_string(),
+            verdict,
+            labeled_at: now.to_string(),
+            note,
+        });
+    }
+
+    /// Label counts per rule.
+    pub fn outcomes(&self) -> BTreeMap<String, RuleOutcomes> {
+        let mut outcomes: BTreeMap<String, RuleOutcomes> = BTreeMap::new();
+        for label in &self.labels {
+            let entry = outcomes.entry(label.rule.clone()).or_default();
+            match label.verdict {
+                Verdict::TruePositive => entry.true_positives += 1,
+                Verdict::FalsePositive => entry.false_positives += 1,
+            }
+        }
+        outcomes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcomes_update_the_prior() {
+        let mut feedback = SignatureFeedback::default();
+        for _ in 0..5 {
+            feedback.mark("deadlock", Verdict::FalsePositive, None, "now");
+        }
+        feedback.mark(
+            "deadlock",
+            Verdict::TruePositive,
+            Some("real".into()),
+            "now",
+        );
+        feedback.mark("stderr_double_free", Verdict::TruePositive, None, "now");
+
+        let outcomes = feedback.outcomes();
+        let deadlock = outcomes["deadlock"];
+        assert_eq!(deadlock.true_positives, 1);
+        assert_eq!(deadlock.false_positives, 5);
+        // (0.7 * 10 + 1) / (10 + 6)
+        assert!((deadlock.posterior(0.7) - 0.5).abs() < 1e-9);
+        assert!(outcomes["stderr_double_free"].posterior(0.95) > 0.95);
+        assert_eq!(RuleOutcomes::default().posterior(0.8), 0.8);
+    }
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Leak signatures from the target's resource time series
+//!
+//! A target that survives an attack can still be degrading: RSS, open file
+//! descriptors or threads climbing steadily for the whole run. Each metric's
+//! series is fitted with a least-squares line; a signature is reported when
+//! the series rises almost monotonically, the line explains it well (low
+//! variance around the trend) and the total growth is large enough to matter.
+
+use crate::types::{BugSignature, SignatureType};
+use std::time::Duration;
+
+/// Fewer samples than this say nothing about a trend.
+const MIN_SAMPLES: usize = 8;
+
+/// Series shorter than this are start-up noise.
+const MIN_SPAN: Duration = Duration::from_secs(2);
+
+/// Share of consecutive steps that must not go down.
+const MIN_MONOTONIC: f64 = 0.9;
+
+/// Coefficient of determination the linear fit must reach.
+const MIN_R_SQUARED: f64 = 0.9;
+
+/// One observation of the target process; `None` when the metric could not be read.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourcePoint {
+    pub elapsed: Duration,
+    pub rss: Option<u64>,
+    pub fds: Option<u64>,
+    pub threads: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metric {
+    rule: &'static str,
+    signature_type: SignatureType,
+    label: &'static str,
+    read: fn(&ResourcePoint) -> Option<u64>,
+    /// Growth below this many units is ignored however clean the trend.
+    min_growth: u64,
+    format: fn(f64) -> String,
+}
+
+const METRICS: &[Metric] = &[
+    Metric {
+        rule: "leak_rss",
+        signature_type: SignatureType::MemoryGrowthSuspected,
+        label: "RSS",
+        read: |point| point.rss,
+        min_growth: 8 * 1024 * 1024,
+        format: |bytes| format!("{:.1} MiB", bytes / (1024.0 * 1024.0)),
+    },
+    Metric {
+        rule: "leak_fds",
+        signature_type: SignatureType::FdLeakSuspected,
+        label: "Open file descriptors",
+        read: |point| point.fds,
+        min_growth: 16,
+        format: |count| format!("{:.0}", count),
+    },
+    Metric {
+        rule: "leak_threads",
+        signature_type: SignatureType::ResourceLeakSuspected,
+        label: "Threads",
+        read: |point| point.threads,
+        min_growth: 8,
+        format: |count| format!("{:.0}", count),
+    },
+];
+
+/// Leak signatures for every metric that grows steadily over `points`.
+pub fn detect(points: &[ResourcePoint]) -> Vec<BugSignature> {
+    METRICS
+        .iter()
+        .filter_map(|metric| growth_signature(metric, points))
+        .collect()
+}
+
+fn growth_signature(metric: &Metric, points: &[ResourcePoint]) -> Option<BugSignature> {
+    let series: Vec<(f64, f64)> = points
+        .iter()
+        .filter_map(|point| Some((point.elapsed.as_secs_f64(), (metric.read)(point)? as f64)))
+        .collect();
+    let (first, last) = (series.first()?, series.last()?);
+    let span = last.0 - first.0;
+    if series.len() < MIN_SAMPLES || span < MIN_SPAN.as_secs_f64() {
+        return None;
+    }
+    let growth = last.1 - first.1;
+    if growth < metric.min_growth as f64 {
+        return None;
+    }
+    let steps = series.len() - 1;
+    let rising = series
+        .windows(2)
+        .filter(|pair| pair[1].1 >= pair[0].1)
+        .count();
+    let monotonic = rising as f64 / steps as f64;
+    let (slope, r_squared) = fit(&series)?;
+    if monotonic < MIN_MONOTONIC || r_squared < MIN_R_SQUARED || slope <= 0.0 {
+        return None;
+    }
+
+    Some(BugSignature {
+        signature_type: metric.signature_type,
+        confidence: (0.4 + 0.5 * r_squared * monotonic).min(0.9),
+        evidence: vec![
+            format!(
+                "{} grew from {} to {} over {:.1}s ({}/s)",
+                metric.label,
+                (metric.format)(first.1),
+                (metric.format)(last.1),
+                span,
+                (metric.format)(slope)
+            ),
+            format!(
+                "Linear fit R² {:.2}; {:.0}% of {} samples non-decreasing",
+                r_squared,
+                monotonic * 100.0,
+                series.len()
+            ),
+        ],
+        location: None,
+        rule: Some(metric.rule.to_string()),
+        calibration: None,
+    })
+}
+
+/// Least-squares slope and R² of `series`; `None` for a flat or degenerate series.
+fn fit(series: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let n = series.len() as f64;
+    let mean_x = series.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = series.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for (x, y) in series {
+        sxx += (x - mean_x) * (x - mean_x);
+        sxy += (x - mean_x) * (y - mean_y);
+        syy += (y - mean_y) * (y - mean_y);
+    }
+    if sxx == 0.0 || syy == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    Some((slope, sxy * sxy / (sxx * syy)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(values: impl Fn(u64) -> (u64, u64, u64)) -> Vec<ResourcePoint> {
+        (0..20)
+            .map(|i| {
+                let (rss, fds, threads) = values(i);
+                ResourcePoint {
+                    elapsed: Duration::from_millis(250 * i),
+                    rss: Some(rss),
+                    fds: Some(fds),
+                    threads: Some(threads),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_steady_growth_is_flagged() {
+        const MIB: u64 = 1024 * 1024;
+        // RSS climbs 2 MiB per sample with a small wobble; fds leak one per sample.
+        let points = series(|i| (50 * MIB + i * 2 * MIB + (i % 3) * 64 * 1024, 10 + i, 4));
+        let signatures = detect(&points);
+        let types: Vec<SignatureType> = signatures.iter().map(|s| s.signature_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                SignatureType::MemoryGrowthSuspected,
+                SignatureType::FdLeakSuspected
+            ]
+        );
+        assert_eq!(signatures[0].rule.as_deref(), Some("leak_rss"));
+        assert!(signatures[0].evidence[0].starts_with("RSS grew from 50.0 MiB to 88.1 MiB"));
+        assert!(signatures[0].confidence > 0.8);
+    }
+
+    #[test]
+    fn test_noise_plateaus_and_short_runs_are_ignored() {
+        const MIB: u64 = 1024 * 1024;
+        // Sawtooth RSS (allocate, free) and a one-off jump in threads are not leaks.
+        let points = series(|i| {
+            (
+                50 * MIB + (i % 4) * 10 * MIB,
+                10,
+                if i < 10 { 4 } else { 40 },
+            )
+        });
+        assert!(detect(&points).is_empty());
+
+        let short = series(|i| (50 * MIB + i * 2 * MIB, 10 + i * 4, 4));
+        assert!(detect(&short[..6]).is_empty(), "too few samples");
+        assert!(detect(&[]).is_empty());
+    }
+}
@@ -4,8 +4,13 @@
 //!
 //! Inspired by Mozart/Oz logic programming and Datalog inference
 
+pub mod backtrace;
 pub mod engine;
+pub mod feedback;
+pub mod leak;
+pub mod postmortem;
 pub mod rules;
+pub mod symbolize;
 
 use crate::types::*;
 
@@ -0,0 +1,105 @@
+# SPDX-License-Identifier: PMPL-1.0-or-later
+#
+# Post-mortem extraction, run by panic-attack as
+#   gdb --batch -nx -q -x postmortem.py PROGRAM CORE
+# Prints a single line "PANIC_ATTACK_POSTMORTEM <json>" on stdout. Everything is best
+# effort: without libc debug info, mutex owners and heap statistics are left out.
+
+import json
+
+import gdb
+
+MARKER = "PANIC_ATTACK_POSTMORTEM "
+MAX_FRAMES = 32
+LOCK_FRAMES = (
+    "pthread_mutex_lock",
+    "__pthread_mutex_lock",
+    "___pthread_mutex_lock",
+    "__GI___pthread_mutex_lock",
+    "__pthread_mutex_lock_full",
+    "pthread_mutex_timedlock",
+    "__pthread_mutex_clocklock_common",
+)
+
+
+def frames_of(thread):
+    thread.switch()
+    frames = []
+    frame = gdb.newest_frame()
+    while frame is not None and len(frames) < MAX_FRAMES:
+        frames.append(frame)
+        try:
+            frame = frame.older()
+        except gdb.error:
+            break
+    return frames
+
+
+def frame_name(frame):
+    return frame.name() or "0x%x" % frame.pc()
+
+
+def blocked_mutex(frames):
+    """(address, owner lwp) of the mutex a thread is blocked on, if it is in a lock call."""
+    for frame in frames:
+        if frame.name() not in LOCK_FRAMES:
+            continue
+        try:
+            mutex = frame.read_var("mutex")
+            address = "0x%x" % int(mutex)
+        except (gdb.error, ValueError):
+            return None, None
+        try:
+            owner = int(mutex.dereference()["__data"]["__owner"])
+        except gdb.error:
+            owner = 0
+        return address, (owner or None)
+    return None, None
+
+
+def heap_stats():
+    try:
+        arena = gdb.parse_and_eval("main_arena")
+        return {
+            "system_bytes": int(arena["system_mem"]),
+            "max_system_bytes": int(arena["max_system_mem"]),
+        }
+    except gdb.error:
+        return None
+
+
+def main():
+    threads = []
+    mutexes = {}
+    inferior = gdb.selected_inferior()
+    for thread in sorted(inferior.threads(), key=lambda t: t.num):
+        lwp = thread.ptid[1] or thread.ptid[0]
+        frames = frames_of(thread)
+        address, owner = blocked_mutex(frames)
+        threads.append(
+            {
+                "lwp": lwp,
+                "name": thread.name,
+                "frames": [frame_name(frame) for frame in frames],
+                "waiting_on": address,
+            }
+        )
+        if address is not None:
+            state = mutexes.setdefault(address, {"address": address, "owner": None, "waiters": []})
+            state["waiters"].append(lwp)
+            if owner is not None:
+                state["owner"] = owner
+
+    print(
+        MARKER
+        + json.dumps(
+            {
+                "threads": threads,
+                "mutexes": sorted(mutexes.values(), key=lambda m: m["address"]),
+                "heap": heap_stats(),
+            }
+        )
+    )
+
+
+main()
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Core-file post-mortem via GDB
+//!
+//! When a crashing target leaves a core file, the bundled `postmortem.py` is run under
+//! `gdb --batch` to list threads with their stacks, the mutexes threads were blocked on (and
+//! who held them) and glibc heap size. The result is attached to the crash as a
+//! [`Postmortem`] so signature rules see real lock state instead of stderr keywords.
+//!
+//! Core files are looked for in the working directory (`core`, `core.<pid>`) and, when the
+//! kernel pipes cores to systemd-coredump, fetched with `coredumpctl`. Nothing happens unless
+//! core dumps are enabled for the target (e.g. `ulimit -c unlimited`).
+
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::SystemTime;
+
+const SCRIPT: &str = include_str!("postmortem.py");
+const MARKER: &str = "PANIC_ATTACK_POSTMORTEM ";
+
+/// Attach a post-mortem to `crash` when the run left a core file and gdb is installed.
+/// Failures are reported and otherwise ignored; the crash is still recorded.
+pub fn attach(
+    crash: &mut CrashReport,
+    output: &Output,
+    program: &Path,
+    pid: Option<u32>,
+    since: SystemTime,
+) {
+    if !core_dumped(output) || !gdb_available() {
+        return;
+    }
+    let Some(core) = find_core(Path::new("."), program, pid, since) else {
+        return;
+    };
+    match analyze_core(program, &core) {
+        Ok(postmortem) => crash.postmortem = Some(postmortem),
+        Err(err) => tracing::warn!("post-mortem of {} failed: {:#}", core.display(), err),
+    }
+}
+
+/// Run the bundled GDB script against `core`.
+pub fn analyze_core(program: &Path, core: &Path) -> Result<Postmortem> {
+    let script =
+        std::env::temp_dir().join(format!("panic-attack-postmortem-{}.py", std::process::id()));
+    std::fs::write(&script, SCRIPT).with_context(|| format!("writing {}", script.display()))?;
+    let output = Command::new("gdb")
+        .args(["--batch", "-nx", "-q", "-x"])
+        .arg(&script)
+        .arg(program)
+        .arg(core)
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run gdb");
+    let _ = std::fs::remove_file(&script);
+    let mut postmortem = parse_output(&String::from_utf8_lossy(&output?.stdout))?;
+    postmortem.core_file = core.to_path_buf();
+    Ok(postmortem)
+}
+
+fn parse_output(stdout: &str) -> Result<Postmortem> {
+    let line = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(MARKER))
+        .ok_or_else(|| anyhow!("gdb script produced no post-mortem"))?;
+    serde_json::from_str(line).context("parsing gdb post-mortem")
+}
+
+/// Mutexes in a wait-for cycle: each is held by a thread blocked on the next one.
+pub fn lock_cycle(postmortem: &Postmortem) -> Vec<String> {
+    let waiting_on = |lwp: u64| {
+        postmortem
+            .threads
+            .iter()
+            .find(|thread| thread.lwp == lwp)
+            .and_then(|thread| thread.waiting_on.clone())
+    };
+    let owner_of = |address: &str| {
+        postmortem
+            .mutexes
+            .iter()
+            .find(|mutex| mutex.address == address)
+            .and_then(|mutex| mutex.owner)
+    };
+
+    for start in &postmortem.mutexes {
+        let mut path = vec![start.address.clone()];
+        let mut current = start.address.clone();
+        while let Some(next) = owner_of(&current).and_then(waiting_on) {
+            if next == start.address {
+                return path;
+            }
+            if path.contains(&next) {
+                break;
+            }
+            path.push(next.clone());
+            current = next;
+        }
+    }
+    Vec::new()
+}
+
+fn core_dumped(output: &Output) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        output.status.core_dumped()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = output;
+        false
+    }
+}
+
+fn gdb_available() -> bool {
+    Command::new("gdb")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Core file written for this run: in `dir` under the kernel's plain naming, or from
+/// systemd-coredump when the core pattern is a pipe.
+fn find_core(dir: &Path, program: &Path, pid: Option<u32>, since: SystemTime) -> Option<PathBuf> {
+    let pattern = std::fs::read_to_string("/proc/sys/kernel/core_pattern").unwrap_or_default();
+    if pattern.trim_start().starts_with('|') {
+        return coredumpctl_dump(program, pid, since);
+    }
+
+    let mut names = vec!["core".to_string()];
+    if let Some(pid) = pid {
+        names.insert(0, format!("core.{}", pid));
+    }
+    names.into_iter().map(|name| dir.join(name)).find(|path| {
+        path.metadata()
+            .and_then(|meta| meta.modified())
+            .is_ok_and(|modified| modified >= since)
+    })
+}
+
+fn coredumpctl_dump(program: &Path, pid: Option<u32>, since: SystemTime) -> Option<PathBuf> {
+    let epoch = since.duration_since(SystemTime::UNIX_EPOCH).ok()?.as_secs();
+    let target = pid.map_or_else(
+        || std::path::absolute(program).unwrap_or_else(|_| program.to_path_buf()),
+        |pid| PathBuf::from(pid.to_string()),
+    );
+    let core = std::env::temp_dir().join(format!(
+        "panic-attack-core-{}-{}",
+        epoch,
+        std::process::id()
+    ));
+    let status = Command::new("coredumpctl")
+        .arg("--no-pager")
+        .arg(format!("--since=@{}", epoch))
+        .arg("--output")
+        .arg(&core)
+        .arg("dump")
+        .arg(target)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .ok()?;
+    (status.success() && core.is_file()).then_some(core)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread(lwp: u64, waiting_on: Option<&str>) -> PostmortemThread {
+        PostmortemThread {
+            lwp,
+            name: None,
+            frames: vec![
+                "__lll_lock_wait".to_string(),
+                "pthread_mutex_lock".to_string(),
+            ],
+            waiting_on: waiting_on.map(str::to_string),
+        }
+    }
+
+    fn mutex(address: &str, owner: u64, waiter: u64) -> MutexState {
+        MutexState {
+            address: address.to_string(),
+            owner: Some(owner),
+            waiters: vec![waiter],
+        }
+    }
+
+    #[test]
+    fn test_parse_gdb_output() {
+        let stdout = "[New LWP 12]\nCore was generated by `./app'.\n\
+            PANIC_ATTACK_POSTMORTEM {\"threads\": [{\"lwp\": 12, \"name\": \"app\", \
+            \"frames\": [\"main\"], \"waiting_on\": null}], \"mutexes\": [], \
+            \"heap\": {\"system_bytes\": 135168, \"max_system_bytes\": 135168}}\n";
+        let postmortem = parse_output(stdout).unwrap();
+        assert_eq!(postmortem.threads.len(), 1);
+        assert_eq!(postmortem.threads[0].name.as_deref(), Some("app"));
+        assert_eq!(postmortem.heap.unwrap().system_bytes, 135168);
+
+        assert!(parse_output("No symbol table is loaded.\n").is_err());
+    }
+
+    #[test]
+    fn test_lock_cycle_finds_circular_wait() {
+        let mut postmortem = Postmortem {
+            core_file: PathBuf::from("core"),
+            threads: vec![
+                thread(1, Some("0x10")),
+                thread(2, Some("0x20")),
+                thread(3, None),
+            ],
+            mutexes: vec![mutex("0x10", 2, 1), mutex("0x20", 1, 2)],
+            heap: None,
+        };
+        assert_eq!(lock_cycle(&postmortem), vec!["0x10", "0x20"]);
+
+        // Thread 2 waiting on a mutex held by an idle thread is contention, not deadlock.
+        postmortem.mutexes[1].owner = Some(3);
+        assert!(lock_cycle(&postmortem).is_empty());
+    }
+}
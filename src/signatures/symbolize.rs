@@ -0,0 +1,470 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Symbols for frames of stripped binaries from separate debug info
+//!
+//! Release binaries usually ship without symbols, so their backtraces only name a module and
+//! an offset (`./app(+0x1234)`, `(/app+0x1234)`). Before a report is written, such frames are
+//! resolved with `addr2line` against the module's debug file, looked up by GNU build id:
+//! `--debug-dir` directories and `/usr/lib/debug` (`.build-id/ab/cdef....debug`), `.debug`
+//! beside the binary, then debuginfod through `debuginfod-find` unless symbolization is
+//! offline. Resolutions are cached per build id and offset, so later reports of the same
+//! build need neither the debug file nor the network.
+
+use crate::storage::write_atomic;
+use crate::types::{AttackResult, StackFrame};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// System directory of separate debug files.
+const SYSTEM_DEBUG_DIR: &str = "/usr/lib/debug";
+
+/// ELF note type of the GNU build id.
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// ELF section type of notes.
+const SHT_NOTE: u32 = 7;
+
+/// Default cache, under the user's cache directory.
+pub fn default_cache_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("panic-attack").join("symbols.json"))
+}
+
+/// A resolved frame location; cached as `None` when the debug file had no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Symbol {
+    pub function: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolCache {
+    /// Keyed by `BUILD_ID+0xOFFSET`.
+    #[serde(default)]
+    pub symbols: BTreeMap<String, Option<Symbol>>,
+}
+
+pub struct Symbolizer {
+    debug_dirs: Vec<PathBuf>,
+    offline: bool,
+    cache_path: Option<PathBuf>,
+    cache: SymbolCache,
+    dirty: bool,
+    /// Build id of each module seen, `None` when unreadable.
+    build_ids: HashMap<String, Option<String>>,
+    /// Debug file of each build id, `None` when none was found.
+    debug_files: HashMap<String, Option<PathBuf>>,
+}
+
+impl Symbolizer {
+    /// A symbolizer searching `debug_dirs` first; `offline` never queries debuginfod.
+    pub fn new(debug_dirs: Vec<PathBuf>, offline: bool, cache_path: Option<PathBuf>) -> Self {
+        // An unreadable cache is rebuilt rather than failing the report.
+        let cache = cache_path
+            .as_deref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            debug_dirs,
+            offline,
+            cache_path,
+            cache,
+            dirty: false,
+            build_ids: HashMap::new(),
+            debug_files: HashMap::new(),
+        }
+    }
+
+    /// Resolve the unsymbolized frames of every crash; returns how many were resolved.
+    pub fn symbolize_results(&mut self, results: &mut [AttackResult]) -> usize {
+        results
+            .iter_mut()
+            .flat_map(|result| result.crashes.iter_mut())
+            .map(|crash| self.symbolize(&mut crash.frames))
+            .sum()
+    }
+
+    /// Resolve frames that have a module and offset but no source location.
+    pub fn symbolize(&mut self, frames: &mut [StackFrame]) -> usize {
+        let mut resolved = 0;
+        for frame in frames.iter_mut().filter(|frame| frame.file.is_none()) {
+            let (Some(module), Some(offset)) = (frame.module.clone(), frame.offset) else {
+                continue;
+            };
+            let Some(build_id) = self.build_id(&module) else {
+                continue;
+            };
+            let key = format!("{}+0x{:x}", build_id, offset);
+            let symbol = match self.cache.symbols.get(&key) {
+                Some(cached) => cached.clone(),
+                None => {
+                    // Without a debug file nothing is cached: it may be installed later.
+                    let Some(debug) = self.debug_file(&module, &build_id) else {
+                        continue;
+                    };
+                    let symbol = addr2line(&debug, offset);
+                    self.cache.symbols.insert(key, symbol.clone());
+                    self.dirty = true;
+                    symbol
+                }
+            };
+            if let Some(symbol) = symbol {
+                frame.function = symbol.function;
+                frame.file = symbol.file;
+                frame.line = symbol.line;
+                resolved += 1;
+            }
+        }
+        resolved
+    }
+
+    /// Write the cache back when this run added to it.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = self.cache_path.as_deref().filter(|_| self.dirty) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("creating symbol cache directory {}", dir.display()))?;
+        }
+        write_atomic(path, serde_json::to_string_pretty(&self.cache)?)
+            .with_context(|| format!("writing symbol cache {}", path.display()))
+    }
+
+    fn build_id(&mut self, module: &str) -> Option<String> {
+        self.build_ids
+            .entry(module.to_string())
+            .or_insert_with(|| read_build_id(Path::new(module)))
+            .clone()
+    }
+
+    fn debug_file(&mut self, module: &str, build_id: &str) -> Option<PathBuf> {
+        if let Some(found) = self.debug_files.get(build_id) {
+            return found.clone();
+        }
+        let found = debug_candidates(&self.debug_dirs, Path::new(module), build_id)
+            .into_iter()
+            .find(|candidate| read_build_id(candidate).as_deref() == Some(build_id))
+            .or_else(|| (!self.offline).then(|| debuginfod(build_id)).flatten());
+        self.debug_files.insert(build_id.to_string(), found.clone());
+        found
+    }
+}
+
+/// Places a debug file for `module` may live, in search order.
+fn debug_candidates(debug_dirs: &[PathBuf], module: &Path, build_id: &str) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    let system = PathBuf::from(SYSTEM_DEBUG_DIR);
+    if build_id.len() > 2 {
+        let (head, tail) = build_id.split_at(2);
+        candidates.extend(
+            debug_dirs
+                .iter()
+                .chain(std::iter::once(&system))
+                .map(|dir| {
+                    dir.join(".build-id")
+                        .join(head)
+                        .join(format!("{}.debug", tail))
+                }),
+        );
+    }
+    let Some(name) = module.file_name().map(|name| name.to_string_lossy()) else {
+        return candidates;
+    };
+    let debug_name = format!("{}.debug", name);
+    candidates.extend(debug_dirs.iter().map(|dir| dir.join(&debug_name)));
+    let dir = module.parent().unwrap_or(Path::new(""));
+    candidates.push(dir.join(".debug").join(&debug_name));
+    candidates.push(dir.join(&debug_name));
+    if let Ok(absolute) = std::fs::canonicalize(module) {
+        let mut system_copy = system.into_os_string();
+        system_copy.push(absolute.as_os_str());
+        system_copy.push(".debug");
+        candidates.push(PathBuf::from(system_copy));
+    }
+    candidates
+}
+
+/// Path of the debug file `debuginfod-find` downloads (into its own cache) for `build_id`.
+fn debuginfod(build_id: &str) -> Option<PathBuf> {
+    let output = Command::new("debuginfod-find")
+        .args(["debuginfo", build_id])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !path.is_empty()).then(|| PathBuf::from(path))
+}
+
+fn addr2line(debug_file: &Path, offset: u64) -> Option<Symbol> {
+    let output = Command::new("addr2line")
+        .arg("-f")
+        .arg("-C")
+        .arg("-e")
+        .arg(debug_file)
+        .arg(format!("0x{:x}", offset))
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_addr2line(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// `function` then `file:line` from `addr2line -f`; `??` marks what it could not resolve.
+fn parse_addr2line(text: &str) -> Option<Symbol> {
+    let mut lines = text.lines();
+    let function = lines.next()?.trim();
+    if function.is_empty() || function == "??" {
+        return None;
+    }
+    let location = lines.next().unwrap_or("??:0").trim();
+    // "src/app.c:27 (discriminator 3)"
+    let location = location.split(" (").next().unwrap_or(location);
+    let (file, line) = location.rsplit_once(':').unwrap_or((location, "0"));
+    Some(Symbol {
+        function: function.to_string(),
+        file: Some(file.to_string()).filter(|file| file != "??"),
+        line: line.parse().ok().filter(|line| *line > 0),
+    })
+}
+
+/// GNU build id of an ELF file, as lowercase hex, from its note sections.
+pub fn read_build_id(path: &Path) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    let mut ident = [0u8; 64];
+    file.read_exact(&mut ident).ok()?;
+    if &ident[..4] != b"\x7fELF" {
+        return None;
+    }
+    let elf = Elf {
+        wide: ident[4] == 2,
+        little: ident[5] == 1,
+    };
+    let (shoff, shentsize, shnum) = if elf.wide {
+        (
+            elf.u64(&ident[0x28..]),
+            elf.u16(&ident[0x3a..]),
+            elf.u16(&ident[0x3c..]),
+        )
+    } else {
+        (
+            u64::from(elf.u32(&ident[0x20..])),
+            elf.u16(&ident[0x2e..]),
+            elf.u16(&ident[0x30..]),
+        )
+    };
+    let mut headers = vec![0u8; usize::from(shentsize) * usize::from(shnum)];
+    file.seek(SeekFrom::Start(shoff)).ok()?;
+    file.read_exact(&mut headers).ok()?;
+    for header in headers.chunks(usize::from(shentsize).max(1)) {
+        if header.len() < 0x28 || elf.u32(&header[4..]) != SHT_NOTE {
+            continue;
+        }
+        let (offset, size) = if elf.wide {
+            (elf.u64(&header[0x18..]), elf.u64(&header[0x20..]))
+        } else {
+            (
+                u64::from(elf.u32(&header[0x10..])),
+                u64::from(elf.u32(&header[0x14..])),
+            )
+        };
+        let mut notes = vec![0u8; usize::try_from(size).ok()?.min(1 << 16)];
+        file.seek(SeekFrom::Start(offset)).ok()?;
+        file.read_exact(&mut notes).ok()?;
+        if let Some(id) = elf.build_id_note(&notes) {
+            return Some(id);
+        }
+    }
+    None
+}
+
+struct Elf {
+    wide: bool,
+    little: bool,
+}
+
+impl Elf {
+    fn u16(&self, bytes: &[u8]) -> u16 {
+        let raw = [bytes[0], bytes[1]];
+        if self.little {
+            u16::from_le_bytes(raw)
+        } else {
+            u16::from_be_bytes(raw)
+        }
+    }
+
+    fn u32(&self, bytes: &[u8]) -> u32 {
+        let raw = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        if self.little {
+            u32::from_le_bytes(raw)
+        } else {
+            u32::from_be_bytes(raw)
+        }
+    }
+
+    fn u64(&self, bytes: &[u8]) -> u64 {
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&bytes[..8]);
+        if self.little {
+            u64::from_le_bytes(raw)
+        } else {
+            u64::from_be_bytes(raw)
+        }
+    }
+
+    /// Walk `namesz, descsz, type, name, desc` records, each padded to four bytes.
+    fn build_id_note(&self, mut notes: &[u8]) -> Option<String> {
+        let pad = |len: usize| (len + 3) & !3;
+        while notes.len() >= 12 {
+            let namesz = self.u32(notes) as usize;
+            let descsz = self.u32(&notes[4..]) as usize;
+            let kind = self.u32(&notes[8..]);
+            let desc_start = 12 + pad(namesz);
+            let end = desc_start + pad(descsz);
+            let name = notes.get(12..12 + namesz)?;
+            let desc = notes.get(desc_start..desc_start + descsz)?;
+            if kind == NT_GNU_BUILD_ID && name == b"GNU\0" {
+                return Some(hex::encode(desc));
+            }
+            notes = notes.get(end..)?;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// A minimal 64-bit little-endian ELF with one note section holding `build_id`.
+    fn elf_with_build_id(build_id: &[u8]) -> Vec<u8> {
+        let mut note = Vec::new();
+        note.extend(4u32.to_le_bytes());
+        note.extend((build_id.len() as u32).to_le_bytes());
+        note.extend(NT_GNU_BUILD_ID.to_le_bytes());
+        note.extend(b"GNU\0");
+        note.extend(build_id);
+        let mut elf = vec![0u8; 64];
+        elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+        let note_offset = 64u64;
+        let shoff = note_offset + note.len() as u64;
+        elf[0x28..0x30].copy_from_slice(&shoff.to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&2u16.to_le_bytes());
+        elf.extend(&note);
+        elf.extend([0u8; 64]); // null section
+        let mut header = [0u8; 64];
+        header[4..8].copy_from_slice(&SHT_NOTE.to_le_bytes());
+        header[0x18..0x20].copy_from_slice(&note_offset.to_le_bytes());
+        header[0x20..0x28].copy_from_slice(&(note.len() as u64).to_le_bytes());
+        elf.extend(header);
+        elf
+    }
+
+    #[test]
+    fn test_build_id_and_debug_dir_lookup() {
+        let dir = TempDir::new().unwrap();
+        let binary = dir.path().join("app");
+        std::fs::write(&binary, elf_with_build_id(&[0xab, 0xcd, 0xef, 0x01])).unwrap();
+        assert_eq!(read_build_id(&binary).as_deref(), Some("abcdef01"));
+        std::fs::write(dir.path().join("script"), "#!/bin/sh\n").unwrap();
+        assert_eq!(read_build_id(&dir.path().join("script")), None);
+
+        let debug_dir = dir.path().join("debug");
+        let candidates = debug_candidates(std::slice::from_ref(&debug_dir), &binary, "abcdef01");
+        assert_eq!(candidates[0], debug_dir.join(".build-id/ab/cdef01.debug"));
+        assert_eq!(
+            candidates[1],
+            Path::new(SYSTEM_DEBUG_DIR).join(".build-id/ab/cdef01.debug")
+        );
+        assert!(candidates.contains(&dir.path().join(".debug/app.debug")));
+        assert!(candidates.contains(&debug_dir.join("app.debug")));
+
+        // A debug file is only accepted when its build id matches the binary's.
+        let mut symbolizer = Symbolizer::new(vec![debug_dir.clone()], true, None);
+        std::fs::create_dir_all(debug_dir.join(".build-id/ab")).unwrap();
+        std::fs::write(
+            debug_dir.join("app.debug"),
+            elf_with_build_id(&[0x11, 0x22]),
+        )
+        .unwrap();
+        let module = binary.display().to_string();
+        assert_eq!(symbolizer.debug_file(&module, "abcdef01"), None);
+        std::fs::write(
+            debug_dir.join(".build-id/ab/cdef01.debug"),
+            elf_with_build_id(&[0xab, 0xcd, 0xef, 0x01]),
+        )
+        .unwrap();
+        symbolizer.debug_files.clear();
+        assert_eq!(
+            symbolizer.debug_file(&module, "abcdef01"),
+            Some(debug_dir.join(".build-id/ab/cdef01.debug"))
+        );
+    }
+
+    #[test]
+    fn test_cached_symbols_resolve_offline() {
+        let dir = TempDir::new().unwrap();
+        let binary = dir.path().join("app");
+        std::fs::write(&binary, elf_with_build_id(&[0xab, 0xcd])).unwrap();
+        let cache_path = dir.path().join("cache/symbols.json");
+        let mut cache = SymbolCache::default();
+        cache.symbols.insert(
+            "abcd+0x1234".to_string(),
+            Some(Symbol {
+                function: "parse_header".to_string(),
+                file: Some("src/http.c".to_string()),
+                line: Some(88),
+            }),
+        );
+        std::fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+        std::fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
+
+        let mut frames = crate::signatures::backtrace::parse(&format!(
+            "{0}(+0x1234)[0x55d0c0ff1234]\n{0}(+0x99)[0x55d0c0ff0099]\n",
+            binary.display()
+        ));
+        assert_eq!(frames[0].function, "app+0x1234");
+        let mut symbolizer = Symbolizer::new(Vec::new(), true, Some(cache_path.clone()));
+        assert_eq!(symbolizer.symbolize(&mut frames), 1);
+        assert_eq!(frames[0].describe(), "parse_header (src/http.c:88)");
+        assert_eq!(frames[1].function, "app+0x99", "no debug file: left as is");
+        assert_eq!(frames[1].module, Some(binary.display().to_string()));
+        assert_eq!(frames[1].offset, Some(0x99));
+
+        // Nothing new was learned, so the cache is left alone.
+        std::fs::remove_file(&cache_path).unwrap();
+        symbolizer.save().unwrap();
+        assert!(!cache_path.exists());
+    }
+
+    #[test]
+    fn test_parse_addr2line() {
+        assert_eq!(
+            parse_addr2line("handle_request\n/src/app/server.c:27 (discriminator 3)\n"),
+            Some(Symbol {
+                function: "handle_request".to_string(),
+                file: Some("/src/app/server.c".to_string()),
+                line: Some(27),
+            })
+        );
+        assert_eq!(
+            parse_addr2line("main\n??:0\n").map(|s| (s.file, s.line)),
+            Some((None, None))
+        );
+        assert_eq!(parse_addr2line("??\n??:0\n"), None);
+    }
+}
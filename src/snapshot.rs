@@ -0,0 +1,455 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Before/after snapshots of target-observable state
+//!
+//! A target can survive an assault and still be broken: a half-written file, a lost row, a
+//! balance that no longer adds up. An assault configured with snapshot sources captures each
+//! one before the attacks and again after them, and reports the structural difference:
+//!
+//! - a directory: every file under it, by size and BLAKE3 hash
+//! - a SQLite database: each table's schema and rows, read with the `sqlite3` client
+//! - an `http://` state endpoint: the JSON it serves, flattened to JSON pointers
+//!
+//! Every source is reduced to a map of keys to values, so the diff is the same for all of
+//! them. Changes the target makes on purpose show up as well; the diff says what changed,
+//! not whether it should have.
+
+use crate::ambush::http_request;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use walkdir::WalkDir;
+
+/// State endpoint response bytes read per snapshot.
+const MAX_STATE_BYTES: u64 = 16 * 1024 * 1024;
+/// Changes listed per source; the rest are only counted.
+const MAX_CHANGES: usize = 200;
+/// Longest before/after value kept in a change.
+const MAX_VALUE_LEN: usize = 120;
+
+/// One source's values by key: relative path, `table/rowid`, or JSON pointer.
+type SourceState = BTreeMap<String, String>;
+
+/// State of every configured source at one point in time.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    sources: Vec<(String, Result<SourceState, String>)>,
+}
+
+/// Capture every source in `config`; a source that cannot be read is kept as its error.
+pub fn capture(config: &SnapshotConfig) -> Snapshot {
+    let mut sources = Vec::new();
+    for dir in &config.dirs {
+        sources.push((format!("dir:{}", dir.display()), capture_dir(dir)));
+    }
+    if let Some(database) = &config.sqlite {
+        sources.push((
+            format!("sqlite:{}", database.display()),
+            capture_sqlite(database),
+        ));
+    }
+    if let Some(url) = &config.url {
+        sources.push((url.clone(), capture_url(url)));
+    }
+    let sources = sources
+        .into_iter()
+        .map(|(source, state)| (source, state.map_err(|err| format!("{:#}", err))))
+        .collect();
+    Snapshot { sources }
+}
+
+/// Differences between two snapshots of the same configuration.
+pub fn diff(before: &Snapshot, after: &Snapshot) -> StateDiff {
+    let sources = before
+        .sources
+        .iter()
+        .zip(&after.sources)
+        .map(|((source, before), (_, after))| match (before, after) {
+            (Ok(before), Ok(after)) => {
+                let mut changes = diff_maps(before, after);
+                let omitted = changes.len().checked_sub(MAX_CHANGES).filter(|&n| n > 0);
+                changes.truncate(MAX_CHANGES);
+                SourceDiff {
+                    source: source.clone(),
+                    changes,
+                    omitted,
+                    error: None,
+                }
+            }
+            (Err(err), _) | (_, Err(err)) => SourceDiff {
+                source: source.clone(),
+                changes: Vec::new(),
+                omitted: None,
+                error: Some(err.clone()),
+            },
+        })
+        .collect();
+    StateDiff { sources }
+}
+
+fn diff_maps(before: &SourceState, after: &SourceState) -> Vec<StateChange> {
+    let mut changes = Vec::new();
+    for (path, old) in before {
+        match after.get(path) {
+            None => changes.push(change(StateChangeKind::Removed, path, Some(old), None)),
+            Some(new) if new != old => changes.push(change(
+                StateChangeKind::Modified,
+                path,
+                Some(old),
+                Some(new),
+            )),
+            Some(_) => {}
+        }
+    }
+    for (path, new) in after {
+        if !before.contains_key(path) {
+            changes.push(change(StateChangeKind::Added, path, None, Some(new)));
+        }
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+fn change(
+    kind: StateChangeKind,
+    path: &str,
+    before: Option<&String>,
+    after: Option<&String>,
+) -> StateChange {
+    StateChange {
+        kind,
+        path: path.to_string(),
+        before: before.map(|value| clip(value)),
+        after: after.map(|value| clip(value)),
+    }
+}
+
+fn clip(value: &str) -> String {
+    match value.char_indices().nth(MAX_VALUE_LEN) {
+        Some((end, _)) => format!("{}...", &value[..end]),
+        None => value.to_string(),
+    }
+}
+
+/// Files under `dir` by relative path; a directory that does not exist yet is empty.
+fn capture_dir(dir: &Path) -> Result<SourceState> {
+    let mut files = BTreeMap::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        // The target may delete files while the walk runs.
+        let Ok(contents) = fs::read(entry.path()) else {
+            continue;
+        };
+        let relative = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        files.insert(
+            relative.display().to_string(),
+            format!(
+                "{} bytes, blake3 {}",
+                contents.len(),
+                &blake3::hash(&contents).to_hex()[..16]
+            ),
+        );
+    }
+    Ok(files)
+}
+
+/// Each table's schema under `table` and its rows under `table/rowid`; a database that
+/// does not exist yet is empty.
+fn capture_sqlite(database: &Path) -> Result<SourceState> {
+    let mut state = BTreeMap::new();
+    if !database.exists() {
+        return Ok(state);
+    }
+    let tables = sqlite_query(
+        database,
+        "SELECT name, sql FROM sqlite_master \
+         WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    for table in tables {
+        let name = table["name"].as_str().unwrap_or_default().to_string();
+        state.insert(
+            name.clone(),
+            table["sql"].as_str().unwrap_or_default().to_string(),
+        );
+        let quoted = format!("\"{}\"", name.replace('"', "\"\""));
+        // WITHOUT ROWID tables have no rowid; their rows are keyed by position instead.
+        let rows = sqlite_query(
+            database,
+            &format!(
+                "SELECT rowid AS \"__rowid\", * FROM {} ORDER BY rowid",
+                quoted
+            ),
+        )
+        .or_else(|_| sqlite_query(database, &format!("SELECT * FROM {}", quoted)))?;
+        for (index, mut row) in rows.into_iter().enumerate() {
+            let key = match row.as_object_mut().and_then(|row| row.remove("__rowid")) {
+                Some(rowid) => rowid.to_string(),
+                None => index.to_string(),
+            };
+            state.insert(format!("{}/{}", name, key), row.to_string());
+        }
+    }
+    Ok(state)
+}
+
+fn sqlite_query(database: &Path, sql: &str) -> Result<Vec<Value>> {
+    let output = Command::new("sqlite3")
+        .arg("-readonly")
+        .arg("-json")
+        .arg(database)
+        .arg(sql)
+        .output()
+        .context("running sqlite3 (is the sqlite3 client installed?)")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "sqlite3: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // An empty result prints nothing rather than `[]`.
+    if stdout.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(&stdout).context("sqlite3 sent malformed JSON")
+}
+
+/// The endpoint's JSON flattened to pointers, or its whole body under `body` when it is
+/// not JSON.
+fn capture_url(url: &str) -> Result<SourceState> {
+    let body = http_get(url)?;
+    let mut state = BTreeMap::new();
+    match serde_json::from_str::<Value>(&body) {
+        Ok(value) => flatten(&value, String::new(), &mut state),
+        Err(_) => {
+            state.insert("body".to_string(), body);
+        }
+    }
+    Ok(state)
+}
+
+/// Leaf values of `value` by JSON pointer; empty objects and arrays count as leaves.
+fn flatten(value: &Value, pointer: String, state: &mut SourceState) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                flatten(value, format!("{}/{}", pointer, key), state);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (index, value) in items.iter().enumerate() {
+                flatten(value, format!("{}/{}", pointer, index), state);
+            }
+        }
+        leaf => {
+            let pointer = if pointer.is_empty() {
+                "/".to_string()
+            } else {
+                pointer
+            };
+            state.insert(pointer, leaf.to_string());
+        }
+    }
+}
+
+fn http_get(url: &str) -> Result<String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| anyhow!("state endpoint must be an http:// URL: {}", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    let (status, body) = http_request(
+        authority,
+        "GET",
+        path,
+        &[("Accept", "application/json")],
+        "",
+        MAX_STATE_BYTES,
+    )
+    .with_context(|| format!("fetching {}", url))?;
+    if !(200..300).contains(&status) {
+        return Err(anyhow!("{} answered {}", url, status));
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn config(dir: &Path) -> SnapshotConfig {
+        SnapshotConfig {
+            dirs: vec![dir.to_path_buf()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dir_diff_reports_added_removed_and_modified_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("kept"), "same").unwrap();
+        fs::write(dir.path().join("gone"), "old").unwrap();
+        fs::create_dir(dir.path().join("db")).unwrap();
+        fs::write(dir.path().join("db/ledger"), "balance=10").unwrap();
+        let before = capture(&config(dir.path()));
+
+        fs::remove_file(dir.path().join("gone")).unwrap();
+        fs::write(dir.path().join("db/ledger"), "balance=-3").unwrap();
+        fs::write(dir.path().join("new"), "fresh").unwrap();
+        let after = capture(&config(dir.path()));
+
+        let diff = diff(&before, &after);
+        let changes: Vec<_> = diff.sources[0]
+            .changes
+            .iter()
+            .map(|change| (change.kind, change.path.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (StateChangeKind::Modified, "db/ledger"),
+                (StateChangeKind::Removed, "gone"),
+                (StateChangeKind::Added, "new"),
+            ]
+        );
+        assert_eq!(diff.change_count(), 3);
+    }
+
+    #[test]
+    fn test_missing_dir_counts_as_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("state");
+        let before = capture(&config(&state));
+        fs::create_dir(&state).unwrap();
+        fs::write(state.join("out"), "x").unwrap();
+        let diff = diff(&before, &capture(&config(&state)));
+        assert_eq!(diff.sources[0].changes[0].kind, StateChangeKind::Added);
+        assert!(diff.sources[0].error.is_none());
+    }
+
+    #[test]
+    fn test_sqlite_rows_are_diffed_by_rowid() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = dir.path().join("app.db");
+        let sql = |statement: &str| {
+            Command::new("sqlite3")
+                .arg(&database)
+                .arg(statement)
+                .status()
+        };
+        let Ok(status) = sql("CREATE TABLE accounts (name TEXT, balance INTEGER); \
+             INSERT INTO accounts VALUES ('ada', 10), ('bob', 5);")
+        else {
+            // No sqlite3 client on this host.
+            return;
+        };
+        assert!(status.success());
+        let config = SnapshotConfig {
+            sqlite: Some(database.clone()),
+            ..Default::default()
+        };
+        let before = capture(&config);
+        sql("UPDATE accounts SET balance = -1 WHERE name = 'bob';").unwrap();
+        let diff = diff(&before, &capture(&config));
+
+        let source = &diff.sources[0];
+        assert!(source.error.is_none(), "{:?}", source.error);
+        assert_eq!(source.changes.len(), 1);
+        assert_eq!(source.changes[0].kind, StateChangeKind::Modified);
+        assert_eq!(source.changes[0].path, "accounts/2");
+        assert!(source.changes[0].after.as_deref().unwrap().contains("-1"));
+    }
+
+    #[test]
+    fn test_flatten_uses_escaped_json_pointers() {
+        let mut state = BTreeMap::new();
+        let value = serde_json::json!({"a/b": {"c": [1, {}]}, "n": null});
+        flatten(&value, String::new(), &mut state);
+        let keys: Vec<_> = state.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["/a~1b/c/0", "/a~1b/c/1", "/n"]);
+        assert_eq!(state["/a~1b/c/1"], "{}");
+    }
+
+    #[test]
+    fn test_changes_past_the_cap_are_counted() {
+        let before = BTreeMap::new();
+        let after: BTreeMap<_, _> = (0..MAX_CHANGES + 5)
+            .map(|index| (format!("{:04}", index), "x".to_string()))
+            .collect();
+        let snapshot = |state| Snapshot {
+            sources: vec![("dir:x".to_string(), Ok(state))],
+        };
+        let diff = diff(&snapshot(before), &snapshot(after));
+        assert_eq!(diff.sources[0].changes.len(), MAX_CHANGES);
+        assert_eq!(diff.sources[0].omitted, Some(5));
+        assert_eq!(diff.change_count(), MAX_CHANGES + 5);
+    }
+
+    #[test]
+    fn test_url_state_is_diffed_by_pointer() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/state", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            for body in [
+                r#"{"users":[{"balance":10}]}"#,
+                r#"{"users":[{"balance":7}]}"#,
+            ] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        let config = SnapshotConfig {
+            url: Some(url),
+            ..Default::default()
+        };
+        let before = capture(&config);
+        let after = capture(&config);
+        server.join().unwrap();
+
+        let diff = diff(&before, &after);
+        let change = &diff.sources[0].changes[0];
+        assert_eq!(change.path, "/users/0/balance");
+        assert_eq!(change.before.as_deref(), Some("10"));
+        assert_eq!(change.after.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn test_unreachable_url_is_an_error_not_a_change() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let config = SnapshotConfig {
+            url: Some(format!("http://127.0.0.1:{}/state", port)),
+            ..Default::default()
+        };
+        let diff = diff(&capture(&config), &capture(&config));
+        assert!(diff.sources[0].changes.is_empty());
+        assert!(diff.sources[0].error.is_some());
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Store consistency check (`panic-attack storage fsck`)
+//!
+//! Reports written before atomic writes, or damaged afterwards, break `diff`, `adjudicate`
+//! and `reports query` when they are read back. This scans a store and its `hexads/`
+//! directory, the same files those commands load, and moves every report that no longer
+//! parses, and every temp file an interrupted write left behind, into `<store>/quarantine/`.
+
+use super::TEMP_MARKER;
+use crate::error::StorageError;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const QUARANTINE_DIR: &str = "quarantine";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FsckFinding {
+    pub path: PathBuf,
+    pub problem: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quarantined_to: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FsckReport {
+    pub checked: usize,
+    pub findings: Vec<FsckFinding>,
+}
+
+/// Check every report in `dir`; with `quarantine`, move the broken ones aside.
+pub fn fsck(dir: &Path, quarantine: bool) -> Result<FsckReport, StorageError> {
+    if !dir.is_dir() {
+        return Err(StorageError::DirectoryMissing(dir.to_path_buf()));
+    }
+    let mut report = FsckReport::default();
+    for scanned in [dir.to_path_buf(), dir.join("hexads")] {
+        if !scanned.is_dir() {
+            continue;
+        }
+        let mut paths: Vec<PathBuf> = fs::read_dir(&scanned)
+            .with_context(|| format!("reading {}", scanned.display()))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+        for path in paths {
+            let Some(problem) = check_file(&path) else {
+                continue;
+            };
+            report.checked += 1;
+            let Some(problem) = problem else {
+                continue;
+            };
+            let quarantined_to = if quarantine {
+                Some(move_to_quarantine(dir, &path)?)
+            } else {
+                None
+            };
+            report.findings.push(FsckFinding {
+                path,
+                problem,
+                quarantined_to,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// `None` for files fsck does not look at, `Some(None)` for a healthy report.
+fn check_file(path: &Path) -> Option<Option<String>> {
+    let name = path.file_name()?.to_string_lossy();
+    if name.starts_with('.') && name.contains(TEMP_MARKER) {
+        return Some(Some("left over from an interrupted write".to_string()));
+    }
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    if !matches!(ext.as_str(), "json" | "yaml" | "yml") {
+        return None;
+    }
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => return Some(Some(format!("unreadable: {}", err))),
+    };
+    if content.trim().is_empty() {
+        return Some(Some("empty".to_string()));
+    }
+    let parsed = if ext == "json" {
+        serde_json::from_str::<serde_json::Value>(&content).map_err(|err| err.to_string())
+    } else {
+        serde_yaml::from_str::<serde_yaml::Value>(&content)
+            .map(|_| serde_json::Value::Null)
+            .map_err(|err| err.to_string())
+    };
+    Some(parsed.err().map(|err| format!("does not parse: {}", err)))
+}
+
+fn move_to_quarantine(dir: &Path, path: &Path) -> Result<PathBuf> {
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    let mut destination = dir.join(QUARANTINE_DIR).join(relative);
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("creating quarantine {}", parent.display()))?;
+    }
+    // Never overwrite an earlier quarantined copy of the same name.
+    let mut attempt = 1;
+    while destination.exists() {
+        let mut name = relative.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{}", attempt));
+        destination.set_file_name(name);
+        attempt += 1;
+    }
+    fs::rename(path, &destination)
+        .with_context(|| format!("moving {} to {}", path.display(), destination.display()))?;
+    Ok(destination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn broken_reports_are_quarantined() {
+        let dir = TempDir::new().expect("tempdir should create");
+        let store = dir.path();
+        fs::create_dir(store.join("hexads")).unwrap();
+        fs::write(store.join("panic-attack-1.json"), "{\"ok\": true}").unwrap();
+        fs::write(store.join("panic-attack-2.json"), "{\"cut off\": ").unwrap();
+        fs::write(store.join("panic-attack-3.yaml"), "").unwrap();
+        fs::write(store.join("panic-attack-3.ncl"), "{").unwrap();
+        fs::write(store.join(".panic-attack-4.json.tmp-99"), "{").unwrap();
+        fs::write(store.join("hexads/pa-1.json"), "[1,").unwrap();
+
+        let dry = fsck(store, false).expect("fsck");
+        assert_eq!(dry.checked, 5);
+        assert_eq!(dry.findings.len(), 4);
+        assert!(dry.findings.iter().all(|f| f.quarantined_to.is_none()));
+        assert!(store.join("panic-attack-2.json").exists());
+
+        let fixed = fsck(store, true).expect("fsck");
+        assert_eq!(fixed.findings.len(), 4);
+        assert!(store.join("quarantine/panic-attack-2.json").exists());
+        assert!(store.join("quarantine/hexads/pa-1.json").exists());
+        assert!(!store.join(".panic-attack-4.json.tmp-99").exists());
+        assert!(fixed.findings.iter().any(|f| f.problem == "empty"));
+
+        let clean = fsck(store, true).expect("fsck");
+        assert_eq!(clean.checked, 1);
+        assert!(clean.findings.is_empty());
+    }
+}
@@ -10,18 +10,40 @@
 //!   Currently file-based only — HTTP API integration is planned for when
 //!   VerisimDB's REST endpoint stabilises.
 //!
+//! Assault reports are stored at the top of the store; the other report kinds
+//! (amuck, abduct, adjudicate, axial/audience) go through [`persist_any_report`]
+//! into a subdirectory per kind. The manifest's `(reports (persist-kinds ...))`
+//! chooses which kinds are stored at all. [`latest_reports`] reads the kind,
+//! program and time of every stored report, whatever its file name, so callers
+//! can ask for e.g. the newest two assault runs of one program.
+//!
 //! Both modes create parent directories as needed and return the paths of
-//! all files written.
-
+//! all files written. Every report is written through [`write_atomic`], so an
+//! interrupted run leaves either the previous file or the complete new one;
+//! `panic-attack storage fsck` quarantines whatever slipped through anyway.
+
+pub mod fsck;
+pub mod query;
+
+use crate::abduct::AbductReport;
+use crate::adjudicate::AdjudicateReport;
+use crate::amuck::AmuckReport;
+use crate::axial::AxialReport;
+use crate::error::StorageError;
+use crate::report::artifacts::{self, ArtifactStore};
+use crate::report::repro::{self, ReproOptions};
 use crate::report::ReportOutputFormat;
 use crate::types::AssaultReport;
-use anyhow::{anyhow, Result};
-use chrono::Utc;
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_json;
-use std::fs;
+use std::fs::{self, File};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+/// Infix of the temporary files [`write_atomic`] renames into place.
+pub const TEMP_MARKER: &str = ".tmp-";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StorageMode {
     /// Direct filesystem persistence in the chosen output format(s)
@@ -31,6 +53,7 @@ pub enum StorageMode {
 }
 
 impl StorageMode {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(value: &str) -> Option<Self> {
         match value.to_lowercase().as_str() {
             "filesystem" | "disk" | "local" => Some(StorageMode::Filesystem),
@@ -40,6 +63,44 @@ impl StorageMode {
     }
 }
 
+/// Report kinds the store keeps apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportKind {
+    Assault,
+    Amuck,
+    Abduct,
+    Adjudicate,
+    /// `axial` reports, which pipelines also call `audience`.
+    #[serde(alias = "audience")]
+    Axial,
+}
+
+impl ReportKind {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "assault" => Some(ReportKind::Assault),
+            "amuck" => Some(ReportKind::Amuck),
+            "abduct" => Some(ReportKind::Abduct),
+            "adjudicate" => Some(ReportKind::Adjudicate),
+            "axial" | "audience" => Some(ReportKind::Axial),
+            _ => None,
+        }
+    }
+
+    /// File-name prefix and, for every kind but assault, the store subdirectory.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReportKind::Assault => "assault",
+            ReportKind::Amuck => "amuck",
+            ReportKind::Abduct => "abduct",
+            ReportKind::Adjudicate => "adjudicate",
+            ReportKind::Axial => "axial",
+        }
+    }
+}
+
 /// VerisimDB hexad wrapper for panic-attack reports.
 ///
 /// A hexad is the VerisimDB unit of storage — six facets representing
@@ -64,6 +125,10 @@ pub struct PanicAttackHexad {
     pub semantic: HexadSemantic,
     /// Full report payload (JSON-encoded AssaultReport)
     pub document: serde_json::Value,
+    /// Kind of report in `document`; absent in hexads written before kinds were recorded
+    /// and in assemblyline hexads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_kind: Option<ReportKind>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -177,6 +242,7 @@ fn build_hexad(report: &AssaultReport) -> Result<PanicAttackHexad> {
             migration,
         },
         document,
+        report_kind: Some(ReportKind::Assault),
     })
 }
 
@@ -190,7 +256,8 @@ pub fn persist_report(
     directory: Option<&Path>,
     formats: &[ReportOutputFormat],
     modes: &[StorageMode],
-) -> Result<Vec<PathBuf>> {
+    repro_options: ReproOptions,
+) -> Result<Vec<PathBuf>, StorageError> {
     let mut stored = Vec::new();
     let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
 
@@ -198,12 +265,16 @@ pub fn persist_report(
         let base_dir = directory
             .map(Path::to_path_buf)
             .unwrap_or_else(|| PathBuf::from("reports"));
-        fs::create_dir_all(&base_dir)?;
+        fs::create_dir_all(&base_dir).map_err(|err| StorageError::from_io(&base_dir, err))?;
+        let mut stored_report =
+            artifacts::externalize_assault_report(report, &ArtifactStore::new(&base_dir))?;
+        let name = format!("panic-attack-{}", timestamp);
+        repro::write_repro_scripts(&mut stored_report, &base_dir, &name, repro_options)?;
         for format in formats {
-            let file_name = format!("panic-attack-{}.{}", timestamp, format.extension());
+            let file_name = format!("{}.{}", name, format.extension());
             let path = base_dir.join(&file_name);
-            let content = format.serialize(report)?;
-            fs::write(&path, content)?;
+            let content = format.serialize(&stored_report)?;
+            write_atomic(&path, content).map_err(|err| StorageError::from_io(&path, err))?;
             stored.push(path);
         }
     }
@@ -213,12 +284,185 @@ pub fn persist_report(
             .map(Path::to_path_buf)
             .unwrap_or_else(|| PathBuf::from("verisimdb-data"));
         let hexad_dir = base_dir.join("hexads");
-        fs::create_dir_all(&hexad_dir)?;
+        fs::create_dir_all(&hexad_dir).map_err(|err| StorageError::from_io(&hexad_dir, err))?;
+
+        let stored_report =
+            artifacts::externalize_assault_report(report, &ArtifactStore::new(&hexad_dir))?;
+        let hexad = build_hexad(&stored_report)?;
+        let path = hexad_dir.join(format!("{}.json", hexad.id));
+        let payload = serde_json::to_string_pretty(&hexad)?;
+        write_atomic(&path, payload).map_err(|err| StorageError::from_io(&path, err))?;
+        stored.push(path);
+    }
+
+    Ok(stored)
+}
+
+/// A non-assault report [`persist_any_report`] can store.
+pub trait StoredReport: Serialize {
+    const KIND: ReportKind;
+
+    /// Program or file the report is about, for the hexad provenance.
+    fn subject(&self) -> String;
+
+    /// Counts for the hexad's semantic facet; fields that do not apply stay zero.
+    fn semantic(&self) -> HexadSemantic;
+}
+
+fn empty_semantic(categories: Vec<String>) -> HexadSemantic {
+    let mut categories = categories;
+    categories.sort();
+    categories.dedup();
+    HexadSemantic {
+        total_weak_points: 0,
+        critical_count: 0,
+        high_count: 0,
+        total_crashes: 0,
+        robustness_score: 0.0,
+        categories,
+        migration: None,
+    }
+}
+
+impl StoredReport for AmuckReport {
+    const KIND: ReportKind = ReportKind::Amuck;
+
+    fn subject(&self) -> String {
+        self.target.display().to_string()
+    }
+
+    /// Crashes are mutated runs that failed; categories are the mutation operations used.
+    fn semantic(&self) -> HexadSemantic {
+        let mut semantic = empty_semantic(
+            self.outcomes
+                .iter()
+                .flat_map(|outcome| outcome.operations.iter().cloned())
+                .collect(),
+        );
+        semantic.total_crashes = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.execution.as_ref().is_some_and(|exe| !exe.success))
+            .count();
+        semantic
+    }
+}
+
+impl StoredReport for AbductReport {
+    const KIND: ReportKind = ReportKind::Abduct;
+
+    fn subject(&self) -> String {
+        self.target.display().to_string()
+    }
+
+    fn semantic(&self) -> HexadSemantic {
+        let mut semantic =
+            empty_semantic(vec![self.dependency_scope.clone(), self.time_mode.clone()]);
+        semantic.total_crashes = usize::from(
+            self.execution
+                .as_ref()
+                .is_some_and(|exe| !exe.success || exe.timed_out),
+        );
+        semantic
+    }
+}
+
+impl StoredReport for AdjudicateReport {
+    const KIND: ReportKind = ReportKind::Adjudicate;
+
+    /// A campaign spans several targets, so there is no single subject.
+    fn subject(&self) -> String {
+        String::new()
+    }
+
+    fn semantic(&self) -> HexadSemantic {
+        let mut semantic = empty_semantic(
+            self.rule_hits
+                .iter()
+                .filter(|hit| hit.derived > 0)
+                .map(|hit| hit.rule.clone())
+                .collect(),
+        );
+        semantic.critical_count = self.totals.critical_weak_points;
+        semantic.total_crashes = self.totals.total_crashes;
+        semantic
+    }
+}
+
+impl StoredReport for AxialReport {
+    const KIND: ReportKind = ReportKind::Axial;
+
+    fn subject(&self) -> String {
+        self.target.display().to_string()
+    }
+
+    /// Categories are the reaction signals observed at least once.
+    fn semantic(&self) -> HexadSemantic {
+        empty_semantic(
+            self.signal_counts
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(signal, _)| signal.clone())
+                .collect(),
+        )
+    }
+}
+
+/// Persist a non-assault report under `<store>/<kind>/` (filesystem) and
+/// `<store>/hexads/<kind>/` (VerisimDB). Assault reports go through
+/// [`persist_report`], which also externalizes artifacts and writes repro scripts.
+pub fn persist_any_report<R: StoredReport>(
+    report: &R,
+    directory: Option<&Path>,
+    modes: &[StorageMode],
+) -> Result<Vec<PathBuf>, StorageError> {
+    let mut stored = Vec::new();
+    let now = Utc::now();
+    let timestamp = now.format("%Y%m%d%H%M%S").to_string();
+    let kind = R::KIND.name();
+
+    if modes.contains(&StorageMode::Filesystem) {
+        let kind_dir = directory
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("reports"))
+            .join(kind);
+        fs::create_dir_all(&kind_dir).map_err(|err| StorageError::from_io(&kind_dir, err))?;
+        let path = kind_dir.join(format!("{}-{}.json", kind, timestamp));
+        let content = serde_json::to_string_pretty(report)?;
+        write_atomic(&path, content).map_err(|err| StorageError::from_io(&path, err))?;
+        stored.push(path);
+    }
 
-        let hexad = build_hexad(report)?;
+    if modes.contains(&StorageMode::VerisimDb) {
+        let hexad_dir = directory
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("verisimdb-data"))
+            .join("hexads")
+            .join(kind);
+        fs::create_dir_all(&hexad_dir).map_err(|err| StorageError::from_io(&hexad_dir, err))?;
+        let hexad = PanicAttackHexad {
+            schema: "verisimdb.hexad.v1".to_string(),
+            id: format!(
+                "pa-{}-{}-{}",
+                kind,
+                timestamp,
+                uuid_from_timestamp(now.timestamp_millis())
+            ),
+            created_at: now.to_rfc3339(),
+            provenance: HexadProvenance {
+                tool: "panic-attack".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                program_path: report.subject(),
+                language: "unknown".to_string(),
+                attestation_hash: None,
+            },
+            semantic: report.semantic(),
+            document: serde_json::to_value(report)?,
+            report_kind: Some(R::KIND),
+        };
         let path = hexad_dir.join(format!("{}.json", hexad.id));
         let payload = serde_json::to_string_pretty(&hexad)?;
-        fs::write(&path, payload)?;
+        write_atomic(&path, payload).map_err(|err| StorageError::from_io(&path, err))?;
         stored.push(path);
     }
 
@@ -280,6 +524,7 @@ fn build_assemblyline_hexad(
             migration: None,
         },
         document,
+        report_kind: None,
     })
 }
 
@@ -291,7 +536,7 @@ pub fn persist_assemblyline_report(
     report: &crate::assemblyline::AssemblylineReport,
     directory: Option<&Path>,
     modes: &[StorageMode],
-) -> Result<Vec<PathBuf>> {
+) -> Result<Vec<PathBuf>, StorageError> {
     let mut stored = Vec::new();
     let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
 
@@ -299,11 +544,11 @@ pub fn persist_assemblyline_report(
         let base_dir = directory
             .map(Path::to_path_buf)
             .unwrap_or_else(|| PathBuf::from("reports"));
-        fs::create_dir_all(&base_dir)?;
+        fs::create_dir_all(&base_dir).map_err(|err| StorageError::from_io(&base_dir, err))?;
         let file_name = format!("assemblyline-{}.json", timestamp);
         let path = base_dir.join(&file_name);
         let content = serde_json::to_string_pretty(report)?;
-        fs::write(&path, content)?;
+        write_atomic(&path, content).map_err(|err| StorageError::from_io(&path, err))?;
         stored.push(path);
     }
 
@@ -312,48 +557,247 @@ pub fn persist_assemblyline_report(
             .map(Path::to_path_buf)
             .unwrap_or_else(|| PathBuf::from("verisimdb-data"));
         let hexad_dir = base_dir.join("hexads");
-        fs::create_dir_all(&hexad_dir)?;
+        fs::create_dir_all(&hexad_dir).map_err(|err| StorageError::from_io(&hexad_dir, err))?;
 
         let hexad = build_assemblyline_hexad(report)?;
         let path = hexad_dir.join(format!("{}.json", hexad.id));
         let payload = serde_json::to_string_pretty(&hexad)?;
-        fs::write(&path, payload)?;
+        write_atomic(&path, payload).map_err(|err| StorageError::from_io(&path, err))?;
         stored.push(path);
     }
 
     Ok(stored)
 }
 
-pub fn latest_reports(dir: &Path, count: usize) -> Result<Vec<PathBuf>> {
+/// Drop-in for `fs::write`: write a hidden sibling temp file, fsync it and rename it over
+/// `path`, so readers never see a half-written report.
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} has no file name", path.display()),
+        )
+    })?;
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let temp = dir.join(format!(
+        ".{}{}{}",
+        name.to_string_lossy(),
+        TEMP_MARKER,
+        std::process::id()
+    ));
+    let written = File::create(&temp).and_then(|mut file| {
+        file.write_all(contents.as_ref())?;
+        file.sync_all()
+    });
+    if let Err(err) = written.and_then(|_| fs::rename(&temp, path)) {
+        let _ = fs::remove_file(&temp);
+        return Err(err);
+    }
+    // The rename lives in the directory; sync it too where the platform allows.
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    }
+    Ok(())
+}
+
+/// Which stored reports [`latest_reports`] considers; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct ReportFilter {
+    pub kind: Option<ReportKind>,
+    /// Program the report is about: `assail_report.program_path` for assault runs, `target`
+    /// for the other kinds. A leading `./` is ignored.
+    pub program: Option<String>,
+    /// Recorded at or after.
+    pub since: Option<DateTime<Utc>>,
+    /// Recorded before.
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Run metadata read from a stored report.
+#[derive(Debug, Clone)]
+pub struct StoredReportMeta {
+    pub path: PathBuf,
+    /// `None` for files that are not one of the [`ReportKind`]s (assemblyline reports,
+    /// damaged files).
+    pub kind: Option<ReportKind>,
+    pub program: Option<String>,
+    /// `created_at` when the report has one, else the timestamp in the file name, else the
+    /// file's modification time.
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl ReportFilter {
+    pub fn matches(&self, meta: &StoredReportMeta) -> bool {
+        self.kind.is_none_or(|kind| meta.kind == Some(kind))
+            && self.program.as_deref().is_none_or(|program| {
+                meta.program.as_deref().map(normalize_program) == Some(normalize_program(program))
+            })
+            && self.since.is_none_or(|since| meta.recorded_at >= since)
+            && self.until.is_none_or(|until| meta.recorded_at < until)
+    }
+}
+
+fn normalize_program(program: &str) -> &str {
+    let program = program.strip_prefix("./").unwrap_or(program);
+    program.strip_suffix('/').unwrap_or(program)
+}
+
+/// Reports in `dir` and its per-kind subdirectories that match `filter`, oldest first.
+pub fn stored_reports(
+    dir: &Path,
+    filter: &ReportFilter,
+) -> Result<Vec<StoredReportMeta>, StorageError> {
     if !dir.exists() {
-        return Err(anyhow!(
-            "storage directory not found: {}",
-            dir.display()
-        ));
+        return Err(StorageError::DirectoryMissing(dir.to_path_buf()));
     }
+    let mut dirs = vec![dir.to_path_buf()];
+    dirs.extend(
+        [
+            ReportKind::Amuck,
+            ReportKind::Abduct,
+            ReportKind::Adjudicate,
+            ReportKind::Axial,
+        ]
+        .iter()
+        .map(|kind| dir.join(kind.name()))
+        .filter(|sub| sub.is_dir()),
+    );
 
-    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
-        .filter_map(|entry| entry.ok())
-        .map(|entry| entry.path())
-        .filter(|path| {
-            path.extension()
-                .and_then(|ext| ext.to_str())
-                .map(|ext| ext.eq_ignore_ascii_case("json"))
-                .unwrap_or(false)
-        })
-        .collect();
+    let mut reports = Vec::new();
+    for scanned in dirs {
+        for entry in fs::read_dir(&scanned)?.filter_map(|entry| entry.ok()) {
+            let path = entry.path();
+            let is_report = path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| {
+                        ["json", "yaml", "yml"]
+                            .iter()
+                            .any(|known| ext.eq_ignore_ascii_case(known))
+                    });
+            if !is_report {
+                continue;
+            }
+            let meta = read_meta(path);
+            if filter.matches(&meta) {
+                reports.push(meta);
+            }
+        }
+    }
+    reports.sort_by(|a, b| {
+        a.recorded_at
+            .cmp(&b.recorded_at)
+            .then_with(|| a.path.file_name().cmp(&b.path.file_name()))
+    });
+    Ok(reports)
+}
 
-    entries.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
-    if entries.len() < count {
-        return Err(anyhow!(
-            "not enough reports in {} (need {}, found {})",
-            dir.display(),
-            count,
-            entries.len()
-        ));
+fn read_meta(path: PathBuf) -> StoredReportMeta {
+    let value: Option<serde_json::Value> = fs::read_to_string(&path).ok().and_then(|content| {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content).ok(),
+            _ => serde_json::from_str(&content).ok(),
+        }
+    });
+    let value = value.unwrap_or_default();
+    let has = |key: &str| value.get(key).is_some();
+    let kind = if has("assail_report") && has("attack_results") {
+        Some(ReportKind::Assault)
+    } else if has("outcomes") && has("preset") {
+        Some(ReportKind::Amuck)
+    } else if has("workspace_dir") && has("dependency_scope") {
+        Some(ReportKind::Abduct)
+    } else if has("verdict") && has("totals") {
+        Some(ReportKind::Adjudicate)
+    } else if has("observed_runs") && has("signal_counts") {
+        Some(ReportKind::Axial)
+    } else {
+        None
+    };
+    let program = match kind {
+        Some(ReportKind::Assault) => value.pointer("/assail_report/program_path"),
+        Some(_) => value.get("target"),
+        None => None,
+    }
+    .and_then(|program| program.as_str())
+    .map(str::to_string);
+    let recorded_at = value
+        .get("created_at")
+        .and_then(|at| at.as_str())
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+        .map(|at| at.with_timezone(&Utc))
+        .or_else(|| timestamp_from_name(&path))
+        .unwrap_or_else(|| {
+            fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .map(DateTime::<Utc>::from)
+                .unwrap_or_else(|_| Utc::now())
+        });
+    StoredReportMeta {
+        path,
+        kind,
+        program,
+        recorded_at,
+    }
+}
+
+/// `<prefix>-20260131120000.<ext>`, as the storage modes and default output paths name files.
+fn timestamp_from_name(path: &Path) -> Option<DateTime<Utc>> {
+    let stem = path.file_stem()?.to_str()?;
+    let digits = stem.rsplit('-').next()?;
+    NaiveDateTime::parse_from_str(digits, "%Y%m%d%H%M%S")
+        .ok()
+        .map(|at| at.and_utc())
+}
+
+/// The newest `count` reports in `dir` matching `filter`, oldest first.
+pub fn latest_reports(
+    dir: &Path,
+    count: usize,
+    filter: &ReportFilter,
+) -> Result<Vec<PathBuf>, StorageError> {
+    let reports = stored_reports(dir, filter)?;
+    take_latest(dir, count, reports)
+}
+
+/// Like [`latest_reports`], but all of the same program: `filter.program`, or else the
+/// program of the newest matching report.
+pub fn latest_reports_of_one_program(
+    dir: &Path,
+    count: usize,
+    filter: &ReportFilter,
+) -> Result<Vec<PathBuf>, StorageError> {
+    let mut reports = stored_reports(dir, filter)?;
+    if filter.program.is_none() {
+        if let Some(program) = reports.last().and_then(|meta| meta.program.clone()) {
+            reports.retain(|meta| meta.program.as_deref() == Some(program.as_str()));
+        }
     }
-    let start = entries.len() - count;
-    Ok(entries[start..].to_vec())
+    take_latest(dir, count, reports)
+}
+
+fn take_latest(
+    dir: &Path,
+    count: usize,
+    reports: Vec<StoredReportMeta>,
+) -> Result<Vec<PathBuf>, StorageError> {
+    if reports.len() < count {
+        return Err(StorageError::NotEnoughReports {
+            dir: dir.to_path_buf(),
+            needed: count,
+            found: reports.len(),
+        });
+    }
+    let start = reports.len() - count;
+    Ok(reports[start..]
+        .iter()
+        .map(|meta| meta.path.clone())
+        .collect())
 }
 
 #[cfg(test)]
@@ -367,6 +811,145 @@ mod tests {
         assert_eq!(id.len(), 16);
     }
 
+    #[test]
+    fn atomic_write_replaces_without_leftovers() {
+        let dir = tempfile::TempDir::new().expect("tempdir should create");
+        let path = dir.path().join("report.json");
+        fs::write(&path, "old").unwrap();
+        write_atomic(&path, "{\"new\": true}").expect("atomic write");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"new\": true}");
+        let names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(names, ["report.json"]);
+    }
+
+    #[test]
+    fn persist_any_report_uses_kind_subdirectories() {
+        let dir = tempfile::TempDir::new().expect("tempdir should create");
+        let report = AmuckReport {
+            created_at: Utc::now().to_rfc3339(),
+            target: PathBuf::from("src/main.rs"),
+            source_spec: None,
+            preset: "dangerous".to_string(),
+            max_combinations: 1,
+            seed: 0,
+            output_dir: PathBuf::from("runtime/amuck"),
+            combinations_planned: 0,
+            combinations_run: 0,
+            baseline: None,
+            outcomes: Vec::new(),
+        };
+        let stored = persist_any_report(
+            &report,
+            Some(dir.path()),
+            &[StorageMode::Filesystem, StorageMode::VerisimDb],
+        )
+        .expect("persist should work");
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].parent(), Some(dir.path().join("amuck").as_path()));
+        assert_eq!(
+            stored[1].parent(),
+            Some(dir.path().join("hexads/amuck").as_path())
+        );
+
+        let hexad: PanicAttackHexad =
+            serde_json::from_str(&fs::read_to_string(&stored[1]).unwrap()).unwrap();
+        assert_eq!(hexad.report_kind, Some(ReportKind::Amuck));
+        assert_eq!(hexad.provenance.program_path, "src/main.rs");
+        assert!(hexad.id.starts_with("pa-amuck-"));
+
+        let assaults = ReportFilter {
+            kind: Some(ReportKind::Assault),
+            ..ReportFilter::default()
+        };
+        assert!(latest_reports(dir.path(), 1, &assaults).is_err());
+        let amuck = ReportFilter {
+            kind: Some(ReportKind::Amuck),
+            ..ReportFilter::default()
+        };
+        assert_eq!(
+            latest_reports(dir.path(), 1, &amuck).unwrap(),
+            vec![stored[0].clone()]
+        );
+        assert_eq!(ReportKind::from_str("audience"), Some(ReportKind::Axial));
+    }
+
+    #[test]
+    fn latest_reports_filters_by_kind_program_and_date() {
+        let dir = tempfile::TempDir::new().expect("tempdir should create");
+        let assault = |name: &str, program: &str| {
+            let report = serde_json::json!({
+                "assail_report": { "program_path": program },
+                "attack_results": [],
+            });
+            fs::write(dir.path().join(name), report.to_string()).unwrap();
+        };
+        assault("panic-attack-20260301100000.json", "./app");
+        assault("panic-attack-20260302100000.json", "other");
+        assault("panic-attack-20260303100000.json", "app");
+        assault("panic-attack-20260304100000.json", "other");
+        fs::write(
+            dir.path().join("zz-adjudicate.json"),
+            r#"{"created_at": "2026-03-05T10:00:00Z", "verdict": "pass", "totals": {}}"#,
+        )
+        .unwrap();
+        fs::write(dir.path().join("assemblyline-20260306100000.json"), "{}").unwrap();
+
+        let names = |paths: Vec<PathBuf>| -> Vec<String> {
+            paths
+                .iter()
+                .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+                .collect()
+        };
+        let all = ReportFilter::default();
+        assert_eq!(
+            names(latest_reports(dir.path(), 2, &all).unwrap()),
+            ["zz-adjudicate.json", "assemblyline-20260306100000.json"]
+        );
+
+        let assaults = ReportFilter {
+            kind: Some(ReportKind::Assault),
+            ..ReportFilter::default()
+        };
+        assert_eq!(
+            names(latest_reports_of_one_program(dir.path(), 2, &assaults).unwrap()),
+            [
+                "panic-attack-20260302100000.json",
+                "panic-attack-20260304100000.json"
+            ]
+        );
+        let app = ReportFilter {
+            program: Some("app".to_string()),
+            ..assaults.clone()
+        };
+        assert_eq!(
+            names(latest_reports_of_one_program(dir.path(), 2, &app).unwrap()),
+            [
+                "panic-attack-20260301100000.json",
+                "panic-attack-20260303100000.json"
+            ]
+        );
+
+        let window = ReportFilter {
+            since: Some("2026-03-02T00:00:00Z".parse().unwrap()),
+            until: Some("2026-03-04T00:00:00Z".parse().unwrap()),
+            ..assaults
+        };
+        assert_eq!(
+            names(latest_reports(dir.path(), 2, &window).unwrap()),
+            [
+                "panic-attack-20260302100000.json",
+                "panic-attack-20260303100000.json"
+            ]
+        );
+        assert!(matches!(
+            latest_reports(dir.path(), 3, &window),
+            Err(StorageError::NotEnoughReports { found: 2, .. })
+        ));
+    }
+
     #[test]
     fn test_storage_mode_parsing() {
         assert_eq!(
@@ -0,0 +1,503 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Query language over stored assault runs (`panic-attack reports query`).
+//!
+//! A query is one or more `field op value` clauses joined by `AND`, for example
+//! `crashes > 0 AND axis = memory AND since = 7d`. Runs are read from a filesystem store
+//! (`panic-attack-<timestamp>.json|yaml`) and, when present, its VerisimDB `hexads/` directory.
+//!
+//! Fields:
+//! - numeric (`=`, `!=`, `>`, `>=`, `<`, `<=`): `crashes`, `signatures`, `score`,
+//!   `weak_points`, `critical`, `seed`
+//! - text (`=`, `!=`, `~` for substring): `language`, `program`
+//! - axes (`=`, `!=`): `axis` (attacked and not skipped), `crashed_axis` (crashed on that axis)
+//! - time (`=` / `>=` for "at or after", `<` for "before"): `since`, given as an age
+//!   (`30m`, `12h`, `7d`, `2w`) or a date (`2026-01-31`, RFC 3339)
+
+use crate::error::StorageError;
+use crate::report;
+use crate::schema;
+use crate::types::{AssaultReport, AttackAxis, Severity};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FIELDS: &[&str] = &[
+    "crashes",
+    "signatures",
+    "score",
+    "weak_points",
+    "critical",
+    "seed",
+    "language",
+    "program",
+    "axis",
+    "crashed_axis",
+    "since",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Contains,
+}
+
+impl Op {
+    fn parse(token: &str) -> Option<Self> {
+        Some(match token {
+            "=" => Op::Eq,
+            "!=" => Op::Ne,
+            ">" => Op::Gt,
+            ">=" => Op::Ge,
+            "<" => Op::Lt,
+            "<=" => Op::Le,
+            "~" => Op::Contains,
+            _ => return None,
+        })
+    }
+
+    fn compare(self, left: f64, right: f64) -> bool {
+        match self {
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right,
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Contains => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Clause {
+    Number {
+        field: &'static str,
+        op: Op,
+        value: f64,
+    },
+    Text {
+        field: &'static str,
+        op: Op,
+        value: String,
+    },
+    Axis {
+        crashed: bool,
+        op: Op,
+        axis: AttackAxis,
+    },
+    Since {
+        op: Op,
+        cutoff: Cutoff,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Cutoff {
+    Age(Duration),
+    At(DateTime<Utc>),
+}
+
+impl Cutoff {
+    fn resolve(self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Cutoff::Age(age) => now - age,
+            Cutoff::At(at) => at,
+        }
+    }
+}
+
+/// Parsed query; every clause must match.
+#[derive(Debug, Clone)]
+pub struct Query {
+    clauses: Vec<Clause>,
+}
+
+impl Query {
+    pub fn parse(input: &str) -> Result<Self, StorageError> {
+        parse_clauses(input)
+            .map(|clauses| Self { clauses })
+            .map_err(|err| StorageError::InvalidQuery(format!("{:#}", err)))
+    }
+
+    pub fn matches(&self, run: &StoredRun, now: DateTime<Utc>) -> bool {
+        self.clauses
+            .iter()
+            .all(|clause| clause_matches(clause, run, now))
+    }
+}
+
+fn parse_clauses(input: &str) -> Result<Vec<Clause>> {
+    let splitter = Regex::new(r"(?i)\s+and\s+").expect("valid AND splitter");
+    let clause = Regex::new(r"^\s*([A-Za-z_]+)\s*(>=|<=|!=|=|>|<|~)\s*(.+?)\s*$")
+        .expect("valid clause pattern");
+
+    let mut clauses = Vec::new();
+    for part in splitter.split(input.trim()) {
+        if part.trim().is_empty() {
+            continue;
+        }
+        let captures = clause.captures(part).ok_or_else(|| {
+            anyhow!(
+                "cannot parse `{}`; expected FIELD OP VALUE, e.g. `crashes > 0`",
+                part.trim()
+            )
+        })?;
+        let name = captures[1].to_ascii_lowercase();
+        let op = Op::parse(&captures[2]).expect("operator matched by pattern");
+        let value = captures[3].trim_matches(|c| c == '"' || c == '\'');
+        clauses.push(parse_clause(&name, op, value)?);
+    }
+    if clauses.is_empty() {
+        return Err(anyhow!("empty query"));
+    }
+    Ok(clauses)
+}
+
+fn parse_clause(name: &str, op: Op, value: &str) -> Result<Clause> {
+    let field = FIELDS
+        .iter()
+        .copied()
+        .find(|field| *field == name)
+        .ok_or_else(|| {
+            let hint = schema::suggest(name, FIELDS)
+                .map(|hint| format!(" (did you mean `{}`?)", hint))
+                .unwrap_or_default();
+            anyhow!(
+                "unknown field `{}`{}; expected one of {}",
+                name,
+                hint,
+                FIELDS.join(", ")
+            )
+        })?;
+    let unsupported = || anyhow!("operator not supported for `{}`", field);
+
+    match field {
+        "language" | "program" => {
+            if !matches!(op, Op::Eq | Op::Ne | Op::Contains) {
+                return Err(unsupported());
+            }
+            Ok(Clause::Text {
+                field,
+                op,
+                value: value.to_ascii_lowercase(),
+            })
+        }
+        "axis" | "crashed_axis" => {
+            if !matches!(op, Op::Eq | Op::Ne) {
+                return Err(unsupported());
+            }
+            let axis = AttackAxis::all()
+                .into_iter()
+                .find(|axis| axis_name(axis.clone()) == value.to_ascii_lowercase())
+                .ok_or_else(|| anyhow!("unknown axis `{}`", value))?;
+            Ok(Clause::Axis {
+                crashed: field == "crashed_axis",
+                op,
+                axis,
+            })
+        }
+        "since" => {
+            if !matches!(op, Op::Eq | Op::Ge | Op::Lt) {
+                return Err(unsupported());
+            }
+            Ok(Clause::Since {
+                op,
+                cutoff: parse_cutoff(value)?,
+            })
+        }
+        _ => {
+            if op == Op::Contains {
+                return Err(unsupported());
+            }
+            let value = value
+                .parse::<f64>()
+                .map_err(|_| anyhow!("`{}` expects a number, got `{}`", field, value))?;
+            Ok(Clause::Number { field, op, value })
+        }
+    }
+}
+
+fn parse_cutoff(value: &str) -> Result<Cutoff> {
+    let unit_at = value.char_indices().last().map_or(0, |(index, _)| index);
+    let (amount, unit) = value.split_at(unit_at);
+    if let Ok(amount) = amount.parse::<i64>() {
+        let age = match unit {
+            "m" => Some(Duration::minutes(amount)),
+            "h" => Some(Duration::hours(amount)),
+            "d" => Some(Duration::days(amount)),
+            "w" => Some(Duration::weeks(amount)),
+            _ => None,
+        };
+        if let Some(age) = age {
+            return Ok(Cutoff::Age(age));
+        }
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Cutoff::At(at.with_timezone(&Utc)));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let start = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+        return Ok(Cutoff::At(start.and_utc()));
+    }
+    Err(anyhow!(
+        "`since` expects an age like 7d/12h/30m/2w or a date, got `{}`",
+        value
+    ))
+}
+
+/// An age (`7d`, `12h`, `30m`, `2w`, counted back from `now`) or a date / RFC 3339 time.
+pub fn parse_time(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    parse_cutoff(value)
+        .map(|cutoff| cutoff.resolve(now))
+        .map_err(|_| {
+            anyhow!(
+                "expected an age like 7d/12h/30m/2w or a date, got `{}`",
+                value
+            )
+        })
+}
+
+fn clause_matches(clause: &Clause, run: &StoredRun, now: DateTime<Utc>) -> bool {
+    let report = &run.report;
+    match clause {
+        Clause::Number { field, op, value } => {
+            let actual = match *field {
+                "crashes" => report.total_crashes as f64,
+                "signatures" => report.total_signatures as f64,
+                "score" => report.overall_assessment.robustness_score,
+                "weak_points" => report.assail_report.weak_points.len() as f64,
+                "critical" => report
+                    .assail_report
+                    .weak_points
+                    .iter()
+                    .filter(|wp| wp.severity == Severity::Critical)
+                    .count() as f64,
+                "seed" => match report.seed {
+                    Some(seed) => seed as f64,
+                    None => return false,
+                },
+                _ => return false,
+            };
+            op.compare(actual, *value)
+        }
+        Clause::Text { field, op, value } => {
+            let actual = match *field {
+                "language" => format!("{:?}", report.assail_report.language),
+                _ => report.assail_report.program_path.display().to_string(),
+            }
+            .to_ascii_lowercase();
+            match op {
+                Op::Eq => actual == *value,
+                Op::Ne => actual != *value,
+                _ => actual.contains(value.as_str()),
+            }
+        }
+        Clause::Axis { crashed, op, axis } => {
+            let present = report.attack_results.iter().any(|result| {
+                result.axis == *axis && !result.skipped && (!crashed || !result.crashes.is_empty())
+            });
+            (*op == Op::Eq) == present
+        }
+        Clause::Since { op, cutoff } => {
+            let cutoff = cutoff.resolve(now);
+            match op {
+                Op::Lt => run.recorded_at < cutoff,
+                _ => run.recorded_at >= cutoff,
+            }
+        }
+    }
+}
+
+fn axis_name(axis: AttackAxis) -> String {
+    format!("{:?}", axis).to_ascii_lowercase()
+}
+
+/// A report found in a store, with the identity and time it was recorded under.
+#[derive(Debug, Clone)]
+pub struct StoredRun {
+    pub id: String,
+    pub path: PathBuf,
+    pub recorded_at: DateTime<Utc>,
+    pub report: AssaultReport,
+}
+
+/// One matching run as printed by `reports query`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSummary {
+    pub id: String,
+    pub path: PathBuf,
+    pub recorded_at: String,
+    pub program: PathBuf,
+    pub language: String,
+    pub crashes: usize,
+    pub signatures: usize,
+    pub robustness_score: f64,
+    pub crashed_axes: Vec<String>,
+}
+
+impl From<&StoredRun> for RunSummary {
+    fn from(run: &StoredRun) -> Self {
+        let report = &run.report;
+        let mut crashed_axes: Vec<String> = Vec::new();
+        for result in &report.attack_results {
+            let name = axis_name(result.axis.clone());
+            if !result.crashes.is_empty() && !crashed_axes.contains(&name) {
+                crashed_axes.push(name);
+            }
+        }
+        Self {
+            id: run.id.clone(),
+            path: run.path.clone(),
+            recorded_at: run.recorded_at.to_rfc3339(),
+            program: report.assail_report.program_path.clone(),
+            language: format!("{:?}", report.assail_report.language),
+            crashes: report.total_crashes,
+            signatures: report.total_signatures,
+            robustness_score: report.overall_assessment.robustness_score,
+            crashed_axes,
+        }
+    }
+}
+
+/// Load every assault report in a filesystem store and its `hexads/` directory, oldest first.
+/// Files that are not assault reports (other formats, other report kinds) are skipped.
+pub fn load_runs(dir: &Path) -> Result<Vec<StoredRun>, StorageError> {
+    if !dir.is_dir() {
+        return Err(StorageError::DirectoryMissing(dir.to_path_buf()));
+    }
+    let mut runs = Vec::new();
+    for path in sorted_files(dir)? {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            continue;
+        };
+        if !matches!(ext, "json" | "yaml" | "yml") {
+            continue;
+        }
+        let Ok(report) = report::load_report(&path) else {
+            continue;
+        };
+        let id = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let recorded_at = timestamp_from_name(&id).unwrap_or_else(|| modified_at(&path));
+        runs.push(StoredRun {
+            id,
+            path,
+            recorded_at,
+            report,
+        });
+    }
+
+    let hexad_dir = dir.join("hexads");
+    if hexad_dir.is_dir() {
+        for path in sorted_files(&hexad_dir)? {
+            if let Some(run) = load_hexad(&path)? {
+                runs.push(run);
+            }
+        }
+    }
+    runs.sort_by_key(|run| run.recorded_at);
+    Ok(runs)
+}
+
+/// Runs in `dir` matching `query`, oldest first.
+pub fn query_runs(dir: &Path, query: &Query) -> Result<Vec<RunSummary>, StorageError> {
+    let now = Utc::now();
+    Ok(load_runs(dir)?
+        .iter()
+        .filter(|run| query.matches(run, now))
+        .map(RunSummary::from)
+        .collect())
+}
+
+fn load_hexad(path: &Path) -> Result<Option<StoredRun>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("reading hexad {}", path.display()))?;
+    let Ok(hexad) = serde_json::from_str::<super::PanicAttackHexad>(&content) else {
+        return Ok(None);
+    };
+    let Ok(report) = serde_json::from_value::<AssaultReport>(hexad.document) else {
+        return Ok(None);
+    };
+    let recorded_at = DateTime::parse_from_rfc3339(&hexad.created_at)
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|_| modified_at(path));
+    Ok(Some(StoredRun {
+        id: hexad.id,
+        path: path.to_path_buf(),
+        recorded_at,
+        report,
+    }))
+}
+
+fn sorted_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("reading {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// `panic-attack-20260131120000` as written by the filesystem storage mode.
+fn timestamp_from_name(stem: &str) -> Option<DateTime<Utc>> {
+    let digits = stem.strip_prefix("panic-attack-")?;
+    NaiveDateTime::parse_from_str(digits, "%Y%m%d%H%M%S")
+        .ok()
+        .map(|at| at.and_utc())
+}
+
+fn modified_at(path: &Path) -> DateTime<Utc> {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .map(DateTime::<Utc>::from)
+        .unwrap_or_else(|_| Utc::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_bad_clauses() {
+        assert!(Query::parse("crashes > 0 and axis = memory AND since = 7d").is_ok());
+        let err = Query::parse("crashez > 0").unwrap_err();
+        assert_eq!(err.code(), "storage.invalid_query");
+        let err = err.to_string();
+        assert!(err.contains("did you mean `crashes`"), "{}", err);
+        assert!(Query::parse("axis > memory").is_err());
+        assert!(Query::parse("axis = gpu").is_err());
+        assert!(Query::parse("score ~ 5").is_err());
+        assert!(Query::parse("since = yesterday").is_err());
+        assert!(Query::parse("   ").is_err());
+    }
+
+    #[test]
+    fn test_cutoffs() {
+        let now = Utc::now();
+        match parse_cutoff("7d").unwrap() {
+            Cutoff::Age(age) => assert_eq!(age, Duration::days(7)),
+            other => panic!("unexpected {:?}", other),
+        }
+        let at = parse_cutoff("2026-01-31").unwrap().resolve(now);
+        assert_eq!(at.to_rfc3339(), "2026-01-31T00:00:00+00:00");
+        assert_eq!(
+            timestamp_from_name("panic-attack-20260131120000").map(|at| at.to_rfc3339()),
+            Some("2026-01-31T12:00:00+00:00".to_string())
+        );
+        assert_eq!(timestamp_from_name("custom-name"), None);
+    }
+}
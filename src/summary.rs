@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! `--summary-out` exit summary
+//!
+//! A small JSON file written when any subcommand finishes, successfully or not, so wrapper
+//! scripts can branch on the outcome without parsing full reports. It is built from the
+//! same fields as the `--porcelain` summary: numeric fields become `counts`, written files
+//! become `reports` and everything else lands in `details`. Fields are only added within a
+//! [`VERSION`].
+
+use crate::storage;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub const VERSION: u32 = 1;
+
+/// Where a bare `--summary-out` writes.
+pub const DEFAULT_PATH: &str = "reports/summary.json";
+
+/// Porcelain keys that name a file the command wrote.
+const REPORT_KEYS: &[&str] = &["report", "export", "sarif", "attestation", "summary"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExitSummary {
+    pub summary_version: u32,
+    pub command: String,
+    pub run_id: String,
+    /// `ok` or `error`.
+    pub status: String,
+    /// The command's own verdict (adjudicate, pipeline), otherwise `error`, `crashed` or `ok`.
+    pub verdict: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub started_at: String,
+    pub finished_at: String,
+    pub duration_ms: u64,
+    pub reports: Vec<String>,
+    pub counts: BTreeMap<String, Value>,
+    /// Remaining fields; a key recorded more than once holds an array.
+    pub details: BTreeMap<String, Value>,
+}
+
+impl ExitSummary {
+    /// Summarise a finished command from its recorded porcelain `fields`; `error` is the
+    /// code and message of a failure.
+    pub fn from_fields(
+        command: &str,
+        run_id: &str,
+        started: DateTime<Utc>,
+        finished: DateTime<Utc>,
+        fields: Vec<(String, String)>,
+        error: Option<(&str, String)>,
+    ) -> Self {
+        let mut summary = Self {
+            summary_version: VERSION,
+            command: command.to_string(),
+            run_id: run_id.to_string(),
+            status: if error.is_some() { "error" } else { "ok" }.to_string(),
+            verdict: String::new(),
+            error_code: error.as_ref().map(|(code, _)| code.to_string()),
+            error: error.map(|(_, message)| message),
+            started_at: started.to_rfc3339(),
+            finished_at: finished.to_rfc3339(),
+            duration_ms: (finished - started).num_milliseconds().max(0) as u64,
+            reports: Vec::new(),
+            counts: BTreeMap::new(),
+            details: BTreeMap::new(),
+        };
+        let mut verdict = None;
+        for (key, value) in fields {
+            match key.as_str() {
+                "verdict" => verdict = Some(value),
+                // The pipeline's run id names its output directory; prefer it.
+                "run_id" => summary.run_id = value,
+                key if REPORT_KEYS.contains(&key) => summary.reports.push(value),
+                _ => match number(&value) {
+                    Some(number) => {
+                        summary.counts.insert(key, number);
+                    }
+                    None => match summary.details.get_mut(&key) {
+                        Some(Value::Array(values)) => values.push(Value::String(value)),
+                        Some(first) => {
+                            *first = Value::Array(vec![first.take(), Value::String(value)]);
+                        }
+                        None => {
+                            summary.details.insert(key, Value::String(value));
+                        }
+                    },
+                },
+            }
+        }
+        let crashed = summary
+            .counts
+            .get("crashes")
+            .and_then(Value::as_u64)
+            .is_some_and(|crashes| crashes > 0);
+        summary.verdict = match verdict {
+            Some(verdict) => verdict,
+            None if summary.status == "error" => "error".to_string(),
+            None if crashed => "crashed".to_string(),
+            None => "ok".to_string(),
+        };
+        summary
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        storage::write_atomic(path, json + "\n")
+            .with_context(|| format!("writing summary {}", path.display()))
+    }
+}
+
+fn number(value: &str) -> Option<Value> {
+    if let Ok(n) = value.parse::<u64>() {
+        return Some(n.into());
+    }
+    if let Ok(n) = value.parse::<i64>() {
+        return Some(n.into());
+    }
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .and_then(serde_json::Number::from_f64)
+        .map(Value::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn summary_splits_counts_reports_and_details() {
+        let started = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let finished = started + chrono::Duration::milliseconds(1500);
+        let summary = ExitSummary::from_fields(
+            "assault",
+            "20261016T120000-42",
+            started,
+            finished,
+            fields(&[
+                ("language", "rust"),
+                ("crashes", "2"),
+                ("robustness_score", "87.5"),
+                ("report", "reports/a.json"),
+                ("report", "reports/a.md"),
+                ("damaged_file", "x.json"),
+                ("damaged_file", "y.json"),
+            ]),
+            None,
+        );
+        assert_eq!(summary.status, "ok");
+        assert_eq!(summary.verdict, "crashed");
+        assert_eq!(summary.duration_ms, 1500);
+        assert_eq!(summary.reports, ["reports/a.json", "reports/a.md"]);
+        assert_eq!(summary.counts["crashes"], 2);
+        assert_eq!(summary.counts["robustness_score"], 87.5);
+        assert_eq!(summary.details["language"], "rust");
+        assert_eq!(
+            summary.details["damaged_file"],
+            serde_json::json!(["x.json", "y.json"])
+        );
+
+        let failed = ExitSummary::from_fields(
+            "pipeline",
+            "20261016T120000-42",
+            started,
+            started,
+            fields(&[("run_id", "nightly"), ("verdict", "fail")]),
+            Some(("attack.target_missing", "gone".into())),
+        );
+        assert_eq!(failed.run_id, "nightly");
+        assert_eq!(failed.verdict, "fail");
+        assert_eq!(failed.error_code.as_deref(), Some("attack.target_missing"));
+        let json = serde_json::to_value(&failed).unwrap();
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["summary_version"], 1);
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Syscall summaries of exec runs (`--trace-syscalls`)
+//!
+//! Amuck and abduct exec programs can run under `strace -f`, which follows
+//! every process the program starts. The trace is reduced to a summary stored
+//! in the execution outcome as `syscall_summary`:
+//! - `files_opened`: paths opened successfully;
+//! - `network_contacts`: addresses passed to `connect`, whether or not the
+//!   connection succeeded (`host:port`, or `unix:<path>`);
+//! - `processes_spawned`: programs started with `execve`, the exec program
+//!   itself first.
+//!
+//! Each list keeps its first [`MAX_ENTRIES`] distinct entries. `axial` turns
+//! internet contacts and extra processes into signals.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Distinct entries kept per list.
+pub const MAX_ENTRIES: usize = 200;
+
+/// Syscalls traced; `?` skips names the architecture does not have.
+const TRACED: &str = "trace=?open,openat,?openat2,?creat,connect,execve,?execveat";
+
+static TRACE_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyscallSummary {
+    #[serde(default)]
+    pub files_opened: Vec<String>,
+    #[serde(default)]
+    pub network_contacts: Vec<String>,
+    #[serde(default)]
+    pub processes_spawned: Vec<String>,
+    /// Some list hit [`MAX_ENTRIES`] and dropped entries.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl SyscallSummary {
+    /// Contacts with IPv4 or IPv6 addresses, local Unix sockets left out.
+    pub fn internet_contacts(&self) -> impl Iterator<Item = &String> {
+        self.network_contacts
+            .iter()
+            .filter(|contact| !contact.starts_with("unix:"))
+    }
+
+    /// Processes started beyond the exec program itself.
+    pub fn extra_processes(&self) -> &[String] {
+        self.processes_spawned.get(1..).unwrap_or(&[])
+    }
+
+    fn push(list: &mut Vec<String>, truncated: &mut bool, entry: String) {
+        if list.contains(&entry) {
+            return;
+        }
+        if list.len() >= MAX_ENTRIES {
+            *truncated = true;
+            return;
+        }
+        list.push(entry);
+    }
+}
+
+/// An exec command rewritten to run under strace.
+#[derive(Debug)]
+pub struct Traced {
+    pub program: String,
+    pub args: Vec<String>,
+    trace_file: PathBuf,
+}
+
+impl Traced {
+    pub fn new(program: &str, args: &[String]) -> Traced {
+        // strace writes the trace itself, possibly as a `--run-as` account, so it goes
+        // to the shared temp directory rather than the output directory.
+        let trace_file = std::env::temp_dir().join(format!(
+            "panic-attack-strace-{}-{}.txt",
+            std::process::id(),
+            TRACE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut traced_args = vec![
+            "-f".to_string(),
+            "-qq".to_string(),
+            "-s".to_string(),
+            "4096".to_string(),
+            "-e".to_string(),
+            TRACED.to_string(),
+            "-o".to_string(),
+            trace_file.to_string_lossy().to_string(),
+            "--".to_string(),
+            program.to_string(),
+        ];
+        traced_args.extend(args.iter().cloned());
+        Traced {
+            program: "strace".to_string(),
+            args: traced_args,
+            trace_file,
+        }
+    }
+
+    /// Summarize the trace once the command has exited, removing the trace file.
+    pub fn finish(self) -> Option<SyscallSummary> {
+        let trace = fs::read_to_string(&self.trace_file).ok()?;
+        let _ = fs::remove_file(&self.trace_file);
+        Some(summarize(&trace))
+    }
+}
+
+/// Check that strace can be run before a campaign depends on it.
+pub fn ensure_available() -> Result<()> {
+    let found = Command::new("strace")
+        .arg("-V")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if found {
+        Ok(())
+    } else {
+        Err(anyhow!("--trace-syscalls needs strace on PATH"))
+    }
+}
+
+/// Summarize strace output (`-f`, so lines may start with a pid).
+pub fn summarize(trace: &str) -> SyscallSummary {
+    let mut summary = SyscallSummary::default();
+    // Calls interrupted by another process's line, keyed by pid.
+    let mut unfinished: HashMap<&str, String> = HashMap::new();
+    for line in trace.lines() {
+        let (pid, call) = match line.split_once(' ') {
+            Some((pid, rest)) if pid.bytes().all(|b| b.is_ascii_digit()) => (pid, rest.trim()),
+            _ => ("", line.trim()),
+        };
+        if let Some(start) = call.strip_suffix("<unfinished ...>") {
+            unfinished.insert(pid, start.to_string());
+            continue;
+        }
+        let joined;
+        let call = match call.strip_prefix("<... ") {
+            Some(resumed) => {
+                let rest = resumed.split_once(" resumed>").map_or("", |(_, rest)| rest);
+                joined = format!("{}{}", unfinished.remove(pid).unwrap_or_default(), rest);
+                joined.as_str()
+            }
+            None => call,
+        };
+        record(&mut summary, call);
+    }
+    summary
+}
+
+fn record(summary: &mut SyscallSummary, call: &str) {
+    let Some((name, rest)) = call.split_once('(') else {
+        return;
+    };
+    let succeeded = call
+        .rsplit_once(") = ")
+        .is_some_and(|(_, ret)| !ret.starts_with('-') && !ret.starts_with('?'));
+    let SyscallSummary {
+        files_opened,
+        network_contacts,
+        processes_spawned,
+        truncated,
+    } = summary;
+    match name {
+        "open" | "openat" | "openat2" | "creat" if succeeded => {
+            if let Some(path) = first_string(rest) {
+                SyscallSummary::push(files_opened, truncated, path);
+            }
+        }
+        "execve" | "execveat" if succeeded => {
+            if let Some(path) = first_string(rest) {
+                SyscallSummary::push(processes_spawned, truncated, path);
+            }
+        }
+        // Attempts count as contacts too: under --no-network every connect fails.
+        "connect" => {
+            if let Some(address) = socket_address(rest) {
+                SyscallSummary::push(network_contacts, truncated, address);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// First quoted string argument, unescaped enough for paths.
+fn first_string(args: &str) -> Option<String> {
+    let start = args.find('"')? + 1;
+    let mut value = String::new();
+    let mut chars = args[start..].chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => return Some(value),
+            '\\' => value.extend(chars.next()),
+            _ => value.push(ch),
+        }
+    }
+    None
+}
+
+/// `host:port` or `unix:<path>` from a `connect` sockaddr.
+fn socket_address(args: &str) -> Option<String> {
+    let field = |key: &str| {
+        let start = args.find(key)? + key.len();
+        let rest = &args[start..];
+        let end = rest.find([',', '}', ')']).unwrap_or(rest.len());
+        Some(rest[..end].trim_matches('"').to_string())
+    };
+    if args.contains("AF_UNIX") {
+        return field("sun_path=").map(|path| format!("unix:{}", path.trim_start_matches('@')));
+    }
+    let port = field("htons(")?;
+    if args.contains("AF_INET6") {
+        let host = field("inet_pton(AF_INET6, ")?;
+        Some(format!("[{}]:{}", host, port))
+    } else if args.contains("AF_INET") {
+        let host = field("inet_addr(")?;
+        Some(format!("{}:{}", host, port))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_follows_processes_and_resumed_calls() {
+        let trace = r#"4100 execve("/bin/sh", ["sh", "-c", "curl http://x"], 0x7ffd /* 20 vars */) = 0
+4100 openat(AT_FDCWD, "/etc/ld.so.cache", O_RDONLY|O_CLOEXEC) = 3
+4100 openat(AT_FDCWD, "/nope", O_RDONLY) = -1 ENOENT (No such file or directory)
+4101 execve("/usr/bin/curl", ["curl", "http://x"], 0x55d0 /* 20 vars */) = 0
+4101 connect(5, {sa_family=AF_UNIX, sun_path="/var/run/nscd/socket"}, 110) = -1 ENOENT (No such file or directory)
+4101 connect(6, {sa_family=AF_INET, sin_port=htons(80), sin_addr=inet_addr("93.184.216.34")}, 16 <unfinished ...>
+4100 openat(AT_FDCWD, "/tmp/out \"1\"", O_WRONLY|O_CREAT, 0644) = 4
+4101 <... connect resumed>) = -1 ENETUNREACH (Network is unreachable)
+4101 connect(7, {sa_family=AF_INET6, sin6_port=htons(443), sin6_flowinfo=htonl(0), inet_pton(AF_INET6, "::1", &sin6_addr), sin6_scope_id=0}, 28) = 0
+"#;
+        let summary = summarize(trace);
+        assert_eq!(summary.files_opened, ["/etc/ld.so.cache", "/tmp/out \"1\""]);
+        assert_eq!(summary.processes_spawned, ["/bin/sh", "/usr/bin/curl"]);
+        assert_eq!(
+            summary.network_contacts,
+            ["unix:/var/run/nscd/socket", "93.184.216.34:80", "[::1]:443"]
+        );
+        assert_eq!(summary.internet_contacts().count(), 2);
+        assert_eq!(summary.extra_processes(), ["/usr/bin/curl"]);
+        assert!(!summary.truncated);
+
+        let traced = Traced::new("sh", &["-c".to_string(), "true".to_string()]);
+        assert_eq!(traced.program, "strace");
+        assert_eq!(traced.args[traced.args.len() - 3..], ["sh", "-c", "true"]);
+    }
+}
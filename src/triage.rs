@@ -0,0 +1,280 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Triage annotations: who is looking at which finding, and what they decided
+//!
+//! `panic-attack annotate REPORT --finding ID --state investigating` records a
+//! state change in a triage file next to the report, shared by every report in
+//! that directory. Findings are keyed by IDs that survive reruns: weak points
+//! by SARIF rule and location (`PA004@src/ffi.rs:42`), crashes by their crash
+//! bucket (`memory:exit-101`), so an annotation made on one run still applies
+//! to the same finding in the next one and shows up in `diff`, the TUI and the
+//! GUI.
+
+use crate::attack::explore::crash_bucket;
+use crate::report::sarif::rule_id;
+use crate::storage::write_atomic;
+use crate::types::*;
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Triage file, in the directory of the report it annotates.
+pub const TRIAGE_FILE: &str = ".panic-attack-triage.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+#[value(rename_all = "kebab-case")]
+pub enum TriageState {
+    Investigating,
+    Confirmed,
+    WontFix,
+    FalsePositive,
+    Fixed,
+}
+
+impl TriageState {
+    pub fn label(self) -> &'static str {
+        match self {
+            TriageState::Investigating => "investigating",
+            TriageState::Confirmed => "confirmed",
+            TriageState::WontFix => "won't fix",
+            TriageState::FalsePositive => "false positive",
+            TriageState::Fixed => "fixed",
+        }
+    }
+}
+
+/// One state change of a finding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub state: TriageState,
+    pub author: String,
+    /// RFC 3339 time of the change.
+    pub at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// File name of the report the finding was annotated on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TriageStore {
+    /// Annotations per finding ID, oldest first; the last one is the current state.
+    #[serde(default)]
+    pub findings: BTreeMap<String, Vec<Annotation>>,
+}
+
+impl TriageStore {
+    /// Triage file shared by the reports in `report_path`'s directory.
+    pub fn path_beside(report_path: &Path) -> PathBuf {
+        report_path
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(TRIAGE_FILE)
+    }
+
+    /// Load a triage file, starting empty when it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("reading triage file {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("parsing triage file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write_atomic(path, json).with_context(|| format!("writing triage file {}", path.display()))
+    }
+
+    /// Record a state change for `finding`, which must be one of `report`'s findings.
+    pub fn annotate(
+        &mut self,
+        report: &AssaultReport,
+        finding: &str,
+        annotation: Annotation,
+    ) -> Result<()> {
+        if !finding_ids(report).iter().any(|(id, _)| id == finding) {
+            return Err(anyhow!(
+                "no finding `{}` in the report; `annotate --list` shows the IDs",
+                finding
+            ));
+        }
+        self.findings
+            .entry(finding.to_string())
+            .or_default()
+            .push(annotation);
+        Ok(())
+    }
+
+    /// Current annotation of `finding`, if any.
+    pub fn current(&self, finding: &str) -> Option<&Annotation> {
+        self.findings
+            .get(finding)
+            .and_then(|history| history.last())
+    }
+
+    /// One line per annotated finding of `report`, e.g.
+    /// `PA004@src/ffi.rs:42: investigating by alice (2026-03-02): checking bounds`.
+    pub fn summary_lines(&self, report: &AssaultReport) -> Vec<String> {
+        finding_ids(report)
+            .into_iter()
+            .filter_map(|(id, _)| {
+                let annotation = self.current(&id)?;
+                Some(format!("{}: {}", id, annotation.describe()))
+            })
+            .collect()
+    }
+}
+
+impl Annotation {
+    /// `state by author (date)[: comment]`.
+    pub fn describe(&self) -> String {
+        let date = self.at.get(..10).unwrap_or(&self.at);
+        let mut line = format!("{} by {} ({})", self.state.label(), self.author, date);
+        if let Some(comment) = &self.comment {
+            line.push_str(": ");
+            line.push_str(comment);
+        }
+        line
+    }
+}
+
+/// Stable ID of a weak point: its SARIF rule and location, e.g. `PA004@src/ffi.rs:42`.
+pub fn weak_point_id(point: &WeakPoint) -> String {
+    format!(
+        "{}@{}",
+        rule_id(&point.category),
+        point.location.as_deref().unwrap_or("-")
+    )
+}
+
+/// Every annotatable finding of `report` as `(id, description)`, weak points first, then
+/// crash buckets, without duplicates.
+pub fn finding_ids(report: &AssaultReport) -> Vec<(String, String)> {
+    let mut ids: Vec<(String, String)> = Vec::new();
+    for point in &report.assail_report.weak_points {
+        let id = weak_point_id(point);
+        if !ids.iter().any(|(known, _)| *known == id) {
+            ids.push((id, point.description.clone()));
+        }
+    }
+    for result in &report.attack_results {
+        if let Some(bucket) = crash_bucket(result) {
+            if !ids.iter().any(|(known, _)| *known == bucket) {
+                let description = format!("{:?} crash", result.axis);
+                ids.push((bucket, description));
+            }
+        }
+    }
+    ids
+}
+
+/// Author recorded when `--author` is not given.
+pub fn default_author() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(locations: &[&str]) -> AssaultReport {
+        AssaultReport {
+            assail_report: AssailReport {
+                program_path: PathBuf::from("app"),
+                language: Language::Rust,
+                frameworks: Vec::new(),
+                weak_points: locations
+                    .iter()
+                    .map(|location| WeakPoint {
+                        category: WeakPointCategory::UnsafeCode,
+                        location: Some(location.to_string()),
+                        severity: Severity::High,
+                        description: "unsafe block".to_string(),
+                        recommended_attack: Vec::new(),
+                        provenance: None,
+                        calibrated: None,
+                        owner: None,
+                    })
+                    .collect(),
+                statistics: ProgramStatistics::default(),
+                file_statistics: Vec::new(),
+                recommended_attacks: Vec::new(),
+                dependency_graph: DependencyGraph::default(),
+                taint_matrix: TaintMatrix::default(),
+                migration_metrics: None,
+                annotations: Vec::new(),
+                policies: Vec::new(),
+                manifests: Vec::new(),
+            },
+            total_crashes: 0,
+            total_signatures: 0,
+            overall_assessment: OverallAssessment {
+                robustness_score: 50.0,
+                critical_issues: Vec::new(),
+                recommendations: Vec::new(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn annotation(state: TriageState, comment: Option<&str>) -> Annotation {
+        Annotation {
+            state,
+            author: "alice".to_string(),
+            at: "2026-03-02T10:00:00+00:00".to_string(),
+            comment: comment.map(str::to_string),
+            report: None,
+        }
+    }
+
+    #[test]
+    fn test_annotations_follow_the_finding_across_reports() {
+        let first = report(&["src/ffi.rs:42", "src/io.rs:7"]);
+        assert_eq!(finding_ids(&first)[0].0, "PA004@src/ffi.rs:42");
+
+        let mut store = TriageStore::default();
+        store
+            .annotate(
+                &first,
+                "PA004@src/ffi.rs:42",
+                annotation(TriageState::Investigating, Some("checking bounds")),
+            )
+            .unwrap();
+        store
+            .annotate(
+                &first,
+                "PA004@src/ffi.rs:42",
+                annotation(TriageState::Confirmed, None),
+            )
+            .unwrap();
+        assert!(store
+            .annotate(
+                &first,
+                "PA004@src/lib.rs:1",
+                annotation(TriageState::Fixed, None)
+            )
+            .is_err());
+        assert_eq!(store.findings["PA004@src/ffi.rs:42"].len(), 2);
+
+        // A later run with the same finding sees the current state; one without it sees nothing.
+        let later = report(&["src/ffi.rs:42"]);
+        assert_eq!(
+            store.summary_lines(&later),
+            vec!["PA004@src/ffi.rs:42: confirmed by alice (2026-03-02)"]
+        );
+        assert!(store.summary_lines(&report(&["src/io.rs:7"])).is_empty());
+        assert_eq!(
+            TriageStore::path_beside(Path::new("runs/report.json")),
+            PathBuf::from("runs").join(TRIAGE_FILE)
+        );
+    }
+}
@@ -7,14 +7,16 @@
 //! and custom DSL families.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use smol_str::SmolStr;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::path::PathBuf;
 use std::time::Duration;
 
 /// Supported programming languages
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Language {
     // === Original languages ===
     Rust,
@@ -85,6 +87,7 @@ pub enum Language {
     VQL,
     FBQL,
 
+    #[default]
     Unknown,
 }
 
@@ -104,7 +107,7 @@ impl Language {
             "java" => Language::Java,
             "py" | "pyw" => Language::Python,
             "js" | "mjs" | "cjs" => Language::JavaScript,
-            "ts" | "tsx" | "jsx" => Language::JavaScript,
+            "ts" | "tsx" | "mts" | "cts" | "jsx" => Language::JavaScript,
             "rb" => Language::Ruby,
 
             // BEAM family
@@ -215,6 +218,7 @@ impl Language {
 
 /// Application frameworks detected in the codebase
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Framework {
     WebServer,
     Database,
@@ -247,15 +251,23 @@ pub struct DependencyGraph {
 }
 
 /// Attack axes for stress testing
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// The built-in axes serialize as their lowercase names (`cpu`, `memory`, ...); a
+/// [`Custom`](AttackAxis::Custom) axis serializes as its own name, so reports written before
+/// custom axes existed read back unchanged.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum AttackAxis {
+    #[default]
     Cpu,
     Memory,
     Disk,
     Network,
     Concurrency,
     Time,
+    Signal,
+    /// A user-defined axis, run through a [`CustomAxisSpec`] from the profile or config.
+    Custom(SmolStr),
 }
 
 impl AttackAxis {
@@ -267,8 +279,105 @@ impl AttackAxis {
             AttackAxis::Network,
             AttackAxis::Concurrency,
             AttackAxis::Time,
+            AttackAxis::Signal,
         ]
     }
+
+    /// Axes a campaign runs when none are named: every built-in axis but signal, which
+    /// delivers signals to the target and so has to be asked for.
+    pub fn defaults() -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|axis| *axis != AttackAxis::Signal)
+            .collect()
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            AttackAxis::Cpu => "cpu",
+            AttackAxis::Memory => "memory",
+            AttackAxis::Disk => "disk",
+            AttackAxis::Network => "network",
+            AttackAxis::Concurrency => "concurrency",
+            AttackAxis::Time => "time",
+            AttackAxis::Signal => "signal",
+            AttackAxis::Custom(name) => name,
+        }
+    }
+
+    /// The built-in axis called `name`, if any (case-insensitive).
+    pub fn builtin(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|axis| axis.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, AttackAxis::Custom(_))
+    }
+
+    /// Whether `name` can identify a custom axis: lowercase ASCII letters, digits, `-` and
+    /// `_`, starting with a letter.
+    pub fn valid_custom_name(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    }
+}
+
+impl From<String> for AttackAxis {
+    fn from(name: String) -> Self {
+        Self::builtin(&name).unwrap_or_else(|| AttackAxis::Custom(SmolStr::from(name)))
+    }
+}
+
+impl From<AttackAxis> for String {
+    fn from(axis: AttackAxis) -> Self {
+        axis.name().to_string()
+    }
+}
+
+impl std::fmt::Display for AttackAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Built-in axes keep their variant names (progress lines print `{:?}`); a custom axis shows
+/// its own name rather than `Custom("...")`.
+impl std::fmt::Debug for AttackAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttackAxis::Cpu => f.write_str("Cpu"),
+            AttackAxis::Memory => f.write_str("Memory"),
+            AttackAxis::Disk => f.write_str("Disk"),
+            AttackAxis::Network => f.write_str("Network"),
+            AttackAxis::Concurrency => f.write_str("Concurrency"),
+            AttackAxis::Time => f.write_str("Time"),
+            AttackAxis::Signal => f.write_str("Signal"),
+            AttackAxis::Custom(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Definition of a user-defined axis: the target arguments that put it under that kind of
+/// stress, and optionally a stressor command run alongside it for the attack window.
+///
+/// The stressor sees `PANIC_ATTACK_AXIS`, `PANIC_ATTACK_INTENSITY`,
+/// `PANIC_ATTACK_DURATION_SECS`, `PANIC_ATTACK_SEED` and (in ambush runs)
+/// `PANIC_ATTACK_TARGET_PID`, and is killed when the window closes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomAxisSpec {
+    pub name: SmolStr,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Extra target arguments for this axis (appended after `common_args`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Stressor command and its arguments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stressor: Vec<String>,
 }
 
 /// Known weak points in program behavior
@@ -279,9 +388,39 @@ pub struct WeakPoint {
     pub severity: Severity,
     pub description: String,
     pub recommended_attack: Vec<AttackAxis>,
+    /// External analyzer that reported this weak point; `None` for assail's own rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<FindingProvenance>,
+    /// Set when calibration lowered the severity after repeated clean attack runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calibrated: Option<CalibrationMarker>,
+    /// Owners of the file from CODEOWNERS (space-separated, as listed there) or, with
+    /// `--blame-owners`, the author of the flagged line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Tool and rule behind a weak point imported from an external analyzer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingProvenance {
+    /// Analyzer name, e.g. `clippy`, `semgrep` or `bandit`.
+    pub tool: String,
+    /// The tool's own rule identifier (`clippy::unwrap_used`, `B602`, ...).
+    pub rule: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+/// Records why a weak point's severity differs from what assail reported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationMarker {
+    /// Severity assail assigned before calibration.
+    pub original_severity: Severity,
+    /// Attack runs that exercised this weak point without a crash.
+    pub clean_runs: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum)]
 pub enum WeakPointCategory {
     // Original categories
     UncheckedAllocation,
@@ -305,6 +444,13 @@ pub enum WeakPointCategory {
     UncheckedError,
     InfiniteRecursion,
     UnsafeTypeCoercion,
+    // Unsafe Rust specifics
+    UnsafeTransmute,
+    RawPointerDeref,
+    MutableStatic,
+    ManualSendSync,
+    /// A declared dependency matched a denial-of-service or memory-safety advisory.
+    VulnerableDependency,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -333,6 +479,36 @@ pub struct BugSignature {
     pub confidence: f64,
     pub evidence: Vec<String>,
     pub location: Option<String>,
+    /// Detection rule that fired (`deadlock`, `stderr_double_free`, `hang`, ...); signature
+    /// feedback is labeled per rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// Set when signature feedback moved `confidence` away from the rule's own value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calibration: Option<SignatureCalibration>,
+}
+
+/// Provenance of a feedback-calibrated signature confidence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureCalibration {
+    /// Confidence the rule assigned before feedback.
+    pub prior: f64,
+    pub true_positives: u32,
+    pub false_positives: u32,
+    /// Feedback file the labels came from.
+    pub feedback: PathBuf,
+}
+
+impl SignatureCalibration {
+    pub fn describe(&self) -> String {
+        format!(
+            "Calibrated from {:.2} by {} true / {} false positive label(s) in {}",
+            self.prior,
+            self.true_positives,
+            self.false_positives,
+            self.feedback.display()
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -341,11 +517,22 @@ pub enum SignatureType {
     DoubleFree,
     MemoryLeak,
     Deadlock,
+    Livelock,
+    /// A progress check saw no progress for its window (see `attack::progress`).
+    Stall,
     DataRace,
     BufferOverflow,
     IntegerOverflow,
     NullPointerDeref,
     UnhandledError,
+    /// The target's RSS rose steadily through a run it survived (see `signatures::leak`).
+    MemoryGrowthSuspected,
+    /// Open file descriptors rose steadily through the run.
+    FdLeakSuspected,
+    /// Other per-process resources (threads) rose steadily through the run.
+    ResourceLeakSuspected,
+    /// A hung target pinned a core without doing any I/O (see `attack::hang`).
+    InfiniteLoopSuspected,
 }
 
 /// Per-file statistics from Assail analysis
@@ -362,7 +549,7 @@ pub struct FileStatistics {
 }
 
 /// Assail analysis results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AssailReport {
     pub program_path: PathBuf,
     pub language: Language,
@@ -378,6 +565,94 @@ pub struct AssailReport {
     /// Migration-specific metrics (populated when target is ReScript)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub migration_metrics: Option<MigrationMetrics>,
+    /// `panic-attack:` source comments honoured during the scan, kept for auditability.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<SourceAnnotation>,
+    /// `panic-attack.policy.yaml` files that changed findings during the scan.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<AppliedPolicy>,
+    /// Dependency manifests found under the scan root, feeding `panic-attack sbom`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub manifests: Vec<DependencyManifest>,
+}
+
+/// A `Cargo.toml`, `package.json` or `go.mod` found during assail, with what it declares.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyManifest {
+    /// Manifest path relative to the scan root.
+    pub path: String,
+    pub ecosystem: Ecosystem,
+    /// Package or module the manifest declares; a Cargo workspace root has none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<DeclaredDependency>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    Go,
+}
+
+/// One dependency as its manifest declares it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeclaredDependency {
+    pub name: String,
+    /// Version requirement verbatim (`^1.2`, `~> 0.4`, `v1.9.0`); unset for path and git deps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub scope: DependencyScope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyScope {
+    Runtime,
+    Dev,
+    Build,
+    Optional,
+    /// Go requirements marked `// indirect`.
+    Indirect,
+}
+
+/// Effect of one directory policy on assail findings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedPolicy {
+    /// Policy file relative to the scan root; counts go to the closest policy.
+    pub path: String,
+    /// Weak points dropped by `exclude_categories`.
+    pub excluded: usize,
+    /// Weak points whose severity the policy changed.
+    pub adjusted: usize,
+}
+
+/// A `panic-attack: ...` magic comment found in scanned source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceAnnotation {
+    pub file: String,
+    pub line: usize,
+    /// Annotation text after `panic-attack:`, verbatim.
+    pub text: String,
+    pub action: AnnotationAction,
+    /// Category the annotation is restricted to (`category=...`); `None` means all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<WeakPointCategory>,
+    /// Weak points removed by an `ignore` annotation.
+    #[serde(default)]
+    pub suppressed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnnotationAction {
+    /// Drop matching assail findings for the file.
+    Ignore,
+    /// Add a finding at the annotated site.
+    Force,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -392,11 +667,17 @@ pub struct ProgramStatistics {
 }
 
 /// Attack configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AttackConfig {
     pub axes: Vec<AttackAxis>,
     pub duration: Duration,
     pub intensity: IntensityLevel,
+    /// Per-axis intensity overrides; other axes run at `intensity`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub axis_intensity: HashMap<AttackAxis, IntensityLevel>,
+    /// Per-axis duration overrides; other axes run for `duration`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub axis_duration: HashMap<AttackAxis, Duration>,
     pub target_programs: Vec<PathBuf>,
     pub data_corpus: Option<PathBuf>,
     pub parallel_attacks: bool,
@@ -404,45 +685,411 @@ pub struct AttackConfig {
     pub common_args: Vec<String>,
     #[serde(default)]
     pub axis_args: HashMap<AttackAxis, Vec<String>>,
+    /// Registry of user-defined axes that `axes` may name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_axes: Vec<CustomAxisSpec>,
     #[serde(default)]
     pub probe_mode: ProbeMode,
+    #[serde(default)]
+    pub signal_storm: SignalStormConfig,
+    #[serde(default)]
+    pub memory_pressure: MemoryPressureConfig,
+    /// Make the target's own allocations fail during memory-axis attacks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub malloc_faults: Option<MallocFaultConfig>,
+    /// Make the target's file reads, writes and opens fail during disk-axis attacks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_faults: Option<IoFaultConfig>,
+    /// Make the target's connects, sends and receives fail during network-axis attacks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_faults: Option<IoFaultConfig>,
+    #[serde(default)]
+    pub disk_throttle: DiskThrottleConfig,
+    #[serde(default)]
+    pub cpu_chaos: CpuChaosConfig,
+    /// Closed-loop intensity; when set, `intensity` is the ceiling the controller scales.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptiveConfig>,
+    /// Campaign seed for every randomized choice (see `crate::seed`).
+    #[serde(default)]
+    pub seed: u64,
+    /// Tool the concurrency axis runs the target under.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrapper: Option<TargetWrapper>,
+    /// Schedule file whose holds the concurrency axis applies instead of seeded ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_replay: Option<PathBuf>,
+    /// Directory crashing schedules are kept in; `reports/schedules` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_dir: Option<PathBuf>,
+    /// Liveness probe; a target without progress for its window is stopped as stalled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<ProgressProbe>,
+    /// Sample package power, temperatures and thermal throttling during each attack.
+    #[serde(default)]
+    pub telemetry: bool,
+    /// Hold and thrash GPU memory during each attack (`gpu` feature).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<GpuStressConfig>,
+    /// Drive the target's web UI through WebDriver while it is ambushed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<BrowserConfig>,
+    /// Exhaust, kill and lock the target's database while it is ambushed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<DbChaosConfig>,
+    /// Flood a message broker topic the target consumes while it is ambushed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flood: Option<MessageFloodConfig>,
+    /// Wall-clock budget for all axes together; later axes are shortened to fit it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>,
+}
+
+impl AttackConfig {
+    /// This config narrowed to `axis`'s intensity and duration overrides, if it has any.
+    pub fn for_axis(&self, axis: &AttackAxis) -> Option<AttackConfig> {
+        let intensity = self
+            .axis_intensity
+            .get(axis)
+            .copied()
+            .unwrap_or(self.intensity);
+        let duration = self
+            .axis_duration
+            .get(axis)
+            .copied()
+            .unwrap_or(self.duration);
+        (intensity != self.intensity || duration != self.duration).then(|| AttackConfig {
+            intensity,
+            duration,
+            ..self.clone()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrokerProtocol {
+    Mqtt,
+    Kafka,
+}
+
+/// Message flood settings: phases of doubling publish rate over the attack window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageFloodConfig {
+    /// `mqtt://host[:port]/topic` or `kafka://host[:port]/topic`.
+    pub broker: String,
+    #[serde(default = "default_flood_phases")]
+    pub phases: usize,
+    /// Publish rate of the first phase; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_rate_hz: Option<f64>,
+}
+
+fn default_flood_phases() -> usize {
+    4
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseEngine {
+    Postgres,
+    Mysql,
+}
+
+/// Database chaos settings; queries run through the `psql` or `mysql` client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbChaosConfig {
+    /// `postgres://` or `mysql://` URL of a test instance the target also uses.
+    pub dsn: String,
+    /// Table to hold exclusive locks on; lock contention is skipped without one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_table: Option<String>,
+    /// Connections to hold open; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<usize>,
+}
+
+/// Browser behind a WebDriver endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrowserKind {
+    /// chromedriver
+    #[default]
+    Chrome,
+    /// geckodriver
+    Firefox,
+}
+
+/// Randomized interaction storm against a web UI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrowserConfig {
+    /// WebDriver endpoint, e.g. `http://127.0.0.1:9515` for chromedriver.
+    pub webdriver: String,
+    /// Page the storm starts from and returns to when it wanders off-site.
+    pub url: String,
+    #[serde(default)]
+    pub browser: BrowserKind,
+}
+
+/// GPU memory stressor settings for accelerator-bound targets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpuStressConfig {
+    /// Adapter index or case-insensitive name fragment; the first adapter when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    /// Device memory to hold; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
+}
+
+/// What counts as progress for a running target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressKind {
+    /// An output line matching the target regex.
+    Output,
+    /// A newer modification time on the target file.
+    File,
+    /// The target address accepting a TCP connection.
+    Tcp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgressCheck {
+    pub kind: ProgressKind,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressProbe {
+    pub check: ProgressCheck,
+    /// Longest gap between progress events before the target counts as stalled.
+    pub window: Duration,
+}
+
+/// A target stopped because its progress check saw nothing for the whole window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StallSignal {
+    pub check: ProgressCheck,
+    pub window: Duration,
+    /// Offset into the run at which the stall was declared.
+    pub detected_at: Duration,
+    /// Offset of the last progress event, if there was one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_progress: Option<Duration>,
 }
 
+/// Recording wrapper for the concurrency axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetWrapper {
+    /// `rr record`; traces of crashing runs are kept for `rr replay`.
+    Rr,
+    /// Seeded ptrace schedule fuzzing; schedules of crashing runs are kept for replay.
+    Schedule,
+}
+
+/// Signal-storm stressor settings (signal axis).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignalStormConfig {
+    /// Deliveries per second; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_hz: Option<f64>,
+    /// Signal rotation (names such as `SIGUSR1` or `usr1`); defaults to the benign set.
+    #[serde(default)]
+    pub signals: Vec<String>,
+    /// Interleave SIGSTOP/SIGCONT pulses with the rotation.
+    #[serde(default)]
+    pub stop_pulses: bool,
+}
+
+/// Attack intensity on a 0.0–1.0 scale.
+///
+/// The four named presets are points on the scale (light 0.25, medium 0.5, heavy 0.75,
+/// extreme 1.0) and keep serializing by name; any other point is a [`Scale`] and serializes as
+/// its number. Profiles can name further points under `intensity_presets`.
+///
+/// [`Scale`]: IntensityLevel::Scale
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(try_from = "IntensitySpec", into = "IntensitySpec")]
 pub enum IntensityLevel {
     Light,
+    #[default]
     Medium,
     Heavy,
     Extreme,
+    /// A point between the presets.
+    Scale(UnitScale),
+}
+
+/// A finite point on the 0.0–1.0 intensity scale, made only by
+/// [`IntensityLevel::from_scale`], which is what keeps [`IntensityLevel`]'s equality total.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct UnitScale(f64);
+
+impl UnitScale {
+    pub fn get(self) -> f64 {
+        self.0
+    }
 }
 
+/// Scale points and stressor multipliers of the presets; multipliers in between are
+/// interpolated linearly, so the presets keep the load they always had.
+const INTENSITY_ANCHORS: [(f64, f64); 5] = [
+    (0.0, 0.2),
+    (0.25, 1.0),
+    (0.5, 5.0),
+    (0.75, 10.0),
+    (1.0, 50.0),
+];
+
 impl IntensityLevel {
+    pub const PRESETS: [IntensityLevel; 4] = [
+        IntensityLevel::Light,
+        IntensityLevel::Medium,
+        IntensityLevel::Heavy,
+        IntensityLevel::Extreme,
+    ];
+
+    /// Position on the 0.0–1.0 scale.
+    pub fn scale(&self) -> f64 {
+        match self {
+            IntensityLevel::Light => 0.25,
+            IntensityLevel::Medium => 0.5,
+            IntensityLevel::Heavy => 0.75,
+            IntensityLevel::Extreme => 1.0,
+            IntensityLevel::Scale(scale) => scale.get(),
+        }
+    }
+
+    /// The intensity at `scale`, as a preset when it lands exactly on one.
+    pub fn from_scale(scale: f64) -> Result<Self, String> {
+        if !(0.0..=1.0).contains(&scale) {
+            return Err(format!("intensity {} is outside 0.0-1.0", scale));
+        }
+        Ok(Self::PRESETS
+            .into_iter()
+            .find(|preset| preset.scale() == scale)
+            .unwrap_or(IntensityLevel::Scale(UnitScale(scale))))
+    }
+
+    /// A preset by (case-insensitive) name.
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::PRESETS
+            .into_iter()
+            .find(|preset| format!("{:?}", preset).eq_ignore_ascii_case(name))
+    }
+
     pub fn multiplier(&self) -> f64 {
+        let scale = self.scale();
+        INTENSITY_ANCHORS
+            .windows(2)
+            .find(|pair| scale <= pair[1].0)
+            .map(|pair| {
+                let ((low, low_mult), (high, high_mult)) = (pair[0], pair[1]);
+                low_mult + (high_mult - low_mult) * (scale - low) / (high - low)
+            })
+            .unwrap_or(INTENSITY_ANCHORS[4].1)
+    }
+}
+
+impl PartialEq for IntensityLevel {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale() == other.scale()
+    }
+}
+
+// Scale values are validated finite, so equality is total.
+impl Eq for IntensityLevel {}
+
+/// Presets print as their variant names; other points as their scale value.
+impl std::fmt::Debug for IntensityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            IntensityLevel::Light => 1.0,
-            IntensityLevel::Medium => 5.0,
-            IntensityLevel::Heavy => 10.0,
-            IntensityLevel::Extreme => 50.0,
+            IntensityLevel::Light => f.write_str("Light"),
+            IntensityLevel::Medium => f.write_str("Medium"),
+            IntensityLevel::Heavy => f.write_str("Heavy"),
+            IntensityLevel::Extreme => f.write_str("Extreme"),
+            IntensityLevel::Scale(scale) => write!(f, "{}", scale.get()),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl std::str::FromStr for IntensityLevel {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        IntensitySpec::parse(raw).resolve(&HashMap::new())
+    }
+}
+
+/// An intensity as written in a profile, timeline or on the command line: a preset name
+/// (built-in or from `intensity_presets`) or a 0.0–1.0 scale value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IntensitySpec {
+    Scale(f64),
+    Name(String),
+}
+
+impl IntensitySpec {
+    pub fn parse(raw: &str) -> Self {
+        let raw = raw.trim();
+        match raw.parse::<f64>() {
+            Ok(scale) => IntensitySpec::Scale(scale),
+            Err(_) => IntensitySpec::Name(raw.to_string()),
+        }
+    }
+
+    /// Resolve against the built-in presets and `presets` (name → scale).
+    pub fn resolve(&self, presets: &HashMap<String, f64>) -> Result<IntensityLevel, String> {
+        match self {
+            IntensitySpec::Scale(scale) => IntensityLevel::from_scale(*scale),
+            IntensitySpec::Name(name) => match IntensityLevel::preset(name) {
+                Some(preset) => Ok(preset),
+                None => match presets.get(name) {
+                    Some(scale) => IntensityLevel::from_scale(*scale)
+                        .map_err(|err| format!("intensity preset `{}`: {}", name, err)),
+                    None => Err(format!(
+                        "unknown intensity `{}` (expected light, medium, heavy, extreme, a \
+                         0.0-1.0 value or a profile intensity preset)",
+                        name
+                    )),
+                },
+            },
+        }
+    }
+}
+
+impl TryFrom<IntensitySpec> for IntensityLevel {
+    type Error = String;
+
+    fn try_from(spec: IntensitySpec) -> Result<Self, Self::Error> {
+        spec.resolve(&HashMap::new())
+    }
+}
+
+/// Reports keep the preset variant names they always carried.
+impl From<IntensityLevel> for IntensitySpec {
+    fn from(intensity: IntensityLevel) -> Self {
+        match intensity {
+            IntensityLevel::Scale(scale) => IntensitySpec::Scale(scale.get()),
+            preset => IntensitySpec::Name(format!("{:?}", preset)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ProbeMode {
+    #[default]
     Auto,
     Always,
     Never,
 }
 
-impl Default for ProbeMode {
-    fn default() -> Self {
-        ProbeMode::Auto
-    }
-}
-
 /// Attack execution results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AttackResult {
     pub program: PathBuf,
     pub axis: AttackAxis,
@@ -456,44 +1103,1095 @@ pub struct AttackResult {
     pub peak_memory: u64,
     pub crashes: Vec<CrashReport>,
     pub signatures_detected: Vec<BugSignature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_storm: Option<SignalStormReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_pressure: Option<MemoryPressureReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_throttle: Option<DiskThrottleReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_chaos: Option<CpuChaosReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptiveReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stress_profile: Option<StressProfile>,
+    /// Set when the target was still running at its deadline without producing output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hang: Option<HangReport>,
+    /// Set when the target was stopped for failing its progress check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stall: Option<StallSignal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telemetry: Option<TelemetryReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<GpuStressReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<BrowserReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<DbChaosReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flood: Option<MessageFloodReport>,
+    /// Socket syscalls intercepted and faults injected per kind (network axis, `--net-faults`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_faults: Option<IoFaultReport>,
+    /// How the campaign timeout shortened or skipped this run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_note: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CrashReport {
-    pub timestamp: String,
-    pub signal: Option<String>,
-    pub backtrace: Option<String>,
-    pub stderr: String,
-    pub stdout: String,
-}
-
-/// Complete assault report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AssaultReport {
-    pub assail_report: AssailReport,
-    pub attack_results: Vec<AttackResult>,
-    pub total_crashes: usize,
-    pub total_signatures: usize,
-    pub overall_assessment: OverallAssessment,
+/// Per-phase delivery statistics of a message flood.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageFloodReport {
+    pub protocol: BrokerProtocol,
+    /// Broker address (`host:port`).
+    pub broker: String,
+    pub topic: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<FloodPhase>,
+    /// Phase the target failed in, when it failed.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub timeline: Option<TimelineReport>,
+    pub correlation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverallAssessment {
-    pub robustness_score: f64,
-    pub critical_issues: Vec<String>,
-    pub recommendations: Vec<String>,
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FloodPhase {
+    pub index: usize,
+    /// Offset into the attack at which the phase begins.
+    pub started_at: Duration,
+    pub target_rate_hz: f64,
+    /// Rate actually reached; lower than the target when the broker slowed publishing down.
+    #[serde(default)]
+    pub achieved_rate_hz: f64,
+    #[serde(default)]
+    pub sent: u64,
+    /// Acknowledged by the broker (MQTT PUBACK, Kafka produce response without error).
+    #[serde(default)]
+    pub delivered: u64,
+    /// Refused by the broker with an error response.
+    #[serde(default)]
+    pub rejected: u64,
+    /// Lost to a dropped or refused connection.
+    #[serde(default)]
+    pub failed: u64,
+    #[serde(default)]
+    pub malformed: u64,
+    #[serde(default)]
+    pub oversized: u64,
+    #[serde(default)]
+    pub duplicated: u64,
+    /// Messages carrying a sequence number older than one already sent.
+    #[serde(default)]
+    pub out_of_order: u64,
+    /// First distinct broker errors of the phase.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
 }
 
-/// Timeline metadata for ambush runs.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimelineReport {
-    pub duration: Duration,
-    pub events: Vec<TimelineEventReport>,
+/// What database chaos did during the attack and what the server showed for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbChaosReport {
+    pub engine: DatabaseEngine,
+    /// DSN with any password masked.
+    pub target: String,
+    /// Most connections held open at once.
+    #[serde(default)]
+    pub peak_held_connections: usize,
+    #[serde(default)]
+    pub killed_connections: u64,
+    /// Pathological queries issued (each bounded by a statement timeout).
+    #[serde(default)]
+    pub slow_queries: u64,
+    /// Exclusive lock holds on the lock table.
+    #[serde(default)]
+    pub lock_holds: u64,
+    /// Server-wide connection count at its highest, from the server's own view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_server_connections: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<u64>,
+    /// Most sessions seen waiting on locks at once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_lock_waits: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub symptoms: Vec<DbSymptom>,
+    /// How the target's failure lines up with the symptoms, when it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DbSymptomKind {
+    /// The server refused new connections for lack of slots.
+    PoolExhausted,
+    /// The server could not be reached at all.
+    Unreachable,
+    /// Sessions queued behind a held lock.
+    LockWait,
+    QueryError,
+}
+
+/// Database-side event during the attack.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbSymptom {
+    pub kind: DbSymptomKind,
+    /// Offset into the attack.
+    pub at: Duration,
+    pub message: String,
+}
+
+/// What a WebDriver interaction storm did and the UI failures it saw.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrowserReport {
+    pub browser: BrowserKind,
+    pub url: String,
+    #[serde(default)]
+    pub actions: u64,
+    /// Actions the browser rejected (stale or hidden elements); not failures of the target.
+    #[serde(default)]
+    pub failed_actions: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<BrowserError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserErrorKind {
+    /// Uncaught exception, unhandled rejection or `console.error` in the page.
+    Console,
+    /// Browser error page or server error page instead of the UI.
+    CrashPage,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrowserError {
+    pub kind: BrowserErrorKind,
+    pub message: String,
+    /// Page URL when the error was seen.
+    pub page: String,
+    /// Offset into the storm.
+    pub at: Duration,
+}
+
+/// What the GPU memory stressor held on the device while the attack ran.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpuStressReport {
+    /// Adapter name as reported by the driver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    #[serde(default)]
+    pub requested_bytes: u64,
+    /// Most device memory the stressor held at once.
+    #[serde(default)]
+    pub peak_vram_bytes: u64,
+    #[serde(default)]
+    pub buffers: usize,
+    /// Buffer-to-buffer copy passes completed while thrashing.
+    #[serde(default)]
+    pub passes: u64,
+    /// Allocation stopped early because the device ran out of memory.
+    #[serde(default)]
+    pub out_of_memory: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Host power and temperature sampled while the attack ran (`--telemetry`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryReport {
+    #[serde(default)]
+    pub samples: usize,
+    /// RAPL package energy over the run, summed across packages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub energy_joules: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_package_watts: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_package_watts: Option<f64>,
+    /// Hottest sensor reading when the run started, in °C.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_temperature_c: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_temperature_c: Option<f64>,
+    /// Lowest critical trip point the sensors report, in °C.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_temperature_c: Option<f64>,
+    /// Kernel thermal-throttle events counted during the run.
+    #[serde(default)]
+    pub throttle_events: u64,
+    #[serde(default)]
+    pub throttled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Thread states of a target sampled just before it was killed for stalling past its
+/// deadline, so a hang is reported as a probable deadlock or livelock rather than a timeout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HangReport {
+    pub pid: u32,
+    /// Time since the target last wrote to stdout or stderr.
+    pub stalled_for: Duration,
+    pub threads: Vec<HangThread>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guess: Option<SignatureType>,
+    /// Probes taken across the sampling window (see [`HangThread::on_cpu`]).
+    #[serde(default)]
+    pub probes: u32,
+    /// I/O the whole process did during the sampling window, from `/proc/<pid>/io`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io: Option<IoProgress>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoProgress {
+    pub read_bytes: u64,
+    pub written_bytes: u64,
+    /// Read and write system calls.
+    pub syscalls: u64,
+}
+
+impl IoProgress {
+    pub fn is_idle(&self) -> bool {
+        self.read_bytes == 0 && self.written_bytes == 0 && self.syscalls == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HangThread {
+    pub tid: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Scheduler state from `/proc/<pid>/task/<tid>/stat` (`R`, `S`, `D`, ...).
+    pub state: String,
+    /// Kernel function the thread sleeps in, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wchan: Option<String>,
+    /// CPU ticks the thread used over the sampling window.
+    #[serde(default)]
+    pub cpu_ticks: u64,
+    /// Share of one core the thread used over the sampling window.
+    #[serde(default)]
+    pub cpu_share: f64,
+    /// Probes that found the thread running user code rather than in a system call.
+    #[serde(default)]
+    pub on_cpu: u32,
+    /// User-space frames from `eu-stack`, innermost first, when it is installed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stack: Vec<String>,
+    /// Innermost frame of each repeated stack sample of a thread pinning a core.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ip_samples: Vec<String>,
+}
+
+/// Fully resolved stressor parameters behind one attack result, so it can be read (and rerun)
+/// without knowing how this version scales intensity. Only the fields the axis uses are set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressProfile {
+    pub intensity: IntensityLevel,
+    pub multiplier: f64,
+    pub duration: Duration,
+    pub seed: u64,
+    /// Arguments the target received, including axis-specific load flags.
+    #[serde(default)]
+    pub target_args: Vec<String>,
+    /// Run limit applied to the target when it differs from `duration` (time axis).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>,
+    /// Host parallelism the CPU worker count was scaled from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_parallelism: Option<usize>,
+    /// Busy-loop (CPU) or sleeper (concurrency) threads run beside the target.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workers: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_target_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_chunk_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_files_per_cycle: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_file_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_clients: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_payload_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_hz: Option<f64>,
+    /// Allocation failures injected into the target (memory axis, `--malloc-faults`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub malloc_faults: Option<MallocFaultReport>,
+    /// File syscalls intercepted and faults injected (disk axis, `--io-faults`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_faults: Option<IoFaultReport>,
+}
+
+impl StressProfile {
+    pub fn new(intensity: IntensityLevel, duration: Duration, seed: u64) -> Self {
+        Self {
+            intensity,
+            multiplier: intensity.multiplier(),
+            duration,
+            seed,
+            target_args: Vec::new(),
+            timeout: None,
+            host_parallelism: None,
+            workers: None,
+            memory_target_bytes: None,
+            memory_chunk_bytes: None,
+            disk_files_per_cycle: None,
+            disk_file_bytes: None,
+            network_clients: None,
+            network_payload_bytes: None,
+            signal_hz: None,
+            malloc_faults: None,
+            io_faults: None,
+        }
+    }
+}
+
+/// Schedule actually delivered by a signal storm.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignalStormReport {
+    pub frequency_hz: f64,
+    pub signals: Vec<String>,
+    #[serde(default)]
+    pub stop_pulses: bool,
+    pub delivered: usize,
+    #[serde(default)]
+    pub failed: usize,
+    /// Per-signal delivery counts keyed by signal name.
+    #[serde(default)]
+    pub counts: HashMap<String, usize>,
+    /// First deliveries in order; capped to keep reports small.
+    #[serde(default)]
+    pub schedule: Vec<SignalDelivery>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalDelivery {
+    pub offset: Duration,
+    pub signal: String,
+    pub delivered: bool,
+}
+
+/// How the memory axis applies pressure during ambush runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryPressureMode {
+    /// Allocate inside panic-attack (pressures the host).
+    #[default]
+    Allocate,
+    /// Lower `memory.high`/`memory.max` on the target's own cgroup (Linux cgroup v2).
+    Cgroup,
+}
+
+/// Memory-axis stressor settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryPressureConfig {
+    #[serde(default)]
+    pub mode: MemoryPressureMode,
+    /// Initial `memory.high` in MiB (cgroup mode, default 1024).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_mb: Option<u64>,
+    /// Final `memory.high` in MiB; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub floor_mb: Option<u64>,
+}
+
+/// Limits applied and kernel reactions observed during cgroup memory pressure.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryPressureReport {
+    pub mode: MemoryPressureMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    #[serde(default)]
+    pub start_bytes: u64,
+    #[serde(default)]
+    pub floor_bytes: u64,
+    #[serde(default)]
+    pub steps: Vec<MemoryPressureStep>,
+    /// `memory.events` counters: reclaim throttling (high), limit hits (max), OOM.
+    #[serde(default)]
+    pub high_events: u64,
+    #[serde(default)]
+    pub max_events: u64,
+    #[serde(default)]
+    pub oom_events: u64,
+    #[serde(default)]
+    pub oom_kills: u64,
+    #[serde(default)]
+    pub reclaimed_pages: u64,
+    #[serde(default)]
+    pub peak_current: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// LD_PRELOAD allocation-failure injection (memory axis).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MallocFaultConfig {
+    /// Probability that a malloc/calloc/realloc call fails; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+}
+
+/// Allocation calls the shim saw and the failures it injected.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MallocFaultReport {
+    pub rate: f64,
+    #[serde(default)]
+    pub calls: u64,
+    #[serde(default)]
+    pub injected: u64,
+}
+
+/// seccomp-notify syscall fault injection (files on the disk axis, sockets on the network axis).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IoFaultConfig {
+    /// Probability that an intercepted call fails; derived from intensity when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+}
+
+/// Syscalls the interceptor saw and the faults it injected.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IoFaultReport {
+    pub rate: f64,
+    #[serde(default)]
+    pub intercepted: u64,
+    #[serde(default)]
+    pub injected: u64,
+    /// Injected faults by kind (`EIO`, `ECONNRESET`, `partial write`, `stall`, ...).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub faults: BTreeMap<String, u64>,
+}
+
+/// One fault injected into a file syscall.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InjectedFault {
+    /// Offset from the start of the run.
+    pub at: Duration,
+    /// `read`, `write`, `open`, `connect`, `sendto`, `recvfrom`, `sendmsg` or `recvmsg`.
+    pub syscall: String,
+    /// File path, socket (`socket:[inode]`) or the address being connected to.
+    pub path: String,
+    /// Error name (`EIO`, `ECONNRESET`, ...), or a short transfer or stall with its size.
+    pub fault: String,
+}
+
+/// CPU-axis affinity/priority chaos applied to the target during ambush runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CpuChaosConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Milliseconds between migrations (default 250).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_ms: Option<u64>,
+    /// Nice value toggled against 0 every third interval (default 10).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_nice: Option<i32>,
+}
+
+/// Migrations and renices applied by CPU chaos, with per-interval scheduler delay.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CpuChaosReport {
+    pub interval_ms: u64,
+    pub max_nice: i32,
+    #[serde(default)]
+    pub migrations: usize,
+    #[serde(default)]
+    pub renice_attempts: usize,
+    /// Renices refused by the kernel (lowering nice needs CAP_SYS_NICE).
+    #[serde(default)]
+    pub renice_denied: usize,
+    #[serde(default)]
+    pub intervals: Vec<CpuChaosInterval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpuChaosInterval {
+    pub offset: Duration,
+    pub cpus: Vec<usize>,
+    pub applied: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reniced: Option<bool>,
+    /// Run-queue wait accrued by the target during the interval, in microseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_delay_us: Option<u64>,
+}
+
+/// Metric the adaptive controller holds at its setpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdaptiveMetric {
+    /// Host CPU busy time, percent.
+    #[default]
+    HostCpu,
+    /// Host memory in use (total minus available), percent.
+    HostMemory,
+    /// Target CPU use, percent of one core.
+    TargetCpu,
+    /// Target resident set size, MiB.
+    TargetRss,
+}
+
+impl AdaptiveMetric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AdaptiveMetric::HostCpu => "host-cpu",
+            AdaptiveMetric::HostMemory => "host-memory",
+            AdaptiveMetric::TargetCpu => "target-cpu",
+            AdaptiveMetric::TargetRss => "target-rss",
+        }
+    }
+}
+
+/// Adaptive intensity: scale stress to hold `metric` at `setpoint`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveConfig {
+    pub metric: AdaptiveMetric,
+    /// Percent for the CPU and memory metrics, MiB for `target-rss`.
+    pub setpoint: f64,
+    /// Milliseconds between controller steps (default 500).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_ms: Option<u64>,
+}
+
+/// Intensity trajectory chosen by the adaptive controller.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdaptiveReport {
+    pub metric: AdaptiveMetric,
+    pub setpoint: f64,
+    pub interval_ms: u64,
+    /// Mean fraction of the configured intensity applied over the run.
+    #[serde(default)]
+    pub mean_level: f64,
+    /// Mean absolute distance from the setpoint over the second half of the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settled_error: Option<f64>,
+    #[serde(default)]
+    pub trajectory: Vec<AdaptiveStep>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdaptiveStep {
+    pub offset: Duration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub measured: Option<f64>,
+    /// Level set after this sample, as a fraction of the configured intensity.
+    pub level: f64,
+}
+
+/// Secret scrubbing applied to crash output before a report is printed, saved or stored.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedactionConfig {
+    /// Turn off redaction entirely (`--no-redact`).
+    #[serde(default)]
+    pub disabled: bool,
+    /// Keep only the user patterns, dropping the built-in ones.
+    #[serde(default)]
+    pub skip_builtin: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<RedactionPattern>,
+}
+
+/// User redaction rule; a `secret` capture group limits the replacement to that part.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactionPattern {
+    pub name: String,
+    pub pattern: String,
+}
+
+/// Disk-axis throttling of the target's cgroup via `io.max`; inactive when no limit is set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiskThrottleConfig {
+    /// Directory whose backing device is throttled (defaults to the working directory).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_bps: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_bps: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_iops: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_iops: Option<u64>,
+}
+
+impl DiskThrottleConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.read_bps.is_some()
+            || self.write_bps.is_some()
+            || self.read_iops.is_some()
+            || self.write_iops.is_some()
+    }
+
+    /// Render an `io.max` line such as `8:0 rbps=1048576 wbps=max riops=max wiops=100`.
+    pub fn io_max_line(&self, device: &str) -> String {
+        let limit = |value: Option<u64>| value.map_or("max".to_string(), |v| v.to_string());
+        format!(
+            "{} rbps={} wbps={} riops={} wiops={}",
+            device,
+            limit(self.read_bps),
+            limit(self.write_bps),
+            limit(self.read_iops),
+            limit(self.write_iops)
+        )
+    }
+}
+
+/// Limits applied and I/O observed while the target ran under `io.max`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiskThrottleReport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    #[serde(default)]
+    pub device: String,
+    #[serde(default)]
+    pub limits: String,
+    #[serde(default)]
+    pub read_bytes: u64,
+    #[serde(default)]
+    pub write_bytes: u64,
+    #[serde(default)]
+    pub read_ios: u64,
+    #[serde(default)]
+    pub write_ios: u64,
+    /// Total `io.pressure` stall time in microseconds, when PSI is available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stall_micros: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryPressureStep {
+    pub offset: Duration,
+    pub memory_high: u64,
+    pub memory_max: u64,
+    pub memory_current: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub timestamp: String,
+    pub signal: Option<String>,
+    pub backtrace: Option<String>,
+    pub stderr: String,
+    pub stdout: String,
+    /// Full text of outputs too large to inline; the fields above then hold a preview.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<OutputArtifact>,
+    /// Scripts that rerun this crash outside panic-attack, relative to the report's directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repro_scripts: Vec<PathBuf>,
+    /// rr trace of the crashing run (`rr replay <dir>` replays the failing schedule).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rr_trace: Option<PathBuf>,
+    /// Thread schedule of the crashing run (`--replay-schedule <file>` applies it again).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<PathBuf>,
+    /// Faults most recently injected into the target's file I/O before it crashed
+    /// (`--io-faults`), oldest first; the crash usually follows one of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injected_faults: Vec<InjectedFault>,
+    /// Process state read from the crash's core file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postmortem: Option<Postmortem>,
+    /// Ambush timeline events that were running when the target crashed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub active_stressors: Vec<ActiveStressor>,
+    /// Frames parsed from the backtrace (see `signatures::backtrace`), innermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frames: Vec<StackFrame>,
+}
+
+/// One frame of a parsed backtrace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StackFrame {
+    pub function: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// Binary or shared library the frame is in, when the trace names it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
+    /// Address relative to `module`, for symbolizing frames of stripped binaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+impl StackFrame {
+    /// `function (file:line)`, or just the function when the frame has no location.
+    pub fn describe(&self) -> String {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => format!("{} ({}:{})", self.function, file, line),
+            (Some(file), None) => format!("{} ({})", self.function, file),
+            _ => self.function.clone(),
+        }
+    }
+}
+
+/// A timeline event in progress at the moment of a crash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveStressor {
+    /// Timeline event id.
+    pub event: String,
+    pub axis: AttackAxis,
+    pub intensity: IntensityLevel,
+    /// How long the event had been running when the target crashed.
+    pub active_for: Duration,
+}
+
+impl CrashReport {
+    /// Where in the timeline the crash fell, e.g. `crashed 3.0s into the memory+network
+    /// overlap`; `None` when no stressor was running.
+    pub fn stressor_context(&self) -> Option<String> {
+        let overlap = self
+            .active_stressors
+            .iter()
+            .map(|stressor| stressor.active_for)
+            .min()?;
+        let mut axes: Vec<String> = Vec::new();
+        let mut by_start: Vec<&ActiveStressor> = self.active_stressors.iter().collect();
+        by_start.sort_by_key(|stressor| std::cmp::Reverse(stressor.active_for));
+        for stressor in by_start {
+            let name = stressor.axis.to_string();
+            if !axes.contains(&name) {
+                axes.push(name);
+            }
+        }
+        let what = if axes.len() == 1 && self.active_stressors.len() == 1 {
+            format!("{} stressor", axes[0])
+        } else {
+            format!("{} overlap", axes.join("+"))
+        };
+        Some(format!(
+            "crashed {:.1}s into the {}",
+            overlap.as_secs_f64(),
+            what
+        ))
+    }
+}
+
+/// Threads, lock state and heap size extracted from a core file by the bundled GDB script.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Postmortem {
+    #[serde(default)]
+    pub core_file: PathBuf,
+    pub threads: Vec<PostmortemThread>,
+    /// Mutexes some thread was blocked on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutexes: Vec<MutexState>,
+    /// glibc main-arena sizes; absent without libc debug info.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heap: Option<HeapStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostmortemThread {
+    pub lwp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Innermost frame first.
+    pub frames: Vec<String>,
+    /// Address of the mutex the thread was blocked acquiring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_on: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MutexState {
+    pub address: String,
+    /// LWP holding the mutex, when the lock word was readable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<u64>,
+    #[serde(default)]
+    pub waiters: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeapStats {
+    pub system_bytes: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_system_bytes: Option<u64>,
+}
+
+/// Captured output moved into the content-addressed store next to a report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputArtifact {
+    /// Which field was moved out: `stdout`, `stderr` or `backtrace`.
+    pub field: String,
+    /// SHA-256 of the stored bytes (also the file name).
+    pub sha256: String,
+    pub bytes: u64,
+    /// Location relative to the directory holding the report.
+    pub path: PathBuf,
+    /// True when the output exceeded the store's size limit and was cut before storing.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// Complete assault report
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssaultReport {
+    pub assail_report: AssailReport,
+    pub attack_results: Vec<AttackResult>,
+    pub total_crashes: usize,
+    pub total_signatures: usize,
+    pub overall_assessment: OverallAssessment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeline: Option<TimelineReport>,
+    /// Campaign seed; rerun with `--seed` to replay the same stress schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Least stress that reproduced each crash bucket (`--explore-crashes`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reproduction: Vec<ReproductionThreshold>,
+    /// Secrets scrubbed from crash output before the report was written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redactions_applied: Option<usize>,
+    /// Campaign-level remarks, such as axes the campaign timeout trimmed or skipped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    /// Identity of each target binary, so runs against different builds can be told apart.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<TargetProvenance>,
+    /// The machine the stress ran on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_environment: Option<HostEnvironment>,
+    /// Each crash bucket checked against the flakiness store (`--flaky`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub crash_history: Vec<CrashHistory>,
+    /// Owner of each crash bucket, from CODEOWNERS for the file of its crashing frame.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub crash_owners: BTreeMap<String, String>,
+    /// Target state that changed between snapshots taken before and after the attacks
+    /// (`--snapshot-dir`, `--snapshot-sqlite`, `--snapshot-url`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_diff: Option<StateDiff>,
+}
+
+/// How a crash bucket of this run relates to earlier runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashHistory {
+    pub bucket: String,
+    pub status: CrashHistoryStatus,
+    /// RFC 3339 time of the first run that hit the bucket (this run for a new failure).
+    pub first_seen: String,
+    /// Runs that hit the bucket, this one included.
+    pub occurrences: u32,
+    /// Acknowledgement note, for acknowledged buckets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashHistoryStatus {
+    NewFailure,
+    KnownFlaky,
+    Acknowledged,
+}
+
+/// State sources snapshotted around an assault.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotConfig {
+    /// Directories whose files are hashed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dirs: Vec<PathBuf>,
+    /// SQLite database dumped with the `sqlite3` client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sqlite: Option<PathBuf>,
+    /// `http://` endpoint that serves the target's state, ideally as JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SnapshotConfig {
+    pub fn is_empty(&self) -> bool {
+        self.dirs.is_empty() && self.sqlite.is_none() && self.url.is_none()
+    }
+}
+
+/// Structural difference between the before and after snapshots, per source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateDiff {
+    pub sources: Vec<SourceDiff>,
+}
+
+impl StateDiff {
+    /// Changes across all sources.
+    pub fn change_count(&self) -> usize {
+        self.sources
+            .iter()
+            .map(|source| source.changes.len() + source.omitted.unwrap_or(0))
+            .sum()
+    }
+}
+
+/// Changes seen in one snapshotted source, such as `dir:/var/lib/app`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceDiff {
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<StateChange>,
+    /// Changes past the per-source cap that were counted but not listed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub omitted: Option<usize>,
+    /// Why the source could not be captured; it is then left out of the diff.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// One file, table row or JSON value that differs between the snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateChange {
+    pub kind: StateChangeKind,
+    /// Relative file path, `table` or `table/row`, or JSON pointer.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Hardware, kernel and limits of the host a report was produced on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostEnvironment {
+    /// Distribution name from os-release, else the OS family (`linux`, `macos`).
+    pub os: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<String>,
+    pub arch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_model: Option<String>,
+    /// Logical CPUs available to panic-attack (affinity and cgroup quotas included).
+    pub cpu_count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_memory_bytes: Option<u64>,
+    /// `v2`, `hybrid` or `v1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup_version: Option<String>,
+    /// Container runtime the run was inside, e.g. `docker` or `kubernetes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(default)]
+    pub limits: ResourceLimits,
+}
+
+/// Soft resource limits the target inherited; unset when unlimited or unknown.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_files: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_space_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub core_bytes: Option<u64>,
+}
+
+/// Which build of a target program a report stressed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetProvenance {
+    pub program: PathBuf,
+    pub sha256: String,
+    pub size_bytes: u64,
+    /// GNU build-id note of an ELF binary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_id: Option<String>,
+    /// First line `--version` printed, when the target answered it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Result of bisecting one crash bucket down to its reproduction threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReproductionThreshold {
+    /// Axis and cause, e.g. `memory:exit-101` or `cpu:useafterfree`.
+    pub bucket: String,
+    pub program: PathBuf,
+    pub axis: AttackAxis,
+    pub original_intensity: IntensityLevel,
+    pub original_duration: Duration,
+    /// False when a rerun at the original settings did not crash the same way.
+    pub reproducible: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_intensity: Option<IntensityLevel>,
+    /// Only searched when the run length drives the stress (ambush, or the time axis).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_duration: Option<Duration>,
+    /// Reruns spent on this bucket.
+    pub probes: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverallAssessment {
+    pub robustness_score: f64,
+    pub critical_issues: Vec<String>,
+    pub recommendations: Vec<String>,
+}
+
+/// Timeline metadata for ambush runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineReport {
+    pub duration: Duration,
+    pub events: Vec<TimelineEventReport>,
+    /// Global `stop_when` condition that ended all stress early, with when it fired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_by: Option<String>,
+    /// Times the timeline ran (`ambush --repeat`); `events` are those of the first run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u32>,
+    /// Events ranked by how often a crash fell while they were active, most suspect first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution: Vec<EventAttribution>,
+}
+
+/// Crash statistics of one timeline event over repeated runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventAttribution {
+    pub event: String,
+    pub axis: AttackAxis,
+    /// Runs in which the event started.
+    pub ran: u32,
+    /// Runs that crashed while the event was active.
+    pub crashes_while_active: u32,
+    /// `crashes_while_active` over all runs.
+    pub crash_probability: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TimelineEventReport {
     pub id: String,
     pub axis: AttackAxis,
@@ -503,23 +2201,35 @@ pub struct TimelineEventReport {
     #[serde(default)]
     pub args: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub peak_memory: Option<u64>,
     #[serde(default)]
     pub ran: bool,
+    /// What started a dependent or conditional event (`after x`, `rss > 1GiB at 12.30s`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
+    /// Event `stop_when` condition that ended this event early.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_storm: Option<SignalStormReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_pressure: Option<MemoryPressureReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_throttle: Option<DiskThrottleReport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_chaos: Option<CpuChaosReport>,
 }
 
 /// Matrix rows representing taint source/sink interactions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TaintMatrix {
     pub rows: Vec<TaintMatrixRow>,
 }
 
-impl Default for TaintMatrix {
-    fn default() -> Self {
-        Self { rows: Vec::new() }
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaintMatrixRow {
     pub source_category: WeakPointCategory,
@@ -807,3 +2517,38 @@ pub enum Predicate {
     },
     Fact(Fact),
 }
+
+/// Attack fixtures shared by unit tests across modules.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+
+    /// A one-second light attack on `/bin/true` along `axes`, seeded 42.
+    pub(crate) fn config(axes: Vec<AttackAxis>) -> AttackConfig {
+        AttackConfig {
+            axes,
+            duration: Duration::from_secs(1),
+            intensity: IntensityLevel::Light,
+            target_programs: vec![PathBuf::from("/bin/true")],
+            seed: 42,
+            ..Default::default()
+        }
+    }
+
+    /// A one-second run of `target` along `axis`; any exit code but 0 comes with a crash.
+    pub(crate) fn result(axis: AttackAxis, exit_code: i32) -> AttackResult {
+        AttackResult {
+            program: PathBuf::from("target"),
+            axis,
+            success: exit_code == 0,
+            exit_code: Some(exit_code),
+            duration: Duration::from_secs(1),
+            crashes: if exit_code == 0 {
+                Vec::new()
+            } else {
+                vec![CrashReport::default()]
+            },
+            ..Default::default()
+        }
+    }
+}
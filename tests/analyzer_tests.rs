@@ -229,3 +229,406 @@ fn main() {
     assert!(stats.file_path.contains("test.rs"));
     assert!(stats.lines > 0);
 }
+
+#[test]
+fn test_rust_analyzer_separates_unsafe_hazards() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+static mut COUNTER: u32 = 0;
+
+struct Handle(*mut u8);
+unsafe impl Send for Handle {}
+unsafe impl Sync for Handle {}
+
+fn main() {
+    let x = 5u32;
+    let p = &x as *const u32;
+    let total = x * 2;
+    unsafe {
+        let y = *p;
+        COUNTER += y;
+        let f: f32 = std::mem::transmute(y);
+    }
+}
+"#;
+    let file = create_test_file(&dir, "hazards.rs", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+
+    let find = |category: WeakPointCategory| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.category == category)
+            .unwrap_or_else(|| panic!("missing {:?}", category))
+    };
+    assert_eq!(
+        find(WeakPointCategory::UnsafeTransmute).recommended_attack,
+        vec![AttackAxis::Memory]
+    );
+    assert!(find(WeakPointCategory::RawPointerDeref)
+        .description
+        .starts_with("1 raw pointer"));
+    assert_eq!(
+        find(WeakPointCategory::MutableStatic).recommended_attack,
+        vec![AttackAxis::Concurrency]
+    );
+    let send_sync = find(WeakPointCategory::ManualSendSync);
+    assert!(send_sync.description.contains("Send for Handle"));
+    assert!(send_sync.description.contains("Sync for Handle"));
+    assert_eq!(send_sync.recommended_attack, vec![AttackAxis::Concurrency]);
+}
+
+#[test]
+fn test_rust_analyzer_detects_async_hazards() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+use std::time::Duration;
+
+pub async fn poll(tx: tokio::sync::mpsc::UnboundedSender<u8>) {
+    std::thread::sleep(Duration::from_millis(10));
+    let data = std::fs::read("state.bin").unwrap_or_default();
+    tx.send(data.len() as u8).ok();
+}
+
+pub fn spawn_channel() {
+    let (_tx, _rx) = tokio::sync::mpsc::unbounded_channel::<u8>();
+}
+
+pub fn sync_wrapper() {
+    futures::executor::block_on(async { tokio::time::sleep(Duration::from_millis(1)).await });
+}
+"#;
+    let file = create_test_file(&dir, "lib.rs", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+
+    let find = |category: WeakPointCategory, needle: &str| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.category == category && wp.description.contains(needle))
+            .unwrap_or_else(|| panic!("missing {:?} ({})", category, needle))
+    };
+    let timing = vec![AttackAxis::Concurrency, AttackAxis::Time];
+    assert!(find(WeakPointCategory::BlockingIO, "inside async code")
+        .description
+        .starts_with("2 blocking"));
+    assert_eq!(
+        find(WeakPointCategory::UncheckedAllocation, "unbounded channel").recommended_attack,
+        timing
+    );
+    assert_eq!(
+        find(WeakPointCategory::DeadlockPotential, "block_on").recommended_attack,
+        timing
+    );
+
+    // The same block_on in a binary entry point is the normal way to start a runtime.
+    let bin = create_test_file(
+        &dir,
+        "main.rs",
+        "fn main() { futures::executor::block_on(run()); }",
+    );
+    let report = assail::analyze(&bin).expect("analysis should succeed");
+    assert!(!report
+        .weak_points
+        .iter()
+        .any(|wp| wp.description.contains("block_on")));
+}
+
+#[test]
+fn test_go_analyzer_detects_race_and_context_misuse() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+package main
+
+import (
+    "context"
+    "os"
+    "time"
+)
+
+func collect(ctx context.Context, paths []string) {
+    seen := make(map[string]int)
+    for _, p := range paths {
+        go func(p string) {
+            seen[p] = len(p)
+        }(p)
+    }
+    time.Sleep(100 * time.Millisecond)
+
+    for {
+        poll()
+    }
+}
+
+func readAll(paths []string) {
+    for _, p := range paths {
+        f, _ := os.Open(p)
+        defer f.Close()
+    }
+}
+"#;
+    let file = create_test_file(&dir, "main.go", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+
+    let find = |category: WeakPointCategory, needle: &str| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.category == category && wp.description.contains(needle))
+            .unwrap_or_else(|| panic!("missing {:?} ({})", category, needle))
+    };
+    assert_eq!(
+        find(WeakPointCategory::RaceCondition, "seen").recommended_attack,
+        vec![AttackAxis::Concurrency]
+    );
+    find(WeakPointCategory::RaceCondition, "time.Sleep");
+    assert!(find(WeakPointCategory::UnboundedLoop, "ctx.Done()")
+        .description
+        .starts_with("1 long-running"));
+    find(WeakPointCategory::ResourceLeak, "defer");
+}
+
+#[test]
+fn test_python_analyzer_detects_async_and_injection_patterns() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+import asyncio, pickle, subprocess, time, requests
+
+async def refresh(host):
+    time.sleep(1)
+    resp = requests.get(f"https://{host}/state")
+    return pickle.loads(resp.content)
+
+def archive(name):
+    subprocess.run(f"tar czf {name}.tgz data/", shell=True)
+    try:
+        cleanup()
+    except:
+        pass
+"#;
+    let file = create_test_file(&dir, "worker.py", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+
+    let find = |category: WeakPointCategory, needle: &str| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.category == category && wp.description.contains(needle))
+            .unwrap_or_else(|| panic!("missing {:?} ({})", category, needle))
+    };
+    assert!(find(WeakPointCategory::BlockingIO, "async def")
+        .description
+        .starts_with("2 blocking"));
+    assert_eq!(
+        find(WeakPointCategory::CommandInjection, "interpolated").severity,
+        Severity::Critical
+    );
+    assert!(
+        find(WeakPointCategory::UnsafeDeserialization, "external data")
+            .recommended_attack
+            .contains(&AttackAxis::Network)
+    );
+    find(WeakPointCategory::UncheckedError, "bare except");
+}
+
+#[test]
+fn test_js_analyzer_detects_pollution_and_event_loop_blocking() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+import express from "express";
+import fs from "fs";
+import _ from "lodash";
+
+const app = express();
+const settings = {};
+
+app.post("/settings", (req, res) => {
+    _.merge(settings, req.body);
+    const template = fs.readFileSync("views/settings.html", "utf8");
+    const render = new Function("data", template);
+    fetch("https://audit.example/log").then((r) => r.json());
+    res.send(render(settings));
+});
+
+app.listen(8080);
+"#;
+    let file = create_test_file(&dir, "server.ts", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+    assert_eq!(report.language, Language::JavaScript);
+
+    let find = |category: WeakPointCategory, needle: &str| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.category == category && wp.description.contains(needle))
+            .unwrap_or_else(|| panic!("missing {:?} ({})", category, needle))
+    };
+    find(
+        WeakPointCategory::DynamicCodeExecution,
+        "Function constructor",
+    );
+    assert!(find(
+        WeakPointCategory::UnsafeDeserialization,
+        "prototype pollution"
+    )
+    .recommended_attack
+    .contains(&AttackAxis::Network));
+    find(WeakPointCategory::BlockingIO, "server code");
+    find(WeakPointCategory::UncheckedError, "without .catch()");
+
+    // Declaration files carry no runtime code.
+    let decl = create_test_file(
+        &dir,
+        "server.d.ts",
+        "export declare function merge(a: object): void;",
+    );
+    let report = assail::analyze(&decl).expect("analysis should succeed");
+    assert!(report.weak_points.is_empty());
+}
+
+#[test]
+fn test_magic_comments_suppress_and_force_findings() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"
+// panic-attack: ignore category=UnsafeCode reason="audited FFI shim"
+fn main() {
+    unsafe {
+        let p = std::ptr::null::<u8>();
+    }
+    // panic-attack: axis=concurrency severity=high reason="shared cache without lock"
+    let cache = std::cell::RefCell::new(0);
+}
+"#;
+    let file = create_test_file(&dir, "shim.rs", content);
+    let report = assail::analyze(&file).expect("analysis should succeed");
+
+    assert!(!report
+        .weak_points
+        .iter()
+        .any(|wp| wp.category == WeakPointCategory::UnsafeCode));
+    let forced = report
+        .weak_points
+        .iter()
+        .find(|wp| wp.description.starts_with("Annotated at"))
+        .expect("forced finding");
+    assert_eq!(forced.category, WeakPointCategory::RaceCondition);
+    assert!(forced
+        .description
+        .ends_with("shim.rs:7: shared cache without lock"));
+
+    assert_eq!(report.annotations.len(), 2);
+    assert_eq!(report.annotations[0].action, AnnotationAction::Ignore);
+    assert_eq!(report.annotations[0].suppressed, 1);
+    assert!(report.annotations[0].text.contains("audited FFI shim"));
+}
+
+#[test]
+fn test_directory_policy_excludes_and_downgrades_subtree() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    fs::create_dir_all(dir.path().join("tests")).unwrap();
+    let content = "static mut COUNTER: u32 = 0;\nfn main() {\n    unsafe { COUNTER += 1; }\n}\n";
+    create_test_file(&dir, "src/main.rs", content);
+    create_test_file(&dir, "tests/it.rs", content);
+    create_test_file(
+        &dir,
+        "tests/panic-attack.policy.yaml",
+        "exclude_categories: [MutableStatic]\nmax_severity: Low\n",
+    );
+
+    let report = assail::analyze(dir.path()).expect("analysis should succeed");
+
+    let in_tests: Vec<_> = report
+        .weak_points
+        .iter()
+        .filter(|wp| wp.location.as_deref() == Some("tests/it.rs"))
+        .collect();
+    assert!(!in_tests.is_empty());
+    assert!(in_tests
+        .iter()
+        .all(|wp| wp.category != WeakPointCategory::MutableStatic && wp.severity == Severity::Low));
+    assert!(report.weak_points.iter().any(|wp| {
+        wp.location.as_deref() == Some("src/main.rs")
+            && wp.category == WeakPointCategory::MutableStatic
+    }));
+
+    assert_eq!(report.policies.len(), 1);
+    assert_eq!(report.policies[0].path, "tests/panic-attack.policy.yaml");
+    assert!(report.policies[0].excluded == 1 && report.policies[0].adjusted >= 1);
+}
+
+#[test]
+fn test_advisories_flag_declared_dependencies() {
+    let dir = TempDir::new().unwrap();
+    create_test_file(&dir, "main.rs", "fn main() {}\n");
+    create_test_file(
+        &dir,
+        "Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nhyper = \"0.14.2\"\nserde = \"1\"\n",
+    );
+    let osv = dir.path().join("osv.json");
+    fs::write(
+        &osv,
+        r#"[{
+            "id": "RUSTSEC-2023-0034",
+            "summary": "Resource exhaustion vulnerability in h2 may lead to Denial of Service",
+            "affected": [{
+                "package": { "ecosystem": "crates.io", "name": "hyper" },
+                "ranges": [{ "type": "SEMVER", "events": [
+                    { "introduced": "0.0.0-0" }, { "fixed": "0.14.10" }
+                ] }]
+            }]
+        }]"#,
+    )
+    .unwrap();
+
+    let database = assail::advisories::AdvisoryDatabase::load(&osv).unwrap();
+    let report = assail::Analyzer::new(dir.path())
+        .unwrap()
+        .with_advisories(database)
+        .analyze()
+        .expect("analysis should succeed");
+
+    let flagged: Vec<_> = report
+        .weak_points
+        .iter()
+        .filter(|wp| wp.category == WeakPointCategory::VulnerableDependency)
+        .collect();
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].location.as_deref(), Some("Cargo.toml"));
+    assert!(flagged[0].description.contains("hyper 0.14.2"));
+    assert!(flagged[0].recommended_attack.contains(&AttackAxis::Cpu));
+    assert_eq!(report.manifests.len(), 1);
+}
+
+#[test]
+fn test_codeowners_assign_weak_point_owners() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".git")).unwrap();
+    fs::create_dir_all(dir.path().join(".github")).unwrap();
+    fs::create_dir_all(dir.path().join("app/src")).unwrap();
+    fs::create_dir_all(dir.path().join("app/tests")).unwrap();
+    create_test_file(
+        &dir,
+        ".github/CODEOWNERS",
+        "*             @core\n/app/src/     @backend-team @alice\n",
+    );
+    let content = "fn main() {\n    unsafe { std::ptr::null::<i32>().read(); }\n}\n";
+    create_test_file(&dir, "app/src/main.rs", content);
+    create_test_file(&dir, "app/tests/it.rs", content);
+
+    // Scanning a subdirectory: locations are relative to it, CODEOWNERS to the repository.
+    let report = assail::analyze(dir.path().join("app")).expect("analysis should succeed");
+    let owner_of = |location: &str| {
+        report
+            .weak_points
+            .iter()
+            .find(|wp| wp.location.as_deref() == Some(location))
+            .and_then(|wp| wp.owner.clone())
+    };
+    assert_eq!(
+        owner_of("src/main.rs").as_deref(),
+        Some("@backend-team @alice")
+    );
+    assert_eq!(owner_of("tests/it.rs").as_deref(), Some("@core"));
+}
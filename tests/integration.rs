@@ -77,3 +77,19 @@ fn test_assail_per_file_stats() {
         assert!(fs.lines > 0, "lines should be > 0 for source files");
     }
 }
+
+#[test]
+fn test_missing_targets_have_typed_errors() {
+    use panic_attack::error::{AssailError, AttackError};
+
+    let missing = Path::new("/nonexistent/panic-attack-target");
+    let err = assail::analyze(missing).expect_err("missing target should fail");
+    assert!(matches!(err, AssailError::TargetMissing(_)), "{:?}", err);
+    assert_eq!(err.code(), "assail.target_missing");
+
+    let err = panic_attack::attack::check_target(missing).expect_err("missing program");
+    assert!(matches!(err, AttackError::TargetMissing(_)), "{:?}", err);
+
+    let err = panic_attack::attack::parse_axis("Gr@vity").expect_err("invalid axis name");
+    assert_eq!(err.code(), "attack.unsupported_axis");
+}
@@ -4,6 +4,7 @@
 
 use panic_attack::panll;
 use panic_attack::types::*;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::Duration;
 use tempfile::TempDir;
@@ -35,6 +36,9 @@ fn make_assault_report(
             taint_matrix: TaintMatrix { rows: vec![] },
             recommended_attacks: vec![],
             migration_metrics: None,
+            annotations: Vec::new(),
+            policies: Vec::new(),
+            manifests: Vec::new(),
         },
         attack_results,
         total_crashes: 0,
@@ -45,6 +49,15 @@ fn make_assault_report(
             recommendations: vec![],
         },
         timeline: None,
+        seed: None,
+        reproduction: Vec::new(),
+        redactions_applied: None,
+        notes: Vec::new(),
+        targets: Vec::new(),
+        host_environment: None,
+        crash_history: Vec::new(),
+        crash_owners: BTreeMap::new(),
+        state_diff: None,
     }
 }
 
@@ -71,6 +84,9 @@ fn test_panll_export_summary_reflects_report() {
             severity: Severity::Critical,
             description: "unsafe block".to_string(),
             recommended_attack: vec![],
+            provenance: None,
+            calibrated: None,
+            owner: None,
         }],
         vec![],
     );
@@ -96,6 +112,9 @@ fn test_panll_export_constraints_from_critical_wp() {
                 severity: Severity::Critical,
                 description: "transmute usage".to_string(),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
             WeakPoint {
                 category: WeakPointCategory::PanicPath,
@@ -103,6 +122,9 @@ fn test_panll_export_constraints_from_critical_wp() {
                 severity: Severity::Medium,
                 description: "unwrap call".to_string(),
                 recommended_attack: vec![],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
         ],
         vec![],
@@ -141,6 +163,21 @@ fn test_panll_export_event_chain_from_attacks() {
                 peak_memory: 1024,
                 crashes: vec![],
                 signatures_detected: vec![],
+                signal_storm: None,
+                memory_pressure: None,
+                disk_throttle: None,
+                cpu_chaos: None,
+                adaptive: None,
+                stress_profile: None,
+                hang: None,
+                stall: None,
+                telemetry: None,
+                gpu: None,
+                browser: None,
+                database: None,
+                flood: None,
+                net_faults: None,
+                budget_note: None,
             },
             AttackResult {
                 program: PathBuf::from("/tmp/target"),
@@ -153,6 +190,21 @@ fn test_panll_export_event_chain_from_attacks() {
                 peak_memory: 4096,
                 crashes: vec![],
                 signatures_detected: vec![],
+                signal_storm: None,
+                memory_pressure: None,
+                disk_throttle: None,
+                cpu_chaos: None,
+                adaptive: None,
+                stress_profile: None,
+                hang: None,
+                stall: None,
+                telemetry: None,
+                gpu: None,
+                browser: None,
+                database: None,
+                flood: None,
+                net_faults: None,
+                budget_note: None,
             },
         ],
     );
@@ -191,8 +243,31 @@ fn test_panll_export_constraints_from_failed_attacks() {
                 backtrace: None,
                 stderr: "segfault".to_string(),
                 stdout: String::new(),
+                artifacts: Vec::new(),
+                repro_scripts: Vec::new(),
+                rr_trace: None,
+                schedule: None,
+                injected_faults: Vec::new(),
+                postmortem: None,
+                active_stressors: Vec::new(),
+                frames: Vec::new(),
             }],
             signatures_detected: vec![],
+            signal_storm: None,
+            memory_pressure: None,
+            disk_throttle: None,
+            cpu_chaos: None,
+            adaptive: None,
+            stress_profile: None,
+            hang: None,
+            stall: None,
+            telemetry: None,
+            gpu: None,
+            browser: None,
+            database: None,
+            flood: None,
+            net_faults: None,
+            budget_note: None,
         }],
     );
     report.total_crashes = 1;
@@ -230,6 +305,21 @@ fn test_panll_export_skipped_attacks_not_in_constraints() {
             peak_memory: 0,
             crashes: vec![],
             signatures_detected: vec![],
+            signal_storm: None,
+            memory_pressure: None,
+            disk_throttle: None,
+            cpu_chaos: None,
+            adaptive: None,
+            stress_profile: None,
+            hang: None,
+            stall: None,
+            telemetry: None,
+            gpu: None,
+            browser: None,
+            database: None,
+            flood: None,
+            net_faults: None,
+            budget_note: None,
         }],
     );
 
@@ -471,7 +471,7 @@ fn readiness_b_panicbot_json_contract() {
         sev
     );
 
-    // All 20 WeakPointCategory variants must map to PA001–PA020
+    // All 25 WeakPointCategory variants must map to PA001–PA025
     // Verify the category enum values match panicbot's expected strings
     let expected_categories = [
         "UncheckedAllocation", "UnboundedLoop", "BlockingIO", "UnsafeCode",
@@ -479,7 +479,8 @@ fn readiness_b_panicbot_json_contract() {
         "CommandInjection", "UnsafeDeserialization", "DynamicCodeExecution",
         "UnsafeFFI", "AtomExhaustion", "InsecureProtocol", "ExcessivePermissions",
         "PathTraversal", "HardcodedSecret", "UncheckedError", "InfiniteRecursion",
-        "UnsafeTypeCoercion",
+        "UnsafeTypeCoercion", "UnsafeTransmute", "RawPointerDeref", "MutableStatic",
+        "ManualSendSync", "VulnerableDependency",
     ];
     for variant_name in &expected_categories {
         let variant_json = format!("\"{}\"", variant_name);
@@ -2,6 +2,7 @@
 
 //! Tests for the report generation and formatting modules
 
+use panic_attack::i18n::{Lang, TemplateSet};
 use panic_attack::report::{self, ReportOutputFormat};
 use panic_attack::types::*;
 use std::path::PathBuf;
@@ -19,6 +20,9 @@ fn make_assail_report() -> AssailReport {
                 severity: Severity::Critical,
                 description: "2 unsafe blocks in src/main.rs".to_string(),
                 recommended_attack: vec![AttackAxis::Memory, AttackAxis::Concurrency],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
             WeakPoint {
                 category: WeakPointCategory::PanicPath,
@@ -26,6 +30,9 @@ fn make_assail_report() -> AssailReport {
                 severity: Severity::Medium,
                 description: "5 unwrap/expect calls in src/lib.rs".to_string(),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
         ],
         statistics: ProgramStatistics {
@@ -41,7 +48,7 @@ fn make_assail_report() -> AssailReport {
         dependency_graph: DependencyGraph { edges: vec![] },
         taint_matrix: TaintMatrix { rows: vec![] },
         recommended_attacks: vec![AttackAxis::Memory, AttackAxis::Concurrency],
-        migration_metrics: None,
+        ..Default::default()
     }
 }
 
@@ -50,22 +57,20 @@ fn make_attack_result(axis: AttackAxis, success: bool, crashes: usize) -> Attack
         .map(|_| CrashReport {
             timestamp: "2026-03-01T00:00:00Z".to_string(),
             signal: Some("SIGSEGV".to_string()),
-            backtrace: None,
             stderr: "segfault".to_string(),
-            stdout: String::new(),
+            ..Default::default()
         })
         .collect();
     AttackResult {
         program: PathBuf::from("/tmp/test-program"),
         axis,
         success,
-        skipped: false,
-        skip_reason: None,
         exit_code: if success { Some(0) } else { Some(139) },
         duration: Duration::from_millis(100),
         peak_memory: 1024,
         crashes: crash_reports,
         signatures_detected: vec![],
+        ..Default::default()
     }
 }
 
@@ -135,6 +140,9 @@ fn test_robustness_score_clamped_to_zero() {
             severity: Severity::Critical,
             description: format!("critical issue {}", i),
             recommended_attack: vec![],
+            provenance: None,
+            calibrated: None,
+            owner: None,
         });
     }
     let results = vec![make_attack_result(AttackAxis::Memory, false, 5)];
@@ -174,6 +182,36 @@ fn test_critical_issues_from_crashes() {
     );
 }
 
+#[test]
+fn test_assessment_uses_report_language_and_org_templates() {
+    let templates: TemplateSet = serde_yaml::from_str(
+        "de:\n  assess.rec.unsafe: \"{count} unsafe-Blöcke prüfen, siehe wiki/unsafe\"\n  assess.rec.error_handling: \"\"\n",
+    )
+    .unwrap();
+    let generator = report::ReportGenerator::new()
+        .with_lang(Lang::De)
+        .with_templates(templates);
+    let report = generator
+        .generate(
+            make_assail_report(),
+            vec![make_attack_result(AttackAxis::Memory, false, 3)],
+        )
+        .unwrap();
+    let assessment = &report.overall_assessment;
+    assert_eq!(
+        assessment.critical_issues[0],
+        "Programm ist unter Memory-Angriff abgestürzt (3 Abstürze)"
+    );
+    assert!(assessment
+        .recommendations
+        .contains(&"2 unsafe-Blöcke prüfen, siehe wiki/unsafe".to_string()));
+    // The empty override drops the error-handling advice despite the crashes.
+    assert!(!assessment
+        .recommendations
+        .iter()
+        .any(|rec| rec.contains("Fehlerbehandlung")));
+}
+
 #[test]
 fn test_json_serialization_roundtrip() {
     let assail = make_assail_report();
@@ -186,6 +224,37 @@ fn test_json_serialization_roundtrip() {
     assert!(parsed["overall_assessment"]["robustness_score"].is_number());
 }
 
+#[test]
+fn test_intensity_presets_keep_their_names() {
+    let levels = vec![
+        IntensityLevel::Heavy,
+        IntensityLevel::from_scale(0.65).unwrap(),
+    ];
+    let json = serde_json::to_string(&levels).unwrap();
+    assert_eq!(json, r#"["Heavy",0.65]"#);
+    let back: Vec<IntensityLevel> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, levels);
+
+    // A scale value that lands on a preset is that preset.
+    assert_eq!(IntensityLevel::from_scale(0.5).unwrap(), IntensityLevel::Medium);
+    assert_eq!("0.75".parse::<IntensityLevel>().unwrap(), IntensityLevel::Heavy);
+    assert!(serde_json::from_str::<IntensityLevel>("1.5").is_err());
+    assert!(IntensityLevel::from_scale(f64::NAN).is_err());
+
+    // Multipliers interpolate between the presets, which keep their historical values.
+    assert_eq!(IntensityLevel::Medium.multiplier(), 5.0);
+    assert_eq!(IntensityLevel::Extreme.multiplier(), 50.0);
+    let between = IntensityLevel::from_scale(0.65).unwrap().multiplier();
+    assert!(between > 5.0 && between < 10.0);
+}
+
+#[test]
+fn test_signal_axis_is_opt_in() {
+    assert!(!AttackAxis::defaults().contains(&AttackAxis::Signal));
+    assert_eq!(AttackAxis::defaults().len() + 1, AttackAxis::all().len());
+    assert_eq!(AttackAxis::builtin("signal"), Some(AttackAxis::Signal));
+}
+
 #[test]
 fn test_yaml_serialization() {
     let assail = make_assail_report();
@@ -208,3 +277,360 @@ fn test_sarif_serialization() {
     assert!(parsed["$schema"].is_string());
     assert!(parsed["runs"].is_array());
 }
+
+#[test]
+fn test_stress_profile_roundtrip_omits_unused_fields() {
+    let assail = make_assail_report();
+    let mut result = make_attack_result(AttackAxis::Network, true, 0);
+    let mut profile = StressProfile::new(IntensityLevel::Heavy, Duration::from_secs(30), 7);
+    profile.network_clients = Some(200);
+    profile.network_payload_bytes = Some(4096);
+    result.stress_profile = Some(profile.clone());
+    let report = report::generate_assault_report(assail, vec![result]).unwrap();
+
+    let json = ReportOutputFormat::Json.serialize(&report).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let stored = &parsed["attack_results"][0]["stress_profile"];
+    assert_eq!(stored["network_clients"], 200);
+    assert_eq!(stored["multiplier"], 10.0);
+    assert!(stored.get("workers").is_none());
+
+    let restored: AssaultReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.attack_results[0].stress_profile, Some(profile));
+}
+
+#[test]
+fn test_save_report_moves_large_output_to_artifacts() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let mut result = make_attack_result(AttackAxis::Memory, false, 1);
+    result.crashes[0].stderr = "thread 'main' panicked\n".repeat(2048);
+    let full = result.crashes[0].stderr.clone();
+    let report = report::generate_assault_report(make_assail_report(), vec![result]).unwrap();
+
+    let path = dir.path().join("report.json");
+    report::save_report(&report, &path, ReportOutputFormat::Json).unwrap();
+    let saved = report::load_report(&path).unwrap();
+    let crash = &saved.attack_results[0].crashes[0];
+    assert!(crash.stderr.len() < full.len());
+    assert_eq!(crash.artifacts.len(), 1);
+    assert_eq!(crash.artifacts[0].field, "stderr");
+
+    let store = report::ArtifactStore::beside(&path);
+    assert_eq!(store.load(&crash.artifacts[0]).unwrap(), full);
+
+    // Re-saving keeps the existing reference instead of storing the preview.
+    report::save_report(&saved, &path, ReportOutputFormat::Json).unwrap();
+    let resaved = report::load_report(&path).unwrap();
+    assert_eq!(resaved.attack_results[0].crashes[0].artifacts.len(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_save_report_writes_runnable_repro_scripts() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let mut result = make_attack_result(AttackAxis::Cpu, false, 1);
+    result.program = PathBuf::from("/bin/sh");
+    let mut profile = StressProfile::new(IntensityLevel::Heavy, Duration::from_secs(5), 99);
+    profile.target_args = vec!["-c".to_string(), "echo \"it's $0\"; exit 3".to_string()];
+    result.stress_profile = Some(profile);
+    let report = report::generate_assault_report(make_assail_report(), vec![result]).unwrap();
+
+    let path = dir.path().join("run.json");
+    report::save_report_with_repro(
+        &report,
+        &path,
+        ReportOutputFormat::Json,
+        report::ReproOptions { rust_tests: true },
+    )
+    .unwrap();
+    let saved = report::load_report(&path).unwrap();
+    let scripts = &saved.attack_results[0].crashes[0].repro_scripts;
+    assert_eq!(
+        scripts,
+        &vec![
+            PathBuf::from("repro/run/sh-cpu-crash-1.sh"),
+            PathBuf::from("repro/run/sh_cpu_crash_1.rs"),
+        ]
+    );
+
+    let script = std::fs::read_to_string(dir.path().join(&scripts[0])).unwrap();
+    assert!(script.contains("seed 99"));
+    assert!(script.contains("panic-attack --seed 99 attack /bin/sh --axis cpu"));
+    let output = std::process::Command::new(dir.path().join(&scripts[0]))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "it's /bin/sh\n");
+
+    let test = std::fs::read_to_string(dir.path().join(&scripts[1])).unwrap();
+    assert!(test.contains("fn repro_cpu_crash_1()"));
+    assert!(test.contains(".args([\"-c\", "));
+}
+
+#[test]
+fn test_query_stored_runs() {
+    use panic_attack::storage::query::{query_runs, Query};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let clean = report::generate_assault_report(
+        make_assail_report(),
+        vec![make_attack_result(AttackAxis::Memory, true, 0)],
+    )
+    .unwrap();
+    let crashed = report::generate_assault_report(
+        make_assail_report(),
+        vec![
+            make_attack_result(AttackAxis::Cpu, true, 0),
+            make_attack_result(AttackAxis::Memory, false, 2),
+        ],
+    )
+    .unwrap();
+    let old = dir.path().join("panic-attack-20200101000000.json");
+    let recent = dir.path().join(format!(
+        "panic-attack-{}.json",
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    report::save_report(&crashed, &old, ReportOutputFormat::Json).unwrap();
+    report::save_report(&crashed, &recent, ReportOutputFormat::Json).unwrap();
+    report::save_report(
+        &clean,
+        dir.path().join("panic-attack-20250101000000.json"),
+        ReportOutputFormat::Json,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("notes.json"), "{}").unwrap();
+
+    let query = Query::parse("crashes > 0 AND crashed_axis = memory").unwrap();
+    let matched = query_runs(dir.path(), &query).unwrap();
+    assert_eq!(matched.len(), 2);
+    assert_eq!(matched[0].path, old);
+    assert_eq!(matched[0].crashed_axes, vec!["memory".to_string()]);
+
+    let query = Query::parse("crashes > 0 AND axis = memory AND since = 7d").unwrap();
+    let matched = query_runs(dir.path(), &query).unwrap();
+    assert_eq!(matched.len(), 1);
+    assert_eq!(matched[0].path, recent);
+
+    let query = Query::parse("crashed_axis != memory AND language = rust").unwrap();
+    assert_eq!(query_runs(dir.path(), &query).unwrap().len(), 1);
+}
+
+#[test]
+fn test_adjudicate_flags_stalled_attacks() {
+    use panic_attack::adjudicate::{self, AdjudicateConfig};
+
+    let mut stalled = make_attack_result(AttackAxis::Network, false, 0);
+    stalled.stall = Some(StallSignal {
+        check: ProgressCheck {
+            kind: ProgressKind::Tcp,
+            target: "127.0.0.1:8080".to_string(),
+        },
+        window: Duration::from_secs(30),
+        detected_at: Duration::from_secs(42),
+        last_progress: Some(Duration::from_secs(12)),
+    });
+    let mut assail = make_assail_report();
+    // Without critical weak points the stall alone decides the verdict.
+    assail
+        .weak_points
+        .retain(|wp| wp.severity != Severity::Critical);
+    let report = report::generate_assault_report(assail, vec![stalled]).unwrap();
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("stalled.json");
+    report::save_report(&report, &path, ReportOutputFormat::Json).unwrap();
+
+    let out = adjudicate::run(AdjudicateConfig {
+        reports: vec![path],
+        previous: None,
+        seed: 0,
+    })
+    .unwrap();
+    assert_eq!(out.totals.stalled_attacks, 1);
+    assert_eq!(out.verdict, "warn");
+    assert!(out
+        .priorities
+        .iter()
+        .any(|item| item.message == "1 attacks stalled without progress"));
+}
+
+#[test]
+fn test_diff_and_adjudicate_notice_different_builds() {
+    use panic_attack::adjudicate::{self, AdjudicateConfig};
+    use panic_attack::report::{format_diff, DiffOptions, DiffReport};
+
+    let build = |sha: char, version: &str| TargetProvenance {
+        program: PathBuf::from("./target/release/app"),
+        sha256: sha.to_string().repeat(64),
+        size_bytes: 1024,
+        build_id: None,
+        version: Some(version.to_string()),
+    };
+    let results = vec![make_attack_result(AttackAxis::Cpu, true, 0)];
+    let mut base = report::generate_assault_report(make_assail_report(), results.clone()).unwrap();
+    base.targets = vec![build('a', "app 1.0")];
+    let mut compare = base.clone();
+    compare.targets = vec![build('b', "app 1.1")];
+
+    let options = DiffOptions::default();
+    assert!(DiffReport::build(&base, &base, "a", "a", &options)
+        .targets
+        .is_empty());
+    let diff = DiffReport::build(&base, &compare, "a", "b", &options);
+    assert_eq!(diff.targets.len(), 1);
+    let text = format_diff(&base, &compare, "a", "b", &options);
+    assert!(text.contains("WARNING: different target build"));
+    assert!(text.contains("Version: app 1.0 -> app 1.1"));
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let paths = vec![dir.path().join("a.json"), dir.path().join("b.json")];
+    report::save_report(&base, &paths[0], ReportOutputFormat::Json).unwrap();
+    report::save_report(&compare, &paths[1], ReportOutputFormat::Json).unwrap();
+    let out = adjudicate::run(AdjudicateConfig {
+        reports: paths,
+        previous: None,
+        seed: 0,
+    })
+    .unwrap();
+    assert_eq!(out.totals.mixed_build_targets, 1);
+    assert!(out
+        .notes
+        .iter()
+        .any(|note| note.contains("reports stressed 2 different builds")));
+}
+
+#[test]
+fn test_sarif_locates_crashes_by_backtrace() {
+    let assail = make_assail_report();
+    let mut result = make_attack_result(AttackAxis::Memory, false, 2);
+    result.crashes[0].frames = panic_attack::signatures::backtrace::parse(
+        "stack backtrace:\n   0: rust_begin_unwind\n   1: app::parse\n             at ./src/parse.rs:42:9\n",
+    );
+    let report = report::generate_assault_report(assail, vec![result]).unwrap();
+
+    let sarif = ReportOutputFormat::Sarif.serialize(&report).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let run = &parsed["runs"][0];
+    let crashes: Vec<&serde_json::Value> = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|r| r["ruleId"] == "PA100")
+        .collect();
+    assert_eq!(crashes.len(), 2);
+    let location = &crashes[0]["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "./src/parse.rs");
+    assert_eq!(location["region"]["startLine"], 42);
+    assert_eq!(
+        crashes[0]["message"]["text"],
+        "Memory attack crashed the target (SIGSEGV) in app::parse"
+    );
+    // No parsed frames: the crash is pinned to the binary.
+    assert_eq!(
+        crashes[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "/tmp/test-program"
+    );
+    assert!(run["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|rule| rule["id"] == "PA100"));
+}
+
+#[test]
+fn test_triage_annotations_carry_across_diffs() {
+    use panic_attack::report::{format_diff, DiffOptions, DiffReport};
+    use panic_attack::triage::{Annotation, TriageState, TriageStore};
+
+    let base = report::generate_assault_report(
+        make_assail_report(),
+        vec![make_attack_result(AttackAxis::Memory, false, 1)],
+    )
+    .unwrap();
+    let mut compare = base.clone();
+    compare.assail_report.weak_points.remove(0);
+
+    let mut store = TriageStore::default();
+    let annotate = |store: &mut TriageStore, finding: &str, state| {
+        let annotation = Annotation {
+            state,
+            author: "bob".to_string(),
+            at: "2026-03-02T10:00:00+00:00".to_string(),
+            comment: Some("on it".to_string()),
+            report: None,
+        };
+        store.annotate(&base, finding, annotation)
+    };
+    annotate(&mut store, "PA004@src/main.rs", TriageState::Fixed).unwrap();
+    annotate(&mut store, "memory:sigsegv", TriageState::Investigating).unwrap();
+    assert!(annotate(&mut store, "PA004@src/other.rs", TriageState::Fixed).is_err());
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = TriageStore::path_beside(&dir.path().join("report.json"));
+    store.save(&path).unwrap();
+    let options = DiffOptions {
+        triage: TriageStore::load(&path).unwrap(),
+        ..DiffOptions::default()
+    };
+
+    let diff = DiffReport::build(&base, &compare, "a", "b", &options);
+    assert_eq!(diff.triage.len(), 2);
+    assert_eq!(diff.triage[0].finding, "PA004@src/main.rs");
+    assert!(diff.triage[0].in_base && !diff.triage[0].in_compare);
+    assert!(diff.triage[1].in_compare);
+    let text = format_diff(&base, &compare, "a", "b", &options);
+    assert!(text.contains("PA004@src/main.rs [gone in compare]: fixed by bob (2026-03-02): on it"));
+    assert!(text.contains("memory:sigsegv [still present]: investigating by bob"));
+}
+
+#[test]
+fn test_owner_slices_crashes_and_exports() {
+    use panic_attack::assail::owners::{self, Ownership};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir(dir.path().join(".git")).unwrap();
+    std::fs::write(
+        dir.path().join("CODEOWNERS"),
+        "src/main.rs @app-team\nsrc/parse.rs @parser-team\n",
+    )
+    .unwrap();
+
+    let mut assail = make_assail_report();
+    assail.weak_points[0].owner = Some("@app-team".to_string());
+    let mut parser_crash = make_attack_result(AttackAxis::Memory, false, 1);
+    parser_crash.crashes[0].frames = panic_attack::signatures::backtrace::parse(
+        "stack backtrace:\n   0: app::parse\n             at ./src/parse.rs:42:9\n",
+    );
+    let results = vec![
+        parser_crash,
+        make_attack_result(AttackAxis::Cpu, false, 1),
+        make_attack_result(AttackAxis::Disk, true, 0),
+    ];
+    let mut assault = report::generate_assault_report(assail, results).unwrap();
+    assault.crash_owners = Ownership::discover(dir.path())
+        .unwrap()
+        .crash_owners(&assault.attack_results);
+    assert_eq!(
+        assault.crash_owners.get("memory:sigsegv@app::parse"),
+        Some(&"@parser-team".to_string())
+    );
+    assert_eq!(assault.crash_owners.len(), 1, "no frame, no owner");
+
+    let sarif = ReportOutputFormat::Sarif.serialize(&assault).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let results = parsed["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results[0]["properties"]["owner"], "@app-team");
+    assert!(results[1].get("properties").is_none());
+    assert_eq!(results[2]["properties"]["owner"], "@parser-team");
+
+    let mut sliced = assault.clone();
+    owners::slice(&mut sliced, "@parser-team");
+    assert!(sliced.assail_report.weak_points.is_empty());
+    let axes: Vec<AttackAxis> = sliced
+        .attack_results
+        .iter()
+        .map(|r| r.axis.clone())
+        .collect();
+    assert_eq!(axes, vec![AttackAxis::Memory, AttackAxis::Disk]);
+    assert_eq!(sliced.total_crashes, 1);
+}
@@ -19,6 +19,9 @@ fn make_test_report() -> AssailReport {
                 description: "unsafe block found".to_string(),
                 location: Some("src/main.rs:10".to_string()),
                 recommended_attack: vec![AttackAxis::Memory],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
             WeakPoint {
                 category: WeakPointCategory::PanicPath,
@@ -26,6 +29,9 @@ fn make_test_report() -> AssailReport {
                 description: "unwrap on Option".to_string(),
                 location: Some("src/lib.rs:42".to_string()),
                 recommended_attack: vec![],
+                provenance: None,
+                calibrated: None,
+                owner: None,
             },
         ],
         statistics: ProgramStatistics::default(),
@@ -34,6 +40,9 @@ fn make_test_report() -> AssailReport {
         dependency_graph: Default::default(),
         taint_matrix: Default::default(),
         migration_metrics: None,
+        annotations: Vec::new(),
+        policies: Vec::new(),
+        manifests: Vec::new(),
     }
 }
 
@@ -142,6 +151,9 @@ fn test_sarif_empty_report() {
         dependency_graph: Default::default(),
         taint_matrix: Default::default(),
         migration_metrics: None,
+        annotations: Vec::new(),
+        policies: Vec::new(),
+        manifests: Vec::new(),
     };
 
     let json = sarif::to_sarif_json(&report).expect("SARIF conversion should succeed");
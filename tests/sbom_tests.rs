@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: PMPL-1.0-or-later
+
+//! Tests for CycloneDX and SPDX SBOM output
+
+use panic_attack::report::sbom::{self, SbomFormat};
+use panic_attack::types::*;
+use serde_json::Value;
+
+fn dependency(name: &str, version: Option<&str>, scope: DependencyScope) -> DeclaredDependency {
+    DeclaredDependency {
+        name: name.to_string(),
+        version: version.map(str::to_string),
+        scope,
+    }
+}
+
+fn make_report() -> AssailReport {
+    AssailReport {
+        program_path: "demo".into(),
+        language: Language::Rust,
+        frameworks: vec![Framework::WebServer],
+        weak_points: vec![],
+        file_statistics: vec![],
+        recommended_attacks: vec![],
+        manifests: vec![
+            DependencyManifest {
+                path: "Cargo.toml".to_string(),
+                ecosystem: Ecosystem::Cargo,
+                name: Some("server".to_string()),
+                version: Some("0.4.0".to_string()),
+                dependencies: vec![
+                    dependency("serde", Some("1.0"), DependencyScope::Runtime),
+                    dependency("ring", Some("=0.17.8"), DependencyScope::Runtime),
+                    dependency("proto", None, DependencyScope::Runtime),
+                    dependency("tempfile", Some("3"), DependencyScope::Dev),
+                ],
+            },
+            DependencyManifest {
+                path: "proto/Cargo.toml".to_string(),
+                ecosystem: Ecosystem::Cargo,
+                name: Some("proto".to_string()),
+                version: Some("0.1.0".to_string()),
+                dependencies: vec![dependency("serde", Some("1.0"), DependencyScope::Runtime)],
+            },
+            DependencyManifest {
+                path: "web/package.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                name: Some("web".to_string()),
+                version: None,
+                dependencies: vec![dependency(
+                    "@types/node",
+                    Some("20.1.0"),
+                    DependencyScope::Dev,
+                )],
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+fn render(format: SbomFormat) -> Value {
+    let json = sbom::to_sbom_json(format, &make_report(), None).expect("SBOM should serialize");
+    serde_json::from_str(&json).expect("SBOM should be valid JSON")
+}
+
+#[test]
+fn test_cyclonedx_components_and_purls() {
+    let bom = render(SbomFormat::Cyclonedx);
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.5");
+    assert!(bom["serialNumber"]
+        .as_str()
+        .unwrap()
+        .starts_with("urn:uuid:"));
+    assert_eq!(bom["metadata"]["component"]["name"], "demo");
+
+    let components = bom["components"].as_array().unwrap();
+    let purls: Vec<&str> = components
+        .iter()
+        .map(|c| c["purl"].as_str().unwrap())
+        .collect();
+    // Shared dependencies and the sibling path crate appear once.
+    assert_eq!(
+        purls,
+        vec![
+            "pkg:cargo/server@0.4.0",
+            "pkg:cargo/proto@0.1.0",
+            "pkg:npm/web",
+            "pkg:cargo/serde",
+            "pkg:cargo/ring@0.17.8",
+            "pkg:cargo/tempfile",
+            "pkg:npm/%40types/node@20.1.0",
+        ]
+    );
+    let serde = &components[3];
+    assert_eq!(serde["version"], "1.0");
+    assert_eq!(serde["scope"], "required");
+    assert_eq!(components[5]["scope"], "excluded");
+
+    let dependencies = bom["dependencies"].as_array().unwrap();
+    assert_eq!(dependencies[0]["ref"], "panic-attack:target");
+    assert_eq!(
+        dependencies[0]["dependsOn"].as_array().unwrap().len(),
+        3,
+        "root depends on each manifest package"
+    );
+    assert_eq!(
+        dependencies[1]["dependsOn"],
+        serde_json::json!([
+            "cargo:serde@1.0",
+            "cargo:ring@=0.17.8",
+            "cargo:proto@0.1.0",
+            "cargo:tempfile@3"
+        ])
+    );
+}
+
+#[test]
+fn test_spdx_packages_and_relationships() {
+    let doc = render(SbomFormat::Spdx);
+    assert_eq!(doc["spdxVersion"], "SPDX-2.3");
+    assert_eq!(doc["dataLicense"], "CC0-1.0");
+    assert_eq!(doc["packages"].as_array().unwrap().len(), 8);
+
+    let relationships = doc["relationships"].as_array().unwrap();
+    assert_eq!(relationships[0]["relationshipType"], "DESCRIBES");
+    let has = |from: &str, kind: &str, to: &str| {
+        relationships.iter().any(|r| {
+            r["spdxElementId"] == from
+                && r["relationshipType"] == kind
+                && r["relatedSpdxElement"] == to
+        })
+    };
+    // server (1) depends on serde (4); tempfile (6) is a dev dependency of server.
+    assert!(has("SPDXRef-Package-1", "DEPENDS_ON", "SPDXRef-Package-4"));
+    assert!(has(
+        "SPDXRef-Package-6",
+        "DEV_DEPENDENCY_OF",
+        "SPDXRef-Package-1"
+    ));
+}